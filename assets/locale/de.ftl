@@ -0,0 +1,226 @@
+project-zyheeda = Project Zyheeda
+loading-assets = Lade Assets ...
+resolving-dependencies = Löse Abhängigkeiten auf ...
+
+start-menu-new-game = Neues Spiel
+start-menu-continue-game = Fortsetzen
+
+equipment = Ausrüstung
+inventory = Inventar
+
+item-force-essence = Kraftessenz
+item-gravity-essence = Gravitationsessenz
+item-socketed-essence = Gesockelt: { $essence }
+item-plasma-pistol = Plasmapistole
+item-void-beam = Leerenstrahl
+item-stat-max-health = +{ $amount } maximale Gesundheit
+item-stat-movement-speed = { $percent }% Bewegungsgeschwindigkeit
+item-stat-damage = { $percent }% Schaden
+item-stat-resistance = { $percent }% { $type }-Resistenz
+
+skill-force-shield = Kraftschild
+skill-gravity-well = Gravitationsquelle
+skill-pistol-projectile = Pistolenprojektil
+skill-pistol-beam = Pistolenstrahl
+skill-void-beam = Leerenstrahl
+skill-pistol-chain = Kettenschuss
+skill-pistol-explosive-shot = Sprenggeschoss
+skill-force-dash = Kraftsprung
+skill-void-sweep = Leerenhieb
+
+skill-stat-cast-time = Wirkzeit: { $seconds }s
+skill-stat-cooldown = Abklingzeit: { $seconds }s
+skill-stat-energy = Energie: { $energy }
+skill-stat-range = Reichweite: { $range }m
+skill-stat-radius = Radius: { $radius }m
+skill-stat-jumps = { $jumps ->
+    [one] Springt einmal
+   *[other] Springt { $jumps } mal
+}
+skill-stat-damage = Verursacht { $damage } { $type }schaden
+skill-stat-damage-per-second = Verursacht { $damage } { $type }schaden pro Sekunde
+skill-stat-damage-over-time = Verursacht { $damage } { $type }schaden über { $seconds }s
+skill-stat-slow = Verlangsamt um { $percent }% für { $seconds }s
+skill-stat-stun = Betäubt für { $seconds }s
+skill-stat-absorb = Absorbiert { $amount } Schaden für { $seconds }s
+
+damage-type-kinetic = Kinetik
+damage-type-plasma = Plasma
+damage-type-void = Leeren
+damage-type-force = Kraft
+
+progression-level = Stufe { $level }
+progression-experience = Erfahrung: { $experience } / { $next }
+progression-skill-points = Fertigkeitspunkte: { $points }
+progression-upgrade = { $cost ->
+    [one] { $upgrade } ({ $cost } Punkt)
+   *[other] { $upgrade } ({ $cost } Punkte)
+}
+progression-upgrade-acquire = Erwerben
+progression-upgrade-acquired = Erworben
+
+upgrade-pistol-projectile-damage-1 = Geschärfte Munition
+upgrade-pistol-projectile-damage-2 = Durchschlagende Munition
+upgrade-pistol-beam-range = Fokussierter Strahl
+upgrade-pistol-beam-damage = Überladener Strahl
+upgrade-gravity-well-radius = Weite Quelle
+upgrade-gravity-well-lifetime = Beständige Quelle
+
+combo-skill-menu = Kombos
+combo-skill-add = Neue Kombo-Fertigkeit hinzufügen
+combo-skill-delete = Fertigkeit (und nachfolgende Fertigkeiten) aus dem Kombo-Baum entfernen
+
+paused = Pausiert
+save-slot = Spielstand { $slot }
+save-slot-empty = Leer
+save-slot-level = Stufe { $level }
+save-slot-play-time = Gespielt: { $time }
+save-slot-save = Speichern
+save-slot-load = Laden
+
+key-bindings = Tastenbelegung
+key-bindings-slots = Slots
+key-bindings-movement = Bewegung
+key-bindings-miscellaneous = Sonstiges
+key-bindings-camera = Kamera
+key-bindings-savegame = Spielstand
+key-bindings-targeting = Zielerfassung
+
+audio = Audio
+audio-volumes = Lautstärke
+audio-master = Gesamt
+audio-sfx = Effekte
+audio-music = Musik
+audio-ui = Oberfläche
+
+settings = Allgemein
+settings-display = Anzeige
+settings-fps-cap = FPS-Limit
+settings-vsync = VSync
+settings-window-mode = Fenstermodus
+settings-window-mode-windowed = Fenster
+settings-window-mode-borderless = Randlos
+settings-window-mode-fullscreen = Vollbild
+settings-resolution-scale = Auflösungsskalierung
+settings-post-processing = Nachbearbeitung
+settings-outlines = Umrisse
+settings-camera-sensitivity = Kameraempfindlichkeit
+settings-language = Sprache
+settings-on = An
+settings-off = Aus
+settings-targeting-left = Zielwahl linke Hand
+settings-targeting-right = Zielwahl rechte Hand
+settings-targeting-cursor = Cursor
+settings-targeting-auto = Automatisch
+settings-targeting-lock-on = Zielerfassung
+settings-targeting-self = Selbst
+
+language-de = Deutsch
+language-en-us = English
+
+slot-key-hand-left = Linke Hand
+slot-key-hand-right = Rechte Hand
+terrain-targeting = Geländeziel
+interact = Interagieren
+movement-key-forward = Vorwärts
+movement-key-backward = Rückwärts
+movement-key-left = Links
+movement-key-right = Rechts
+movement-key-pointer = Zum Mauszeiger bewegen
+movement-key-toggle-walk-run = Gehen/Laufen umschalten
+camera-key-rotate = Kamera drehen
+menu-inventory = Inventar
+menu-combos = Kombos
+menu-progression = Fortschritt
+menu-settings = Einstellungen
+menu-paused = Pause
+save-quick-save = Schnellspeichern
+save-quick-load = Schnellladen
+
+key-code-digit-0 = 0
+key-code-digit-1 = 1
+key-code-digit-2 = 2
+key-code-digit-3 = 3
+key-code-digit-4 = 4
+key-code-digit-5 = 5
+key-code-digit-6 = 6
+key-code-digit-7 = 7
+key-code-digit-8 = 8
+key-code-digit-9 = 9
+key-code-key-a = A
+key-code-key-b = B
+key-code-key-c = C
+key-code-key-d = D
+key-code-key-e = E
+key-code-key-f = F
+key-code-key-g = G
+key-code-key-h = H
+key-code-key-i = I
+key-code-key-j = J
+key-code-key-k = K
+key-code-key-l = L
+key-code-key-m = M
+key-code-key-n = N
+key-code-key-o = O
+key-code-key-p = P
+key-code-key-q = Q
+key-code-key-r = R
+key-code-key-s = S
+key-code-key-t = T
+key-code-key-u = U
+key-code-key-v = V
+key-code-key-w = W
+key-code-key-x = X
+key-code-key-y = Y
+key-code-key-z = Z
+key-code-f-1 = F1
+key-code-f-2 = F2
+key-code-f-3 = F3
+key-code-f-4 = F4
+key-code-f-5 = F5
+key-code-f-6 = F6
+key-code-f-7 = F7
+key-code-f-8 = F8
+key-code-f-9 = F9
+key-code-f-10 = F10
+key-code-f-11 = F11
+key-code-f-12 = F12
+key-code-escape = Esc
+key-code-numpad-subtract = Ziffernblock -
+key-code-space = Leertaste
+key-code-shift-left = Linke Umschalttaste
+
+mouse-button-left = Linke Maustaste
+mouse-button-right = Rechte Maustaste
+mouse-button-middle = Mittlere Maustaste
+
+gamepad-button-south = Gamepad-Taste Süd
+gamepad-button-east = Gamepad-Taste Ost
+gamepad-button-north = Gamepad-Taste Nord
+gamepad-button-west = Gamepad-Taste West
+gamepad-button-c = Gamepad-Taste C
+gamepad-button-z = Gamepad-Taste Z
+gamepad-button-left-trigger = Linke Schultertaste
+gamepad-button-left-trigger-2 = Linker Trigger
+gamepad-button-right-trigger = Rechte Schultertaste
+gamepad-button-right-trigger-2 = Rechter Trigger
+gamepad-button-select = Select
+gamepad-button-start = Start
+gamepad-button-mode = Gamepad-Modustaste
+gamepad-button-left-thumb = Linke Sticktaste
+gamepad-button-right-thumb = Rechte Sticktaste
+gamepad-button-d-pad-up = Steuerkreuz oben
+gamepad-button-d-pad-down = Steuerkreuz unten
+gamepad-button-d-pad-left = Steuerkreuz links
+gamepad-button-d-pad-right = Steuerkreuz rechts
+
+gamepad-stick-left-up = Linker Stick oben
+gamepad-stick-left-down = Linker Stick unten
+gamepad-stick-left-left = Linker Stick links
+gamepad-stick-left-right = Linker Stick rechts
+gamepad-stick-right-up = Rechter Stick oben
+gamepad-stick-right-down = Rechter Stick unten
+gamepad-stick-right-left = Rechter Stick links
+gamepad-stick-right-right = Rechter Stick rechts
+
+rebind-text-prompt = Taste zum Neubelegen drücken
@@ -11,20 +11,74 @@ equipment = Equipment
 inventory = Inventory
 
 item-force-essence = Force Essence
+item-gravity-essence = Gravity Essence
+item-socketed-essence = Socketed: { $essence }
 item-plasma-pistol = Plasma Pistol
 item-void-beam = Void Beam
+item-stat-max-health = +{ $amount } max health
+item-stat-movement-speed = { $percent }% movement speed
+item-stat-damage = { $percent }% damage
+item-stat-resistance = { $percent }% { $type } resistance
 
 skill-force-shield = Force Shield
 skill-gravity-well = Gravity Well
 skill-pistol-projectile = Pistol Projectile
 skill-pistol-beam = Pistol Beam
 skill-void-beam = Void Beam
+skill-pistol-chain = Chain Shot
+skill-pistol-explosive-shot = Explosive Shot
+skill-force-dash = Force Dash
+skill-void-sweep = Void Sweep
+
+skill-stat-cast-time = Cast time: { $seconds }s
+skill-stat-cooldown = Cooldown: { $seconds }s
+skill-stat-energy = Energy: { $energy }
+skill-stat-range = Range: { $range }m
+skill-stat-radius = Radius: { $radius }m
+skill-stat-jumps = { $jumps ->
+    [one] Jumps once
+   *[other] Jumps { $jumps } times
+}
+skill-stat-damage = Deals { $damage } { $type } damage
+skill-stat-damage-per-second = Deals { $damage } { $type } damage per second
+skill-stat-damage-over-time = Deals { $damage } { $type } damage over { $seconds }s
+skill-stat-slow = Slows by { $percent }% for { $seconds }s
+skill-stat-stun = Stuns for { $seconds }s
+skill-stat-absorb = Absorbs { $amount } damage for { $seconds }s
+
+damage-type-kinetic = kinetic
+damage-type-plasma = plasma
+damage-type-void = void
+damage-type-force = force
+
+progression-level = Level { $level }
+progression-experience = Experience: { $experience } / { $next }
+progression-skill-points = Skill points: { $points }
+progression-upgrade = { $cost ->
+    [one] { $upgrade } ({ $cost } point)
+   *[other] { $upgrade } ({ $cost } points)
+}
+progression-upgrade-acquire = Acquire
+progression-upgrade-acquired = Acquired
+
+upgrade-pistol-projectile-damage-1 = Sharpened Rounds
+upgrade-pistol-projectile-damage-2 = Piercing Rounds
+upgrade-pistol-beam-range = Focused Beam
+upgrade-pistol-beam-damage = Overcharged Beam
+upgrade-gravity-well-radius = Wide Well
+upgrade-gravity-well-lifetime = Lasting Well
 
 combo-skill-menu = Combos
 combo-skill-add = Add new combo skill
 combo-skill-delete = Delete skill (and subsequent skills) from combo tree
 
 paused = Paused
+save-slot = Slot { $slot }
+save-slot-empty = Empty
+save-slot-level = Level { $level }
+save-slot-play-time = Played { $time }
+save-slot-save = Save
+save-slot-load = Load
 
 key-bindings = Key Bindings
 key-bindings-slots = Slots
@@ -34,6 +88,38 @@ key-bindings-camera = Camera
 key-bindings-savegame = Save Game
 key-bindings-targeting = Targeting
 
+audio = Audio
+audio-volumes = Volumes
+audio-master = Master
+audio-sfx = Effects
+audio-music = Music
+audio-ui = Interface
+
+settings = General
+settings-display = Display
+settings-fps-cap = FPS Cap
+settings-vsync = VSync
+settings-window-mode = Window Mode
+settings-window-mode-windowed = Windowed
+settings-window-mode-borderless = Borderless
+settings-window-mode-fullscreen = Fullscreen
+settings-resolution-scale = Resolution Scale
+settings-post-processing = Post Processing
+settings-outlines = Outlines
+settings-camera-sensitivity = Camera Sensitivity
+settings-language = Language
+settings-on = On
+settings-off = Off
+settings-targeting-left = Left Hand Targeting
+settings-targeting-right = Right Hand Targeting
+settings-targeting-cursor = Cursor
+settings-targeting-auto = Auto Target
+settings-targeting-lock-on = Lock-On
+settings-targeting-self = Self
+
+language-de = Deutsch
+language-en-us = English
+
 slot-key-hand-left = Left Hand
 slot-key-hand-right = Right Hand
 
@@ -52,6 +138,7 @@ camera-key-rotate = Camera Rotation
 
 menu-inventory = Inventory
 menu-combos = Combos
+menu-progression = Progression
 menu-settings = Settings
 menu-paused = Pause
 
@@ -115,5 +202,34 @@ mouse-button-left = Left Mouse Button
 mouse-button-right = Right Mouse Button
 mouse-button-middle = Middle Mouse Button
 
+gamepad-button-south = Gamepad South Button
+gamepad-button-east = Gamepad East Button
+gamepad-button-north = Gamepad North Button
+gamepad-button-west = Gamepad West Button
+gamepad-button-c = Gamepad C Button
+gamepad-button-z = Gamepad Z Button
+gamepad-button-left-trigger = Left Bumper
+gamepad-button-left-trigger-2 = Left Trigger
+gamepad-button-right-trigger = Right Bumper
+gamepad-button-right-trigger-2 = Right Trigger
+gamepad-button-select = Select
+gamepad-button-start = Start
+gamepad-button-mode = Gamepad Mode Button
+gamepad-button-left-thumb = Left Stick Button
+gamepad-button-right-thumb = Right Stick Button
+gamepad-button-d-pad-up = D-Pad Up
+gamepad-button-d-pad-down = D-Pad Down
+gamepad-button-d-pad-left = D-Pad Left
+gamepad-button-d-pad-right = D-Pad Right
+
+gamepad-stick-left-up = Left Stick Up
+gamepad-stick-left-down = Left Stick Down
+gamepad-stick-left-left = Left Stick Left
+gamepad-stick-left-right = Left Stick Right
+gamepad-stick-right-up = Right Stick Up
+gamepad-stick-right-down = Right Stick Down
+gamepad-stick-right-left = Right Stick Left
+gamepad-stick-right-right = Right Stick Right
+
 rebind-text-prompt = Press key to rebind
 
@@ -6,6 +6,7 @@ use syn::{
 	DeriveInput,
 	Error,
 	Expr,
+	ExprArray,
 	Field,
 	Fields,
 	Ident,
@@ -462,6 +463,8 @@ fn find_attribute<'a>(input: &'a DeriveInput, name: &str) -> Option<&'a syn::Att
 ///   Sets `SavableComponent::TDto` to the given type. Defaults to `Self`.
 /// - `has_priority` *(optional, flag)*:
 ///   When present, `SavableComponent::PRIORITY` will be `true`. Defaults to `false`.
+/// - `migrations = [fn, ...]` *(optional)*:
+///   Sets `SavableComponent::MIGRATIONS` to the given functions. Defaults to no migrations.
 #[proc_macro_derive(SavableComponent, attributes(savable_component))]
 pub fn derive_savable_component(input: TokenStream) -> TokenStream {
 	let common = match crate_root("common") {
@@ -474,6 +477,7 @@ pub fn derive_savable_component(input: TokenStream) -> TokenStream {
 	let mut id = None;
 	let mut dto = None;
 	let mut priority = false;
+	let mut migrations = None;
 	let mut where_clause = match where_clause.cloned() {
 		Some(where_clause) => where_clause,
 		None => syn::WhereClause {
@@ -500,6 +504,10 @@ pub fn derive_savable_component(input: TokenStream) -> TokenStream {
 				priority = true;
 				Ok(())
 			}
+			Some(ident) if ident == "migrations" => {
+				migrations = Some(nested.value()?.parse::<ExprArray>()?);
+				Ok(())
+			}
 			Some(other) => Err(Error::new(
 				nested.path.span(),
 				format!("unknown key word '{other}'"),
@@ -529,6 +537,17 @@ pub fn derive_savable_component(input: TokenStream) -> TokenStream {
 		None => quote! {Self},
 	};
 
+	let migrations = match migrations {
+		Some(ExprArray { elems, .. }) => {
+			quote! {
+				const MIGRATIONS: &'static [#common::traits::handles_saving::Migration] = &[#elems];
+			}
+		}
+		None => {
+			quote! {}
+		}
+	};
+
 	where_clause.predicates.push(syn::parse_quote! {
 		Self: bevy::prelude::Component +
 			Sized +
@@ -547,6 +566,7 @@ pub fn derive_savable_component(input: TokenStream) -> TokenStream {
 			type TDto = #dto;
 			const PRIORITY: bool = #priority;
 			#id
+			#migrations
 		}
 	})
 }
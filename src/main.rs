@@ -1,5 +1,6 @@
 use agents::AgentsPlugin;
 use animations::AnimationsPlugin;
+use audio::AudioPlugin;
 use bars::BarsPlugin;
 use bevy::prelude::*;
 use camera_control::CameraControlPlugin;
@@ -17,6 +18,7 @@ use movement::MovementPlugin;
 use path_finding::PathFindingPlugin;
 use physics::PhysicsPlugin;
 use savegame::SavegamePlugin;
+use settings::SettingsPlugin;
 use std::{
 	env::home_dir,
 	process::{ExitCode, Termination},
@@ -46,6 +48,7 @@ fn prepare_game(app: &mut App) -> Result<(), ZyheedaAppError> {
 	let loading = LoadingPlugin;
 	let input = InputPlugin::from_plugin(&loading);
 	let localization = LocalizationPlugin::from_plugin(&loading);
+	let settings = SettingsPlugin::from_plugin(&localization).with_game_directory(game_dir.clone());
 	let savegame = SavegamePlugin::from_plugin(&input).with_game_directory(game_dir);
 	let animations = AnimationsPlugin::from_plugin(&savegame);
 	let physics = PhysicsPlugin::new(TARGET_FPS, &savegame, &animations);
@@ -60,10 +63,12 @@ fn prepare_game(app: &mut App) -> Result<(), ZyheedaAppError> {
 		&loading,
 		&savegame,
 		&physics,
+		&input,
+		&settings,
 	);
 
 	#[cfg(not(feature = "debug-utils"))]
-	let graphics = GraphicsPlugin::from_plugins(&loading, &savegame, &physics);
+	let graphics = GraphicsPlugin::from_plugins(&loading, &savegame, &physics, &input, &settings);
 
 	let loadout = LoadoutPlugin::from_plugins(&savegame, &physics, &loading, &movement);
 	let interactive =
@@ -79,7 +84,9 @@ fn prepare_game(app: &mut App) -> Result<(), ZyheedaAppError> {
 		&map_generation,
 		&movement,
 		&loadout,
+		&settings,
 	);
+	let audio = AudioPlugin::from_plugins(&loading, &agents);
 	let menus = MenuPlugin::from_plugins(
 		&loading,
 		&savegame,
@@ -88,18 +95,20 @@ fn prepare_game(app: &mut App) -> Result<(), ZyheedaAppError> {
 		&graphics,
 		&agents,
 		&loadout,
+		&audio,
+		&settings,
 	);
 	let bars = BarsPlugin::from_plugins(&agents, &physics, &graphics);
-	let camera_control =
-		CameraControlPlugin::from_plugins(&input, &physics, &savegame, &agents, &graphics);
-	let frame_limiter = FrameLimiterPlugin {
-		target_fps: TARGET_FPS,
-	};
+	let camera_control = CameraControlPlugin::from_plugins(
+		&input, &physics, &savegame, &agents, &graphics, &settings,
+	);
+	let frame_limiter = FrameLimiterPlugin::from_plugin(&settings);
 	let common = CommonPlugin::with_asset_loading(true);
 
 	app.add_plugins(DefaultPlugins)
 		.add_plugins(agents)
 		.add_plugins(animations)
+		.add_plugins(audio)
 		.add_plugins(bars)
 		.add_plugins(camera_control)
 		.add_plugins(common)
@@ -116,6 +125,7 @@ fn prepare_game(app: &mut App) -> Result<(), ZyheedaAppError> {
 		.add_plugins(path_finding)
 		.add_plugins(physics)
 		.add_plugins(savegame)
+		.add_plugins(settings)
 		.insert_resource(ClearColor(Color::BLACK));
 
 	Ok(())
@@ -1,5 +1,6 @@
 pub(crate) mod dto;
 
+use crate::components::enemy::behavior::EnemyBehavior;
 use bevy::prelude::*;
 use common::{
 	prelude::*,
@@ -22,6 +23,13 @@ pub struct AgentMeta {
 	pub(crate) attributes: PhysicalDefaultAttributes,
 	pub(crate) animations: HashMap<AnimationKey, Animation<AnimationNames>>,
 	pub(crate) animation_mask_groups: HashMap<AnimationMaskBits, AffectedAnimationBones>,
+	pub(crate) behavior: Option<EnemyBehavior>,
+	/// Items dropped as world pickups when the agent dies.
+	pub(crate) drops: Vec<ItemName>,
+	/// Experience awarded to the player when the agent dies.
+	pub(crate) experience: u32,
+	pub(crate) footstep_sound: Option<SoundCue>,
+	pub(crate) death_sound: Option<SoundCue>,
 }
 
 impl AssetFolderPath for AgentMeta {
@@ -1,6 +1,6 @@
 use crate::{
 	assets::agent_meta::{AgentMeta, AgentModel, Bones, HeightLevels, Loadout, RequiredClearance},
-	components::enemy::void_sphere::VoidSphere,
+	components::enemy::{behavior::EnemyBehavior, void_sphere::VoidSphere},
 };
 use bevy::prelude::*;
 use common::prelude::*;
@@ -13,6 +13,16 @@ pub struct AgentConfigDto {
 	model: ModelConfig,
 	loadout: Loadout,
 	attributes: PhysicalDefaultAttributes,
+	#[serde(default)]
+	behavior: Option<EnemyBehavior>,
+	#[serde(default)]
+	drops: Vec<ItemName>,
+	#[serde(default)]
+	experience: u32,
+	#[serde(default)]
+	footstep_sound: Option<SoundCue>,
+	#[serde(default)]
+	death_sound: Option<SoundCue>,
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -45,13 +55,23 @@ impl TryLoadFrom<AgentConfigDto> for AgentMeta {
 			model,
 			loadout,
 			attributes,
+			behavior,
+			drops,
+			experience,
+			footstep_sound,
+			death_sound,
 		}: AgentConfigDto,
 		_: &mut TLoadAsset,
 	) -> Result<Self, Self::TInstantiationError> {
 		match model {
-			ModelConfig::Procedural(ProceduralModel::VoidSphere) => {
-				Ok(VoidSphere::config(loadout, attributes))
-			}
+			ModelConfig::Procedural(ProceduralModel::VoidSphere) => Ok(AgentMeta {
+				behavior,
+				drops,
+				experience,
+				footstep_sound,
+				death_sound,
+				..VoidSphere::config(loadout, attributes)
+			}),
 			ModelConfig::Asset {
 				model_path,
 				interactive_detection_shape,
@@ -72,6 +92,11 @@ impl TryLoadFrom<AgentConfigDto> for AgentMeta {
 				attributes,
 				animations,
 				animation_mask_groups,
+				behavior,
+				drops,
+				experience,
+				footstep_sound,
+				death_sound,
 			}),
 		}
 	}
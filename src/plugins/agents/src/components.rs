@@ -2,4 +2,7 @@ pub(crate) mod agent;
 pub(crate) mod agent_config;
 pub(crate) mod animate_idle;
 pub(crate) mod enemy;
+pub(crate) mod footsteps;
 pub(crate) mod player;
+pub(crate) mod target_lock;
+pub(crate) mod targeting_slot;
@@ -1,10 +1,13 @@
-use crate::{assets::agent_meta::AgentMeta, components::animate_idle::AnimateIdle};
+use crate::{
+	assets::agent_meta::AgentMeta,
+	components::{animate_idle::AnimateIdle, footsteps::Footsteps},
+};
 use bevy::prelude::*;
 use common::prelude::*;
 
 #[derive(Component, Clone, Debug, PartialEq, Default)]
 #[component(immutable)]
-#[require(PersistentEntity, Transform, Visibility, AnimateIdle)]
+#[require(PersistentEntity, Transform, Visibility, AnimateIdle, Footsteps)]
 pub struct AgentConfig {
 	pub(crate) config_handle: Handle<AgentMeta>,
 }
@@ -1,6 +1,7 @@
 pub(crate) mod attack_config;
 pub(crate) mod attack_phase;
 pub(crate) mod attacking;
+pub(crate) mod behavior;
 pub(crate) mod chasing;
 pub(crate) mod void_sphere;
 
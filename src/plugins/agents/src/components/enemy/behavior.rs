@@ -0,0 +1,297 @@
+use crate::components::enemy::attack_config::EnemyAttackConfig;
+use bevy::prelude::*;
+use common::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Data driven decision making of an enemy, configured in its `.agent` file.
+///
+/// Movement and attacks are chosen independently, so that an enemy can, for
+/// instance, strafe while attacking. Of each list the action with the highest
+/// score, whose considerations all hold, is chosen.
+#[derive(Component, Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[require(EnemyAttackConfig, EnemyMovementDecision, EnemyBehaviorState)]
+pub(crate) struct EnemyBehavior {
+	#[serde(default)]
+	pub(crate) movement: Vec<Scored<MovementAction>>,
+	#[serde(default)]
+	pub(crate) attacks: Vec<Scored<AttackAction>>,
+}
+
+impl EnemyBehavior {
+	pub(crate) fn movement(&self, situation: &Situation) -> MovementAction {
+		best(&self.movement, situation).unwrap_or(MovementAction::Idle)
+	}
+
+	pub(crate) fn attack(&self, situation: &Situation) -> Option<AttackAction> {
+		best(&self.attacks, situation)
+	}
+}
+
+fn best<T>(actions: &[Scored<T>], situation: &Situation) -> Option<T>
+where
+	T: Copy,
+{
+	actions
+		.iter()
+		.filter(|action| action.score > 0. && action.applies(situation))
+		.max_by(|a, b| a.score.total_cmp(&b.score))
+		.map(|action| action.action)
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub(crate) struct Scored<T> {
+	pub(crate) action: T,
+	pub(crate) score: f32,
+	#[serde(default)]
+	pub(crate) considerations: Vec<Consideration>,
+}
+
+impl<T> Scored<T> {
+	fn applies(&self, situation: &Situation) -> bool {
+		self.considerations
+			.iter()
+			.all(|consideration| consideration.applies(situation))
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) enum Consideration {
+	PlayerWithin(Units),
+	PlayerBeyond(Units),
+	/// Fraction of max health
+	HealthBelow(f32),
+	/// Fraction of max health
+	HealthAbove(f32),
+	LineOfSight,
+	NoLineOfSight,
+}
+
+impl Consideration {
+	fn applies(&self, situation: &Situation) -> bool {
+		match self {
+			Self::PlayerWithin(range) => situation.player_distance <= **range,
+			Self::PlayerBeyond(range) => situation.player_distance > **range,
+			Self::HealthBelow(fraction) => situation.health_fraction() < *fraction,
+			Self::HealthAbove(fraction) => situation.health_fraction() > *fraction,
+			Self::LineOfSight => situation.has_los,
+			Self::NoLineOfSight => !situation.has_los,
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) enum MovementAction {
+	Idle,
+	Chase,
+	Flee,
+	/// Circle around the player
+	Strafe,
+	/// Walk a square around the position where patrolling started
+	Patrol {
+		radius: Units,
+	},
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct AttackAction {
+	pub(crate) slot: SlotKey,
+	pub(crate) hold: DurationInSeconds,
+	pub(crate) cooldown: DurationInSeconds,
+}
+
+impl From<AttackAction> for EnemyAttackConfig {
+	fn from(
+		AttackAction {
+			slot,
+			hold,
+			cooldown,
+		}: AttackAction,
+	) -> Self {
+		Self {
+			key: slot,
+			hold: hold.into(),
+			cooldown: cooldown.into(),
+		}
+	}
+}
+
+/// What an enemy perceives when deciding.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Situation {
+	pub(crate) player_distance: f32,
+	pub(crate) health: Health,
+	pub(crate) has_los: bool,
+}
+
+impl Situation {
+	fn health_fraction(&self) -> f32 {
+		if self.health.max <= 0. {
+			return 0.;
+		}
+
+		self.health.current / self.health.max
+	}
+}
+
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub(crate) struct EnemyMovementDecision(pub(crate) MovementAction);
+
+impl Default for EnemyMovementDecision {
+	fn default() -> Self {
+		Self(MovementAction::Idle)
+	}
+}
+
+/// Progress of the currently executed movement action.
+#[derive(Component, Debug, PartialEq, Clone, Default)]
+pub(crate) struct EnemyBehaviorState {
+	pub(crate) target: Option<Vec3>,
+	pub(crate) patrol_anchor: Option<Vec3>,
+	pub(crate) patrol_step: u8,
+	pub(crate) strafe_clockwise: bool,
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use serde_json::json;
+	use test_case::test_case;
+
+	const SITUATION: Situation = Situation {
+		player_distance: 5.,
+		health: Health {
+			current: 3.,
+			max: 10.,
+		},
+		has_los: true,
+	};
+
+	fn scored<T>(
+		action: T,
+		score: f32,
+		considerations: impl Into<Vec<Consideration>>,
+	) -> Scored<T> {
+		Scored {
+			action,
+			score,
+			considerations: considerations.into(),
+		}
+	}
+
+	#[test_case(Consideration::PlayerWithin(Units::from(5.)), true; "player within")]
+	#[test_case(Consideration::PlayerWithin(Units::from(4.)), false; "player not within")]
+	#[test_case(Consideration::PlayerBeyond(Units::from(4.)), true; "player beyond")]
+	#[test_case(Consideration::PlayerBeyond(Units::from(5.)), false; "player not beyond")]
+	#[test_case(Consideration::HealthBelow(0.4), true; "health below")]
+	#[test_case(Consideration::HealthBelow(0.3), false; "health not below")]
+	#[test_case(Consideration::HealthAbove(0.2), true; "health above")]
+	#[test_case(Consideration::HealthAbove(0.3), false; "health not above")]
+	#[test_case(Consideration::LineOfSight, true; "line of sight")]
+	#[test_case(Consideration::NoLineOfSight, false; "no line of sight")]
+	fn consideration(consideration: Consideration, expected: bool) {
+		assert_eq!(expected, consideration.applies(&SITUATION));
+	}
+
+	#[test]
+	fn choose_highest_scoring_movement() {
+		let behavior = EnemyBehavior {
+			movement: vec![
+				scored(MovementAction::Chase, 1., []),
+				scored(MovementAction::Flee, 2., []),
+				scored(MovementAction::Strafe, 0.5, []),
+			],
+			..default()
+		};
+
+		assert_eq!(MovementAction::Flee, behavior.movement(&SITUATION));
+	}
+
+	#[test]
+	fn skip_movement_with_failing_considerations() {
+		let behavior = EnemyBehavior {
+			movement: vec![
+				scored(MovementAction::Chase, 1., []),
+				scored(MovementAction::Flee, 2., [Consideration::NoLineOfSight]),
+			],
+			..default()
+		};
+
+		assert_eq!(MovementAction::Chase, behavior.movement(&SITUATION));
+	}
+
+	#[test]
+	fn idle_when_no_movement_applies() {
+		let behavior = EnemyBehavior {
+			movement: vec![scored(MovementAction::Chase, 0., [])],
+			..default()
+		};
+
+		assert_eq!(MovementAction::Idle, behavior.movement(&SITUATION));
+	}
+
+	#[test]
+	fn choose_highest_scoring_attack() {
+		let attack = |slot| AttackAction {
+			slot: SlotKey(slot),
+			hold: DurationInSeconds::from(std::time::Duration::ZERO),
+			cooldown: DurationInSeconds::from(std::time::Duration::ZERO),
+		};
+		let behavior = EnemyBehavior {
+			attacks: vec![
+				scored(attack(0), 1., []),
+				scored(
+					attack(1),
+					3.,
+					[Consideration::PlayerWithin(Units::from(2.))],
+				),
+				scored(attack(2), 2., []),
+			],
+			..default()
+		};
+
+		assert_eq!(Some(attack(2)), behavior.attack(&SITUATION));
+	}
+
+	#[test]
+	fn deserialize() {
+		let value = json!({
+			"movement": [{
+				"action": {"Patrol": {"radius": 2}},
+				"score": 0.5,
+				"considerations": [{"PlayerBeyond": 8}, "NoLineOfSight"]
+			}],
+			"attacks": [{
+				"action": {"slot": 1, "hold": {"seconds": 1}, "cooldown": {"seconds": 2}},
+				"score": 1
+			}]
+		});
+
+		let behavior = serde_json::from_value::<EnemyBehavior>(value).unwrap();
+
+		assert_eq!(
+			EnemyBehavior {
+				movement: vec![scored(
+					MovementAction::Patrol {
+						radius: Units::from(2.)
+					},
+					0.5,
+					[
+						Consideration::PlayerBeyond(Units::from(8.)),
+						Consideration::NoLineOfSight
+					]
+				)],
+				attacks: vec![scored(
+					AttackAction {
+						slot: SlotKey(1),
+						hold: DurationInSeconds::from(std::time::Duration::from_secs(1)),
+						cooldown: DurationInSeconds::from(std::time::Duration::from_secs(2)),
+					},
+					1.,
+					[]
+				)],
+			},
+			behavior
+		);
+	}
+}
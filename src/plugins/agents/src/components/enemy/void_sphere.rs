@@ -79,6 +79,11 @@ impl VoidSphere {
 			speed: MovementSpeed::Fixed(UnitsPerSecond::from_u8(1)),
 			animations: HashMap::from([]),
 			animation_mask_groups: HashMap::from([]),
+			behavior: None,
+			drops: vec![],
+			experience: 0,
+			footstep_sound: None,
+			death_sound: None,
 		}
 	}
 
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Time an agent has been moving since its last footstep sound.
+#[derive(Component, Debug, PartialEq, Default)]
+pub(crate) struct Footsteps {
+	pub(crate) elapsed: Duration,
+}
+
+impl Footsteps {
+	pub(crate) const INTERVAL: Duration = Duration::from_millis(400);
+}
@@ -1,9 +1,10 @@
+use crate::components::{target_lock::TargetLock, targeting_slot::TargetingSlot};
 use bevy::prelude::*;
 use common::prelude::*;
 
 #[derive(Component, Default, Debug, PartialEq, Clone)]
 #[component(immutable)]
-#[require(Name = "Player")]
+#[require(Name = "Player", Transform, TargetLock, TargetingSlot)]
 pub struct Player;
 
 impl From<Player> for AgentType {
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// The enemy a player keeps targeting with [`TargetingMode::LockOn`].
+#[derive(Component, Debug, PartialEq, Default, Clone, Copy)]
+pub(crate) struct TargetLock(pub(crate) Option<PersistentEntity>);
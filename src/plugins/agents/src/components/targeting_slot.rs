@@ -0,0 +1,74 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// The held hand slot whose [`TargetingMode`] decides the player's skill target.
+///
+/// All skills of an agent share one target, so when both hands are held, the most recently
+/// pressed one wins.
+#[derive(Component, Debug, PartialEq, Default, Clone, Copy)]
+pub(crate) struct TargetingSlot(pub(crate) Option<HandSlot>);
+
+impl TargetingSlot {
+	/// Picks the slot that was just pressed, otherwise keeps the current one while it is held.
+	pub(crate) fn next<T>(self, held: T) -> Self
+	where
+		T: IntoIterator<Item = (HandSlot, InputState)>,
+	{
+		let mut first_held = None;
+		let mut current_held = None;
+
+		for (slot, state) in held {
+			match state {
+				InputState::Pressed { just_now: true } => return Self(Some(slot)),
+				InputState::Pressed { just_now: false } if Some(slot) == self.0 => {
+					current_held = Some(slot);
+				}
+				InputState::Pressed { just_now: false } => {
+					first_held = first_held.or(Some(slot));
+				}
+				InputState::Released { .. } => {}
+			}
+		}
+
+		Self(current_held.or(first_held))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	#[test_case(None, [], None; "nothing held")]
+	#[test_case(
+		None,
+		[(HandSlot::Left, InputState::pressed())],
+		Some(HandSlot::Left);
+		"first held"
+	)]
+	#[test_case(
+		Some(HandSlot::Right),
+		[(HandSlot::Left, InputState::pressed()), (HandSlot::Right, InputState::pressed())],
+		Some(HandSlot::Right);
+		"keep current while held"
+	)]
+	#[test_case(
+		Some(HandSlot::Left),
+		[(HandSlot::Left, InputState::pressed()), (HandSlot::Right, InputState::just_pressed())],
+		Some(HandSlot::Right);
+		"switch to just pressed"
+	)]
+	#[test_case(
+		Some(HandSlot::Left),
+		[(HandSlot::Left, InputState::released()), (HandSlot::Right, InputState::pressed())],
+		Some(HandSlot::Right);
+		"fall back to other held when current released"
+	)]
+	fn next<const N: usize>(
+		current: Option<HandSlot>,
+		held: [(HandSlot, InputState); N],
+		expected: Option<HandSlot>,
+	) {
+		assert_eq!(TargetingSlot(expected), TargetingSlot(current).next(held));
+	}
+}
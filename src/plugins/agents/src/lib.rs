@@ -1,5 +1,6 @@
 mod assets;
 mod components;
+mod observers;
 mod system_params;
 mod systems;
 
@@ -9,7 +10,12 @@ use crate::{
 		agent::{Agent, ApplyAgentAnimations, ApplyAgentConfig},
 		agent_config::AgentConfig,
 		animate_idle::AnimateIdle,
-		enemy::{Enemy, attack_phase::EnemyAttackPhase, void_sphere::VoidSphere},
+		enemy::{
+			Enemy,
+			attack_phase::EnemyAttackPhase,
+			behavior::EnemyBehavior,
+			void_sphere::VoidSphere,
+		},
 		player::Player,
 	},
 	system_params::agent_param::AgentParam,
@@ -36,6 +42,7 @@ impl<
 	TMaps,
 	TMovement,
 	TLoadout,
+	TSettings,
 >
 	AgentsPlugin<(
 		TLoading,
@@ -48,6 +55,7 @@ impl<
 		TMaps,
 		TMovement,
 		TLoadout,
+		TSettings,
 	)>
 where
 	TLoading: ThreadSafe + HandlesCustomFolderAssets,
@@ -57,13 +65,15 @@ where
 		+ HandlesPhysicsConfig
 		+ HandlesRaycast
 		+ HandlesPhysicalSkillAgent
-		+ HandlesInteractiveDetection,
+		+ HandlesInteractiveDetection
+		+ HandlesLife,
 	TGraphics: ThreadSafe + SystemSetDefinition + HandlesGraphics + HandlesCameras,
 	TInteractive: ThreadSafe + SystemSetDefinition + HandlesInteractive,
 	TAnimations: ThreadSafe + HandlesAnimations,
 	TMaps: ThreadSafe + HandlesMapGeneration,
 	TMovement: ThreadSafe + HandlesMovement + HandlesOrientation,
 	TLoadout: ThreadSafe + HandlesLoadout,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	#[allow(clippy::too_many_arguments)]
 	pub fn from_plugins(
@@ -77,6 +87,7 @@ where
 		_: &TMaps,
 		_: &TMovement,
 		_: &TLoadout,
+		_: &TSettings,
 	) -> Self {
 		Self(PhantomData)
 	}
@@ -93,6 +104,7 @@ impl<
 	TMaps,
 	TMovement,
 	TLoadout,
+	TSettings,
 > Plugin
 	for AgentsPlugin<(
 		TLoading,
@@ -105,6 +117,7 @@ impl<
 		TMaps,
 		TMovement,
 		TLoadout,
+		TSettings,
 	)>
 where
 	TLoading: ThreadSafe + HandlesCustomFolderAssets,
@@ -114,13 +127,15 @@ where
 		+ HandlesPhysicsConfig
 		+ HandlesRaycast
 		+ HandlesPhysicalSkillAgent
-		+ HandlesInteractiveDetection,
+		+ HandlesInteractiveDetection
+		+ HandlesLife,
 	TGraphics: ThreadSafe + SystemSetDefinition + HandlesGraphics + HandlesCameras,
 	TInteractive: ThreadSafe + SystemSetDefinition + HandlesInteractive,
 	TAnimations: ThreadSafe + HandlesAnimations,
 	TMaps: ThreadSafe + HandlesMapGeneration,
 	TMovement: ThreadSafe + HandlesMovement + HandlesOrientation,
 	TLoadout: ThreadSafe + HandlesLoadout,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	fn build(&self, app: &mut App) {
 		// # Load Agent
@@ -155,6 +170,23 @@ where
 		TSaveGame::register_savable_component::<Agent>(app);
 		TSaveGame::register_savable_component::<Enemy>(app);
 		TSaveGame::register_savable_component::<EnemyAttackPhase>(app);
+		TSaveGame::on_before_save(
+			app,
+			Player::write_health_metadata::<
+				TPhysics::TAffectedComponent,
+				TSaveGame::TSlotMetadataMut,
+			>,
+		);
+
+		// # Drops
+		app.add_observer(AgentConfig::drop_items::<TInteractive::TPickupsMut>);
+
+		// # Progression
+		app.add_observer(AgentConfig::award_experience::<TLoadout::TLoadoutMut>);
+
+		// # Sounds
+		app.add_message::<PlaySound>()
+			.add_observer(AgentConfig::play_death_sound);
 
 		// # Prefabs
 		app.add_prefab_observer::<Agent, TGraphics::TRolesMut>();
@@ -182,17 +214,25 @@ where
 						TPhysics::TInteractions,
 						TInteractive::TInteractiveMut,
 					>,
+					Player::loot_interactives::<
+						TPhysics::TInteractions,
+						TInteractive::TInteractiveMut,
+						TLoadout::TLoadoutMut,
+					>,
 					Player::use_skills::<
 						TInput::TInput,
 						TPhysics::TAgentMut,
 						TLoadout::TLoadoutActivityMut,
+						TSettings::TSettings,
 					>,
 				)
 					.chain(),
 				(
 					Enemy::attack_decision::<TPhysics::TRaycastMut>,
 					Enemy::chase_decision,
+					EnemyBehavior::decide::<TPhysics::TAffectedComponent, TPhysics::TRaycastMut>,
 					Enemy::chase_player::<TMovement::TMovementMut>,
+					EnemyBehavior::follow::<TMovement::TMovementMut>,
 					Enemy::animate_movement::<TMovement::TMovement, TAnimations::TAnimationsMut>,
 					ring_rotation,
 					Enemy::open_doors::<TPhysics::TInteractions, TInteractive::TInteractiveMut>,
@@ -206,6 +246,7 @@ where
 					TLoadout::TLoadoutActivity,
 					TAnimations::TAnimationsMut,
 				>,
+				Update::delta.pipe(AgentConfig::play_footsteps::<TMovement::TMovement>),
 			)
 				.chain()
 				.run_if(in_state(GameState::Play))
@@ -0,0 +1,3 @@
+pub(crate) mod award_experience;
+pub(crate) mod drop_items;
+pub(crate) mod play_death_sound;
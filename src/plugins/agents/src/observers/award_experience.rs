@@ -0,0 +1,143 @@
+use crate::{
+	assets::agent_meta::AgentMeta,
+	components::{agent_config::AgentConfig, player::Player},
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl AgentConfig {
+	pub(crate) fn award_experience<TLoadoutMut>(
+		on_died: On<Died>,
+		agents: Query<&Self, Without<Player>>,
+		players: Query<Entity, With<Player>>,
+		configs: Res<Assets<AgentMeta>>,
+		mut loadout: StaticSystemParam<TLoadoutMut>,
+	) where
+		TLoadoutMut: for<'c> TryGetContextMut<Progression, TContext<'c>: GainExperience>,
+	{
+		let Ok(AgentConfig { config_handle }) = agents.get(on_died.entity) else {
+			return;
+		};
+		let Some(AgentMeta { experience, .. }) = configs.get(config_handle) else {
+			return;
+		};
+
+		for entity in &players {
+			let key = Progression { entity };
+			let Some(mut ctx) = TLoadoutMut::try_get_context_mut(&mut loadout, key) else {
+				continue;
+			};
+			ctx.gain_experience(*experience);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{SystemParam, SystemParamItem};
+	use std::collections::HashMap;
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Experience(HashMap<Entity, u32>);
+
+	#[derive(SystemParam)]
+	struct _LoadoutMut<'w> {
+		experience: ResMut<'w, _Experience>,
+	}
+
+	impl TryGetContextMut<Progression> for _LoadoutMut<'static> {
+		type TContext<'ctx> = _Progression<'ctx>;
+
+		fn try_get_context_mut<'ctx>(
+			param: &'ctx mut SystemParamItem<Self>,
+			Progression { entity }: Progression,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(_Progression {
+				entity,
+				experience: param.experience.reborrow(),
+			})
+		}
+	}
+
+	struct _Progression<'ctx> {
+		entity: Entity,
+		experience: Mut<'ctx, _Experience>,
+	}
+
+	impl GainExperience for _Progression<'_> {
+		fn gain_experience(&mut self, amount: u32) {
+			*self.experience.0.entry(self.entity).or_default() += amount;
+		}
+	}
+
+	fn setup<const N: usize>(configs: [(&Handle<AgentMeta>, AgentMeta); N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+
+		for (id, asset) in configs {
+			_ = assets.insert(id, asset);
+		}
+
+		app.insert_resource(assets);
+		app.init_resource::<_Experience>();
+		app.add_observer(AgentConfig::award_experience::<_LoadoutMut>);
+
+		app
+	}
+
+	#[test]
+	fn award_experience_to_player() {
+		let handle = new_handle();
+		let mut app = setup([(
+			&handle,
+			AgentMeta {
+				experience: 42,
+				..default()
+			},
+		)]);
+		let player = app.world_mut().spawn(Player).id();
+		let entity = app
+			.world_mut()
+			.spawn(AgentConfig {
+				config_handle: handle,
+			})
+			.id();
+
+		app.world_mut().trigger(Died { entity });
+
+		assert_eq!(
+			&_Experience(HashMap::from([(player, 42)])),
+			app.world().resource::<_Experience>(),
+		);
+	}
+
+	#[test]
+	fn no_experience_when_player_dies() {
+		let handle = new_handle();
+		let mut app = setup([(
+			&handle,
+			AgentMeta {
+				experience: 42,
+				..default()
+			},
+		)]);
+		let player = app
+			.world_mut()
+			.spawn((
+				Player,
+				AgentConfig {
+					config_handle: handle,
+				},
+			))
+			.id();
+
+		app.world_mut().trigger(Died { entity: player });
+
+		assert_eq!(
+			&_Experience(HashMap::from([])),
+			app.world().resource::<_Experience>(),
+		);
+	}
+}
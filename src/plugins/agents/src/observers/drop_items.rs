@@ -0,0 +1,152 @@
+use crate::{assets::agent_meta::AgentMeta, components::agent_config::AgentConfig};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+use std::f32::consts::TAU;
+
+impl AgentConfig {
+	pub(crate) fn drop_items<TPickups>(
+		on_died: On<Died>,
+		agents: Query<(&Self, &Transform)>,
+		configs: Res<Assets<AgentMeta>>,
+		mut pickups: StaticSystemParam<TPickups>,
+	) where
+		TPickups: for<'c> GetContextMut<Pickups, TContext<'c>: SpawnPickup>,
+	{
+		let Ok((AgentConfig { config_handle }, transform)) = agents.get(on_died.entity) else {
+			return;
+		};
+		let Some(AgentMeta { drops, .. }) = configs.get(config_handle) else {
+			return;
+		};
+
+		let mut pickups = TPickups::get_context_mut(&mut pickups, Pickups);
+		for (i, item) in drops.iter().enumerate() {
+			let offset = drop_offset(i, drops.len());
+			pickups.spawn_pickup(item.clone(), transform.translation + offset);
+		}
+	}
+}
+
+const DROP_SPREAD: f32 = 0.5;
+
+/// Spread multiple drops on a circle around the agent, so their pickups do not overlap.
+fn drop_offset(index: usize, count: usize) -> Vec3 {
+	if count <= 1 {
+		return Vec3::ZERO;
+	}
+
+	let angle = TAU * index as f32 / count as f32;
+	Vec3::new(angle.cos(), 0., angle.sin()) * DROP_SPREAD
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Pickups(Vec<(ItemName, Vec3)>);
+
+	impl SpawnPickup for _Pickups {
+		fn spawn_pickup(&mut self, item: ItemName, translation: Vec3) {
+			self.0.push((item, translation));
+		}
+	}
+
+	fn setup<const N: usize>(configs: [(&Handle<AgentMeta>, AgentMeta); N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+
+		for (id, asset) in configs {
+			_ = assets.insert(id, asset);
+		}
+
+		app.insert_resource(assets);
+		app.init_resource::<_Pickups>();
+		app.add_observer(AgentConfig::drop_items::<ResMut<_Pickups>>);
+
+		app
+	}
+
+	#[test]
+	fn spawn_pickup_for_single_drop() {
+		let handle = new_handle();
+		let mut app = setup([(
+			&handle,
+			AgentMeta {
+				drops: vec![ItemName::from("my item")],
+				..default()
+			},
+		)]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				AgentConfig {
+					config_handle: handle,
+				},
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.id();
+
+		app.world_mut().trigger(Died { entity });
+
+		assert_eq!(
+			&_Pickups(vec![(ItemName::from("my item"), Vec3::new(1., 2., 3.))]),
+			app.world().resource::<_Pickups>(),
+		);
+	}
+
+	#[test]
+	fn spread_multiple_drops_around_agent() {
+		let handle = new_handle();
+		let mut app = setup([(
+			&handle,
+			AgentMeta {
+				drops: vec![ItemName::from("a"), ItemName::from("b")],
+				..default()
+			},
+		)]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				AgentConfig {
+					config_handle: handle,
+				},
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.id();
+
+		app.world_mut().trigger(Died { entity });
+
+		let pickups = &app.world().resource::<_Pickups>().0;
+		assert_eq!(
+			vec![(ItemName::from("a"), true), (ItemName::from("b"), true),],
+			pickups
+				.iter()
+				.map(|(item, translation)| (
+					item.clone(),
+					(translation.distance(Vec3::new(1., 2., 3.)) - DROP_SPREAD).abs() < 0.0001
+				))
+				.collect::<Vec<_>>(),
+		);
+	}
+
+	#[test]
+	fn no_pickups_when_no_drops() {
+		let handle = new_handle();
+		let mut app = setup([(&handle, AgentMeta::default())]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				AgentConfig {
+					config_handle: handle,
+				},
+				Transform::default(),
+			))
+			.id();
+
+		app.world_mut().trigger(Died { entity });
+
+		assert_eq!(&_Pickups(vec![]), app.world().resource::<_Pickups>());
+	}
+}
@@ -0,0 +1,103 @@
+use crate::{assets::agent_meta::AgentMeta, components::agent_config::AgentConfig};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl AgentConfig {
+	pub(crate) fn play_death_sound(
+		on_died: On<Died>,
+		mut sounds: MessageWriter<PlaySound>,
+		agents: Query<(&Self, &Transform)>,
+		configs: Res<Assets<AgentMeta>>,
+	) {
+		let Ok((AgentConfig { config_handle }, transform)) = agents.get(on_died.entity) else {
+			return;
+		};
+		let Some(AgentMeta {
+			death_sound: Some(cue),
+			..
+		}) = configs.get(config_handle)
+		else {
+			return;
+		};
+
+		// the agent is despawned right after dying, so the sound must not be attached to it
+		sounds.write(PlaySound::sfx(
+			cue.clone(),
+			SoundEmitter::Position(transform.translation),
+		));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Sounds(Vec<PlaySound>);
+
+	impl _Sounds {
+		fn collect(mut reader: MessageReader<PlaySound>, mut sounds: ResMut<Self>) {
+			sounds.0.extend(reader.read().cloned());
+		}
+	}
+
+	fn setup(config_handle: &Handle<AgentMeta>, config: AgentMeta) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+		_ = assets.insert(config_handle, config);
+
+		app.insert_resource(assets);
+		app.add_message::<PlaySound>();
+		app.init_resource::<_Sounds>();
+		app.add_observer(AgentConfig::play_death_sound);
+		app.add_systems(Update, _Sounds::collect);
+
+		app
+	}
+
+	#[test]
+	fn play_death_sound_at_agent_position() {
+		let config_handle = new_handle();
+		let mut app = setup(
+			&config_handle,
+			AgentMeta {
+				death_sound: Some(SoundCue::from("sounds/death.ogg")),
+				..default()
+			},
+		);
+		let entity = app
+			.world_mut()
+			.spawn((
+				AgentConfig { config_handle },
+				Transform::from_xyz(1., 2., 3.),
+			))
+			.id();
+
+		app.world_mut().trigger(Died { entity });
+		app.update();
+
+		assert_eq!(
+			&_Sounds(vec![PlaySound::sfx(
+				SoundCue::from("sounds/death.ogg"),
+				SoundEmitter::Position(Vec3::new(1., 2., 3.)),
+			)]),
+			app.world().resource::<_Sounds>(),
+		);
+	}
+
+	#[test]
+	fn play_nothing_without_death_sound() {
+		let config_handle = new_handle();
+		let mut app = setup(&config_handle, AgentMeta::default());
+		let entity = app
+			.world_mut()
+			.spawn((AgentConfig { config_handle }, Transform::default()))
+			.id();
+
+		app.world_mut().trigger(Died { entity });
+		app.update();
+
+		assert_eq!(&_Sounds(vec![]), app.world().resource::<_Sounds>());
+	}
+}
@@ -1,3 +1,4 @@
 pub(crate) mod animate_idle;
 pub(crate) mod animate_skills;
 pub(crate) mod apply_config_system;
+pub(crate) mod play_footsteps;
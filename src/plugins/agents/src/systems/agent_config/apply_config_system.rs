@@ -112,6 +112,9 @@ impl ApplyAgentConfig {
 						func(&mut e);
 					}
 				};
+				if let Some(behavior) = &config.behavior {
+					e.try_insert(behavior.clone());
+				}
 				e.try_insert(ApplyAgentAnimations);
 				e.try_remove::<(Self, AgentTransformDirty)>();
 			});
@@ -164,7 +167,10 @@ mod tests {
 	use super::*;
 	use crate::{
 		assets::agent_meta::{Bones, Loadout},
-		components::agent::AgentTransformDirty,
+		components::{
+			agent::AgentTransformDirty,
+			enemy::behavior::{EnemyBehavior, MovementAction, Scored},
+		},
 	};
 	use macros::{NestedMocks, simple_mock};
 	use mockall::{automock, mock, predicate::eq};
@@ -669,6 +675,7 @@ mod tests {
 				health: Health::new(100.),
 				force_interaction: EffectTarget::Immune,
 				gravity_interaction: EffectTarget::Affected,
+				..default()
 			};
 			let mut app = setup([(
 				&config_handle,
@@ -775,6 +782,41 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn insert_enemy_behavior() {
+		let config_handle = new_handle();
+		let behavior = EnemyBehavior {
+			movement: vec![Scored {
+				action: MovementAction::Chase,
+				score: 1.,
+				considerations: vec![],
+			}],
+			..default()
+		};
+		let mut app = setup([(
+			&config_handle,
+			AgentMeta {
+				behavior: Some(behavior.clone()),
+				..default()
+			},
+		)]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				ApplyAgentConfig,
+				Transform::default(),
+				AgentConfig { config_handle },
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&behavior),
+			app.world().entity(entity).get::<EnemyBehavior>(),
+		);
+	}
+
 	#[test]
 	fn act_only_once() {
 		let config_handle = new_handle();
@@ -0,0 +1,187 @@
+use crate::{
+	assets::agent_meta::AgentMeta,
+	components::{agent_config::AgentConfig, footsteps::Footsteps},
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+use std::time::Duration;
+
+impl AgentConfig {
+	pub(crate) fn play_footsteps<TMovement>(
+		In(delta): In<Duration>,
+		mut sounds: MessageWriter<PlaySound>,
+		movement: StaticSystemParam<TMovement>,
+		mut agents: Query<(Entity, &Self, &mut Footsteps)>,
+		configs: Res<Assets<AgentMeta>>,
+	) where
+		TMovement: for<'c> TryGetContext<Movement, TContext<'c>: CurrentMovement>,
+	{
+		for (entity, AgentConfig { config_handle }, mut footsteps) in &mut agents {
+			let Some(AgentMeta {
+				footstep_sound: Some(cue),
+				..
+			}) = configs.get(config_handle)
+			else {
+				continue;
+			};
+			let is_moving = TMovement::try_get_context(&movement, Movement { entity })
+				.is_some_and(|movement| movement.view_of::<Option<MovementTarget>>().is_some());
+
+			if !is_moving {
+				if footsteps.elapsed != Duration::ZERO {
+					footsteps.elapsed = Duration::ZERO;
+				}
+				continue;
+			}
+
+			footsteps.elapsed += delta;
+			if footsteps.elapsed < Footsteps::INTERVAL {
+				continue;
+			}
+
+			footsteps.elapsed -= Footsteps::INTERVAL;
+			sounds.write(PlaySound::sfx(cue.clone(), SoundEmitter::Entity(entity)));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Component, Default)]
+	struct _Movement {
+		target: Option<MovementTarget>,
+	}
+
+	impl View<Option<MovementTarget>> for _Movement {
+		fn view(&self) -> Option<MovementTarget> {
+			self.target
+		}
+	}
+
+	impl View<SpeedToggle> for _Movement {
+		fn view(&self) -> SpeedToggle {
+			SpeedToggle::default()
+		}
+	}
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Sounds(Vec<PlaySound>);
+
+	impl _Sounds {
+		fn collect(mut reader: MessageReader<PlaySound>, mut sounds: ResMut<Self>) {
+			sounds.0.extend(reader.read().cloned());
+		}
+	}
+
+	#[derive(Resource)]
+	struct _Delta(Duration);
+
+	fn setup(config_handle: &Handle<AgentMeta>, config: AgentMeta, delta: Duration) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+		_ = assets.insert(config_handle, config);
+
+		app.insert_resource(assets);
+		app.insert_resource(_Delta(delta));
+		app.add_message::<PlaySound>();
+		app.init_resource::<_Sounds>();
+		app.add_systems(
+			Update,
+			(
+				(|delta: Res<_Delta>| delta.0)
+					.pipe(AgentConfig::play_footsteps::<Query<Ref<_Movement>>>),
+				_Sounds::collect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn config() -> AgentMeta {
+		AgentMeta {
+			footstep_sound: Some(SoundCue::from("sounds/step.ogg")),
+			..default()
+		}
+	}
+
+	fn moving() -> _Movement {
+		_Movement {
+			target: Some(MovementTarget::Dir(Dir3::NEG_Z)),
+		}
+	}
+
+	#[test]
+	fn play_footstep_after_interval() {
+		let config_handle = new_handle();
+		let mut app = setup(&config_handle, config(), Footsteps::INTERVAL);
+		let entity = app
+			.world_mut()
+			.spawn((AgentConfig { config_handle }, moving()))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			&_Sounds(vec![PlaySound::sfx(
+				SoundCue::from("sounds/step.ogg"),
+				SoundEmitter::Entity(entity),
+			)]),
+			app.world().resource::<_Sounds>(),
+		);
+	}
+
+	#[test]
+	fn play_nothing_before_interval() {
+		let config_handle = new_handle();
+		let mut app = setup(
+			&config_handle,
+			config(),
+			Footsteps::INTERVAL - Duration::from_millis(1),
+		);
+		app.world_mut()
+			.spawn((AgentConfig { config_handle }, moving()));
+
+		app.update();
+
+		assert_eq!(&_Sounds(vec![]), app.world().resource::<_Sounds>());
+	}
+
+	#[test]
+	fn play_nothing_when_not_moving() {
+		let config_handle = new_handle();
+		let mut app = setup(&config_handle, config(), Footsteps::INTERVAL);
+		app.world_mut()
+			.spawn((AgentConfig { config_handle }, _Movement::default()));
+
+		app.update();
+
+		assert_eq!(&_Sounds(vec![]), app.world().resource::<_Sounds>());
+	}
+
+	#[test]
+	fn reset_elapsed_when_not_moving() {
+		let config_handle = new_handle();
+		let mut app = setup(&config_handle, config(), Duration::from_millis(100));
+		let entity = app
+			.world_mut()
+			.spawn((
+				AgentConfig { config_handle },
+				_Movement::default(),
+				Footsteps {
+					elapsed: Duration::from_millis(300),
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Footsteps::default()),
+			app.world().entity(entity).get::<Footsteps>(),
+		);
+	}
+}
@@ -4,5 +4,7 @@ pub(crate) mod attack_decision;
 pub(crate) mod begin_attack;
 pub(crate) mod chase_decision;
 pub(crate) mod chase_player;
+pub(crate) mod decide_behavior;
+pub(crate) mod follow_behavior;
 pub(crate) mod hold_attack;
 pub(crate) mod open_doors;
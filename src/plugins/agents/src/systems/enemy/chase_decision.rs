@@ -1,5 +1,5 @@
 use crate::components::{
-	enemy::{Enemy, attacking::Attacking, chasing::Chasing},
+	enemy::{Enemy, attacking::Attacking, behavior::EnemyBehavior, chasing::Chasing},
 	player::Player,
 };
 use bevy::prelude::*;
@@ -9,7 +9,7 @@ impl Enemy {
 	pub(crate) fn chase_decision(
 		mut commands: ZyheedaCommands,
 		players: Query<(Entity, &Transform), With<Player>>,
-		enemies: Query<(Entity, &Self, &Transform, Option<&Attacking>)>,
+		enemies: Query<(Entity, &Self, &Transform, Option<&Attacking>), Without<EnemyBehavior>>,
 	) {
 		let Ok((player, player_transform)) = players.single() else {
 			return;
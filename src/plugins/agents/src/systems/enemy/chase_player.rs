@@ -1,16 +1,19 @@
-use crate::components::enemy::{Enemy, chasing::Chasing};
+use crate::components::enemy::{Enemy, behavior::EnemyBehavior, chasing::Chasing};
 use bevy::{ecs::system::StaticSystemParam, prelude::*};
 use common::prelude::*;
 
+/// Enemies with an [`EnemyBehavior`] follow their own movement decisions.
+type WithoutBehavior = (With<Enemy>, Without<EnemyBehavior>);
+
 impl Enemy {
 	pub(crate) fn chase_player<TMovement>(
 		mut movement: StaticSystemParam<TMovement>,
-		enemies: Query<(Entity, Option<&Chasing>), With<Self>>,
+		enemies: Query<(Entity, Option<&Chasing>), WithoutBehavior>,
 		transforms: Query<&Transform>,
 	) where
 		TMovement: for<'c> TryGetContextMut<
 				ConfiguredMovement,
-				TContext<'c>: StartMovement + StopMovement + View<Option<MovementTarget>>,
+				TContext<'c>: ChaseMovement + StopMovement + View<Option<MovementTarget>>,
 			>,
 	{
 		for (entity, chasing) in &enemies {
@@ -32,7 +35,7 @@ impl Enemy {
 						continue;
 					}
 
-					ctx.start(player.translation);
+					ctx.chase(player.translation);
 				}
 				_ => {}
 			}
@@ -53,12 +56,9 @@ mod tests {
 		mock: Mock_Movement,
 	}
 
-	impl StartMovement for _Movement {
-		fn start<T>(&mut self, target: T)
-		where
-			T: Into<MovementTarget> + 'static,
-		{
-			self.mock.start(target);
+	impl ChaseMovement for _Movement {
+		fn chase(&mut self, target: Vec3) {
+			self.mock.chase(target);
 		}
 	}
 
@@ -76,8 +76,8 @@ mod tests {
 
 	mock! {
 		_Movement {}
-		impl StartMovement for _Movement {
-			fn start<T>(&mut self, target: T) where T: Into<MovementTarget> + 'static;
+		impl ChaseMovement for _Movement {
+			fn chase(&mut self, target: Vec3);
 		}
 		impl StopMovement for _Movement {
 			fn stop(&mut self);
@@ -109,7 +109,7 @@ mod tests {
 			},
 			Chasing { player },
 			_Movement::new().with_mock(move |mock| {
-				mock.expect_start()
+				mock.expect_chase()
 					.once()
 					.with(eq(Vec3::new(1., 2., 3.)))
 					.return_const(());
@@ -131,8 +131,7 @@ mod tests {
 				min_target_distance: None,
 			},
 			_Movement::new().with_mock(move |mock| {
-				mock.expect_start::<Vec3>().never();
-				mock.expect_start::<Dir3>().never();
+				mock.expect_chase().never();
 				mock.expect_stop().once().return_const(());
 				mock.expect_view()
 					.return_const(Some(MovementTarget::Point(Vec3::ONE)));
@@ -155,8 +154,7 @@ mod tests {
 			},
 			Chasing { player },
 			_Movement::new().with_mock(move |mock| {
-				mock.expect_start::<Vec3>().never();
-				mock.expect_start::<Dir3>().never();
+				mock.expect_chase().never();
 				mock.expect_stop().never();
 				mock.expect_view()
 					.return_const(MovementTarget::Point(Vec3::new(1., 2., 3.)));
@@ -176,8 +174,7 @@ mod tests {
 				min_target_distance: None,
 			},
 			_Movement::new().with_mock(move |mock| {
-				mock.expect_start::<Vec3>().never();
-				mock.expect_start::<Dir3>().never();
+				mock.expect_chase().never();
 				mock.expect_stop().never();
 				mock.expect_view().return_const(None);
 			}),
@@ -0,0 +1,419 @@
+use crate::components::{
+	enemy::{
+		attack_config::EnemyAttackConfig,
+		attack_phase::EnemyAttackPhase,
+		attacking::Attacking,
+		behavior::{EnemyBehavior, EnemyMovementDecision, Situation},
+	},
+	player::Player,
+};
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+type Enemies<'a, TLife> = (
+	Entity,
+	&'a EnemyBehavior,
+	&'a Transform,
+	&'a EnemyMovementDecision,
+	&'a EnemyAttackConfig,
+	Option<&'a TLife>,
+	Option<&'a EnemyAttackPhase>,
+);
+
+impl EnemyBehavior {
+	pub(crate) fn decide<TLife, TRaycast>(
+		mut commands: ZyheedaCommands,
+		mut raycast: StaticSystemParam<TRaycast>,
+		players: Query<(Entity, &Transform), With<Player>>,
+		enemies: Query<Enemies<TLife>>,
+	) where
+		TLife: Component + View<Health>,
+		TRaycast: for<'w, 's> SystemParam<Item<'w, 's>: Raycast<SolidObjects>>,
+	{
+		let Ok((player, player_transform)) = players.single() else {
+			return;
+		};
+
+		for (entity, behavior, transform, decision, config, life, phase) in &enemies {
+			let direction = player_transform.translation - transform.translation;
+			let situation = Situation {
+				player_distance: direction.length(),
+				health: life.map(View::<Health>::view).unwrap_or(Health::new(1.)),
+				has_los: has_line_of_sight(&mut raycast, entity, transform, direction, player),
+			};
+			let movement = EnemyMovementDecision(behavior.movement(&situation));
+			let attack = behavior.attack(&situation).map(EnemyAttackConfig::from);
+
+			commands.try_apply_on(&entity, |mut e| {
+				if &movement != decision {
+					e.try_insert(movement);
+				}
+
+				match attack {
+					None => {
+						e.try_remove::<Attacking>();
+					}
+					Some(attack) if phase.is_none() && &attack != config => {
+						e.try_insert(attack);
+					}
+					Some(_) => {}
+				}
+			});
+		}
+	}
+}
+
+/// Whether the first solid object hit on the way to the player is the player.
+fn has_line_of_sight(
+	raycast: &mut impl Raycast<SolidObjects>,
+	enemy: Entity,
+	transform: &Transform,
+	direction: Vec3,
+	player: Entity,
+) -> bool {
+	let Ok(direction) = Dir3::try_from(direction) else {
+		return false;
+	};
+	let hit = raycast.raycast(SolidObjects {
+		ray: Ray3d {
+			origin: transform.translation,
+			direction,
+		},
+		exclude: vec![enemy],
+		only_hoverable: false,
+	});
+
+	hit.is_some_and(|hit| hit.entity == player)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::enemy::behavior::{AttackAction, Consideration, MovementAction, Scored};
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use std::time::Duration;
+	use testing::{IsChanged, NestedMocks, SingleThreadedApp};
+
+	#[derive(Resource, NestedMocks)]
+	struct _Raycast {
+		mock: Mock_Raycast,
+	}
+
+	impl Default for _Raycast {
+		fn default() -> Self {
+			Self::new().with_mock(|mock| {
+				mock.expect_raycast().return_const(None);
+			})
+		}
+	}
+
+	#[automock]
+	impl Raycast<SolidObjects> for _Raycast {
+		fn raycast(&mut self, args: SolidObjects) -> Option<RaycastHit> {
+			self.mock.raycast(args)
+		}
+	}
+
+	#[derive(Component)]
+	struct _Life(Health);
+
+	impl View<Health> for _Life {
+		fn view(&self) -> Health {
+			self.0
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<_Raycast>();
+		app.add_systems(
+			Update,
+			(
+				EnemyBehavior::decide::<_Life, ResMut<_Raycast>>,
+				IsChanged::<EnemyMovementDecision>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn scored<T>(
+		action: T,
+		score: f32,
+		considerations: impl Into<Vec<Consideration>>,
+	) -> Scored<T> {
+		Scored {
+			action,
+			score,
+			considerations: considerations.into(),
+		}
+	}
+
+	fn attack(slot: u8) -> AttackAction {
+		AttackAction {
+			slot: SlotKey(slot),
+			hold: DurationInSeconds::from(Duration::from_secs(1)),
+			cooldown: DurationInSeconds::from(Duration::from_secs(2)),
+		}
+	}
+
+	#[test]
+	fn flee_at_low_health() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(1., 0., 0.)));
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					movement: vec![
+						scored(MovementAction::Chase, 1., []),
+						scored(MovementAction::Flee, 2., [Consideration::HealthBelow(0.5)]),
+					],
+					..default()
+				},
+				_Life(Health {
+					current: 2.,
+					max: 10.,
+				}),
+				Transform::default(),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnemyMovementDecision(MovementAction::Flee)),
+			app.world().entity(enemy).get::<EnemyMovementDecision>()
+		);
+	}
+
+	#[test]
+	fn consider_player_distance() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(10., 0., 0.)));
+		let radius = Units::from(2.);
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					movement: vec![
+						scored(MovementAction::Chase, 1., []),
+						scored(
+							MovementAction::Patrol { radius },
+							2.,
+							[Consideration::PlayerBeyond(Units::from(8.))],
+						),
+					],
+					..default()
+				},
+				Transform::default(),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnemyMovementDecision(MovementAction::Patrol { radius })),
+			app.world().entity(enemy).get::<EnemyMovementDecision>()
+		);
+	}
+
+	#[test]
+	fn do_not_reinsert_same_decision() {
+		let mut app = setup();
+		app.world_mut().spawn((Player, Transform::default()));
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					movement: vec![scored(MovementAction::Chase, 1., [])],
+					..default()
+				},
+				Transform::default(),
+			))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world()
+				.entity(enemy)
+				.get::<IsChanged<EnemyMovementDecision>>()
+		);
+	}
+
+	#[test]
+	fn configure_chosen_attack() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(1., 0., 0.)));
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					attacks: vec![
+						scored(
+							attack(0),
+							1.,
+							[Consideration::PlayerBeyond(Units::from(3.))],
+						),
+						scored(
+							attack(1),
+							1.,
+							[Consideration::PlayerWithin(Units::from(3.))],
+						),
+					],
+					..default()
+				},
+				Transform::default(),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnemyAttackConfig::from(attack(1))),
+			app.world().entity(enemy).get::<EnemyAttackConfig>()
+		);
+	}
+
+	#[test]
+	fn do_not_change_attack_during_attack_phase() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(1., 0., 0.)));
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					attacks: vec![scored(attack(1), 1., [])],
+					..default()
+				},
+				EnemyAttackPhase::Cooldown(Duration::from_secs(1)),
+				Transform::default(),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnemyAttackConfig::default()),
+			app.world().entity(enemy).get::<EnemyAttackConfig>()
+		);
+	}
+
+	#[test]
+	fn stop_attacking_when_no_attack_applies() {
+		let mut app = setup();
+		let player = PersistentEntity::default();
+		app.world_mut()
+			.spawn((Player, player, Transform::from_xyz(1., 0., 0.)));
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					attacks: vec![scored(attack(1), 1., [Consideration::HealthBelow(0.5)])],
+					..default()
+				},
+				Attacking {
+					has_los: true,
+					player,
+				},
+				Transform::default(),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(enemy).get::<Attacking>());
+	}
+
+	#[test]
+	fn consider_line_of_sight_beyond_attack_range() {
+		let mut app = setup();
+		let player = app
+			.world_mut()
+			.spawn((Player, Transform::from_xyz(10., 0., 0.)))
+			.id();
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					movement: vec![
+						scored(MovementAction::Idle, 1., []),
+						scored(MovementAction::Chase, 2., [Consideration::LineOfSight]),
+					],
+					..default()
+				},
+				Transform::default(),
+			))
+			.id();
+		app.insert_resource(_Raycast::new().with_mock(|mock| {
+			mock.expect_raycast()
+				.once()
+				.with(eq(SolidObjects {
+					ray: Ray3d {
+						origin: Vec3::ZERO,
+						direction: Dir3::X,
+					},
+					exclude: vec![enemy],
+					only_hoverable: false,
+				}))
+				.return_const(RaycastHit {
+					entity: player,
+					time_of_impact: 10.,
+				});
+		}));
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnemyMovementDecision(MovementAction::Chase)),
+			app.world().entity(enemy).get::<EnemyMovementDecision>()
+		);
+	}
+
+	#[test]
+	fn no_line_of_sight_when_other_object_hit_first() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(10., 0., 0.)));
+		let wall = app.world_mut().spawn_empty().id();
+		let enemy = app
+			.world_mut()
+			.spawn((
+				EnemyBehavior {
+					movement: vec![
+						scored(MovementAction::Idle, 1., []),
+						scored(MovementAction::Chase, 2., [Consideration::NoLineOfSight]),
+					],
+					..default()
+				},
+				Transform::default(),
+			))
+			.id();
+		app.insert_resource(_Raycast::new().with_mock(|mock| {
+			mock.expect_raycast().return_const(RaycastHit {
+				entity: wall,
+				time_of_impact: 4.,
+			});
+		}));
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnemyMovementDecision(MovementAction::Chase)),
+			app.world().entity(enemy).get::<EnemyMovementDecision>()
+		);
+	}
+}
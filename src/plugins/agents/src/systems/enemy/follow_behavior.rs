@@ -0,0 +1,361 @@
+use crate::components::{
+	enemy::behavior::{EnemyBehavior, EnemyBehaviorState, EnemyMovementDecision, MovementAction},
+	player::Player,
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl EnemyBehavior {
+	const FLEE_DISTANCE: f32 = 4.;
+	const STRAFE_DISTANCE: f32 = 2.;
+	const ARRIVAL_TOLERANCE: f32 = 0.3;
+	const PATROL_CORNERS: [Vec3; 4] = [Vec3::X, Vec3::Z, Vec3::NEG_X, Vec3::NEG_Z];
+
+	pub(crate) fn follow<TMovement>(
+		mut movement: StaticSystemParam<TMovement>,
+		players: Query<&Transform, With<Player>>,
+		enemies: Query<
+			(
+				Entity,
+				&Transform,
+				Ref<EnemyMovementDecision>,
+				&mut EnemyBehaviorState,
+			),
+			With<Self>,
+		>,
+	) where
+		TMovement: for<'c> TryGetContextMut<
+				ConfiguredMovement,
+				TContext<'c>: StartMovement
+				                  + ChaseMovement
+				                  + StopMovement
+				                  + View<Option<MovementTarget>>,
+			>,
+	{
+		let player = players.single().ok().map(|player| player.translation);
+
+		for (entity, transform, decision, mut state) in enemies {
+			let key = ConfiguredMovement { entity };
+			let Some(mut ctx) = TMovement::try_get_context_mut(&mut movement, key) else {
+				continue;
+			};
+			let position = transform.translation;
+			let retarget = decision.is_changed() || state.arrived(position);
+
+			let target = match (decision.0, player) {
+				(MovementAction::Idle, _) => None,
+				(MovementAction::Chase, Some(player)) => Some(player),
+				(MovementAction::Flee, Some(player)) if retarget => {
+					let away = (position - player).with_y(0.).normalize_or_zero();
+					Some(position + away * Self::FLEE_DISTANCE)
+				}
+				(MovementAction::Strafe, Some(player)) if retarget => {
+					if !decision.is_changed() {
+						state.strafe_clockwise = !state.strafe_clockwise;
+					}
+					let side = match state.strafe_clockwise {
+						true => Vec3::Y,
+						false => Vec3::NEG_Y,
+					};
+					let around = (player - position)
+						.with_y(0.)
+						.cross(side)
+						.normalize_or_zero();
+					Some(position + around * Self::STRAFE_DISTANCE)
+				}
+				(MovementAction::Patrol { radius }, _) if retarget => {
+					if !decision.is_changed() {
+						state.patrol_step = state.patrol_step.wrapping_add(1);
+					}
+					let anchor = *state.patrol_anchor.get_or_insert(position);
+					let corner = Self::PATROL_CORNERS[usize::from(state.patrol_step) % 4];
+					Some(anchor + corner * *radius)
+				}
+				(MovementAction::Chase, None) => None,
+				_ => continue,
+			};
+
+			match target {
+				None => {
+					state.target = None;
+					if ctx.view().is_some() {
+						ctx.stop();
+					}
+				}
+				Some(target) if ctx.view() == Some(MovementTarget::Point(target)) => {}
+				Some(target) => {
+					state.target = Some(target);
+					match decision.0 {
+						MovementAction::Chase => ctx.chase(target),
+						_ => ctx.start(target),
+					}
+				}
+			}
+		}
+	}
+}
+
+impl EnemyBehaviorState {
+	fn arrived(&self, position: Vec3) -> bool {
+		match self.target {
+			None => true,
+			Some(target) => {
+				(target - position).with_y(0.).length() <= EnemyBehavior::ARRIVAL_TOLERANCE
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use macros::NestedMocks;
+	use mockall::{mock, predicate::eq};
+	use testing::{NestedMocks, SingleThreadedApp, assert_eq_approx};
+
+	#[derive(Component, NestedMocks)]
+	struct _Movement {
+		mock: Mock_Movement,
+	}
+
+	impl StartMovement for _Movement {
+		fn start<T>(&mut self, target: T)
+		where
+			T: Into<MovementTarget> + 'static,
+		{
+			self.mock.start(target);
+		}
+	}
+
+	impl ChaseMovement for _Movement {
+		fn chase(&mut self, target: Vec3) {
+			self.mock.chase(target);
+		}
+	}
+
+	impl StopMovement for _Movement {
+		fn stop(&mut self) {
+			self.mock.stop();
+		}
+	}
+
+	impl View<Option<MovementTarget>> for _Movement {
+		fn view(&self) -> Option<MovementTarget> {
+			self.mock.view()
+		}
+	}
+
+	mock! {
+		_Movement {}
+		impl StartMovement for _Movement {
+			fn start<T>(&mut self, target: T) where T: Into<MovementTarget> + 'static;
+		}
+		impl ChaseMovement for _Movement {
+			fn chase(&mut self, target: Vec3);
+		}
+		impl StopMovement for _Movement {
+			fn stop(&mut self);
+		}
+		impl View<Option<MovementTarget>> for _Movement {
+			fn view(&self) -> Option<MovementTarget>;
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, EnemyBehavior::follow::<Query<&mut _Movement>>);
+
+		app
+	}
+
+	fn behavior(action: MovementAction) -> (EnemyBehavior, EnemyMovementDecision) {
+		(EnemyBehavior::default(), EnemyMovementDecision(action))
+	}
+
+	#[test]
+	fn chase_player() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(1., 2., 3.)));
+		app.world_mut().spawn((
+			behavior(MovementAction::Chase),
+			Transform::default(),
+			_Movement::new().with_mock(|mock| {
+				mock.expect_view().return_const(None);
+				mock.expect_chase()
+					.once()
+					.with(eq(Vec3::new(1., 2., 3.)))
+					.return_const(());
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn flee_from_player() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(1., 0., 0.)));
+		app.world_mut().spawn((
+			behavior(MovementAction::Flee),
+			Transform::default(),
+			_Movement::new().with_mock(|mock| {
+				mock.expect_view().return_const(None);
+				mock.expect_start()
+					.once()
+					.with(eq(Vec3::new(-EnemyBehavior::FLEE_DISTANCE, 0., 0.)))
+					.return_const(());
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn strafe_around_player() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(1., 0., 0.)));
+		let enemy = app
+			.world_mut()
+			.spawn((
+				behavior(MovementAction::Strafe),
+				Transform::default(),
+				_Movement::new().with_mock(|mock| {
+					mock.expect_view().return_const(None);
+					mock.expect_start::<Vec3>().return_const(());
+				}),
+			))
+			.id();
+
+		app.update();
+
+		let target = app
+			.world()
+			.entity(enemy)
+			.get::<EnemyBehaviorState>()
+			.and_then(|state| state.target)
+			.unwrap();
+		assert_eq_approx!(
+			Vec3::new(0., 0., -EnemyBehavior::STRAFE_DISTANCE),
+			target,
+			0.001
+		);
+	}
+
+	#[test]
+	fn switch_strafe_side_on_arrival() {
+		let mut app = setup();
+		app.world_mut()
+			.spawn((Player, Transform::from_xyz(1., 0., 0.)));
+		let enemy = app
+			.world_mut()
+			.spawn((
+				behavior(MovementAction::Strafe),
+				Transform::default(),
+				_Movement::new().with_mock(|mock| {
+					mock.expect_view().return_const(None);
+					mock.expect_start::<Vec3>().return_const(());
+				}),
+			))
+			.id();
+
+		app.update();
+		let first = app
+			.world()
+			.entity(enemy)
+			.get::<EnemyBehaviorState>()
+			.unwrap()
+			.target
+			.unwrap();
+		app.world_mut()
+			.entity_mut(enemy)
+			.insert(Transform::from_translation(first));
+		app.update();
+
+		let second = app
+			.world()
+			.entity(enemy)
+			.get::<EnemyBehaviorState>()
+			.unwrap()
+			.target
+			.unwrap();
+		assert!(
+			second.z > first.z,
+			"{second} should lie beyond {first} on the z-axis"
+		);
+	}
+
+	#[test]
+	fn patrol_corners_around_anchor() {
+		let mut app = setup();
+		let radius = Units::from(3.);
+		let enemy = app
+			.world_mut()
+			.spawn((
+				behavior(MovementAction::Patrol { radius }),
+				Transform::from_xyz(1., 0., 1.),
+				_Movement::new().with_mock(|mock| {
+					mock.expect_view().return_const(None);
+					mock.expect_start()
+						.once()
+						.with(eq(Vec3::new(4., 0., 1.)))
+						.return_const(());
+					mock.expect_start()
+						.once()
+						.with(eq(Vec3::new(1., 0., 4.)))
+						.return_const(());
+				}),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(enemy)
+			.insert(Transform::from_xyz(4., 0., 1.));
+		app.update();
+	}
+
+	#[test]
+	fn do_not_retarget_before_arrival() {
+		let mut app = setup();
+		let radius = Units::from(3.);
+		let enemy = app
+			.world_mut()
+			.spawn((
+				behavior(MovementAction::Patrol { radius }),
+				Transform::from_xyz(1., 0., 1.),
+				_Movement::new().with_mock(|mock| {
+					mock.expect_view().return_const(None);
+					mock.expect_start::<Vec3>().once().return_const(());
+				}),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(enemy)
+			.insert(Transform::from_xyz(2., 0., 1.));
+		app.update();
+	}
+
+	#[test]
+	fn stop_when_idle() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			behavior(MovementAction::Idle),
+			Transform::default(),
+			_Movement::new().with_mock(|mock| {
+				mock.expect_view()
+					.return_const(Some(MovementTarget::Point(Vec3::ONE)));
+				mock.expect_start::<Vec3>().never();
+				mock.expect_stop().once().return_const(());
+			}),
+		));
+
+		app.update();
+	}
+}
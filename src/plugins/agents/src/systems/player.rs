@@ -1,6 +1,8 @@
 pub(crate) mod animate_movement;
 pub(crate) mod highlight_interactive;
+pub(crate) mod loot_interactives;
 pub(crate) mod movement;
 pub(crate) mod toggle_interactive;
 pub(crate) mod toggle_speed;
 pub(crate) mod use_skills;
+pub(crate) mod write_health_metadata;
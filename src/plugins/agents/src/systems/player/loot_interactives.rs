@@ -0,0 +1,284 @@
+use crate::components::player::Player;
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl Player {
+	pub(crate) fn loot_interactives<TPhysics, TInteractive, TLoadout>(
+		mut interactive: StaticSystemParam<TInteractive>,
+		mut loadout: StaticSystemParam<TLoadout>,
+		physics: StaticSystemParam<TPhysics>,
+		players: Query<Entity, With<Self>>,
+	) where
+		TPhysics: for<'c> GetContext<InteractionsOngoing, TContext<'c>: IterInteractions>,
+		TInteractive:
+			for<'c> TryGetContextMut<Interactive, TContext<'c>: InspectInteractive + TakeLoot>,
+		TLoadout: for<'c> TryGetContextMut<Items, TContext<'c>: AddItems>,
+	{
+		for entity in players {
+			let key = Items { entity };
+			let Some(mut items) = TLoadout::try_get_context_mut(&mut loadout, key) else {
+				continue;
+			};
+
+			let key = InteractionsOngoing { entity };
+			let interactions = TPhysics::get_context(&physics, key);
+			let mut free_slots = items.free_slots();
+
+			for entity in interactions.iter_interactions() {
+				let key = Interactive { entity };
+				let interactive = TInteractive::try_get_context_mut(&mut interactive, key);
+				let Some(mut interactive) = interactive else {
+					continue;
+				};
+
+				if !matches!(
+					interactive.view_of::<InteractiveType>(),
+					InteractiveType::Container | InteractiveType::Pickup
+				) {
+					continue;
+				}
+
+				if interactive.view_of::<InteractiveState>() != InteractiveState::Active {
+					continue;
+				}
+
+				let loot = interactive.take_loot(free_slots);
+				free_slots -= loot.len();
+				items.add_items(loot);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{iter::Copied, slice::Iter};
+	use test_case::test_case;
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource, Debug, PartialEq)]
+	struct _PlayerInteractions(Vec<Entity>);
+
+	impl IterInteractions for _PlayerInteractions {
+		type TIter<'a>
+			= Copied<Iter<'a, Entity>>
+		where
+			Self: 'a;
+
+		fn iter_interactions(&self) -> Self::TIter<'_> {
+			self.0.iter().copied()
+		}
+	}
+
+	#[derive(Component, Debug, PartialEq)]
+	struct _Interactive {
+		interactive_type: InteractiveType,
+		state: InteractiveState,
+		loot: Vec<ItemName>,
+	}
+
+	impl View<InteractiveType> for _Interactive {
+		fn view(&self) -> InteractiveType {
+			self.interactive_type
+		}
+	}
+
+	impl View<InteractiveState> for _Interactive {
+		fn view(&self) -> InteractiveState {
+			self.state
+		}
+	}
+
+	impl TakeLoot for _Interactive {
+		fn take_loot(&mut self, max: usize) -> Vec<ItemName> {
+			let count = max.min(self.loot.len());
+			self.loot.drain(..count).collect()
+		}
+	}
+
+	#[derive(Component, Debug, PartialEq)]
+	struct _Items {
+		items: Vec<ItemName>,
+		capacity: usize,
+	}
+
+	impl Default for _Items {
+		fn default() -> Self {
+			Self {
+				items: vec![],
+				capacity: 10,
+			}
+		}
+	}
+
+	impl AddItems for _Items {
+		fn free_slots(&self) -> usize {
+			self.capacity - self.items.len()
+		}
+
+		fn add_items<TItems>(&mut self, items: TItems)
+		where
+			TItems: IntoIterator<Item = ItemName>,
+		{
+			self.items.extend(items);
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(_PlayerInteractions(vec![]));
+		app.add_systems(
+			Update,
+			Player::loot_interactives::<
+				Res<_PlayerInteractions>,
+				Query<Mut<_Interactive>>,
+				Query<Mut<_Items>>,
+			>,
+		);
+
+		app
+	}
+
+	fn loot() -> Vec<ItemName> {
+		vec![ItemName::from("a"), ItemName::from("b")]
+	}
+
+	#[test_case(InteractiveType::Container; "container")]
+	#[test_case(InteractiveType::Pickup; "pickup")]
+	fn move_loot_into_items(interactive_type: InteractiveType) {
+		let mut app = setup();
+		let container = app
+			.world_mut()
+			.spawn(_Interactive {
+				interactive_type,
+				state: InteractiveState::Active,
+				loot: loot(),
+			})
+			.id();
+		let player = app.world_mut().spawn((Player, _Items::default())).id();
+		app.insert_resource(_PlayerInteractions(vec![container]));
+
+		app.update();
+
+		assert_eq!(
+			(Some(&loot()), Some(&vec![])),
+			(
+				app.world().entity(player).get::<_Items>().map(|i| &i.items),
+				app.world()
+					.entity(container)
+					.get::<_Interactive>()
+					.map(|c| &c.loot),
+			)
+		);
+	}
+
+	#[test]
+	fn leave_loot_that_does_not_fit() {
+		let mut app = setup();
+		let containers = [
+			app.world_mut()
+				.spawn(_Interactive {
+					interactive_type: InteractiveType::Container,
+					state: InteractiveState::Active,
+					loot: loot(),
+				})
+				.id(),
+			app.world_mut()
+				.spawn(_Interactive {
+					interactive_type: InteractiveType::Container,
+					state: InteractiveState::Active,
+					loot: vec![ItemName::from("c")],
+				})
+				.id(),
+		];
+		let player = app
+			.world_mut()
+			.spawn((
+				Player,
+				_Items {
+					items: vec![ItemName::from("x")],
+					capacity: 2,
+				},
+			))
+			.id();
+		app.insert_resource(_PlayerInteractions(containers.to_vec()));
+
+		app.update();
+
+		assert_eq!(
+			(
+				Some(&vec![ItemName::from("x"), ItemName::from("a")]),
+				[
+					Some(&vec![ItemName::from("b")]),
+					Some(&vec![ItemName::from("c")])
+				],
+			),
+			(
+				app.world().entity(player).get::<_Items>().map(|i| &i.items),
+				containers.map(|container| {
+					app.world()
+						.entity(container)
+						.get::<_Interactive>()
+						.map(|c| &c.loot)
+				}),
+			)
+		);
+	}
+
+	#[test_case(InteractiveType::Container, InteractiveState::Inactive; "inactive container")]
+	#[test_case(InteractiveType::Pickup, InteractiveState::Inactive; "inactive pickup")]
+	#[test_case(InteractiveType::Door, InteractiveState::Active; "active door")]
+	fn do_not_loot(interactive_type: InteractiveType, state: InteractiveState) {
+		let mut app = setup();
+		let interactive = app
+			.world_mut()
+			.spawn(_Interactive {
+				interactive_type,
+				state,
+				loot: loot(),
+			})
+			.id();
+		let player = app.world_mut().spawn((Player, _Items::default())).id();
+		app.insert_resource(_PlayerInteractions(vec![interactive]));
+
+		app.update();
+
+		assert_eq!(
+			(Some(&_Items::default()), Some(&loot())),
+			(
+				app.world().entity(player).get::<_Items>(),
+				app.world()
+					.entity(interactive)
+					.get::<_Interactive>()
+					.map(|c| &c.loot),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_loot_when_player_missing() {
+		let mut app = setup();
+		let container = app
+			.world_mut()
+			.spawn(_Interactive {
+				interactive_type: InteractiveType::Container,
+				state: InteractiveState::Active,
+				loot: loot(),
+			})
+			.id();
+		app.world_mut().spawn(_Items::default());
+		app.insert_resource(_PlayerInteractions(vec![container]));
+
+		app.update();
+
+		assert_eq!(
+			Some(&loot()),
+			app.world()
+				.entity(container)
+				.get::<_Interactive>()
+				.map(|c| &c.loot),
+		);
+	}
+}
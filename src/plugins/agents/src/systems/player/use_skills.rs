@@ -1,28 +1,43 @@
-use crate::components::player::Player;
+use crate::components::{
+	enemy::Enemy,
+	player::Player,
+	target_lock::TargetLock,
+	targeting_slot::TargetingSlot,
+};
 use bevy::{
 	ecs::system::{StaticSystemParam, SystemParam},
 	prelude::*,
 };
 use common::prelude::*;
+use std::f32::consts::FRAC_PI_4;
+
+/// Half angle of the cone in front of the player, in which enemies are targeted
+const TARGETING_CONE: f32 = FRAC_PI_4;
+/// Enemies further away are neither auto targeted nor kept locked on
+const TARGETING_RANGE: f32 = 15.;
+
+type Enemies<'w, 's> = Query<'w, 's, (&'static PersistentEntity, &'static Transform), With<Enemy>>;
 
 impl Player {
-	pub(crate) fn use_skills<TInput, TPhysics, TLoadout>(
+	/// Targets with the [`TargetingMode`] of the [`TargetingSlot`], the most recently pressed
+	/// held slot, because all held skills share one target.
+	pub(crate) fn use_skills<TInput, TPhysics, TLoadout, TSettings>(
 		mut loadout: StaticSystemParam<TLoadout>,
 		mut physics: StaticSystemParam<TPhysics>,
 		input: StaticSystemParam<TInput>,
-		players: Query<Entity, With<Self>>,
+		settings: StaticSystemParam<TSettings>,
+		mut players: Query<(Entity, &Transform, &mut TargetLock, &mut TargetingSlot), With<Self>>,
+		enemies: Enemies,
 	) where
 		TInput: for<'w, 's> SystemParam<Item<'w, 's>: GetAllInputStates>,
 		TPhysics: for<'c> TryGetContextMut<InitializedAgent, TContext<'c>: TargetMut>,
 		TLoadout: for<'c> TryGetContextMut<Skills, TContext<'c>: HeldSkillsMut>,
+		TSettings: for<'c> TryGetContext<GameSettings, TContext<'c>: GetSettings>,
 	{
 		let held = || {
 			input
 				.get_all_input_states::<HandSlot>()
-				.filter_map(|(key, state)| match state {
-					InputState::Pressed { .. } => Some(key),
-					_ => None,
-				})
+				.filter(|(_, state)| matches!(state, InputState::Pressed { .. }))
 		};
 		let get_cursor = || {
 			let target_terrain =
@@ -38,10 +53,32 @@ impl Player {
 				Cursor::Direction
 			}
 		};
+		let targeting = |slot: HandSlot| {
+			TSettings::try_get_context(&settings, GameSettings)
+				.map(|ctx| ctx.settings().targeting(slot))
+				.unwrap_or_default()
+		};
 
-		for entity in &players {
-			let skill_target = SkillTarget::Cursor(get_cursor());
-			let new_held_skills = held().map(SlotKey::from).collect();
+		for (entity, transform, mut lock, mut slot) in &mut players {
+			slot.set_if_neq(slot.next(held()));
+			let mode = slot.0.map(targeting).unwrap_or_default();
+			let target = match mode {
+				TargetingMode::Cursor => None,
+				TargetingMode::AutoTarget => {
+					nearest_enemy_in_cone(transform, &enemies).map(SkillTarget::Entity)
+				}
+				TargetingMode::LockOn => {
+					let locked = lock
+						.0
+						.filter(|locked| in_range(transform, &enemies, locked))
+						.or_else(|| nearest_enemy_in_cone(transform, &enemies));
+					lock.set_if_neq(TargetLock(locked));
+					locked.map(SkillTarget::Entity)
+				}
+				TargetingMode::OnSelf => Some(SkillTarget::Caster),
+			};
+			let skill_target = target.unwrap_or(SkillTarget::Cursor(get_cursor()));
+			let new_held_skills = held().map(|(key, _)| SlotKey::from(key)).collect();
 
 			let agent = InitializedAgent { entity };
 			if let Some(mut ctx) = TPhysics::try_get_context_mut(&mut physics, agent)
@@ -60,9 +97,36 @@ impl Player {
 	}
 }
 
+fn nearest_enemy_in_cone(player: &Transform, enemies: &Enemies) -> Option<PersistentEntity> {
+	let forward = player.forward().with_y(0.);
+
+	enemies
+		.iter()
+		.filter_map(|(enemy, transform)| {
+			let direction = (transform.translation - player.translation).with_y(0.);
+			let distance = direction.length();
+			if distance > TARGETING_RANGE {
+				return None;
+			}
+			if distance > 0. && forward.angle_between(direction) > TARGETING_CONE {
+				return None;
+			}
+			Some((enemy, distance))
+		})
+		.min_by(|(_, a), (_, b)| a.total_cmp(b))
+		.map(|(enemy, _)| *enemy)
+}
+
+fn in_range(player: &Transform, enemies: &Enemies, locked: &PersistentEntity) -> bool {
+	enemies.iter().any(|(enemy, transform)| {
+		enemy == locked && transform.translation.distance(player.translation) <= TARGETING_RANGE
+	})
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use bevy::ecs::system::SystemParamItem;
 	use mockall::automock;
 	use std::collections::{HashMap, HashSet};
 	use test_case::test_case;
@@ -133,14 +197,55 @@ mod tests {
 		}
 	}
 
+	#[derive(Resource)]
+	struct _Settings(GeneralSettings);
+
+	impl GetSettings for _Settings {
+		fn settings(&self) -> &GeneralSettings {
+			&self.0
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _SettingsParam<'w> {
+		settings: Res<'w, _Settings>,
+	}
+
+	impl TryGetContext<GameSettings> for _SettingsParam<'static> {
+		type TContext<'ctx> = &'ctx Res<'ctx, _Settings>;
+
+		fn try_get_context<'ctx>(
+			param: &'ctx SystemParamItem<Self>,
+			_: GameSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(&param.settings)
+		}
+	}
+
 	fn setup(input: _Input) -> App {
+		setup_with_targeting(input, [])
+	}
+
+	fn setup_with_targeting<const N: usize>(
+		input: _Input,
+		targeting: [(HandSlot, TargetingMode); N],
+	) -> App {
 		let mut app = App::new().single_threaded(Update);
 
 		app.insert_resource(input);
+		app.insert_resource(_Settings(GeneralSettings {
+			targeting: HashMap::from(targeting),
+			..default()
+		}));
 		app.add_systems(
 			Update,
 			(
-				Player::use_skills::<Res<_Input>, Query<&mut _Physics>, Query<&mut _Loadout>>,
+				Player::use_skills::<
+					Res<_Input>,
+					Query<&mut _Physics>,
+					Query<&mut _Loadout>,
+					_SettingsParam,
+				>,
 				IsChanged::<_Physics>::detect,
 				IsChanged::<_Loadout>::detect,
 			)
@@ -319,4 +424,295 @@ mod tests {
 			app.world().entity(entity).get::<IsChanged<_Physics>>(),
 		);
 	}
+
+	mod targeting_modes {
+		use super::*;
+
+		fn physics_target(app: &App, entity: Entity) -> Option<SkillTarget> {
+			app.world().entity(entity).get::<_Physics>()?.target
+		}
+
+		#[test]
+		fn auto_target_nearest_enemy_in_cone() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Left, InputState::pressed())]),
+				[(HandSlot::Left, TargetingMode::AutoTarget)],
+			);
+			let near = PersistentEntity::default();
+			app.world_mut().spawn((
+				Enemy::default(),
+				PersistentEntity::default(),
+				Transform::from_xyz(0., 0., -5.),
+			));
+			app.world_mut()
+				.spawn((Enemy::default(), near, Transform::from_xyz(1., 0., -3.)));
+			app.world_mut().spawn((
+				Enemy::default(),
+				PersistentEntity::default(),
+				Transform::from_xyz(0., 0., 2.),
+			));
+			let entity = app.world_mut().spawn((Player, _Physics::default())).id();
+
+			app.update();
+
+			assert_eq!(
+				Some(SkillTarget::Entity(near)),
+				physics_target(&app, entity)
+			);
+		}
+
+		#[test]
+		fn auto_target_falls_back_to_cursor() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Left, InputState::pressed())]),
+				[(HandSlot::Left, TargetingMode::AutoTarget)],
+			);
+			app.world_mut().spawn((
+				Enemy::default(),
+				PersistentEntity::default(),
+				Transform::from_xyz(0., 0., 2.),
+			));
+			app.world_mut().spawn((
+				Enemy::default(),
+				PersistentEntity::default(),
+				Transform::from_xyz(0., 0., -20.),
+			));
+			let entity = app.world_mut().spawn((Player, _Physics::default())).id();
+
+			app.update();
+
+			assert_eq!(
+				Some(SkillTarget::Cursor(Cursor::Direction)),
+				physics_target(&app, entity)
+			);
+		}
+
+		#[test]
+		fn lock_on_nearest_enemy_in_cone() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Left, InputState::pressed())]),
+				[(HandSlot::Left, TargetingMode::LockOn)],
+			);
+			let enemy = PersistentEntity::default();
+			app.world_mut()
+				.spawn((Enemy::default(), enemy, Transform::from_xyz(0., 0., -5.)));
+			let entity = app.world_mut().spawn((Player, _Physics::default())).id();
+
+			app.update();
+
+			assert_eq!(
+				(
+					Some(SkillTarget::Entity(enemy)),
+					Some(&TargetLock(Some(enemy)))
+				),
+				(
+					physics_target(&app, entity),
+					app.world().entity(entity).get::<TargetLock>(),
+				)
+			);
+		}
+
+		#[test]
+		fn keep_locked_enemy_outside_cone() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Left, InputState::pressed())]),
+				[(HandSlot::Left, TargetingMode::LockOn)],
+			);
+			let locked = PersistentEntity::default();
+			app.world_mut()
+				.spawn((Enemy::default(), locked, Transform::from_xyz(0., 0., 5.)));
+			app.world_mut().spawn((
+				Enemy::default(),
+				PersistentEntity::default(),
+				Transform::from_xyz(0., 0., -2.),
+			));
+			let entity = app
+				.world_mut()
+				.spawn((Player, TargetLock(Some(locked)), _Physics::default()))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(SkillTarget::Entity(locked)),
+				physics_target(&app, entity)
+			);
+		}
+
+		#[test]
+		fn replace_locked_enemy_out_of_range() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Left, InputState::pressed())]),
+				[(HandSlot::Left, TargetingMode::LockOn)],
+			);
+			let locked = PersistentEntity::default();
+			let other = PersistentEntity::default();
+			app.world_mut()
+				.spawn((Enemy::default(), locked, Transform::from_xyz(0., 0., -20.)));
+			app.world_mut()
+				.spawn((Enemy::default(), other, Transform::from_xyz(0., 0., -2.)));
+			let entity = app
+				.world_mut()
+				.spawn((Player, TargetLock(Some(locked)), _Physics::default()))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				(
+					Some(SkillTarget::Entity(other)),
+					Some(&TargetLock(Some(other)))
+				),
+				(
+					physics_target(&app, entity),
+					app.world().entity(entity).get::<TargetLock>(),
+				)
+			);
+		}
+
+		#[test]
+		fn release_lock_when_enemy_gone() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Left, InputState::pressed())]),
+				[(HandSlot::Left, TargetingMode::LockOn)],
+			);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Player,
+					TargetLock(Some(PersistentEntity::default())),
+					_Physics::default(),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				(
+					Some(SkillTarget::Cursor(Cursor::Direction)),
+					Some(&TargetLock(None))
+				),
+				(
+					physics_target(&app, entity),
+					app.world().entity(entity).get::<TargetLock>(),
+				)
+			);
+		}
+
+		#[test]
+		fn target_self() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Right, InputState::pressed())]),
+				[(HandSlot::Right, TargetingMode::OnSelf)],
+			);
+			let entity = app.world_mut().spawn((Player, _Physics::default())).id();
+
+			app.update();
+
+			assert_eq!(Some(SkillTarget::Caster), physics_target(&app, entity));
+		}
+
+		#[test]
+		fn use_cursor_when_slot_with_targeting_mode_not_held() {
+			let mut app = setup_with_targeting(
+				_Input::from([(HandSlot::Left, InputState::pressed())]),
+				[(HandSlot::Right, TargetingMode::OnSelf)],
+			);
+			let entity = app.world_mut().spawn((Player, _Physics::default())).id();
+
+			app.update();
+
+			assert_eq!(
+				Some(SkillTarget::Cursor(Cursor::Direction)),
+				physics_target(&app, entity)
+			);
+		}
+
+		#[test]
+		fn use_targeting_mode_of_most_recently_pressed_slot() {
+			let mut app = setup_with_targeting(
+				_Input::from([
+					(HandSlot::Left, InputState::pressed()),
+					(HandSlot::Right, InputState::just_pressed()),
+				]),
+				[
+					(HandSlot::Left, TargetingMode::Cursor),
+					(HandSlot::Right, TargetingMode::OnSelf),
+				],
+			);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Player,
+					TargetingSlot(Some(HandSlot::Left)),
+					_Physics::default(),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				(
+					Some(SkillTarget::Caster),
+					Some(&TargetingSlot(Some(HandSlot::Right)))
+				),
+				(
+					physics_target(&app, entity),
+					app.world().entity(entity).get::<TargetingSlot>(),
+				)
+			);
+		}
+
+		#[test]
+		fn keep_targeting_mode_of_slot_pressed_earlier_while_held() {
+			let mut app = setup_with_targeting(
+				_Input::from([
+					(HandSlot::Left, InputState::pressed()),
+					(HandSlot::Right, InputState::pressed()),
+				]),
+				[
+					(HandSlot::Left, TargetingMode::Cursor),
+					(HandSlot::Right, TargetingMode::OnSelf),
+				],
+			);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Player,
+					TargetingSlot(Some(HandSlot::Right)),
+					_Physics::default(),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(Some(SkillTarget::Caster), physics_target(&app, entity));
+		}
+
+		#[test]
+		fn use_targeting_mode_of_other_held_slot_when_released() {
+			let mut app = setup_with_targeting(
+				_Input::from([
+					(HandSlot::Left, InputState::just_released()),
+					(HandSlot::Right, InputState::pressed()),
+				]),
+				[
+					(HandSlot::Left, TargetingMode::Cursor),
+					(HandSlot::Right, TargetingMode::OnSelf),
+				],
+			);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Player,
+					TargetingSlot(Some(HandSlot::Left)),
+					_Physics::default(),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(Some(SkillTarget::Caster), physics_target(&app, entity));
+		}
+	}
 }
@@ -0,0 +1,89 @@
+use crate::components::player::Player;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl Player {
+	pub(crate) fn write_health_metadata<TLife, TMetadata>(
+		players: Query<&TLife, With<Self>>,
+		mut metadata: StaticSystemParam<TMetadata>,
+	) where
+		TLife: Component + View<Health>,
+		TMetadata: for<'w, 's> SystemParam<Item<'w, 's>: WriteSlotMetadata>,
+	{
+		let Ok(life) = players.single() else {
+			return;
+		};
+
+		metadata.write_player_health(life.view());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use testing::{NestedMocks, SingleThreadedApp};
+
+	#[derive(Component)]
+	struct _Life(Health);
+
+	impl View<Health> for _Life {
+		fn view(&self) -> Health {
+			self.0
+		}
+	}
+
+	#[derive(Resource, NestedMocks)]
+	struct _Metadata {
+		mock: Mock_Metadata,
+	}
+
+	#[automock]
+	impl WriteSlotMetadata for _Metadata {
+		fn write_level(&mut self, level: i8) {
+			self.mock.write_level(level);
+		}
+
+		fn write_player_health(&mut self, health: Health) {
+			self.mock.write_player_health(health);
+		}
+	}
+
+	fn setup(metadata: _Metadata) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(metadata);
+
+		app
+	}
+
+	#[test]
+	fn write_player_health() -> Result<(), RunSystemError> {
+		let mut app = setup(_Metadata::new().with_mock(|mock| {
+			mock.expect_write_player_health()
+				.times(1)
+				.with(eq(Health::new(42.)))
+				.return_const(());
+		}));
+		app.world_mut().spawn((Player, _Life(Health::new(42.))));
+
+		app.world_mut()
+			.run_system_once(Player::write_health_metadata::<_Life, ResMut<_Metadata>>)
+	}
+
+	#[test]
+	fn ignore_non_player_life() -> Result<(), RunSystemError> {
+		let mut app = setup(_Metadata::new().with_mock(|mock| {
+			mock.expect_write_player_health().never();
+		}));
+		app.world_mut().spawn(_Life(Health::new(42.)));
+
+		app.world_mut()
+			.run_system_once(Player::write_health_metadata::<_Life, ResMut<_Metadata>>)
+	}
+}
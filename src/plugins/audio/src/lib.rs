@@ -0,0 +1,68 @@
+mod resources;
+mod system_params;
+mod systems;
+
+use crate::{
+	resources::volumes::{Volumes, dto::VolumesDto},
+	system_params::audio_settings_param::{AudioSettingsParam, AudioSettingsParamMut},
+	systems::{
+		insert_player_listener::insert_player_listener,
+		play_sounds::play_sounds,
+		save_changes::SaveChanges,
+	},
+};
+use bevy::prelude::*;
+use common::{
+	prelude::*,
+	resources::asset_writer::AssetWriter,
+	states::game_state::LoadingEssentialAssets,
+	tools::path::Path,
+};
+use std::marker::PhantomData;
+
+pub struct AudioPlugin<TDependencies>(PhantomData<TDependencies>);
+
+impl<TLoading, TPlayers> AudioPlugin<(TLoading, TPlayers)>
+where
+	TLoading: ThreadSafe + HandlesAssetResourceLoading,
+	TPlayers: ThreadSafe + HandlesPlayer,
+{
+	pub fn from_plugins(_: &TLoading, _: &TPlayers) -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<TLoading, TPlayers> Plugin for AudioPlugin<(TLoading, TPlayers)>
+where
+	TLoading: ThreadSafe + HandlesAssetResourceLoading,
+	TPlayers: ThreadSafe + HandlesPlayer,
+{
+	fn build(&self, app: &mut App) {
+		let path = Path::from("settings/audio.volumes");
+		TLoading::register_custom_resource_loading::<Volumes, VolumesDto, LoadingEssentialAssets>(
+			app,
+			path.clone(),
+		);
+
+		app.init_resource::<AssetWriter>()
+			.add_message::<PlaySound>()
+			.add_systems(
+				Update,
+				(
+					Volumes::save_changes::<VolumesDto>(path)
+						.pipe(OnError::log)
+						.run_if(resource_exists::<Volumes>),
+					insert_player_listener::<TPlayers::TPlayer>,
+				),
+			)
+			.add_systems(
+				PostUpdate,
+				play_sounds::<AssetServer>.run_if(resource_exists::<Volumes>),
+			);
+	}
+}
+
+impl<TDependencies> HandlesAudio for AudioPlugin<TDependencies> {
+	type TAudioSettings = AudioSettingsParam<'static>;
+	type TAudioSettingsMut = AudioSettingsParamMut<'static>;
+}
@@ -0,0 +1,135 @@
+pub(crate) mod dto;
+
+use crate::resources::volumes::dto::VolumesDto;
+use bevy::prelude::*;
+use common::prelude::*;
+
+#[derive(Resource, Asset, TypePath, Debug, PartialEq, Clone, Copy)]
+pub struct Volumes {
+	pub(crate) master: f32,
+	pub(crate) sfx: f32,
+	pub(crate) music: f32,
+	pub(crate) ui: f32,
+}
+
+impl Volumes {
+	pub(crate) fn get(&self, category: AudioCategory) -> f32 {
+		match category {
+			AudioCategory::Master => self.master,
+			AudioCategory::Sfx => self.sfx,
+			AudioCategory::Music => self.music,
+			AudioCategory::Ui => self.ui,
+		}
+	}
+
+	pub(crate) fn set(&mut self, category: AudioCategory, volume: f32) {
+		let volume = volume.clamp(0., 1.);
+
+		match category {
+			AudioCategory::Master => self.master = volume,
+			AudioCategory::Sfx => self.sfx = volume,
+			AudioCategory::Music => self.music = volume,
+			AudioCategory::Ui => self.ui = volume,
+		}
+	}
+
+	/// The volume a sound of the given category is played with, which includes the master volume.
+	pub(crate) fn effective(&self, category: AudioCategory) -> f32 {
+		match category {
+			AudioCategory::Master => self.master,
+			category => self.master * self.get(category),
+		}
+	}
+}
+
+impl Default for Volumes {
+	fn default() -> Self {
+		Self {
+			master: 1.,
+			sfx: 1.,
+			music: 1.,
+			ui: 1.,
+		}
+	}
+}
+
+impl TryLoadFrom<VolumesDto> for Volumes {
+	type TInstantiationError = Unreachable;
+
+	fn try_load_from<TLoadAsset>(
+		VolumesDto {
+			master,
+			sfx,
+			music,
+			ui,
+		}: VolumesDto,
+		_: &mut TLoadAsset,
+	) -> Result<Self, Self::TInstantiationError>
+	where
+		TLoadAsset: LoadAsset,
+	{
+		let mut volumes = Self::default();
+
+		volumes.set(AudioCategory::Master, master);
+		volumes.set(AudioCategory::Sfx, sfx);
+		volumes.set(AudioCategory::Music, music);
+		volumes.set(AudioCategory::Ui, ui);
+
+		Ok(volumes)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use common::traits::load_asset::mock_asset_loader::MockAssetServer;
+	use test_case::test_case;
+
+	#[test_case(AudioCategory::Master, 0.5; "master")]
+	#[test_case(AudioCategory::Sfx, 0.25; "sfx")]
+	#[test_case(AudioCategory::Music, 0.2; "music")]
+	#[test_case(AudioCategory::Ui, 0.1; "ui")]
+	fn effective_volume(category: AudioCategory, expected: f32) {
+		let volumes = Volumes {
+			master: 0.5,
+			sfx: 0.5,
+			music: 0.4,
+			ui: 0.2,
+		};
+
+		assert_eq!(expected, volumes.effective(category));
+	}
+
+	#[test_case(1.5, 1.; "above one")]
+	#[test_case(-0.5, 0.; "below zero")]
+	#[test_case(0.3, 0.3; "in range")]
+	fn set_clamped(volume: f32, expected: f32) {
+		let mut volumes = Volumes::default();
+
+		volumes.set(AudioCategory::Sfx, volume);
+
+		assert_eq!(expected, volumes.get(AudioCategory::Sfx));
+	}
+
+	#[test]
+	fn load_clamped_from_dto() {
+		let dto = VolumesDto {
+			master: 2.,
+			sfx: 0.5,
+			music: -1.,
+			ui: 0.25,
+		};
+
+		let volumes = Volumes::try_load_from(dto, &mut MockAssetServer::default());
+
+		assert_eq!(
+			Ok(Volumes {
+				master: 1.,
+				sfx: 0.5,
+				music: 0.,
+				ui: 0.25,
+			}),
+			volumes,
+		);
+	}
+}
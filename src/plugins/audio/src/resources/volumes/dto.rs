@@ -0,0 +1,36 @@
+use crate::resources::volumes::Volumes;
+use bevy::reflect::TypePath;
+use common::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(TypePath, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct VolumesDto {
+	pub(crate) master: f32,
+	pub(crate) sfx: f32,
+	pub(crate) music: f32,
+	pub(crate) ui: f32,
+}
+
+impl AssetFileExtensions for VolumesDto {
+	fn asset_file_extensions() -> &'static [&'static str] {
+		&[".volumes"]
+	}
+}
+
+impl From<Volumes> for VolumesDto {
+	fn from(
+		Volumes {
+			master,
+			sfx,
+			music,
+			ui,
+		}: Volumes,
+	) -> Self {
+		Self {
+			master,
+			sfx,
+			music,
+			ui,
+		}
+	}
+}
@@ -0,0 +1 @@
+pub(crate) mod audio_settings_param;
@@ -0,0 +1,62 @@
+mod volume;
+
+use crate::resources::volumes::Volumes;
+use bevy::{
+	ecs::system::{SystemParam, SystemParamItem},
+	prelude::*,
+};
+use common::prelude::*;
+
+#[derive(SystemParam)]
+pub struct AudioSettingsParam<'w> {
+	volumes: Option<Res<'w, Volumes>>,
+}
+
+impl TryGetContext<AudioSettings> for AudioSettingsParam<'static> {
+	type TContext<'ctx> = AudioSettingsContext<'ctx>;
+
+	fn try_get_context<'ctx>(
+		param: &'ctx SystemParamItem<Self>,
+		_: AudioSettings,
+	) -> Option<Self::TContext<'ctx>> {
+		let volumes = param.volumes.as_ref()?;
+
+		Some(AudioSettingsContext {
+			volumes: Res::clone(volumes),
+		})
+	}
+}
+
+pub struct AudioSettingsContext<'ctx> {
+	volumes: Res<'ctx, Volumes>,
+}
+
+impl ContextChanged for AudioSettingsContext<'_> {
+	fn context_changed(&self) -> bool {
+		self.volumes.is_changed()
+	}
+}
+
+#[derive(SystemParam)]
+pub struct AudioSettingsParamMut<'w> {
+	volumes: Option<ResMut<'w, Volumes>>,
+}
+
+impl TryGetContextMut<AudioSettings> for AudioSettingsParamMut<'static> {
+	type TContext<'ctx> = AudioSettingsContextMut<'ctx>;
+
+	fn try_get_context_mut<'ctx>(
+		param: &'ctx mut SystemParamItem<Self>,
+		_: AudioSettings,
+	) -> Option<Self::TContext<'ctx>> {
+		let volumes = param.volumes.as_mut()?;
+
+		Some(AudioSettingsContextMut {
+			volumes: volumes.reborrow(),
+		})
+	}
+}
+
+pub struct AudioSettingsContextMut<'ctx> {
+	volumes: Mut<'ctx, Volumes>,
+}
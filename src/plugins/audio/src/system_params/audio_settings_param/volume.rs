@@ -0,0 +1,92 @@
+use crate::system_params::audio_settings_param::{AudioSettingsContext, AudioSettingsContextMut};
+use common::prelude::*;
+
+impl GetVolume for AudioSettingsContext<'_> {
+	fn volume(&self, category: AudioCategory) -> f32 {
+		self.volumes.get(category)
+	}
+}
+
+impl GetVolume for AudioSettingsContextMut<'_> {
+	fn volume(&self, category: AudioCategory) -> f32 {
+		self.volumes.get(category)
+	}
+}
+
+impl SetVolume for AudioSettingsContextMut<'_> {
+	fn set_volume(&mut self, category: AudioCategory, volume: f32) {
+		self.volumes.set(category, volume);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		resources::volumes::Volumes,
+		system_params::audio_settings_param::{AudioSettingsParam, AudioSettingsParamMut},
+	};
+	use bevy::{
+		ecs::system::{RunSystemError, RunSystemOnce},
+		prelude::*,
+	};
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		App::new().single_threaded(Update)
+	}
+
+	#[test]
+	fn get_volume() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		app.insert_resource(Volumes {
+			music: 0.5,
+			..default()
+		});
+
+		let volume = app.world_mut().run_system_once(|p: AudioSettingsParam| {
+			let ctx = AudioSettingsParam::try_get_context(&p, AudioSettings)?;
+			Some(ctx.volume(AudioCategory::Music))
+		})?;
+
+		assert_eq!(Some(0.5), volume);
+		Ok(())
+	}
+
+	#[test]
+	fn no_context_when_volumes_not_loaded() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		let is_some = app.world_mut().run_system_once(|p: AudioSettingsParam| {
+			AudioSettingsParam::try_get_context(&p, AudioSettings).is_some()
+		})?;
+
+		assert!(!is_some);
+		Ok(())
+	}
+
+	#[test]
+	fn set_volume() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		app.init_resource::<Volumes>();
+
+		app.world_mut()
+			.run_system_once(|mut p: AudioSettingsParamMut| {
+				let Some(mut ctx) =
+					AudioSettingsParamMut::try_get_context_mut(&mut p, AudioSettings)
+				else {
+					return;
+				};
+				ctx.set_volume(AudioCategory::Ui, 0.25);
+			})?;
+
+		assert_eq!(
+			&Volumes {
+				ui: 0.25,
+				..default()
+			},
+			app.world().resource::<Volumes>(),
+		);
+		Ok(())
+	}
+}
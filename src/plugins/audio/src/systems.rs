@@ -0,0 +1,3 @@
+pub(crate) mod insert_player_listener;
+pub(crate) mod play_sounds;
+pub(crate) mod save_changes;
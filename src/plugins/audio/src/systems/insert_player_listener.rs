@@ -0,0 +1,98 @@
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+pub(crate) fn insert_player_listener<TPlayer>(
+	mut commands: ZyheedaCommands,
+	player: StaticSystemParam<TPlayer>,
+	listeners: Query<(), With<SpatialListener>>,
+) where
+	TPlayer: for<'w, 's> SystemParam<Item<'w, 's>: View<PlayerEntity>>,
+{
+	let Some(player) = player.view() else {
+		return;
+	};
+	let Some(entity) = commands.get(&player) else {
+		return;
+	};
+	if listeners.contains(entity) {
+		return;
+	}
+
+	commands.try_apply_on(&entity, |mut e| {
+		e.try_insert(SpatialListener::default());
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	#[derive(SystemParam)]
+	struct _PlayerParam<'w> {
+		player: Res<'w, _Player>,
+	}
+
+	impl View<PlayerEntity> for _PlayerParam<'_> {
+		fn view(&self) -> Option<PersistentEntity> {
+			self.player.0
+		}
+	}
+
+	#[derive(Resource, Default)]
+	struct _Player(Option<PersistentEntity>);
+
+	fn setup(player: Option<PersistentEntity>) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+		app.insert_resource(_Player(player));
+		app.add_systems(Update, insert_player_listener::<_PlayerParam>);
+
+		app
+	}
+
+	#[test]
+	fn insert_listener() {
+		let player = PersistentEntity::default();
+		let mut app = setup(Some(player));
+		let entity = app.world_mut().spawn(player).id();
+
+		app.update();
+
+		assert!(app.world().entity(entity).contains::<SpatialListener>());
+	}
+
+	#[test]
+	fn do_not_insert_listener_when_not_player() {
+		let mut app = setup(Some(PersistentEntity::default()));
+		let entity = app.world_mut().spawn(PersistentEntity::default()).id();
+
+		app.update();
+
+		assert!(!app.world().entity(entity).contains::<SpatialListener>());
+	}
+
+	#[test]
+	fn do_not_override_existing_listener() {
+		let player = PersistentEntity::default();
+		let mut app = setup(Some(player));
+		let entity = app
+			.world_mut()
+			.spawn((player, SpatialListener::new(1.)))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(SpatialListener::new(1.).left_ear_offset),
+			app.world()
+				.entity(entity)
+				.get::<SpatialListener>()
+				.map(|listener| listener.left_ear_offset),
+		);
+	}
+}
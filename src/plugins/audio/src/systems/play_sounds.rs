@@ -0,0 +1,172 @@
+use crate::resources::volumes::Volumes;
+use bevy::{audio::Volume, ecs::component::Mutable, prelude::*};
+use common::{prelude::*, traits::load_asset::LoadAsset};
+
+pub(crate) fn play_sounds<TAssetServer>(
+	mut commands: ZyheedaCommands,
+	mut sounds: MessageReader<PlaySound>,
+	mut server: ResMut<TAssetServer>,
+	volumes: Res<Volumes>,
+) where
+	TAssetServer: Resource<Mutability = Mutable> + LoadAsset,
+{
+	for PlaySound {
+		cue: SoundCue(path),
+		category,
+		emitter,
+	} in sounds.read()
+	{
+		let player = AudioPlayer::<AudioSource>(server.load_asset(path));
+		let settings = PlaybackSettings::DESPAWN
+			.with_volume(Volume::Linear(volumes.effective(*category)))
+			.with_spatial(!matches!(emitter, SoundEmitter::None));
+
+		match emitter {
+			SoundEmitter::None => {
+				commands.spawn((player, settings));
+			}
+			SoundEmitter::Position(position) => {
+				commands.spawn((player, settings, Transform::from_translation(*position)));
+			}
+			SoundEmitter::Entity(entity) => {
+				commands.try_apply_on(entity, |mut e| {
+					e.with_child((player, settings, Transform::default()));
+				});
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use common::traits::load_asset::mock_asset_loader::MockAssetServer;
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Resource, Default)]
+	struct _Sounds(Vec<PlaySound>);
+
+	fn setup(server: MockAssetServer, volumes: Volumes) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_message::<PlaySound>();
+		app.insert_resource(server);
+		app.insert_resource(volumes);
+		app.init_resource::<_Sounds>();
+		app.add_systems(
+			Update,
+			(
+				|mut sounds: ResMut<_Sounds>, mut writer: MessageWriter<PlaySound>| {
+					writer.write_batch(sounds.0.drain(..));
+				},
+				play_sounds::<MockAssetServer>,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn players(app: &mut App) -> Vec<(Entity, &AudioPlayer, &PlaybackSettings)> {
+		app.world_mut()
+			.query::<(Entity, &AudioPlayer, &PlaybackSettings)>()
+			.iter(app.world())
+			.collect()
+	}
+
+	#[test]
+	fn spawn_audio_player() {
+		let handle = new_handle();
+		let server = MockAssetServer::default()
+			.path("sounds/cast.ogg")
+			.returns(handle.clone());
+		let mut app = setup(server, Volumes::default());
+		app.insert_resource(_Sounds(vec![PlaySound::sfx(
+			SoundCue::from("sounds/cast.ogg"),
+			SoundEmitter::None,
+		)]));
+
+		app.update();
+
+		assert_eq!(
+			vec![(&handle, false)],
+			players(&mut app)
+				.into_iter()
+				.map(|(_, AudioPlayer(handle), settings)| (handle, settings.spatial))
+				.collect::<Vec<_>>(),
+		);
+	}
+
+	#[test]
+	fn use_effective_volume() {
+		let mut app = setup(
+			MockAssetServer::default(),
+			Volumes {
+				master: 0.5,
+				sfx: 0.5,
+				..default()
+			},
+		);
+		app.insert_resource(_Sounds(vec![PlaySound::sfx(
+			SoundCue::from("sounds/cast.ogg"),
+			SoundEmitter::None,
+		)]));
+
+		app.update();
+
+		assert_eq!(
+			vec![Volume::Linear(0.25)],
+			players(&mut app)
+				.into_iter()
+				.map(|(.., settings)| settings.volume)
+				.collect::<Vec<_>>(),
+		);
+	}
+
+	#[test]
+	fn spawn_spatial_audio_player_at_position() {
+		let mut app = setup(MockAssetServer::default(), Volumes::default());
+		app.insert_resource(_Sounds(vec![PlaySound::sfx(
+			SoundCue::from("sounds/impact.ogg"),
+			SoundEmitter::Position(Vec3::new(1., 2., 3.)),
+		)]));
+
+		app.update();
+
+		let players = players(&mut app)
+			.into_iter()
+			.map(|(entity, _, settings)| (entity, settings.spatial))
+			.collect::<Vec<_>>();
+		let [(entity, spatial)] = players.as_slice() else {
+			panic!("expected exactly one audio player, got: {players:?}");
+		};
+		assert_eq!(
+			(true, Some(&Transform::from_xyz(1., 2., 3.))),
+			(*spatial, app.world().entity(*entity).get::<Transform>()),
+		);
+	}
+
+	#[test]
+	fn spawn_spatial_audio_player_as_child_of_entity() {
+		let mut app = setup(MockAssetServer::default(), Volumes::default());
+		let emitter = app.world_mut().spawn_empty().id();
+		app.insert_resource(_Sounds(vec![PlaySound::sfx(
+			SoundCue::from("sounds/footstep.ogg"),
+			SoundEmitter::Entity(emitter),
+		)]));
+
+		app.update();
+
+		let players = players(&mut app)
+			.into_iter()
+			.map(|(entity, _, settings)| (entity, settings.spatial))
+			.collect::<Vec<_>>();
+		let [(entity, spatial)] = players.as_slice() else {
+			panic!("expected exactly one audio player, got: {players:?}");
+		};
+		assert_eq!(
+			(true, Some(&ChildOf(emitter))),
+			(*spatial, app.world().entity(*entity).get::<ChildOf>()),
+		);
+	}
+}
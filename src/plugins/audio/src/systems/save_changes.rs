@@ -0,0 +1,155 @@
+use bevy::{ecs::component::Mutable, prelude::*};
+use common::{
+	resources::asset_writer::{AssetWriter, WriteAsset, WriteError},
+	tools::path::Path,
+};
+use serde::Serialize;
+
+impl<T> SaveChanges for T where T: Resource<Mutability = Mutable> + Clone {}
+
+pub(crate) trait SaveChanges: Resource<Mutability = Mutable> + Clone + Sized {
+	fn save_changes<TDto>(
+		path: Path,
+	) -> impl Fn(Res<Self>, Res<AssetWriter>) -> Result<(), WriteError>
+	where
+		TDto: Serialize + From<Self> + 'static,
+	{
+		save_changes::<Self, TDto, AssetWriter>(path)
+	}
+}
+
+fn save_changes<TAsset, TDto, TWriter>(
+	path: Path,
+) -> impl Fn(Res<TAsset>, Res<TWriter>) -> Result<(), TWriter::TError>
+where
+	TAsset: Resource + Clone,
+	TDto: Serialize + From<TAsset> + 'static,
+	TWriter: WriteAsset + Resource,
+{
+	move |resource, writer| {
+		if resource.is_added() || !resource.is_changed() {
+			return Ok(());
+		}
+
+		writer.write(TDto::from(resource.clone()), path.clone())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use testing::{NestedMocks, SingleThreadedApp};
+
+	#[derive(Resource, Debug, PartialEq, Serialize, Clone, Default)]
+	struct _Resource;
+
+	#[derive(Debug, PartialEq, Serialize)]
+	struct _ResourceDto(_Resource);
+
+	impl From<_Resource> for _ResourceDto {
+		fn from(resource: _Resource) -> Self {
+			Self(resource)
+		}
+	}
+
+	#[derive(Resource, NestedMocks)]
+	struct _Writer {
+		mock: Mock_Writer,
+	}
+
+	#[automock]
+	impl WriteAsset for _Writer {
+		type TError = _Error;
+
+		fn write<TAsset>(&self, asset: TAsset, path: Path) -> Result<(), _Error>
+		where
+			TAsset: Serialize + 'static,
+		{
+			self.mock.write(asset, path)
+		}
+	}
+
+	#[derive(Debug, PartialEq, Clone)]
+	pub struct _Error;
+
+	#[derive(Resource, Debug, PartialEq)]
+	struct _Result(Result<(), _Error>);
+
+	fn setup(writer: _Writer, path: Path) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			save_changes::<_Resource, _ResourceDto, _Writer>(path).pipe(
+				|In(r), mut commands: Commands| {
+					commands.insert_resource(_Result(r));
+				},
+			),
+		);
+		app.insert_resource(writer);
+		app.init_resource::<_Resource>();
+
+		app
+	}
+
+	fn update_with_change(app: &mut App) {
+		app.update();
+		app.world_mut()
+			.get_resource_mut::<_Resource>()
+			.as_deref_mut();
+		app.update();
+	}
+
+	#[test]
+	fn call_writer() {
+		let writer = _Writer::new().with_mock(|mock| {
+			mock.expect_write()
+				.times(1)
+				.with(eq(_ResourceDto(_Resource)), eq(Path::from("my/path")))
+				.return_const(Ok(()));
+		});
+		let mut app = setup(writer, Path::from("my/path"));
+
+		update_with_change(&mut app);
+	}
+
+	#[test]
+	fn return_result() {
+		let writer = _Writer::new().with_mock(|mock| {
+			mock.expect_write::<_ResourceDto>()
+				.return_const(Err(_Error));
+		});
+		let mut app = setup(writer, Path::from("my/path"));
+
+		update_with_change(&mut app);
+
+		assert_eq!(&_Result(Err(_Error)), app.world().resource::<_Result>());
+	}
+
+	#[test]
+	fn do_not_call_writer_when_added() {
+		let writer = _Writer::new().with_mock(|mock| {
+			mock.expect_write::<_ResourceDto>()
+				.never()
+				.return_const(Ok(()));
+		});
+		let mut app = setup(writer, Path::from("my/path"));
+
+		app.update();
+	}
+
+	#[test]
+	fn do_not_call_writer_when_not_changed() {
+		let writer = _Writer::new().with_mock(|mock| {
+			mock.expect_write::<_ResourceDto>()
+				.never()
+				.return_const(Ok(()));
+		});
+		let mut app = setup(writer, Path::from("my/path"));
+
+		app.update();
+		app.update();
+	}
+}
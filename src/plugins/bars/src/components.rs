@@ -1,3 +1,4 @@
 pub(crate) mod bar;
 pub(crate) mod bar_values;
+pub(crate) mod combat_text;
 pub(crate) mod ui;
@@ -0,0 +1,134 @@
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+/// Floating number shown above an agent whose life changed.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct CombatText {
+	pub(crate) target: Entity,
+	pub(crate) kind: CombatTextKind,
+	pub(crate) receiver: Receiver,
+	pub(crate) amount: f32,
+	pub(crate) anchor: Vec3,
+	pub(crate) elapsed: Duration,
+	pub(crate) ui: Option<Entity>,
+}
+
+impl CombatText {
+	pub(crate) const OFFSET: Vec3 = Vec3::new(0., 2.5, 0.);
+	pub(crate) const LIFETIME: Duration = Duration::from_millis(1500);
+	/// Changes of the same kind arriving within this window after spawning are
+	/// added to the existing number, so continuous ticks read as one value.
+	pub(crate) const BATCH_WINDOW: Duration = Duration::from_secs(1);
+	/// Screen pixels per second
+	pub(crate) const RISE_SPEED: f32 = 40.;
+
+	pub(crate) fn new(
+		target: Entity,
+		kind: CombatTextKind,
+		receiver: Receiver,
+		anchor: Vec3,
+	) -> Self {
+		Self {
+			target,
+			kind,
+			receiver,
+			amount: 0.,
+			anchor,
+			elapsed: Duration::ZERO,
+			ui: None,
+		}
+	}
+
+	pub(crate) fn batches(&self, target: Entity, kind: CombatTextKind) -> bool {
+		self.target == target && self.kind == kind && self.elapsed < Self::BATCH_WINDOW
+	}
+
+	pub(crate) fn label(&self) -> String {
+		let amount = self.amount.round();
+
+		match (self.kind, self.receiver) {
+			(CombatTextKind::Heal, _) => format!("+{amount}"),
+			(CombatTextKind::Damage(_), Receiver::Player) => format!("-{amount}"),
+			(CombatTextKind::Damage(_), Receiver::Enemy) => format!("{amount}"),
+		}
+	}
+
+	pub(crate) fn font_size(&self) -> f32 {
+		match self.receiver {
+			Receiver::Player => 26.,
+			Receiver::Enemy => 20.,
+		}
+	}
+
+	pub(crate) fn color(&self) -> Color {
+		let color = match self.kind {
+			CombatTextKind::Heal => Color::srgb(0.3, 1., 0.3),
+			CombatTextKind::Damage(DamageType::Kinetic) => Color::srgb(1., 1., 1.),
+			CombatTextKind::Damage(DamageType::Plasma) => Color::srgb(1., 0.55, 0.1),
+			CombatTextKind::Damage(DamageType::Void) => Color::srgb(0.7, 0.3, 1.),
+			CombatTextKind::Damage(DamageType::Force) => Color::srgb(0.3, 0.8, 1.),
+		};
+		let remaining = 1. - self.elapsed.as_secs_f32() / Self::LIFETIME.as_secs_f32();
+
+		color.with_alpha(remaining.clamp(0., 1.))
+	}
+
+	pub(crate) fn rise(&self) -> f32 {
+		self.elapsed.as_secs_f32() * Self::RISE_SPEED
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum CombatTextKind {
+	Damage(DamageType),
+	Heal,
+}
+
+/// Who received the life change, so that hits on the player stand out.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum Receiver {
+	Player,
+	Enemy,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	fn text(kind: CombatTextKind, receiver: Receiver, amount: f32) -> CombatText {
+		CombatText {
+			amount,
+			..CombatText::new(Entity::PLACEHOLDER, kind, receiver, Vec3::ZERO)
+		}
+	}
+
+	#[test_case(CombatTextKind::Damage(DamageType::Kinetic), Receiver::Enemy, "12"; "enemy damaged")]
+	#[test_case(CombatTextKind::Damage(DamageType::Kinetic), Receiver::Player, "-12"; "player damaged")]
+	#[test_case(CombatTextKind::Heal, Receiver::Player, "+12"; "healed")]
+	fn label(kind: CombatTextKind, receiver: Receiver, expected: &str) {
+		assert_eq!(expected, text(kind, receiver, 11.6).label());
+	}
+
+	#[test]
+	fn fade_out() {
+		let text = CombatText {
+			elapsed: CombatText::LIFETIME / 2,
+			..text(CombatTextKind::Heal, Receiver::Enemy, 1.)
+		};
+
+		assert_eq!(0.5, text.color().alpha());
+	}
+
+	#[test]
+	fn do_not_batch_after_window() {
+		let entity = Entity::PLACEHOLDER;
+		let text = CombatText {
+			elapsed: CombatText::BATCH_WINDOW,
+			..CombatText::new(entity, CombatTextKind::Heal, Receiver::Enemy, Vec3::ZERO)
+		};
+
+		assert!(!text.batches(entity, CombatTextKind::Heal));
+	}
+}
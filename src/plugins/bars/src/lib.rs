@@ -4,7 +4,7 @@ mod traits;
 
 use bevy::prelude::*;
 use common::{prelude::*, traits::ownership_relation::OwnershipRelation};
-use components::bar::Bar;
+use components::{bar::Bar, combat_text::CombatText};
 use std::marker::PhantomData;
 use systems::{bar::bar, render_bar::render_bar};
 
@@ -12,8 +12,8 @@ pub struct BarsPlugin<TDependencies>(PhantomData<TDependencies>);
 
 impl<TAgents, TPhysics, TGraphics> BarsPlugin<(TAgents, TPhysics, TGraphics)>
 where
-	TAgents: ThreadSafe + HandlesAgents,
-	TPhysics: ThreadSafe + SystemSetDefinition + HandlesLife,
+	TAgents: ThreadSafe + HandlesAgents + HandlesPlayer,
+	TPhysics: ThreadSafe + SystemSetDefinition + HandlesLife + HandlesEnergy,
 	TGraphics: ThreadSafe + HandlesCameras,
 {
 	pub fn from_plugins(_: &TAgents, _: &TPhysics, _: &TGraphics) -> Self {
@@ -23,21 +23,29 @@ where
 
 impl<TAgents, TPhysics, TGraphics> Plugin for BarsPlugin<(TAgents, TPhysics, TGraphics)>
 where
-	TAgents: ThreadSafe + HandlesAgents,
-	TPhysics: ThreadSafe + SystemSetDefinition + HandlesLife,
+	TAgents: ThreadSafe + HandlesAgents + HandlesPlayer,
+	TPhysics: ThreadSafe + SystemSetDefinition + HandlesLife + HandlesEnergy,
 	TGraphics: ThreadSafe + HandlesCameras,
 {
 	fn build(&self, app: &mut App) {
 		let update_life_bars = bar::<TPhysics::TAffectedComponent, Health, TGraphics::TCameraMut>;
 		let render_life_bars = render_bar::<Health, TGraphics::TCameraMut>;
+		let update_energy_bars = bar::<TPhysics::TEnergy, Energy, TGraphics::TCameraMut>;
+		let render_energy_bars = render_bar::<Energy, TGraphics::TCameraMut>;
 
 		app.manage_ownership::<Bar>(Update);
+		app.manage_ownership::<CombatText>(Update);
 		app.add_systems(
 			Update,
 			(
 				Bar::add_to::<TAgents::TAgent<Without<Bar>>>,
 				update_life_bars,
 				render_life_bars,
+				update_energy_bars,
+				render_energy_bars,
+				CombatText::spawn_from_health_changes::<TAgents::TPlayer>,
+				Update::delta.pipe(CombatText::advance),
+				CombatText::render::<TGraphics::TCameraMut>,
 			)
 				.chain()
 				.after_plugin(TPhysics::SYSTEMS),
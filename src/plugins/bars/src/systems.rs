@@ -1,3 +1,6 @@
 pub mod add_bar;
+pub mod advance_combat_text;
 pub mod bar;
 pub mod render_bar;
+pub mod render_combat_text;
+pub mod spawn_combat_text;
@@ -0,0 +1,77 @@
+use crate::components::combat_text::CombatText;
+use bevy::prelude::*;
+use std::time::Duration;
+
+impl CombatText {
+	pub(crate) fn advance(
+		In(delta): In<Duration>,
+		mut commands: Commands,
+		texts: Query<(Entity, &mut Self)>,
+	) {
+		for (entity, mut text) in texts {
+			text.elapsed += delta;
+
+			if text.elapsed < Self::LIFETIME {
+				continue;
+			}
+
+			commands.entity(entity).despawn();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::combat_text::{CombatTextKind, Receiver};
+	use testing::SingleThreadedApp;
+
+	fn setup(delta: Duration) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, (move || delta).pipe(CombatText::advance));
+
+		app
+	}
+
+	fn text(elapsed: Duration) -> CombatText {
+		CombatText {
+			elapsed,
+			..CombatText::new(
+				Entity::PLACEHOLDER,
+				CombatTextKind::Heal,
+				Receiver::Enemy,
+				Vec3::ZERO,
+			)
+		}
+	}
+
+	#[test]
+	fn advance_elapsed() {
+		let mut app = setup(Duration::from_millis(100));
+		let entity = app.world_mut().spawn(text(Duration::from_millis(200))).id();
+
+		app.update();
+
+		assert_eq!(
+			Some(Duration::from_millis(300)),
+			app.world()
+				.entity(entity)
+				.get::<CombatText>()
+				.map(|text| text.elapsed)
+		);
+	}
+
+	#[test]
+	fn despawn_when_lifetime_exceeded() {
+		let mut app = setup(Duration::from_millis(100));
+		let entity = app
+			.world_mut()
+			.spawn(text(CombatText::LIFETIME - Duration::from_millis(100)))
+			.id();
+
+		app.update();
+
+		assert!(app.world().get_entity(entity).is_err());
+	}
+}
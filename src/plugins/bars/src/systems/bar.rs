@@ -42,10 +42,14 @@ fn add_bar_values<TSource, TValue, TCamera>(
 	BarValues<TValue>: UIBarUpdate<TValue>,
 {
 	for (id, transform, display, mut bar) in &mut agents {
+		let value = display.view();
+		if !BarValues::<TValue>::is_displayed(&value) {
+			continue;
+		}
 		let world_position = transform.translation() + bar.offset;
 		bar.position = camera.screen_position(world_position);
 		let mut bar_values = BarValues::default();
-		bar_values.update(&display.view());
+		bar_values.update(&value);
 
 		commands.try_apply_on(&id, |mut e| {
 			e.try_insert(bar_values);
@@ -117,6 +121,7 @@ mod tests {
 	struct _Value {
 		current: u8,
 		max: u8,
+		hidden: bool,
 	}
 
 	impl UIBarUpdate<_Value> for BarValues<_Value> {
@@ -124,6 +129,10 @@ mod tests {
 			self.current = value.current as f32;
 			self.max = value.max as f32;
 		}
+
+		fn is_displayed(value: &_Value) -> bool {
+			!value.hidden
+		}
 	}
 
 	fn setup(camera: Option<_Camera>) -> App {
@@ -163,6 +172,28 @@ mod tests {
 		assert!(agent.contains::<BarValues<_Value>>());
 	}
 
+	#[test]
+	fn do_not_add_bar_values_when_not_displayed() {
+		let mut app = setup(None);
+		let agent = app
+			.world_mut()
+			.spawn((
+				GlobalTransform::default(),
+				Bar::new(Vec3::default(), 0.),
+				_Source(_Value {
+					hidden: true,
+					..default()
+				}),
+			))
+			.id();
+
+		app.update();
+
+		let agent = app.world().entity(agent);
+
+		assert!(!agent.contains::<BarValues<_Value>>());
+	}
+
 	#[test]
 	fn set_position_with_camera_transform_and_agent_position_plus_ui_bar_offset() {
 		let offset = Vec3::new(1., 2., 3.);
@@ -217,7 +248,11 @@ mod tests {
 			.spawn((
 				GlobalTransform::default(),
 				Bar::default(),
-				_Source(_Value { current: 1, max: 2 }),
+				_Source(_Value {
+					current: 1,
+					max: 2,
+					..default()
+				}),
 			))
 			.id();
 
@@ -289,7 +324,11 @@ mod tests {
 			.spawn((
 				GlobalTransform::default(),
 				Bar::default(),
-				_Source(_Value { current: 1, max: 2 }),
+				_Source(_Value {
+					current: 1,
+					max: 2,
+					..default()
+				}),
 			))
 			.id();
 
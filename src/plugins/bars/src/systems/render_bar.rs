@@ -1,6 +1,6 @@
 use crate::{
 	components::{bar::Bar, bar_values::BarValues, ui::UI},
-	traits::UIBarColors,
+	traits::{UIBarColors, UIBarRow},
 };
 use bevy::{ecs::system::StaticSystemParam, prelude::*};
 use common::prelude::*;
@@ -14,7 +14,7 @@ pub(crate) fn render_bar<T, TCamera>(
 	mut camera: StaticSystemParam<TCamera>,
 ) where
 	T: ThreadSafe,
-	BarValues<T>: UIBarColors,
+	BarValues<T>: UIBarColors + UIBarRow,
 	TCamera: for<'c> GetContextMut<CameraHandle, TContext<'c>: RenderUi>,
 {
 	let cam = &mut TCamera::get_context_mut(&mut camera, CameraHandle);
@@ -37,7 +37,7 @@ fn add_ui<T>(
 	camera: &mut impl RenderUi,
 ) where
 	T: ThreadSafe,
-	BarValues<T>: UIBarColors,
+	BarValues<T>: UIBarColors + UIBarRow,
 {
 	let scaled_dimension = BASE_DIMENSIONS * bar.scale;
 	let background = commands
@@ -48,7 +48,7 @@ fn add_ui<T>(
 				height: Val::Px(scaled_dimension.y),
 				position_type: PositionType::Absolute,
 				left: Val::Px(position.x - scaled_dimension.x / 2.),
-				top: Val::Px(position.y - scaled_dimension.y / 2.),
+				top: top::<T>(position, scaled_dimension),
 				..default()
 			},
 			BackgroundColor::from(BarValues::<T>::background_color()),
@@ -79,17 +79,28 @@ fn update_ui<T>(
 	bar: &Bar,
 	bar_values: Mut<BarValues<T>>,
 	position: Vec2,
-) {
+) where
+	BarValues<T>: UIBarRow,
+{
 	if let Ok(mut background) = styles.get_mut(ui.background) {
 		let scaled_dimension = BASE_DIMENSIONS * bar.scale;
 		background.left = Val::Px(position.x - scaled_dimension.x / 2.);
-		background.top = Val::Px(position.y - scaled_dimension.y / 2.);
+		background.top = top::<T>(position, scaled_dimension);
 	}
 	if let Ok(mut foreground) = styles.get_mut(ui.foreground) {
 		foreground.width = Val::Percent(bar_values.current / bar_values.max * 100.);
 	}
 }
 
+fn top<T>(position: Vec2, scaled_dimension: Vec2) -> Val
+where
+	BarValues<T>: UIBarRow,
+{
+	let row = f32::from(BarValues::<T>::ROW);
+
+	Val::Px(position.y - scaled_dimension.y / 2. + row * scaled_dimension.y)
+}
+
 fn noop() {}
 
 #[cfg(test)]
@@ -121,6 +132,10 @@ mod tests {
 		}
 	}
 
+	impl UIBarRow for BarValues<_Display> {
+		const ROW: u8 = 0;
+	}
+
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 
@@ -294,6 +309,44 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn set_position_in_row() {
+		struct _SecondRow;
+
+		impl UIBarColors for BarValues<_SecondRow> {
+			fn background_color() -> Color {
+				Color::BLACK
+			}
+
+			fn foreground_color() -> Color {
+				Color::WHITE
+			}
+		}
+
+		impl UIBarRow for BarValues<_SecondRow> {
+			const ROW: u8 = 1;
+		}
+
+		let mut app = setup();
+		app.add_systems(Update, render_bar::<_SecondRow, ResMut<_Camera>>);
+		let bar = Bar {
+			scale: 2.,
+			position: Some(Vec2::new(300., 400.)),
+			..default()
+		};
+		let bar_values = BarValues::<_SecondRow>::new(0., 0.);
+		app.world_mut().spawn((bar, bar_values));
+
+		app.update();
+
+		let mut backgrounds = app.world_mut().query_filtered::<&Node, Without<ChildOf>>();
+		let background = backgrounds.iter(app.world()).next().unwrap();
+		assert_eq!(
+			Val::Px(400. - BASE_DIMENSIONS.y * 2. / 2. + BASE_DIMENSIONS.y * 2.),
+			background.top
+		);
+	}
+
 	#[test]
 	fn set_background_color() {
 		let mut app = setup();
@@ -0,0 +1,161 @@
+use crate::components::combat_text::CombatText;
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl CombatText {
+	pub(crate) fn render<TCamera>(
+		mut commands: Commands,
+		texts: Query<(Entity, &mut Self)>,
+		mut uis: Query<(&mut Node, &mut Text, &mut TextColor)>,
+		mut camera: StaticSystemParam<TCamera>,
+	) where
+		TCamera: for<'c> GetContextMut<CameraHandle, TContext<'c>: ScreenPosition + RenderUi>,
+	{
+		let mut camera = TCamera::get_context_mut(&mut camera, CameraHandle);
+
+		for (entity, mut text) in texts {
+			let Some(position) = camera.screen_position(text.anchor) else {
+				continue;
+			};
+			let left = Val::Px(position.x);
+			let top = Val::Px(position.y - text.rise());
+
+			match text.ui.and_then(|ui| uis.get_mut(ui).ok()) {
+				Some((mut node, mut label, mut color)) => {
+					node.left = left;
+					node.top = top;
+					label.set_if_neq(Text::new(text.label()));
+					color.set_if_neq(TextColor(text.color()));
+				}
+				None => {
+					let ui = commands
+						.spawn((
+							UiNodeFor::<Self>::with(entity),
+							Node {
+								position_type: PositionType::Absolute,
+								left,
+								top,
+								..default()
+							},
+							Text::new(text.label()),
+							TextFont {
+								font_size: FontSize::Px(text.font_size()),
+								..default()
+							},
+							TextColor(text.color()),
+						))
+						.id();
+					camera.render_ui(ui);
+					text.ui = Some(ui);
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::components::combat_text::{CombatTextKind, Receiver};
+	use std::time::Duration;
+	use testing::{SingleThreadedApp, assert_count};
+
+	#[derive(Resource, Default)]
+	struct _Camera {
+		renders: Vec<Entity>,
+	}
+
+	impl ScreenPosition for &mut _Camera {
+		fn screen_position(&self, translation: Vec3) -> Option<Vec2> {
+			Some(translation.xy() * 10.)
+		}
+	}
+
+	impl RenderUi for &mut _Camera {
+		fn render_ui(&mut self, ui: Entity) {
+			self.renders.push(ui);
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<_Camera>();
+		app.add_systems(Update, CombatText::render::<ResMut<_Camera>>);
+
+		app
+	}
+
+	fn text(amount: f32, elapsed: Duration) -> CombatText {
+		CombatText {
+			amount,
+			elapsed,
+			..CombatText::new(
+				Entity::PLACEHOLDER,
+				CombatTextKind::Damage(DamageType::Void),
+				Receiver::Enemy,
+				Vec3::new(1., 2., 0.),
+			)
+		}
+	}
+
+	fn uis(app: &mut App) -> Vec<(Entity, &Node, &Text, &UiNodeFor<CombatText>)> {
+		app.world_mut()
+			.query::<(Entity, &Node, &Text, &UiNodeFor<CombatText>)>()
+			.iter(app.world())
+			.collect()
+	}
+
+	#[test]
+	fn spawn_ui_at_screen_position() {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(text(11., Duration::ZERO)).id();
+
+		app.update();
+
+		let [(_, node, label, owner)] = assert_count!(1, uis(&mut app).into_iter());
+		assert_eq!(
+			(
+				Val::Px(10.),
+				Val::Px(20.),
+				&Text::new("11"),
+				&UiNodeFor::with(entity)
+			),
+			(node.left, node.top, label, owner)
+		);
+	}
+
+	#[test]
+	fn render_ui_with_camera() {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(text(11., Duration::ZERO)).id();
+
+		app.update();
+
+		let ui = app.world().entity(entity).get::<CombatText>().unwrap().ui;
+		assert_eq!(
+			ui.into_iter().collect::<Vec<_>>(),
+			app.world().resource::<_Camera>().renders
+		);
+	}
+
+	#[test]
+	fn rise_and_update_label() {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(text(11., Duration::ZERO)).id();
+		app.update();
+
+		let mut combat_text = app.world_mut().entity_mut(entity);
+		let mut combat_text = combat_text.get_mut::<CombatText>().unwrap();
+		combat_text.amount = 20.;
+		combat_text.elapsed = Duration::from_secs(1);
+		app.update();
+
+		let [(_, node, label, _)] = assert_count!(1, uis(&mut app).into_iter());
+		assert_eq!(
+			(Val::Px(20. - CombatText::RISE_SPEED), &Text::new("20")),
+			(node.top, label)
+		);
+	}
+}
@@ -0,0 +1,273 @@
+use crate::components::combat_text::{CombatText, CombatTextKind, Receiver};
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl CombatText {
+	pub(crate) fn spawn_from_health_changes<TPlayer>(
+		mut commands: Commands,
+		mut damage_dealt: MessageReader<DamageDealt>,
+		mut health_restored: MessageReader<HealthRestored>,
+		mut texts: Query<&mut Self>,
+		targets: Query<(&GlobalTransform, Option<&PersistentEntity>)>,
+		player: StaticSystemParam<TPlayer>,
+	) where
+		TPlayer: for<'w, 's> SystemParam<Item<'w, 's>: View<PlayerEntity>>,
+	{
+		let player = player.view();
+		let mut new_texts = Vec::<Self>::new();
+
+		let damage = damage_dealt.read().map(
+			|DamageDealt {
+			     target,
+			     damage_type,
+			     amount,
+			     ..
+			 }| (*target, CombatTextKind::Damage(*damage_type), *amount),
+		);
+		let heals = health_restored
+			.read()
+			.map(|HealthRestored { target, amount, .. }| (*target, CombatTextKind::Heal, *amount));
+
+		for (target, kind, amount) in damage.chain(heals) {
+			if amount <= 0. {
+				continue;
+			}
+			let Ok((transform, persistent_entity)) = targets.get(target) else {
+				continue;
+			};
+
+			let existing = texts.iter_mut().find(|text| text.batches(target, kind));
+			if let Some(mut text) = existing {
+				text.amount += amount;
+				continue;
+			}
+
+			let new = new_texts.iter_mut().find(|text| text.batches(target, kind));
+			if let Some(text) = new {
+				text.amount += amount;
+				continue;
+			}
+
+			let receiver = match persistent_entity {
+				Some(entity) if Some(*entity) == player => Receiver::Player,
+				_ => Receiver::Enemy,
+			};
+			let anchor = transform.translation() + Self::OFFSET;
+			new_texts.push(Self {
+				amount,
+				..Self::new(target, kind, receiver, anchor)
+			});
+		}
+
+		for text in new_texts {
+			commands.spawn(text);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+	use testing::{SingleThreadedApp, assert_count};
+
+	#[derive(SystemParam)]
+	struct _PlayerParam<'w> {
+		player: Res<'w, _Player>,
+	}
+
+	impl View<PlayerEntity> for _PlayerParam<'_> {
+		fn view(&self) -> Option<PersistentEntity> {
+			self.player.0
+		}
+	}
+
+	#[derive(Resource, Default)]
+	struct _Player(Option<PersistentEntity>);
+
+	#[derive(Resource, Default)]
+	struct _Damage(Vec<DamageDealt>);
+
+	#[derive(Resource, Default)]
+	struct _Heals(Vec<HealthRestored>);
+
+	fn damage(target: Entity, damage_type: DamageType, amount: f32) -> DamageDealt {
+		DamageDealt {
+			target,
+			source: None,
+			damage_type,
+			amount,
+			lethal: false,
+		}
+	}
+
+	fn setup(player: Option<PersistentEntity>) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_message::<DamageDealt>();
+		app.add_message::<HealthRestored>();
+		app.insert_resource(_Player(player));
+		app.init_resource::<_Damage>();
+		app.init_resource::<_Heals>();
+		app.add_systems(
+			Update,
+			(
+				|mut damage: ResMut<_Damage>, mut writer: MessageWriter<DamageDealt>| {
+					writer.write_batch(damage.0.drain(..));
+				},
+				|mut heals: ResMut<_Heals>, mut writer: MessageWriter<HealthRestored>| {
+					writer.write_batch(heals.0.drain(..));
+				},
+				CombatText::spawn_from_health_changes::<_PlayerParam>,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn texts(app: &mut App) -> Vec<&CombatText> {
+		app.world_mut()
+			.query::<&CombatText>()
+			.iter(app.world())
+			.collect()
+	}
+
+	#[test]
+	fn spawn_damage_text_above_target() {
+		let mut app = setup(None);
+		let target = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(1., 2., 3.))
+			.id();
+		app.insert_resource(_Damage(vec![damage(target, DamageType::Plasma, 11.)]));
+
+		app.update();
+
+		let [text] = assert_count!(1, texts(&mut app).into_iter());
+		assert_eq!(
+			&CombatText {
+				amount: 11.,
+				..CombatText::new(
+					target,
+					CombatTextKind::Damage(DamageType::Plasma),
+					Receiver::Enemy,
+					Vec3::new(1., 2., 3.) + CombatText::OFFSET,
+				)
+			},
+			text
+		);
+	}
+
+	#[test]
+	fn spawn_heal_text_for_restored_health() {
+		let mut app = setup(None);
+		let target = app.world_mut().spawn(GlobalTransform::default()).id();
+		app.insert_resource(_Heals(vec![HealthRestored {
+			target,
+			source: None,
+			amount: 5.,
+		}]));
+
+		app.update();
+
+		let [text] = assert_count!(1, texts(&mut app).into_iter());
+		assert_eq!((CombatTextKind::Heal, 5.), (text.kind, text.amount));
+	}
+
+	#[test]
+	fn mark_player_as_receiver() {
+		let player = PersistentEntity::default();
+		let mut app = setup(Some(player));
+		let target = app
+			.world_mut()
+			.spawn((GlobalTransform::default(), player))
+			.id();
+		app.insert_resource(_Damage(vec![damage(target, DamageType::Kinetic, 5.)]));
+
+		app.update();
+
+		let [text] = assert_count!(1, texts(&mut app).into_iter());
+		assert_eq!(Receiver::Player, text.receiver);
+	}
+
+	#[test]
+	fn batch_damage_of_same_type_in_one_frame() {
+		let mut app = setup(None);
+		let target = app.world_mut().spawn(GlobalTransform::default()).id();
+		app.insert_resource(_Damage(vec![
+			damage(target, DamageType::Void, 1.),
+			damage(target, DamageType::Void, 2.),
+			damage(target, DamageType::Force, 4.),
+		]));
+
+		app.update();
+
+		let mut amounts = texts(&mut app)
+			.into_iter()
+			.map(|text| (text.kind, text.amount))
+			.collect::<Vec<_>>();
+		amounts.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+		assert_eq!(
+			vec![
+				(CombatTextKind::Damage(DamageType::Void), 3.),
+				(CombatTextKind::Damage(DamageType::Force), 4.),
+			],
+			amounts
+		);
+	}
+
+	#[test]
+	fn batch_damage_into_existing_text() {
+		let mut app = setup(None);
+		let target = app.world_mut().spawn(GlobalTransform::default()).id();
+		app.insert_resource(_Damage(vec![damage(target, DamageType::Void, 1.)]));
+		app.update();
+
+		app.insert_resource(_Damage(vec![damage(target, DamageType::Void, 2.)]));
+		app.update();
+
+		let [text] = assert_count!(1, texts(&mut app).into_iter());
+		assert_eq!(3., text.amount);
+	}
+
+	#[test]
+	fn do_not_batch_into_old_text() {
+		let mut app = setup(None);
+		let target = app.world_mut().spawn(GlobalTransform::default()).id();
+		app.world_mut().spawn(CombatText {
+			elapsed: Duration::from_secs(2),
+			amount: 1.,
+			..CombatText::new(
+				target,
+				CombatTextKind::Damage(DamageType::Void),
+				Receiver::Enemy,
+				Vec3::ZERO,
+			)
+		});
+		app.insert_resource(_Damage(vec![damage(target, DamageType::Void, 2.)]));
+
+		app.update();
+
+		let mut amounts = texts(&mut app)
+			.into_iter()
+			.map(|text| text.amount)
+			.collect::<Vec<_>>();
+		amounts.sort_by(f32::total_cmp);
+		assert_eq!(vec![1., 2.], amounts);
+	}
+
+	#[test]
+	fn ignore_fully_resisted_or_absorbed_damage() {
+		let mut app = setup(None);
+		let target = app.world_mut().spawn(GlobalTransform::default()).id();
+		app.insert_resource(_Damage(vec![damage(target, DamageType::Void, 0.)]));
+
+		app.update();
+
+		assert!(texts(&mut app).is_empty());
+	}
+}
@@ -1,12 +1,23 @@
+pub mod energy;
 pub mod health;
 
 use bevy::prelude::*;
 
 pub trait UIBarUpdate<T> {
 	fn update(&mut self, value: &T);
+
+	/// Whether an agent with the given value should get a bar at all.
+	fn is_displayed(_: &T) -> bool {
+		true
+	}
 }
 
 pub trait UIBarColors {
 	fn background_color() -> Color;
 	fn foreground_color() -> Color;
 }
+
+pub trait UIBarRow {
+	/// Row in which the bar is placed, so that bars of different values do not overlap.
+	const ROW: u8;
+}
@@ -0,0 +1,46 @@
+use crate::{
+	components::bar_values::BarValues,
+	traits::{UIBarColors, UIBarRow, UIBarUpdate},
+};
+use bevy::color::Color;
+use common::prelude::*;
+
+impl UIBarUpdate<Energy> for BarValues<Energy> {
+	fn update(&mut self, value: &Energy) {
+		self.current = value.current;
+		self.max = value.max;
+	}
+
+	fn is_displayed(value: &Energy) -> bool {
+		value.max > 0.
+	}
+}
+
+impl UIBarColors for BarValues<Energy> {
+	fn background_color() -> Color {
+		Color::srgb(0.5, 0.5, 0.5)
+	}
+
+	fn foreground_color() -> Color {
+		Color::srgb(0.1, 0.45, 1.)
+	}
+}
+
+impl UIBarRow for BarValues<Energy> {
+	const ROW: u8 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn display_when_energy_available() {
+		assert!(BarValues::<Energy>::is_displayed(&Energy::new(1., 0.)));
+	}
+
+	#[test]
+	fn do_not_display_without_energy() {
+		assert!(!BarValues::<Energy>::is_displayed(&Energy::default()));
+	}
+}
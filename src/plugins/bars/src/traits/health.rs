@@ -1,6 +1,6 @@
 use crate::{
 	components::bar_values::BarValues,
-	traits::{UIBarColors, UIBarUpdate},
+	traits::{UIBarColors, UIBarRow, UIBarUpdate},
 };
 use bevy::color::Color;
 use common::prelude::*;
@@ -21,3 +21,7 @@ impl UIBarColors for BarValues<Health> {
 		Color::srgb(1., 0.27, 0.)
 	}
 }
+
+impl UIBarRow for BarValues<Health> {
+	const ROW: u8 = 0;
+}
@@ -13,6 +13,11 @@ pub struct CameraArm {
 	pub sensitivity: Units,
 }
 
+impl CameraArm {
+	/// Sensitivity for a camera sensitivity setting of `1.0`
+	pub(crate) const BASE_SENSITIVITY: f32 = 0.005;
+}
+
 impl Default for CameraArm {
 	fn default() -> Self {
 		Self {
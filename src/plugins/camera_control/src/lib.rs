@@ -9,14 +9,15 @@ use std::marker::PhantomData;
 
 pub struct CameraControlPlugin<TDependencies>(PhantomData<TDependencies>);
 
-impl<TInput, TPhysics, TSavegame, TPlayers, TGraphics>
-	CameraControlPlugin<(TInput, TPhysics, TSavegame, TPlayers, TGraphics)>
+impl<TInput, TPhysics, TSavegame, TPlayers, TGraphics, TSettings>
+	CameraControlPlugin<(TInput, TPhysics, TSavegame, TPlayers, TGraphics, TSettings)>
 where
 	TInput: ThreadSafe + SystemSetDefinition + HandlesInput,
 	TPhysics: ThreadSafe + SystemSetDefinition,
 	TSavegame: ThreadSafe + HandlesSaving,
 	TPlayers: ThreadSafe + HandlesPlayer,
 	TGraphics: ThreadSafe + SystemSetDefinition + HandlesCameras,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	pub fn from_plugins(
 		_: &TInput,
@@ -24,19 +25,21 @@ where
 		_: &TSavegame,
 		_: &TPlayers,
 		_: &TGraphics,
+		_: &TSettings,
 	) -> Self {
 		Self(PhantomData)
 	}
 }
 
-impl<TInput, TPhysics, TSavegame, TPlayers, TGraphics> Plugin
-	for CameraControlPlugin<(TInput, TPhysics, TSavegame, TPlayers, TGraphics)>
+impl<TInput, TPhysics, TSavegame, TPlayers, TGraphics, TSettings> Plugin
+	for CameraControlPlugin<(TInput, TPhysics, TSavegame, TPlayers, TGraphics, TSettings)>
 where
 	TInput: ThreadSafe + SystemSetDefinition + HandlesInput,
 	TPhysics: ThreadSafe + SystemSetDefinition,
 	TSavegame: ThreadSafe + HandlesSaving,
 	TPlayers: ThreadSafe + HandlesPlayer,
 	TGraphics: ThreadSafe + SystemSetDefinition + HandlesCameras,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	fn build(&self, app: &mut App) {
 		TSavegame::register_savable_component::<CameraArm>(app);
@@ -45,6 +48,7 @@ where
 			Update,
 			(
 				CameraArm::init_for::<TPlayers::TPlayer>,
+				CameraArm::apply_sensitivity::<TSettings::TSettings>,
 				CameraArm::move_arms::<TInput::TInput>,
 				CameraArm::apply_direction::<TGraphics::TCameraMut>
 					.after_plugin(TPhysics::SYSTEMS)
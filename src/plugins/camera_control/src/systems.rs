@@ -1,3 +1,4 @@
 pub(crate) mod apply_camera_arm_direction;
+pub(crate) mod apply_camera_sensitivity;
 pub(crate) mod init_camera_arm;
 pub(crate) mod move_on_orbit;
@@ -0,0 +1,134 @@
+use crate::components::camera_arm::CameraArm;
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl CameraArm {
+	pub(crate) fn apply_sensitivity<TSettings>(
+		param: StaticSystemParam<TSettings>,
+		mut arms: Query<&mut Self>,
+	) where
+		TSettings: for<'c> TryGetContext<GameSettings, TContext<'c>: GetSettings>,
+	{
+		let Some(ctx) = TSettings::try_get_context(&param, GameSettings) else {
+			return;
+		};
+		let changed = ctx.context_changed();
+		let sensitivity = Units::from(Self::BASE_SENSITIVITY * ctx.settings().camera_sensitivity);
+
+		for mut arm in &mut arms {
+			if !changed && !arm.is_added() {
+				continue;
+			}
+
+			arm.sensitivity = sensitivity;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use bevy::ecs::system::{SystemParam, SystemParamItem};
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource)]
+	struct _Settings(GeneralSettings);
+
+	impl GetSettings for _Settings {
+		fn settings(&self) -> &GeneralSettings {
+			&self.0
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _Param<'w> {
+		settings: Res<'w, _Settings>,
+	}
+
+	impl TryGetContext<GameSettings> for _Param<'static> {
+		type TContext<'ctx> = &'ctx Res<'ctx, _Settings>;
+
+		fn try_get_context<'ctx>(
+			param: &'ctx SystemParamItem<Self>,
+			_: GameSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(&param.settings)
+		}
+	}
+
+	fn setup(settings: GeneralSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(_Settings(settings));
+		app.add_systems(Update, CameraArm::apply_sensitivity::<_Param>);
+
+		app
+	}
+
+	#[test]
+	fn apply_sensitivity_to_new_arm() {
+		let mut app = setup(GeneralSettings {
+			camera_sensitivity: 2.,
+			..default()
+		});
+		app.update();
+		let arm = app.world_mut().spawn(CameraArm::default()).id();
+
+		app.update();
+
+		assert_eq!(
+			Units::from(CameraArm::BASE_SENSITIVITY * 2.),
+			app.world()
+				.entity(arm)
+				.get::<CameraArm>()
+				.unwrap()
+				.sensitivity,
+		);
+	}
+
+	#[test]
+	fn apply_sensitivity_when_settings_changed() {
+		let mut app = setup(GeneralSettings::default());
+		let arm = app.world_mut().spawn(CameraArm::default()).id();
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<_Settings>()
+			.0
+			.camera_sensitivity = 3.;
+		app.update();
+
+		assert_eq!(
+			Units::from(CameraArm::BASE_SENSITIVITY * 3.),
+			app.world()
+				.entity(arm)
+				.get::<CameraArm>()
+				.unwrap()
+				.sensitivity,
+		);
+	}
+
+	#[test]
+	fn do_not_override_sensitivity_when_nothing_changed() {
+		let mut app = setup(GeneralSettings::default());
+		let arm = app.world_mut().spawn(CameraArm::default()).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(arm)
+			.get_mut::<CameraArm>()
+			.unwrap()
+			.sensitivity = Units::from(42.);
+		app.update();
+
+		assert_eq!(
+			Units::from(42.),
+			app.world()
+				.entity(arm)
+				.get::<CameraArm>()
+				.unwrap()
+				.sensitivity,
+		);
+	}
+}
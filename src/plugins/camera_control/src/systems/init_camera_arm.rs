@@ -34,7 +34,7 @@ impl CameraArm {
 
 		Self {
 			distance: Units::from(15.),
-			sensitivity: Units::from(0.005),
+			sensitivity: Units::from(Self::BASE_SENSITIVITY),
 			direction,
 		}
 	}
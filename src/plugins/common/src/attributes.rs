@@ -1,2 +1,6 @@
+pub mod damage_type;
 pub mod effect_target;
+pub mod energy;
+pub mod equipment_stats;
 pub mod health;
+pub mod resistances;
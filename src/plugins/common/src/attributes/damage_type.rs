@@ -0,0 +1,22 @@
+use crate::traits::handles_localization::Token;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum DamageType {
+	#[default]
+	Kinetic,
+	Plasma,
+	Void,
+	Force,
+}
+
+impl From<DamageType> for Token {
+	fn from(damage_type: DamageType) -> Self {
+		match damage_type {
+			DamageType::Kinetic => Self::from("damage-type-kinetic"),
+			DamageType::Plasma => Self::from("damage-type-plasma"),
+			DamageType::Void => Self::from("damage-type-void"),
+			DamageType::Force => Self::from("damage-type-force"),
+		}
+	}
+}
@@ -0,0 +1,27 @@
+use crate::traits::accessors::get::ViewField;
+use serde::{Deserialize, Serialize};
+
+/// Resource spent when using skills.
+///
+/// `regeneration` is the amount regained per second.
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Energy {
+	pub current: f32,
+	pub max: f32,
+	#[serde(default)]
+	pub regeneration: f32,
+}
+
+impl Energy {
+	pub fn new(value: f32, regeneration: f32) -> Self {
+		Self {
+			current: value,
+			max: value,
+			regeneration,
+		}
+	}
+}
+
+impl ViewField for Energy {
+	type TValue<'a> = Self;
+}
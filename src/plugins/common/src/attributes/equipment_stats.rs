@@ -0,0 +1,93 @@
+use crate::attributes::resistances::Resistances;
+use serde::{Deserialize, Serialize};
+
+/// Attribute changes granted by equipped items.
+///
+/// Max health and resistances are added, movement speed and damage are multiplied.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EquipmentStats {
+	pub max_health: f32,
+	pub movement_speed: f32,
+	pub damage: f32,
+	pub resistances: Resistances,
+}
+
+impl EquipmentStats {
+	pub fn stack(self, other: Self) -> Self {
+		Self {
+			max_health: self.max_health + other.max_health,
+			movement_speed: self.movement_speed * other.movement_speed,
+			damage: self.damage * other.damage,
+			resistances: self.resistances + other.resistances,
+		}
+	}
+}
+
+impl Default for EquipmentStats {
+	fn default() -> Self {
+		Self {
+			max_health: 0.,
+			movement_speed: 1.,
+			damage: 1.,
+			resistances: Resistances::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn stack() {
+		let a = EquipmentStats {
+			max_health: 10.,
+			movement_speed: 0.5,
+			damage: 2.,
+			resistances: Resistances {
+				kinetic: 0.25,
+				..Resistances::default()
+			},
+		};
+		let b = EquipmentStats {
+			max_health: 5.,
+			movement_speed: 0.5,
+			damage: 1.5,
+			resistances: Resistances {
+				kinetic: 0.25,
+				void: -0.5,
+				..Resistances::default()
+			},
+		};
+
+		assert_eq!(
+			EquipmentStats {
+				max_health: 15.,
+				movement_speed: 0.25,
+				damage: 3.,
+				resistances: Resistances {
+					kinetic: 0.5,
+					void: -0.5,
+					..Resistances::default()
+				},
+			},
+			a.stack(b)
+		);
+	}
+
+	#[test]
+	fn default_is_neutral() {
+		let stats = EquipmentStats {
+			max_health: 10.,
+			movement_speed: 0.5,
+			damage: 2.,
+			resistances: Resistances {
+				plasma: 0.25,
+				..Resistances::default()
+			},
+		};
+
+		assert_eq!(stats, stats.stack(EquipmentStats::default()));
+	}
+}
@@ -0,0 +1,82 @@
+use crate::{attributes::damage_type::DamageType, traits::accessors::get::ViewField};
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Index};
+
+/// Fraction of incoming damage negated per [`DamageType`].
+///
+/// `1.` grants immunity, negative values increase the damage taken.
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Resistances {
+	pub kinetic: f32,
+	pub plasma: f32,
+	pub void: f32,
+	pub force: f32,
+}
+
+impl Resistances {
+	pub fn apply(&self, damage: f32, damage_type: DamageType) -> f32 {
+		damage * (1. - self[damage_type].min(1.))
+	}
+}
+
+impl Index<DamageType> for Resistances {
+	type Output = f32;
+
+	fn index(&self, damage_type: DamageType) -> &Self::Output {
+		match damage_type {
+			DamageType::Kinetic => &self.kinetic,
+			DamageType::Plasma => &self.plasma,
+			DamageType::Void => &self.void,
+			DamageType::Force => &self.force,
+		}
+	}
+}
+
+impl Add for Resistances {
+	type Output = Self;
+
+	fn add(self, other: Self) -> Self::Output {
+		Self {
+			kinetic: self.kinetic + other.kinetic,
+			plasma: self.plasma + other.plasma,
+			void: self.void + other.void,
+			force: self.force + other.force,
+		}
+	}
+}
+
+impl ViewField for Resistances {
+	type TValue<'a> = Self;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	const RESISTANCES: Resistances = Resistances {
+		kinetic: 0.,
+		plasma: 0.5,
+		void: 1.,
+		force: -0.5,
+	};
+
+	#[test_case(DamageType::Kinetic, 10.; "none")]
+	#[test_case(DamageType::Plasma, 5.; "half")]
+	#[test_case(DamageType::Void, 0.; "immune")]
+	#[test_case(DamageType::Force, 15.; "weak")]
+	fn apply(damage_type: DamageType, expected: f32) {
+		assert_eq!(expected, RESISTANCES.apply(10., damage_type));
+	}
+
+	#[test]
+	fn do_not_heal_when_resistance_exceeds_one() {
+		let resistances = Resistances {
+			void: 2.,
+			..Resistances::default()
+		};
+
+		assert_eq!(0., resistances.apply(10., DamageType::Void));
+	}
+}
@@ -1,5 +1,6 @@
 pub mod asset_mesh_name;
 pub mod child_of_persistent;
+pub mod dropped_item;
 pub mod essence;
 pub mod gltf;
 pub mod insert_asset;
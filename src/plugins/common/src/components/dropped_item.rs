@@ -0,0 +1,9 @@
+use crate::traits::loadout::ItemName;
+use bevy::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+
+/// An item lying in the world, waiting to be picked up.
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "dropped item")]
+pub struct DroppedItem(pub ItemName);
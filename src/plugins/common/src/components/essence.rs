@@ -7,4 +7,5 @@ pub enum Essence {
 	#[default]
 	None,
 	Force,
+	Gravity,
 }
@@ -8,7 +8,7 @@ use std::time::Duration;
 /// - Positive infinity or overflow: `Duration::MAX`
 ///
 /// Use only where the precision loss of `f32` is acceptable.
-#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct DurationInSeconds {
 	seconds: f32,
 }
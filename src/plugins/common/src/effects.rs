@@ -1,6 +1,10 @@
+pub mod absorb_shield;
+pub mod damage_over_time;
 pub mod force;
 pub mod gravity;
 pub mod health_damage;
+pub mod slow;
+pub mod stun;
 
 use serde::{Deserialize, Serialize};
 
@@ -10,3 +14,16 @@ pub enum EffectApplies {
 	OncePerSecond,
 	Once,
 }
+
+/// How a timed status effect combines with an already active status of the same kind.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum StatusStacking {
+	/// Replace the active status and restart its duration.
+	#[default]
+	Refresh,
+	/// Add another instance, up to `max` instances. When full, the instance closest to expiring
+	/// is replaced.
+	Stack { max: u8 },
+	/// Keep the active status and drop the new one.
+	Ignore,
+}
@@ -0,0 +1,12 @@
+use super::StatusStacking;
+use crate::dto::duration_in_seconds::DurationInSeconds;
+use serde::{Deserialize, Serialize};
+
+/// Absorbs up to `amount` incoming damage before health is reduced, for the given duration.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct AbsorbShield {
+	pub amount: f32,
+	pub duration: DurationInSeconds,
+	#[serde(default)]
+	pub stacking: StatusStacking,
+}
@@ -0,0 +1,14 @@
+use super::StatusStacking;
+use crate::{attributes::damage_type::DamageType, dto::duration_in_seconds::DurationInSeconds};
+use serde::{Deserialize, Serialize};
+
+/// Reduces health continuously for the given duration.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct DamageOverTime {
+	pub damage_per_second: f32,
+	#[serde(default)]
+	pub damage_type: DamageType,
+	pub duration: DurationInSeconds,
+	#[serde(default)]
+	pub stacking: StatusStacking,
+}
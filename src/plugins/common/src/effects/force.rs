@@ -1,9 +1,60 @@
-use crate::{attributes::effect_target::EffectTarget, traits::handles_physics::PhysicalEffect};
+use crate::{
+	attributes::effect_target::EffectTarget,
+	tools::UnitsPerSecond,
+	traits::handles_physics::PhysicalEffect,
+};
 use serde::{Deserialize, Serialize};
 
+/// Pushes affected characters out of the force volume.
+///
+/// Deserializes from `null` with zero strength, which was the format before the strength was
+/// configurable.
 #[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
-pub struct Force;
+#[serde(from = "Option<ForceDto>")]
+pub struct Force {
+	pub strength: UnitsPerSecond,
+}
 
 impl PhysicalEffect for Force {
 	type TTarget = EffectTarget<Force>;
 }
+
+#[derive(Deserialize)]
+struct ForceDto {
+	strength: UnitsPerSecond,
+}
+
+impl From<Option<ForceDto>> for Force {
+	fn from(dto: Option<ForceDto>) -> Self {
+		match dto {
+			Some(ForceDto { strength }) => Self { strength },
+			None => Self::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn deserialize_strength() {
+		let force = serde_json::from_value::<Force>(json!({"strength": 4.}));
+
+		assert_eq!(
+			Force {
+				strength: UnitsPerSecond::from(4.),
+			},
+			force.unwrap(),
+		);
+	}
+
+	#[test]
+	fn deserialize_null() {
+		let force = serde_json::from_value::<Force>(json!(null));
+
+		assert_eq!(Force::default(), force.unwrap());
+	}
+}
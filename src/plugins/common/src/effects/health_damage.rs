@@ -1,17 +1,25 @@
 use super::EffectApplies;
-use crate::{attributes::health::Health, traits::handles_physics::PhysicalEffect};
+use crate::{
+	attributes::{damage_type::DamageType, health::Health},
+	traits::handles_physics::PhysicalEffect,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
-pub struct HealthDamage(pub f32, pub EffectApplies);
+pub struct HealthDamage(pub f32, pub EffectApplies, #[serde(default)] pub DamageType);
 
 impl HealthDamage {
 	pub const fn once(amount: f32) -> Self {
-		HealthDamage(amount, EffectApplies::Once)
+		HealthDamage(amount, EffectApplies::Once, DamageType::Kinetic)
 	}
 
 	pub const fn per_second(amount: f32) -> Self {
-		HealthDamage(amount, EffectApplies::OncePerSecond)
+		HealthDamage(amount, EffectApplies::OncePerSecond, DamageType::Kinetic)
+	}
+
+	pub const fn with_type(self, damage_type: DamageType) -> Self {
+		let HealthDamage(amount, applies, ..) = self;
+		HealthDamage(amount, applies, damage_type)
 	}
 }
 
@@ -0,0 +1,14 @@
+use super::StatusStacking;
+use crate::dto::duration_in_seconds::DurationInSeconds;
+use serde::{Deserialize, Serialize};
+
+/// Reduces movement speed for the given duration.
+///
+/// `speed_reduction` is the fraction of speed that is removed, `0.3` results in 70% speed.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct Slow {
+	pub speed_reduction: f32,
+	pub duration: DurationInSeconds,
+	#[serde(default)]
+	pub stacking: StatusStacking,
+}
@@ -0,0 +1,11 @@
+use super::StatusStacking;
+use crate::dto::duration_in_seconds::DurationInSeconds;
+use serde::{Deserialize, Serialize};
+
+/// Prevents queueing new skills for the given duration.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct Stun {
+	pub duration: DurationInSeconds,
+	#[serde(default)]
+	pub stacking: StatusStacking,
+}
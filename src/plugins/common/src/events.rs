@@ -0,0 +1,2 @@
+pub mod died;
+pub mod expired;
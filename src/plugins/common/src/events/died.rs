@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+/// Triggered right before an entity, whose health dropped to zero, is despawned.
+#[derive(EntityEvent, Debug, PartialEq, Clone, Copy)]
+pub struct Died {
+	pub entity: Entity,
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Triggered right before an entity, whose [`Lifetime`](crate::components::lifetime::Lifetime)
+/// ran out, is despawned.
+#[derive(EntityEvent, Debug, PartialEq, Clone, Copy)]
+pub struct Expired {
+	pub entity: Entity,
+}
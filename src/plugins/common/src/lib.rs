@@ -4,6 +4,8 @@ pub mod dto;
 pub mod effects;
 pub mod error_logger;
 pub mod errors;
+pub mod events;
+pub mod messages;
 pub mod observers;
 pub mod prelude;
 pub mod resources;
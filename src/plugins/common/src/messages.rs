@@ -0,0 +1,4 @@
+pub mod damage_dealt;
+pub mod health_restored;
+pub mod localization_changed;
+pub mod play_sound;
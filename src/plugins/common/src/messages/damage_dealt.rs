@@ -0,0 +1,15 @@
+use crate::{attributes::damage_type::DamageType, components::persistent_entity::PersistentEntity};
+use bevy::prelude::*;
+
+/// Written whenever damage was applied to an entity's health.
+#[derive(Message, Debug, PartialEq, Clone, Copy)]
+pub struct DamageDealt {
+	pub target: Entity,
+	/// The skill (or other effect root) that caused the damage, if any
+	pub source: Option<PersistentEntity>,
+	pub damage_type: DamageType,
+	/// Health lost after resistances, excluding the part absorbed by shields
+	pub amount: f32,
+	/// Whether the damage reduced health to zero or below
+	pub lethal: bool,
+}
@@ -0,0 +1,12 @@
+use crate::components::persistent_entity::PersistentEntity;
+use bevy::prelude::*;
+
+/// Written whenever negative damage restored an entity's health.
+#[derive(Message, Debug, PartialEq, Clone, Copy)]
+pub struct HealthRestored {
+	pub target: Entity,
+	/// The skill (or other effect root) that caused the healing, if any
+	pub source: Option<PersistentEntity>,
+	/// Health actually gained, capped by max health
+	pub amount: f32,
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// Written whenever the texts of the active localization changed, for instance when switching
+/// the language or when a language file finished loading.
+#[derive(Message, Debug, PartialEq, Clone, Copy)]
+pub struct LocalizationChanged;
@@ -0,0 +1,32 @@
+use crate::traits::handles_audio::{AudioCategory, SoundCue};
+use bevy::prelude::*;
+
+/// Written whenever a sound cue should be played.
+#[derive(Message, Debug, PartialEq, Clone)]
+pub struct PlaySound {
+	pub cue: SoundCue,
+	pub category: AudioCategory,
+	pub emitter: SoundEmitter,
+}
+
+impl PlaySound {
+	pub fn sfx(cue: SoundCue, emitter: SoundEmitter) -> Self {
+		Self {
+			cue,
+			category: AudioCategory::Sfx,
+			emitter,
+		}
+	}
+}
+
+/// Where a sound is played from
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum SoundEmitter {
+	/// Not spatial
+	#[default]
+	None,
+	/// Spatial, following the entity
+	Entity(Entity),
+	/// Spatial, at a fixed position
+	Position(Vec3),
+}
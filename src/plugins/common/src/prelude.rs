@@ -1,8 +1,16 @@
 pub use crate::{
 	CommonPlugin,
-	attributes::{effect_target::EffectTarget, health::Health},
+	attributes::{
+		damage_type::DamageType,
+		effect_target::EffectTarget,
+		energy::Energy,
+		equipment_stats::EquipmentStats,
+		health::Health,
+		resistances::Resistances,
+	},
 	components::{
 		child_of_persistent::ChildOfPersistent,
+		dropped_item::DroppedItem,
 		insert_asset::InsertAsset,
 		lifetime::Lifetime,
 		model::*,
@@ -10,8 +18,25 @@ pub use crate::{
 		ui_node_for::UiNodeFor,
 	},
 	dto::duration_in_seconds::DurationInSeconds,
-	effects::{EffectApplies, force::Force, gravity::Gravity, health_damage::HealthDamage},
+	effects::{
+		EffectApplies,
+		StatusStacking,
+		absorb_shield::AbsorbShield,
+		damage_over_time::DamageOverTime,
+		force::Force,
+		gravity::Gravity,
+		health_damage::HealthDamage,
+		slow::Slow,
+		stun::Stun,
+	},
 	errors::*,
+	events::{died::Died, expired::Expired},
+	messages::{
+		damage_dealt::DamageDealt,
+		health_restored::HealthRestored,
+		localization_changed::LocalizationChanged,
+		play_sound::{PlaySound, SoundEmitter},
+	},
 	resources::Shared,
 	systems::{link::to_target::LinkToTarget, log::OnError},
 	tools::{
@@ -28,7 +53,11 @@ pub use crate::{
 			save_key::SaveKey,
 			slot::{HandSlot, SlotKey},
 			targeting::TerrainTargeting,
-			user_input::UserInput,
+			user_input::{
+				InputDevice,
+				UserInput,
+				gamepad_stick::{GamepadStick, StickDirection},
+			},
 		},
 		attribute::AttributeOnSpawn,
 		bone_name::BoneName,
@@ -52,8 +81,10 @@ pub use crate::{
 		handles_agents::*,
 		handles_animations::*,
 		handles_asset_resource_loading::*,
+		handles_audio::*,
 		handles_custom_assets::*,
 		handles_enemies::*,
+		handles_equipment_stats::*,
 		handles_game_states::*,
 		handles_graphics::*,
 		handles_input::*,
@@ -64,6 +95,7 @@ pub use crate::{
 			combos::*,
 			insert_default_loadout::*,
 			items::*,
+			progression::*,
 			register_loadout_bones::*,
 			skills::*,
 			*,
@@ -76,7 +108,18 @@ pub use crate::{
 		handles_physics::{physical_bodies::*, *},
 		handles_player::*,
 		handles_saving::*,
-		handles_skill_physics::{beam::*, ground_target::*, projectile::*, shield::*, *},
+		handles_settings::*,
+		handles_skill_physics::{
+			beam::*,
+			chain::*,
+			dash::*,
+			follow_up::*,
+			ground_target::*,
+			melee_arc::*,
+			projectile::*,
+			shield::*,
+			*,
+		},
 		init_resource::*,
 		iter_descendants_conditional::*,
 		iteration::*,
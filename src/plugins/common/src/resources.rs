@@ -1,3 +1,4 @@
+pub mod asset_writer;
 pub(crate) mod persistent_entities;
 
 use bevy::prelude::*;
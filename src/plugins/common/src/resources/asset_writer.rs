@@ -1,5 +1,8 @@
+use crate::{
+	errors::{ErrorData, Level},
+	tools::path::Path,
+};
 use bevy::prelude::*;
-use common::{prelude::*, tools::path::Path};
 use serde::Serialize;
 use serde_json::{Error as JsonError, to_string_pretty};
 use std::{
@@ -9,8 +12,9 @@ use std::{
 	path::PathBuf,
 };
 
+/// Writes serializable assets as json into the asset folder, overriding existing files.
 #[derive(Resource, Debug, PartialEq)]
-pub(crate) struct AssetWriter {
+pub struct AssetWriter {
 	asset_path: PathBuf,
 }
 
@@ -29,7 +33,7 @@ impl Default for AssetWriter {
 	}
 }
 
-pub(crate) trait WriteAsset {
+pub trait WriteAsset {
 	type TError;
 
 	fn write<TAsset>(&self, asset: TAsset, path: Path) -> Result<(), Self::TError>
@@ -60,7 +64,7 @@ impl WriteAsset for AssetWriter {
 	}
 }
 
-pub(crate) enum WriteError {
+pub enum WriteError {
 	Serde(JsonError),
 	Io(IoError),
 }
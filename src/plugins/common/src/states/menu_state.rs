@@ -2,12 +2,12 @@ use super::game_state::GameState;
 use crate::{
 	tools::action_key::{ActionKey, user_input::UserInput},
 	traits::{
-		handles_input::InvalidUserInput,
+		handles_input::{DefaultGamepadInput, InvalidUserInput},
 		handles_localization::Token,
 		iteration::{FiniteIter, IterFinite},
 	},
 };
-use bevy::input::{keyboard::KeyCode, mouse::MouseButton};
+use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode, mouse::MouseButton};
 use serde::{Deserialize, Serialize};
 use zyheeda_core::prelude::*;
 
@@ -16,6 +16,7 @@ pub enum MenuState {
 	#[default]
 	Inventory,
 	ComboOverview,
+	Progression,
 	Settings,
 	Paused,
 }
@@ -37,6 +38,7 @@ impl From<MenuState> for UserInput {
 		match menu_state {
 			MenuState::Inventory => Self::from(KeyCode::KeyI),
 			MenuState::ComboOverview => Self::from(KeyCode::KeyK),
+			MenuState::Progression => Self::from(KeyCode::KeyL),
 			MenuState::Settings => Self::from(KeyCode::Escape),
 			MenuState::Paused => Self::from(KeyCode::KeyP),
 		}
@@ -48,6 +50,7 @@ impl From<MenuState> for Token {
 		match menu_state {
 			MenuState::Inventory => Token::from("menu-inventory"),
 			MenuState::ComboOverview => Token::from("menu-combos"),
+			MenuState::Progression => Token::from("menu-progression"),
 			MenuState::Settings => Token::from("menu-settings"),
 			MenuState::Paused => Token::from("menu-paused"),
 		}
@@ -84,7 +87,8 @@ impl IterFinite for MenuState {
 	fn next(current: &FiniteIter<Self>) -> Option<Self> {
 		match &current.0? {
 			MenuState::Inventory => Some(MenuState::ComboOverview),
-			MenuState::ComboOverview => Some(MenuState::Settings),
+			MenuState::ComboOverview => Some(MenuState::Progression),
+			MenuState::Progression => Some(MenuState::Settings),
 			MenuState::Settings => Some(MenuState::Paused),
 			MenuState::Paused => None,
 		}
@@ -97,6 +101,18 @@ impl InvalidUserInput for MenuState {
 	}
 }
 
+impl DefaultGamepadInput for MenuState {
+	fn default_gamepad_input(&self) -> UserInput {
+		match self {
+			MenuState::Inventory => UserInput::GamepadButton(GamepadButton::North),
+			MenuState::ComboOverview => UserInput::GamepadButton(GamepadButton::West),
+			MenuState::Progression => UserInput::GamepadButton(GamepadButton::DPadRight),
+			MenuState::Settings => UserInput::GamepadButton(GamepadButton::Start),
+			MenuState::Paused => UserInput::GamepadButton(GamepadButton::Select),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -107,6 +123,7 @@ mod tests {
 			vec![
 				MenuState::Inventory,
 				MenuState::ComboOverview,
+				MenuState::Progression,
 				MenuState::Settings,
 				MenuState::Paused,
 			],
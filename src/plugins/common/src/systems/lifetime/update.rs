@@ -1,5 +1,6 @@
 use crate::{
 	components::lifetime::Lifetime,
+	events::expired::Expired,
 	traits::accessors::get::TryApplyOn,
 	zyheeda_commands::ZyheedaCommands,
 };
@@ -18,7 +19,10 @@ impl Lifetime {
 			if delta < lifetime.0 {
 				lifetime.0 -= delta;
 			} else {
-				commands.try_apply_on(&entity, |e| e.try_despawn());
+				commands.try_apply_on(&entity, |mut e| {
+					e.trigger(|entity| Expired { entity });
+					e.try_despawn();
+				});
 			}
 		}
 	}
@@ -36,10 +40,23 @@ mod tests {
 	use std::time::Duration;
 	use testing::{MissingLastUpdate, SingleThreadedApp, TickTime};
 
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Expired(Vec<(Entity, bool)>);
+
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 		app.add_systems(Update, Lifetime::update::<Real>);
 		app.init_resource::<Time<Real>>();
+		app.init_resource::<_Expired>();
+		app.add_observer(
+			|on_expired: On<Expired>,
+			 mut expired: ResMut<_Expired>,
+			 lifetimes: Query<(), With<Lifetime>>| {
+				expired
+					.0
+					.push((on_expired.entity, lifetimes.contains(on_expired.entity)));
+			},
+		);
 
 		app
 	}
@@ -90,4 +107,34 @@ mod tests {
 		assert!(app.world().get_entity(lifetime).is_err());
 		Ok(())
 	}
+
+	#[test]
+	fn trigger_expired_before_despawn() -> Result<(), MissingLastUpdate> {
+		let mut app = setup();
+		let lifetime = app
+			.world_mut()
+			.spawn(Lifetime(Duration::from_secs(100)))
+			.id();
+
+		app.tick_time(Duration::from_secs(100))?;
+		app.update();
+
+		assert_eq!(
+			&_Expired(vec![(lifetime, true)]),
+			app.world().resource::<_Expired>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_trigger_expired_when_lifetime_remaining() -> Result<(), MissingLastUpdate> {
+		let mut app = setup();
+		app.world_mut().spawn(Lifetime(Duration::from_secs(100)));
+
+		app.tick_time(Duration::from_secs(10))?;
+		app.update();
+
+		assert_eq!(&_Expired(vec![]), app.world().resource::<_Expired>());
+		Ok(())
+	}
 }
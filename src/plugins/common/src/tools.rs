@@ -3,6 +3,7 @@ pub mod attribute;
 pub mod bone_name;
 pub mod change;
 pub mod collider_radius;
+pub mod file_io;
 pub mod handle;
 pub mod inventory_key;
 pub mod is_not;
@@ -17,7 +17,7 @@ use crate::{
 		iter_helpers::{first, next},
 	},
 	traits::{
-		handles_input::InvalidUserInput,
+		handles_input::{DefaultGamepadInput, InvalidUserInput},
 		handles_localization::Token,
 		iteration::{FiniteIter, IterFinite},
 	},
@@ -108,12 +108,27 @@ impl InvalidUserInput for ActionKey {
 	}
 }
 
+impl DefaultGamepadInput for ActionKey {
+	fn default_gamepad_input(&self) -> UserInput {
+		match self {
+			ActionKey::Movement(key) => key.default_gamepad_input(),
+			ActionKey::Slot(key) => key.default_gamepad_input(),
+			ActionKey::Targeting(key) => key.default_gamepad_input(),
+			ActionKey::Miscellaneous(key) => key.default_gamepad_input(),
+			ActionKey::Menu(key) => key.default_gamepad_input(),
+			ActionKey::Camera(key) => key.default_gamepad_input(),
+			ActionKey::Save(key) => key.default_gamepad_input(),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::traits::iteration::IterFinite;
-	use std::collections::HashMap;
+	use std::collections::{HashMap, HashSet};
 	use testing::assert_eq_unordered;
+	use user_input::InputDevice;
 
 	#[test]
 	fn iter_all_keys() {
@@ -173,4 +188,44 @@ mod tests {
 				.collect::<HashMap<_, _>>(),
 		);
 	}
+
+	#[test]
+	fn map_keys_to_default_gamepad_input() {
+		fn pair_with_gamepad_input<TKey>(key: TKey) -> (ActionKey, UserInput)
+		where
+			TKey: Into<ActionKey> + DefaultGamepadInput + Copy,
+		{
+			(key.into(), key.default_gamepad_input())
+		}
+
+		assert_eq!(
+			std::iter::empty()
+				.chain(MovementKey::iterator().map(pair_with_gamepad_input))
+				.chain(HandSlot::iterator().map(pair_with_gamepad_input))
+				.chain(TerrainTargeting::iterator().map(pair_with_gamepad_input))
+				.chain(Miscellaneous::iterator().map(pair_with_gamepad_input))
+				.chain(MenuState::iterator().map(pair_with_gamepad_input))
+				.chain(CameraKey::iterator().map(pair_with_gamepad_input))
+				.chain(SaveKey::iterator().map(pair_with_gamepad_input))
+				.collect::<HashMap<_, _>>(),
+			ActionKey::iterator()
+				.map(pair_with_gamepad_input)
+				.collect::<HashMap<_, _>>(),
+		);
+	}
+
+	#[test]
+	fn default_gamepad_inputs_are_unique_gamepad_inputs() {
+		let inputs = ActionKey::iterator()
+			.map(|key| key.default_gamepad_input())
+			.collect::<Vec<_>>();
+
+		assert_eq!(
+			(inputs.len(), true),
+			(
+				inputs.iter().collect::<HashSet<_>>().len(),
+				inputs.iter().all(|i| i.device() == InputDevice::Gamepad),
+			)
+		);
+	}
 }
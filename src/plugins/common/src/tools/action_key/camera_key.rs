@@ -1,10 +1,10 @@
 use super::{ActionKey, user_input::UserInput};
 use crate::traits::{
-	handles_input::InvalidUserInput,
+	handles_input::{DefaultGamepadInput, InvalidUserInput},
 	handles_localization::Token,
 	iteration::{FiniteIter, IterFinite},
 };
-use bevy::input::mouse::MouseButton;
+use bevy::input::{gamepad::GamepadButton, mouse::MouseButton};
 use serde::{Deserialize, Serialize};
 use zyheeda_core::prelude::*;
 
@@ -64,6 +64,14 @@ impl InvalidUserInput for CameraKey {
 	}
 }
 
+impl DefaultGamepadInput for CameraKey {
+	fn default_gamepad_input(&self) -> UserInput {
+		match self {
+			CameraKey::Rotate => UserInput::GamepadButton(GamepadButton::LeftTrigger),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
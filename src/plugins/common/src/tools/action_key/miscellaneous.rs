@@ -1,12 +1,12 @@
 use crate::{
 	tools::action_key::{ActionKey, user_input::UserInput},
 	traits::{
-		handles_input::InvalidUserInput,
+		handles_input::{DefaultGamepadInput, InvalidUserInput},
 		handles_localization::Token,
 		iteration::{FiniteIter, IterFinite},
 	},
 };
-use bevy::input::keyboard::KeyCode;
+use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
@@ -20,6 +20,14 @@ impl InvalidUserInput for Miscellaneous {
 	}
 }
 
+impl DefaultGamepadInput for Miscellaneous {
+	fn default_gamepad_input(&self) -> UserInput {
+		match self {
+			Miscellaneous::Interact => UserInput::GamepadButton(GamepadButton::South),
+		}
+	}
+}
+
 impl From<Miscellaneous> for ActionKey {
 	fn from(target: Miscellaneous) -> Self {
 		Self::Miscellaneous(target)
@@ -1,10 +1,16 @@
-use super::{ActionKey, user_input::UserInput};
+use super::{
+	ActionKey,
+	user_input::{
+		UserInput,
+		gamepad_stick::{GamepadStick, StickDirection},
+	},
+};
 use crate::traits::{
-	handles_input::InvalidUserInput,
+	handles_input::{DefaultGamepadInput, InvalidUserInput},
 	handles_localization::Token,
 	iteration::{FiniteIter, IterFinite},
 };
-use bevy::input::{keyboard::KeyCode, mouse::MouseButton};
+use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode, mouse::MouseButton};
 use serde::{Deserialize, Serialize};
 use zyheeda_core::prelude::*;
 
@@ -85,6 +91,23 @@ impl InvalidUserInput for MovementKey {
 	}
 }
 
+impl DefaultGamepadInput for MovementKey {
+	fn default_gamepad_input(&self) -> UserInput {
+		match self {
+			MovementKey::Forward => UserInput::GamepadStick(GamepadStick::Left, StickDirection::Up),
+			MovementKey::Backward => {
+				UserInput::GamepadStick(GamepadStick::Left, StickDirection::Down)
+			}
+			MovementKey::Left => UserInput::GamepadStick(GamepadStick::Left, StickDirection::Left),
+			MovementKey::Right => {
+				UserInput::GamepadStick(GamepadStick::Left, StickDirection::Right)
+			}
+			MovementKey::Pointer => UserInput::GamepadButton(GamepadButton::RightThumb),
+			MovementKey::ToggleWalkRun => UserInput::GamepadButton(GamepadButton::LeftThumb),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -1,12 +1,12 @@
 use crate::{
 	tools::action_key::{ActionKey, user_input::UserInput},
 	traits::{
-		handles_input::InvalidUserInput,
+		handles_input::{DefaultGamepadInput, InvalidUserInput},
 		handles_localization::Token,
 		iteration::{FiniteIter, IterFinite},
 	},
 };
-use bevy::input::keyboard::KeyCode;
+use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode};
 use serde::{Deserialize, Serialize};
 use zyheeda_core::prelude::*;
 
@@ -70,6 +70,15 @@ impl InvalidUserInput for SaveKey {
 	}
 }
 
+impl DefaultGamepadInput for SaveKey {
+	fn default_gamepad_input(&self) -> UserInput {
+		match self {
+			SaveKey::QuickSave => UserInput::GamepadButton(GamepadButton::DPadUp),
+			SaveKey::QuickLoad => UserInput::GamepadButton(GamepadButton::DPadDown),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
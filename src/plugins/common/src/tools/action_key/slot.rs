@@ -3,7 +3,7 @@ use crate::{
 	errors::{ErrorData, Level},
 	traits::{
 		accessors::get::ViewField,
-		handles_input::InvalidUserInput,
+		handles_input::{DefaultGamepadInput, InvalidUserInput},
 		handles_localization::Token,
 		iteration::{FiniteIter, IterFinite},
 	},
@@ -81,6 +81,15 @@ impl InvalidUserInput for HandSlot {
 	}
 }
 
+impl DefaultGamepadInput for HandSlot {
+	fn default_gamepad_input(&self) -> UserInput {
+		match self {
+			HandSlot::Left => UserInput::GamepadButton(GamepadButton::LeftTrigger2),
+			HandSlot::Right => UserInput::GamepadButton(GamepadButton::RightTrigger2),
+		}
+	}
+}
+
 impl From<HandSlot> for SlotKey {
 	fn from(slot: HandSlot) -> Self {
 		Self(match slot {
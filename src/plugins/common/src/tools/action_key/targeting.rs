@@ -1,7 +1,7 @@
 use crate::{
 	tools::action_key::{ActionKey, user_input::UserInput},
 	traits::{
-		handles_input::InvalidUserInput,
+		handles_input::{DefaultGamepadInput, InvalidUserInput},
 		handles_localization::Token,
 		iteration::{FiniteIter, IterFinite},
 	},
@@ -18,6 +18,12 @@ impl InvalidUserInput for TerrainTargeting {
 	}
 }
 
+impl DefaultGamepadInput for TerrainTargeting {
+	fn default_gamepad_input(&self) -> UserInput {
+		UserInput::GamepadButton(GamepadButton::RightTrigger)
+	}
+}
+
 impl From<TerrainTargeting> for ActionKey {
 	fn from(target: TerrainTargeting) -> Self {
 		Self::Targeting(target)
@@ -1,5 +1,8 @@
+pub mod gamepad_stick;
+
 use crate::traits::{accessors::get::ViewField, handles_localization::Token};
 use bevy::prelude::*;
+use gamepad_stick::{GamepadStick, StickDirection};
 use serde::{Deserialize, Serialize};
 use zyheeda_core::prelude::*;
 
@@ -10,10 +13,29 @@ pub struct UserInputSystem;
 pub enum UserInput {
 	KeyCode(KeyCode),
 	MouseButton(MouseButton),
+	GamepadButton(GamepadButton),
+	GamepadStick(GamepadStick, StickDirection),
 }
 
 impl UserInput {
 	pub const SYSTEM: UserInputSystem = UserInputSystem;
+
+	pub const fn device(&self) -> InputDevice {
+		match self {
+			Self::KeyCode(_) | Self::MouseButton(_) => InputDevice::KeyboardMouse,
+			Self::GamepadButton(_) | Self::GamepadStick(..) => InputDevice::Gamepad,
+		}
+	}
+}
+
+/// The device an input originates from.
+///
+/// Actions are bound once per device, so switching devices does not require rebinding.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub enum InputDevice {
+	#[default]
+	KeyboardMouse,
+	Gamepad,
 }
 
 impl From<UserInput> for Token {
@@ -21,6 +43,8 @@ impl From<UserInput> for Token {
 		match value {
 			UserInput::KeyCode(key_code) => Self::from(key_code),
 			UserInput::MouseButton(mouse_button) => Self::from(mouse_button),
+			UserInput::GamepadButton(gamepad_button) => Self::from(gamepad_button),
+			UserInput::GamepadStick(stick, direction) => Self::from((stick, direction)),
 		}
 	}
 }
@@ -61,6 +85,48 @@ impl TryFrom<UserInput> for MouseButton {
 	}
 }
 
+impl From<GamepadButton> for UserInput {
+	fn from(gamepad_button: GamepadButton) -> Self {
+		Self::GamepadButton(gamepad_button)
+	}
+}
+
+impl TryFrom<UserInput> for GamepadButton {
+	type Error = IsNot<GamepadButton>;
+
+	fn try_from(user_input: UserInput) -> Result<Self, Self::Error> {
+		let UserInput::GamepadButton(gamepad_button) = user_input else {
+			return Err(IsNot::target_type());
+		};
+
+		Ok(gamepad_button)
+	}
+}
+
+impl From<(GamepadStick, StickDirection)> for UserInput {
+	fn from((stick, direction): (GamepadStick, StickDirection)) -> Self {
+		Self::GamepadStick(stick, direction)
+	}
+}
+
 impl ViewField for UserInput {
 	type TValue<'a> = Self;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	#[test_case(UserInput::KeyCode(KeyCode::KeyA), InputDevice::KeyboardMouse; "key")]
+	#[test_case(UserInput::MouseButton(MouseButton::Left), InputDevice::KeyboardMouse; "mouse")]
+	#[test_case(UserInput::GamepadButton(GamepadButton::South), InputDevice::Gamepad; "button")]
+	#[test_case(
+		UserInput::GamepadStick(GamepadStick::Left, StickDirection::Up),
+		InputDevice::Gamepad;
+		"stick"
+	)]
+	fn device(input: UserInput, device: InputDevice) {
+		assert_eq!(device, input.device());
+	}
+}
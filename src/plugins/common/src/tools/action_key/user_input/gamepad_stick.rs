@@ -0,0 +1,69 @@
+use crate::traits::handles_localization::Token;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum GamepadStick {
+	Left,
+	Right,
+}
+
+impl GamepadStick {
+	pub const fn axes(self) -> (GamepadAxis, GamepadAxis) {
+		match self {
+			GamepadStick::Left => (GamepadAxis::LeftStickX, GamepadAxis::LeftStickY),
+			GamepadStick::Right => (GamepadAxis::RightStickX, GamepadAxis::RightStickY),
+		}
+	}
+}
+
+/// Direction in which a [`GamepadStick`] is tilted, so it can be used like a button.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum StickDirection {
+	Up,
+	Down,
+	Left,
+	Right,
+}
+
+impl StickDirection {
+	pub const ALL: [Self; 4] = [Self::Up, Self::Down, Self::Left, Self::Right];
+
+	pub const fn as_vec2(self) -> Vec2 {
+		match self {
+			StickDirection::Up => Vec2::Y,
+			StickDirection::Down => Vec2::NEG_Y,
+			StickDirection::Left => Vec2::NEG_X,
+			StickDirection::Right => Vec2::X,
+		}
+	}
+}
+
+impl From<(GamepadStick, StickDirection)> for Token {
+	fn from((stick, direction): (GamepadStick, StickDirection)) -> Self {
+		let stick = match stick {
+			GamepadStick::Left => "left",
+			GamepadStick::Right => "right",
+		};
+		let direction = match direction {
+			StickDirection::Up => "up",
+			StickDirection::Down => "down",
+			StickDirection::Left => "left",
+			StickDirection::Right => "right",
+		};
+
+		Self::from(format!("gamepad-stick-{stick}-{direction}"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	#[test_case(GamepadStick::Left, StickDirection::Up, "gamepad-stick-left-up"; "left up")]
+	#[test_case(GamepadStick::Right, StickDirection::Left, "gamepad-stick-right-left"; "right left")]
+	fn tokenize(stick: GamepadStick, direction: StickDirection, token: &str) {
+		assert_eq!(Token::from((stick, direction)), Token::from(token));
+	}
+}
@@ -1,5 +1,7 @@
-use crate::traits::{file_exists::FileExists, read_file::ReadFile, write_file::WriteFile};
-use common::prelude::*;
+use crate::{
+	errors::{ErrorData, Level},
+	traits::{file_exists::FileExists, read_file::ReadFile, write_file::WriteFile},
+};
 use std::{
 	ffi::OsStr,
 	fmt::Display,
@@ -13,8 +15,12 @@ mod prefixes {
 	pub const OLD: &str = "old";
 }
 
+/// Reads and writes a single file.
+///
+/// Writing goes through a temporary file and keeps the previous version as a backup, which is
+/// used as a fallback when reading.
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) struct FileIO<TIO = IO>
+pub struct FileIO<TIO = IO>
 where
 	TIO: IOWrite + IORead,
 {
@@ -23,7 +29,7 @@ where
 }
 
 impl FileIO {
-	pub(crate) fn with_file(file: PathBuf) -> Self {
+	pub fn with_file(file: PathBuf) -> Self {
 		Self { file, io: IO }
 	}
 }
@@ -113,7 +119,7 @@ where
 }
 
 #[derive(Debug, PartialEq)]
-pub(crate) enum FileError<TError = IOError> {
+pub enum FileError<TError = IOError> {
 	IO(TError),
 	DoesNotExist(String),
 }
@@ -142,9 +148,9 @@ impl ErrorData for FileError {
 }
 
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) struct IO;
+pub struct IO;
 
-pub(crate) trait IOWrite {
+pub trait IOWrite {
 	type TWriteError;
 
 	fn create_dir_all(&self, path: &Path) -> Result<(), Self::TWriteError>;
@@ -168,7 +174,7 @@ impl IOWrite for IO {
 	}
 }
 
-pub(crate) trait IORead {
+pub trait IORead {
 	type TReadError;
 
 	fn exists(&self, path: &Path) -> bool;
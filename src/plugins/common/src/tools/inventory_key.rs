@@ -1,6 +1,7 @@
 use crate::traits::iteration::{Infinite, IterInfinite};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub struct InventoryKey(pub usize);
 
 impl IterInfinite for InventoryKey {
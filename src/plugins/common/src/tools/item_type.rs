@@ -8,6 +8,7 @@ pub enum ItemType {
 	Pistol,
 	Bracer,
 	ForceEssence,
+	GravityEssence,
 	VoidBeam,
 }
 
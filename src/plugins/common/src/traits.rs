@@ -6,6 +6,7 @@ pub mod automatic_transitions;
 pub mod clear;
 pub mod collect_reversed;
 pub mod delta;
+pub mod file_exists;
 pub mod get_asset_load_state;
 pub mod get_asset_path;
 pub mod get_handle_from_path;
@@ -13,8 +14,10 @@ pub mod get_ray;
 pub mod handles_agents;
 pub mod handles_animations;
 pub mod handles_asset_resource_loading;
+pub mod handles_audio;
 pub mod handles_custom_assets;
 pub mod handles_enemies;
+pub mod handles_equipment_stats;
 pub mod handles_game_states;
 pub mod handles_graphics;
 pub mod handles_input;
@@ -29,6 +32,7 @@ pub mod handles_path_finding;
 pub mod handles_physics;
 pub mod handles_player;
 pub mod handles_saving;
+pub mod handles_settings;
 pub mod handles_skill_physics;
 pub mod init_resource;
 pub mod iter_descendants_conditional;
@@ -42,6 +46,7 @@ pub mod ownership_relation;
 pub mod pause_control;
 pub mod prefab;
 pub mod read;
+pub mod read_file;
 pub mod register_controlled_state;
 pub mod register_derived_component;
 pub mod register_persistent_entities;
@@ -56,3 +61,4 @@ pub mod thread_safe;
 pub mod update_cumulative;
 pub mod visible_slots;
 pub mod wrap_handle;
+pub mod write_file;
@@ -1,3 +1,3 @@
-pub(crate) trait FileExists {
+pub trait FileExists {
 	fn file_exists(&self) -> bool;
 }
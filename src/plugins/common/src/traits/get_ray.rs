@@ -4,4 +4,15 @@ use bevy::prelude::*;
 
 pub trait GetCamRay<TWindow> {
 	fn get_ray(&self, camera_transform: &GlobalTransform, window: &TWindow) -> Option<Ray3d>;
+
+	/// Ray through a point offset from the window center in the given aim direction.
+	///
+	/// Used instead of [`GetCamRay::get_ray`] when aiming without a cursor, like with a gamepad
+	/// stick.
+	fn get_aim_ray(
+		&self,
+		camera_transform: &GlobalTransform,
+		window: &TWindow,
+		aim: Dir2,
+	) -> Option<Ray3d>;
 }
@@ -7,4 +7,19 @@ impl GetCamRay<Window> for Camera {
 			.cursor_position()
 			.and_then(|c| self.viewport_to_world(camera_transform, c).ok())
 	}
+
+	fn get_aim_ray(
+		&self,
+		camera_transform: &GlobalTransform,
+		window: &Window,
+		aim: Dir2,
+	) -> Option<Ray3d> {
+		let size = window.size();
+		let radius = size.min_element() / 4.;
+		// viewport y grows downwards, while stick y grows upwards
+		let offset = Vec2::new(aim.x, -aim.y) * radius;
+
+		self.viewport_to_world(camera_transform, size / 2. + offset)
+			.ok()
+	}
 }
@@ -0,0 +1,108 @@
+use crate::{
+	tools::path::Path,
+	traits::{
+		accessors::get::{TryGetContext, TryGetContextMut},
+		handles_localization::Token,
+		iteration::{FiniteIter, IterFinite},
+	},
+};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+pub trait HandlesAudio {
+	type TAudioSettings: for<'c> TryGetContext<AudioSettings, TContext<'c>: GetVolume>;
+	type TAudioSettingsMut: for<'c> TryGetContextMut<AudioSettings, TContext<'c>: SetVolume>;
+}
+
+pub struct AudioSettings;
+
+pub trait GetVolume {
+	/// Volume in the range of `0.0..=1.0`
+	fn volume(&self, category: AudioCategory) -> f32;
+}
+
+impl<T> GetVolume for T
+where
+	T: Deref<Target: GetVolume>,
+{
+	fn volume(&self, category: AudioCategory) -> f32 {
+		self.deref().volume(category)
+	}
+}
+
+pub trait SetVolume: GetVolume {
+	/// Values outside of `0.0..=1.0` are clamped
+	fn set_volume(&mut self, category: AudioCategory, volume: f32);
+}
+
+impl<T> SetVolume for T
+where
+	T: DerefMut<Target: SetVolume>,
+{
+	fn set_volume(&mut self, category: AudioCategory, volume: f32) {
+		self.deref_mut().set_volume(category, volume);
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum AudioCategory {
+	Master,
+	#[default]
+	Sfx,
+	Music,
+	Ui,
+}
+
+impl From<AudioCategory> for Token {
+	fn from(category: AudioCategory) -> Self {
+		match category {
+			AudioCategory::Master => Self::from("audio-master"),
+			AudioCategory::Sfx => Self::from("audio-sfx"),
+			AudioCategory::Music => Self::from("audio-music"),
+			AudioCategory::Ui => Self::from("audio-ui"),
+		}
+	}
+}
+
+impl IterFinite for AudioCategory {
+	fn iterator() -> FiniteIter<Self> {
+		FiniteIter(Some(Self::Master))
+	}
+
+	fn next(FiniteIter(current): &FiniteIter<Self>) -> Option<Self> {
+		match current.as_ref()? {
+			Self::Master => Some(Self::Sfx),
+			Self::Sfx => Some(Self::Music),
+			Self::Music => Some(Self::Ui),
+			Self::Ui => None,
+		}
+	}
+}
+
+/// Asset path of a sound, as declared in skill, agent, item or door assets
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct SoundCue(pub Path);
+
+impl From<&str> for SoundCue {
+	fn from(path: &str) -> Self {
+		Self(Path::from(path))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn iterate() {
+		assert_eq!(
+			vec![
+				AudioCategory::Master,
+				AudioCategory::Sfx,
+				AudioCategory::Music,
+				AudioCategory::Ui,
+			],
+			AudioCategory::iterator().take(100).collect::<Vec<_>>()
+		);
+	}
+}
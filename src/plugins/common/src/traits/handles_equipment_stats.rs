@@ -0,0 +1,7 @@
+use crate::attributes::equipment_stats::EquipmentStats;
+use bevy::prelude::*;
+
+pub trait HandlesEquipmentStats {
+	/// The component receiving the stacked [`EquipmentStats`] of all equipped items.
+	type TEquipmentStats: Component + From<EquipmentStats>;
+}
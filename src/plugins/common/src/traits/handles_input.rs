@@ -28,7 +28,8 @@ pub trait HandlesInput {
 	type TInput: SystemParam
 		+ for<'w, 's> SystemParam<Item<'w, 's>: GetInput + GetAllInputs + InputSetupChanged>
 		+ for<'w, 's> SystemParam<Item<'w, 's>: GetInputState + GetAllInputStates>
-		+ for<'w, 's> SystemParam<Item<'w, 's>: GetRawUserInput>;
+		+ for<'w, 's> SystemParam<Item<'w, 's>: GetRawUserInput>
+		+ for<'w, 's> SystemParam<Item<'w, 's>: GetAimDirection>;
 }
 
 pub trait HandlesInputMut {
@@ -36,6 +37,7 @@ pub trait HandlesInputMut {
 		+ for<'w, 's> SystemParam<Item<'w, 's>: GetInput + GetAllInputs + InputSetupChanged>
 		+ for<'w, 's> SystemParam<Item<'w, 's>: GetInputState + GetAllInputStates>
 		+ for<'w, 's> SystemParam<Item<'w, 's>: GetRawUserInput>
+		+ for<'w, 's> SystemParam<Item<'w, 's>: GetAimDirection>
 		+ for<'w, 's> SystemParam<Item<'w, 's>: UpdateKey>;
 }
 
@@ -49,6 +51,11 @@ pub trait InvalidUserInput {
 	fn invalid_input(&self) -> &[UserInput];
 }
 
+/// Input assigned to an action by default, when playing with a gamepad.
+pub trait DefaultGamepadInput {
+	fn default_gamepad_input(&self) -> UserInput;
+}
+
 pub trait InputSetupChanged {
 	fn input_setup_changed(&self) -> bool;
 }
@@ -71,6 +78,14 @@ pub trait GetRawUserInput {
 	fn get_raw_user_input(&self, state: RawInputState) -> impl Iterator<Item = UserInput>;
 }
 
+/// Screen space direction in which the player aims with an analog stick.
+///
+/// Returns [`None`] while the stick rests or when the player uses another device, in which case
+/// the cursor should be used for aiming.
+pub trait GetAimDirection {
+	fn get_aim_direction(&self) -> Option<Dir2>;
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum RawInputState {
 	JustPressed,
@@ -1,7 +1,7 @@
 //! Implementations for common bevy system parameters to forward traits
 //! from the inner to the outer type.
 
-use super::{GetInput, GetInputState, GetRawUserInput, RawInputState, UpdateKey};
+use super::{GetAimDirection, GetInput, GetInputState, GetRawUserInput, RawInputState, UpdateKey};
 use crate::{
 	tools::action_key::{ActionKey, user_input::UserInput},
 	traits::{
@@ -72,6 +72,24 @@ where
 	}
 }
 
+impl<T> GetAimDirection for Res<'_, T>
+where
+	T: GetAimDirection + Resource,
+{
+	fn get_aim_direction(&self) -> Option<Dir2> {
+		self.deref().get_aim_direction()
+	}
+}
+
+impl<T> GetAimDirection for ResMut<'_, T>
+where
+	T: GetAimDirection + Resource<Mutability = Mutable>,
+{
+	fn get_aim_direction(&self) -> Option<Dir2> {
+		self.deref().get_aim_direction()
+	}
+}
+
 impl<T> GetInputState for Res<'_, T>
 where
 	T: GetInputState + Resource,
@@ -1,6 +1,7 @@
 use crate::traits::{
-	accessors::get::{TryGetContext, TryGetContextMut, View, ViewField},
+	accessors::get::{GetContextMut, TryGetContext, TryGetContextMut, View, ViewField},
 	handles_map_generation::InteractiveType,
+	loadout::ItemName,
 };
 use bevy::{ecs::system::SystemParam, prelude::*};
 use macros::EntityKey;
@@ -11,7 +12,9 @@ pub trait HandlesInteractive {
 		+ for<'c> TryGetContext<Interactive, TContext<'c>: InspectInteractive>;
 
 	type TInteractiveMut: SystemParam
-		+ for<'c> TryGetContextMut<Interactive, TContext<'c>: SetInteractiveState>;
+		+ for<'c> TryGetContextMut<Interactive, TContext<'c>: SetInteractiveState + TakeLoot>;
+
+	type TPickupsMut: SystemParam + for<'c> GetContextMut<Pickups, TContext<'c>: SpawnPickup>;
 }
 
 pub trait InspectInteractive: View<InteractiveType> + View<InteractiveState> {}
@@ -31,6 +34,35 @@ where
 	}
 }
 
+pub trait TakeLoot {
+	/// Take up to `max` items from the loot of an interactive, leaving the rest in place.
+	fn take_loot(&mut self, max: usize) -> Vec<ItemName>;
+}
+
+impl<T> TakeLoot for T
+where
+	T: DerefMut<Target: TakeLoot>,
+{
+	fn take_loot(&mut self, max: usize) -> Vec<ItemName> {
+		self.deref_mut().take_loot(max)
+	}
+}
+
+pub struct Pickups;
+
+pub trait SpawnPickup {
+	fn spawn_pickup(&mut self, item: ItemName, translation: Vec3);
+}
+
+impl<T> SpawnPickup for T
+where
+	T: DerefMut<Target: SpawnPickup>,
+{
+	fn spawn_pickup(&mut self, item: ItemName, translation: Vec3) {
+		self.deref_mut().spawn_pickup(item, translation);
+	}
+}
+
 #[derive(EntityKey)]
 pub struct Interactive {
 	pub entity: Entity,
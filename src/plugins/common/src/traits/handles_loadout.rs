@@ -2,6 +2,7 @@ pub mod available_skills;
 pub mod combos;
 pub mod insert_default_loadout;
 pub mod items;
+pub mod progression;
 pub mod register_loadout_bones;
 pub mod skills;
 
@@ -17,7 +18,8 @@ use crate::{
 			available_skills::{AvailableSkills, ReadAvailableSkills},
 			combos::{Combos, ReadCombos, UpdateCombos},
 			insert_default_loadout::{InsertDefaultLoadout, NotLoadedOut},
-			items::{Items, ReadItems, SwapItems},
+			items::{AddItems, Items, ReadItems, SocketItems, SwapItems},
+			progression::{AcquireUpgrade, GainExperience, Progression, ReadProgression},
 			register_loadout_bones::{NoBonesRegistered, RegisterLoadoutBones},
 			skills::{ReadSkills, Skills},
 		},
@@ -25,6 +27,7 @@ use crate::{
 	},
 };
 use bevy::ecs::system::SystemParam;
+use serde::{Deserialize, Serialize};
 use std::{
 	collections::HashSet,
 	fmt::Debug,
@@ -42,11 +45,16 @@ pub trait HandlesLoadout {
 		+ for<'c> TryGetContext<Items, TContext<'c>: ReadItems>
 		+ for<'c> TryGetContext<Skills, TContext<'c>: ReadSkills>
 		+ for<'c> TryGetContext<Combos, TContext<'c>: ReadCombos<Self::TSkillID>>
-		+ for<'c> TryGetContext<AvailableSkills, TContext<'c>: ReadAvailableSkills<Self::TSkillID>>;
+		+ for<'c> TryGetContext<AvailableSkills, TContext<'c>: ReadAvailableSkills<Self::TSkillID>>
+		+ for<'c> TryGetContext<Progression, TContext<'c>: ReadProgression<Self::TSkillID>>;
 
 	type TLoadoutMut: SystemParam
-		+ for<'c> TryGetContextMut<Items, TContext<'c>: SwapItems>
-		+ for<'c> TryGetContextMut<Combos, TContext<'c>: UpdateCombos<Self::TSkillID>>;
+		+ for<'c> TryGetContextMut<Items, TContext<'c>: SwapItems + SocketItems + AddItems>
+		+ for<'c> TryGetContextMut<Combos, TContext<'c>: UpdateCombos<Self::TSkillID>>
+		+ for<'c> TryGetContextMut<
+			Progression,
+			TContext<'c>: GainExperience + AcquireUpgrade<Self::TSkillID>,
+		>;
 
 	type TLoadoutActivity: SystemParam
 		+ for<'c> TryGetContext<Skills, TContext<'c>: ActiveSkills>
@@ -56,7 +64,7 @@ pub trait HandlesLoadout {
 		+ for<'c> TryGetContextMut<Skills, TContext<'c>: HeldSkillsMut>;
 }
 
-#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum LoadoutKey {
 	Inventory(InventoryKey),
 	Slot(SlotKey),
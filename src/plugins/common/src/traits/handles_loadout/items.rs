@@ -1,7 +1,8 @@
 use crate::traits::{
 	accessors::get::{View, ViewField},
-	handles_loadout::LoadoutKey,
+	handles_loadout::{LoadoutKey, skills::SkillStats},
 	handles_localization::Token,
+	loadout::ItemName,
 };
 use bevy::prelude::*;
 use macros::EntityKey;
@@ -19,7 +20,7 @@ impl ViewField for ItemToken {
 }
 
 pub trait ReadItems {
-	type TItem<'a>: View<ItemToken>
+	type TItem<'a>: View<ItemToken> + View<SkillStats>
 	where
 		Self: 'a;
 
@@ -64,3 +65,55 @@ where
 		self.deref_mut().swap_items(a, b);
 	}
 }
+
+pub trait SocketItems {
+	/// Socket the essence at `essence` into the item at `item`.
+	///
+	/// An essence that was socketed before is put where the new essence came from. Returns
+	/// `false` without changing anything, if the essence cannot be socketed into the item.
+	fn socket_item<TEssence, TItem>(&mut self, essence: TEssence, item: TItem) -> bool
+	where
+		TEssence: Into<LoadoutKey>,
+		TItem: Into<LoadoutKey>;
+}
+
+impl<T> SocketItems for T
+where
+	T: DerefMut<Target: SocketItems>,
+{
+	fn socket_item<TEssence, TItem>(&mut self, essence: TEssence, item: TItem) -> bool
+	where
+		TEssence: Into<LoadoutKey>,
+		TItem: Into<LoadoutKey>,
+	{
+		self.deref_mut().socket_item(essence, item)
+	}
+}
+
+pub trait AddItems {
+	/// How many more items fit into the inventory.
+	fn free_slots(&self) -> usize;
+
+	/// Put the items into the first free inventory slots.
+	///
+	/// Items exceeding [`AddItems::free_slots`] are dropped, so callers should not pass more.
+	fn add_items<TItems>(&mut self, items: TItems)
+	where
+		TItems: IntoIterator<Item = ItemName>;
+}
+
+impl<T> AddItems for T
+where
+	T: DerefMut<Target: AddItems>,
+{
+	fn free_slots(&self) -> usize {
+		self.deref().free_slots()
+	}
+
+	fn add_items<TItems>(&mut self, items: TItems)
+	where
+		TItems: IntoIterator<Item = ItemName>,
+	{
+		self.deref_mut().add_items(items);
+	}
+}
@@ -0,0 +1,106 @@
+use crate::traits::handles_localization::Token;
+use bevy::prelude::*;
+use macros::EntityKey;
+use serde::{Deserialize, Serialize};
+use std::{
+	ops::{Deref, DerefMut},
+	sync::Arc,
+};
+
+#[derive(EntityKey)]
+pub struct Progression {
+	pub entity: Entity,
+}
+
+/// Level, experience and unspent skill points of an agent.
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub struct LevelProgress {
+	pub level: u32,
+	/// Experience gathered since the current level was reached.
+	pub experience: u32,
+	/// Experience needed to reach the next level.
+	pub next_level: u32,
+	pub skill_points: u32,
+}
+
+/// Identifies an upgrade node within the upgrade tree of a skill.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct UpgradeId(Arc<str>);
+
+impl From<&str> for UpgradeId {
+	fn from(value: &str) -> Self {
+		Self(Arc::from(value))
+	}
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SkillUpgrades<TSkillId> {
+	pub skill: TSkillId,
+	pub token: Token,
+	pub upgrades: Vec<Upgrade>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Upgrade {
+	pub id: UpgradeId,
+	pub token: Token,
+	pub cost: u32,
+	pub state: UpgradeState,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum UpgradeState {
+	Acquired,
+	/// Required upgrades are acquired and enough skill points are left.
+	Available,
+	Locked,
+}
+
+pub trait ReadProgression<TSkillId> {
+	fn level_progress(&self) -> LevelProgress;
+
+	/// Get the upgrade trees of all skills with a consistent ordering.
+	fn skill_upgrades(&self) -> Vec<SkillUpgrades<TSkillId>>;
+}
+
+impl<T, TSkillId> ReadProgression<TSkillId> for T
+where
+	T: Deref<Target: ReadProgression<TSkillId>>,
+{
+	fn level_progress(&self) -> LevelProgress {
+		self.deref().level_progress()
+	}
+
+	fn skill_upgrades(&self) -> Vec<SkillUpgrades<TSkillId>> {
+		self.deref().skill_upgrades()
+	}
+}
+
+pub trait GainExperience {
+	fn gain_experience(&mut self, amount: u32);
+}
+
+impl<T> GainExperience for T
+where
+	T: DerefMut<Target: GainExperience>,
+{
+	fn gain_experience(&mut self, amount: u32) {
+		self.deref_mut().gain_experience(amount);
+	}
+}
+
+pub trait AcquireUpgrade<TSkillId> {
+	/// Spend skill points on the given upgrade.
+	///
+	/// Does nothing, if the upgrade is not [`UpgradeState::Available`].
+	fn acquire_upgrade(&mut self, skill: TSkillId, upgrade: &UpgradeId);
+}
+
+impl<T, TSkillId> AcquireUpgrade<TSkillId> for T
+where
+	T: DerefMut<Target: AcquireUpgrade<TSkillId>>,
+{
+	fn acquire_upgrade(&mut self, skill: TSkillId, upgrade: &UpgradeId) {
+		self.deref_mut().acquire_upgrade(skill, upgrade);
+	}
+}
@@ -27,12 +27,30 @@ impl ViewField for SkillIcon {
 	type TValue<'a> = &'a Handle<Image>;
 }
 
+/// Fraction of a skill's cooldown still remaining, `0.` when the skill is ready.
+pub struct SkillCooldown;
+
+impl ViewField for SkillCooldown {
+	type TValue<'a> = f32;
+}
+
+/// Localizable lines describing the values of a skill, like its cast time, damage or range.
+pub struct SkillStats;
+
+impl ViewField for SkillStats {
+	type TValue<'a> = &'a [Token];
+}
+
 pub trait GetSkillId<TSkillId> {
 	fn get_skill_id(&self) -> TSkillId;
 }
 
 pub trait ReadSkills {
-	type TSkill<'a>: View<SkillToken> + View<SkillIcon> + View<SkillExecution>
+	type TSkill<'a>: View<SkillToken>
+		+ View<SkillIcon>
+		+ View<SkillExecution>
+		+ View<SkillCooldown>
+		+ View<SkillStats>
 	where
 		Self: 'a;
 
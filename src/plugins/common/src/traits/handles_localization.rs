@@ -1,16 +1,22 @@
 pub mod localized;
 
+mod gamepad_button;
 mod key_code;
 mod mouse_button;
 
 use crate::traits::{accessors::get::ViewField, thread_safe::ThreadSafe};
 use bevy::{ecs::system::SystemParam, prelude::*};
 use localized::Localized;
-use std::{fmt::Display, ops::Deref, sync::Arc};
+use std::{
+	fmt::Display,
+	ops::{Deref, DerefMut},
+	sync::Arc,
+};
 use unic_langid::LanguageIdentifier;
 
 pub trait HandlesLocalization {
-	type TLocalizationServer: ThreadSafe + for<'w, 's> SystemParam<Item<'w, 's>: Localize>;
+	type TLocalizationServer: ThreadSafe
+		+ for<'w, 's> SystemParam<Item<'w, 's>: Localize + ListLocales>;
 	type TLocalizationServerMut: ThreadSafe + for<'w, 's> SystemParam<Item<'w, 's>: SetLocalization>;
 }
 
@@ -18,6 +24,29 @@ pub trait SetLocalization {
 	fn set_localization(&mut self, language: LanguageIdentifier);
 }
 
+impl<T> SetLocalization for T
+where
+	T: DerefMut<Target: SetLocalization>,
+{
+	fn set_localization(&mut self, language: LanguageIdentifier) {
+		self.deref_mut().set_localization(language);
+	}
+}
+
+pub trait ListLocales {
+	/// The locales shipped with the game, sorted and without duplicates
+	fn locales(&self) -> &[LanguageIdentifier];
+}
+
+impl<T> ListLocales for T
+where
+	T: Deref<Target: ListLocales>,
+{
+	fn locales(&self) -> &[LanguageIdentifier] {
+		self.deref().locales()
+	}
+}
+
 pub trait Localize {
 	fn localize(&self, token: &Token) -> LocalizationResult;
 }
@@ -49,30 +78,53 @@ where
 	}
 }
 
+/// Key of a fluent message, optionally carrying the arguments the message is formatted with.
 #[derive(Debug, PartialEq, Default, Clone)]
-pub struct Token(Arc<str>);
+pub struct Token {
+	key: Arc<str>,
+	args: Vec<(Arc<str>, TokenArg)>,
+}
 
 impl Token {
 	pub fn failed(&self) -> FailedToken {
-		FailedToken(self.0.clone())
+		FailedToken(self.key.clone())
+	}
+
+	/// Adds a fluent argument, which can be referenced in the message via `{ $name }`.
+	pub fn with_arg<TArg>(mut self, name: &str, arg: TArg) -> Self
+	where
+		TArg: Into<TokenArg>,
+	{
+		self.args.push((Arc::from(name), arg.into()));
+		self
+	}
+
+	pub fn args(&self) -> &[(Arc<str>, TokenArg)] {
+		&self.args
 	}
 }
 
 impl Display for Token {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		write!(f, "token: {}", self.0)
+		write!(f, "token: {}", self.key)
 	}
 }
 
 impl From<&str> for Token {
 	fn from(value: &str) -> Self {
-		Token(Arc::from(value))
+		Token {
+			key: Arc::from(value),
+			args: vec![],
+		}
 	}
 }
 
 impl From<String> for Token {
 	fn from(value: String) -> Self {
-		Token(Arc::from(value))
+		Token {
+			key: Arc::from(value),
+			args: vec![],
+		}
 	}
 }
 
@@ -80,7 +132,54 @@ impl Deref for Token {
 	type Target = str;
 
 	fn deref(&self) -> &Self::Target {
-		self.0.as_ref()
+		self.key.as_ref()
+	}
+}
+
+/// Argument of a [`Token`]. Nested tokens are localized before being passed on.
+#[derive(Debug, PartialEq, Clone)]
+pub enum TokenArg {
+	Number(f64),
+	String(Arc<str>),
+	Token(Token),
+}
+
+/// Converts via the shortest decimal representation, so `0.2_f32` is passed on as `0.2`
+/// instead of `0.20000000298023224`.
+impl From<f32> for TokenArg {
+	fn from(value: f32) -> Self {
+		let value = value.to_string().parse().unwrap_or(f64::from(value));
+		Self::Number(value)
+	}
+}
+
+impl From<f64> for TokenArg {
+	fn from(value: f64) -> Self {
+		Self::Number(value)
+	}
+}
+
+impl From<u32> for TokenArg {
+	fn from(value: u32) -> Self {
+		Self::Number(f64::from(value))
+	}
+}
+
+impl From<&str> for TokenArg {
+	fn from(value: &str) -> Self {
+		Self::String(Arc::from(value))
+	}
+}
+
+impl From<String> for TokenArg {
+	fn from(value: String) -> Self {
+		Self::String(Arc::from(value))
+	}
+}
+
+impl From<Token> for TokenArg {
+	fn from(value: Token) -> Self {
+		Self::Token(value)
 	}
 }
 
@@ -152,6 +251,32 @@ impl From<FailedToken> for LocalizationResult {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn token_with_args() {
+		let token = Token::from("my token")
+			.with_arg("a", 42.)
+			.with_arg("b", "text")
+			.with_arg("c", Token::from("nested"));
+
+		assert_eq!(
+			(
+				"my token",
+				[
+					(Arc::from("a"), TokenArg::Number(42.)),
+					(Arc::from("b"), TokenArg::String(Arc::from("text"))),
+					(Arc::from("c"), TokenArg::Token(Token::from("nested"))),
+				]
+				.as_slice()
+			),
+			(&*token, token.args())
+		);
+	}
+
+	#[test]
+	fn f32_arg_without_precision_artifacts() {
+		assert_eq!(TokenArg::Number(0.2), TokenArg::from(0.2_f32));
+	}
+
 	#[test]
 	fn localize_result_or_ok() {
 		let result = LocalizationResult::Ok(Localized::from("my string"));
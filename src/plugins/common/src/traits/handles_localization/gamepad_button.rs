@@ -0,0 +1,29 @@
+use super::Token;
+use bevy::prelude::*;
+
+impl From<GamepadButton> for Token {
+	fn from(value: GamepadButton) -> Self {
+		match value {
+			GamepadButton::South => Self::from("gamepad-button-south"),
+			GamepadButton::East => Self::from("gamepad-button-east"),
+			GamepadButton::North => Self::from("gamepad-button-north"),
+			GamepadButton::West => Self::from("gamepad-button-west"),
+			GamepadButton::C => Self::from("gamepad-button-c"),
+			GamepadButton::Z => Self::from("gamepad-button-z"),
+			GamepadButton::LeftTrigger => Self::from("gamepad-button-left-trigger"),
+			GamepadButton::LeftTrigger2 => Self::from("gamepad-button-left-trigger-2"),
+			GamepadButton::RightTrigger => Self::from("gamepad-button-right-trigger"),
+			GamepadButton::RightTrigger2 => Self::from("gamepad-button-right-trigger-2"),
+			GamepadButton::Select => Self::from("gamepad-button-select"),
+			GamepadButton::Start => Self::from("gamepad-button-start"),
+			GamepadButton::Mode => Self::from("gamepad-button-mode"),
+			GamepadButton::LeftThumb => Self::from("gamepad-button-left-thumb"),
+			GamepadButton::RightThumb => Self::from("gamepad-button-right-thumb"),
+			GamepadButton::DPadUp => Self::from("gamepad-button-d-pad-up"),
+			GamepadButton::DPadDown => Self::from("gamepad-button-d-pad-down"),
+			GamepadButton::DPadLeft => Self::from("gamepad-button-d-pad-left"),
+			GamepadButton::DPadRight => Self::from("gamepad-button-d-pad-right"),
+			GamepadButton::Other(index) => Self::from(format!("gamepad-button-other-{index}")),
+		}
+	}
+}
@@ -4,11 +4,13 @@ use crate::{
 	traits::{
 		accessors::get::{TryGetContextMut, View, ViewField},
 		handles_enemies::EnemyType,
+		handles_physics::physical_bodies::ShapeParameters,
 		system_set_definition::SystemSetDefinition,
 	},
 	zyheeda_commands::ZyheedaEntityCommands,
 };
 use bevy::{ecs::system::SystemParam, prelude::*};
+use macros::EntityKey;
 use serde::{Deserialize, Serialize};
 use std::{
 	fmt::Debug,
@@ -25,12 +27,43 @@ pub trait HandlesMapGeneration: SystemSetDefinition {
 			TContext<'c>: SetPrefab<InteractiveType>,
 		>;
 
+	type TGridBlockersMut: SystemParam
+		+ for<'c> TryGetContextMut<GridBlocker, TContext<'c>: BlockGrid>;
+
 	type TGraph: Graph + for<'a> From<&'a Self::TMap> + ThreadSafe;
 
 	type TMap: Component;
 	type TMapRef: Component + View<Entity>;
 }
 
+#[derive(EntityKey)]
+pub struct GridBlocker {
+	pub entity: Entity,
+}
+
+/// Block or release navigation grid cells at runtime, for instance for closed doors.
+pub trait BlockGrid {
+	/// Block all grid cells covered by the xz-footprint of the given collider shape, placed
+	/// at the blocker's [`GlobalTransform`].
+	fn block_grid(&mut self, shape: ShapeParameters);
+
+	/// Release all grid cells previously blocked by this blocker.
+	fn unblock_grid(&mut self);
+}
+
+impl<T> BlockGrid for T
+where
+	T: DerefMut<Target: BlockGrid>,
+{
+	fn block_grid(&mut self, shape: ShapeParameters) {
+		self.deref_mut().block_grid(shape);
+	}
+
+	fn unblock_grid(&mut self) {
+		self.deref_mut().unblock_grid();
+	}
+}
+
 pub trait Graph:
 	GraphNode<TNNode = Self::TNode>
 	+ GraphSuccessors<TSNode = Self::TNode>
@@ -158,6 +191,7 @@ impl PrefabType for AgentType {
 pub enum InteractiveType {
 	Door,
 	Container,
+	Pickup,
 }
 
 impl ViewField for InteractiveType {
@@ -8,7 +8,7 @@ use crate::{
 use bevy::{ecs::system::SystemParam, prelude::*};
 use macros::EntityKey;
 use serde::{Deserialize, Serialize};
-use std::ops::DerefMut;
+use std::{ops::DerefMut, time::Duration};
 
 pub trait HandlesMovement: SystemSetDefinition {
 	type TMovement: SystemParam + for<'c> TryGetContext<Movement, TContext<'c>: CurrentMovement>;
@@ -18,9 +18,15 @@ pub trait HandlesMovement: SystemSetDefinition {
 		+ for<'c> TryGetContextMut<NotConfiguredMovement, TContext<'c>: ConfigureMovement>;
 }
 
-pub trait ControlMovement: StartMovement + StopMovement + ToggleSpeed + CurrentMovement {}
+pub trait ControlMovement:
+	StartMovement + ChaseMovement + StopMovement + ForceMovement + ToggleSpeed + CurrentMovement
+{
+}
 
-impl<T> ControlMovement for T where T: StartMovement + StopMovement + ToggleSpeed + CurrentMovement {}
+impl<T> ControlMovement for T where
+	T: StartMovement + ChaseMovement + StopMovement + ForceMovement + ToggleSpeed + CurrentMovement
+{
+}
 
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub enum MovementTarget {
@@ -102,6 +108,21 @@ where
 	}
 }
 
+pub trait ChaseMovement {
+	/// Move to `target` like [`StartMovement::start`], but with paths computed by
+	/// [`HandlesPathFinding::TComputeChasePath`](crate::traits::handles_path_finding::HandlesPathFinding::TComputeChasePath).
+	fn chase(&mut self, target: Vec3);
+}
+
+impl<T> ChaseMovement for T
+where
+	T: DerefMut<Target: ChaseMovement>,
+{
+	fn chase(&mut self, target: Vec3) {
+		self.deref_mut().chase(target);
+	}
+}
+
 pub trait StopMovement {
 	fn stop(&mut self);
 }
@@ -115,6 +136,20 @@ where
 	}
 }
 
+pub trait ForceMovement {
+	/// Move towards `target` at `speed` for `duration`, overriding any other movement until then.
+	fn force_movement(&mut self, target: Vec3, speed: UnitsPerSecond, duration: Duration);
+}
+
+impl<T> ForceMovement for T
+where
+	T: DerefMut<Target: ForceMovement>,
+{
+	fn force_movement(&mut self, target: Vec3, speed: UnitsPerSecond, duration: Duration) {
+		self.deref_mut().force_movement(target, speed, duration);
+	}
+}
+
 pub trait ToggleSpeed {
 	fn toggle_speed(&mut self) -> SpeedToggle;
 }
@@ -10,6 +10,11 @@ use bevy::prelude::*;
 
 pub trait HandlesPathFinding: SystemSetDefinition {
 	type TComputePath: Component + ComputePath;
+
+	/// Path computation for agents that chase the same, moving target.
+	///
+	/// Used for movements started via [`ChaseMovement`](crate::traits::handles_movement::ChaseMovement).
+	type TComputeChasePath: Component + ComputePath;
 	type TComputerRef: Component + View<Entity>;
 }
 
@@ -24,4 +29,8 @@ pub trait ComputePath {
 		end: Vec3,
 		required_clearance: Units,
 	) -> Option<Self::TIter<'_>>;
+
+	/// Check whether a previously computed path, walked from `start`, runs through cells
+	/// that are no longer passable.
+	fn path_obstructed(&self, start: Vec3, waypoints: &[Vec3], required_clearance: Units) -> bool;
 }
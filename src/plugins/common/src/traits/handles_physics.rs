@@ -1,7 +1,12 @@
 pub mod physical_bodies;
 
 use crate::{
-	attributes::{effect_target::EffectTarget, health::Health},
+	attributes::{
+		effect_target::EffectTarget,
+		energy::Energy,
+		health::Health,
+		resistances::Resistances,
+	},
 	effects::{force::Force, gravity::Gravity, health_damage::HealthDamage},
 	tools::{Units, speed::Speed},
 	traits::{
@@ -9,7 +14,10 @@ use crate::{
 		handles_physics::physical_bodies::{Blocker, BodyConfig},
 	},
 };
-use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy::{
+	ecs::{component::Mutable, system::SystemParam},
+	prelude::*,
+};
 use macros::EntityKey;
 use serde::{Deserialize, Serialize};
 use std::{
@@ -177,12 +185,15 @@ impl ViewField for CharacterMotion {
 }
 
 pub trait HandlesAllPhysicalEffects:
-	HandlesLife + HandlesPhysicalEffect<Gravity> + HandlesPhysicalEffect<Force>
+	HandlesLife + HandlesPhysicalEffect<Gravity> + HandlesPhysicalEffect<Force> + HandlesStatusEffects
 {
 }
 
 impl<T> HandlesAllPhysicalEffects for T where
-	T: HandlesLife + HandlesPhysicalEffect<Gravity> + HandlesPhysicalEffect<Force>
+	T: HandlesLife
+		+ HandlesPhysicalEffect<Gravity>
+		+ HandlesPhysicalEffect<Force>
+		+ HandlesStatusEffects
 {
 }
 
@@ -206,6 +217,49 @@ impl<T> HandlesLife for T where
 {
 }
 
+pub trait HandlesStatusEffects {
+	/// The component tracking timed status effects (damage over time, slows, stuns) of an agent.
+	///
+	/// Absorb shields are not exposed here, they are part of the life handling.
+	type TStatusEffects: Component + View<Stunned> + View<MovementSpeedFactor>;
+}
+
+/// Whether an agent is prevented from queueing new skills.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Stunned;
+
+impl ViewField for Stunned {
+	type TValue<'a> = bool;
+}
+
+/// The factor by which the movement speed of an agent is scaled, `1.` when unaffected.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MovementSpeedFactor;
+
+impl ViewField for MovementSpeedFactor {
+	type TValue<'a> = f32;
+}
+
+pub trait HandlesEnergy {
+	/// The component holding the regenerating energy of an agent, which is spent on skills.
+	type TEnergy: Component<Mutability = Mutable> + View<Energy> + SpendEnergy;
+}
+
+pub trait SpendEnergy {
+	/// Reduce the current energy by `amount`, but not below zero.
+	fn spend_energy(&mut self, amount: f32);
+}
+
+pub trait HandlesMaxHealth {
+	/// The component holding the health of an agent, whose maximum can be raised.
+	type TMaxHealth: Component<Mutability = Mutable> + RaiseMaxHealth;
+}
+
+pub trait RaiseMaxHealth {
+	/// Raise the maximum health by `amount` and heal by the same amount.
+	fn raise_max_health(&mut self, amount: f32);
+}
+
 pub trait PhysicalEffect {
 	type TTarget;
 }
@@ -213,6 +267,10 @@ pub trait PhysicalEffect {
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub struct PhysicalDefaultAttributes {
 	pub health: Health,
+	#[serde(default)]
+	pub resistances: Resistances,
+	#[serde(default)]
+	pub energy: Energy,
 	pub force_interaction: EffectTarget<Force>,
 	pub gravity_interaction: EffectTarget<Gravity>,
 }
@@ -221,6 +279,8 @@ impl Default for PhysicalDefaultAttributes {
 	fn default() -> Self {
 		Self {
 			health: Health::new(10.),
+			resistances: Resistances::default(),
+			energy: Energy::default(),
 			force_interaction: EffectTarget::Affected,
 			gravity_interaction: EffectTarget::Affected,
 		}
@@ -1,16 +1,51 @@
 mod external;
 
-use crate::{errors::Unreachable, traits::handles_custom_assets::TryLoadFrom};
-use bevy::{ecs::system::ScheduleSystem, prelude::*};
-use serde::{Serialize, de::DeserializeOwned};
-use std::{hash::Hash, ops::Deref, sync::OnceLock};
+use crate::{
+	attributes::health::Health,
+	errors::Unreachable,
+	traits::{handles_custom_assets::TryLoadFrom, thread_safe::ThreadSafe},
+};
+use bevy::{
+	ecs::system::{ScheduleSystem, SystemParam},
+	prelude::*,
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use std::{
+	hash::Hash,
+	ops::{Deref, DerefMut},
+	sync::OnceLock,
+	time::Duration,
+};
 
 pub trait HandlesSaving {
+	/// Request saving to or loading from a specific [`SaveSlot`]
+	type TSaveSlotsMut: ThreadSafe + for<'w, 's> SystemParam<Item<'w, 's>: RequestSlot>;
+
+	/// Inspect the manual [`SaveSlot`]s and the [`SlotMetadata`] stored in them
+	type TSaveSlots: ThreadSafe + for<'w, 's> SystemParam<Item<'w, 's>: ReadManualSlots>;
+
+	/// Write data into the [`SlotMetadata`] of the save that is currently written.
+	///
+	/// Meant to be used by systems registered via [`HandlesSaving::on_before_save`].
+	type TSlotMetadataMut: ThreadSafe + for<'w, 's> SystemParam<Item<'w, 's>: WriteSlotMetadata>;
+
+	/// Move persistent entities out of the world and back in.
+	///
+	/// Stashed entities are kept in serialized form and are part of save files.
+	/// Requests are processed after [`Update`], so commands issued alongside them are applied.
+	type TStashMut: ThreadSafe + for<'w, 's> SystemParam<Item<'w, 's>: StashEntities>;
+
 	/// Check whether quick loading is possible
 	///
 	/// Useful for button (dis|en)ables.
 	fn can_quick_load() -> impl SystemCondition<()>;
 
+	/// Check whether any save slot can be loaded
+	///
+	/// Useful for button (dis|en)ables.
+	fn can_continue() -> impl SystemCondition<()>;
+
 	/// Register savable components.
 	///
 	/// Implementors are likely to panic if uniqueness of [`SavableComponent::ID`] is violated.
@@ -22,6 +57,123 @@ pub trait HandlesSaving {
 	fn on_before_save<M>(app: &mut App, systems: impl IntoScheduleConfigs<ScheduleSystem, M>);
 }
 
+/// Identifies a save file.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum SaveSlot {
+	Quick,
+	Manual(u8),
+	Auto(u8),
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum SlotRequest {
+	Save(SaveSlot),
+	Load(SaveSlot),
+}
+
+pub trait RequestSlot {
+	fn request_slot(&mut self, request: SlotRequest);
+}
+
+impl<T> RequestSlot for T
+where
+	T: DerefMut<Target: RequestSlot>,
+{
+	fn request_slot(&mut self, request: SlotRequest) {
+		self.deref_mut().request_slot(request);
+	}
+}
+
+pub trait ReadManualSlots {
+	/// All manual slots in order, with the [`SlotMetadata`] of those that hold a save.
+	///
+	/// Reads the slot files, so avoid calling this every frame.
+	fn manual_slots(&self) -> Vec<(SaveSlot, Option<SlotMetadata>)>;
+}
+
+impl<T> ReadManualSlots for T
+where
+	T: Deref<Target: ReadManualSlots>,
+{
+	fn manual_slots(&self) -> Vec<(SaveSlot, Option<SlotMetadata>)> {
+		self.deref().manual_slots()
+	}
+}
+
+/// Header information stored alongside the entities of a save slot.
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+pub struct SlotMetadata {
+	/// Seconds since [`UNIX_EPOCH`](std::time::UNIX_EPOCH)
+	pub timestamp: u64,
+	pub play_time: Duration,
+	pub level: Option<i8>,
+	pub player_health: Option<Health>,
+}
+
+pub trait WriteSlotMetadata {
+	fn write_level(&mut self, level: i8);
+	fn write_player_health(&mut self, health: Health);
+}
+
+impl<T> WriteSlotMetadata for T
+where
+	T: DerefMut<Target: WriteSlotMetadata>,
+{
+	fn write_level(&mut self, level: i8) {
+		self.deref_mut().write_level(level);
+	}
+
+	fn write_player_health(&mut self, health: Health) {
+		self.deref_mut().write_player_health(health);
+	}
+}
+
+/// Identifies a group of stashed entities.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct StashKey(String);
+
+impl From<&str> for StashKey {
+	fn from(key: &str) -> Self {
+		Self(key.to_owned())
+	}
+}
+
+impl From<String> for StashKey {
+	fn from(key: String) -> Self {
+		Self(key)
+	}
+}
+
+pub trait StashEntities {
+	/// Serialize and despawn `entities` together with their persistent descendants.
+	///
+	/// Replaces entities previously stashed under the same key.
+	fn stash_entities(&mut self, key: StashKey, entities: Vec<Entity>);
+
+	/// Respawn the entities stashed under `key` and drop them from the stash.
+	fn unstash_entities(&mut self, key: StashKey);
+
+	/// Whether entities are stashed under `key`, including pending (un)stash requests.
+	fn is_stashed(&self, key: &StashKey) -> bool;
+}
+
+impl<T> StashEntities for T
+where
+	T: DerefMut<Target: StashEntities>,
+{
+	fn stash_entities(&mut self, key: StashKey, entities: Vec<Entity>) {
+		self.deref_mut().stash_entities(key, entities);
+	}
+
+	fn unstash_entities(&mut self, key: StashKey) {
+		self.deref_mut().unstash_entities(key);
+	}
+
+	fn is_stashed(&self, key: &StashKey) -> bool {
+		self.deref().is_stashed(key)
+	}
+}
+
 /// Marks components as being (de)serializable.
 ///
 /// A blanket implementation exists for components that can use `Self`
@@ -44,8 +196,18 @@ pub trait SavableComponent:
 
 	/// Identifier for component saving/loading
 	const ID: UniqueComponentId;
+
+	/// Steps that upgrade serialized data of older versions of [`Self::TDto`].
+	///
+	/// The component version is the number of migrations, where the migration at index `n`
+	/// rewrites version `n` into version `n + 1`. Append a migration whenever the serialized
+	/// shape of [`Self::TDto`] changes, existing migrations must stay untouched.
+	const MIGRATIONS: &'static [Migration] = &[];
 }
 
+/// Rewrites the serialized data of a [`SavableComponent`] into its next version.
+pub type Migration = fn(&mut Value);
+
 /// A unique id for a component.
 ///
 /// Uniqueness:
@@ -173,6 +335,14 @@ mod test_savable_component_derive {
 	#[savable_component(id = "priority", has_priority)]
 	struct _Priority;
 
+	#[derive(Component, SavableComponent, Clone, Serialize, Deserialize)]
+	#[savable_component(id = "migrated", migrations = [rename_a, rename_b])]
+	struct _Migrated;
+
+	fn rename_a(_: &mut Value) {}
+
+	fn rename_b(_: &mut Value) {}
+
 	#[test]
 	fn default_to_self_as_dto() {
 		assert_eq!(
@@ -200,4 +370,22 @@ mod test_savable_component_derive {
 	fn has_priority() {
 		assert!(_Priority::PRIORITY);
 	}
+
+	#[test]
+	fn default_to_no_migrations() {
+		assert!(_Default::MIGRATIONS.is_empty());
+	}
+
+	#[test]
+	fn has_migrations() {
+		let expected: [Migration; 2] = [rename_a, rename_b];
+
+		assert!(
+			_Migrated::MIGRATIONS.len() == expected.len()
+				&& _Migrated::MIGRATIONS
+					.iter()
+					.zip(expected)
+					.all(|(a, b)| std::ptr::fn_addr_eq(*a, b))
+		);
+	}
 }
@@ -0,0 +1,125 @@
+use crate::{
+	tools::action_key::slot::HandSlot,
+	traits::{
+		accessors::get::{TryGetContext, TryGetContextMut},
+		handles_localization::Token,
+	},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::HashMap,
+	ops::{Deref, DerefMut},
+};
+use unic_langid::{LanguageIdentifier, langid};
+
+pub trait HandlesSettings {
+	type TSettings: for<'c> TryGetContext<GameSettings, TContext<'c>: GetSettings>;
+	type TSettingsMut: for<'c> TryGetContextMut<GameSettings, TContext<'c>: SetSettings>;
+}
+
+pub struct GameSettings;
+
+pub trait GetSettings {
+	fn settings(&self) -> &GeneralSettings;
+}
+
+impl<T> GetSettings for T
+where
+	T: Deref<Target: GetSettings>,
+{
+	fn settings(&self) -> &GeneralSettings {
+		self.deref().settings()
+	}
+}
+
+pub trait SetSettings: GetSettings {
+	fn settings_mut(&mut self) -> &mut GeneralSettings;
+}
+
+impl<T> SetSettings for T
+where
+	T: DerefMut<Target: SetSettings>,
+{
+	fn settings_mut(&mut self) -> &mut GeneralSettings {
+		self.deref_mut().settings_mut()
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GeneralSettings {
+	pub fps_cap: u32,
+	pub vsync: bool,
+	pub window_mode: DisplayMode,
+	/// Multiplies the scale factor of the window
+	pub resolution_scale: f32,
+	pub post_processing: bool,
+	pub outlines: bool,
+	pub camera_sensitivity: f32,
+	pub language: LanguageIdentifier,
+	/// How the skills of a hand slot pick their target, [`TargetingMode::Cursor`] when missing
+	pub targeting: HashMap<HandSlot, TargetingMode>,
+}
+
+impl GeneralSettings {
+	pub fn targeting(&self, slot: HandSlot) -> TargetingMode {
+		self.targeting.get(&slot).copied().unwrap_or_default()
+	}
+}
+
+impl Default for GeneralSettings {
+	fn default() -> Self {
+		Self {
+			fps_cap: 60,
+			vsync: true,
+			window_mode: DisplayMode::Windowed,
+			resolution_scale: 1.,
+			post_processing: true,
+			outlines: true,
+			camera_sensitivity: 1.,
+			language: langid!("en-US"),
+			targeting: HashMap::default(),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum DisplayMode {
+	#[default]
+	Windowed,
+	BorderlessFullscreen,
+	Fullscreen,
+}
+
+impl From<DisplayMode> for Token {
+	fn from(mode: DisplayMode) -> Self {
+		match mode {
+			DisplayMode::Windowed => Self::from("settings-window-mode-windowed"),
+			DisplayMode::BorderlessFullscreen => Self::from("settings-window-mode-borderless"),
+			DisplayMode::Fullscreen => Self::from("settings-window-mode-fullscreen"),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum TargetingMode {
+	#[default]
+	Cursor,
+	/// The nearest enemy in front of the caster, falling back to the cursor
+	AutoTarget,
+	/// An enemy picked like with [`TargetingMode::AutoTarget`], kept until it is gone
+	LockOn,
+	/// The caster itself, for skills like heals
+	OnSelf,
+}
+
+impl From<TargetingMode> for Token {
+	fn from(mode: TargetingMode) -> Self {
+		match mode {
+			TargetingMode::Cursor => Self::from("settings-targeting-cursor"),
+			TargetingMode::AutoTarget => Self::from("settings-targeting-auto"),
+			TargetingMode::LockOn => Self::from("settings-targeting-lock-on"),
+			TargetingMode::OnSelf => Self::from("settings-targeting-self"),
+		}
+	}
+}
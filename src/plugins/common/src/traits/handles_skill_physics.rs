@@ -1,17 +1,34 @@
 pub mod beam;
+pub mod chain;
+pub mod dash;
+pub mod follow_up;
 pub mod ground_target;
+pub mod melee_arc;
 pub mod projectile;
 pub mod shield;
 
 use crate::{
 	components::persistent_entity::PersistentEntity,
-	effects::{force::Force, gravity::Gravity, health_damage::HealthDamage},
+	effects::{
+		absorb_shield::AbsorbShield,
+		damage_over_time::DamageOverTime,
+		force::Force,
+		gravity::Gravity,
+		health_damage::HealthDamage,
+		slow::Slow,
+		stun::Stun,
+	},
 	tools::{action_key::slot::SlotKey, bone_name::BoneName},
 	traits::{
 		accessors::get::{TryGetContext, TryGetContextMut},
+		handles_audio::SoundCue,
 		handles_skill_physics::{
 			beam::Beam,
+			chain::Chain,
+			dash::Dash,
+			follow_up::FollowUpSkill,
 			ground_target::SphereAoE,
+			melee_arc::MeleeArc,
 			projectile::Projectile,
 			shield::Shield,
 		},
@@ -139,6 +156,9 @@ pub struct SpawnArgs<'a> {
 	pub projection_effects: &'a [SkillEffect],
 	pub caster: SkillCaster,
 	pub mount: SkillMount,
+	/// Played where the skill first collides with something
+	pub impact_sound: Option<&'a SoundCue>,
+	pub follow_ups: &'a [FollowUpSkill],
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
@@ -147,6 +167,9 @@ pub enum SkillShape {
 	Projectile(Projectile),
 	Beam(Beam),
 	Shield(Shield),
+	MeleeArc(MeleeArc),
+	Chain(Chain),
+	Dash(Dash),
 }
 
 #[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
@@ -154,6 +177,10 @@ pub enum SkillEffect {
 	Force(Force),
 	Gravity(Gravity),
 	HealthDamage(HealthDamage),
+	DamageOverTime(DamageOverTime),
+	Slow(Slow),
+	Stun(Stun),
+	AbsorbShield(AbsorbShield),
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -177,6 +204,8 @@ impl Deref for SkillCaster {
 pub enum SkillTarget {
 	Cursor(Cursor),
 	Entity(PersistentEntity),
+	/// The agent using the skill, for skills like heals
+	Caster,
 }
 
 impl Default for SkillTarget {
@@ -203,6 +232,8 @@ pub enum SkillMount {
 	#[default]
 	Center,
 	Bone(SkillMountBone),
+	/// The transform of the parent skill, used by [`FollowUpSkill`]s
+	Parent,
 }
 
 impl SkillMount {
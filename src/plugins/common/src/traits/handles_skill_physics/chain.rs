@@ -0,0 +1,20 @@
+use crate::{
+	tools::Units,
+	traits::{handles_physics::physical_bodies::Blockers, handles_skill_physics::SkillShape},
+};
+use serde::{Deserialize, Serialize};
+
+/// Travels like a projectile and jumps from each hit target to the closest
+/// target within `range`, for up to `jumps` times
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Chain {
+	pub range: Units,
+	pub jumps: u8,
+	pub destroyed_by: Blockers,
+}
+
+impl From<Chain> for SkillShape {
+	fn from(chain: Chain) -> Self {
+		Self::Chain(chain)
+	}
+}
@@ -0,0 +1,39 @@
+use crate::{
+	dto::duration_in_seconds::DurationInSeconds,
+	tools::Units,
+	traits::handles_skill_physics::SkillShape,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Moves the caster `range` forward, applying contact effects around the caster
+/// for `duration`
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct Dash<TDuration = Duration> {
+	pub range: Units,
+	pub duration: TDuration,
+}
+
+impl From<Dash> for SkillShape {
+	fn from(dash: Dash) -> Self {
+		Self::Dash(dash)
+	}
+}
+
+impl From<Dash<DurationInSeconds>> for Dash {
+	fn from(dash: Dash<DurationInSeconds>) -> Self {
+		Self {
+			range: dash.range,
+			duration: Duration::from(dash.duration),
+		}
+	}
+}
+
+impl From<Dash> for Dash<DurationInSeconds> {
+	fn from(dash: Dash) -> Self {
+		Self {
+			range: dash.range,
+			duration: DurationInSeconds::from(dash.duration),
+		}
+	}
+}
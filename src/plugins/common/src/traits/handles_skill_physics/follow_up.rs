@@ -0,0 +1,55 @@
+use crate::{
+	dto::duration_in_seconds::DurationInSeconds,
+	traits::{
+		handles_audio::SoundCue,
+		handles_skill_physics::{SkillEffect, SkillShape},
+	},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A skill spawned by another skill when `trigger` fires.
+///
+/// Follow-ups use [`SkillMount::Parent`](crate::traits::handles_skill_physics::SkillMount::Parent)
+/// and inherit the caster of their parent.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct FollowUpSkill {
+	pub trigger: FollowUpTrigger,
+	pub shape: SkillShape,
+	pub contact_effects: Vec<SkillEffect>,
+	pub projection_effects: Vec<SkillEffect>,
+	pub impact_sound: Option<SoundCue>,
+	pub follow_ups: Vec<FollowUpSkill>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub enum FollowUpTrigger<TDuration = Duration> {
+	/// When the parent starts colliding with something
+	OnContact,
+	/// When the [`Lifetime`](crate::components::lifetime::Lifetime) of the parent runs out
+	OnExpiry,
+	/// Repeatedly, while the parent exists
+	Interval(TDuration),
+}
+
+impl From<FollowUpTrigger<DurationInSeconds>> for FollowUpTrigger {
+	fn from(trigger: FollowUpTrigger<DurationInSeconds>) -> Self {
+		match trigger {
+			FollowUpTrigger::OnContact => Self::OnContact,
+			FollowUpTrigger::OnExpiry => Self::OnExpiry,
+			FollowUpTrigger::Interval(interval) => Self::Interval(Duration::from(interval)),
+		}
+	}
+}
+
+impl From<FollowUpTrigger> for FollowUpTrigger<DurationInSeconds> {
+	fn from(trigger: FollowUpTrigger) -> Self {
+		match trigger {
+			FollowUpTrigger::OnContact => Self::OnContact,
+			FollowUpTrigger::OnExpiry => Self::OnExpiry,
+			FollowUpTrigger::Interval(interval) => {
+				Self::Interval(DurationInSeconds::from(interval))
+			}
+		}
+	}
+}
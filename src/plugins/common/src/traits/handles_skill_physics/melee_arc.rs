@@ -0,0 +1,44 @@
+use crate::{
+	dto::duration_in_seconds::DurationInSeconds,
+	tools::Units,
+	traits::{handles_physics::physical_bodies::Blockers, handles_skill_physics::SkillShape},
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// A blade of length `range`, sweeping across `angle` degrees in front of its mount
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MeleeArc<TDuration = Duration> {
+	pub range: Units,
+	pub angle: f32,
+	pub duration: TDuration,
+	pub destroyed_by: Blockers,
+}
+
+impl From<MeleeArc> for SkillShape {
+	fn from(arc: MeleeArc) -> Self {
+		Self::MeleeArc(arc)
+	}
+}
+
+impl From<MeleeArc<DurationInSeconds>> for MeleeArc {
+	fn from(arc: MeleeArc<DurationInSeconds>) -> Self {
+		Self {
+			range: arc.range,
+			angle: arc.angle,
+			duration: Duration::from(arc.duration),
+			destroyed_by: arc.destroyed_by,
+		}
+	}
+}
+
+impl From<MeleeArc> for MeleeArc<DurationInSeconds> {
+	fn from(arc: MeleeArc) -> Self {
+		Self {
+			range: arc.range,
+			angle: arc.angle,
+			duration: DurationInSeconds::from(arc.duration),
+			destroyed_by: arc.destroyed_by,
+		}
+	}
+}
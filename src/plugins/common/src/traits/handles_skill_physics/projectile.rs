@@ -7,6 +7,10 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Projectile {
 	pub destroyed_by: Blockers,
+	#[serde(default)]
+	pub motion: ProjectileMotion,
+	#[serde(default)]
+	pub impact: ProjectileImpact,
 }
 
 impl From<Projectile> for SkillShape {
@@ -14,3 +18,27 @@ impl From<Projectile> for SkillShape {
 		Self::Projectile(projectile)
 	}
 }
+
+/// How a projectile moves after it has been launched
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum ProjectileMotion {
+	/// Flies straight ahead
+	#[default]
+	Straight,
+	/// Turns towards the targeted entity by up to `turn_rate` degrees per second
+	Homing { turn_rate: f32 },
+	/// Flies in an arc that lands on the targeted ground point
+	Ballistic,
+}
+
+/// What happens when a projectile hits one of its `destroyed_by` blockers
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum ProjectileImpact {
+	/// Destroyed on the first hit
+	#[default]
+	Destroy,
+	/// Passes through up to `count` hits and is destroyed on the next one
+	Pierce { count: u8 },
+	/// Bounces off up to `count` hits and is destroyed on the next one
+	Ricochet { count: u8 },
+}
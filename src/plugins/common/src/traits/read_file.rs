@@ -1,4 +1,4 @@
-pub(crate) trait ReadFile {
+pub trait ReadFile {
 	type TReadError;
 
 	fn read(&self) -> Result<String, Self::TReadError>;
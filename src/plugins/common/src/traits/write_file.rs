@@ -1,4 +1,4 @@
-pub(crate) trait WriteFile {
+pub trait WriteFile {
 	type TWriteError;
 
 	fn write(&self, string: &str) -> Result<(), Self::TWriteError>;
@@ -1,8 +1,16 @@
 use bevy::{
+	ecs::system::StaticSystemParam,
 	prelude::*,
-	render::{Render, RenderApp, RenderSystems},
+	render::{
+		Render,
+		RenderApp,
+		RenderSystems,
+		extract_resource::{ExtractResource, ExtractResourcePlugin},
+	},
 };
+use common::prelude::*;
 use std::{
+	marker::PhantomData,
 	thread,
 	time::{Duration, Instant},
 };
@@ -11,33 +19,34 @@ use std::{
 /// <https://github.com/aevyrie/bevy_framepace>.
 ///
 /// This plugin implements a stripped down frame-limiting logic,
-/// designed to cap the frames per second (FPS) at the specified
-/// `target_fps`. Its primary purpose is to mitigate unexpected FPS
-/// drops that can occur on certain systems (e.g., Linux with X11
-/// and Nvidia GPUs) during mouse movement or clicks.
+/// designed to cap the frames per second (FPS) at the FPS cap
+/// of the general settings. Its primary purpose is to mitigate
+/// unexpected FPS drops that can occur on certain systems (e.g.,
+/// Linux with X11 and Nvidia GPUs) during mouse movement or clicks.
 ///
 /// The frame rate can only be limited within the range of 1 to 60 FPS
 /// due to the render schedule (which we hook into) running at 60 FPS.
-pub struct FrameLimiterPlugin {
-	pub target_fps: u32,
+pub struct FrameLimiterPlugin<TSettings>(PhantomData<TSettings>);
+
+impl<TSettings> FrameLimiterPlugin<TSettings>
+where
+	TSettings: ThreadSafe + HandlesSettings,
+{
+	pub fn from_plugin(_: &TSettings) -> Self {
+		Self(PhantomData)
+	}
 }
 
-impl Plugin for FrameLimiterPlugin {
+impl<TSettings> Plugin for FrameLimiterPlugin<TSettings>
+where
+	TSettings: ThreadSafe + HandlesSettings,
+{
 	fn build(&self, app: &mut App) {
-		let time_per_frame = match self.target_fps {
-			0 => {
-				error!("Target FPS was set to 0, using 1 FPS instead");
-				Duration::from_secs(1)
-			}
-			target_fps if target_fps > 60 => {
-				error!("Target FPS was set to >60, using 60 FPS instead");
-				Duration::from_secs(1) / 60
-			}
-			target_fps => Duration::from_secs(1) / target_fps,
-		};
+		app.init_resource::<Sleep>()
+			.add_plugins(ExtractResourcePlugin::<Sleep>::default())
+			.add_systems(Update, Sleep::update_from::<TSettings::TSettings>);
 
 		app.sub_app_mut(RenderApp)
-			.insert_resource(Sleep(time_per_frame))
 			.insert_resource(LastSleep(Instant::now()))
 			.add_systems(Render, Sleep::system.in_set(RenderSystems::Cleanup));
 	}
@@ -46,10 +55,45 @@ impl Plugin for FrameLimiterPlugin {
 #[derive(Resource, Debug, PartialEq)]
 struct LastSleep(Instant);
 
-#[derive(Resource, Debug, PartialEq)]
+#[derive(Resource, ExtractResource, Debug, PartialEq, Clone, Copy)]
 struct Sleep(Duration);
 
+impl Default for Sleep {
+	fn default() -> Self {
+		Self(Duration::from_secs(1) / 60)
+	}
+}
+
 impl Sleep {
+	fn from_target_fps(target_fps: u32) -> Self {
+		match target_fps {
+			0 => {
+				error!("Target FPS was set to 0, using 1 FPS instead");
+				Self(Duration::from_secs(1))
+			}
+			target_fps if target_fps > 60 => {
+				error!("Target FPS was set to >60, using 60 FPS instead");
+				Self(Duration::from_secs(1) / 60)
+			}
+			target_fps => Self(Duration::from_secs(1) / target_fps),
+		}
+	}
+
+	fn update_from<TSettings>(mut sleep: ResMut<Sleep>, param: StaticSystemParam<TSettings>)
+	where
+		TSettings: for<'c> TryGetContext<GameSettings, TContext<'c>: GetSettings>,
+	{
+		let Some(ctx) = TSettings::try_get_context(&param, GameSettings) else {
+			return;
+		};
+
+		if !ctx.context_changed() {
+			return;
+		}
+
+		*sleep = Self::from_target_fps(ctx.settings().fps_cap);
+	}
+
 	fn system(sleep: Res<Sleep>, mut last_sleep: ResMut<LastSleep>) {
 		let Sleep(sleep) = *sleep;
 		let LastSleep(last_sleep) = last_sleep.as_mut();
@@ -59,3 +103,89 @@ impl Sleep {
 		*last_sleep = Instant::now();
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{SystemParam, SystemParamItem};
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource)]
+	struct _Settings(GeneralSettings);
+
+	impl GetSettings for _Settings {
+		fn settings(&self) -> &GeneralSettings {
+			&self.0
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _Param<'w> {
+		settings: Res<'w, _Settings>,
+	}
+
+	impl TryGetContext<GameSettings> for _Param<'static> {
+		type TContext<'ctx> = &'ctx Res<'ctx, _Settings>;
+
+		fn try_get_context<'ctx>(
+			param: &'ctx SystemParamItem<Self>,
+			_: GameSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(&param.settings)
+		}
+	}
+
+	fn setup(settings: GeneralSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<Sleep>();
+		app.insert_resource(_Settings(settings));
+		app.add_systems(Update, Sleep::update_from::<_Param>);
+
+		app
+	}
+
+	#[test]
+	fn set_sleep_from_fps_cap() {
+		let mut app = setup(GeneralSettings {
+			fps_cap: 30,
+			..default()
+		});
+
+		app.update();
+
+		assert_eq!(
+			&Sleep(Duration::from_secs(1) / 30),
+			app.world().resource::<Sleep>(),
+		);
+	}
+
+	#[test]
+	fn limit_sleep_to_60_fps() {
+		let mut app = setup(GeneralSettings {
+			fps_cap: 120,
+			..default()
+		});
+
+		app.update();
+
+		assert_eq!(
+			&Sleep(Duration::from_secs(1) / 60),
+			app.world().resource::<Sleep>(),
+		);
+	}
+
+	#[test]
+	fn update_sleep_only_when_settings_changed() {
+		let mut app = setup(GeneralSettings {
+			fps_cap: 30,
+			..default()
+		});
+
+		app.update();
+		*app.world_mut().resource_mut::<Sleep>() = Sleep(Duration::ZERO);
+		app.update();
+
+		assert_eq!(&Sleep(Duration::ZERO), app.world().resource::<Sleep>());
+	}
+}
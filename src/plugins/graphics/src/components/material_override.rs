@@ -1,8 +1,8 @@
 use crate::materials::essence_material::EssenceMaterial;
 use bevy::{
 	color::palettes::{
-		css::LIGHT_CYAN,
-		tailwind::{CYAN_100, CYAN_200},
+		css::{LIGHT_CYAN, PLUM},
+		tailwind::{CYAN_100, CYAN_200, VIOLET_100, VIOLET_200},
 	},
 	prelude::*,
 };
@@ -30,6 +30,12 @@ impl<'w, 's> DerivableFrom<'w, 's, Essence> for MaterialOverride {
 				fresnel_color: (LIGHT_CYAN * 1.5).into(),
 				..default()
 			}),
+			Essence::Gravity => MaterialOverride::Material(EssenceMaterial {
+				texture_color: VIOLET_100.into(),
+				fill_color: VIOLET_200.into(),
+				fresnel_color: (PLUM * 1.5).into(),
+				..default()
+			}),
 		}
 	}
 }
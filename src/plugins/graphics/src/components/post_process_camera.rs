@@ -5,7 +5,7 @@ use bevy::{
 use common::prelude::*;
 use macros::asset_path;
 
-#[derive(Component, Default, Clone, Copy, ExtractComponent, ShaderType)]
+#[derive(Component, Debug, PartialEq, Default, Clone, Copy, ExtractComponent, ShaderType)]
 pub(crate) struct PostProcessCamera {
 	outline_color: LinearRgba,
 	outline_width: f32,
@@ -24,6 +24,13 @@ impl PostProcessCamera {
 			dark_region_light_factor: args.dark_region_light_factor,
 		}
 	}
+
+	pub(crate) fn with_outline_width(self, outline_width: Pixel) -> Self {
+		Self {
+			outline_width: outline_width.0,
+			..self
+		}
+	}
 }
 
 pub(crate) struct PostProcessArgs {
@@ -8,7 +8,7 @@ mod traits;
 
 use crate::{
 	components::{
-		camera_labels::{AgentsPass, OutlinePass, WorldLight},
+		camera_labels::{AgentsPass, CompositePass, OutlinePass, WorldLight},
 		los::{LoS, LoSCameras},
 		model_render_layers::ModelRenderLayers,
 		only_depth_prepass::OnlyDepthPrepass,
@@ -45,7 +45,7 @@ use components::{
 use materials::essence_material::EssenceMaterial;
 use resources::window_size::WindowSize;
 use std::{hash::Hash, marker::PhantomData};
-use systems::no_waiting_pipelines::no_waiting_pipelines;
+use systems::{apply_graphics_settings::toggle_bloom, no_waiting_pipelines::no_waiting_pipelines};
 
 #[cfg(not(feature = "debug-utils"))]
 use components::no_debug_cam::NoDebugCam;
@@ -56,7 +56,8 @@ pub struct GraphicsPlugin<TDebugCam, TDependencies> {
 }
 
 #[cfg(not(feature = "debug-utils"))]
-impl<TLoading, TSavegame, TPhysics> GraphicsPlugin<NoDebugCam, (TLoading, TSavegame, TPhysics)>
+impl<TLoading, TSavegame, TPhysics, TInput, TSettings>
+	GraphicsPlugin<NoDebugCam, (TLoading, TSavegame, TPhysics, TInput, TSettings)>
 where
 	TLoading: ThreadSafe + HandlesLoadTracking,
 	TSavegame: ThreadSafe + HandlesSaving,
@@ -65,8 +66,16 @@ where
 		+ HandlesRaycast
 		+ HandlesAllPhysicalEffects
 		+ HandlesSkillPhysics,
+	TInput: ThreadSafe + HandlesInput,
+	TSettings: ThreadSafe + HandlesSettings,
 {
-	pub fn from_plugins(_: &TLoading, _: &TSavegame, _: &TPhysics) -> Self {
+	pub fn from_plugins(
+		_: &TLoading,
+		_: &TSavegame,
+		_: &TPhysics,
+		_: &TInput,
+		_: &TSettings,
+	) -> Self {
 		Self {
 			debug_cam: || NoDebugCam,
 			_p: PhantomData,
@@ -74,8 +83,8 @@ where
 	}
 }
 
-impl<TDebugCam, TLoading, TSavegame, TPhysics>
-	GraphicsPlugin<TDebugCam, (TLoading, TSavegame, TPhysics)>
+impl<TDebugCam, TLoading, TSavegame, TPhysics, TInput, TSettings>
+	GraphicsPlugin<TDebugCam, (TLoading, TSavegame, TPhysics, TInput, TSettings)>
 where
 	TDebugCam: Component,
 	TLoading: ThreadSafe + HandlesLoadTracking,
@@ -85,9 +94,18 @@ where
 		+ HandlesRaycast
 		+ HandlesAllPhysicalEffects
 		+ HandlesSkillPhysics,
+	TInput: ThreadSafe + HandlesInput,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	#[cfg(feature = "debug-utils")]
-	pub fn new(debug_cam: fn() -> TDebugCam, _: &TLoading, _: &TSavegame, _: &TPhysics) -> Self {
+	pub fn new(
+		debug_cam: fn() -> TDebugCam,
+		_: &TLoading,
+		_: &TSavegame,
+		_: &TPhysics,
+		_: &TInput,
+		_: &TSettings,
+	) -> Self {
 		Self {
 			debug_cam,
 			_p: PhantomData,
@@ -160,8 +178,12 @@ where
 				Update,
 				(
 					UiPass::process_new_ui_pass.pipe(OnError::log),
-					WorldPass::update_target_ray::<TPhysics::TRaycastMut>.pipe(OnError::log),
+					WorldPass::update_target_ray::<TPhysics::TRaycastMut, TInput::TInput>
+						.pipe(OnError::log),
 					CameraParameters::apply_changes,
+					toggle_bloom::<TSettings::TSettings, WorldPass>,
+					toggle_bloom::<TSettings::TSettings, CompositePass>,
+					CompositePass::toggle_outlines::<TSettings::TSettings>,
 				)
 					.chain()
 					.in_set(GraphicSystems)
@@ -174,8 +196,8 @@ where
 	}
 }
 
-impl<TDebugCam, TLoading, TSavegame, TPhysics> Plugin
-	for GraphicsPlugin<TDebugCam, (TLoading, TSavegame, TPhysics)>
+impl<TDebugCam, TLoading, TSavegame, TPhysics, TInput, TSettings> Plugin
+	for GraphicsPlugin<TDebugCam, (TLoading, TSavegame, TPhysics, TInput, TSettings)>
 where
 	TDebugCam: Component,
 	TLoading: ThreadSafe + HandlesLoadTracking,
@@ -185,6 +207,8 @@ where
 		+ HandlesRaycast
 		+ HandlesAllPhysicalEffects
 		+ HandlesSkillPhysics,
+	TInput: ThreadSafe + HandlesInput,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	fn build(&self, app: &mut App) {
 		Self::track_render_pipeline_ready(app);
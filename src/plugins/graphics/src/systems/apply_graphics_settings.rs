@@ -0,0 +1,204 @@
+use crate::components::{camera_labels::CompositePass, post_process_camera::PostProcessCamera};
+use bevy::{ecs::system::StaticSystemParam, post_process::bloom::Bloom, prelude::*};
+use common::prelude::*;
+
+pub(crate) fn toggle_bloom<TSettings, TCamera>(
+	mut commands: ZyheedaCommands,
+	param: StaticSystemParam<TSettings>,
+	cameras: Query<(Entity, Ref<TCamera>, Has<Bloom>)>,
+) where
+	TSettings: for<'c> TryGetContext<GameSettings, TContext<'c>: GetSettings>,
+	TCamera: Component,
+{
+	let Some(ctx) = TSettings::try_get_context(&param, GameSettings) else {
+		return;
+	};
+	let changed = ctx.context_changed();
+	let post_processing = ctx.settings().post_processing;
+
+	for (entity, camera, has_bloom) in &cameras {
+		if !changed && !camera.is_added() {
+			continue;
+		}
+
+		commands.try_apply_on(&entity, |mut e| match (post_processing, has_bloom) {
+			(true, false) => {
+				e.try_insert(Bloom::default());
+			}
+			(false, true) => {
+				e.try_remove::<Bloom>();
+			}
+			_ => {}
+		});
+	}
+}
+
+impl CompositePass {
+	pub(crate) fn toggle_outlines<TSettings>(
+		param: StaticSystemParam<TSettings>,
+		mut cameras: Query<(Ref<Self>, &mut PostProcessCamera)>,
+	) where
+		TSettings: for<'c> TryGetContext<GameSettings, TContext<'c>: GetSettings>,
+	{
+		let Some(ctx) = TSettings::try_get_context(&param, GameSettings) else {
+			return;
+		};
+		let changed = ctx.context_changed();
+		let post_process = match ctx.settings().outlines {
+			true => PostProcessCamera::from(CompositePass),
+			false => PostProcessCamera::from(CompositePass).with_outline_width(Pixel(0.)),
+		};
+
+		for (pass, mut camera) in &mut cameras {
+			if !changed && !pass.is_added() {
+				continue;
+			}
+
+			*camera = post_process;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use bevy::ecs::system::{SystemParam, SystemParamItem};
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource)]
+	struct _Settings(GeneralSettings);
+
+	impl GetSettings for _Settings {
+		fn settings(&self) -> &GeneralSettings {
+			&self.0
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _Param<'w> {
+		settings: Res<'w, _Settings>,
+	}
+
+	impl TryGetContext<GameSettings> for _Param<'static> {
+		type TContext<'ctx> = &'ctx Res<'ctx, _Settings>;
+
+		fn try_get_context<'ctx>(
+			param: &'ctx SystemParamItem<Self>,
+			_: GameSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(&param.settings)
+		}
+	}
+
+	#[derive(Component)]
+	struct _Camera;
+
+	fn setup(settings: GeneralSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(_Settings(settings));
+		app.add_systems(
+			Update,
+			(
+				toggle_bloom::<_Param, _Camera>,
+				CompositePass::toggle_outlines::<_Param>,
+			),
+		);
+
+		app
+	}
+
+	#[test]
+	fn remove_bloom() {
+		let mut app = setup(GeneralSettings {
+			post_processing: false,
+			..default()
+		});
+		let camera = app.world_mut().spawn((_Camera, Bloom::default())).id();
+
+		app.update();
+
+		assert!(!app.world().entity(camera).contains::<Bloom>());
+	}
+
+	#[test]
+	fn insert_bloom() {
+		let mut app = setup(GeneralSettings {
+			post_processing: true,
+			..default()
+		});
+		let camera = app.world_mut().spawn(_Camera).id();
+
+		app.update();
+
+		assert!(app.world().entity(camera).contains::<Bloom>());
+	}
+
+	#[test]
+	fn toggle_bloom_when_settings_changed() {
+		let mut app = setup(GeneralSettings {
+			post_processing: true,
+			..default()
+		});
+		let camera = app.world_mut().spawn(_Camera).id();
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<_Settings>()
+			.0
+			.post_processing = false;
+		app.update();
+
+		assert!(!app.world().entity(camera).contains::<Bloom>());
+	}
+
+	#[test]
+	fn do_not_toggle_bloom_when_nothing_changed() {
+		let mut app = setup(GeneralSettings {
+			post_processing: true,
+			..default()
+		});
+		let camera = app.world_mut().spawn(_Camera).id();
+
+		app.update();
+		app.world_mut().entity_mut(camera).remove::<Bloom>();
+		app.update();
+
+		assert!(!app.world().entity(camera).contains::<Bloom>());
+	}
+
+	#[test]
+	fn disable_outlines() {
+		let mut app = setup(GeneralSettings {
+			outlines: false,
+			..default()
+		});
+		let camera = app.world_mut().spawn(CompositePass).id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&PostProcessCamera::from(CompositePass).with_outline_width(Pixel(0.))),
+			app.world().entity(camera).get::<PostProcessCamera>(),
+		);
+	}
+
+	#[test]
+	fn enable_outlines_when_settings_changed() {
+		let mut app = setup(GeneralSettings {
+			outlines: false,
+			..default()
+		});
+		let camera = app.world_mut().spawn(CompositePass).id();
+
+		app.update();
+		app.world_mut().resource_mut::<_Settings>().0.outlines = true;
+		app.update();
+
+		assert_eq!(
+			Some(&PostProcessCamera::from(CompositePass)),
+			app.world().entity(camera).get::<PostProcessCamera>(),
+		);
+	}
+}
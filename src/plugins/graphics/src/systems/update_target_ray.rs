@@ -10,24 +10,30 @@ use common::prelude::*;
 use std::fmt::Display;
 
 impl WorldPass {
-	pub(crate) fn update_target_ray<TRaycast>(
+	pub(crate) fn update_target_ray<TRaycast, TInput>(
 		raycast: StaticSystemParam<TRaycast>,
+		input: StaticSystemParam<TInput>,
 		cameras: Query<(Ref<Camera>, Ref<GlobalTransform>), With<Self>>,
 		windows: Query<Ref<Window>>,
+		aiming: Local<bool>,
 	) -> Result<(), WindowError>
 	where
 		TRaycast: for<'w, 's> SystemParam<Item<'w, 's>: UpdateTargetRay>,
+		TInput: for<'w, 's> SystemParam<Item<'w, 's>: GetAimDirection>,
 	{
-		Self::update_target_ray_internal(raycast, cameras, windows)
+		Self::update_target_ray_internal(raycast, input, cameras, windows, aiming)
 	}
 
-	fn update_target_ray_internal<TRaycast, TCamera, TWindow>(
+	fn update_target_ray_internal<TRaycast, TInput, TCamera, TWindow>(
 		mut raycast: StaticSystemParam<TRaycast>,
+		input: StaticSystemParam<TInput>,
 		cameras: Query<(Ref<TCamera>, Ref<GlobalTransform>), With<Self>>,
 		windows: Query<Ref<TWindow>>,
+		mut aiming: Local<bool>,
 	) -> Result<(), WindowError>
 	where
 		TRaycast: for<'w, 's> SystemParam<Item<'w, 's>: UpdateTargetRay>,
+		TInput: for<'w, 's> SystemParam<Item<'w, 's>: GetAimDirection>,
 		TCamera: Component + GetCamRay<TWindow>,
 		TWindow: Component,
 	{
@@ -36,10 +42,25 @@ impl WorldPass {
 			Err(QuerySingleError::MultipleEntities(..)) => return Err(WindowError::Multiple),
 			Ok(window) => window,
 		};
+
+		if let Some(aim) = input.get_aim_direction() {
+			for (camera, transform) in &cameras {
+				let ray = camera.get_aim_ray(&transform, &window, aim);
+				raycast.update_target_ray(ChangedTargetRay(ray));
+			}
+			*aiming = true;
+			return Ok(());
+		}
+
+		let stopped_aiming = std::mem::take(&mut *aiming);
 		let window_is_changed = window.is_changed();
 
 		for (camera, transform) in cameras {
-			if !window_is_changed && !camera.is_changed() && !transform.is_changed() {
+			if !stopped_aiming
+				&& !window_is_changed
+				&& !camera.is_changed()
+				&& !transform.is_changed()
+			{
 				continue;
 			}
 
@@ -92,6 +113,26 @@ mod tests {
 		fn get_ray(&self, camera_transform: &GlobalTransform, window: &_Window) -> Option<Ray3d> {
 			self.mock.get_ray(camera_transform, window)
 		}
+
+		fn get_aim_ray(
+			&self,
+			camera_transform: &GlobalTransform,
+			window: &_Window,
+			aim: Dir2,
+		) -> Option<Ray3d> {
+			self.mock.get_aim_ray(camera_transform, window, aim)
+		}
+	}
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Input {
+		aim: Option<Dir2>,
+	}
+
+	impl GetAimDirection for _Input {
+		fn get_aim_direction(&self) -> Option<Dir2> {
+			self.aim
+		}
 	}
 
 	#[derive(Component, Debug, PartialEq)]
@@ -115,13 +156,18 @@ mod tests {
 		let mut app = App::new().single_threaded(Update);
 
 		app.init_resource::<_WorldCamera>();
+		app.init_resource::<_Input>();
 		app.add_systems(
 			Update,
-			WorldPass::update_target_ray_internal::<ResMut<_WorldCamera>, _Camera, _Window>.pipe(
-				|In(result), mut commands: Commands| {
+			WorldPass::update_target_ray_internal::<
+				ResMut<_WorldCamera>,
+				Res<_Input>,
+				_Camera,
+				_Window,
+			>
+				.pipe(|In(result), mut commands: Commands| {
 					commands.insert_resource(_Result(result));
-				},
-			),
+				}),
 		);
 
 		app
@@ -313,6 +359,80 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn update_aim_ray_each_frame_while_aiming() {
+		let mut app = setup();
+		app.insert_resource(_Input { aim: Some(Dir2::X) });
+		app.world_mut().spawn((
+			WorldPass,
+			_Camera::new().with_mock(|mock| {
+				mock.expect_get_ray().never();
+				mock.expect_get_aim_ray()
+					.times(2)
+					.with(
+						eq(GlobalTransform::from_xyz(1., 2., 3.)),
+						eq(_Window),
+						eq(Dir2::X),
+					)
+					.return_const(Ray3d {
+						origin: Vec3::new(4., 5., 6.),
+						direction: Dir3::NEG_Y,
+					});
+			}),
+			GlobalTransform::from_xyz(1., 2., 3.),
+		));
+		app.world_mut().spawn(_Window);
+
+		app.update();
+		app.insert_resource(_WorldCamera::default());
+		app.update();
+
+		assert_eq!(
+			&_WorldCamera {
+				ray: Some(Ray3d {
+					origin: Vec3::new(4., 5., 6.),
+					direction: Dir3::NEG_Y,
+				}),
+			},
+			app.world().resource::<_WorldCamera>(),
+		);
+	}
+
+	#[test]
+	fn use_cursor_ray_again_when_aiming_stopped() {
+		let mut app = setup();
+		app.insert_resource(_Input { aim: Some(Dir2::X) });
+		app.world_mut().spawn((
+			WorldPass,
+			_Camera::new().with_mock(|mock| {
+				mock.expect_get_ray().return_const(Ray3d {
+					origin: Vec3::new(4., 5., 6.),
+					direction: Dir3::NEG_X,
+				});
+				mock.expect_get_aim_ray().return_const(Ray3d {
+					origin: Vec3::new(4., 5., 6.),
+					direction: Dir3::NEG_Y,
+				});
+			}),
+			GlobalTransform::from_xyz(1., 2., 3.),
+		));
+		app.world_mut().spawn(_Window);
+
+		app.update();
+		app.insert_resource(_Input { aim: None });
+		app.update();
+
+		assert_eq!(
+			&_WorldCamera {
+				ray: Some(Ray3d {
+					origin: Vec3::new(4., 5., 6.),
+					direction: Dir3::NEG_X,
+				}),
+			},
+			app.world().resource::<_WorldCamera>(),
+		);
+	}
+
 	#[test]
 	fn missing_window_error() {
 		let mut app = setup();
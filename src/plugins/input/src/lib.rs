@@ -6,15 +6,21 @@ mod traits;
 
 use crate::{
 	components::action_key_interaction::ActionKeyInteraction,
-	resources::mouse_override::MouseOverride,
+	resources::{
+		active_input_device::ActiveInputDevice,
+		gamepad_state::GamepadState,
+		mouse_override::MouseOverride,
+	},
 	system_params::input::Input,
 };
-use bevy::prelude::*;
-use common::{prelude::*, states::game_state::LoadingEssentialAssets, tools::path::Path};
-use resources::{
-	asset_writer::AssetWriter,
-	key_map::{KeyMap, dto::KeyMapDto as KeyMapDtoGeneric},
+use bevy::{input::InputSystems as BevyInputSystems, prelude::*};
+use common::{
+	prelude::*,
+	resources::asset_writer::AssetWriter,
+	states::game_state::LoadingEssentialAssets,
+	tools::path::Path,
 };
+use resources::key_map::{KeyMap, dto::KeyMapDto as KeyMapDtoGeneric};
 use std::marker::PhantomData;
 use systems::save_changes::SaveChanges;
 
@@ -45,6 +51,14 @@ where
 
 		app.init_resource::<AssetWriter>()
 			.init_resource::<MouseOverride>()
+			.init_resource::<GamepadState>()
+			.init_resource::<ActiveInputDevice>()
+			.add_systems(
+				PreUpdate,
+				(GamepadState::update, ActiveInputDevice::update)
+					.chain()
+					.after(BevyInputSystems),
+			)
 			.add_systems(
 				Update,
 				(
@@ -52,6 +66,7 @@ where
 						.pipe(OnError::log)
 						.run_if(resource_exists::<KeyMap>),
 					(
+						MouseOverride::cancel_on_gamepad,
 						MouseOverride::set_active,
 						MouseOverride::set_input,
 						MouseOverride::update_action_key_interaction,
@@ -1,3 +1,4 @@
-pub(crate) mod asset_writer;
+pub(crate) mod active_input_device;
+pub(crate) mod gamepad_state;
 pub(crate) mod key_map;
 pub(crate) mod mouse_override;
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// The device last used by the player.
+///
+/// Determines which bindings are presented and whether aiming is done via cursor or stick.
+#[derive(Resource, Debug, PartialEq, Default, Clone, Copy)]
+pub(crate) struct ActiveInputDevice(pub(crate) InputDevice);
@@ -0,0 +1,12 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Gamepad input of all connected gamepads, merged into [`UserInput`]s.
+///
+/// Stick directions are tracked like buttons, so they can be bound to actions.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct GamepadState {
+	pub(crate) input: ButtonInput<UserInput>,
+	pub(crate) left_stick: Vec2,
+	pub(crate) right_stick: Vec2,
+}
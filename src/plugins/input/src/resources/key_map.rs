@@ -1,9 +1,11 @@
 pub(crate) mod dto;
+pub(crate) mod gamepad_action;
 
-use crate::traits::drain_invalid_inputs::DrainInvalidInputs;
+use crate::traits::{drain_invalid_inputs::DrainInvalidInputs, get_device_input::GetDeviceInput};
 use bevy::prelude::*;
 use common::prelude::*;
 use dto::KeyMapDto;
+use gamepad_action::GamepadAction;
 use std::{
 	collections::{
 		HashMap,
@@ -16,15 +18,23 @@ use std::{
 };
 use zyheeda_core::prelude::*;
 
-#[derive(Resource, Asset, TypePath, Debug, PartialEq, Clone)]
-pub struct KeyMap(KeyMapInternal);
+#[derive(Resource, Asset, TypePath, Debug, PartialEq, Clone, Default)]
+pub struct KeyMap {
+	keyboard_mouse: KeyMapInternal,
+	gamepad: KeyMapInternal<GamepadAction>,
+}
 
-impl GetInput for KeyMap {
-	fn get_input<TAction>(&self, action: TAction) -> UserInput
+impl GetDeviceInput for KeyMap {
+	fn get_device_input<TAction>(&self, action: TAction, device: InputDevice) -> UserInput
 	where
-		TAction: Into<ActionKey>,
+		TAction: Into<ActionKey> + 'static,
 	{
-		self.0.get_input(action)
+		let action = action.into();
+
+		match device {
+			InputDevice::KeyboardMouse => self.keyboard_mouse.get_input(action),
+			InputDevice::Gamepad => self.gamepad.get_input(GamepadAction(action)),
+		}
 	}
 }
 
@@ -33,7 +43,12 @@ impl UpdateKey for KeyMap {
 	where
 		TAction: Copy + Into<ActionKey>,
 	{
-		self.0.update_key(action, input);
+		let action = action.into();
+
+		match input.device() {
+			InputDevice::KeyboardMouse => self.keyboard_mouse.update_key(action, input),
+			InputDevice::Gamepad => self.gamepad.update_key(GamepadAction(action), input),
+		}
 	}
 }
 
@@ -41,13 +56,29 @@ impl TryLoadFrom<KeyMapDto<ActionKey>> for KeyMap {
 	type TInstantiationError = LoadError<ActionKey>;
 
 	fn try_load_from<TLoadAsset>(
-		dto: KeyMapDto<ActionKey>,
+		KeyMapDto { actions }: KeyMapDto<ActionKey>,
 		asset_server: &mut TLoadAsset,
 	) -> Result<Self, Self::TInstantiationError>
 	where
 		TLoadAsset: LoadAsset,
 	{
-		KeyMapInternal::try_load_from(dto, asset_server).map(KeyMap)
+		let (keyboard_mouse, gamepad): (Vec<_>, Vec<_>) = actions
+			.into_iter()
+			.partition(|(_, input)| input.device() == InputDevice::KeyboardMouse);
+		let gamepad = gamepad
+			.into_iter()
+			.map(|(action, input)| (GamepadAction(action), input))
+			.collect();
+
+		Ok(Self {
+			keyboard_mouse: KeyMapInternal::try_load_from(
+				KeyMapDto {
+					actions: keyboard_mouse,
+				},
+				asset_server,
+			)?,
+			gamepad: KeyMapInternal::try_load_from(KeyMapDto { actions: gamepad }, asset_server)?,
+		})
 	}
 }
 
@@ -55,7 +86,14 @@ impl DrainInvalidInputs for KeyMap {
 	type TInvalidInput = (ActionKey, HashSet<UserInput>);
 
 	fn drain_invalid_inputs(&mut self) -> impl Iterator<Item = Self::TInvalidInput> {
-		self.0.invalid_inputs.0.drain()
+		let gamepad = self
+			.gamepad
+			.invalid_inputs
+			.0
+			.drain()
+			.map(|(GamepadAction(action), inputs)| (action, inputs));
+
+		self.keyboard_mouse.invalid_inputs.0.drain().chain(gamepad)
 	}
 }
 
@@ -375,6 +413,27 @@ where
 	}
 }
 
+impl From<LoadError<GamepadAction>> for LoadError<ActionKey> {
+	fn from(error: LoadError<GamepadAction>) -> Self {
+		match error {
+			LoadError::RepeatedInputs(repeated) => Self::RepeatedInputs(
+				repeated
+					.into_iter()
+					.map(|(input, actions)| {
+						(
+							input,
+							actions.into_iter().map(|GamepadAction(a)| a).collect(),
+						)
+					})
+					.collect(),
+			),
+			LoadError::MissingInputs(missing) => {
+				Self::MissingInputs(missing.into_iter().map(|GamepadAction(a)| a).collect())
+			}
+		}
+	}
+}
+
 impl<TAction> StdError for LoadError<TAction> where
 	TAction: Debug + Eq + Hash + TypePath + InvalidUserInput
 {
@@ -385,7 +444,7 @@ impl<'a> Iterate<'a> for KeyMap {
 	type TIter = Iter<'a, ActionKey, UserInput>;
 
 	fn iterate(&'a self) -> Self::TIter {
-		self.0.action_to_input.iter()
+		self.keyboard_mouse.action_to_input.iter()
 	}
 }
 
@@ -577,6 +636,58 @@ mod tests {
 		}
 	}
 
+	mod device_layers {
+		use super::*;
+
+		#[test]
+		fn get_input_per_device() {
+			let map = KeyMap::default();
+			let action = ActionKey::from(HandSlot::Left);
+
+			assert_eq!(
+				(UserInput::from(action), action.default_gamepad_input()),
+				(
+					map.get_device_input(action, InputDevice::KeyboardMouse),
+					map.get_device_input(action, InputDevice::Gamepad),
+				)
+			);
+		}
+
+		#[test]
+		fn update_gamepad_layer_with_gamepad_input() {
+			let mut map = KeyMap::default();
+			let action = ActionKey::from(HandSlot::Left);
+			let input = UserInput::GamepadButton(GamepadButton::Mode);
+
+			map.update_key(action, input);
+
+			assert_eq!(
+				(UserInput::from(action), input),
+				(
+					map.get_device_input(action, InputDevice::KeyboardMouse),
+					map.get_device_input(action, InputDevice::Gamepad),
+				)
+			);
+		}
+
+		#[test]
+		fn update_keyboard_mouse_layer_with_key() {
+			let mut map = KeyMap::default();
+			let action = ActionKey::from(HandSlot::Left);
+			let input = UserInput::KeyCode(KeyCode::F35);
+
+			map.update_key(action, input);
+
+			assert_eq!(
+				(input, action.default_gamepad_input()),
+				(
+					map.get_device_input(action, InputDevice::KeyboardMouse),
+					map.get_device_input(action, InputDevice::Gamepad),
+				)
+			);
+		}
+	}
+
 	mod try_load {
 		use super::*;
 		use bevy::asset::AssetPath;
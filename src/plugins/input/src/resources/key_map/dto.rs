@@ -1,4 +1,4 @@
-use super::KeyMap;
+use super::{KeyMap, gamepad_action::GamepadAction};
 use bevy::reflect::TypePath;
 use common::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -30,11 +30,23 @@ impl AssetFileExtensions for KeyMapDto<ActionKey> {
 }
 
 impl From<KeyMap> for KeyMapDto<ActionKey> {
-	fn from(KeyMap(map): KeyMap) -> Self {
+	fn from(
+		KeyMap {
+			keyboard_mouse,
+			gamepad,
+		}: KeyMap,
+	) -> Self {
+		let keyboard_mouse = ActionKey::iterator()
+			.filter_map(|action| Some((action, *keyboard_mouse.action_to_input.get(&action)?)));
+		let gamepad = ActionKey::iterator().filter_map(|action| {
+			Some((
+				action,
+				*gamepad.action_to_input.get(&GamepadAction(action))?,
+			))
+		});
+
 		Self {
-			actions: ActionKey::iterator()
-				.filter_map(|action| Some((action, *map.action_to_input.get(&action)?)))
-				.collect::<Vec<_>>(),
+			actions: keyboard_mouse.chain(gamepad).collect::<Vec<_>>(),
 		}
 	}
 }
@@ -42,20 +54,23 @@ impl From<KeyMap> for KeyMapDto<ActionKey> {
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::resources::key_map::KeyMapInternal;
-	use bevy::input::keyboard::KeyCode;
+	use bevy::input::{gamepad::GamepadButton, keyboard::KeyCode};
 	use testing::repeat_scope;
 
 	#[test]
 	fn convert_to_dto_with_key_standard_ordering() {
 		repeat_scope!(10, {
-			let map = KeyMap(KeyMapInternal::default());
+			let map = KeyMap::default();
 
 			let dto = KeyMapDto::from(map);
 
 			assert_eq!(
 				ActionKey::iterator()
 					.map(|action| (action, UserInput::from(action)))
+					.chain(
+						ActionKey::iterator()
+							.map(|action| (action, action.default_gamepad_input()))
+					)
 					.collect::<Vec<_>>(),
 				dto.actions,
 			);
@@ -65,7 +80,7 @@ mod tests {
 	#[test]
 	fn convert_to_dto_with_key_standard_ordering_with_overrides() {
 		repeat_scope!(10, {
-			let mut map = KeyMap(KeyMapInternal::default());
+			let mut map = KeyMap::default();
 			map.update_key(
 				ActionKey::Camera(CameraKey::Rotate),
 				UserInput::KeyCode(KeyCode::F35),
@@ -83,6 +98,38 @@ mod tests {
 							UserInput::KeyCode(KeyCode::F35)
 						}
 					))
+					.chain(
+						ActionKey::iterator()
+							.map(|action| (action, action.default_gamepad_input()))
+					)
+					.collect::<Vec<_>>(),
+				dto.actions,
+			);
+		});
+	}
+
+	#[test]
+	fn convert_to_dto_with_gamepad_overrides() {
+		repeat_scope!(10, {
+			let mut map = KeyMap::default();
+			map.update_key(
+				ActionKey::Camera(CameraKey::Rotate),
+				UserInput::GamepadButton(GamepadButton::Mode),
+			);
+
+			let dto = KeyMapDto::from(map);
+
+			assert_eq!(
+				ActionKey::iterator()
+					.map(|action| (action, UserInput::from(action)))
+					.chain(ActionKey::iterator().map(|action| (
+						action,
+						if action != ActionKey::Camera(CameraKey::Rotate) {
+							action.default_gamepad_input()
+						} else {
+							UserInput::GamepadButton(GamepadButton::Mode)
+						}
+					)))
 					.collect::<Vec<_>>(),
 				dto.actions,
 			);
@@ -0,0 +1,58 @@
+use bevy::reflect::TypePath;
+use common::prelude::*;
+
+/// Wraps an [`ActionKey`] to address its binding on the gamepad layer of the key map.
+#[derive(TypePath, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub(crate) struct GamepadAction(pub(crate) ActionKey);
+
+impl From<ActionKey> for GamepadAction {
+	fn from(action: ActionKey) -> Self {
+		Self(action)
+	}
+}
+
+impl From<GamepadAction> for UserInput {
+	fn from(GamepadAction(action): GamepadAction) -> Self {
+		action.default_gamepad_input()
+	}
+}
+
+impl InvalidUserInput for GamepadAction {
+	fn invalid_input(&self) -> &[UserInput] {
+		self.0.invalid_input()
+	}
+}
+
+impl IterFinite for GamepadAction {
+	fn iterator() -> FiniteIter<Self> {
+		FiniteIter(ActionKey::iterator().0.map(Self))
+	}
+
+	fn next(FiniteIter(current): &FiniteIter<Self>) -> Option<Self> {
+		let GamepadAction(action) = (*current)?;
+		ActionKey::next(&FiniteIter(Some(action))).map(Self)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn iterate_all_action_keys() {
+		assert_eq!(
+			ActionKey::iterator().map(GamepadAction).collect::<Vec<_>>(),
+			GamepadAction::iterator().collect::<Vec<_>>(),
+		);
+	}
+
+	#[test]
+	fn use_default_gamepad_input() {
+		let action = ActionKey::from(HandSlot::Left);
+
+		assert_eq!(
+			action.default_gamepad_input(),
+			UserInput::from(GamepadAction(action)),
+		);
+	}
+}
@@ -1,3 +1,4 @@
+mod get_aim_direction;
 mod get_all_input_states;
 mod get_all_inputs;
 mod get_input;
@@ -6,7 +7,11 @@ mod get_raw_user_input;
 mod input_setup_changed;
 mod update_key;
 
-use crate::resources::mouse_override::MouseOverride;
+use crate::resources::{
+	active_input_device::ActiveInputDevice,
+	gamepad_state::GamepadState,
+	mouse_override::MouseOverride,
+};
 use bevy::{
 	ecs::system::{StaticSystemParam, SystemParam},
 	prelude::*,
@@ -19,6 +24,8 @@ where
 {
 	keys: Res<'w, ButtonInput<KeyCode>>,
 	mouse: Res<'w, ButtonInput<MouseButton>>,
+	gamepad: Res<'w, GamepadState>,
+	device: Res<'w, ActiveInputDevice>,
 	mouse_override: Res<'w, MouseOverride>,
 	key_map: StaticSystemParam<'w, 's, TKeyMap>,
 }
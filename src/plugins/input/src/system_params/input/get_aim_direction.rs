@@ -0,0 +1,93 @@
+use crate::system_params::input::Input;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use common::prelude::*;
+
+/// Stick tilt below this length is treated as noise and does not aim.
+const AIM_DEADZONE: f32 = 0.2;
+
+impl<TKeyMap> GetAimDirection for Input<'_, '_, TKeyMap>
+where
+	TKeyMap: SystemParam + 'static,
+{
+	fn get_aim_direction(&self) -> Option<Dir2> {
+		if self.device.0 != InputDevice::Gamepad {
+			return None;
+		}
+
+		let aim = self.gamepad.right_stick;
+		if aim.length() < AIM_DEADZONE {
+			return None;
+		}
+
+		Dir2::new(aim).ok()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::resources::{
+		active_input_device::ActiveInputDevice,
+		gamepad_state::GamepadState,
+		mouse_override::MouseOverride,
+	};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource)]
+	struct _Map;
+
+	type _Input<'w, 's> = Input<'w, 's, Res<'static, _Map>>;
+
+	fn setup(device: InputDevice, right_stick: Vec2) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(_Map);
+		app.init_resource::<MouseOverride>();
+		app.init_resource::<ButtonInput<KeyCode>>();
+		app.init_resource::<ButtonInput<MouseButton>>();
+		app.insert_resource(GamepadState {
+			right_stick,
+			..default()
+		});
+		app.insert_resource(ActiveInputDevice(device));
+
+		app
+	}
+
+	#[test]
+	fn get_right_stick_direction() -> Result<(), RunSystemError> {
+		let mut app = setup(InputDevice::Gamepad, Vec2::new(0., 0.5));
+
+		let aim = app
+			.world_mut()
+			.run_system_once(|input: _Input| input.get_aim_direction())?;
+
+		assert_eq!(Some(Dir2::Y), aim);
+		Ok(())
+	}
+
+	#[test]
+	fn no_direction_within_deadzone() -> Result<(), RunSystemError> {
+		let mut app = setup(InputDevice::Gamepad, Vec2::new(0., 0.1));
+
+		let aim = app
+			.world_mut()
+			.run_system_once(|input: _Input| input.get_aim_direction())?;
+
+		assert_eq!(None, aim);
+		Ok(())
+	}
+
+	#[test]
+	fn no_direction_when_using_keyboard_mouse() -> Result<(), RunSystemError> {
+		let mut app = setup(InputDevice::KeyboardMouse, Vec2::new(0., 0.5));
+
+		let aim = app
+			.world_mut()
+			.run_system_once(|input: _Input| input.get_aim_direction())?;
+
+		assert_eq!(None, aim);
+		Ok(())
+	}
+}
@@ -1,29 +1,37 @@
-use crate::system_params::input::Input;
+use crate::{system_params::input::Input, traits::get_device_input::GetDeviceInput};
 use bevy::ecs::system::SystemParam;
 use common::prelude::*;
 
 impl<'w, 's, TKeyMap> GetInput for Input<'w, 's, TKeyMap>
 where
-	TKeyMap: SystemParam<Item<'w, 's>: GetInput> + 'static,
+	TKeyMap: SystemParam<Item<'w, 's>: GetDeviceInput> + 'static,
 {
 	fn get_input<TAction>(&self, action: TAction) -> UserInput
 	where
 		TAction: Into<ActionKey> + 'static,
 	{
-		self.key_map.get_input(action)
+		self.key_map.get_device_input(action, self.device.0)
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::resources::mouse_override::MouseOverride;
+	use crate::resources::{
+		active_input_device::ActiveInputDevice,
+		gamepad_state::GamepadState,
+		mouse_override::MouseOverride,
+	};
 	use bevy::{
 		ecs::system::{RunSystemError, RunSystemOnce},
 		prelude::*,
 	};
 	use macros::NestedMocks;
-	use mockall::automock;
+	use mockall::{
+		automock,
+		predicate::{always, eq},
+	};
+	use test_case::test_case;
 	use testing::{NestedMocks, SingleThreadedApp};
 
 	#[derive(Resource, NestedMocks)]
@@ -32,12 +40,12 @@ mod tests {
 	}
 
 	#[automock]
-	impl GetInput for _Map {
-		fn get_input<TAction>(&self, action: TAction) -> UserInput
+	impl GetDeviceInput for _Map {
+		fn get_device_input<TAction>(&self, action: TAction, device: InputDevice) -> UserInput
 		where
 			TAction: Into<ActionKey> + 'static,
 		{
-			self.mock.get_input(action)
+			self.mock.get_device_input(action, device)
 		}
 	}
 
@@ -58,16 +66,21 @@ mod tests {
 		app.init_resource::<MouseOverride>();
 		app.init_resource::<ButtonInput<KeyCode>>();
 		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<GamepadState>();
+		app.init_resource::<ActiveInputDevice>();
 
 		app
 	}
 
-	#[test]
-	fn get_input() -> Result<(), RunSystemError> {
+	#[test_case(InputDevice::KeyboardMouse; "keyboard mouse")]
+	#[test_case(InputDevice::Gamepad; "gamepad")]
+	fn get_input(device: InputDevice) -> Result<(), RunSystemError> {
 		let mut app = setup(_Map::new().with_mock(|mock| {
-			mock.expect_get_input::<_Action>()
+			mock.expect_get_device_input::<_Action>()
+				.with(always(), eq(device))
 				.return_const(UserInput::KeyCode(KeyCode::ArrowUp));
 		}));
+		app.insert_resource(ActiveInputDevice(device));
 
 		let user_input = app
 			.world_mut()
@@ -1,11 +1,15 @@
-use crate::{resources::mouse_override::MouseOverride, system_params::input::Input};
+use crate::{
+	resources::mouse_override::MouseOverride,
+	system_params::input::Input,
+	traits::get_device_input::GetDeviceInput,
+};
 use bevy::{ecs::system::SystemParam, input::ButtonInput, prelude::*};
 use common::prelude::*;
 use std::{hash::Hash, ops::Deref};
 
 impl<'w, 's, TKeyMap> GetInputState for Input<'w, 's, TKeyMap>
 where
-	TKeyMap: SystemParam<Item<'w, 's>: GetInput> + 'static,
+	TKeyMap: SystemParam<Item<'w, 's>: GetDeviceInput> + 'static,
 {
 	fn get_input_state<TAction>(&self, action: TAction) -> InputState
 	where
@@ -17,7 +21,21 @@ where
 			return input_state;
 		}
 
-		let input = self.key_map.get_input(action_key);
+		let keyboard_mouse = self.keyboard_mouse_state(action_key);
+		let gamepad = self.gamepad_state(action_key);
+
+		strongest(keyboard_mouse, gamepad)
+	}
+}
+
+impl<'w, 's, TKeyMap> Input<'w, 's, TKeyMap>
+where
+	TKeyMap: SystemParam<Item<'w, 's>: GetDeviceInput> + 'static,
+{
+	fn keyboard_mouse_state(&self, action_key: ActionKey) -> InputState {
+		let input = self
+			.key_map
+			.get_device_input(action_key, InputDevice::KeyboardMouse);
 
 		if input == LEFT_MOUSE && self.mouse_override_active() {
 			return InputState::released();
@@ -26,8 +44,19 @@ where
 		match input {
 			UserInput::KeyCode(key_code) => get_bevy_input_state(&self.keys, key_code),
 			UserInput::MouseButton(mouse_button) => get_bevy_input_state(&self.mouse, mouse_button),
+			UserInput::GamepadButton(_) | UserInput::GamepadStick(..) => {
+				get_bevy_input_state(&self.gamepad.input, input)
+			}
 		}
 	}
+
+	fn gamepad_state(&self, action_key: ActionKey) -> InputState {
+		let input = self
+			.key_map
+			.get_device_input(action_key, InputDevice::Gamepad);
+
+		get_bevy_input_state(&self.gamepad.input, input)
+	}
 }
 
 impl<'w, 's, TKeyMap> Input<'w, 's, TKeyMap>
@@ -58,7 +87,19 @@ where
 
 const LEFT_MOUSE: UserInput = UserInput::MouseButton(MouseButton::Left);
 
-fn get_bevy_input_state<T>(input: &Res<ButtonInput<T>>, button: T) -> InputState
+/// Picks the state that carries the most activity, so either device can trigger an action.
+fn strongest(a: InputState, b: InputState) -> InputState {
+	[
+		InputState::just_pressed(),
+		InputState::pressed(),
+		InputState::just_released(),
+	]
+	.into_iter()
+	.find(|state| a == *state || b == *state)
+	.unwrap_or(InputState::released())
+}
+
+fn get_bevy_input_state<T>(input: &ButtonInput<T>, button: T) -> InputState
 where
 	T: Copy + Eq + Hash + ThreadSafe,
 {
@@ -80,6 +121,7 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::resources::{active_input_device::ActiveInputDevice, gamepad_state::GamepadState};
 	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
 	use macros::NestedMocks;
 	use mockall::automock;
@@ -92,12 +134,12 @@ mod tests {
 	}
 
 	#[automock]
-	impl GetInput for _Map {
-		fn get_input<TAction>(&self, action: TAction) -> UserInput
+	impl GetDeviceInput for _Map {
+		fn get_device_input<TAction>(&self, action: TAction, device: InputDevice) -> UserInput
 		where
 			TAction: Into<ActionKey> + 'static,
 		{
-			self.mock.get_input(action)
+			self.mock.get_device_input(action, device)
 		}
 	}
 
@@ -119,6 +161,8 @@ mod tests {
 		app.init_resource::<MouseOverride>();
 		app.init_resource::<ButtonInput<KeyCode>>();
 		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<GamepadState>();
+		app.init_resource::<ActiveInputDevice>();
 
 		app
 	}
@@ -130,7 +174,7 @@ mod tests {
 		TInput: Into<UserInput> + Copy + Eq + Hash + ThreadSafe,
 	{
 		let mut app = setup(_Map::new().with_mock(|mock| {
-			mock.expect_get_input::<ActionKey>()
+			mock.expect_get_device_input::<ActionKey>()
 				.return_const(user_input.into());
 		}));
 		set_input!(app, pressed(user_input));
@@ -150,7 +194,7 @@ mod tests {
 		TInput: Into<UserInput> + Copy + Eq + Hash + ThreadSafe,
 	{
 		let mut app = setup(_Map::new().with_mock(|mock| {
-			mock.expect_get_input::<ActionKey>()
+			mock.expect_get_device_input::<ActionKey>()
 				.return_const(user_input.into());
 		}));
 		set_input!(app, just_pressed(user_input));
@@ -170,7 +214,7 @@ mod tests {
 		TInput: Into<UserInput> + Copy + Eq + Hash + ThreadSafe,
 	{
 		let mut app = setup(_Map::new().with_mock(|mock| {
-			mock.expect_get_input::<ActionKey>()
+			mock.expect_get_device_input::<ActionKey>()
 				.return_const(user_input.into());
 		}));
 		set_input!(app, released(user_input));
@@ -190,7 +234,7 @@ mod tests {
 		TInput: Into<UserInput> + Copy + Eq + Hash + ThreadSafe,
 	{
 		let mut app = setup(_Map::new().with_mock(|mock| {
-			mock.expect_get_input::<ActionKey>()
+			mock.expect_get_device_input::<ActionKey>()
 				.return_const(user_input.into());
 		}));
 		set_input!(app, just_released(user_input));
@@ -203,6 +247,87 @@ mod tests {
 		Ok(())
 	}
 
+	mod gamepad {
+		use super::*;
+		use mockall::predicate::{always, eq};
+
+		const SOUTH: UserInput = UserInput::GamepadButton(GamepadButton::South);
+
+		fn setup_gamepad_binding() -> App {
+			setup(_Map::new().with_mock(|mock| {
+				mock.expect_get_device_input::<ActionKey>()
+					.with(always(), eq(InputDevice::KeyboardMouse))
+					.return_const(UserInput::KeyCode(KeyCode::KeyA));
+				mock.expect_get_device_input::<ActionKey>()
+					.with(always(), eq(InputDevice::Gamepad))
+					.return_const(SOUTH);
+			}))
+		}
+
+		#[test]
+		fn get_just_pressed() -> Result<(), RunSystemError> {
+			let mut app = setup_gamepad_binding();
+			app.world_mut()
+				.resource_mut::<GamepadState>()
+				.input
+				.press(SOUTH);
+
+			let state = app
+				.world_mut()
+				.run_system_once(|input: _Input| input.get_input_state(_Action::default()))?;
+
+			assert_eq!(InputState::just_pressed(), state);
+			Ok(())
+		}
+
+		#[test]
+		fn get_pressed() -> Result<(), RunSystemError> {
+			let mut app = setup_gamepad_binding();
+			let mut gamepad = app.world_mut().resource_mut::<GamepadState>();
+			gamepad.input.press(SOUTH);
+			gamepad.input.clear();
+
+			let state = app
+				.world_mut()
+				.run_system_once(|input: _Input| input.get_input_state(_Action::default()))?;
+
+			assert_eq!(InputState::pressed(), state);
+			Ok(())
+		}
+
+		#[test]
+		fn prefer_pressed_gamepad_over_just_released_key() -> Result<(), RunSystemError> {
+			let mut app = setup_gamepad_binding();
+			let mut gamepad = app.world_mut().resource_mut::<GamepadState>();
+			gamepad.input.press(SOUTH);
+			gamepad.input.clear();
+			set_input!(app, just_released(KeyCode::KeyA));
+
+			let state = app
+				.world_mut()
+				.run_system_once(|input: _Input| input.get_input_state(_Action::default()))?;
+
+			assert_eq!(InputState::pressed(), state);
+			Ok(())
+		}
+
+		#[test]
+		fn prefer_just_pressed_key_over_pressed_gamepad() -> Result<(), RunSystemError> {
+			let mut app = setup_gamepad_binding();
+			let mut gamepad = app.world_mut().resource_mut::<GamepadState>();
+			gamepad.input.press(SOUTH);
+			gamepad.input.clear();
+			set_input!(app, just_pressed(KeyCode::KeyA));
+
+			let state = app
+				.world_mut()
+				.run_system_once(|input: _Input| input.get_input_state(_Action::default()))?;
+
+			assert_eq!(InputState::just_pressed(), state);
+			Ok(())
+		}
+	}
+
 	mod mouse_override {
 		use super::*;
 		use test_case::test_case;
@@ -213,7 +338,7 @@ mod tests {
 		#[test]
 		fn ignore_left_mouse_button_when_mouse_override_active() -> Result<(), RunSystemError> {
 			let mut app = setup(_Map::new().with_mock(|mock| {
-				mock.expect_get_input::<ActionKey>()
+				mock.expect_get_device_input::<ActionKey>()
 					.return_const(LEFT_MOUSE);
 			}));
 			app.insert_resource(MouseOverride::Active {
@@ -234,7 +359,7 @@ mod tests {
 		#[test]
 		fn use_left_mouse_button_when_mouse_override_idle() -> Result<(), RunSystemError> {
 			let mut app = setup(_Map::new().with_mock(|mock| {
-				mock.expect_get_input::<ActionKey>()
+				mock.expect_get_device_input::<ActionKey>()
 					.return_const(LEFT_MOUSE);
 			}));
 			app.insert_resource(MouseOverride::Idle);
@@ -254,7 +379,7 @@ mod tests {
 		fn return_override_key_input_state(input_state: InputState) -> Result<(), RunSystemError> {
 			let action = ActionKey::from(HandSlot::Left);
 			let mut app = setup(_Map::new().with_mock(|mock| {
-				mock.expect_get_input::<ActionKey>().never();
+				mock.expect_get_device_input::<ActionKey>().never();
 			}));
 			app.insert_resource(MouseOverride::Active {
 				panel: fake_entity!(123),
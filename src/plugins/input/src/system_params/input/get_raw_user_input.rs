@@ -10,14 +10,17 @@ where
 		let just_pressed = || Iter {
 			keys: Some(self.keys.get_just_pressed().copied()),
 			mouse: Some(self.mouse.get_just_pressed().copied()),
+			gamepad: Some(self.gamepad.input.get_just_pressed().copied()),
 		};
 		let held = || Iter {
 			keys: Some(self.keys.get_pressed().copied()),
 			mouse: Some(self.mouse.get_pressed().copied()),
+			gamepad: Some(self.gamepad.input.get_pressed().copied()),
 		};
 		let just_released = || Iter {
 			keys: Some(self.keys.get_just_released().copied()),
 			mouse: Some(self.mouse.get_just_released().copied()),
+			gamepad: Some(self.gamepad.input.get_just_released().copied()),
 		};
 
 		match state {
@@ -28,19 +31,22 @@ where
 	}
 }
 
-struct Iter<TKeys, TMouse> {
+struct Iter<TKeys, TMouse, TGamepad> {
 	keys: Option<TKeys>,
 	mouse: Option<TMouse>,
+	gamepad: Option<TGamepad>,
 }
 
-impl<TKeys, TMouse> Iter<TKeys, TMouse>
+impl<TKeys, TMouse, TGamepad> Iter<TKeys, TMouse, TGamepad>
 where
 	TKeys: Iterator<Item = KeyCode>,
 	TMouse: Iterator<Item = MouseButton>,
+	TGamepad: Iterator<Item = UserInput>,
 {
 	const EMPTY: Self = Self {
 		keys: None,
 		mouse: None,
+		gamepad: None,
 	};
 
 	fn next_key(&mut self) -> Option<UserInput> {
@@ -56,23 +62,35 @@ where
 			.and_then(Iterator::next)
 			.map(UserInput::MouseButton)
 	}
+
+	fn next_gamepad(&mut self) -> Option<UserInput> {
+		self.gamepad.as_mut().and_then(Iterator::next)
+	}
 }
 
-impl<TKeys: Iterator<Item = KeyCode>, TMouse: Iterator<Item = MouseButton>> Iterator
-	for Iter<TKeys, TMouse>
+impl<TKeys, TMouse, TGamepad> Iterator for Iter<TKeys, TMouse, TGamepad>
+where
+	TKeys: Iterator<Item = KeyCode>,
+	TMouse: Iterator<Item = MouseButton>,
+	TGamepad: Iterator<Item = UserInput>,
 {
 	type Item = UserInput;
 
 	fn next(&mut self) -> Option<Self::Item> {
-		self.next_key().or_else(|| self.next_mouse())
+		self.next_key()
+			.or_else(|| self.next_mouse())
+			.or_else(|| self.next_gamepad())
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::resources::mouse_override::MouseOverride;
-
 	use super::*;
+	use crate::resources::{
+		active_input_device::ActiveInputDevice,
+		gamepad_state::GamepadState,
+		mouse_override::MouseOverride,
+	};
 	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
 	use testing::{SingleThreadedApp, assert_eq_unordered, set_input};
 
@@ -88,6 +106,8 @@ mod tests {
 		app.init_resource::<MouseOverride>();
 		app.init_resource::<ButtonInput<KeyCode>>();
 		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<GamepadState>();
+		app.init_resource::<ActiveInputDevice>();
 
 		app
 	}
@@ -165,4 +185,26 @@ mod tests {
 		);
 		Ok(())
 	}
+
+	#[test]
+	fn get_gamepad_just_pressed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let mut gamepad = app.world_mut().resource_mut::<GamepadState>();
+		gamepad
+			.input
+			.press(UserInput::GamepadButton(GamepadButton::North));
+		gamepad.input.clear();
+		gamepad
+			.input
+			.press(UserInput::GamepadButton(GamepadButton::South));
+
+		let input = app.world_mut().run_system_once(|input: _Input| {
+			input
+				.get_raw_user_input(RawInputState::JustPressed)
+				.collect::<Vec<_>>()
+		})?;
+
+		assert_eq!(vec![UserInput::GamepadButton(GamepadButton::South)], input);
+		Ok(())
+	}
 }
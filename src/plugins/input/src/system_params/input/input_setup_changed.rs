@@ -7,7 +7,7 @@ where
 	TKeyMap: Resource,
 {
 	fn input_setup_changed(&self) -> bool {
-		self.key_map.is_changed()
+		self.key_map.is_changed() || self.device.is_changed()
 	}
 }
 
@@ -16,15 +16,18 @@ where
 	TKeyMap: Resource<Mutability = Mutable>,
 {
 	fn input_setup_changed(&self) -> bool {
-		self.key_map.is_changed()
+		self.key_map.is_changed() || self.device.is_changed()
 	}
 }
 
 #[cfg(test)]
 mod tests {
-	use crate::resources::mouse_override::MouseOverride;
-
 	use super::*;
+	use crate::resources::{
+		active_input_device::ActiveInputDevice,
+		gamepad_state::GamepadState,
+		mouse_override::MouseOverride,
+	};
 	use testing::SingleThreadedApp;
 
 	#[derive(Resource)]
@@ -41,6 +44,8 @@ mod tests {
 		app.init_resource::<MouseOverride>();
 		app.init_resource::<ButtonInput<KeyCode>>();
 		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<GamepadState>();
+		app.init_resource::<ActiveInputDevice>();
 
 		app
 	}
@@ -67,6 +72,18 @@ mod tests {
 			assert_eq!(&_Changed(true), app.world().resource::<_Changed>());
 		}
 
+		#[test]
+		fn device_changed() {
+			let mut app = setup();
+			app.add_systems(Update, detect_change);
+			app.update();
+
+			app.insert_resource(ActiveInputDevice(InputDevice::Gamepad));
+			app.update();
+
+			assert_eq!(&_Changed(true), app.world().resource::<_Changed>());
+		}
+
 		#[test]
 		fn unchanged() {
 			let mut app = setup();
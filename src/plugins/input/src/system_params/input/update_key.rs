@@ -17,7 +17,11 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::resources::mouse_override::MouseOverride;
+	use crate::resources::{
+		active_input_device::ActiveInputDevice,
+		gamepad_state::GamepadState,
+		mouse_override::MouseOverride,
+	};
 	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
 	use macros::NestedMocks;
 	use mockall::{automock, predicate::eq};
@@ -56,6 +60,8 @@ mod tests {
 		app.init_resource::<MouseOverride>();
 		app.init_resource::<ButtonInput<KeyCode>>();
 		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<GamepadState>();
+		app.init_resource::<ActiveInputDevice>();
 
 		app
 	}
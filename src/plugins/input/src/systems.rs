@@ -1,2 +1,4 @@
 pub(crate) mod mouse_override;
 pub(crate) mod save_changes;
+pub(crate) mod update_active_input_device;
+pub(crate) mod update_gamepad_state;
@@ -1,3 +1,4 @@
+pub(crate) mod cancel_on_gamepad;
 pub(crate) mod set_active;
 pub(crate) mod set_input;
 pub(crate) mod update_action_key_interaction;
@@ -0,0 +1,87 @@
+use crate::resources::{active_input_device::ActiveInputDevice, mouse_override::MouseOverride};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl MouseOverride {
+	/// The override is triggered via mouse, so it is dropped once the player switches to a gamepad.
+	pub(crate) fn cancel_on_gamepad(
+		device: Res<ActiveInputDevice>,
+		mut mouse_override: ResMut<Self>,
+	) {
+		if !device.is_changed() || device.0 != InputDevice::Gamepad {
+			return;
+		}
+
+		mouse_override.set_if_neq(Self::Idle);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{SingleThreadedApp, fake_entity};
+
+	fn setup(device: InputDevice) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(ActiveInputDevice(device));
+		app.insert_resource(MouseOverride::Active {
+			panel: fake_entity!(42),
+			action: ActionKey::from(HandSlot::Left),
+			input_state: None,
+		});
+		app.add_systems(Update, MouseOverride::cancel_on_gamepad);
+
+		app
+	}
+
+	#[test]
+	fn cancel_when_switched_to_gamepad() {
+		let mut app = setup(InputDevice::Gamepad);
+
+		app.update();
+
+		assert_eq!(
+			&MouseOverride::Idle,
+			app.world().resource::<MouseOverride>()
+		);
+	}
+
+	#[test]
+	fn keep_when_using_keyboard_mouse() {
+		let mut app = setup(InputDevice::KeyboardMouse);
+
+		app.update();
+
+		assert_eq!(
+			&MouseOverride::Active {
+				panel: fake_entity!(42),
+				action: ActionKey::from(HandSlot::Left),
+				input_state: None,
+			},
+			app.world().resource::<MouseOverride>()
+		);
+	}
+
+	#[test]
+	fn keep_when_device_unchanged() {
+		let mut app = setup(InputDevice::Gamepad);
+
+		app.update();
+		app.insert_resource(MouseOverride::Active {
+			panel: fake_entity!(42),
+			action: ActionKey::from(HandSlot::Left),
+			input_state: None,
+		});
+		app.update();
+
+		assert_eq!(
+			&MouseOverride::Active {
+				panel: fake_entity!(42),
+				action: ActionKey::from(HandSlot::Left),
+				input_state: None,
+			},
+			app.world().resource::<MouseOverride>()
+		);
+	}
+}
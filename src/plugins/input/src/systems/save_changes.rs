@@ -1,12 +1,13 @@
 use crate::{
-	resources::{
-		asset_writer::{AssetWriter, WriteAsset, WriteError},
-		key_map::InvalidInputWarning,
-	},
+	resources::key_map::InvalidInputWarning,
 	traits::drain_invalid_inputs::DrainInvalidInputs,
 };
 use bevy::{ecs::component::Mutable, prelude::*};
-use common::{prelude::*, tools::path::Path};
+use common::{
+	prelude::*,
+	resources::asset_writer::{AssetWriter, WriteAsset, WriteError},
+	tools::path::Path,
+};
 use serde::Serialize;
 use std::{
 	collections::{HashMap, HashSet},
@@ -0,0 +1,123 @@
+use crate::resources::{active_input_device::ActiveInputDevice, gamepad_state::GamepadState};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl ActiveInputDevice {
+	pub(crate) fn update(
+		keys: Res<ButtonInput<KeyCode>>,
+		mouse: Res<ButtonInput<MouseButton>>,
+		gamepad: Res<GamepadState>,
+		mut active: ResMut<Self>,
+	) {
+		let device = if gamepad.input.get_just_pressed().next().is_some() {
+			InputDevice::Gamepad
+		} else if keys.get_just_pressed().next().is_some()
+			|| mouse.get_just_pressed().next().is_some()
+		{
+			InputDevice::KeyboardMouse
+		} else {
+			return;
+		};
+
+		active.set_if_neq(Self(device));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{SingleThreadedApp, set_input};
+
+	#[derive(Resource, Debug, PartialEq)]
+	struct _Changed(bool);
+
+	fn setup(active: InputDevice) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(ActiveInputDevice(active));
+		app.init_resource::<ButtonInput<KeyCode>>();
+		app.init_resource::<ButtonInput<MouseButton>>();
+		app.init_resource::<GamepadState>();
+		app.add_systems(
+			Update,
+			(
+				ActiveInputDevice::update,
+				|device: Res<ActiveInputDevice>, mut commands: Commands| {
+					commands.insert_resource(_Changed(device.is_changed()));
+				},
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	#[test]
+	fn switch_to_gamepad() {
+		let mut app = setup(InputDevice::KeyboardMouse);
+		app.world_mut()
+			.resource_mut::<GamepadState>()
+			.input
+			.press(UserInput::GamepadButton(GamepadButton::South));
+
+		app.update();
+
+		assert_eq!(
+			&ActiveInputDevice(InputDevice::Gamepad),
+			app.world().resource::<ActiveInputDevice>()
+		);
+	}
+
+	#[test]
+	fn switch_to_keyboard_mouse_on_key() {
+		let mut app = setup(InputDevice::Gamepad);
+		set_input!(app, just_pressed(KeyCode::KeyA));
+
+		app.update();
+
+		assert_eq!(
+			&ActiveInputDevice(InputDevice::KeyboardMouse),
+			app.world().resource::<ActiveInputDevice>()
+		);
+	}
+
+	#[test]
+	fn switch_to_keyboard_mouse_on_mouse_button() {
+		let mut app = setup(InputDevice::Gamepad);
+		set_input!(app, just_pressed(MouseButton::Left));
+
+		app.update();
+
+		assert_eq!(
+			&ActiveInputDevice(InputDevice::KeyboardMouse),
+			app.world().resource::<ActiveInputDevice>()
+		);
+	}
+
+	#[test]
+	fn keep_device_when_nothing_pressed() {
+		let mut app = setup(InputDevice::Gamepad);
+		set_input!(app, pressed(KeyCode::KeyA));
+
+		app.update();
+
+		assert_eq!(
+			&ActiveInputDevice(InputDevice::Gamepad),
+			app.world().resource::<ActiveInputDevice>()
+		);
+	}
+
+	#[test]
+	fn do_not_mark_changed_when_device_unchanged() {
+		let mut app = setup(InputDevice::KeyboardMouse);
+
+		app.update();
+		set_input!(app, just_pressed(KeyCode::KeyA));
+		app.update();
+
+		assert_eq!(
+			Some(&_Changed(false)),
+			app.world().get_resource::<_Changed>()
+		);
+	}
+}
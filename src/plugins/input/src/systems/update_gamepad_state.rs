@@ -0,0 +1,217 @@
+use crate::resources::gamepad_state::GamepadState;
+use bevy::prelude::*;
+use common::prelude::*;
+use std::collections::HashSet;
+
+impl GamepadState {
+	const STICK_PRESS_THRESHOLD: f32 = 0.5;
+
+	pub(crate) fn update(gamepads: Query<&Gamepad>, mut state: ResMut<Self>) {
+		let mut pressed = HashSet::new();
+		let mut left_stick = Vec2::ZERO;
+		let mut right_stick = Vec2::ZERO;
+
+		for gamepad in &gamepads {
+			pressed.extend(gamepad.get_pressed().copied().map(UserInput::GamepadButton));
+			left_stick = strongest(left_stick, gamepad.left_stick());
+			right_stick = strongest(right_stick, gamepad.right_stick());
+		}
+
+		for (stick, position) in [
+			(GamepadStick::Left, left_stick),
+			(GamepadStick::Right, right_stick),
+		] {
+			for direction in StickDirection::ALL {
+				if position.dot(direction.as_vec2()) < Self::STICK_PRESS_THRESHOLD {
+					continue;
+				}
+
+				pressed.insert(UserInput::GamepadStick(stick, direction));
+			}
+		}
+
+		let released = state
+			.input
+			.get_pressed()
+			.filter(|input| !pressed.contains(input))
+			.copied()
+			.collect::<Vec<_>>();
+
+		state.input.clear();
+		for input in released {
+			state.input.release(input);
+		}
+		for input in pressed {
+			state.input.press(input);
+		}
+		state.left_stick = left_stick;
+		state.right_stick = right_stick;
+	}
+}
+
+fn strongest(a: Vec2, b: Vec2) -> Vec2 {
+	if b.length_squared() > a.length_squared() {
+		return b;
+	}
+
+	a
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::input::gamepad::GamepadInput;
+	use test_case::test_case;
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<GamepadState>();
+		app.add_systems(Update, GamepadState::update);
+
+		app
+	}
+
+	fn press(app: &mut App, gamepad: Entity, button: GamepadButton) {
+		if let Some(mut gamepad) = app.world_mut().entity_mut(gamepad).get_mut::<Gamepad>() {
+			gamepad.digital_mut().press(button);
+		}
+	}
+
+	fn release(app: &mut App, gamepad: Entity, button: GamepadButton) {
+		if let Some(mut gamepad) = app.world_mut().entity_mut(gamepad).get_mut::<Gamepad>() {
+			gamepad.digital_mut().release(button);
+		}
+	}
+
+	fn tilt(app: &mut App, gamepad: Entity, stick: GamepadStick, position: Vec2) {
+		let (x, y) = stick.axes();
+		if let Some(mut gamepad) = app.world_mut().entity_mut(gamepad).get_mut::<Gamepad>() {
+			gamepad.analog_mut().set(GamepadInput::Axis(x), position.x);
+			gamepad.analog_mut().set(GamepadInput::Axis(y), position.y);
+		}
+	}
+
+	#[test]
+	fn press_button() {
+		let mut app = setup();
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+		press(&mut app, gamepad, GamepadButton::South);
+
+		app.update();
+
+		let state = app.world().resource::<GamepadState>();
+		assert_eq!(
+			(true, true),
+			(
+				state
+					.input
+					.just_pressed(UserInput::GamepadButton(GamepadButton::South)),
+				state
+					.input
+					.pressed(UserInput::GamepadButton(GamepadButton::South)),
+			)
+		);
+	}
+
+	#[test]
+	fn hold_button() {
+		let mut app = setup();
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+		press(&mut app, gamepad, GamepadButton::South);
+
+		app.update();
+		app.update();
+
+		let state = app.world().resource::<GamepadState>();
+		assert_eq!(
+			(false, true),
+			(
+				state
+					.input
+					.just_pressed(UserInput::GamepadButton(GamepadButton::South)),
+				state
+					.input
+					.pressed(UserInput::GamepadButton(GamepadButton::South)),
+			)
+		);
+	}
+
+	#[test]
+	fn release_button() {
+		let mut app = setup();
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+		press(&mut app, gamepad, GamepadButton::South);
+
+		app.update();
+		release(&mut app, gamepad, GamepadButton::South);
+		app.update();
+
+		let state = app.world().resource::<GamepadState>();
+		assert_eq!(
+			(true, false),
+			(
+				state
+					.input
+					.just_released(UserInput::GamepadButton(GamepadButton::South)),
+				state
+					.input
+					.pressed(UserInput::GamepadButton(GamepadButton::South)),
+			)
+		);
+	}
+
+	#[test_case(Vec2::new(0., 0.8), &[StickDirection::Up]; "up")]
+	#[test_case(Vec2::new(-0.8, 0.), &[StickDirection::Left]; "left")]
+	#[test_case(Vec2::new(0.6, -0.6), &[StickDirection::Right, StickDirection::Down]; "down right")]
+	#[test_case(Vec2::new(0.3, 0.3), &[]; "below threshold")]
+	fn press_stick_directions(position: Vec2, directions: &[StickDirection]) {
+		let mut app = setup();
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+		tilt(&mut app, gamepad, GamepadStick::Left, position);
+
+		app.update();
+
+		let state = app.world().resource::<GamepadState>();
+		assert_eq!(
+			directions
+				.iter()
+				.map(|d| UserInput::GamepadStick(GamepadStick::Left, *d))
+				.collect::<HashSet<_>>(),
+			state.input.get_pressed().copied().collect::<HashSet<_>>(),
+		);
+	}
+
+	#[test]
+	fn track_stick_positions() {
+		let mut app = setup();
+		let gamepad = app.world_mut().spawn(Gamepad::default()).id();
+		tilt(&mut app, gamepad, GamepadStick::Left, Vec2::new(0.1, 0.2));
+		tilt(&mut app, gamepad, GamepadStick::Right, Vec2::new(0.3, 0.4));
+
+		app.update();
+
+		let state = app.world().resource::<GamepadState>();
+		assert_eq!(
+			(Vec2::new(0.1, 0.2), Vec2::new(0.3, 0.4)),
+			(state.left_stick, state.right_stick)
+		);
+	}
+
+	#[test]
+	fn use_strongest_stick_position_of_all_gamepads() {
+		let mut app = setup();
+		let a = app.world_mut().spawn(Gamepad::default()).id();
+		let b = app.world_mut().spawn(Gamepad::default()).id();
+		tilt(&mut app, a, GamepadStick::Right, Vec2::new(0.1, 0.2));
+		tilt(&mut app, b, GamepadStick::Right, Vec2::new(0.3, 0.4));
+
+		app.update();
+
+		assert_eq!(
+			Vec2::new(0.3, 0.4),
+			app.world().resource::<GamepadState>().right_stick,
+		);
+	}
+}
@@ -1 +1,2 @@
 pub(crate) mod drain_invalid_inputs;
+pub(crate) mod get_device_input;
@@ -0,0 +1,33 @@
+use bevy::{ecs::component::Mutable, prelude::*};
+use common::prelude::*;
+use std::ops::Deref;
+
+pub trait GetDeviceInput {
+	fn get_device_input<TAction>(&self, action: TAction, device: InputDevice) -> UserInput
+	where
+		TAction: Into<ActionKey> + 'static;
+}
+
+impl<T> GetDeviceInput for Res<'_, T>
+where
+	T: GetDeviceInput + Resource,
+{
+	fn get_device_input<TAction>(&self, action: TAction, device: InputDevice) -> UserInput
+	where
+		TAction: Into<ActionKey> + 'static,
+	{
+		self.deref().get_device_input(action, device)
+	}
+}
+
+impl<T> GetDeviceInput for ResMut<'_, T>
+where
+	T: GetDeviceInput + Resource<Mutability = Mutable>,
+{
+	fn get_device_input<TAction>(&self, action: TAction, device: InputDevice) -> UserInput
+	where
+		TAction: Into<ActionKey> + 'static,
+	{
+		self.deref().get_device_input(action, device)
+	}
+}
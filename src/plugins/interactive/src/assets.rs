@@ -1 +1,3 @@
+pub(crate) mod container_meta;
 pub(crate) mod door_meta;
+pub(crate) mod pickup_meta;
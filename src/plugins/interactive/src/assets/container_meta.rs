@@ -0,0 +1,48 @@
+use crate::{assets::door_meta::OpenCloseAnimations, traits::GetInteractiveDetectionShape};
+use bevy::{platform::collections::HashMap, prelude::*};
+use common::{
+	prelude::*,
+	systems::register_animations::{AnimationConfig, AnimationKeyAndNames, AnimationMaskAndBones},
+	tools::path::Path,
+};
+use macros::asset_path;
+use serde::{Deserialize, Serialize};
+
+#[derive(Asset, TypePath, Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct ContainerMeta {
+	pub(crate) animations: OpenCloseAnimations,
+	pub(crate) animation_mask_groups: HashMap<AnimationMaskBits, AffectedAnimationBones>,
+	pub(crate) interactive_detection_shape: ShapeParameters,
+	/// Items a container holds until it is looted for the first time.
+	pub(crate) loot: Vec<ItemName>,
+}
+
+impl AssetFolderPath for ContainerMeta {
+	fn asset_folder_path() -> Path {
+		Path::from(asset_path!("maps"))
+	}
+}
+
+impl AssetFileExtensions for ContainerMeta {
+	fn asset_file_extensions() -> &'static [&'static str] {
+		const { &["container"] }
+	}
+}
+
+impl GetInteractiveDetectionShape for ContainerMeta {
+	fn interactive_detection_shape(&self) -> ShapeParameters {
+		self.interactive_detection_shape
+	}
+}
+
+impl AnimationConfig for ContainerMeta {
+	fn animations(&self) -> impl ExactSizeIterator<Item = AnimationKeyAndNames> {
+		self.animations.iter()
+	}
+
+	fn masks(&self) -> impl ExactSizeIterator<Item = AnimationMaskAndBones> {
+		self.animation_mask_groups
+			.iter()
+			.map(|(mask, bones)| (*mask, bones.clone()))
+	}
+}
@@ -1,3 +1,4 @@
+use crate::traits::GetInteractiveDetectionShape;
 use bevy::{platform::collections::HashMap, prelude::*};
 use common::{
 	prelude::*,
@@ -9,9 +10,14 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Asset, TypePath, Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
 pub(crate) struct DoorMeta {
-	pub(crate) animations: DoorAnimations,
+	pub(crate) animations: OpenCloseAnimations,
 	pub(crate) animation_mask_groups: HashMap<AnimationMaskBits, AffectedAnimationBones>,
 	pub(crate) interactive_detection_shape: ShapeParameters,
+	pub(crate) grid_obstacle_shape: ShapeParameters,
+	#[serde(default)]
+	pub(crate) open_sound: Option<SoundCue>,
+	#[serde(default)]
+	pub(crate) close_sound: Option<SoundCue>,
 }
 
 impl AssetFolderPath for DoorMeta {
@@ -26,13 +32,15 @@ impl AssetFileExtensions for DoorMeta {
 	}
 }
 
+impl GetInteractiveDetectionShape for DoorMeta {
+	fn interactive_detection_shape(&self) -> ShapeParameters {
+		self.interactive_detection_shape
+	}
+}
+
 impl AnimationConfig for DoorMeta {
 	fn animations(&self) -> impl ExactSizeIterator<Item = AnimationKeyAndNames> {
-		[
-			(AnimationKey::Open, self.animations.open.clone()),
-			(AnimationKey::Close, self.animations.close.clone()),
-		]
-		.into_iter()
+		self.animations.iter()
 	}
 
 	fn masks(&self) -> impl ExactSizeIterator<Item = AnimationMaskAndBones> {
@@ -43,7 +51,17 @@ impl AnimationConfig for DoorMeta {
 }
 
 #[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
-pub(crate) struct DoorAnimations {
+pub(crate) struct OpenCloseAnimations {
 	open: Animation<AnimationNames>,
 	close: Animation<AnimationNames>,
 }
+
+impl OpenCloseAnimations {
+	pub(crate) fn iter(&self) -> impl ExactSizeIterator<Item = AnimationKeyAndNames> {
+		[
+			(AnimationKey::Open, self.open.clone()),
+			(AnimationKey::Close, self.close.clone()),
+		]
+		.into_iter()
+	}
+}
@@ -0,0 +1,28 @@
+use crate::traits::GetInteractiveDetectionShape;
+use bevy::prelude::*;
+use common::{prelude::*, tools::path::Path};
+use macros::asset_path;
+use serde::{Deserialize, Serialize};
+
+#[derive(Asset, TypePath, Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
+pub(crate) struct PickupMeta {
+	pub(crate) interactive_detection_shape: ShapeParameters,
+}
+
+impl AssetFolderPath for PickupMeta {
+	fn asset_folder_path() -> Path {
+		Path::from(asset_path!("maps"))
+	}
+}
+
+impl AssetFileExtensions for PickupMeta {
+	fn asset_file_extensions() -> &'static [&'static str] {
+		const { &["pickup"] }
+	}
+}
+
+impl GetInteractiveDetectionShape for PickupMeta {
+	fn interactive_detection_shape(&self) -> ShapeParameters {
+		self.interactive_detection_shape
+	}
+}
@@ -1,5 +1,9 @@
 pub(crate) mod container;
+pub(crate) mod container_loot;
+pub(crate) mod container_meta_handle;
 pub(crate) mod door;
 pub(crate) mod door_meta_handle;
 pub(crate) mod interactive;
 pub(crate) mod interactive_state;
+pub(crate) mod pickup;
+pub(crate) mod pickup_meta_handle;
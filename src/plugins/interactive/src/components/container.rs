@@ -1,7 +1,57 @@
-use crate::components::interactive::Interactive;
-use bevy::prelude::*;
-use common::prelude::*;
+use crate::{
+	assets::container_meta::ContainerMeta,
+	components::{
+		container_meta_handle::ContainerMetaHandle,
+		interactive::{ApplyInteractiveFrame, Interactive},
+	},
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::{prelude::*, systems::register_animations::AnimationsMarker};
+use macros::{SavableComponent, asset_path};
+use serde::{Deserialize, Serialize};
 
-#[derive(Component, Debug, PartialEq)]
-#[require(Interactive { interactive_type: InteractiveType::Container })]
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[savable_component(id = "container")]
+#[component(immutable)]
+#[require(
+	PersistentEntity,
+	Interactive { interactive_type: InteractiveType::Container },
+	ContainerMetaHandle,
+	Transform,
+	ApplyContainerAnimations,
+	ApplyInteractiveFrame
+)]
 pub(crate) struct Container;
+
+impl Prefab<()> for Container {
+	type TError = Unreachable;
+	type TSystemParam = Res<'static, AssetServer>;
+
+	fn insert_prefab_components(
+		&self,
+		entity: &mut impl PrefabEntityCommands,
+		assets: StaticSystemParam<Res<AssetServer>>,
+	) -> Result<(), Self::TError> {
+		entity.try_insert((
+			Name::from("LootContainer"),
+			Model::scene((
+				asset_path!("maps/assets/loot_container/model.glb"),
+				SceneId(0),
+				UseGltfLookup(true),
+			)),
+			ContainerMetaHandle(
+				assets.load(asset_path!("maps/assets/loot_container/meta.container")),
+			),
+		));
+
+		Ok(())
+	}
+}
+
+#[derive(Component, Debug, PartialEq, Default)]
+pub(crate) struct ApplyContainerAnimations;
+
+impl AnimationsMarker for ApplyContainerAnimations {
+	type TConfig = ContainerMeta;
+	type TConfigComponent = ContainerMetaHandle;
+}
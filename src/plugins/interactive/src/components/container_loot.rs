@@ -0,0 +1,10 @@
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+
+/// Items left in a container. Emptied once looted and saved as such, so a container is
+/// filled from its meta only once.
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[savable_component(id = "container loot")]
+pub(crate) struct ContainerLoot(pub(crate) Vec<ItemName>);
@@ -0,0 +1,18 @@
+use crate::assets::container_meta::ContainerMeta;
+use bevy::prelude::*;
+use common::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Default)]
+pub(crate) struct ContainerMetaHandle(pub(crate) Handle<ContainerMeta>);
+
+impl View<Handle<ContainerMeta>> for ContainerMetaHandle {
+	fn view(&self) -> &'_ Handle<ContainerMeta> {
+		&self.0
+	}
+}
+
+impl From<&'_ ContainerMetaHandle> for AssetId<ContainerMeta> {
+	fn from(ContainerMetaHandle(handle): &'_ ContainerMetaHandle) -> Self {
+		handle.id()
+	}
+}
@@ -1,6 +1,9 @@
 use crate::{
 	assets::door_meta::DoorMeta,
-	components::{door_meta_handle::DoorMetaHandle, interactive::Interactive},
+	components::{
+		door_meta_handle::DoorMetaHandle,
+		interactive::{ApplyInteractiveFrame, Interactive},
+	},
 };
 use bevy::{ecs::system::StaticSystemParam, prelude::*};
 use common::{prelude::*, systems::register_animations::AnimationsMarker};
@@ -16,7 +19,7 @@ use serde::{Deserialize, Serialize};
 	DoorMetaHandle,
 	Transform,
 	ApplyDoorAnimations,
-	ApplyDoorFrame
+	ApplyInteractiveFrame
 )]
 pub(crate) struct Door;
 
@@ -50,6 +53,3 @@ impl AnimationsMarker for ApplyDoorAnimations {
 	type TConfig = DoorMeta;
 	type TConfigComponent = DoorMetaHandle;
 }
-
-#[derive(Component, Debug, PartialEq, Default)]
-pub(crate) struct ApplyDoorFrame;
@@ -4,7 +4,7 @@ use macros::SavableComponent;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
-use crate::components::{container::Container, door::Door};
+use crate::components::{container::Container, door::Door, pickup::Pickup};
 
 #[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
 #[component(immutable)]
@@ -13,6 +13,9 @@ pub(crate) struct Interactive {
 	pub(crate) interactive_type: InteractiveType,
 }
 
+#[derive(Component, Debug, PartialEq, Default)]
+pub(crate) struct ApplyInteractiveFrame;
+
 impl Interactive {
 	fn map_prefab(
 		mut entity: ZyheedaEntityCommands,
@@ -24,6 +27,7 @@ impl Interactive {
 		match interactive_type {
 			InteractiveType::Door => entity.try_insert(Door),
 			InteractiveType::Container => entity.try_insert(Container),
+			InteractiveType::Pickup => entity.try_insert(Pickup),
 		};
 	}
 
@@ -0,0 +1,39 @@
+use crate::components::{
+	interactive::{ApplyInteractiveFrame, Interactive},
+	pickup_meta_handle::PickupMetaHandle,
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+use macros::{SavableComponent, asset_path};
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[savable_component(id = "pickup")]
+#[component(immutable)]
+#[require(
+	PersistentEntity,
+	Interactive { interactive_type: InteractiveType::Pickup },
+	PickupMetaHandle,
+	Transform,
+	Visibility,
+	ApplyInteractiveFrame
+)]
+pub(crate) struct Pickup;
+
+impl Prefab<()> for Pickup {
+	type TError = Unreachable;
+	type TSystemParam = Res<'static, AssetServer>;
+
+	fn insert_prefab_components(
+		&self,
+		entity: &mut impl PrefabEntityCommands,
+		assets: StaticSystemParam<Res<AssetServer>>,
+	) -> Result<(), Self::TError> {
+		entity.try_insert((
+			Name::from("Pickup"),
+			PickupMetaHandle(assets.load(asset_path!("maps/assets/pickup/meta.pickup"))),
+		));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,18 @@
+use crate::assets::pickup_meta::PickupMeta;
+use bevy::prelude::*;
+use common::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Default)]
+pub(crate) struct PickupMetaHandle(pub(crate) Handle<PickupMeta>);
+
+impl View<Handle<PickupMeta>> for PickupMetaHandle {
+	fn view(&self) -> &'_ Handle<PickupMeta> {
+		&self.0
+	}
+}
+
+impl From<&'_ PickupMetaHandle> for AssetId<PickupMeta> {
+	fn from(PickupMetaHandle(handle): &'_ PickupMetaHandle) -> Self {
+		handle.id()
+	}
+}
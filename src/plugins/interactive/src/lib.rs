@@ -3,15 +3,25 @@ mod components;
 mod observers;
 mod system_params;
 mod systems;
+mod traits;
 
 use crate::{
-	assets::door_meta::DoorMeta,
+	assets::{container_meta::ContainerMeta, door_meta::DoorMeta, pickup_meta::PickupMeta},
 	components::{
-		door::{ApplyDoorAnimations, ApplyDoorFrame, Door},
-		interactive::Interactive,
+		container::{ApplyContainerAnimations, Container},
+		container_loot::ContainerLoot,
+		container_meta_handle::ContainerMetaHandle,
+		door::{ApplyDoorAnimations, Door},
+		door_meta_handle::DoorMetaHandle,
+		interactive::{ApplyInteractiveFrame, Interactive},
 		interactive_state::IsActive,
+		pickup::Pickup,
+		pickup_meta_handle::PickupMetaHandle,
+	},
+	system_params::{
+		interactive_param::{InteractiveParam, InteractiveParamMut},
+		pickups_param::PickupsParamMut,
 	},
-	system_params::interactive_param::{InteractiveParam, InteractiveParamMut},
 };
 use bevy::prelude::*;
 use common::{
@@ -54,14 +64,33 @@ where
 {
 	fn build(&self, app: &mut App) {
 		TLoading::register_custom_folder_assets::<DoorMeta, DoorMeta, LoadingEssentialAssets>(app);
+		TLoading::register_custom_folder_assets::<
+			ContainerMeta,
+			ContainerMeta,
+			LoadingEssentialAssets,
+		>(app);
+		TLoading::register_custom_folder_assets::<PickupMeta, PickupMeta, LoadingEssentialAssets>(
+			app,
+		);
 
 		TSavegame::register_savable_component::<Door>(app);
+		TSavegame::register_savable_component::<Container>(app);
+		TSavegame::register_savable_component::<ContainerLoot>(app);
+		TSavegame::register_savable_component::<Pickup>(app);
+		TSavegame::register_savable_component::<DroppedItem>(app);
 		TSavegame::register_savable_component::<IsActive>(app);
 
 		app.init_asset::<DoorMeta>()
+			.init_asset::<ContainerMeta>()
+			.init_asset::<PickupMeta>()
 			.add_prefab_observer::<Door, ()>()
-			.add_observer(Door::animate_open::<TAnimations::TAnimationsMut>)
-			.add_observer(Door::animate_close::<TAnimations::TAnimationsMut>)
+			.add_prefab_observer::<Container, ()>()
+			.add_prefab_observer::<Pickup, ()>()
+			.add_observer(Interactive::animate_open::<TAnimations::TAnimationsMut>)
+			.add_observer(Interactive::animate_close::<TAnimations::TAnimationsMut>)
+			.add_message::<PlaySound>()
+			.add_observer(Door::play_open_sound)
+			.add_observer(Door::play_close_sound)
 			.add_systems(
 				Startup,
 				Interactive::configure_map_prefab::<TMaps::TMapPrefabs>.pipe(OnError::log),
@@ -70,9 +99,25 @@ where
 				Update,
 				(
 					Interactive::reset_when_no_interactions::<TPhysics::TInteractions>,
-					ApplyDoorFrame::apply::<TPhysics::TConfigMut>,
+					ApplyInteractiveFrame::apply::<DoorMeta, DoorMetaHandle, TPhysics::TConfigMut>,
+					ApplyInteractiveFrame::apply::<
+						ContainerMeta,
+						ContainerMetaHandle,
+						TPhysics::TConfigMut,
+					>,
+					ApplyInteractiveFrame::apply::<
+						PickupMeta,
+						PickupMetaHandle,
+						TPhysics::TConfigMut,
+					>,
+					Door::block_grid::<TMaps::TGridBlockersMut>,
+					ContainerLoot::fill,
 					ApplyDoorAnimations::register_animations_system::<TAnimations::TAnimationsMut>
 						.pipe(OnError::log),
+					ApplyContainerAnimations::register_animations_system::<
+						TAnimations::TAnimationsMut,
+					>
+						.pipe(OnError::log),
 				)
 					.chain()
 					.in_set(InteractiveSystems)
@@ -94,4 +139,5 @@ impl<TDependencies> SystemSetDefinition for InteractivePlugin<TDependencies> {
 impl<TDependencies> HandlesInteractive for InteractivePlugin<TDependencies> {
 	type TInteractive = InteractiveParam<'static, 'static>;
 	type TInteractiveMut = InteractiveParamMut<'static, 'static>;
+	type TPickupsMut = PickupsParamMut<'static, 'static>;
 }
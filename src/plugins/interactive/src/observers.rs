@@ -1 +1,2 @@
-pub(crate) mod animate_door;
+pub(crate) mod animate_interactive;
+pub(crate) mod play_door_sound;
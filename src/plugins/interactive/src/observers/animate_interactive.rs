@@ -1,19 +1,19 @@
-use crate::components::{door::Door, interactive_state::IsActive};
+use crate::components::{interactive::Interactive, interactive_state::IsActive};
 use bevy::{ecs::system::StaticSystemParam, prelude::*};
 use common::prelude::*;
 use zyheeda_core::collections::ordered::OrderedSet;
 
-impl Door {
+impl Interactive {
 	pub(crate) fn animate_open<TAnimations>(
 		on_add: On<Add, IsActive>,
-		doors: Query<(), With<Self>>,
+		interactives: Query<(), With<Self>>,
 		mut animations: StaticSystemParam<TAnimations>,
 	) where
 		TAnimations: for<'c> TryGetContextMut<Animations, TContext<'c>: ActiveAnimationsMut>,
 	{
 		let entity = on_add.entity;
 
-		if !doors.contains(entity) {
+		if !interactives.contains(entity) {
 			return;
 		};
 
@@ -27,14 +27,14 @@ impl Door {
 
 	pub(crate) fn animate_close<TAnimations>(
 		on_insert: On<Remove, IsActive>,
-		doors: Query<(), With<Self>>,
+		interactives: Query<(), With<Self>>,
 		mut animations: StaticSystemParam<TAnimations>,
 	) where
 		TAnimations: for<'c> TryGetContextMut<Animations, TContext<'c>: ActiveAnimationsMut>,
 	{
 		let entity = on_insert.entity;
 
-		if !doors.contains(entity) {
+		if !interactives.contains(entity) {
 			return;
 		};
 
@@ -89,20 +89,22 @@ mod tests {
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 
-		app.add_observer(Door::animate_open::<Query<&mut _Animations>>);
-		app.add_observer(Door::animate_close::<Query<&mut _Animations>>);
+		app.add_observer(Interactive::animate_open::<Query<&mut _Animations>>);
+		app.add_observer(Interactive::animate_close::<Query<&mut _Animations>>);
 
 		app
 	}
 
 	mod open {
 		use super::*;
+		use test_case::test_case;
 
-		#[test]
-		fn play_only_open() {
+		#[test_case(InteractiveType::Door; "door")]
+		#[test_case(InteractiveType::Container; "container")]
+		fn play_only_open(interactive_type: InteractiveType) {
 			let mut app = setup();
 			let mut entity = app.world_mut().spawn((
-				Door,
+				Interactive { interactive_type },
 				_Animations(HashMap::from([(
 					AnimationPriority::High,
 					OrderedSet::from([AnimationKey::Close, AnimationKey::Idle]),
@@ -121,7 +123,7 @@ mod tests {
 		}
 
 		#[test]
-		fn do_nothing_when_door_missing() {
+		fn do_nothing_when_interactive_missing() {
 			let mut app = setup();
 			let mut entity = app.world_mut().spawn(_Animations::default());
 
@@ -133,7 +135,12 @@ mod tests {
 		#[test]
 		fn act_only_once() {
 			let mut app = setup();
-			let mut entity = app.world_mut().spawn((Door, _Animations::default()));
+			let mut entity = app.world_mut().spawn((
+				Interactive {
+					interactive_type: InteractiveType::Door,
+				},
+				_Animations::default(),
+			));
 
 			entity.insert(IsActive);
 			let mut animations = entity.get_mut::<_Animations>().unwrap();
@@ -154,13 +161,15 @@ mod tests {
 
 	mod close {
 		use super::*;
+		use test_case::test_case;
 
-		#[test]
-		fn play_only_close() {
+		#[test_case(InteractiveType::Door; "door")]
+		#[test_case(InteractiveType::Container; "container")]
+		fn play_only_close(interactive_type: InteractiveType) {
 			let mut app = setup();
 			let mut entity = app.world_mut().spawn((
 				IsActive,
-				Door,
+				Interactive { interactive_type },
 				_Animations(HashMap::from([(
 					AnimationPriority::High,
 					OrderedSet::from([AnimationKey::Open, AnimationKey::Idle]),
@@ -179,7 +188,7 @@ mod tests {
 		}
 
 		#[test]
-		fn do_nothing_when_door_missing() {
+		fn do_nothing_when_interactive_missing() {
 			let mut app = setup();
 			let mut entity = app.world_mut().spawn((IsActive, _Animations::default()));
 
@@ -0,0 +1,135 @@
+use crate::{
+	assets::door_meta::DoorMeta,
+	components::{door::Door, door_meta_handle::DoorMetaHandle, interactive_state::IsActive},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl Door {
+	pub(crate) fn play_open_sound(
+		on_add: On<Add, IsActive>,
+		sounds: MessageWriter<PlaySound>,
+		doors: Query<&DoorMetaHandle, With<Self>>,
+		metas: Res<Assets<DoorMeta>>,
+	) {
+		play(sounds, on_add.entity, doors, metas, |meta| &meta.open_sound);
+	}
+
+	pub(crate) fn play_close_sound(
+		on_remove: On<Remove, IsActive>,
+		sounds: MessageWriter<PlaySound>,
+		doors: Query<&DoorMetaHandle, With<Self>>,
+		metas: Res<Assets<DoorMeta>>,
+	) {
+		play(sounds, on_remove.entity, doors, metas, |meta| {
+			&meta.close_sound
+		});
+	}
+}
+
+fn play(
+	mut sounds: MessageWriter<PlaySound>,
+	entity: Entity,
+	doors: Query<&DoorMetaHandle, With<Door>>,
+	metas: Res<Assets<DoorMeta>>,
+	sound: fn(&DoorMeta) -> &Option<SoundCue>,
+) {
+	let Ok(DoorMetaHandle(handle)) = doors.get(entity) else {
+		return;
+	};
+	let Some(cue) = metas.get(handle).and_then(|meta| sound(meta).as_ref()) else {
+		return;
+	};
+
+	sounds.write(PlaySound::sfx(cue.clone(), SoundEmitter::Entity(entity)));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Sounds(Vec<PlaySound>);
+
+	impl _Sounds {
+		fn collect(mut reader: MessageReader<PlaySound>, mut sounds: ResMut<Self>) {
+			sounds.0.extend(reader.read().cloned());
+		}
+	}
+
+	fn setup(handle: &Handle<DoorMeta>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+		_ = assets.insert(
+			handle,
+			DoorMeta {
+				open_sound: Some(SoundCue::from("sounds/open.ogg")),
+				close_sound: Some(SoundCue::from("sounds/close.ogg")),
+				..default()
+			},
+		);
+
+		app.insert_resource(assets);
+		app.add_message::<PlaySound>();
+		app.init_resource::<_Sounds>();
+		app.add_observer(Door::play_open_sound);
+		app.add_observer(Door::play_close_sound);
+		app.add_systems(Update, _Sounds::collect);
+
+		app
+	}
+
+	#[test]
+	fn play_open_sound() {
+		let handle = new_handle();
+		let mut app = setup(&handle);
+		let entity = app.world_mut().spawn((Door, DoorMetaHandle(handle))).id();
+
+		app.world_mut().entity_mut(entity).insert(IsActive);
+		app.update();
+
+		assert_eq!(
+			&_Sounds(vec![PlaySound::sfx(
+				SoundCue::from("sounds/open.ogg"),
+				SoundEmitter::Entity(entity),
+			)]),
+			app.world().resource::<_Sounds>(),
+		);
+	}
+
+	#[test]
+	fn play_close_sound() {
+		let handle = new_handle();
+		let mut app = setup(&handle);
+		let entity = app
+			.world_mut()
+			.spawn((Door, DoorMetaHandle(handle), IsActive))
+			.id();
+		app.update();
+		app.world_mut().resource_mut::<_Sounds>().0.clear();
+
+		app.world_mut().entity_mut(entity).remove::<IsActive>();
+		app.update();
+
+		assert_eq!(
+			&_Sounds(vec![PlaySound::sfx(
+				SoundCue::from("sounds/close.ogg"),
+				SoundEmitter::Entity(entity),
+			)]),
+			app.world().resource::<_Sounds>(),
+		);
+	}
+
+	#[test]
+	fn play_nothing_for_non_doors() {
+		let handle = new_handle();
+		let mut app = setup(&handle);
+		let entity = app.world_mut().spawn(DoorMetaHandle(handle)).id();
+
+		app.world_mut().entity_mut(entity).insert(IsActive);
+		app.update();
+
+		assert_eq!(&_Sounds(vec![]), app.world().resource::<_Sounds>());
+	}
+}
@@ -1 +1,2 @@
 pub(crate) mod interactive_param;
+pub(crate) mod pickups_param;
@@ -1,7 +1,11 @@
 mod read;
 mod write;
 
-use crate::components::{interactive::Interactive, interactive_state::IsActive};
+use crate::components::{
+	container_loot::ContainerLoot,
+	interactive::Interactive,
+	interactive_state::IsActive,
+};
 use bevy::{
 	ecs::system::{SystemParam, SystemParamItem},
 	prelude::*,
@@ -47,6 +51,8 @@ pub struct InteractiveParamMut<'w, 's> {
 	commands: ZyheedaCommands<'w, 's>,
 	interactive_entities: Query<'w, 's, &'static Interactive>,
 	actives: Query<'w, 's, (), With<IsActive>>,
+	loot: Query<'w, 's, &'static mut ContainerLoot>,
+	dropped_items: Query<'w, 's, &'static DroppedItem>,
 }
 
 impl TryGetContextMut<InteractiveKey> for InteractiveParamMut<'static, 'static> {
@@ -63,6 +69,8 @@ impl TryGetContextMut<InteractiveKey> for InteractiveParamMut<'static, 'static>
 				true => InteractiveState::Active,
 				false => InteractiveState::Inactive,
 			},
+			loot: param.loot.get_mut(entity).ok(),
+			dropped_item: param.dropped_items.get(entity).ok(),
 		})
 	}
 }
@@ -71,4 +79,6 @@ pub struct InteractiveContextMut<'ctx> {
 	entity: ZyheedaEntityCommands<'ctx>,
 	interactive: &'ctx Interactive,
 	state: InteractiveState,
+	loot: Option<Mut<'ctx, ContainerLoot>>,
+	dropped_item: Option<&'ctx DroppedItem>,
 }
@@ -1,3 +1,4 @@
 mod set_interactive_state;
+mod take_loot;
 mod view_interactive_state;
 mod view_interactive_type;
@@ -0,0 +1,197 @@
+use crate::system_params::interactive_param::InteractiveContextMut;
+use common::prelude::*;
+
+impl TakeLoot for InteractiveContextMut<'_> {
+	fn take_loot(&mut self, max: usize) -> Vec<ItemName> {
+		if max == 0 {
+			return vec![];
+		}
+
+		if let Some(DroppedItem(item)) = self.dropped_item {
+			self.entity.reborrow().try_despawn();
+			return vec![item.clone()];
+		}
+
+		let Some(loot) = self.loot.as_mut() else {
+			return vec![];
+		};
+
+		if loot.0.is_empty() {
+			return vec![];
+		}
+
+		let count = max.min(loot.0.len());
+		loot.0.drain(..count).collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		components::{container_loot::ContainerLoot, interactive::Interactive},
+		system_params::interactive_param::InteractiveParamMut,
+	};
+	use bevy::{
+		ecs::system::{RunSystemError, RunSystemOnce},
+		prelude::*,
+	};
+	use common::traits::handles_interactive::Interactive as InteractiveKey;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	fn setup() -> App {
+		App::new().single_threaded(Update)
+	}
+
+	fn take(
+		entity: Entity,
+		max: usize,
+	) -> impl FnMut(InteractiveParamMut) -> Option<Vec<ItemName>> {
+		move |mut i: InteractiveParamMut| {
+			let key = InteractiveKey { entity };
+			let mut ctx = InteractiveParamMut::try_get_context_mut(&mut i, key)?;
+
+			Some(ctx.take_loot(max))
+		}
+	}
+
+	#[test]
+	fn take_items() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Interactive {
+					interactive_type: InteractiveType::Container,
+				},
+				ContainerLoot(vec![ItemName::from("a"), ItemName::from("b")]),
+			))
+			.id();
+
+		let items = app.world_mut().run_system_once(take(entity, 10))?;
+
+		assert_eq!(
+			(
+				Some(vec![ItemName::from("a"), ItemName::from("b")]),
+				Some(&ContainerLoot(vec![])),
+			),
+			(items, app.world().entity(entity).get::<ContainerLoot>()),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn leave_items_exceeding_max() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Interactive {
+					interactive_type: InteractiveType::Container,
+				},
+				ContainerLoot(vec![ItemName::from("a"), ItemName::from("b")]),
+			))
+			.id();
+
+		let items = app.world_mut().run_system_once(take(entity, 1))?;
+
+		assert_eq!(
+			(
+				Some(vec![ItemName::from("a")]),
+				Some(&ContainerLoot(vec![ItemName::from("b")])),
+			),
+			(items, app.world().entity(entity).get::<ContainerLoot>()),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn take_dropped_item_and_despawn() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Interactive {
+					interactive_type: InteractiveType::Pickup,
+				},
+				DroppedItem(ItemName::from("a")),
+			))
+			.id();
+
+		let items = app.world_mut().run_system_once(take(entity, 10))?;
+
+		assert_eq!(
+			(Some(vec![ItemName::from("a")]), None),
+			(items, app.world().get_entity(entity).ok().map(|e| e.id())),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn leave_dropped_item_when_max_zero() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Interactive {
+					interactive_type: InteractiveType::Pickup,
+				},
+				DroppedItem(ItemName::from("a")),
+			))
+			.id();
+
+		let items = app.world_mut().run_system_once(take(entity, 0))?;
+
+		assert_eq!(
+			(Some(vec![]), Some(entity)),
+			(items, app.world().get_entity(entity).ok().map(|e| e.id())),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn nothing_when_no_loot() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn(Interactive {
+				interactive_type: InteractiveType::Door,
+			})
+			.id();
+
+		let items = app.world_mut().run_system_once(take(entity, 10))?;
+
+		assert_eq!(Some(vec![]), items);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_mutate_when_already_empty() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Interactive {
+					interactive_type: InteractiveType::Container,
+				},
+				ContainerLoot(vec![]),
+			))
+			.id();
+		app.add_systems(
+			Update,
+			(
+				take(entity, 10).map(drop),
+				IsChanged::<ContainerLoot>::detect,
+			)
+				.chain(),
+		);
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<ContainerLoot>>(),
+		);
+	}
+}
@@ -0,0 +1,29 @@
+mod spawn_pickup;
+
+use bevy::{
+	ecs::system::{SystemParam, SystemParamItem},
+	prelude::*,
+};
+use common::prelude::*;
+
+#[derive(SystemParam)]
+pub struct PickupsParamMut<'w, 's> {
+	commands: Commands<'w, 's>,
+}
+
+impl GetContextMut<Pickups> for PickupsParamMut<'static, 'static> {
+	type TContext<'ctx> = PickupsContextMut<'ctx>;
+
+	fn get_context_mut<'ctx>(
+		param: &'ctx mut SystemParamItem<Self>,
+		_: Pickups,
+	) -> Self::TContext<'ctx> {
+		PickupsContextMut {
+			commands: param.commands.reborrow(),
+		}
+	}
+}
+
+pub struct PickupsContextMut<'ctx> {
+	commands: Commands<'ctx, 'ctx>,
+}
@@ -0,0 +1,47 @@
+use crate::{components::pickup::Pickup, system_params::pickups_param::PickupsContextMut};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl SpawnPickup for PickupsContextMut<'_> {
+	fn spawn_pickup(&mut self, item: ItemName, translation: Vec3) {
+		self.commands.spawn((
+			Pickup,
+			DroppedItem(item),
+			Transform::from_translation(translation),
+		));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::system_params::pickups_param::PickupsParamMut;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		App::new().single_threaded(Update)
+	}
+
+	#[test]
+	fn spawn_pickup() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut().run_system_once(|mut p: PickupsParamMut| {
+			let mut ctx = PickupsParamMut::get_context_mut(&mut p, Pickups);
+			ctx.spawn_pickup(ItemName::from("my item"), Vec3::new(1., 2., 3.));
+		})?;
+
+		let mut pickups = app
+			.world_mut()
+			.query_filtered::<(&DroppedItem, &Transform), With<Pickup>>();
+		assert_eq!(
+			vec![(
+				&DroppedItem(ItemName::from("my item")),
+				&Transform::from_xyz(1., 2., 3.),
+			)],
+			pickups.iter(app.world()).collect::<Vec<_>>(),
+		);
+		Ok(())
+	}
+}
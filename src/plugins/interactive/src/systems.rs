@@ -1,2 +1,4 @@
-pub(crate) mod apply_door_frame;
+pub(crate) mod apply_interactive_frame;
+pub(crate) mod block_grid;
+pub(crate) mod fill_container_loot;
 pub(crate) mod reset_interactive_entities;
@@ -1,21 +1,20 @@
-use crate::{
-	assets::door_meta::DoorMeta,
-	components::{door::ApplyDoorFrame, door_meta_handle::DoorMetaHandle},
-};
+use crate::{components::interactive::ApplyInteractiveFrame, traits::GetInteractiveDetectionShape};
 use bevy::{ecs::system::StaticSystemParam, prelude::*};
 use common::prelude::*;
 
-impl ApplyDoorFrame {
-	pub(crate) fn apply<TBody>(
-		doors: Query<(Entity, &DoorMetaHandle), With<Self>>,
-		assets: Res<Assets<DoorMeta>>,
+impl ApplyInteractiveFrame {
+	pub(crate) fn apply<TMeta, TMetaHandle, TBody>(
+		interactives: Query<(Entity, &TMetaHandle), With<Self>>,
+		assets: Res<Assets<TMeta>>,
 		mut commands: ZyheedaCommands,
 		mut body: StaticSystemParam<TBody>,
 	) where
+		TMeta: Asset + GetInteractiveDetectionShape,
+		TMetaHandle: Component + View<Handle<TMeta>>,
 		TBody: for<'c> TryGetContextMut<NoBodyConfigured, TContext<'c>: ConfigureBody>,
 	{
-		for (entity, handle) in doors {
-			let Some(meta) = assets.get(handle) else {
+		for (entity, handle) in interactives {
+			let Some(meta) = assets.get(handle.view()) else {
 				continue;
 			};
 
@@ -30,7 +29,7 @@ impl ApplyDoorFrame {
 
 			let body = BodyConfig {
 				sub_frames: vec![InteractiveFrame {
-					shape: meta.interactive_detection_shape,
+					shape: meta.interactive_detection_shape(),
 					..default()
 				}],
 				..default()
@@ -78,7 +77,10 @@ mod tests {
 		}
 
 		app.insert_resource(door_assets);
-		app.add_systems(Update, ApplyDoorFrame::apply::<Query<&mut _Body>>);
+		app.add_systems(
+			Update,
+			ApplyInteractiveFrame::apply::<DoorMeta, DoorMetaHandle, Query<&mut _Body>>,
+		);
 
 		app
 	}
@@ -94,7 +96,7 @@ mod tests {
 		};
 		let mut app = setup([(&handle, meta)]);
 		app.world_mut().spawn((
-			ApplyDoorFrame,
+			ApplyInteractiveFrame,
 			_Body::new().with_mock(assert_config_body),
 			DoorMetaHandle(handle),
 		));
@@ -127,7 +129,7 @@ mod tests {
 		let entity = app
 			.world_mut()
 			.spawn((
-				ApplyDoorFrame,
+				ApplyInteractiveFrame,
 				_Body::default(),
 				DoorMetaHandle(handle.clone()),
 			))
@@ -135,7 +137,10 @@ mod tests {
 
 		app.update();
 
-		assert_eq!(None, app.world().entity(entity).get::<ApplyDoorFrame>());
+		assert_eq!(
+			None,
+			app.world().entity(entity).get::<ApplyInteractiveFrame>()
+		);
 	}
 
 	#[test]
@@ -144,12 +149,15 @@ mod tests {
 		let mut app = setup([(&handle, DoorMeta::default())]);
 		let entity = app
 			.world_mut()
-			.spawn((ApplyDoorFrame, DoorMetaHandle(handle.clone())))
+			.spawn((ApplyInteractiveFrame, DoorMetaHandle(handle.clone())))
 			.id();
 
 		app.update();
 
-		assert_eq!(None, app.world().entity(entity).get::<ApplyDoorFrame>());
+		assert_eq!(
+			None,
+			app.world().entity(entity).get::<ApplyInteractiveFrame>()
+		);
 	}
 
 	#[test]
@@ -158,7 +166,7 @@ mod tests {
 		let entity = app
 			.world_mut()
 			.spawn((
-				ApplyDoorFrame,
+				ApplyInteractiveFrame,
 				_Body::default(),
 				DoorMetaHandle(new_handle()),
 			))
@@ -167,8 +175,8 @@ mod tests {
 		app.update();
 
 		assert_eq!(
-			Some(&ApplyDoorFrame),
-			app.world().entity(entity).get::<ApplyDoorFrame>(),
+			Some(&ApplyInteractiveFrame),
+			app.world().entity(entity).get::<ApplyInteractiveFrame>(),
 		);
 	}
 
@@ -0,0 +1,132 @@
+use crate::{
+	assets::door_meta::DoorMeta,
+	components::{door::Door, door_meta_handle::DoorMetaHandle, interactive_state::IsActive},
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl Door {
+	pub(crate) fn block_grid<TGridBlockers>(
+		doors: Query<(Entity, &DoorMetaHandle, Has<IsActive>), With<Self>>,
+		assets: Res<Assets<DoorMeta>>,
+		mut grid_blockers: StaticSystemParam<TGridBlockers>,
+	) where
+		TGridBlockers: for<'c> TryGetContextMut<GridBlocker, TContext<'c>: BlockGrid>,
+	{
+		for (entity, handle, is_open) in doors {
+			let Some(meta) = assets.get(handle) else {
+				continue;
+			};
+
+			let key = GridBlocker { entity };
+			let Some(mut ctx) = TGridBlockers::try_get_context_mut(&mut grid_blockers, key) else {
+				continue;
+			};
+
+			if is_open {
+				ctx.unblock_grid();
+			} else {
+				ctx.block_grid(meta.grid_obstacle_shape);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use testing::{NestedMocks, SingleThreadedApp, new_handle};
+
+	#[derive(Component, NestedMocks)]
+	struct _Blocker {
+		mock: Mock_Blocker,
+	}
+
+	#[automock]
+	impl BlockGrid for _Blocker {
+		fn block_grid(&mut self, shape: ShapeParameters) {
+			self.mock.block_grid(shape);
+		}
+
+		fn unblock_grid(&mut self) {
+			self.mock.unblock_grid();
+		}
+	}
+
+	const SHAPE: ShapeParameters = ShapeParameters::Cuboid {
+		half_x: Units::from_u8(1),
+		half_y: Units::from_u8(2),
+		half_z: Units::from_u8(3),
+	};
+
+	fn setup(handle: &Handle<DoorMeta>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut door_assets = Assets::default();
+
+		_ = door_assets.insert(
+			handle,
+			DoorMeta {
+				grid_obstacle_shape: SHAPE,
+				..default()
+			},
+		);
+
+		app.insert_resource(door_assets);
+		app.add_systems(Update, Door::block_grid::<Query<&mut _Blocker>>);
+
+		app
+	}
+
+	#[test]
+	fn block_grid_when_closed() {
+		let handle = new_handle();
+		let mut app = setup(&handle);
+		app.world_mut().spawn((
+			Door,
+			DoorMetaHandle(handle),
+			_Blocker::new().with_mock(|mock| {
+				mock.expect_block_grid()
+					.times(1)
+					.with(eq(SHAPE))
+					.return_const(());
+				mock.expect_unblock_grid().never();
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn unblock_grid_when_open() {
+		let handle = new_handle();
+		let mut app = setup(&handle);
+		app.world_mut().spawn((
+			Door,
+			IsActive,
+			DoorMetaHandle(handle),
+			_Blocker::new().with_mock(|mock| {
+				mock.expect_block_grid().never();
+				mock.expect_unblock_grid().times(1).return_const(());
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn do_nothing_when_asset_missing() {
+		let mut app = setup(&new_handle());
+		app.world_mut().spawn((
+			Door,
+			DoorMetaHandle(new_handle()),
+			_Blocker::new().with_mock(|mock| {
+				mock.expect_block_grid().never();
+				mock.expect_unblock_grid().never();
+			}),
+		));
+
+		app.update();
+	}
+}
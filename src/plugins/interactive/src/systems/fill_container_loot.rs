@@ -0,0 +1,106 @@
+use crate::{
+	assets::container_meta::ContainerMeta,
+	components::{container_loot::ContainerLoot, container_meta_handle::ContainerMetaHandle},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl ContainerLoot {
+	pub(crate) fn fill(
+		mut commands: ZyheedaCommands,
+		containers: Query<(Entity, &ContainerMetaHandle), Without<Self>>,
+		assets: Res<Assets<ContainerMeta>>,
+	) {
+		for (entity, handle) in containers {
+			let Some(meta) = assets.get(handle) else {
+				continue;
+			};
+
+			let loot = Self(meta.loot.clone());
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert(loot);
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::container::Container;
+	use testing::{SingleThreadedApp, new_handle};
+
+	fn setup(handle: &Handle<ContainerMeta>, meta: ContainerMeta) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+
+		_ = assets.insert(handle, meta);
+
+		app.insert_resource(assets);
+		app.add_systems(Update, ContainerLoot::fill);
+
+		app
+	}
+
+	#[test]
+	fn fill_from_meta() {
+		let handle = new_handle();
+		let meta = ContainerMeta {
+			loot: vec![ItemName::from("a"), ItemName::from("b")],
+			..default()
+		};
+		let mut app = setup(&handle, meta);
+		let entity = app
+			.world_mut()
+			.spawn((Container, ContainerMetaHandle(handle)))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&ContainerLoot(vec![
+				ItemName::from("a"),
+				ItemName::from("b")
+			])),
+			app.world().entity(entity).get::<ContainerLoot>(),
+		);
+	}
+
+	#[test]
+	fn do_not_refill_looted_container() {
+		let handle = new_handle();
+		let meta = ContainerMeta {
+			loot: vec![ItemName::from("a")],
+			..default()
+		};
+		let mut app = setup(&handle, meta);
+		let entity = app
+			.world_mut()
+			.spawn((
+				Container,
+				ContainerMetaHandle(handle),
+				ContainerLoot(vec![]),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&ContainerLoot(vec![])),
+			app.world().entity(entity).get::<ContainerLoot>(),
+		);
+	}
+
+	#[test]
+	fn do_nothing_when_asset_missing() {
+		let mut app = setup(&new_handle(), ContainerMeta::default());
+		let entity = app
+			.world_mut()
+			.spawn((Container, ContainerMetaHandle(new_handle())))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(entity).get::<ContainerLoot>());
+	}
+}
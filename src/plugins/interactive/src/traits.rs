@@ -0,0 +1,5 @@
+use common::prelude::*;
+
+pub(crate) trait GetInteractiveDetectionShape {
+	fn interactive_detection_shape(&self) -> ShapeParameters;
+}
@@ -1,10 +1,16 @@
 pub(crate) mod active_skill;
+pub(crate) mod aim_preview;
 pub(crate) mod combo_node;
 pub(crate) mod combos;
 pub(crate) mod combos_time_out;
+pub(crate) mod cooldowns;
+pub(crate) mod dropped_item_model;
+pub(crate) mod equipment_stats;
 pub(crate) mod inventory;
 pub(crate) mod loadout;
 pub(crate) mod model_render;
+pub(crate) mod progression;
 pub(crate) mod queue;
 pub(crate) mod slot_definitions;
 pub(crate) mod slots;
+pub(crate) mod sockets;
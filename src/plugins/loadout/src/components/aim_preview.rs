@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Outline of a held `OnAim` skill, drawn on the ground around its caster.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub(crate) enum AimPreview {
+	/// Reach around the caster and the area hit at the edge of that reach
+	Area { range: f32, radius: f32 },
+	/// Straight line ahead of the caster
+	Line { range: f32 },
+	/// Sector ahead of the caster, `angle` radians wide
+	Arc { range: f32, angle: f32 },
+}
+
+impl AimPreview {
+	/// Shapes without a fixed reach, like projectiles or shields, have no preview.
+	pub(crate) fn from_shape(shape: &SkillShape) -> Option<Self> {
+		match shape {
+			SkillShape::SphereAoE(SphereAoE {
+				max_range, radius, ..
+			}) => Some(Self::Area {
+				range: **max_range,
+				radius: **radius,
+			}),
+			SkillShape::Beam(Beam { range, .. }) | SkillShape::Dash(Dash { range, .. }) => {
+				Some(Self::Line { range: **range })
+			}
+			SkillShape::MeleeArc(MeleeArc { range, angle, .. }) => Some(Self::Arc {
+				range: **range,
+				angle: angle.to_radians(),
+			}),
+			SkillShape::Projectile(_) | SkillShape::Shield(_) | SkillShape::Chain(_) => None,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::{f32::consts::PI, time::Duration};
+	use test_case::test_case;
+
+	#[test_case(
+		SkillShape::from(SphereAoE {
+			lifetime: None,
+			max_range: Units::from(5.),
+			radius: Units::from(2.),
+		}),
+		Some(AimPreview::Area { range: 5., radius: 2. });
+		"area"
+	)]
+	#[test_case(
+		SkillShape::from(Beam {
+			range: Units::from(10.),
+			blocked_by: Blockers::All,
+		}),
+		Some(AimPreview::Line { range: 10. });
+		"beam"
+	)]
+	#[test_case(
+		SkillShape::from(Dash {
+			range: Units::from(4.),
+			duration: Duration::from_secs(1),
+		}),
+		Some(AimPreview::Line { range: 4. });
+		"dash"
+	)]
+	#[test_case(
+		SkillShape::from(MeleeArc {
+			range: Units::from(3.),
+			angle: 180.,
+			duration: Duration::from_secs(1),
+			destroyed_by: Blockers::All,
+		}),
+		Some(AimPreview::Arc { range: 3., angle: PI });
+		"melee arc"
+	)]
+	#[test_case(SkillShape::from(Shield), None; "shield")]
+	fn from_shape(shape: SkillShape, expected: Option<AimPreview>) {
+		assert_eq!(expected, AimPreview::from_shape(&shape));
+	}
+}
@@ -0,0 +1,107 @@
+use crate::skills::SkillId;
+use bevy::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+/// Skills of an agent, which cannot be used again until their cooldown ran out.
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[savable_component(id = "cooldowns")]
+pub struct Cooldowns(pub(crate) HashMap<SkillId, Cooldown>);
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct Cooldown {
+	pub(crate) remaining: Duration,
+	pub(crate) total: Duration,
+}
+
+impl Cooldowns {
+	pub(crate) fn start(&mut self, skill: SkillId, duration: Duration) {
+		if duration.is_zero() {
+			return;
+		}
+
+		self.0.insert(
+			skill,
+			Cooldown {
+				remaining: duration,
+				total: duration,
+			},
+		);
+	}
+
+	pub(crate) fn is_ready(&self, skill: &SkillId) -> bool {
+		!self.0.contains_key(skill)
+	}
+
+	/// Fraction of the cooldown still remaining, `0.` when the skill is ready.
+	pub(crate) fn remaining_fraction(&self, skill: &SkillId) -> f32 {
+		let Some(Cooldown { remaining, total }) = self.0.get(skill) else {
+			return 0.;
+		};
+
+		remaining.as_secs_f32() / total.as_secs_f32()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use uuid::uuid;
+
+	const SKILL: SkillId = SkillId(uuid!("d0a1c5e4-1b6f-4c33-9f5e-2e3c4b5a6d7e"));
+
+	#[test]
+	fn ready_without_cooldown() {
+		let cooldowns = Cooldowns::default();
+
+		assert!(cooldowns.is_ready(&SKILL));
+	}
+
+	#[test]
+	fn not_ready_when_started() {
+		let mut cooldowns = Cooldowns::default();
+
+		cooldowns.start(SKILL, Duration::from_secs(1));
+
+		assert!(!cooldowns.is_ready(&SKILL));
+	}
+
+	#[test]
+	fn ignore_zero_cooldown() {
+		let mut cooldowns = Cooldowns::default();
+
+		cooldowns.start(SKILL, Duration::ZERO);
+
+		assert_eq!(Cooldowns::default(), cooldowns);
+	}
+
+	#[test]
+	fn remaining_fraction() {
+		let cooldowns = Cooldowns(HashMap::from([(
+			SKILL,
+			Cooldown {
+				remaining: Duration::from_secs(1),
+				total: Duration::from_secs(4),
+			},
+		)]));
+
+		assert_eq!(0.25, cooldowns.remaining_fraction(&SKILL));
+	}
+
+	#[test]
+	fn save_roundtrip() {
+		let cooldowns = Cooldowns(HashMap::from([(
+			SKILL,
+			Cooldown {
+				remaining: Duration::from_secs(1),
+				total: Duration::from_secs(4),
+			},
+		)]));
+
+		let json = serde_json::to_string(&cooldowns).unwrap();
+
+		assert_eq!(cooldowns, serde_json::from_str(&json).unwrap());
+	}
+}
@@ -0,0 +1,9 @@
+use crate::item::Item;
+use bevy::prelude::*;
+
+/// Tracks the visualization of a [`common::components::dropped_item::DroppedItem`].
+#[derive(Component, Debug, PartialEq)]
+pub(crate) enum DroppedItemModel {
+	Loading(Handle<Item>),
+	Loaded,
+}
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// The stacked [`EquipmentStats`] of all items in the agent's slots.
+///
+/// Derived from [`Slots`](crate::components::slots::Slots), so it is not saved.
+#[derive(Component, Debug, PartialEq, Default, Clone, Copy)]
+pub(crate) struct EquipmentStatsInternal(pub(crate) EquipmentStats);
@@ -12,12 +12,37 @@ use zyheeda_core::prelude::*;
 pub struct Inventory(pub(crate) Vec<Option<Handle<Item>>>);
 
 impl Inventory {
+	/// Number of slots shown in the 5 x 5 inventory grid
+	pub(crate) const CAPACITY: usize = 25;
+
 	pub(crate) fn fill_up_to(&mut self, index: usize) {
 		if index < self.0.len() {
 			return;
 		}
 		self.0.resize_with(index + 1, || None);
 	}
+
+	pub(crate) fn free_slots(&self) -> usize {
+		let used = self.0.iter().take(Self::CAPACITY).flatten().count();
+
+		Self::CAPACITY - used
+	}
+
+	/// Does nothing when all [`Inventory::CAPACITY`] slots are used.
+	pub(crate) fn insert_first_free(&mut self, item: Handle<Item>) {
+		let len = self.0.len();
+
+		match self
+			.0
+			.iter_mut()
+			.take(Self::CAPACITY)
+			.find(|slot| slot.is_none())
+		{
+			Some(slot) => *slot = Some(item),
+			None if len < Self::CAPACITY => self.0.push(Some(item)),
+			None => {}
+		}
+	}
 }
 
 impl<T> From<T> for Inventory
@@ -52,3 +77,49 @@ impl<'a> Iterator for Iter<'a> {
 		Some((InventoryKey(i), item))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::new_handle;
+
+	#[test]
+	fn insert_into_first_free_slot() {
+		let a = new_handle();
+		let b = new_handle();
+		let mut inventory = Inventory::from([Some(a.clone()), None, None]);
+
+		inventory.insert_first_free(b.clone());
+
+		assert_eq!(Inventory::from([Some(a), Some(b), None]), inventory);
+	}
+
+	#[test]
+	fn append_when_all_slots_used() {
+		let a = new_handle();
+		let b = new_handle();
+		let mut inventory = Inventory::from([Some(a.clone())]);
+
+		inventory.insert_first_free(b.clone());
+
+		assert_eq!(Inventory::from([Some(a), Some(b)]), inventory);
+	}
+
+	#[test]
+	fn do_not_insert_beyond_capacity() {
+		let full = std::iter::repeat_with(|| Some(new_handle())).take(Inventory::CAPACITY);
+		let mut inventory = Inventory::from(full);
+		let expected = inventory.clone();
+
+		inventory.insert_first_free(new_handle());
+
+		assert_eq!(expected, inventory);
+	}
+
+	#[test]
+	fn count_free_slots() {
+		let inventory = Inventory::from([Some(new_handle()), None, Some(new_handle())]);
+
+		assert_eq!(Inventory::CAPACITY - 2, inventory.free_slots());
+	}
+}
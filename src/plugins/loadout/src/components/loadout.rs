@@ -2,7 +2,10 @@ use crate::components::{
 	active_skill::ActiveSkill,
 	combos::CombosInternal,
 	combos_time_out::CombosTimeOut,
+	equipment_stats::EquipmentStatsInternal,
+	progression::ProgressionInternal,
 	queue::Queue,
+	sockets::SocketsInternal,
 };
 use bevy::prelude::*;
 use std::time::Duration;
@@ -13,5 +16,8 @@ use std::time::Duration;
 	CombosTimeOut = CombosTimeOut::after(Duration::from_secs(2)),
 	Queue,
 	ActiveSkill,
+	ProgressionInternal,
+	SocketsInternal,
+	EquipmentStatsInternal,
 )]
 pub(crate) struct Loadout;
@@ -0,0 +1,230 @@
+use crate::skills::{Skill, SkillId, upgrades::SkillUpgrade};
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Experience, levels and acquired skill upgrades of an agent.
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "progression")]
+pub struct ProgressionInternal {
+	pub(crate) level: u32,
+	pub(crate) experience: u32,
+	pub(crate) skill_points: u32,
+	/// The level for which max health was raised last.
+	pub(crate) health_level: u32,
+	pub(crate) upgrades: HashMap<SkillId, HashSet<UpgradeId>>,
+}
+
+impl ProgressionInternal {
+	const EXPERIENCE_PER_LEVEL: u32 = 100;
+	const SKILL_POINTS_PER_LEVEL: u32 = 1;
+
+	/// Experience needed to advance from the current level to the next.
+	pub(crate) fn next_level(&self) -> u32 {
+		self.level * Self::EXPERIENCE_PER_LEVEL
+	}
+
+	pub(crate) fn gain_experience(&mut self, amount: u32) {
+		self.experience += amount;
+
+		while self.experience >= self.next_level() {
+			self.experience -= self.next_level();
+			self.level += 1;
+			self.skill_points += Self::SKILL_POINTS_PER_LEVEL;
+		}
+	}
+
+	pub(crate) fn acquired(&self, skill: &SkillId) -> Option<&HashSet<UpgradeId>> {
+		self.upgrades.get(skill)
+	}
+
+	pub(crate) fn upgrade_state(&self, skill: &Skill, upgrade: &SkillUpgrade) -> UpgradeState {
+		let acquired = self.acquired(&skill.id);
+		let is_acquired = |id: &UpgradeId| acquired.is_some_and(|acquired| acquired.contains(id));
+
+		if is_acquired(&upgrade.id) {
+			return UpgradeState::Acquired;
+		}
+
+		if upgrade.cost > self.skill_points || !upgrade.requires.iter().all(is_acquired) {
+			return UpgradeState::Locked;
+		}
+
+		UpgradeState::Available
+	}
+
+	pub(crate) fn acquire(&mut self, skill: &Skill, upgrade: &UpgradeId) {
+		let Some(upgrade) = skill.upgrades.iter().find(|u| &u.id == upgrade) else {
+			return;
+		};
+
+		if self.upgrade_state(skill, upgrade) != UpgradeState::Available {
+			return;
+		}
+
+		self.skill_points -= upgrade.cost;
+		self.upgrades
+			.entry(skill.id)
+			.or_default()
+			.insert(upgrade.id.clone());
+	}
+}
+
+impl Default for ProgressionInternal {
+	fn default() -> Self {
+		Self {
+			level: 1,
+			experience: 0,
+			skill_points: 0,
+			health_level: 1,
+			upgrades: HashMap::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	#[test_case(0, 1, 50, 0; "no level up")]
+	#[test_case(50, 2, 0, 1; "level up")]
+	#[test_case(370, 3, 120, 2; "multiple level ups")]
+	fn gain_experience(amount: u32, level: u32, experience: u32, skill_points: u32) {
+		let mut progression = ProgressionInternal {
+			experience: 50,
+			..default()
+		};
+
+		progression.gain_experience(amount);
+
+		assert_eq!(
+			(level, experience, skill_points),
+			(
+				progression.level,
+				progression.experience,
+				progression.skill_points
+			),
+		);
+	}
+
+	fn upgrade(id: &str, cost: u32, requires: &[&str]) -> SkillUpgrade {
+		SkillUpgrade {
+			id: UpgradeId::from(id),
+			token: Token::from(id),
+			cost,
+			requires: requires.iter().copied().map(UpgradeId::from).collect(),
+			modifiers: vec![],
+		}
+	}
+
+	fn skill(upgrades: Vec<SkillUpgrade>) -> Skill {
+		Skill {
+			upgrades,
+			..default()
+		}
+	}
+
+	#[test]
+	fn acquire_upgrade() {
+		let skill = skill(vec![upgrade("a", 2, &[])]);
+		let mut progression = ProgressionInternal {
+			skill_points: 3,
+			..default()
+		};
+
+		progression.acquire(&skill, &UpgradeId::from("a"));
+
+		assert_eq!(
+			ProgressionInternal {
+				skill_points: 1,
+				upgrades: HashMap::from([(skill.id, HashSet::from([UpgradeId::from("a")]))]),
+				..default()
+			},
+			progression,
+		);
+	}
+
+	#[test]
+	fn do_not_acquire_when_points_missing() {
+		let skill = skill(vec![upgrade("a", 2, &[])]);
+		let mut progression = ProgressionInternal {
+			skill_points: 1,
+			..default()
+		};
+
+		progression.acquire(&skill, &UpgradeId::from("a"));
+
+		assert_eq!(
+			ProgressionInternal {
+				skill_points: 1,
+				..default()
+			},
+			progression,
+		);
+	}
+
+	#[test]
+	fn do_not_acquire_when_requirement_missing() {
+		let skill = skill(vec![upgrade("a", 1, &[]), upgrade("b", 1, &["a"])]);
+		let mut progression = ProgressionInternal {
+			skill_points: 1,
+			..default()
+		};
+
+		progression.acquire(&skill, &UpgradeId::from("b"));
+
+		assert_eq!(
+			ProgressionInternal {
+				skill_points: 1,
+				..default()
+			},
+			progression,
+		);
+	}
+
+	#[test]
+	fn do_not_acquire_twice() {
+		let skill = skill(vec![upgrade("a", 1, &[])]);
+		let mut progression = ProgressionInternal {
+			skill_points: 2,
+			upgrades: HashMap::from([(SkillId::default(), HashSet::from([UpgradeId::from("a")]))]),
+			..default()
+		};
+
+		progression.acquire(&skill, &UpgradeId::from("a"));
+
+		assert_eq!(2, progression.skill_points);
+	}
+
+	#[test]
+	fn upgrade_states() {
+		let skill = skill(vec![
+			upgrade("a", 1, &[]),
+			upgrade("b", 1, &["a"]),
+			upgrade("c", 2, &[]),
+			upgrade("d", 1, &[]),
+		]);
+		let progression = ProgressionInternal {
+			skill_points: 1,
+			upgrades: HashMap::from([(skill.id, HashSet::from([UpgradeId::from("a")]))]),
+			..default()
+		};
+
+		assert_eq!(
+			vec![
+				UpgradeState::Acquired,
+				UpgradeState::Available,
+				UpgradeState::Locked,
+				UpgradeState::Available,
+			],
+			skill
+				.upgrades
+				.iter()
+				.map(|upgrade| progression.upgrade_state(&skill, upgrade))
+				.collect::<Vec<_>>(),
+		);
+	}
+}
@@ -2,9 +2,18 @@ pub(crate) mod dto;
 
 use crate::{
 	QueueDto,
-	skills::{QueuedSkill, RunSkillBehavior, Skill, SkillMode, SkillState},
+	components::cooldowns::Cooldowns,
+	skills::{QueuedSkill, RunSkillBehavior, Skill, SkillCost, SkillMode, SkillState},
 	systems::enqueue::held_slots::HeldSlots,
-	traits::{Enqueue, Flush, GetActiveSkill, GetSkillBehavior, IterAddedMut, IterHoldingMut},
+	traits::{
+		Enqueue,
+		Flush,
+		GetActiveSkill,
+		GetSkillBehavior,
+		GetSkillCost,
+		IterAddedMut,
+		IterHoldingMut,
+	},
 };
 use bevy::prelude::*;
 use common::prelude::*;
@@ -27,7 +36,7 @@ enum State {
 
 #[derive(Component, SavableComponent, PartialEq, Debug, Clone)]
 #[savable_component(id = "queue", dto = QueueDto)]
-#[require(HeldSlots)]
+#[require(HeldSlots, Cooldowns)]
 pub struct Queue {
 	queue: VecDeque<QueuedSkill>,
 	active: Option<SkillElapsed<Duration>>,
@@ -201,6 +210,12 @@ impl StateDuration<SkillState> for ActiveSkill<'_> {
 	}
 }
 
+impl GetSkillCost for ActiveSkill<'_> {
+	fn cost(&self) -> SkillCost {
+		self.skill.skill.cost()
+	}
+}
+
 impl GetSkillBehavior for ActiveSkill<'_> {
 	fn behavior(&self) -> (SlotKey, RunSkillBehavior) {
 		(self.skill.key, self.skill.skill.behavior.clone())
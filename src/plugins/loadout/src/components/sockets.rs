@@ -0,0 +1,103 @@
+mod dto;
+
+use crate::{components::sockets::dto::SocketsDto, item::Item, skills::modifier::SkillModifier};
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use std::collections::HashMap;
+
+/// Essences socketed into the items of an agent, keyed by where the items are stored.
+#[derive(Component, SavableComponent, Debug, PartialEq, Default, Clone)]
+#[savable_component(id = "sockets", dto = SocketsDto)]
+pub struct SocketsInternal(pub(crate) HashMap<LoadoutKey, Handle<Item>>);
+
+impl SocketsInternal {
+	/// Let the socketed essences follow their items, when these are swapped.
+	pub(crate) fn swap(&mut self, a: LoadoutKey, b: LoadoutKey) {
+		let essence_a = self.0.remove(&a);
+		let essence_b = self.0.remove(&b);
+
+		if let Some(essence) = essence_a {
+			self.0.insert(b, essence);
+		}
+		if let Some(essence) = essence_b {
+			self.0.insert(a, essence);
+		}
+	}
+
+	pub(crate) fn essence<'a, TKey>(&self, key: TKey, items: &'a Assets<Item>) -> Option<&'a Item>
+	where
+		TKey: Into<LoadoutKey>,
+	{
+		items.get(self.0.get(&key.into())?)
+	}
+
+	/// The modifiers, which the essence socketed at `key` applies to the skill of `item`.
+	pub(crate) fn modifiers_for<'a, TKey>(
+		&self,
+		key: TKey,
+		item: &Item,
+		items: &'a Assets<Item>,
+	) -> &'a [SkillModifier]
+	where
+		TKey: Into<LoadoutKey>,
+	{
+		self.essence(key, items)
+			.and_then(|essence| essence.socket_modifiers_for(item))
+			.unwrap_or_default()
+	}
+}
+
+impl<T> From<T> for SocketsInternal
+where
+	T: IntoIterator<Item = (LoadoutKey, Handle<Item>)>,
+{
+	fn from(sockets: T) -> Self {
+		Self(HashMap::from_iter(sockets))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::new_handle;
+
+	#[test]
+	fn swap_socketed_essences() {
+		let a = new_handle();
+		let b = new_handle();
+		let mut sockets = SocketsInternal::from([
+			(LoadoutKey::from(SlotKey(1)), a.clone()),
+			(LoadoutKey::from(InventoryKey(2)), b.clone()),
+		]);
+
+		sockets.swap(
+			LoadoutKey::from(SlotKey(1)),
+			LoadoutKey::from(InventoryKey(2)),
+		);
+
+		assert_eq!(
+			SocketsInternal::from([
+				(LoadoutKey::from(SlotKey(1)), b),
+				(LoadoutKey::from(InventoryKey(2)), a),
+			]),
+			sockets,
+		);
+	}
+
+	#[test]
+	fn move_socketed_essence_to_empty_key() {
+		let a = new_handle();
+		let mut sockets = SocketsInternal::from([(LoadoutKey::from(SlotKey(1)), a.clone())]);
+
+		sockets.swap(
+			LoadoutKey::from(SlotKey(1)),
+			LoadoutKey::from(InventoryKey(2)),
+		);
+
+		assert_eq!(
+			SocketsInternal::from([(LoadoutKey::from(InventoryKey(2)), a)]),
+			sockets,
+		);
+	}
+}
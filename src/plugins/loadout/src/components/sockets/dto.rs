@@ -0,0 +1,64 @@
+use crate::components::sockets::SocketsInternal;
+use bevy::asset::AssetPath;
+use common::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct SocketsDto(Vec<(LoadoutKey, String)>);
+
+impl From<SocketsInternal> for SocketsDto {
+	fn from(SocketsInternal(sockets): SocketsInternal) -> Self {
+		Self(
+			sockets
+				.into_iter()
+				.filter_map(|(key, essence)| Some((key, essence.path().map(AssetPath::to_string)?)))
+				.collect(),
+		)
+	}
+}
+
+impl TryLoadFrom<SocketsDto> for SocketsInternal {
+	type TInstantiationError = Unreachable;
+
+	fn try_load_from<TLoadAsset>(
+		SocketsDto(sockets): SocketsDto,
+		asset_server: &mut TLoadAsset,
+	) -> Result<Self, Self::TInstantiationError>
+	where
+		TLoadAsset: LoadAsset,
+	{
+		Ok(Self(
+			sockets
+				.into_iter()
+				.map(|(key, essence)| (key, asset_server.load_asset(essence)))
+				.collect(),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use common::traits::load_asset::mock_asset_loader::MockAssetServer;
+	use std::collections::HashMap;
+	use testing::new_handle;
+
+	#[test]
+	fn deserialize() {
+		let essence = new_handle();
+		let dto = SocketsDto(vec![(
+			LoadoutKey::from(SlotKey(1)),
+			"asset/path".to_owned(),
+		)]);
+		let mut server = MockAssetServer::default()
+			.path("asset/path")
+			.returns(essence.clone());
+
+		let Ok(sockets) = SocketsInternal::try_load_from(dto, &mut server);
+
+		assert_eq!(
+			SocketsInternal(HashMap::from([(LoadoutKey::from(SlotKey(1)), essence)])),
+			sockets,
+		);
+	}
+}
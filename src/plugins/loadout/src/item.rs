@@ -1,12 +1,14 @@
 pub(crate) mod dto;
+pub(crate) mod stats;
 
 use crate::{
 	components::model_render::ModelRender,
-	skills::Skill,
+	skills::{Skill, modifier::SkillModifier},
 	traits::visualize_item::VisualizeItem,
 };
 use bevy::prelude::*;
 use common::{components::essence::Essence, prelude::*, tools::path::Path};
+use std::collections::HashMap;
 
 #[derive(Debug, PartialEq, Default, Clone, Asset, TypePath)]
 pub struct Item {
@@ -15,6 +17,23 @@ pub struct Item {
 	pub essence: Essence,
 	pub skill: Option<Handle<Skill>>,
 	pub item_type: ItemType,
+	pub cast_sound: Option<SoundCue>,
+	/// Modifiers applied to the skill of an item of the given type, when this item is
+	/// socketed into it.
+	pub(crate) socket_modifiers: HashMap<ItemType, Vec<SkillModifier>>,
+	/// Attribute changes granted to the agent, while this item is equipped in a slot.
+	pub(crate) equipment_stats: EquipmentStats,
+}
+
+impl Item {
+	/// The modifiers this item applies when socketed into `item`.
+	///
+	/// Returns `None`, if this item cannot be socketed into `item`.
+	pub(crate) fn socket_modifiers_for(&self, item: &Item) -> Option<&[SkillModifier]> {
+		self.socket_modifiers
+			.get(&item.item_type)
+			.map(Vec::as_slice)
+	}
 }
 
 impl View<ItemType> for Item {
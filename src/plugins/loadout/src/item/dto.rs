@@ -1,8 +1,9 @@
 use super::Item;
-use crate::components::model_render::ModelRender;
+use crate::{components::model_render::ModelRender, skills::modifier::SkillModifier};
 use bevy::reflect::TypePath;
 use common::{components::essence::Essence, prelude::*};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 type SkillPath = String;
 
@@ -13,6 +14,12 @@ pub(crate) struct ItemDto {
 	essence: Essence,
 	skill: Option<SkillPath>,
 	item_type: ItemType,
+	#[serde(default)]
+	cast_sound: Option<SoundCue>,
+	#[serde(default)]
+	socket_modifiers: HashMap<ItemType, Vec<SkillModifier>>,
+	#[serde(default)]
+	equipment_stats: EquipmentStats,
 }
 
 impl TryLoadFrom<ItemDto> for Item {
@@ -28,6 +35,9 @@ impl TryLoadFrom<ItemDto> for Item {
 			essence: from.essence,
 			skill: from.skill.map(|path| asset_server.load_asset(path)),
 			item_type: from.item_type,
+			cast_sound: from.cast_sound,
+			socket_modifiers: from.socket_modifiers,
+			equipment_stats: from.equipment_stats,
 		})
 	}
 }
@@ -0,0 +1,94 @@
+use crate::item::Item;
+use common::prelude::*;
+
+impl Item {
+	/// Tokens describing the [`EquipmentStats`] that deviate from the neutral defaults.
+	pub(crate) fn equipment_stat_tokens(&self) -> Vec<Token> {
+		let EquipmentStats {
+			max_health,
+			movement_speed,
+			damage,
+			resistances,
+		} = self.equipment_stats;
+		let percent = |factor: f32| (factor * 100.).round();
+		let mut stats = vec![];
+
+		if max_health != 0. {
+			stats.push(Token::from("item-stat-max-health").with_arg("amount", max_health));
+		}
+
+		if movement_speed != 1. {
+			stats.push(
+				Token::from("item-stat-movement-speed")
+					.with_arg("percent", percent(movement_speed - 1.)),
+			);
+		}
+
+		if damage != 1. {
+			stats.push(Token::from("item-stat-damage").with_arg("percent", percent(damage - 1.)));
+		}
+
+		for damage_type in [
+			DamageType::Kinetic,
+			DamageType::Plasma,
+			DamageType::Void,
+			DamageType::Force,
+		] {
+			let resistance = resistances[damage_type];
+			if resistance == 0. {
+				continue;
+			}
+			stats.push(
+				Token::from("item-stat-resistance")
+					.with_arg("percent", percent(resistance))
+					.with_arg("type", Token::from(damage_type)),
+			);
+		}
+
+		stats
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn no_stats_when_neutral() {
+		let item = Item::default();
+
+		assert_eq!(vec![] as Vec<Token>, item.equipment_stat_tokens());
+	}
+
+	#[test]
+	fn all_stats() {
+		let item = Item {
+			equipment_stats: EquipmentStats {
+				max_health: 20.,
+				movement_speed: 0.9,
+				damage: 1.25,
+				resistances: Resistances {
+					plasma: 0.5,
+					void: -0.25,
+					..Resistances::default()
+				},
+			},
+			..Item::default()
+		};
+
+		assert_eq!(
+			vec![
+				Token::from("item-stat-max-health").with_arg("amount", 20.),
+				Token::from("item-stat-movement-speed").with_arg("percent", -10.),
+				Token::from("item-stat-damage").with_arg("percent", 25.),
+				Token::from("item-stat-resistance")
+					.with_arg("percent", 50.)
+					.with_arg("type", Token::from(DamageType::Plasma)),
+				Token::from("item-stat-resistance")
+					.with_arg("percent", -25.)
+					.with_arg("type", Token::from(DamageType::Void)),
+			],
+			item.equipment_stat_tokens()
+		);
+	}
+}
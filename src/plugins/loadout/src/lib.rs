@@ -7,11 +7,16 @@ mod traits;
 
 use crate::{
 	components::{
+		aim_preview::AimPreview,
 		combos::dto::CombosInternalDto,
 		combos_time_out::dto::CombosTimeOutDto,
+		dropped_item_model::DroppedItemModel,
+		equipment_stats::EquipmentStatsInternal,
+		progression::ProgressionInternal,
 		queue::dto::QueueDto,
 		slot_definitions::SlotDefinitions,
 		slots::visualization::SlotVisualization,
+		sockets::SocketsInternal,
 	},
 	skills::{SkillId, behaviors::SkillBehaviorConfig},
 	system_parameters::{
@@ -19,7 +24,7 @@ use crate::{
 			LoadoutPrep,
 			LoadoutReader,
 			LoadoutWriter,
-			write::insert_default_loadout::DefaultLoadout,
+			write::{insert_default_loadout::DefaultLoadout, items::ItemsMut},
 		},
 		loadout_activity::{LoadoutActivityReader, LoadoutActivityWriter},
 	},
@@ -34,6 +39,7 @@ use components::{
 	active_skill::ActiveSkill,
 	combos::CombosInternal,
 	combos_time_out::CombosTimeOut,
+	cooldowns::Cooldowns,
 	inventory::Inventory,
 	queue::Queue,
 	slots::Slots,
@@ -53,9 +59,19 @@ impl<TSaveGame, TPhysics, TLoading, TMovement>
 	LoadoutPlugin<(TSaveGame, TPhysics, TLoading, TMovement)>
 where
 	TSaveGame: ThreadSafe + HandlesSaving,
-	TPhysics: ThreadSafe + HandlesAllPhysicalEffects + HandlesSkillPhysics + HandlesRaycast,
+	TPhysics: ThreadSafe
+		+ HandlesAllPhysicalEffects
+		+ HandlesEnergy
+		+ HandlesMaxHealth
+		+ HandlesEquipmentStats
+		+ HandlesSkillPhysics
+		+ HandlesRaycast,
 	TLoading: ThreadSafe + HandlesCustomAssets + HandlesCustomFolderAssets + HandlesLoadTracking,
-	TMovement: ThreadSafe + HandlesOrientation + SystemSetDefinition,
+	TMovement: ThreadSafe
+		+ HandlesOrientation
+		+ HandlesMovement
+		+ HandlesEquipmentStats
+		+ SystemSetDefinition,
 {
 	#[allow(clippy::too_many_arguments)]
 	pub fn from_plugins(_: &TSaveGame, _: &TPhysics, _: &TLoading, _: &TMovement) -> Self {
@@ -73,8 +89,10 @@ where
 	fn loadout(&self, app: &mut App) {
 		TSaveGame::register_savable_component::<Inventory>(app);
 		TSaveGame::register_savable_component::<Slots>(app);
+		TSaveGame::register_savable_component::<SocketsInternal>(app);
 
 		app.add_observer(DefaultLoadout::insert::<AssetServer>);
+		app.add_observer(ItemsMut::add::<AssetServer>);
 		app.add_systems(
 			Update,
 			(
@@ -84,9 +102,20 @@ where
 				SlotVisualization::<VisibleForearmSlot>::visualize_items,
 				SlotVisualization::<VisibleEssenceSlot>::track_slots_for::<SlotDefinitions>,
 				SlotVisualization::<VisibleEssenceSlot>::visualize_items,
+				DroppedItemModel::visualize::<AssetServer>,
 			)
 				.chain(),
 		);
+		app.add_systems(
+			Update,
+			(
+				EquipmentStatsInternal::update,
+				EquipmentStatsInternal::insert::<TPhysics::TEquipmentStats>,
+				EquipmentStatsInternal::insert::<TMovement::TEquipmentStats>,
+			)
+				.chain()
+				.run_if(in_state(GameState::Play)),
+		);
 	}
 
 	fn skill_execution(&self, app: &mut App) {
@@ -94,14 +123,29 @@ where
 		TSaveGame::register_savable_component::<CombosInternal>(app);
 		TSaveGame::register_savable_component::<Queue>(app);
 		TSaveGame::register_savable_component::<ActiveSkill>(app);
+		TSaveGame::register_savable_component::<Cooldowns>(app);
+		TSaveGame::register_savable_component::<ProgressionInternal>(app);
 
-		app.add_systems(
+		app.add_message::<PlaySound>().add_systems(
 			Update,
 			(
-				Queue::enqueue_system::<Slots>,
+				Update::delta.pipe(Cooldowns::advance),
+				AimPreview::update::<Slots>,
+				Queue::enqueue_system::<Slots, TPhysics::TStatusEffects, TPhysics::TEnergy>,
 				CombosInternal::update::<Queue>,
+				ProgressionInternal::apply_upgrades::<Queue>,
+				SocketsInternal::apply_essences::<Queue>,
+				EquipmentStatsInternal::apply_damage::<Queue>,
 				flush_skill_combos::<CombosInternal, CombosTimeOut, Virtual, Queue>,
-				schedule_active_skill::<Queue, TMovement::TFaceSystemParam, ActiveSkill, Virtual>,
+				schedule_active_skill::<
+					Queue,
+					TMovement::TFaceSystemParam,
+					ActiveSkill,
+					TPhysics::TEnergy,
+					Virtual,
+				>,
+				ActiveSkill::<SkillBehaviorConfig>::play_cast_sound,
+				ActiveSkill::<SkillBehaviorConfig>::dash::<TMovement::TMovementMut>,
 				ActiveSkill::<SkillBehaviorConfig>::execute::<TPhysics::TSkillSpawnerMut>,
 				Queue::flush_system,
 			)
@@ -109,6 +153,14 @@ where
 				.after_plugin(TMovement::SYSTEMS)
 				.run_if(in_state(GameState::Play)),
 		);
+		app.add_systems(
+			Update,
+			(
+				ProgressionInternal::raise_max_health::<TPhysics::TMaxHealth>,
+				AimPreview::draw,
+			)
+				.run_if(in_state(GameState::Play)),
+		);
 	}
 }
 
@@ -116,9 +168,19 @@ impl<TSaveGame, TPhysics, TLoading, TMovement> Plugin
 	for LoadoutPlugin<(TSaveGame, TPhysics, TLoading, TMovement)>
 where
 	TSaveGame: ThreadSafe + HandlesSaving,
-	TPhysics: ThreadSafe + HandlesAllPhysicalEffects + HandlesSkillPhysics + HandlesRaycast,
+	TPhysics: ThreadSafe
+		+ HandlesAllPhysicalEffects
+		+ HandlesEnergy
+		+ HandlesMaxHealth
+		+ HandlesEquipmentStats
+		+ HandlesSkillPhysics
+		+ HandlesRaycast,
 	TLoading: ThreadSafe + HandlesCustomAssets + HandlesCustomFolderAssets + HandlesLoadTracking,
-	TMovement: ThreadSafe + HandlesOrientation + SystemSetDefinition,
+	TMovement: ThreadSafe
+		+ HandlesOrientation
+		+ HandlesMovement
+		+ HandlesEquipmentStats
+		+ SystemSetDefinition,
 {
 	fn build(&self, app: &mut App) {
 		self.skill_load(app);
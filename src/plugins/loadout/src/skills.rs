@@ -1,8 +1,15 @@
 pub(crate) mod behaviors;
 pub(crate) mod dto;
+pub(crate) mod modifier;
 pub(crate) mod shape;
+pub(crate) mod stats;
+pub(crate) mod upgrades;
 
-use crate::{skills::behaviors::SkillBehaviorConfig, traits::ReleaseSkill};
+use crate::{
+	components::cooldowns::Cooldowns,
+	skills::{behaviors::SkillBehaviorConfig, upgrades::SkillUpgrade},
+	traits::{GetSkillCost, ReleaseSkill},
+};
 use bevy::prelude::*;
 use common::{prelude::*, tools::path::Path};
 use serde::{Deserialize, Serialize};
@@ -22,10 +29,13 @@ pub struct Skill {
 	pub(crate) id: SkillId,
 	pub(crate) token: Token,
 	pub(crate) cast_time: Duration,
+	pub(crate) cooldown: Duration,
+	pub(crate) energy_cost: f32,
 	pub(crate) animation: Option<SkillAnimation>,
 	pub(crate) behavior: RunSkillBehavior,
 	pub(crate) compatible_items: CompatibleItems,
 	pub(crate) icon: Handle<Image>,
+	pub(crate) upgrades: Vec<SkillUpgrade>,
 }
 
 impl Display for Skill {
@@ -67,12 +77,62 @@ impl GetSkillId<SkillId> for Skill {
 	}
 }
 
+impl GetSkillCost for Skill {
+	fn cost(&self) -> SkillCost {
+		SkillCost {
+			id: self.id,
+			cooldown: self.cooldown,
+			energy: self.energy_cost,
+		}
+	}
+}
+
+/// What using a skill costs: its cooldown and energy.
 #[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct SkillCost {
+	pub(crate) id: SkillId,
+	pub(crate) cooldown: Duration,
+	pub(crate) energy: f32,
+}
+
+impl SkillCost {
+	pub(crate) fn is_affordable(
+		&self,
+		cooldowns: Option<&Cooldowns>,
+		energy: Option<Energy>,
+	) -> bool {
+		let ready = cooldowns.is_none_or(|cooldowns| cooldowns.is_ready(&self.id));
+		let enough_energy =
+			self.energy <= 0. || energy.is_some_and(|energy| energy.current >= self.energy);
+
+		ready && enough_energy
+	}
+
+	pub(crate) fn pay<TEnergy>(
+		&self,
+		cooldowns: Option<&mut Cooldowns>,
+		energy: Option<&mut TEnergy>,
+	) where
+		TEnergy: SpendEnergy,
+	{
+		if let Some(cooldowns) = cooldowns {
+			cooldowns.start(self.id, self.cooldown);
+		}
+
+		if let Some(energy) = energy
+			&& self.energy > 0.
+		{
+			energy.spend_energy(self.energy);
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub struct SkillId(pub(crate) Uuid);
 
 #[cfg(test)]
 impl SkillId {
-	const DEFAULT_ID: SkillId = SkillId(uuid!("9443883c-3972-43da-a2d7-0a013f16d564"));
+	pub(crate) const DEFAULT_ID: SkillId = SkillId(uuid!("9443883c-3972-43da-a2d7-0a013f16d564"));
 }
 
 #[cfg(test)]
@@ -9,6 +9,9 @@ pub struct SkillBehaviorConfig {
 	pub(crate) shape: SkillShape,
 	pub(crate) contact: Vec<SkillEffect>,
 	pub(crate) projection: Vec<SkillEffect>,
+	pub(crate) cast_sound: Option<SoundCue>,
+	pub(crate) impact_sound: Option<SoundCue>,
+	pub(crate) follow_ups: Vec<FollowUpSkill>,
 }
 
 impl SkillBehaviorConfig {
@@ -18,6 +21,9 @@ impl SkillBehaviorConfig {
 			shape,
 			contact: vec![],
 			projection: vec![],
+			cast_sound: None,
+			impact_sound: None,
+			follow_ups: vec![],
 		}
 	}
 }
@@ -25,8 +31,13 @@ impl SkillBehaviorConfig {
 impl SkillConfigData for SkillBehaviorConfig {
 	fn mount(&self, slot: SlotKey) -> SkillMount {
 		match &self.shape {
-			SkillShape::SphereAoE(_) | SkillShape::Shield(_) => SkillMount::Center,
-			SkillShape::Projectile(_) | SkillShape::Beam(_) => SkillMount::slot(slot),
+			SkillShape::SphereAoE(_) | SkillShape::Shield(_) | SkillShape::Dash(_) => {
+				SkillMount::Center
+			}
+			SkillShape::Projectile(_)
+			| SkillShape::Beam(_)
+			| SkillShape::MeleeArc(_)
+			| SkillShape::Chain(_) => SkillMount::slot(slot),
 		}
 	}
 
@@ -42,6 +53,18 @@ impl SkillConfigData for SkillBehaviorConfig {
 		&self.projection
 	}
 
+	fn cast_sound(&self) -> Option<&'_ SoundCue> {
+		self.cast_sound.as_ref()
+	}
+
+	fn impact_sound(&self) -> Option<&'_ SoundCue> {
+		self.impact_sound.as_ref()
+	}
+
+	fn follow_ups(&self) -> &'_ [FollowUpSkill] {
+		&self.follow_ups
+	}
+
 	fn on_skill_stop(&self, skill: PersistentEntity) -> OnSkillStop {
 		match &self.shape {
 			SkillShape::Beam(_) | SkillShape::Shield(_) => OnSkillStop::Stop(skill),
@@ -7,6 +7,12 @@ pub(crate) struct SkillBehaviorConfigDto {
 	shape: SpawnSkillDto,
 	contact: Vec<SkillEffect>,
 	projection: Vec<SkillEffect>,
+	#[serde(default)]
+	cast_sound: Option<SoundCue>,
+	#[serde(default)]
+	impact_sound: Option<SoundCue>,
+	#[serde(default)]
+	follow_ups: Vec<FollowUpSkillDto>,
 }
 
 impl From<SkillBehaviorConfigDto> for SkillBehaviorConfig {
@@ -15,6 +21,13 @@ impl From<SkillBehaviorConfigDto> for SkillBehaviorConfig {
 			shape: SkillShape::from(value.shape),
 			contact: value.contact,
 			projection: value.projection,
+			cast_sound: value.cast_sound,
+			impact_sound: value.impact_sound,
+			follow_ups: value
+				.follow_ups
+				.into_iter()
+				.map(FollowUpSkill::from)
+				.collect(),
 		}
 	}
 }
@@ -25,6 +38,51 @@ impl From<SkillBehaviorConfig> for SkillBehaviorConfigDto {
 			shape: SpawnSkillDto::from(value.shape),
 			contact: value.contact,
 			projection: value.projection,
+			cast_sound: value.cast_sound,
+			impact_sound: value.impact_sound,
+			follow_ups: value
+				.follow_ups
+				.into_iter()
+				.map(FollowUpSkillDto::from)
+				.collect(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(crate) struct FollowUpSkillDto {
+	trigger: FollowUpTrigger<DurationInSeconds>,
+	shape: SpawnSkillDto,
+	contact: Vec<SkillEffect>,
+	projection: Vec<SkillEffect>,
+	#[serde(default)]
+	impact_sound: Option<SoundCue>,
+	#[serde(default)]
+	follow_ups: Vec<FollowUpSkillDto>,
+}
+
+impl From<FollowUpSkillDto> for FollowUpSkill {
+	fn from(value: FollowUpSkillDto) -> Self {
+		Self {
+			trigger: FollowUpTrigger::from(value.trigger),
+			shape: SkillShape::from(value.shape),
+			contact_effects: value.contact,
+			projection_effects: value.projection,
+			impact_sound: value.impact_sound,
+			follow_ups: value.follow_ups.into_iter().map(Self::from).collect(),
+		}
+	}
+}
+
+impl From<FollowUpSkill> for FollowUpSkillDto {
+	fn from(value: FollowUpSkill) -> Self {
+		Self {
+			trigger: FollowUpTrigger::from(value.trigger),
+			shape: SpawnSkillDto::from(value.shape),
+			contact: value.contact_effects,
+			projection: value.projection_effects,
+			impact_sound: value.impact_sound,
+			follow_ups: value.follow_ups.into_iter().map(Self::from).collect(),
 		}
 	}
 }
@@ -35,6 +93,9 @@ pub(crate) enum SpawnSkillDto {
 	Projectile(Projectile),
 	Beam(Beam),
 	Shield(Shield),
+	MeleeArc(MeleeArc<DurationInSeconds>),
+	Chain(Chain),
+	Dash(Dash<DurationInSeconds>),
 }
 
 impl From<SpawnSkillDto> for SkillShape {
@@ -44,6 +105,9 @@ impl From<SpawnSkillDto> for SkillShape {
 			SpawnSkillDto::Projectile(v) => Self::Projectile(v),
 			SpawnSkillDto::Shield(v) => Self::Shield(v),
 			SpawnSkillDto::Beam(v) => Self::Beam(v),
+			SpawnSkillDto::MeleeArc(v) => Self::MeleeArc(v.into()),
+			SpawnSkillDto::Chain(v) => Self::Chain(v),
+			SpawnSkillDto::Dash(v) => Self::Dash(v.into()),
 		}
 	}
 }
@@ -55,6 +119,9 @@ impl From<SkillShape> for SpawnSkillDto {
 			SkillShape::Projectile(v) => Self::Projectile(v),
 			SkillShape::Shield(v) => Self::Shield(v),
 			SkillShape::Beam(v) => Self::Beam(v),
+			SkillShape::MeleeArc(v) => Self::MeleeArc(v.into()),
+			SkillShape::Chain(v) => Self::Chain(v),
+			SkillShape::Dash(v) => Self::Dash(v.into()),
 		}
 	}
 }
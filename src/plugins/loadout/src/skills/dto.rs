@@ -3,6 +3,7 @@ use crate::skills::{
 	RunSkillBehavior,
 	SkillId,
 	behaviors::{SkillBehaviorConfig, dto::SkillBehaviorConfigDto},
+	upgrades::{SkillUpgrade, dto::SkillUpgradeDto},
 };
 use bevy::{
 	asset::{AssetPath, Handle},
@@ -18,10 +19,16 @@ pub(crate) struct SkillDto {
 	id: Uuid,
 	token: String,
 	cast_time: DurationInSeconds,
+	#[serde(default)]
+	cooldown: DurationInSeconds,
+	#[serde(default)]
+	energy_cost: f32,
 	animation: Option<SkillAnimation>,
 	behavior: RunSkillBehaviorDto,
 	is_usable_with: HashSet<ItemType>,
 	icon: Option<Path>,
+	#[serde(default)]
+	upgrades: Vec<SkillUpgradeDto>,
 }
 
 impl AssetFileExtensions for SkillDto {
@@ -41,6 +48,8 @@ impl TryLoadFrom<SkillDto> for Skill {
 			id: SkillId(skill_data.id),
 			token: Token::from(skill_data.token),
 			cast_time: Duration::from(skill_data.cast_time),
+			cooldown: Duration::from(skill_data.cooldown),
+			energy_cost: skill_data.energy_cost,
 			animation: skill_data.animation,
 			behavior: RunSkillBehavior::from(skill_data.behavior),
 			compatible_items: CompatibleItems(skill_data.is_usable_with),
@@ -48,6 +57,11 @@ impl TryLoadFrom<SkillDto> for Skill {
 				Some(icon) => asset_server.load_asset(icon),
 				None => Handle::default(),
 			},
+			upgrades: skill_data
+				.upgrades
+				.into_iter()
+				.map(SkillUpgrade::from)
+				.collect(),
 		})
 	}
 }
@@ -58,10 +72,17 @@ impl From<Skill> for SkillDto {
 			id: skill.id.0,
 			token: (*skill.token).to_owned(),
 			cast_time: DurationInSeconds::from(skill.cast_time),
+			cooldown: DurationInSeconds::from(skill.cooldown),
+			energy_cost: skill.energy_cost,
 			animation: skill.animation,
 			behavior: RunSkillBehaviorDto::from(skill.behavior),
 			is_usable_with: skill.compatible_items.0,
 			icon: skill.icon.path().map(AssetPath::to_string).map(Path::from),
+			upgrades: skill
+				.upgrades
+				.into_iter()
+				.map(SkillUpgradeDto::from)
+				.collect(),
 		}
 	}
 }
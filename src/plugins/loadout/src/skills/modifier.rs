@@ -0,0 +1,223 @@
+use crate::skills::{RunSkillBehavior, Skill, behaviors::SkillBehaviorConfig};
+use common::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Changes the behavior of a skill, either by scaling the matching values by the contained
+/// factor or by adding effects.
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) enum SkillModifier {
+	Damage(f32),
+	Radius(f32),
+	Range(f32),
+	Lifetime(f32),
+	AddContact(SkillEffect),
+	AddProjection(SkillEffect),
+}
+
+impl Skill {
+	/// Apply the modifiers in the given order.
+	pub(crate) fn apply_modifiers<'a, TModifiers>(&mut self, modifiers: TModifiers)
+	where
+		TModifiers: IntoIterator<Item = &'a SkillModifier>,
+	{
+		let (RunSkillBehavior::OnActive(config) | RunSkillBehavior::OnAim(config)) =
+			&mut self.behavior;
+
+		for modifier in modifiers {
+			config.apply(*modifier);
+		}
+	}
+}
+
+impl SkillBehaviorConfig {
+	fn apply(&mut self, modifier: SkillModifier) {
+		match (modifier, &mut self.shape) {
+			(SkillModifier::Damage(factor), _) => {
+				let effects = self.contact.iter_mut().chain(self.projection.iter_mut());
+				for effect in effects {
+					scale_damage(effect, factor);
+				}
+				scale_follow_up_damage(&mut self.follow_ups, factor);
+			}
+			(SkillModifier::Radius(factor), SkillShape::SphereAoE(sphere)) => {
+				sphere.radius = Units::from(*sphere.radius * factor);
+			}
+			(SkillModifier::Range(factor), SkillShape::SphereAoE(sphere)) => {
+				sphere.max_range = Units::from(*sphere.max_range * factor);
+			}
+			(SkillModifier::Range(factor), SkillShape::Beam(beam)) => {
+				beam.range = Units::from(*beam.range * factor);
+			}
+			(SkillModifier::Range(factor), SkillShape::MeleeArc(arc)) => {
+				arc.range = Units::from(*arc.range * factor);
+			}
+			(SkillModifier::Range(factor), SkillShape::Chain(chain)) => {
+				chain.range = Units::from(*chain.range * factor);
+			}
+			(SkillModifier::Range(factor), SkillShape::Dash(dash)) => {
+				dash.range = Units::from(*dash.range * factor);
+			}
+			(SkillModifier::Lifetime(factor), SkillShape::SphereAoE(sphere)) => {
+				sphere.lifetime = sphere.lifetime.map(|lifetime| lifetime.mul_f32(factor));
+			}
+			(SkillModifier::AddContact(effect), _) => {
+				self.contact.push(effect);
+			}
+			(SkillModifier::AddProjection(effect), _) => {
+				self.projection.push(effect);
+			}
+			_ => {}
+		}
+	}
+}
+
+fn scale_follow_up_damage(follow_ups: &mut [FollowUpSkill], factor: f32) {
+	for follow_up in follow_ups {
+		let effects = follow_up
+			.contact_effects
+			.iter_mut()
+			.chain(follow_up.projection_effects.iter_mut());
+		for effect in effects {
+			scale_damage(effect, factor);
+		}
+		scale_follow_up_damage(&mut follow_up.follow_ups, factor);
+	}
+}
+
+fn scale_damage(effect: &mut SkillEffect, factor: f32) {
+	match effect {
+		SkillEffect::HealthDamage(HealthDamage(damage, ..)) => {
+			*damage *= factor;
+		}
+		SkillEffect::DamageOverTime(DamageOverTime {
+			damage_per_second, ..
+		}) => {
+			*damage_per_second *= factor;
+		}
+		_ => {}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	fn skill(config: SkillBehaviorConfig) -> Skill {
+		Skill {
+			behavior: RunSkillBehavior::OnActive(config),
+			..Skill::default()
+		}
+	}
+
+	#[test]
+	fn scale_damage_effects() {
+		let mut skill = skill(SkillBehaviorConfig {
+			contact: vec![SkillEffect::HealthDamage(HealthDamage::once(10.))],
+			projection: vec![SkillEffect::HealthDamage(HealthDamage::per_second(4.))],
+			..SkillBehaviorConfig::from_shape(SkillShape::Shield(Shield))
+		});
+
+		skill.apply_modifiers(&[SkillModifier::Damage(1.5)]);
+
+		assert_eq!(
+			RunSkillBehavior::OnActive(SkillBehaviorConfig {
+				contact: vec![SkillEffect::HealthDamage(HealthDamage::once(15.))],
+				projection: vec![SkillEffect::HealthDamage(HealthDamage::per_second(6.))],
+				..SkillBehaviorConfig::from_shape(SkillShape::Shield(Shield))
+			}),
+			skill.behavior,
+		);
+	}
+
+	#[test]
+	fn scale_follow_up_damage_effects() {
+		let follow_up = |damage: f32, follow_ups: Vec<FollowUpSkill>| FollowUpSkill {
+			trigger: FollowUpTrigger::OnContact,
+			shape: SkillShape::Shield(Shield),
+			contact_effects: vec![SkillEffect::HealthDamage(HealthDamage::once(damage))],
+			projection_effects: vec![SkillEffect::HealthDamage(HealthDamage::per_second(damage))],
+			impact_sound: None,
+			follow_ups,
+		};
+		let mut skill = skill(SkillBehaviorConfig {
+			follow_ups: vec![follow_up(10., vec![follow_up(4., vec![])])],
+			..SkillBehaviorConfig::from_shape(SkillShape::Shield(Shield))
+		});
+
+		skill.apply_modifiers(&[SkillModifier::Damage(1.5)]);
+
+		assert_eq!(
+			RunSkillBehavior::OnActive(SkillBehaviorConfig {
+				follow_ups: vec![follow_up(15., vec![follow_up(6., vec![])])],
+				..SkillBehaviorConfig::from_shape(SkillShape::Shield(Shield))
+			}),
+			skill.behavior,
+		);
+	}
+
+	#[test]
+	fn scale_sphere_values() {
+		let mut skill = skill(SkillBehaviorConfig::from_shape(SkillShape::SphereAoE(
+			SphereAoE {
+				lifetime: Some(Duration::from_secs(2)),
+				max_range: Units::from(10.),
+				radius: Units::from(2.),
+			},
+		)));
+
+		skill.apply_modifiers(&[
+			SkillModifier::Radius(2.),
+			SkillModifier::Range(1.5),
+			SkillModifier::Lifetime(3.),
+		]);
+
+		assert_eq!(
+			RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(SkillShape::SphereAoE(
+				SphereAoE {
+					lifetime: Some(Duration::from_secs(6)),
+					max_range: Units::from(15.),
+					radius: Units::from(4.),
+				}
+			))),
+			skill.behavior,
+		);
+	}
+
+	#[test]
+	fn add_effects() {
+		let mut skill = skill(SkillBehaviorConfig {
+			contact: vec![SkillEffect::HealthDamage(HealthDamage::once(10.))],
+			..SkillBehaviorConfig::from_shape(SkillShape::Projectile(Projectile {
+				destroyed_by: Blockers::All,
+				motion: ProjectileMotion::Straight,
+				impact: ProjectileImpact::Destroy,
+			}))
+		});
+
+		skill.apply_modifiers(&[
+			SkillModifier::AddContact(SkillEffect::Force(Force::default())),
+			SkillModifier::AddProjection(SkillEffect::Gravity(Gravity {
+				strength: UnitsPerSecond::from(2.),
+			})),
+		]);
+
+		assert_eq!(
+			RunSkillBehavior::OnActive(SkillBehaviorConfig {
+				contact: vec![
+					SkillEffect::HealthDamage(HealthDamage::once(10.)),
+					SkillEffect::Force(Force::default()),
+				],
+				projection: vec![SkillEffect::Gravity(Gravity {
+					strength: UnitsPerSecond::from(2.),
+				})],
+				..SkillBehaviorConfig::from_shape(SkillShape::Projectile(Projectile {
+					destroyed_by: Blockers::All,
+					motion: ProjectileMotion::Straight,
+					impact: ProjectileImpact::Destroy,
+				}))
+			}),
+			skill.behavior,
+		);
+	}
+}
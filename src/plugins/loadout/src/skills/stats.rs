@@ -0,0 +1,227 @@
+use crate::skills::{RunSkillBehavior, Skill};
+use common::prelude::*;
+use std::time::Duration;
+
+impl Skill {
+	/// Localizable lines for the values of this skill, each carrying its values as
+	/// fluent arguments.
+	pub(crate) fn stats(&self) -> Vec<Token> {
+		let mut stats = vec![
+			Token::from("skill-stat-cast-time").with_arg("seconds", self.cast_time.as_secs_f32()),
+		];
+
+		if !self.cooldown.is_zero() {
+			stats.push(
+				Token::from("skill-stat-cooldown").with_arg("seconds", self.cooldown.as_secs_f32()),
+			);
+		}
+
+		if self.energy_cost > 0. {
+			stats.push(Token::from("skill-stat-energy").with_arg("energy", self.energy_cost));
+		}
+
+		let (RunSkillBehavior::OnActive(config) | RunSkillBehavior::OnAim(config)) = &self.behavior;
+
+		match &config.shape {
+			SkillShape::SphereAoE(sphere) => {
+				stats.push(Token::from("skill-stat-range").with_arg("range", *sphere.max_range));
+				stats.push(Token::from("skill-stat-radius").with_arg("radius", *sphere.radius));
+			}
+			SkillShape::Beam(beam) => {
+				stats.push(Token::from("skill-stat-range").with_arg("range", *beam.range));
+			}
+			SkillShape::MeleeArc(arc) => {
+				stats.push(Token::from("skill-stat-range").with_arg("range", *arc.range));
+			}
+			SkillShape::Chain(chain) => {
+				stats.push(Token::from("skill-stat-range").with_arg("range", *chain.range));
+				stats.push(
+					Token::from("skill-stat-jumps").with_arg("jumps", u32::from(chain.jumps)),
+				);
+			}
+			SkillShape::Dash(dash) => {
+				stats.push(Token::from("skill-stat-range").with_arg("range", *dash.range));
+			}
+			SkillShape::Projectile(_) | SkillShape::Shield(_) => {}
+		}
+
+		let effects = config.contact.iter().chain(config.projection.iter());
+		stats.extend(effects.filter_map(effect_stat));
+
+		stats
+	}
+}
+
+fn effect_stat(effect: &SkillEffect) -> Option<Token> {
+	let seconds = |duration: DurationInSeconds| Duration::from(duration).as_secs_f32();
+
+	match *effect {
+		SkillEffect::HealthDamage(HealthDamage(damage, EffectApplies::Once, damage_type)) => Some(
+			Token::from("skill-stat-damage")
+				.with_arg("damage", damage)
+				.with_arg("type", Token::from(damage_type)),
+		),
+		SkillEffect::HealthDamage(HealthDamage(
+			damage,
+			EffectApplies::OncePerSecond,
+			damage_type,
+		)) => Some(
+			Token::from("skill-stat-damage-per-second")
+				.with_arg("damage", damage)
+				.with_arg("type", Token::from(damage_type)),
+		),
+		SkillEffect::DamageOverTime(DamageOverTime {
+			damage_per_second,
+			damage_type,
+			duration,
+			..
+		}) => Some(
+			Token::from("skill-stat-damage-over-time")
+				.with_arg("damage", damage_per_second * seconds(duration))
+				.with_arg("type", Token::from(damage_type))
+				.with_arg("seconds", seconds(duration)),
+		),
+		SkillEffect::Slow(Slow {
+			speed_reduction,
+			duration,
+			..
+		}) => Some(
+			Token::from("skill-stat-slow")
+				.with_arg("percent", (speed_reduction * 100.).round())
+				.with_arg("seconds", seconds(duration)),
+		),
+		SkillEffect::Stun(Stun { duration, .. }) => {
+			Some(Token::from("skill-stat-stun").with_arg("seconds", seconds(duration)))
+		}
+		SkillEffect::AbsorbShield(AbsorbShield {
+			amount, duration, ..
+		}) => Some(
+			Token::from("skill-stat-absorb")
+				.with_arg("amount", amount)
+				.with_arg("seconds", seconds(duration)),
+		),
+		SkillEffect::Force(_) | SkillEffect::Gravity(_) => None,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::behaviors::SkillBehaviorConfig;
+
+	#[test]
+	fn base_stats() {
+		let skill = Skill {
+			cast_time: Duration::from_millis(500),
+			cooldown: Duration::from_secs(3),
+			energy_cost: 10.,
+			behavior: RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(
+				SkillShape::from(Shield),
+			)),
+			..Skill::default()
+		};
+
+		assert_eq!(
+			vec![
+				Token::from("skill-stat-cast-time").with_arg("seconds", 0.5),
+				Token::from("skill-stat-cooldown").with_arg("seconds", 3.),
+				Token::from("skill-stat-energy").with_arg("energy", 10.),
+			],
+			skill.stats()
+		);
+	}
+
+	#[test]
+	fn omit_missing_cooldown_and_energy() {
+		let skill = Skill {
+			behavior: RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(
+				SkillShape::from(Shield),
+			)),
+			..Skill::default()
+		};
+
+		assert_eq!(
+			vec![Token::from("skill-stat-cast-time").with_arg("seconds", 0.)],
+			skill.stats()
+		);
+	}
+
+	#[test]
+	fn shape_stats() {
+		let skill = Skill {
+			behavior: RunSkillBehavior::OnAim(SkillBehaviorConfig::from_shape(SkillShape::from(
+				SphereAoE {
+					max_range: Units::from(10.),
+					radius: Units::from(2.),
+					..SphereAoE::default()
+				},
+			))),
+			..Skill::default()
+		};
+
+		assert_eq!(
+			vec![
+				Token::from("skill-stat-cast-time").with_arg("seconds", 0.),
+				Token::from("skill-stat-range").with_arg("range", 10.),
+				Token::from("skill-stat-radius").with_arg("radius", 2.),
+			],
+			skill.stats()
+		);
+	}
+
+	#[test]
+	fn chain_stats() {
+		let skill = Skill {
+			behavior: RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(
+				SkillShape::from(Chain {
+					range: Units::from(4.),
+					jumps: 3,
+					destroyed_by: Blockers::All,
+				}),
+			)),
+			..Skill::default()
+		};
+
+		assert_eq!(
+			vec![
+				Token::from("skill-stat-cast-time").with_arg("seconds", 0.),
+				Token::from("skill-stat-range").with_arg("range", 4.),
+				Token::from("skill-stat-jumps").with_arg("jumps", 3_u32),
+			],
+			skill.stats()
+		);
+	}
+
+	#[test]
+	fn effect_stats() {
+		let skill = Skill {
+			behavior: RunSkillBehavior::OnActive(SkillBehaviorConfig {
+				contact: vec![SkillEffect::HealthDamage(
+					HealthDamage::once(5.).with_type(DamageType::Plasma),
+				)],
+				projection: vec![SkillEffect::DamageOverTime(DamageOverTime {
+					damage_per_second: 2.,
+					damage_type: DamageType::Void,
+					duration: DurationInSeconds::from(Duration::from_secs(4)),
+					stacking: StatusStacking::default(),
+				})],
+				..SkillBehaviorConfig::from_shape(SkillShape::from(Shield))
+			}),
+			..Skill::default()
+		};
+
+		assert_eq!(
+			vec![
+				Token::from("skill-stat-cast-time").with_arg("seconds", 0.),
+				Token::from("skill-stat-damage")
+					.with_arg("damage", 5.)
+					.with_arg("type", Token::from(DamageType::Plasma)),
+				Token::from("skill-stat-damage-over-time")
+					.with_arg("damage", 8.)
+					.with_arg("type", Token::from(DamageType::Void))
+					.with_arg("seconds", 4.),
+			],
+			skill.stats()
+		);
+	}
+}
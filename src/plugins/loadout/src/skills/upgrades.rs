@@ -0,0 +1,121 @@
+pub(crate) mod dto;
+
+use crate::{
+	components::progression::ProgressionInternal,
+	skills::{Skill, modifier::SkillModifier},
+};
+use common::prelude::*;
+use std::collections::HashSet;
+
+/// A node in the upgrade tree of a skill, bought with skill points.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct SkillUpgrade {
+	pub(crate) id: UpgradeId,
+	pub(crate) token: Token,
+	pub(crate) cost: u32,
+	pub(crate) requires: Vec<UpgradeId>,
+	pub(crate) modifiers: Vec<SkillModifier>,
+}
+
+impl Skill {
+	/// Apply the modifiers of all acquired upgrades in their declared order.
+	pub(crate) fn apply_upgrades(&mut self, acquired: &HashSet<UpgradeId>) {
+		let modifiers = self
+			.upgrades
+			.iter()
+			.filter(|upgrade| acquired.contains(&upgrade.id))
+			.flat_map(|upgrade| upgrade.modifiers.iter())
+			.copied()
+			.collect::<Vec<_>>();
+
+		self.apply_modifiers(&modifiers);
+	}
+
+	/// Like [`Skill::stats`], but with the upgrades acquired in `progression` and the given
+	/// `modifiers` applied.
+	pub(crate) fn modified_stats(
+		&self,
+		progression: Option<&ProgressionInternal>,
+		modifiers: &[SkillModifier],
+	) -> Vec<Token> {
+		let acquired = progression.and_then(|progression| progression.acquired(&self.id));
+
+		if acquired.is_none() && modifiers.is_empty() {
+			return self.stats();
+		}
+
+		let mut skill = self.clone();
+		if let Some(acquired) = acquired {
+			skill.apply_upgrades(acquired);
+		}
+		skill.apply_modifiers(modifiers);
+		skill.stats()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::{RunSkillBehavior, behaviors::SkillBehaviorConfig};
+
+	fn upgrade(id: &str, modifiers: Vec<SkillModifier>) -> SkillUpgrade {
+		SkillUpgrade {
+			id: UpgradeId::from(id),
+			token: Token::from(id),
+			cost: 1,
+			requires: vec![],
+			modifiers,
+		}
+	}
+
+	fn skill(config: SkillBehaviorConfig, upgrades: Vec<SkillUpgrade>) -> Skill {
+		Skill {
+			behavior: RunSkillBehavior::OnActive(config),
+			upgrades,
+			..Skill::default()
+		}
+	}
+
+	#[test]
+	fn ignore_upgrades_not_acquired() {
+		let config = SkillBehaviorConfig::from_shape(SkillShape::Beam(Beam {
+			range: Units::from(10.),
+			blocked_by: Blockers::All,
+		}));
+		let mut skill = skill(
+			config.clone(),
+			vec![upgrade("range", vec![SkillModifier::Range(2.)])],
+		);
+
+		skill.apply_upgrades(&HashSet::from([UpgradeId::from("other")]));
+
+		assert_eq!(RunSkillBehavior::OnActive(config), skill.behavior);
+	}
+
+	#[test]
+	fn apply_multiple_upgrades() {
+		let mut skill = skill(
+			SkillBehaviorConfig::from_shape(SkillShape::Beam(Beam {
+				range: Units::from(10.),
+				blocked_by: Blockers::All,
+			})),
+			vec![
+				upgrade("range a", vec![SkillModifier::Range(2.)]),
+				upgrade("range b", vec![SkillModifier::Range(1.5)]),
+			],
+		);
+
+		skill.apply_upgrades(&HashSet::from([
+			UpgradeId::from("range a"),
+			UpgradeId::from("range b"),
+		]));
+
+		assert_eq!(
+			RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(SkillShape::Beam(Beam {
+				range: Units::from(30.),
+				blocked_by: Blockers::All,
+			}))),
+			skill.behavior,
+		);
+	}
+}
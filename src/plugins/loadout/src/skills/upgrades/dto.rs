@@ -0,0 +1,37 @@
+use crate::skills::{modifier::SkillModifier, upgrades::SkillUpgrade};
+use common::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub(crate) struct SkillUpgradeDto {
+	id: UpgradeId,
+	token: String,
+	cost: u32,
+	#[serde(default)]
+	requires: Vec<UpgradeId>,
+	modifiers: Vec<SkillModifier>,
+}
+
+impl From<SkillUpgradeDto> for SkillUpgrade {
+	fn from(value: SkillUpgradeDto) -> Self {
+		Self {
+			id: value.id,
+			token: Token::from(value.token),
+			cost: value.cost,
+			requires: value.requires,
+			modifiers: value.modifiers,
+		}
+	}
+}
+
+impl From<SkillUpgrade> for SkillUpgradeDto {
+	fn from(value: SkillUpgrade) -> Self {
+		Self {
+			id: value.id,
+			token: (*value.token).to_owned(),
+			cost: value.cost,
+			requires: value.requires,
+			modifiers: value.modifiers,
+		}
+	}
+}
@@ -4,10 +4,13 @@ pub(crate) mod write;
 use crate::{
 	components::{
 		combos::CombosInternal,
+		cooldowns::Cooldowns,
 		inventory::Inventory,
+		progression::ProgressionInternal,
 		queue::Queue,
 		slot_definitions::SlotDefinitions,
 		slots::Slots,
+		sockets::SocketsInternal,
 	},
 	item::Item,
 	skills::Skill,
@@ -18,6 +21,8 @@ use common::prelude::*;
 #[derive(SystemParam)]
 pub struct LoadoutReader<'w, 's> {
 	agents: Query<'w, 's, ReadComponents>,
+	progressions: Query<'w, 's, Ref<'static, ProgressionInternal>>,
+	sockets: Query<'w, 's, Ref<'static, SocketsInternal>>,
 	items: Res<'w, Assets<Item>>,
 	skills: Res<'w, Assets<Skill>>,
 }
@@ -27,13 +32,18 @@ type ReadComponents = (
 	Ref<'static, Inventory>,
 	Ref<'static, CombosInternal>,
 	Ref<'static, Queue>,
+	Ref<'static, Cooldowns>,
 );
 
 #[derive(SystemParam)]
 pub struct LoadoutWriter<'w, 's> {
+	commands: ZyheedaCommands<'w, 's>,
 	slots: Query<'w, 's, &'static mut Slots>,
 	inventories: Query<'w, 's, &'static mut Inventory>,
 	combos: Query<'w, 's, &'static mut CombosInternal>,
+	progressions: Query<'w, 's, &'static mut ProgressionInternal>,
+	sockets: Query<'w, 's, &'static mut SocketsInternal>,
+	items: Res<'w, Assets<Item>>,
 	skills: Res<'w, Assets<Skill>>,
 }
 
@@ -1,4 +1,5 @@
 mod available_skills;
 mod combos;
 mod items;
+mod progression;
 mod skills;
@@ -10,7 +10,7 @@ impl TryGetContext<CombosMarker> for LoadoutReader<'static, 'static> {
 		param: &'ctx LoadoutReader,
 		CombosMarker { entity }: CombosMarker,
 	) -> Option<Self::TContext<'ctx>> {
-		let (_, _, combos, _) = param.agents.get(entity).ok()?;
+		let (_, _, combos, ..) = param.agents.get(entity).ok()?;
 
 		Some(CombosView { combos })
 	}
@@ -1,6 +1,12 @@
 use crate::{
-	components::{inventory::Inventory, slots::Slots},
+	components::{
+		inventory::Inventory,
+		progression::ProgressionInternal,
+		slots::Slots,
+		sockets::SocketsInternal,
+	},
 	item::Item,
+	skills::Skill,
 	system_parameters::loadout::LoadoutReader,
 };
 use bevy::prelude::*;
@@ -13,12 +19,17 @@ impl TryGetContext<Items> for LoadoutReader<'static, 'static> {
 		param: &'ctx LoadoutReader,
 		Items { entity }: Items,
 	) -> Option<Self::TContext<'ctx>> {
-		let (slots, inventory, _, _) = param.agents.get(entity).ok()?;
+		let (slots, inventory, ..) = param.agents.get(entity).ok()?;
+		let progression = param.progressions.get(entity).ok();
+		let sockets = param.sockets.get(entity).ok();
 
 		Some(ItemsView {
 			inventory,
 			slots,
+			progression,
+			sockets,
 			items: &param.items,
+			skills: &param.skills,
 		})
 	}
 }
@@ -26,12 +37,24 @@ impl TryGetContext<Items> for LoadoutReader<'static, 'static> {
 pub struct ItemsView<'a> {
 	inventory: Ref<'a, Inventory>,
 	slots: Ref<'a, Slots>,
+	progression: Option<Ref<'a, ProgressionInternal>>,
+	sockets: Option<Ref<'a, SocketsInternal>>,
 	items: &'a Assets<Item>,
+	skills: &'a Assets<Skill>,
 }
 
 impl ContextChanged for ItemsView<'_> {
 	fn context_changed(&self) -> bool {
-		self.slots.is_changed() || self.inventory.is_changed()
+		self.slots.is_changed()
+			|| self.inventory.is_changed()
+			|| self
+				.progression
+				.as_ref()
+				.is_some_and(|progression| progression.is_changed())
+			|| self
+				.sockets
+				.as_ref()
+				.is_some_and(|sockets| sockets.is_changed())
 	}
 }
 
@@ -45,14 +68,34 @@ impl ReadItems for ItemsView<'_> {
 	where
 		TKey: Into<LoadoutKey>,
 	{
-		let handle = match key.into() {
+		let key = key.into();
+		let handle = match key {
 			LoadoutKey::Inventory(InventoryKey(i)) => self.inventory.0.get(i)?.as_ref()?,
 			LoadoutKey::Slot(slot) => self.slots.items.get(&slot)?.as_ref()?,
 		};
 		let item = self.items.get(handle)?;
+		let skill = item.skill.as_ref().and_then(|skill| self.skills.get(skill));
+		let essence = self
+			.sockets
+			.as_ref()
+			.and_then(|sockets| sockets.essence(key, self.items));
+		let modifiers = essence
+			.and_then(|essence| essence.socket_modifiers_for(item))
+			.unwrap_or_default();
+		let mut stats = skill
+			.map(|skill| skill.modified_stats(self.progression.as_deref(), modifiers))
+			.unwrap_or_default();
+		stats.extend(item.equipment_stat_tokens());
+
+		if let Some(essence) = essence {
+			let socketed =
+				Token::from("item-socketed-essence").with_arg("essence", essence.token.clone());
+			stats.insert(0, socketed);
+		}
 
 		Some(ReadItem {
 			token: item.token.clone(),
+			stats,
 		})
 	}
 }
@@ -60,6 +103,7 @@ impl ReadItems for ItemsView<'_> {
 #[derive(Debug, PartialEq, Clone)]
 pub struct ReadItem {
 	token: Token,
+	stats: Vec<Token>,
 }
 
 impl View<ItemToken> for ReadItem {
@@ -68,6 +112,12 @@ impl View<ItemToken> for ReadItem {
 	}
 }
 
+impl View<SkillStats> for ReadItem {
+	fn view(&self) -> &'_ [Token] {
+		&self.stats
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(clippy::unwrap_used)]
@@ -119,7 +169,8 @@ mod tests {
 
 					assert_eq!(
 						Some(ReadItem {
-							token: Token::from("my item")
+							token: Token::from("my item"),
+							stats: vec![],
 						}),
 						item
 					);
@@ -151,7 +202,8 @@ mod tests {
 
 					assert_eq!(
 						Some(ReadItem {
-							token: Token::from("my item")
+							token: Token::from("my item"),
+							stats: vec![],
 						}),
 						item
 					);
@@ -159,6 +211,152 @@ mod tests {
 		}
 	}
 
+	mod item_stats {
+		use super::*;
+		use crate::{
+			components::queue::Queue,
+			skills::{RunSkillBehavior, behaviors::SkillBehaviorConfig, modifier::SkillModifier},
+		};
+		use std::{collections::HashMap, time::Duration};
+
+		#[test]
+		fn stats_of_item_skill() -> Result<(), RunSystemError> {
+			let item_handle = new_handle();
+			let skill_handle = new_handle();
+			let skill = Skill {
+				cast_time: Duration::from_secs(2),
+				..default()
+			};
+			let item = Item {
+				skill: Some(skill_handle.clone()),
+				..default()
+			};
+			let mut app = App::new().single_threaded(Update);
+			let mut items = Assets::<Item>::default();
+			let mut skills = Assets::<Skill>::default();
+			_ = items.insert(&item_handle, item);
+			_ = skills.insert(&skill_handle, skill.clone());
+			app.insert_resource(items);
+			app.insert_resource(skills);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::from([(SlotKey(11), Some(item_handle))]),
+					Inventory::default(),
+					CombosInternal::default(),
+					Queue::default(),
+				))
+				.id();
+
+			app.world_mut()
+				.run_system_once(move |loadout: LoadoutReader| {
+					let ctx = LoadoutReader::try_get_context(&loadout, Items { entity }).unwrap();
+					let item = ctx.get_item(SlotKey(11)).unwrap();
+
+					assert_eq!(skill.stats(), item.view_of::<SkillStats>());
+				})
+		}
+
+		#[test]
+		fn stats_of_socketed_item() -> Result<(), RunSystemError> {
+			let item_handle = new_handle();
+			let essence_handle = new_handle();
+			let skill_handle = new_handle();
+			let skill = Skill {
+				behavior: RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(
+					SkillShape::Beam(Beam {
+						range: Units::from(10.),
+						blocked_by: Blockers::All,
+					}),
+				)),
+				..default()
+			};
+			let item = Item {
+				skill: Some(skill_handle.clone()),
+				item_type: ItemType::Pistol,
+				..default()
+			};
+			let essence = Item {
+				token: Token::from("my essence"),
+				socket_modifiers: HashMap::from([(
+					ItemType::Pistol,
+					vec![SkillModifier::Range(2.)],
+				)]),
+				..default()
+			};
+			let mut app = App::new().single_threaded(Update);
+			let mut items = Assets::<Item>::default();
+			let mut skills = Assets::<Skill>::default();
+			_ = items.insert(&item_handle, item);
+			_ = items.insert(&essence_handle, essence);
+			_ = skills.insert(&skill_handle, skill.clone());
+			app.insert_resource(items);
+			app.insert_resource(skills);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::from([(SlotKey(11), Some(item_handle))]),
+					Inventory::default(),
+					CombosInternal::default(),
+					Queue::default(),
+					SocketsInternal::from([(LoadoutKey::from(SlotKey(11)), essence_handle)]),
+				))
+				.id();
+
+			app.world_mut()
+				.run_system_once(move |loadout: LoadoutReader| {
+					let ctx = LoadoutReader::try_get_context(&loadout, Items { entity }).unwrap();
+					let item = ctx.get_item(SlotKey(11)).unwrap();
+					let mut modified = skill.clone();
+					modified.apply_modifiers(&[SkillModifier::Range(2.)]);
+					let mut expected = vec![
+						Token::from("item-socketed-essence")
+							.with_arg("essence", Token::from("my essence")),
+					];
+					expected.extend(modified.stats());
+
+					assert_eq!(expected.as_slice(), item.view_of::<SkillStats>());
+				})
+		}
+
+		#[test]
+		fn stats_with_equipment_stats() -> Result<(), RunSystemError> {
+			let item_handle = new_handle();
+			let item = Item {
+				equipment_stats: EquipmentStats {
+					max_health: 20.,
+					..default()
+				},
+				..default()
+			};
+			let mut app = App::new().single_threaded(Update);
+			let mut items = Assets::<Item>::default();
+			_ = items.insert(&item_handle, item);
+			app.insert_resource(items);
+			app.init_resource::<Assets<Skill>>();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::from([(SlotKey(11), Some(item_handle))]),
+					Inventory::default(),
+					CombosInternal::default(),
+					Queue::default(),
+				))
+				.id();
+
+			app.world_mut()
+				.run_system_once(move |loadout: LoadoutReader| {
+					let ctx = LoadoutReader::try_get_context(&loadout, Items { entity }).unwrap();
+					let item = ctx.get_item(SlotKey(11)).unwrap();
+
+					assert_eq!(
+						&[Token::from("item-stat-max-health").with_arg("amount", 20.)],
+						item.view_of::<SkillStats>()
+					);
+				})
+		}
+	}
+
 	mod item {
 		use super::*;
 
@@ -166,9 +364,10 @@ mod tests {
 		fn get_token() {
 			let item = ReadItem {
 				token: Token::from("my item"),
+				stats: vec![],
 			};
 
-			assert_eq!(&Token::from("my item"), item.view());
+			assert_eq!(&Token::from("my item"), item.view_of::<ItemToken>());
 		}
 	}
 }
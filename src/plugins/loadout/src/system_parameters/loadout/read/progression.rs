@@ -0,0 +1,212 @@
+use crate::{
+	components::progression::ProgressionInternal,
+	skills::{Skill, SkillId},
+	system_parameters::loadout::LoadoutReader,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl TryGetContext<Progression> for LoadoutReader<'static, 'static> {
+	type TContext<'ctx> = ProgressionView<'ctx>;
+
+	fn try_get_context<'ctx>(
+		param: &'ctx LoadoutReader,
+		Progression { entity }: Progression,
+	) -> Option<Self::TContext<'ctx>> {
+		let progression = param.progressions.get(entity).ok()?;
+
+		Some(ProgressionView {
+			progression,
+			skills: &param.skills,
+		})
+	}
+}
+
+pub struct ProgressionView<'ctx> {
+	progression: Ref<'ctx, ProgressionInternal>,
+	skills: &'ctx Assets<Skill>,
+}
+
+impl ContextChanged for ProgressionView<'_> {
+	fn context_changed(&self) -> bool {
+		self.progression.is_changed()
+	}
+}
+
+impl ReadProgression<SkillId> for ProgressionView<'_> {
+	fn level_progress(&self) -> LevelProgress {
+		LevelProgress {
+			level: self.progression.level,
+			experience: self.progression.experience,
+			next_level: self.progression.next_level(),
+			skill_points: self.progression.skill_points,
+		}
+	}
+
+	fn skill_upgrades(&self) -> Vec<SkillUpgrades<SkillId>> {
+		let mut skill_upgrades = self
+			.skills
+			.iter()
+			.map(|(_, skill)| skill)
+			.filter(|skill| !skill.upgrades.is_empty())
+			.map(|skill| SkillUpgrades {
+				skill: skill.id,
+				token: skill.token.clone(),
+				upgrades: skill
+					.upgrades
+					.iter()
+					.map(|upgrade| Upgrade {
+						id: upgrade.id.clone(),
+						token: upgrade.token.clone(),
+						cost: upgrade.cost,
+						state: self.progression.upgrade_state(skill, upgrade),
+					})
+					.collect(),
+			})
+			.collect::<Vec<_>>();
+
+		skill_upgrades.sort_by_cached_key(|skill_upgrades| skill_upgrades.token.to_string());
+		skill_upgrades
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::{item::Item, skills::upgrades::SkillUpgrade};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use std::collections::{HashMap, HashSet};
+	use testing::{SingleThreadedApp, new_handle};
+	use uuid::uuid;
+
+	fn setup<const N: usize>(skills: [Skill; N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut skill_assets = Assets::default();
+
+		for skill in skills {
+			_ = skill_assets.insert(&new_handle(), skill);
+		}
+
+		app.insert_resource(skill_assets);
+		app.init_resource::<Assets<Item>>();
+
+		app
+	}
+
+	fn upgrade(id: &str, cost: u32) -> SkillUpgrade {
+		SkillUpgrade {
+			id: UpgradeId::from(id),
+			token: Token::from(id),
+			cost,
+			requires: vec![],
+			modifiers: vec![],
+		}
+	}
+
+	#[test]
+	fn level_progress() -> Result<(), RunSystemError> {
+		let mut app = setup([]);
+		let entity = app
+			.world_mut()
+			.spawn(ProgressionInternal {
+				level: 2,
+				experience: 30,
+				skill_points: 4,
+				..default()
+			})
+			.id();
+
+		let progress = app
+			.world_mut()
+			.run_system_once(move |loadout: LoadoutReader| {
+				let ctx = LoadoutReader::try_get_context(&loadout, Progression { entity });
+				ctx.map(|ctx| ctx.level_progress())
+			})?;
+
+		assert_eq!(
+			Some(LevelProgress {
+				level: 2,
+				experience: 30,
+				next_level: 200,
+				skill_points: 4,
+			}),
+			progress,
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn skill_upgrades_sorted_by_token() -> Result<(), RunSystemError> {
+		const SKILL_A: SkillId = SkillId(uuid!("e2d0f5b1-4a39-4b8a-9e55-1d6a7c2a9f01"));
+		const SKILL_B: SkillId = SkillId(uuid!("6b7c1c0e-2f0f-4f6e-8c1a-0f5e6d7c8b92"));
+		let mut app = setup([
+			Skill {
+				id: SKILL_B,
+				token: Token::from("b"),
+				upgrades: vec![upgrade("b1", 1), upgrade("b2", 2)],
+				..default()
+			},
+			Skill {
+				id: SKILL_A,
+				token: Token::from("a"),
+				upgrades: vec![upgrade("a1", 1)],
+				..default()
+			},
+			Skill {
+				token: Token::from("no upgrades"),
+				..default()
+			},
+		]);
+		let entity = app
+			.world_mut()
+			.spawn(ProgressionInternal {
+				skill_points: 1,
+				upgrades: HashMap::from([(SKILL_A, HashSet::from([UpgradeId::from("a1")]))]),
+				..default()
+			})
+			.id();
+
+		let upgrades = app
+			.world_mut()
+			.run_system_once(move |loadout: LoadoutReader| {
+				let ctx = LoadoutReader::try_get_context(&loadout, Progression { entity });
+				ctx.map(|ctx| ctx.skill_upgrades())
+			})?;
+
+		assert_eq!(
+			Some(vec![
+				SkillUpgrades {
+					skill: SKILL_A,
+					token: Token::from("a"),
+					upgrades: vec![Upgrade {
+						id: UpgradeId::from("a1"),
+						token: Token::from("a1"),
+						cost: 1,
+						state: UpgradeState::Acquired,
+					}],
+				},
+				SkillUpgrades {
+					skill: SKILL_B,
+					token: Token::from("b"),
+					upgrades: vec![
+						Upgrade {
+							id: UpgradeId::from("b1"),
+							token: Token::from("b1"),
+							cost: 1,
+							state: UpgradeState::Available,
+						},
+						Upgrade {
+							id: UpgradeId::from("b2"),
+							token: Token::from("b2"),
+							cost: 2,
+							state: UpgradeState::Locked,
+						},
+					],
+				},
+			]),
+			upgrades,
+		);
+		Ok(())
+	}
+}
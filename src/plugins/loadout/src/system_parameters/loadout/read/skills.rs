@@ -1,5 +1,13 @@
 use crate::{
-	components::{combos::CombosInternal, inventory::Inventory, queue::Queue, slots::Slots},
+	components::{
+		combos::CombosInternal,
+		cooldowns::Cooldowns,
+		inventory::Inventory,
+		progression::ProgressionInternal,
+		queue::Queue,
+		slots::Slots,
+		sockets::SocketsInternal,
+	},
 	item::Item,
 	skills::Skill,
 	system_parameters::loadout::LoadoutReader,
@@ -16,13 +24,18 @@ impl TryGetContext<Skills> for LoadoutReader<'static, 'static> {
 		param: &'ctx LoadoutReader,
 		Skills { entity }: Skills,
 	) -> Option<Self::TContext<'ctx>> {
-		let (slots, inventory, combos, queue) = param.agents.get(entity).ok()?;
+		let (slots, inventory, combos, queue, cooldowns) = param.agents.get(entity).ok()?;
+		let progression = param.progressions.get(entity).ok();
+		let sockets = param.sockets.get(entity).ok();
 
 		Some(SkillsView {
 			inventory,
 			slots,
 			queue,
 			combos,
+			cooldowns,
+			progression,
+			sockets,
 			items: &param.items,
 			skills: &param.skills,
 		})
@@ -34,6 +47,9 @@ pub struct SkillsView<'ctx> {
 	slots: Ref<'ctx, Slots>,
 	queue: Ref<'ctx, Queue>,
 	combos: Ref<'ctx, CombosInternal>,
+	cooldowns: Ref<'ctx, Cooldowns>,
+	progression: Option<Ref<'ctx, ProgressionInternal>>,
+	sockets: Option<Ref<'ctx, SocketsInternal>>,
 	items: &'ctx Assets<Item>,
 	skills: &'ctx Assets<Skill>,
 }
@@ -44,6 +60,15 @@ impl ContextChanged for SkillsView<'_> {
 			|| self.slots.is_changed()
 			|| self.queue.is_changed()
 			|| self.combos.is_changed()
+			|| self.cooldowns.is_changed()
+			|| self
+				.progression
+				.as_ref()
+				.is_some_and(|progression| progression.is_changed())
+			|| self
+				.sockets
+				.as_ref()
+				.is_some_and(|sockets| sockets.is_changed())
 	}
 }
 
@@ -70,7 +95,9 @@ impl ReadSkills for SkillsView<'_> {
 			return Some(ReadSkill {
 				token: skill.token.clone(),
 				icon: skill.icon.clone(),
+				stats: skill.modified_stats(self.progression.as_deref(), &[]),
 				execution: SkillExecution::None,
+				cooldown: 0.,
 			});
 		};
 
@@ -80,7 +107,9 @@ impl ReadSkills for SkillsView<'_> {
 			return Some(ReadSkill {
 				token: active.skill.token.clone(),
 				icon: active.skill.icon.clone(),
+				stats: active.skill.stats(),
 				execution: SkillExecution::Active,
+				cooldown: self.cooldowns.remaining_fraction(&active.skill.id),
 			});
 		}
 
@@ -88,7 +117,9 @@ impl ReadSkills for SkillsView<'_> {
 			return Some(ReadSkill {
 				token: queued.skill.token.clone(),
 				icon: queued.skill.icon.clone(),
+				stats: queued.skill.stats(),
 				execution: SkillExecution::Queued,
+				cooldown: self.cooldowns.remaining_fraction(&queued.skill.id),
 			});
 		}
 
@@ -96,10 +127,18 @@ impl ReadSkills for SkillsView<'_> {
 			skill = combo_skill;
 		}
 
+		let modifiers = self
+			.sockets
+			.as_ref()
+			.map(|sockets| sockets.modifiers_for(slot_key, item, self.items))
+			.unwrap_or_default();
+
 		Some(ReadSkill {
 			token: skill.token.clone(),
 			icon: skill.icon.clone(),
+			stats: skill.modified_stats(self.progression.as_deref(), modifiers),
 			execution: SkillExecution::None,
+			cooldown: self.cooldowns.remaining_fraction(&skill.id),
 		})
 	}
 }
@@ -108,7 +147,9 @@ impl ReadSkills for SkillsView<'_> {
 pub struct ReadSkill {
 	token: Token,
 	icon: Handle<Image>,
+	stats: Vec<Token>,
 	execution: SkillExecution,
+	cooldown: f32,
 }
 
 impl View<SkillToken> for ReadSkill {
@@ -123,12 +164,24 @@ impl View<SkillIcon> for ReadSkill {
 	}
 }
 
+impl View<SkillStats> for ReadSkill {
+	fn view(&self) -> &'_ [Token] {
+		&self.stats
+	}
+}
+
 impl View<SkillExecution> for ReadSkill {
 	fn view(&self) -> SkillExecution {
 		self.execution
 	}
 }
 
+impl View<SkillCooldown> for ReadSkill {
+	fn view(&self) -> f32 {
+		self.cooldown
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(clippy::unwrap_used)]
@@ -141,8 +194,9 @@ mod tests {
 		use super::*;
 		use crate::{
 			components::combo_node::ComboNode,
-			skills::{QueuedSkill, SkillMode},
+			skills::{QueuedSkill, SkillId, SkillMode},
 		};
+		use std::time::Duration;
 
 		fn setup<const I: usize, const S: usize>(
 			items: [(&Handle<Item>, Item); I],
@@ -174,6 +228,7 @@ mod tests {
 			let skill = Skill {
 				token: Token::from("my skill"),
 				icon: icon_handle.clone(),
+				cast_time: Duration::from_secs(1),
 				..default()
 			};
 			let item = Item {
@@ -200,7 +255,11 @@ mod tests {
 						Some(ReadSkill {
 							token: Token::from("my skill"),
 							icon: icon_handle.clone(),
+							stats: vec![
+								Token::from("skill-stat-cast-time").with_arg("seconds", 1.)
+							],
 							execution: SkillExecution::None,
+							cooldown: 0.,
 						}),
 						item
 					);
@@ -241,7 +300,9 @@ mod tests {
 						Some(ReadSkill {
 							token: Token::from("my skill"),
 							icon: icon_handle.clone(),
+							stats: Skill::default().stats(),
 							execution: SkillExecution::None,
+							cooldown: 0.,
 						}),
 						item
 					);
@@ -297,7 +358,9 @@ mod tests {
 						Some(ReadSkill {
 							token: Token::from("my queued skill"),
 							icon: icon_handle.clone(),
+							stats: Skill::default().stats(),
 							execution: SkillExecution::Queued,
+							cooldown: 0.,
 						}),
 						item
 					);
@@ -346,7 +409,9 @@ mod tests {
 						Some(ReadSkill {
 							token: Token::from("my active skill"),
 							icon: icon_handle.clone(),
+							stats: Skill::default().stats(),
 							execution: SkillExecution::Active,
+							cooldown: 0.,
 						}),
 						item
 					);
@@ -406,7 +471,9 @@ mod tests {
 						Some(ReadSkill {
 							token: Token::from("my active skill"),
 							icon: icon_handle.clone(),
+							stats: Skill::default().stats(),
 							execution: SkillExecution::Active,
+							cooldown: 0.,
 						}),
 						item
 					);
@@ -460,7 +527,57 @@ mod tests {
 						Some(ReadSkill {
 							token: Token::from("my combo skill"),
 							icon: icon_handle.clone(),
+							stats: Skill::default().stats(),
+							execution: SkillExecution::None,
+							cooldown: 0.,
+						}),
+						item
+					);
+				})
+		}
+		#[test]
+		fn slot_skill_on_cooldown() -> Result<(), RunSystemError> {
+			let item_handle = new_handle();
+			let skill_handle = new_handle();
+			let icon_handle = new_handle();
+			let skill = Skill {
+				token: Token::from("my skill"),
+				icon: icon_handle.clone(),
+				..default()
+			};
+			let item = Item {
+				skill: Some(skill_handle.clone()),
+				..default()
+			};
+			let mut app = setup([(&item_handle, item)], [(&skill_handle, skill)]);
+			let mut cooldowns = Cooldowns::default();
+			cooldowns.start(SkillId::DEFAULT_ID, Duration::from_secs(2));
+			cooldowns.0.values_mut().for_each(|cooldown| {
+				cooldown.remaining = Duration::from_secs(1);
+			});
+			let entity = app
+				.world_mut()
+				.spawn((
+					Inventory::default(),
+					Slots::from([(SlotKey(11), Some(item_handle))]),
+					CombosInternal::default(),
+					Queue::default(),
+					cooldowns,
+				))
+				.id();
+
+			app.world_mut()
+				.run_system_once(move |loadout: LoadoutReader| {
+					let ctx = LoadoutReader::try_get_context(&loadout, Skills { entity }).unwrap();
+					let item = ctx.get_skill(SlotKey(11));
+
+					assert_eq!(
+						Some(ReadSkill {
+							token: Token::from("my skill"),
+							icon: icon_handle.clone(),
+							stats: Skill::default().stats(),
 							execution: SkillExecution::None,
+							cooldown: 0.5,
 						}),
 						item
 					);
@@ -476,7 +593,9 @@ mod tests {
 			let skill = ReadSkill {
 				token: Token::from("my skill"),
 				icon: new_handle(),
+				stats: Skill::default().stats(),
 				execution: SkillExecution::None,
+				cooldown: 0.,
 			};
 
 			assert_eq!(&Token::from("my skill"), skill.view_of::<SkillToken>());
@@ -487,7 +606,9 @@ mod tests {
 			let skill = ReadSkill {
 				token: Token::from("my skill"),
 				icon: new_handle(),
+				stats: Skill::default().stats(),
 				execution: SkillExecution::None,
+				cooldown: 0.,
 			};
 
 			assert_eq!(&skill.icon, skill.view_of::<SkillIcon>());
@@ -498,7 +619,9 @@ mod tests {
 			let skill = ReadSkill {
 				token: Token::from("my skill"),
 				icon: new_handle(),
+				stats: Skill::default().stats(),
 				execution: SkillExecution::Queued,
+				cooldown: 0.,
 			};
 
 			assert_eq!(SkillExecution::Queued, skill.view_of::<SkillExecution>(),);
@@ -1,5 +1,6 @@
 mod combos;
-mod items;
+mod progression;
 mod register_loadout_bones;
 
 pub(crate) mod insert_default_loadout;
+pub(crate) mod items;
@@ -21,7 +21,7 @@ pub struct DefaultLoadout<'ctx> {
 }
 
 impl DefaultLoadout<'_> {
-	fn asset_path(name: &ItemName) -> String {
+	pub(crate) fn asset_path(name: &ItemName) -> String {
 		format!("items/{name}/meta.item")
 	}
 
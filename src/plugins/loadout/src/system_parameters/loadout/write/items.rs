@@ -1,8 +1,9 @@
 use crate::{
-	components::{inventory::Inventory, slots::Slots},
-	system_parameters::loadout::LoadoutWriter,
+	components::{inventory::Inventory, slots::Slots, sockets::SocketsInternal},
+	item::Item,
+	system_parameters::loadout::{LoadoutWriter, write::insert_default_loadout::DefaultLoadout},
 };
-use bevy::prelude::*;
+use bevy::{ecs::component::Mutable, prelude::*};
 use common::prelude::*;
 
 impl TryGetContextMut<Items> for LoadoutWriter<'static, 'static> {
@@ -14,14 +15,90 @@ impl TryGetContextMut<Items> for LoadoutWriter<'static, 'static> {
 	) -> Option<Self::TContext<'ctx>> {
 		let slots = param.slots.get_mut(entity).ok()?;
 		let inventory = param.inventories.get_mut(entity).ok()?;
-
-		Some(ItemsMut { inventory, slots })
+		let sockets = param.sockets.get_mut(entity).ok();
+		let entity = param.commands.get_mut(&entity)?;
+
+		Some(ItemsMut {
+			entity,
+			inventory,
+			slots,
+			sockets,
+			items: &param.items,
+		})
 	}
 }
 
 pub struct ItemsMut<'ctx> {
+	entity: ZyheedaEntityCommands<'ctx>,
 	inventory: Mut<'ctx, Inventory>,
 	slots: Mut<'ctx, Slots>,
+	sockets: Option<Mut<'ctx, SocketsInternal>>,
+	items: &'ctx Assets<Item>,
+}
+
+impl ItemsMut<'_> {
+	fn item(&self, key: LoadoutKey) -> Option<&Handle<Item>> {
+		match key {
+			LoadoutKey::Inventory(InventoryKey(i)) => self.inventory.0.get(i)?.as_ref(),
+			LoadoutKey::Slot(slot) => self.slots.items.get(&slot)?.as_ref(),
+		}
+	}
+
+	fn set_item(&mut self, key: LoadoutKey, item: Option<Handle<Item>>) {
+		match key {
+			LoadoutKey::Inventory(InventoryKey(i)) => {
+				self.inventory.fill_up_to(i);
+				self.inventory.0[i] = item;
+			}
+			LoadoutKey::Slot(slot) => {
+				self.slots.items.insert(slot, item);
+			}
+		}
+	}
+
+	pub(crate) fn add<TAssetServer>(
+		on_add_items: On<AddItemsEvent>,
+		mut inventories: Query<&mut Inventory>,
+		mut server: ResMut<TAssetServer>,
+	) where
+		TAssetServer: Resource<Mutability = Mutable> + LoadAsset,
+	{
+		let Ok(mut inventory) = inventories.get_mut(on_add_items.entity) else {
+			return;
+		};
+
+		let free_slots = inventory.free_slots();
+
+		for name in on_add_items.items.iter().take(free_slots) {
+			inventory.insert_first_free(server.load_asset(DefaultLoadout::asset_path(name)));
+		}
+	}
+}
+
+impl AddItems for ItemsMut<'_> {
+	fn free_slots(&self) -> usize {
+		self.inventory.free_slots()
+	}
+
+	fn add_items<TItems>(&mut self, items: TItems)
+	where
+		TItems: IntoIterator<Item = ItemName>,
+	{
+		let items = items.into_iter().collect::<Vec<_>>();
+
+		if items.is_empty() {
+			return;
+		}
+
+		self.entity
+			.trigger(move |entity| AddItemsEvent { entity, items });
+	}
+}
+
+#[derive(EntityEvent, Debug, PartialEq)]
+pub(crate) struct AddItemsEvent {
+	entity: Entity,
+	items: Vec<ItemName>,
 }
 
 impl SwapItems for ItemsMut<'_> {
@@ -37,6 +114,10 @@ impl SwapItems for ItemsMut<'_> {
 			return;
 		}
 
+		if let Some(sockets) = self.sockets.as_mut() {
+			sockets.swap(a, b);
+		}
+
 		match (a, b) {
 			(LoadoutKey::Inventory(InventoryKey(a)), LoadoutKey::Inventory(InventoryKey(b))) => {
 				self.inventory.fill_up_to(a.max(b));
@@ -68,6 +149,49 @@ impl SwapItems for ItemsMut<'_> {
 	}
 }
 
+impl SocketItems for ItemsMut<'_> {
+	fn socket_item<TEssence, TItem>(&mut self, essence: TEssence, item: TItem) -> bool
+	where
+		TEssence: Into<LoadoutKey>,
+		TItem: Into<LoadoutKey>,
+	{
+		let essence_key = essence.into();
+		let item_key = item.into();
+
+		if essence_key == item_key {
+			return false;
+		}
+		let Some(sockets) = self.sockets.as_ref() else {
+			return false;
+		};
+		if sockets.0.contains_key(&essence_key) {
+			return false;
+		}
+		let (Some(essence_handle), Some(item_handle)) =
+			(self.item(essence_key), self.item(item_key))
+		else {
+			return false;
+		};
+		let (Some(essence), Some(item)) =
+			(self.items.get(essence_handle), self.items.get(item_handle))
+		else {
+			return false;
+		};
+		if essence.socket_modifiers_for(item).is_none() {
+			return false;
+		}
+
+		let essence_handle = essence_handle.clone();
+		let Some(sockets) = self.sockets.as_mut() else {
+			return false;
+		};
+		let previous = sockets.0.insert(item_key, essence_handle);
+		self.set_item(essence_key, previous);
+
+		true
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(clippy::unwrap_used)]
@@ -87,6 +211,7 @@ mod tests {
 		let mut app = App::new().single_threaded(Update);
 
 		app.init_resource::<Assets<Skill>>();
+		app.init_resource::<Assets<Item>>();
 		app.add_systems(
 			Update,
 			(IsChanged::<Slots>::detect, IsChanged::<Inventory>::detect).in_set(_ChangeDetection),
@@ -545,4 +670,260 @@ mod tests {
 			);
 		}
 	}
+
+	mod socket {
+		use super::*;
+		use crate::skills::modifier::SkillModifier;
+		use std::collections::HashMap;
+
+		fn setup_items(app: &mut App, essence: &Handle<Item>, item: &Handle<Item>) {
+			let mut items = app.world_mut().resource_mut::<Assets<Item>>();
+			_ = items.insert(
+				essence,
+				Item {
+					item_type: ItemType::ForceEssence,
+					socket_modifiers: HashMap::from([(
+						ItemType::Pistol,
+						vec![SkillModifier::Damage(2.)],
+					)]),
+					..default()
+				},
+			);
+			_ = items.insert(
+				item,
+				Item {
+					item_type: ItemType::Pistol,
+					..default()
+				},
+			);
+		}
+
+		#[test]
+		fn socket_essence_from_inventory_into_slot_item() -> Result<(), RunSystemError> {
+			let essence = new_handle();
+			let item = new_handle();
+			let mut app = setup();
+			setup_items(&mut app, &essence, &item);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::from([(SlotKey(1), Some(item.clone()))]),
+					Inventory::from([Some(essence.clone())]),
+					SocketsInternal::default(),
+				))
+				.id();
+
+			let socketed = app
+				.world_mut()
+				.run_system_once(move |mut p: LoadoutWriter| {
+					let mut ctx =
+						LoadoutWriter::try_get_context_mut(&mut p, Items { entity }).unwrap();
+					ctx.socket_item(InventoryKey(0), SlotKey(1))
+				})?;
+
+			assert_eq!(
+				(
+					true,
+					Some(&Inventory::from([None])),
+					Some(&Slots::from([(SlotKey(1), Some(item))])),
+					Some(&SocketsInternal::from([(
+						LoadoutKey::from(SlotKey(1)),
+						essence
+					)])),
+				),
+				(
+					socketed,
+					app.world().entity(entity).get::<Inventory>(),
+					app.world().entity(entity).get::<Slots>(),
+					app.world().entity(entity).get::<SocketsInternal>(),
+				),
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn replace_previously_socketed_essence() -> Result<(), RunSystemError> {
+			let essence = new_handle();
+			let previous = new_handle();
+			let item = new_handle();
+			let mut app = setup();
+			setup_items(&mut app, &essence, &item);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::from([(SlotKey(1), Some(item.clone()))]),
+					Inventory::from([None, Some(essence.clone())]),
+					SocketsInternal::from([(LoadoutKey::from(SlotKey(1)), previous.clone())]),
+				))
+				.id();
+
+			app.world_mut()
+				.run_system_once(move |mut p: LoadoutWriter| {
+					let mut ctx =
+						LoadoutWriter::try_get_context_mut(&mut p, Items { entity }).unwrap();
+					ctx.socket_item(InventoryKey(1), SlotKey(1));
+				})?;
+
+			assert_eq!(
+				(
+					Some(&Inventory::from([None, Some(previous)])),
+					Some(&SocketsInternal::from([(
+						LoadoutKey::from(SlotKey(1)),
+						essence
+					)])),
+				),
+				(
+					app.world().entity(entity).get::<Inventory>(),
+					app.world().entity(entity).get::<SocketsInternal>(),
+				),
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn do_not_socket_into_incompatible_item() -> Result<(), RunSystemError> {
+			let essence = new_handle();
+			let item = new_handle();
+			let mut app = setup();
+			setup_items(&mut app, &essence, &item);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::from([(SlotKey(1), Some(item.clone()))]),
+					Inventory::from([Some(essence.clone())]),
+					SocketsInternal::default(),
+				))
+				.id();
+
+			let socketed = app
+				.world_mut()
+				.run_system_once(move |mut p: LoadoutWriter| {
+					let mut ctx =
+						LoadoutWriter::try_get_context_mut(&mut p, Items { entity }).unwrap();
+					ctx.socket_item(SlotKey(1), InventoryKey(0))
+				})?;
+
+			assert_eq!(
+				(
+					false,
+					Some(&Inventory::from([Some(essence)])),
+					Some(&Slots::from([(SlotKey(1), Some(item))])),
+					Some(&SocketsInternal::default()),
+				),
+				(
+					socketed,
+					app.world().entity(entity).get::<Inventory>(),
+					app.world().entity(entity).get::<Slots>(),
+					app.world().entity(entity).get::<SocketsInternal>(),
+				),
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn swapping_items_moves_socketed_essences() -> Result<(), RunSystemError> {
+			let essence = new_handle();
+			let item = new_handle();
+			let mut app = setup();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::from([(SlotKey(1), Some(item.clone()))]),
+					Inventory::default(),
+					SocketsInternal::from([(LoadoutKey::from(SlotKey(1)), essence.clone())]),
+				))
+				.id();
+
+			app.world_mut()
+				.run_system_once(move |mut p: LoadoutWriter| {
+					let mut ctx =
+						LoadoutWriter::try_get_context_mut(&mut p, Items { entity }).unwrap();
+					ctx.swap_items(SlotKey(1), InventoryKey(0));
+				})?;
+
+			assert_eq!(
+				Some(&SocketsInternal::from([(
+					LoadoutKey::from(InventoryKey(0)),
+					essence
+				)])),
+				app.world().entity(entity).get::<SocketsInternal>(),
+			);
+			Ok(())
+		}
+	}
+
+	mod add {
+		use super::*;
+		use crate::{item::Item, skills::Skill};
+		use common::traits::load_asset::mock_asset_loader::MockAssetServer;
+
+		fn setup(server: MockAssetServer) -> App {
+			let mut app = App::new().single_threaded(Update);
+
+			app.init_resource::<Assets<Skill>>();
+			app.init_resource::<Assets<Item>>();
+			app.insert_resource(server);
+			app.add_observer(ItemsMut::add::<MockAssetServer>);
+
+			app
+		}
+
+		#[test]
+		fn into_first_free_inventory_slots() -> Result<(), RunSystemError> {
+			let handles = [new_handle::<Item>(), new_handle::<Item>(), new_handle()];
+			let mut app = setup(
+				MockAssetServer::default()
+					.path("items/item_a/meta.item")
+					.returns(handles[1].clone())
+					.path("items/item_b/meta.item")
+					.returns(handles[2].clone()),
+			);
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::default(),
+					Inventory::from([None, Some(handles[0].clone())]),
+				))
+				.id();
+
+			app.world_mut()
+				.run_system_once(move |mut p: LoadoutWriter| {
+					let mut ctx =
+						LoadoutWriter::try_get_context_mut(&mut p, Items { entity }).unwrap();
+					ctx.add_items([ItemName::from("item_a"), ItemName::from("item_b")]);
+				})?;
+
+			assert_eq!(
+				Some(&Inventory::from([
+					Some(handles[1].clone()),
+					Some(handles[0].clone()),
+					Some(handles[2].clone()),
+				])),
+				app.world().entity(entity).get::<Inventory>(),
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn count_free_inventory_slots() -> Result<(), RunSystemError> {
+			let mut app = setup(MockAssetServer::default());
+			let entity = app
+				.world_mut()
+				.spawn((
+					Slots::default(),
+					Inventory::from([None, Some(new_handle())]),
+				))
+				.id();
+
+			let free_slots = app
+				.world_mut()
+				.run_system_once(move |mut p: LoadoutWriter| {
+					let ctx = LoadoutWriter::try_get_context_mut(&mut p, Items { entity }).unwrap();
+					ctx.free_slots()
+				})?;
+
+			assert_eq!(Inventory::CAPACITY - 1, free_slots);
+			Ok(())
+		}
+	}
 }
@@ -0,0 +1,168 @@
+use crate::{
+	components::progression::ProgressionInternal,
+	skills::{Skill, SkillId},
+	system_parameters::loadout::LoadoutWriter,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl TryGetContextMut<Progression> for LoadoutWriter<'static, 'static> {
+	type TContext<'ctx> = ProgressionMut<'ctx>;
+
+	fn try_get_context_mut<'ctx>(
+		param: &'ctx mut LoadoutWriter,
+		Progression { entity }: Progression,
+	) -> Option<Self::TContext<'ctx>> {
+		let progression = param.progressions.get_mut(entity).ok()?;
+
+		Some(ProgressionMut {
+			progression,
+			skills: &param.skills,
+		})
+	}
+}
+
+pub struct ProgressionMut<'ctx> {
+	progression: Mut<'ctx, ProgressionInternal>,
+	skills: &'ctx Assets<Skill>,
+}
+
+impl GainExperience for ProgressionMut<'_> {
+	fn gain_experience(&mut self, amount: u32) {
+		if amount == 0 {
+			return;
+		}
+
+		self.progression.gain_experience(amount);
+	}
+}
+
+impl AcquireUpgrade<SkillId> for ProgressionMut<'_> {
+	fn acquire_upgrade(&mut self, skill: SkillId, upgrade: &UpgradeId) {
+		let Some((_, skill)) = self.skills.iter().find(|(_, s)| s.id == skill) else {
+			return;
+		};
+
+		self.progression.acquire(skill, upgrade);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{item::Item, skills::upgrades::SkillUpgrade};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use std::collections::{HashMap, HashSet};
+	use testing::{IsChanged, SingleThreadedApp, new_handle};
+
+	fn setup<const N: usize>(skills: [Skill; N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut skill_assets = Assets::default();
+
+		for skill in skills {
+			_ = skill_assets.insert(&new_handle(), skill);
+		}
+
+		app.insert_resource(skill_assets);
+		app.init_resource::<Assets<Item>>();
+
+		app
+	}
+
+	#[test]
+	fn gain_experience() -> Result<(), RunSystemError> {
+		let mut app = setup([]);
+		let entity = app.world_mut().spawn(ProgressionInternal::default()).id();
+
+		app.world_mut()
+			.run_system_once(move |mut loadout: LoadoutWriter| {
+				let ctx = LoadoutWriter::try_get_context_mut(&mut loadout, Progression { entity });
+				if let Some(mut ctx) = ctx {
+					ctx.gain_experience(120);
+				}
+			})?;
+
+		assert_eq!(
+			Some(&ProgressionInternal {
+				level: 2,
+				experience: 20,
+				skill_points: 1,
+				..default()
+			}),
+			app.world().entity(entity).get::<ProgressionInternal>(),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_mark_changed_when_gaining_no_experience() {
+		let mut app = setup([]);
+		let entity = app.world_mut().spawn(ProgressionInternal::default()).id();
+
+		app.add_systems(
+			Update,
+			(
+				move |mut loadout: LoadoutWriter| {
+					let ctx =
+						LoadoutWriter::try_get_context_mut(&mut loadout, Progression { entity });
+					if let Some(mut ctx) = ctx {
+						ctx.gain_experience(0);
+					}
+				},
+				IsChanged::<ProgressionInternal>::detect,
+			)
+				.chain(),
+		);
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::<ProgressionInternal>::FALSE),
+			app.world()
+				.entity(entity)
+				.get::<IsChanged<ProgressionInternal>>(),
+		);
+	}
+
+	#[test]
+	fn acquire_upgrade() -> Result<(), RunSystemError> {
+		let mut app = setup([Skill {
+			upgrades: vec![SkillUpgrade {
+				id: UpgradeId::from("a"),
+				token: Token::from("a"),
+				cost: 1,
+				requires: vec![],
+				modifiers: vec![],
+			}],
+			..default()
+		}]);
+		let entity = app
+			.world_mut()
+			.spawn(ProgressionInternal {
+				skill_points: 1,
+				..default()
+			})
+			.id();
+
+		app.world_mut()
+			.run_system_once(move |mut loadout: LoadoutWriter| {
+				let ctx = LoadoutWriter::try_get_context_mut(&mut loadout, Progression { entity });
+				if let Some(mut ctx) = ctx {
+					ctx.acquire_upgrade(SkillId::default(), &UpgradeId::from("a"));
+				}
+			})?;
+
+		assert_eq!(
+			Some(&ProgressionInternal {
+				skill_points: 0,
+				upgrades: HashMap::from([(
+					SkillId::default(),
+					HashSet::from([UpgradeId::from("a")])
+				)]),
+				..default()
+			}),
+			app.world().entity(entity).get::<ProgressionInternal>(),
+		);
+		Ok(())
+	}
+}
@@ -1,7 +1,13 @@
 pub(crate) mod active_skill;
+pub(crate) mod advance_cooldowns;
+pub(crate) mod aim_preview;
 pub(crate) mod combos;
 pub(crate) mod enqueue;
+pub(crate) mod equipment_stats;
 pub(crate) mod flush;
 pub(crate) mod flush_skill_combos;
+pub(crate) mod progression;
 pub(crate) mod schedule_active_skill;
 pub(crate) mod slot;
+pub(crate) mod sockets;
+pub(crate) mod visualize_dropped_items;
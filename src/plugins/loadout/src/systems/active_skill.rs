@@ -1 +1,3 @@
+mod dash;
 mod execute;
+mod play_cast_sound;
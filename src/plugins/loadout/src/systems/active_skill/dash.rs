@@ -0,0 +1,215 @@
+use crate::{
+	components::active_skill::ActiveSkill,
+	traits::spawn_skill::extension::SkillConfigData,
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl<TConfig> ActiveSkill<TConfig>
+where
+	TConfig: ThreadSafe + SkillConfigData,
+{
+	/// Moves the caster of a starting [`Dash`] forward by the dash range within the dash duration.
+	///
+	/// The movement cannot be overridden by other movement until the duration elapsed, which
+	/// matches the lifetime of the dash contact.
+	pub(crate) fn dash<TMovement>(
+		mut movement: StaticSystemParam<TMovement>,
+		agents: Query<(Entity, &Self, &Transform)>,
+	) where
+		TMovement: for<'c> TryGetContextMut<ConfiguredMovement, TContext<'c>: ForceMovement>,
+	{
+		for (entity, active_skill, transform) in &agents {
+			let Self::Start { shape, .. } = active_skill else {
+				continue;
+			};
+			let SkillShape::Dash(Dash { range, duration }) = shape.shape() else {
+				continue;
+			};
+			if duration.is_zero() {
+				continue;
+			}
+			let key = ConfiguredMovement { entity };
+			let Some(mut ctx) = TMovement::try_get_context_mut(&mut movement, key) else {
+				continue;
+			};
+
+			let target = transform.translation + transform.forward() * **range;
+			let speed = UnitsPerSecond::from(**range / duration.as_secs_f32());
+			ctx.force_movement(target, speed, *duration);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::shape::OnSkillStop;
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use std::time::Duration;
+	use testing::{NestedMocks, SingleThreadedApp, assert_eq_approx};
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Config(SkillShape);
+
+	impl SkillConfigData for _Config {
+		fn mount(&self, _: SlotKey) -> SkillMount {
+			SkillMount::Center
+		}
+
+		fn shape(&self) -> &'_ SkillShape {
+			&self.0
+		}
+
+		fn contact_effects(&self) -> &'_ [SkillEffect] {
+			&[]
+		}
+
+		fn projection_effects(&self) -> &'_ [SkillEffect] {
+			&[]
+		}
+
+		fn cast_sound(&self) -> Option<&'_ SoundCue> {
+			None
+		}
+
+		fn impact_sound(&self) -> Option<&'_ SoundCue> {
+			None
+		}
+
+		fn follow_ups(&self) -> &'_ [FollowUpSkill] {
+			&[]
+		}
+
+		fn on_skill_stop(&self, _: PersistentEntity) -> OnSkillStop {
+			OnSkillStop::Ignore
+		}
+	}
+
+	#[derive(Component, NestedMocks)]
+	struct _Movement {
+		mock: Mock_Movement,
+	}
+
+	#[automock]
+	impl ForceMovement for _Movement {
+		fn force_movement(&mut self, target: Vec3, speed: UnitsPerSecond, duration: Duration) {
+			self.mock.force_movement(target, speed, duration);
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			ActiveSkill::<_Config>::dash::<Query<&mut _Movement>>,
+		);
+
+		app
+	}
+
+	fn dash(range: f32, duration: Duration) -> _Config {
+		_Config(SkillShape::from(Dash {
+			range: Units::from(range),
+			duration,
+		}))
+	}
+
+	#[test]
+	fn force_movement_forward() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			Transform::from_xyz(1., 2., 3.).looking_to(Dir3::X, Dir3::Y),
+			ActiveSkill::Start {
+				slot_key: SlotKey(11),
+				shape: dash(4., Duration::from_millis(200)),
+			},
+			_Movement::new().with_mock(|mock| {
+				mock.expect_force_movement()
+					.once()
+					.withf(|target, speed, duration| {
+						assert_eq_approx!(Vec3::new(5., 2., 3.), *target, 0.0001);
+						*speed == UnitsPerSecond::from(20.)
+							&& *duration == Duration::from_millis(200)
+					})
+					.return_const(());
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn do_nothing_when_duration_zero() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			Transform::default(),
+			ActiveSkill::Start {
+				slot_key: SlotKey(11),
+				shape: dash(4., Duration::ZERO),
+			},
+			_Movement::new().with_mock(|mock| {
+				mock.expect_force_movement().never();
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn do_nothing_when_not_a_dash() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			Transform::default(),
+			ActiveSkill::Start {
+				slot_key: SlotKey(11),
+				shape: _Config(SkillShape::from(Shield)),
+			},
+			_Movement::new().with_mock(|mock| {
+				mock.expect_force_movement().never();
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn do_nothing_when_not_starting() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			Transform::default(),
+			ActiveSkill::<_Config>::Stop(PersistentEntity::default()),
+			_Movement::new().with_mock(|mock| {
+				mock.expect_force_movement().never();
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn force_movement_along_default_forward() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			Transform::default(),
+			ActiveSkill::Start {
+				slot_key: SlotKey(11),
+				shape: dash(4., Duration::from_secs(2)),
+			},
+			_Movement::new().with_mock(|mock| {
+				mock.expect_force_movement()
+					.once()
+					.with(
+						eq(Vec3::new(0., 0., -4.)),
+						eq(UnitsPerSecond::from(2.)),
+						eq(Duration::from_secs(2)),
+					)
+					.return_const(());
+			}),
+		));
+
+		app.update();
+	}
+}
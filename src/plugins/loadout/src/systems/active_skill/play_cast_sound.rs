@@ -0,0 +1,181 @@
+use crate::{
+	components::{active_skill::ActiveSkill, slots::Slots},
+	item::Item,
+	traits::spawn_skill::extension::SkillConfigData,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl<TConfig> ActiveSkill<TConfig>
+where
+	TConfig: ThreadSafe + SkillConfigData,
+{
+	pub(crate) fn play_cast_sound(
+		mut sounds: MessageWriter<PlaySound>,
+		agents: Query<(Entity, &Self, Option<&Slots>)>,
+		items: Res<Assets<Item>>,
+	) {
+		for (entity, active_skill, slots) in &agents {
+			let Self::Start { slot_key, shape } = active_skill else {
+				continue;
+			};
+			let item_sound = slots
+				.and_then(|slots| slots.get_ref(slot_key))
+				.and_then(|item| items.get(item))
+				.and_then(|item| item.cast_sound.as_ref());
+
+			for cue in [item_sound, shape.cast_sound()].into_iter().flatten() {
+				sounds.write(PlaySound::sfx(cue.clone(), SoundEmitter::Entity(entity)));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::shape::OnSkillStop;
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Config {
+		cast_sound: Option<SoundCue>,
+	}
+
+	impl SkillConfigData for _Config {
+		fn mount(&self, _: SlotKey) -> SkillMount {
+			SkillMount::Center
+		}
+
+		fn shape(&self) -> &'_ SkillShape {
+			&SkillShape::Shield(Shield)
+		}
+
+		fn contact_effects(&self) -> &'_ [SkillEffect] {
+			&[]
+		}
+
+		fn projection_effects(&self) -> &'_ [SkillEffect] {
+			&[]
+		}
+
+		fn cast_sound(&self) -> Option<&'_ SoundCue> {
+			self.cast_sound.as_ref()
+		}
+
+		fn impact_sound(&self) -> Option<&'_ SoundCue> {
+			None
+		}
+
+		fn follow_ups(&self) -> &'_ [FollowUpSkill] {
+			&[]
+		}
+
+		fn on_skill_stop(&self, _: PersistentEntity) -> OnSkillStop {
+			OnSkillStop::Ignore
+		}
+	}
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Sounds(Vec<PlaySound>);
+
+	impl _Sounds {
+		fn collect(mut reader: MessageReader<PlaySound>, mut sounds: ResMut<Self>) {
+			sounds.0.extend(reader.read().cloned());
+		}
+	}
+
+	fn setup<const N: usize>(items: [(&Handle<Item>, Item); N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+
+		for (id, asset) in items {
+			_ = assets.insert(id, asset);
+		}
+
+		app.insert_resource(assets);
+		app.add_message::<PlaySound>();
+		app.init_resource::<_Sounds>();
+		app.add_systems(
+			Update,
+			(ActiveSkill::<_Config>::play_cast_sound, _Sounds::collect).chain(),
+		);
+
+		app
+	}
+
+	#[test]
+	fn play_skill_cast_sound() {
+		let mut app = setup([]);
+		let entity = app
+			.world_mut()
+			.spawn(ActiveSkill::Start {
+				slot_key: SlotKey(0),
+				shape: _Config {
+					cast_sound: Some(SoundCue::from("sounds/cast.ogg")),
+				},
+			})
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			&_Sounds(vec![PlaySound::sfx(
+				SoundCue::from("sounds/cast.ogg"),
+				SoundEmitter::Entity(entity),
+			)]),
+			app.world().resource::<_Sounds>(),
+		);
+	}
+
+	#[test]
+	fn play_item_and_skill_cast_sound() {
+		let item = new_handle();
+		let mut app = setup([(
+			&item,
+			Item {
+				cast_sound: Some(SoundCue::from("sounds/swing.ogg")),
+				..default()
+			},
+		)]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				Slots::from([(SlotKey(4), Some(item))]),
+				ActiveSkill::Start {
+					slot_key: SlotKey(4),
+					shape: _Config {
+						cast_sound: Some(SoundCue::from("sounds/cast.ogg")),
+					},
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			&_Sounds(vec![
+				PlaySound::sfx(
+					SoundCue::from("sounds/swing.ogg"),
+					SoundEmitter::Entity(entity),
+				),
+				PlaySound::sfx(
+					SoundCue::from("sounds/cast.ogg"),
+					SoundEmitter::Entity(entity),
+				),
+			]),
+			app.world().resource::<_Sounds>(),
+		);
+	}
+
+	#[test]
+	fn play_nothing_when_not_started() {
+		let mut app = setup([]);
+		app.world_mut()
+			.spawn(ActiveSkill::<_Config>::Stop(PersistentEntity::default()));
+
+		app.update();
+
+		assert_eq!(&_Sounds(vec![]), app.world().resource::<_Sounds>());
+	}
+}
@@ -0,0 +1,97 @@
+use crate::components::cooldowns::Cooldowns;
+use bevy::prelude::*;
+use std::time::Duration;
+
+impl Cooldowns {
+	pub(crate) fn advance(In(delta): In<Duration>, agents: Query<&mut Self>) {
+		for mut cooldowns in agents {
+			if cooldowns.0.is_empty() {
+				continue;
+			}
+
+			cooldowns.0.retain(|_, cooldown| {
+				cooldown.remaining = cooldown.remaining.saturating_sub(delta);
+				!cooldown.remaining.is_zero()
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{components::cooldowns::Cooldown, skills::SkillId};
+	use std::collections::HashMap;
+	use testing::{IsChanged, SingleThreadedApp};
+	use uuid::uuid;
+
+	const SKILL: SkillId = SkillId(uuid!("6a1f0c9e-3d2b-4e8a-b7c5-1f2e3d4c5b6a"));
+
+	fn setup(delta: Duration) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(
+				(move || delta).pipe(Cooldowns::advance),
+				IsChanged::<Cooldowns>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn cooldown(remaining: u64) -> Cooldown {
+		Cooldown {
+			remaining: Duration::from_secs(remaining),
+			total: Duration::from_secs(4),
+		}
+	}
+
+	#[test]
+	fn reduce_remaining() {
+		let mut app = setup(Duration::from_secs(1));
+		let entity = app
+			.world_mut()
+			.spawn(Cooldowns(HashMap::from([(SKILL, cooldown(3))])))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Cooldowns(HashMap::from([(SKILL, cooldown(2))]))),
+			app.world().entity(entity).get::<Cooldowns>()
+		);
+	}
+
+	#[test]
+	fn remove_when_run_out() {
+		let mut app = setup(Duration::from_secs(3));
+		let entity = app
+			.world_mut()
+			.spawn(Cooldowns(HashMap::from([(SKILL, cooldown(3))])))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Cooldowns::default()),
+			app.world().entity(entity).get::<Cooldowns>()
+		);
+	}
+
+	#[test]
+	fn do_not_mutate_when_empty() {
+		let mut app = setup(Duration::from_secs(1));
+		let entity = app.world_mut().spawn(Cooldowns::default()).id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<Cooldowns>>()
+		);
+	}
+}
@@ -0,0 +1,214 @@
+use crate::{
+	components::aim_preview::AimPreview,
+	item::Item,
+	skills::{RunSkillBehavior, Skill},
+	systems::enqueue::{get_skill, held_slots::HeldSlots},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use std::f32::consts::FRAC_PI_2;
+
+impl AimPreview {
+	const COLOR: Color = Color::srgba(0.9, 0.9, 1., 0.6);
+	/// Lifts the outline slightly above the ground, so it is not hidden by the floor
+	const GROUND_OFFSET: f32 = 0.05;
+
+	/// Uses the held `OnAim` skill with the lowest slot key, when multiple are held.
+	pub(crate) fn update<TSlots>(
+		mut commands: ZyheedaCommands,
+		agents: Query<(Entity, &HeldSlots, &TSlots, Option<&Self>)>,
+		items: Res<Assets<Item>>,
+		skills: Res<Assets<Skill>>,
+	) where
+		for<'a> TSlots: GetRef<SlotKey, TValue<'a> = &'a Handle<Item>> + Component,
+	{
+		for (entity, held_slots, slots, current) in &agents {
+			let preview = held_slots
+				.iter()
+				.filter_map(|key| {
+					let skill = get_skill(key, slots, &items, &skills)?;
+					let RunSkillBehavior::OnAim(config) = &skill.behavior else {
+						return None;
+					};
+					Some((key, Self::from_shape(&config.shape)?))
+				})
+				.min_by_key(|(SlotKey(key), _)| *key)
+				.map(|(_, preview)| preview);
+
+			if preview.as_ref() == current {
+				continue;
+			}
+
+			commands.try_apply_on(&entity, |mut e| match preview {
+				Some(preview) => {
+					e.try_insert(preview);
+				}
+				None => {
+					e.try_remove::<Self>();
+				}
+			});
+		}
+	}
+
+	pub(crate) fn draw(mut gizmos: Gizmos, previews: Query<(&Self, &GlobalTransform)>) {
+		for (preview, transform) in &previews {
+			let center = transform.translation() + Vec3::Y * Self::GROUND_OFFSET;
+			let forward = transform.forward().with_y(0.).normalize_or(Vec3::NEG_Z);
+			let flat = Quat::from_rotation_x(FRAC_PI_2);
+
+			match *preview {
+				Self::Area { range, radius } => {
+					let edge = center + forward * range;
+					gizmos.circle(Isometry3d::new(center, flat), range, Self::COLOR);
+					gizmos.circle(Isometry3d::new(edge, flat), radius, Self::COLOR);
+				}
+				Self::Line { range } => {
+					gizmos.line(center, center + forward * range, Self::COLOR);
+				}
+				Self::Arc { range, angle } => {
+					let start = Quat::from_rotation_y(-angle / 2.) * forward;
+					let end = Quat::from_rotation_y(angle / 2.) * forward;
+					let yaw = Quat::from_rotation_y(f32::atan2(-start.z, start.x));
+					gizmos.arc_3d(angle, range, Isometry3d::new(center, yaw), Self::COLOR);
+					gizmos.line(center, center + start * range, Self::COLOR);
+					gizmos.line(center, center + end * range, Self::COLOR);
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::behaviors::SkillBehaviorConfig;
+	use std::{collections::HashMap, time::Duration};
+	use testing::{SingleThreadedApp, new_handle};
+
+	#[derive(Component)]
+	struct _Slots(HashMap<SlotKey, Handle<Item>>);
+
+	impl GetRef<SlotKey> for _Slots {
+		type TValue<'a>
+			= &'a Handle<Item>
+		where
+			Self: 'a;
+
+		fn get_ref<'a>(&'a self, key: &SlotKey) -> Option<&'a Handle<Item>> {
+			self.0.get(key)
+		}
+	}
+
+	fn beam() -> SkillShape {
+		SkillShape::from(Beam {
+			range: Units::from(10.),
+			blocked_by: Blockers::All,
+		})
+	}
+
+	fn dash() -> SkillShape {
+		SkillShape::from(Dash {
+			range: Units::from(4.),
+			duration: Duration::from_secs(1),
+		})
+	}
+
+	fn setup<const N: usize>(behaviors: [(SlotKey, RunSkillBehavior); N]) -> (App, _Slots) {
+		let mut app = App::new().single_threaded(Update);
+		let mut item_assets = Assets::<Item>::default();
+		let mut skill_assets = Assets::<Skill>::default();
+		let mut slots = HashMap::default();
+
+		for (key, behavior) in behaviors {
+			let item = new_handle();
+			let skill = new_handle();
+			_ = item_assets.insert(
+				item.id(),
+				Item {
+					skill: Some(skill.clone()),
+					..default()
+				},
+			);
+			_ = skill_assets.insert(
+				skill.id(),
+				Skill {
+					behavior,
+					..default()
+				},
+			);
+			slots.insert(key, item);
+		}
+
+		app.insert_resource(item_assets);
+		app.insert_resource(skill_assets);
+		app.add_systems(Update, AimPreview::update::<_Slots>);
+
+		(app, _Slots(slots))
+	}
+
+	fn on_aim(shape: SkillShape) -> RunSkillBehavior {
+		RunSkillBehavior::OnAim(SkillBehaviorConfig::from_shape(shape))
+	}
+
+	fn on_active(shape: SkillShape) -> RunSkillBehavior {
+		RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(shape))
+	}
+
+	#[test]
+	fn insert_preview_of_held_on_aim_skill() {
+		let (mut app, slots) = setup([(SlotKey(1), on_aim(beam()))]);
+		let entity = app
+			.world_mut()
+			.spawn((slots, HeldSlots::from([SlotKey(1)])))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&AimPreview::Line { range: 10. }),
+			app.world().entity(entity).get::<AimPreview>(),
+		);
+	}
+
+	#[test]
+	fn insert_no_preview_for_on_active_skill() {
+		let (mut app, slots) = setup([(SlotKey(1), on_active(beam()))]);
+		let entity = app
+			.world_mut()
+			.spawn((slots, HeldSlots::from([SlotKey(1)])))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(entity).get::<AimPreview>());
+	}
+
+	#[test]
+	fn use_lowest_held_slot() {
+		let (mut app, slots) = setup([(SlotKey(2), on_aim(beam())), (SlotKey(1), on_aim(dash()))]);
+		let entity = app
+			.world_mut()
+			.spawn((slots, HeldSlots::from([SlotKey(2), SlotKey(1)])))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&AimPreview::Line { range: 4. }),
+			app.world().entity(entity).get::<AimPreview>(),
+		);
+	}
+
+	#[test]
+	fn remove_preview_when_slot_released() {
+		let (mut app, slots) = setup([(SlotKey(1), on_aim(beam()))]);
+		let entity = app
+			.world_mut()
+			.spawn((slots, HeldSlots::default(), AimPreview::Line { range: 10. }))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(entity).get::<AimPreview>());
+	}
+}
@@ -1,9 +1,10 @@
 pub(crate) mod held_slots;
 
 use crate::{
+	components::cooldowns::Cooldowns,
 	item::Item,
 	skills::Skill,
-	traits::{Enqueue, IterHoldingMut, ReleaseSkill},
+	traits::{Enqueue, GetSkillCost, IterHoldingMut, ReleaseSkill},
 };
 use bevy::{ecs::component::Mutable, prelude::*};
 use common::prelude::*;
@@ -22,18 +23,26 @@ pub(crate) trait EnqueueSystem:
 	+ IterHoldingMut<TItem: ReleaseSkill + View<SlotKey>>
 	+ Sized
 {
-	fn enqueue_system<TSlots>(
-		agents: Query<(&mut Self, &TSlots, &mut HeldSlots)>,
+	fn enqueue_system<TSlots, TStatusEffects, TEnergy>(
+		agents: Query<Agents<Self, TSlots, TStatusEffects, TEnergy>>,
 		items: Res<Assets<Item>>,
 		skills: Res<Assets<Skill>>,
 	) where
 		for<'a> TSlots: GetRef<SlotKey, TValue<'a> = &'a Handle<Item>> + Component,
+		TStatusEffects: Component + View<Stunned>,
+		TEnergy: Component + View<Energy>,
 	{
-		for (mut queue, slots, mut held_slots) in agents {
-			for key in held_slots.iter_new() {
+		for (mut queue, slots, mut held_slots, cooldowns, status_effects, energy) in agents {
+			let stunned = status_effects.is_some_and(|effects| effects.view());
+			let energy = energy.map(View::<Energy>::view);
+
+			for key in held_slots.iter_new().filter(|_| !stunned) {
 				let Some(skill) = get_skill(key, slots, &items, &skills) else {
 					continue;
 				};
+				if !skill.cost().is_affordable(cooldowns, energy) {
+					continue;
+				}
 				queue.enqueue((skill.clone(), *key));
 			}
 
@@ -50,7 +59,16 @@ pub(crate) trait EnqueueSystem:
 	}
 }
 
-fn get_skill<'a, TSlots>(
+type Agents<'a, TQueue, TSlots, TStatusEffects, TEnergy> = (
+	&'a mut TQueue,
+	&'a TSlots,
+	&'a mut HeldSlots,
+	Option<&'a Cooldowns>,
+	Option<&'a TStatusEffects>,
+	Option<&'a TEnergy>,
+);
+
+pub(crate) fn get_skill<'a, TSlots>(
 	key: &SlotKey,
 	slots: &'a TSlots,
 	items: &'a Assets<Item>,
@@ -69,9 +87,11 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::skills::SkillId;
 	use macros::{NestedMocks, simple_mock};
 	use mockall::{automock, predicate::eq};
-	use std::collections::HashMap;
+	use std::{collections::HashMap, time::Duration};
+	use test_case::test_case;
 	use testing::{Mock, NestedMocks, SingleThreadedApp, new_handle};
 
 	simple_mock! {
@@ -118,6 +138,26 @@ mod tests {
 		}
 	}
 
+	#[derive(Component)]
+	struct _StatusEffects {
+		stunned: bool,
+	}
+
+	impl View<Stunned> for _StatusEffects {
+		fn view(&self) -> bool {
+			self.stunned
+		}
+	}
+
+	#[derive(Component)]
+	struct _Energy(Energy);
+
+	impl View<Energy> for _Energy {
+		fn view(&self) -> Energy {
+			self.0
+		}
+	}
+
 	struct _SkillLoader;
 
 	fn setup<TEnqueue>(
@@ -143,7 +183,10 @@ mod tests {
 
 		app.insert_resource(item_assets);
 		app.insert_resource(skill_assets);
-		app.add_systems(Update, TEnqueue::enqueue_system::<_Skills>);
+		app.add_systems(
+			Update,
+			TEnqueue::enqueue_system::<_Skills, _StatusEffects, _Energy>,
+		);
 
 		app
 	}
@@ -222,6 +265,189 @@ mod tests {
 		app.update();
 	}
 
+	#[test]
+	fn do_not_enqueue_skill_when_stunned() {
+		#[derive(Component, NestedMocks)]
+		struct _Enqueue {
+			mock: Mock_Enqueue,
+		}
+
+		#[automock]
+		impl Enqueue<(Skill, SlotKey)> for _Enqueue {
+			fn enqueue(&mut self, item: (Skill, SlotKey)) {
+				self.mock.enqueue(item)
+			}
+		}
+
+		impl IterHoldingMut for _Enqueue {
+			type TItem = Mock_SkillQueued;
+
+			fn iter_holding_mut<'a>(&mut self) -> impl Iterator<Item = &'a mut Mock_SkillQueued>
+			where
+				Mock_SkillQueued: 'a,
+			{
+				std::iter::empty()
+			}
+		}
+
+		let item = new_handle();
+		let skill = new_handle();
+		let mut app = setup::<_Enqueue>(
+			vec![(
+				item.id(),
+				Item {
+					skill: Some(skill.clone()),
+					..default()
+				},
+			)],
+			vec![(skill.id(), Skill::default())],
+		);
+
+		let skills = _Skills(HashMap::from([(
+			SlotKey::from(HandSlot::Right),
+			item.clone(),
+		)]));
+		let mut held_slots = HeldSlots::default();
+		held_slots.rotate();
+		held_slots.extend([SlotKey::from(HandSlot::Right)]);
+		app.world_mut().spawn((
+			skills,
+			held_slots,
+			_StatusEffects { stunned: true },
+			_Enqueue::new().with_mock(|mock| {
+				mock.expect_enqueue().never();
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test]
+	fn do_not_enqueue_skill_on_cooldown() {
+		#[derive(Component, NestedMocks)]
+		struct _Enqueue {
+			mock: Mock_Enqueue,
+		}
+
+		#[automock]
+		impl Enqueue<(Skill, SlotKey)> for _Enqueue {
+			fn enqueue(&mut self, item: (Skill, SlotKey)) {
+				self.mock.enqueue(item)
+			}
+		}
+
+		impl IterHoldingMut for _Enqueue {
+			type TItem = Mock_SkillQueued;
+
+			fn iter_holding_mut<'a>(&mut self) -> impl Iterator<Item = &'a mut Mock_SkillQueued>
+			where
+				Mock_SkillQueued: 'a,
+			{
+				std::iter::empty()
+			}
+		}
+
+		let item = new_handle();
+		let skill = new_handle();
+		let mut app = setup::<_Enqueue>(
+			vec![(
+				item.id(),
+				Item {
+					skill: Some(skill.clone()),
+					..default()
+				},
+			)],
+			vec![(skill.id(), Skill::default())],
+		);
+
+		let skills = _Skills(HashMap::from([(
+			SlotKey::from(HandSlot::Right),
+			item.clone(),
+		)]));
+		let mut held_slots = HeldSlots::default();
+		held_slots.rotate();
+		held_slots.extend([SlotKey::from(HandSlot::Right)]);
+		let mut cooldowns = Cooldowns::default();
+		cooldowns.start(SkillId::default(), Duration::from_secs(1));
+		app.world_mut().spawn((
+			skills,
+			held_slots,
+			cooldowns,
+			_Enqueue::new().with_mock(|mock| {
+				mock.expect_enqueue().never();
+			}),
+		));
+
+		app.update();
+	}
+
+	#[test_case(4., 0; "when not affordable")]
+	#[test_case(5., 1; "when affordable")]
+	fn enqueue_skill_depending_on_energy(current: f32, times: usize) {
+		#[derive(Component, NestedMocks)]
+		struct _Enqueue {
+			mock: Mock_Enqueue,
+		}
+
+		#[automock]
+		impl Enqueue<(Skill, SlotKey)> for _Enqueue {
+			fn enqueue(&mut self, item: (Skill, SlotKey)) {
+				self.mock.enqueue(item)
+			}
+		}
+
+		impl IterHoldingMut for _Enqueue {
+			type TItem = Mock_SkillQueued;
+
+			fn iter_holding_mut<'a>(&mut self) -> impl Iterator<Item = &'a mut Mock_SkillQueued>
+			where
+				Mock_SkillQueued: 'a,
+			{
+				std::iter::empty()
+			}
+		}
+
+		let item = new_handle();
+		let skill = new_handle();
+		let mut app = setup::<_Enqueue>(
+			vec![(
+				item.id(),
+				Item {
+					skill: Some(skill.clone()),
+					..default()
+				},
+			)],
+			vec![(
+				skill.id(),
+				Skill {
+					energy_cost: 5.,
+					..default()
+				},
+			)],
+		);
+
+		let skills = _Skills(HashMap::from([(
+			SlotKey::from(HandSlot::Right),
+			item.clone(),
+		)]));
+		let mut held_slots = HeldSlots::default();
+		held_slots.rotate();
+		held_slots.extend([SlotKey::from(HandSlot::Right)]);
+		app.world_mut().spawn((
+			skills,
+			held_slots,
+			_Energy(Energy {
+				current,
+				..Energy::new(10., 0.)
+			}),
+			_Enqueue::new().with_mock(|mock| {
+				mock.expect_enqueue().times(times).return_const(());
+			}),
+		));
+
+		app.update();
+	}
+
 	#[test]
 	fn release_skill_when_not_in_current_held_slots() {
 		let mut app = setup::<_Enqueue>(vec![], vec![]);
@@ -0,0 +1,3 @@
+pub(crate) mod apply_damage;
+pub(crate) mod insert;
+pub(crate) mod update;
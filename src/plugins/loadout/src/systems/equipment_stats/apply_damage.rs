@@ -0,0 +1,133 @@
+use crate::{
+	components::equipment_stats::EquipmentStatsInternal,
+	skills::{QueuedSkill, modifier::SkillModifier},
+	traits::IterAddedMut,
+};
+use bevy::{ecs::component::Mutable, prelude::*};
+
+impl EquipmentStatsInternal {
+	pub(crate) fn apply_damage<TQueue>(mut agents: Query<(&Self, &mut TQueue)>)
+	where
+		TQueue: IterAddedMut<TItem = QueuedSkill> + Component<Mutability = Mutable>,
+	{
+		for (Self(stats), mut queue) in &mut agents {
+			if stats.damage == 1. || queue.added_none() {
+				continue;
+			}
+
+			let modifier = SkillModifier::Damage(stats.damage);
+			for QueuedSkill { skill, .. } in queue.iter_added_mut() {
+				skill.apply_modifiers(&[modifier]);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::{RunSkillBehavior, Skill, behaviors::SkillBehaviorConfig};
+	use common::prelude::*;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	#[derive(Component, Default, PartialEq, Debug)]
+	struct _Queue {
+		added: Vec<QueuedSkill>,
+	}
+
+	impl IterAddedMut for _Queue {
+		type TItem = QueuedSkill;
+
+		fn added_none(&self) -> bool {
+			self.added.is_empty()
+		}
+
+		fn iter_added_mut<'a>(&'a mut self) -> impl DoubleEndedIterator<Item = &'a mut QueuedSkill>
+		where
+			QueuedSkill: 'a,
+		{
+			self.added.iter_mut()
+		}
+	}
+
+	fn damaging(damage: f32) -> Skill {
+		let mut config = SkillBehaviorConfig::from_shape(SkillShape::from(Shield));
+		config.contact.push(SkillEffect::HealthDamage(HealthDamage(
+			damage,
+			EffectApplies::Once,
+			DamageType::Kinetic,
+		)));
+
+		Skill {
+			behavior: RunSkillBehavior::OnActive(config),
+			..default()
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(
+				EquipmentStatsInternal::apply_damage::<_Queue>,
+				IsChanged::<_Queue>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn stats(damage: f32) -> EquipmentStatsInternal {
+		EquipmentStatsInternal(EquipmentStats {
+			damage,
+			..default()
+		})
+	}
+
+	#[test]
+	fn scale_damage_of_added_skills() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				stats(2.),
+				_Queue {
+					added: vec![QueuedSkill::new(damaging(5.), SlotKey(1))],
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Queue {
+				added: vec![QueuedSkill::new(damaging(10.), SlotKey(1))],
+			}),
+			app.world().entity(entity).get::<_Queue>(),
+		);
+	}
+
+	#[test]
+	fn do_not_mutate_queue_when_damage_neutral() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				stats(1.),
+				_Queue {
+					added: vec![QueuedSkill::new(damaging(5.), SlotKey(1))],
+				},
+			))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<_Queue>>(),
+		);
+	}
+}
@@ -0,0 +1,97 @@
+use crate::components::equipment_stats::EquipmentStatsInternal;
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl EquipmentStatsInternal {
+	pub(crate) fn insert<TEquipmentStats>(
+		mut commands: ZyheedaCommands,
+		agents: Query<(Entity, &Self), Changed<Self>>,
+	) where
+		TEquipmentStats: Component + From<EquipmentStats>,
+	{
+		for (entity, Self(stats)) in &agents {
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert(TEquipmentStats::from(*stats));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	#[derive(Component, Debug, PartialEq)]
+	struct _Stats(EquipmentStats);
+
+	impl From<EquipmentStats> for _Stats {
+		fn from(stats: EquipmentStats) -> Self {
+			Self(stats)
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, EquipmentStatsInternal::insert::<_Stats>);
+
+		app
+	}
+
+	#[test]
+	fn insert_stats() {
+		let mut app = setup();
+		let stats = EquipmentStats {
+			max_health: 42.,
+			..default()
+		};
+		let entity = app.world_mut().spawn(EquipmentStatsInternal(stats)).id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Stats(stats)),
+			app.world().entity(entity).get::<_Stats>(),
+		);
+	}
+
+	#[test]
+	fn act_only_once() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn(EquipmentStatsInternal::default())
+			.id();
+
+		app.update();
+		app.world_mut().entity_mut(entity).remove::<_Stats>();
+		app.update();
+
+		assert_eq!(None, app.world().entity(entity).get::<_Stats>());
+	}
+
+	#[test]
+	fn act_again_when_changed() {
+		let mut app = setup();
+		let stats = EquipmentStats {
+			damage: 2.,
+			..default()
+		};
+		let entity = app
+			.world_mut()
+			.spawn(EquipmentStatsInternal::default())
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(EquipmentStatsInternal(stats));
+		app.update();
+
+		assert_eq!(
+			Some(&_Stats(stats)),
+			app.world().entity(entity).get::<_Stats>(),
+		);
+	}
+}
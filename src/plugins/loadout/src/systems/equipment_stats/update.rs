@@ -0,0 +1,143 @@
+use crate::{
+	components::{equipment_stats::EquipmentStatsInternal, slots::Slots},
+	item::Item,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl EquipmentStatsInternal {
+	/// Stack the stats of all equipped items, ordered by slot to keep float results stable.
+	///
+	/// Runs every frame, because item assets might finish loading after the slots changed.
+	pub(crate) fn update(agents: Query<(&mut Self, &Slots)>, items: Res<Assets<Item>>) {
+		for (mut stats, slots) in agents {
+			let mut equipped = slots
+				.items
+				.iter()
+				.filter_map(|(key, item)| Some((key.0, items.get(item.as_ref()?)?)))
+				.collect::<Vec<_>>();
+			equipped.sort_by_key(|(key, _)| *key);
+
+			let stacked = equipped
+				.into_iter()
+				.map(|(_, item)| item.equipment_stats)
+				.fold(EquipmentStats::default(), EquipmentStats::stack);
+
+			stats.set_if_neq(Self(stacked));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{IsChanged, SingleThreadedApp, new_handle};
+
+	fn setup<const N: usize>(items: [(&Handle<Item>, Item); N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut item_assets = Assets::default();
+
+		for (id, asset) in items {
+			_ = item_assets.insert(id, asset);
+		}
+
+		app.insert_resource(item_assets);
+		app.add_systems(
+			Update,
+			(
+				EquipmentStatsInternal::update,
+				IsChanged::<EquipmentStatsInternal>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn item(max_health: f32, movement_speed: f32) -> Item {
+		Item {
+			equipment_stats: EquipmentStats {
+				max_health,
+				movement_speed,
+				..default()
+			},
+			..default()
+		}
+	}
+
+	#[test]
+	fn stack_equipped_items() {
+		let a = new_handle();
+		let b = new_handle();
+		let mut app = setup([(&a, item(10., 0.5)), (&b, item(5., 0.5))]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				EquipmentStatsInternal::default(),
+				Slots::from([
+					(SlotKey(0), Some(a)),
+					(SlotKey(1), Some(b)),
+					(SlotKey(2), None),
+				]),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&EquipmentStatsInternal(EquipmentStats {
+				max_health: 15.,
+				movement_speed: 0.25,
+				..default()
+			})),
+			app.world().entity(entity).get::<EquipmentStatsInternal>(),
+		);
+	}
+
+	#[test]
+	fn reset_when_unequipped() {
+		let a = new_handle();
+		let mut app = setup([(&a, item(10., 0.5))]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				EquipmentStatsInternal::default(),
+				Slots::from([(SlotKey(0), Some(a))]),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(Slots::from([(SlotKey(0), None)]));
+		app.update();
+
+		assert_eq!(
+			Some(&EquipmentStatsInternal::default()),
+			app.world().entity(entity).get::<EquipmentStatsInternal>(),
+		);
+	}
+
+	#[test]
+	fn act_only_once() {
+		let a = new_handle();
+		let mut app = setup([(&a, item(10., 0.5))]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				EquipmentStatsInternal::default(),
+				Slots::from([(SlotKey(0), Some(a))]),
+			))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world()
+				.entity(entity)
+				.get::<IsChanged<EquipmentStatsInternal>>(),
+		);
+	}
+}
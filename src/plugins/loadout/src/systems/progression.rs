@@ -0,0 +1,2 @@
+pub(crate) mod apply_upgrades;
+pub(crate) mod raise_max_health;
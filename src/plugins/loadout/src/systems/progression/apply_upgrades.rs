@@ -0,0 +1,180 @@
+use crate::{
+	components::progression::ProgressionInternal,
+	skills::QueuedSkill,
+	traits::IterAddedMut,
+};
+use bevy::{ecs::component::Mutable, prelude::*};
+
+impl ProgressionInternal {
+	pub(crate) fn apply_upgrades<TQueue>(mut agents: Query<(&Self, &mut TQueue)>)
+	where
+		TQueue: IterAddedMut<TItem = QueuedSkill> + Component<Mutability = Mutable>,
+	{
+		for (progression, mut queue) in &mut agents {
+			if queue.added_none() {
+				continue;
+			}
+
+			for QueuedSkill { skill, .. } in queue.iter_added_mut() {
+				let Some(acquired) = progression.acquired(&skill.id) else {
+					continue;
+				};
+
+				skill.apply_upgrades(acquired);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::{
+		RunSkillBehavior,
+		Skill,
+		SkillId,
+		behaviors::SkillBehaviorConfig,
+		modifier::SkillModifier,
+		upgrades::SkillUpgrade,
+	};
+	use common::prelude::*;
+	use std::collections::{HashMap, HashSet};
+	use testing::{IsChanged, SingleThreadedApp};
+
+	#[derive(Component, Default, PartialEq, Debug)]
+	struct _Queue {
+		added: Vec<QueuedSkill>,
+	}
+
+	impl IterAddedMut for _Queue {
+		type TItem = QueuedSkill;
+
+		fn added_none(&self) -> bool {
+			self.added.is_empty()
+		}
+
+		fn iter_added_mut<'a>(&'a mut self) -> impl DoubleEndedIterator<Item = &'a mut QueuedSkill>
+		where
+			QueuedSkill: 'a,
+		{
+			self.added.iter_mut()
+		}
+	}
+
+	fn beam(range: f32) -> RunSkillBehavior {
+		RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(SkillShape::Beam(Beam {
+			range: Units::from(range),
+			blocked_by: Blockers::All,
+		})))
+	}
+
+	fn skill(behavior: RunSkillBehavior) -> Skill {
+		Skill {
+			behavior,
+			upgrades: vec![SkillUpgrade {
+				id: UpgradeId::from("range"),
+				token: Token::from("range"),
+				cost: 1,
+				requires: vec![],
+				modifiers: vec![SkillModifier::Range(2.)],
+			}],
+			..default()
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, ProgressionInternal::apply_upgrades::<_Queue>);
+
+		app
+	}
+
+	#[test]
+	fn apply_acquired_upgrades_to_added_skills() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				ProgressionInternal {
+					upgrades: HashMap::from([(
+						SkillId::default(),
+						HashSet::from([UpgradeId::from("range")]),
+					)]),
+					..default()
+				},
+				_Queue {
+					added: vec![QueuedSkill {
+						skill: skill(beam(5.)),
+						..default()
+					}],
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Queue {
+				added: vec![QueuedSkill {
+					skill: skill(beam(10.)),
+					..default()
+				}],
+			}),
+			app.world().entity(entity).get::<_Queue>(),
+		);
+	}
+
+	#[test]
+	fn do_not_apply_upgrades_of_other_skills() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				ProgressionInternal {
+					upgrades: HashMap::from([(
+						SkillId(uuid::uuid!("ab0fe5c5-8a1e-4e56-8d8a-3e5b1fd0b0c4")),
+						HashSet::from([UpgradeId::from("range")]),
+					)]),
+					..default()
+				},
+				_Queue {
+					added: vec![QueuedSkill {
+						skill: skill(beam(5.)),
+						..default()
+					}],
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Queue {
+				added: vec![QueuedSkill {
+					skill: skill(beam(5.)),
+					..default()
+				}],
+			}),
+			app.world().entity(entity).get::<_Queue>(),
+		);
+	}
+
+	#[test]
+	fn queue_not_marked_changed_when_none_added() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((ProgressionInternal::default(), _Queue::default()))
+			.id();
+
+		app.add_systems(PostUpdate, IsChanged::<_Queue>::detect);
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::<_Queue>::FALSE),
+			app.world().entity(entity).get::<IsChanged<_Queue>>(),
+		);
+	}
+}
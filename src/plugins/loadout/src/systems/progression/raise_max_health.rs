@@ -0,0 +1,128 @@
+use crate::components::progression::ProgressionInternal;
+use bevy::{ecs::component::Mutable, prelude::*};
+use common::prelude::*;
+
+impl ProgressionInternal {
+	const MAX_HEALTH_PER_LEVEL: f32 = 10.;
+
+	pub(crate) fn raise_max_health<TMaxHealth>(
+		mut agents: Query<(&mut Self, &mut TMaxHealth), Changed<Self>>,
+	) where
+		TMaxHealth: Component<Mutability = Mutable> + RaiseMaxHealth,
+	{
+		for (mut progression, mut health) in &mut agents {
+			if progression.health_level >= progression.level {
+				continue;
+			}
+
+			let levels = progression.level - progression.health_level;
+			health.raise_max_health(levels as f32 * Self::MAX_HEALTH_PER_LEVEL);
+			progression.health_level = progression.level;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	#[derive(Component, Debug, PartialEq, Default)]
+	struct _Health(f32);
+
+	impl RaiseMaxHealth for _Health {
+		fn raise_max_health(&mut self, amount: f32) {
+			self.0 += amount;
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, ProgressionInternal::raise_max_health::<_Health>);
+
+		app
+	}
+
+	#[test]
+	fn raise_max_health_for_each_new_level() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				ProgressionInternal {
+					level: 4,
+					health_level: 2,
+					..default()
+				},
+				_Health(100.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			(Some(&_Health(120.)), Some(4)),
+			(
+				app.world().entity(entity).get::<_Health>(),
+				app.world()
+					.entity(entity)
+					.get::<ProgressionInternal>()
+					.map(|p| p.health_level),
+			),
+		);
+	}
+
+	#[test]
+	fn do_not_raise_max_health_when_level_unchanged() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				ProgressionInternal {
+					level: 3,
+					health_level: 3,
+					..default()
+				},
+				_Health(100.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Health(100.)),
+			app.world().entity(entity).get::<_Health>(),
+		);
+	}
+
+	#[test]
+	fn raise_max_health_only_once() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				ProgressionInternal {
+					level: 2,
+					health_level: 1,
+					..default()
+				},
+				_Health(100.),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.get_mut::<ProgressionInternal>()
+			.unwrap()
+			.set_changed();
+		app.update();
+
+		assert_eq!(
+			Some(&_Health(110.)),
+			app.world().entity(entity).get::<_Health>(),
+		);
+	}
+}
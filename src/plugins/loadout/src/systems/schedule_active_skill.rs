@@ -1,6 +1,7 @@
 use crate::{
+	components::cooldowns::Cooldowns,
 	skills::{RunSkillBehavior, SkillState, behaviors::SkillBehaviorConfig},
-	traits::{Flush, GetActiveSkill, GetSkillBehavior, Schedule},
+	traits::{Flush, GetActiveSkill, GetSkillBehavior, GetSkillCost, Schedule},
 };
 use bevy::{
 	ecs::{component::Mutable, system::StaticSystemParam},
@@ -15,24 +16,37 @@ enum Advancement {
 	InProcess,
 }
 
-pub(crate) fn schedule_active_skill<TGetSkill, TFacing, TActiveSkill, TTime>(
+type Agents<'a, TGetSkill, TActiveSkill, TEnergy> = (
+	Entity,
+	&'a mut TGetSkill,
+	&'a mut TActiveSkill,
+	Option<&'a mut Cooldowns>,
+	Option<&'a mut TEnergy>,
+);
+
+pub(crate) fn schedule_active_skill<TGetSkill, TFacing, TActiveSkill, TEnergy, TTime>(
 	time: Res<Time<TTime>>,
-	mut agents: Query<(Entity, &mut TGetSkill, &mut TActiveSkill)>,
+	mut agents: Query<Agents<TGetSkill, TActiveSkill, TEnergy>>,
 	mut facing: StaticSystemParam<TFacing>,
 ) where
 	TGetSkill: GetActiveSkill<SkillState> + Component<Mutability = Mutable>,
 	TFacing: for<'c> TryGetContextMut<Facing, TContext<'c>: OverrideFace>,
 	TActiveSkill: Component<Mutability = Mutable> + Schedule<SkillBehaviorConfig> + Flush,
+	TEnergy: Component<Mutability = Mutable> + View<Energy> + SpendEnergy,
 	TTime: Default + ThreadSafe,
 {
 	let delta = time.delta();
 
-	for (entity, mut dequeue, skill_executer) in &mut agents {
+	for (entity, mut dequeue, skill_executer, mut cooldowns, mut energy) in &mut agents {
 		let Some(mut ctx) = TFacing::try_get_context_mut(&mut facing, Facing { entity }) else {
 			continue;
 		};
+		let payment = Payment {
+			cooldowns: cooldowns.as_deref_mut(),
+			energy: energy.as_deref_mut(),
+		};
 		let advancement = match dequeue.get_active() {
-			Some(skill) => advance(skill, skill_executer, delta, &mut ctx),
+			Some(skill) => advance(skill, skill_executer, delta, &mut ctx, payment),
 			None => clear_side_effects(&mut ctx),
 		};
 
@@ -53,19 +67,30 @@ where
 	Advancement::InProcess
 }
 
-fn advance<TFacing, TSkillExecutor>(
-	mut skill: impl GetSkillBehavior + UpdatedStates<SkillState>,
+struct Payment<'a, TEnergy> {
+	cooldowns: Option<&'a mut Cooldowns>,
+	energy: Option<&'a mut TEnergy>,
+}
+
+fn advance<TFacing, TSkillExecutor, TEnergy>(
+	mut skill: impl GetSkillBehavior + GetSkillCost + UpdatedStates<SkillState>,
 	mut skill_executer: Mut<TSkillExecutor>,
 	delta: Duration,
 	facing: &mut TFacing,
+	payment: Payment<TEnergy>,
 ) -> Advancement
 where
 	TFacing: OverrideFace,
 	TSkillExecutor: Schedule<SkillBehaviorConfig> + Flush,
+	TEnergy: View<Energy> + SpendEnergy,
 {
 	let skill = &mut skill;
 	let states = skill.updated_states(delta);
 
+	if states.contains(&StateMeta::Entering(SkillState::Aim)) && !pay(skill, payment) {
+		return Advancement::Finished;
+	}
+
 	if states.contains(&StateMeta::Entering(SkillState::Aim))
 		|| states.contains(&StateMeta::In(SkillState::Aim))
 	{
@@ -88,6 +113,23 @@ where
 	Advancement::InProcess
 }
 
+/// Skills might have become unaffordable while waiting in the queue, in which case they are
+/// dropped instead of being paid for.
+fn pay<TEnergy>(skill: &impl GetSkillCost, Payment { cooldowns, energy }: Payment<TEnergy>) -> bool
+where
+	TEnergy: View<Energy> + SpendEnergy,
+{
+	let cost = skill.cost();
+	let current_energy = energy.as_deref().map(View::<Energy>::view);
+
+	if !cost.is_affordable(cooldowns.as_deref(), current_energy) {
+		return false;
+	}
+
+	cost.pay(cooldowns, energy);
+	true
+}
+
 fn try_run_on_aim<TSkill>(skill: &TSkill) -> Option<(SlotKey, SkillBehaviorConfig)>
 where
 	TSkill: GetSkillBehavior,
@@ -128,9 +170,13 @@ fn schedule_start<TSkillExecutor, TSkill>(
 mod tests {
 	#![allow(clippy::unwrap_used)]
 	use super::*;
+	use crate::{
+		components::cooldowns::Cooldown,
+		skills::{SkillCost, SkillId},
+	};
 	use macros::{NestedMocks, simple_mock};
 	use mockall::{automock, mock, predicate::eq};
-	use std::collections::HashSet;
+	use std::collections::{HashMap, HashSet};
 	use test_case::test_case;
 	use testing::{IsChanged, MissingLastUpdate, Mock, NestedMocks, SingleThreadedApp, TickTime};
 
@@ -162,6 +208,30 @@ mod tests {
 		impl GetSkillBehavior for _Skill {
 			fn behavior<'a>(&self) -> (SlotKey, RunSkillBehavior);
 		}
+		impl GetSkillCost for _Skill {
+			fn cost(&self) -> SkillCost;
+		}
+	}
+
+	const COST: SkillCost = SkillCost {
+		id: SkillId::DEFAULT_ID,
+		cooldown: Duration::from_secs(2),
+		energy: 5.,
+	};
+
+	#[derive(Component, Debug, PartialEq)]
+	struct _Energy(Energy);
+
+	impl View<Energy> for _Energy {
+		fn view(&self) -> Energy {
+			self.0
+		}
+	}
+
+	impl SpendEnergy for _Energy {
+		fn spend_energy(&mut self, amount: f32) {
+			self.0.current -= amount;
+		}
 	}
 
 	#[derive(Component, NestedMocks)]
@@ -239,7 +309,7 @@ mod tests {
 		app.update();
 		app.add_systems(
 			Update,
-			schedule_active_skill::<_Dequeue, Query<Mut<_Facing>>, _Executor, Real>,
+			schedule_active_skill::<_Dequeue, Query<Mut<_Facing>>, _Executor, _Energy, Real>,
 		);
 
 		Ok((app, agent))
@@ -393,6 +463,8 @@ mod tests {
 								skill_behavior(RunSkillBehavior::OnAim),
 							)
 						});
+						mock.expect_cost()
+							.return_const(SkillCost { energy: 0., ..COST });
 						mock.expect_updated_states().return_const(
 							HashSet::<StateMeta<SkillState>>::from([StateMeta::Entering(
 								SkillState::Aim,
@@ -502,6 +574,8 @@ mod tests {
 					Mock_Skill::new_mock(|mock| {
 						mock.expect_behavior()
 							.return_const((SlotKey(0), RunSkillBehavior::default()));
+						mock.expect_cost()
+							.return_const(SkillCost { energy: 0., ..COST });
 						mock.expect_updated_states().return_const(
 							HashSet::<StateMeta<SkillState>>::from([meta(SkillState::Aim)]),
 						);
@@ -522,6 +596,80 @@ mod tests {
 		Ok(())
 	}
 
+	fn entering_aim() -> Mock_Skill {
+		Mock_Skill::new_mock(|mock| {
+			mock.expect_behavior()
+				.return_const((SlotKey(0), RunSkillBehavior::default()));
+			mock.expect_cost().return_const(COST);
+			mock.expect_updated_states()
+				.return_const(HashSet::<StateMeta<SkillState>>::from([
+					StateMeta::Entering(SkillState::Aim),
+					StateMeta::In(SkillState::Aim),
+				]));
+		})
+	}
+
+	#[test]
+	fn pay_cost_when_starting() -> Result<(), MissingLastUpdate> {
+		let (mut app, agent) = setup()?;
+		app.world_mut().entity_mut(agent).insert((
+			_Dequeue {
+				active: Some(Box::new(entering_aim)),
+			},
+			_Energy(Energy::new(10., 0.)),
+			Cooldowns::default(),
+			_Facing::default(),
+		));
+
+		app.update();
+
+		let agent = app.world().entity(agent);
+		let mut cooldowns = Cooldowns::default();
+		cooldowns.start(COST.id, COST.cooldown);
+		assert_eq!(
+			(
+				Some(&_Energy(Energy {
+					current: 5.,
+					..Energy::new(10., 0.)
+				})),
+				Some(&cooldowns),
+			),
+			(agent.get::<_Energy>(), agent.get::<Cooldowns>()),
+		);
+		Ok(())
+	}
+
+	#[test_case(_Energy(Energy::new(4., 0.)), Cooldowns::default(); "not enough energy")]
+	#[test_case(
+		_Energy(Energy::new(10., 0.)),
+		Cooldowns(HashMap::from([(COST.id, Cooldown { remaining: Duration::from_secs(1), total: COST.cooldown })]));
+		"on cooldown"
+	)]
+	fn drop_unaffordable_skill(
+		energy: _Energy,
+		cooldowns: Cooldowns,
+	) -> Result<(), MissingLastUpdate> {
+		let (mut app, agent) = setup()?;
+		app.world_mut().entity_mut(agent).insert((
+			_Executor::new().with_mock(|mock| {
+				mock.expect_schedule().never();
+				mock.expect_flush().return_const(());
+			}),
+			_Dequeue {
+				active: Some(Box::new(entering_aim)),
+			},
+			energy,
+			cooldowns,
+			_Facing::default(),
+		));
+
+		app.update();
+
+		let agent = app.world().entity(agent);
+		assert!(agent.get::<_Dequeue>().unwrap().active.is_none());
+		Ok(())
+	}
+
 	#[test]
 	fn stop_facing_override_when_no_skills_active() -> Result<(), MissingLastUpdate> {
 		let (mut app, agent) = setup()?;
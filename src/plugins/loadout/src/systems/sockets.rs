@@ -0,0 +1 @@
+pub(crate) mod apply_essences;
@@ -0,0 +1,236 @@
+use crate::{
+	components::{slots::Slots, sockets::SocketsInternal},
+	item::Item,
+	skills::QueuedSkill,
+	traits::IterAddedMut,
+};
+use bevy::{ecs::component::Mutable, prelude::*};
+
+impl SocketsInternal {
+	pub(crate) fn apply_essences<TQueue>(
+		mut agents: Query<(&Self, &Slots, &mut TQueue)>,
+		items: Res<Assets<Item>>,
+	) where
+		TQueue: IterAddedMut<TItem = QueuedSkill> + Component<Mutability = Mutable>,
+	{
+		for (sockets, slots, mut queue) in &mut agents {
+			if sockets.0.is_empty() || queue.added_none() {
+				continue;
+			}
+
+			for QueuedSkill { skill, key, .. } in queue.iter_added_mut() {
+				let Some(Some(item)) = slots.items.get(key) else {
+					continue;
+				};
+				let Some(item) = items.get(item) else {
+					continue;
+				};
+
+				skill.apply_modifiers(sockets.modifiers_for(*key, item, &items));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::skills::{
+		RunSkillBehavior,
+		Skill,
+		behaviors::SkillBehaviorConfig,
+		modifier::SkillModifier,
+	};
+	use common::prelude::*;
+	use std::collections::HashMap;
+	use testing::{IsChanged, SingleThreadedApp, new_handle};
+
+	#[derive(Component, Default, PartialEq, Debug)]
+	struct _Queue {
+		added: Vec<QueuedSkill>,
+	}
+
+	impl IterAddedMut for _Queue {
+		type TItem = QueuedSkill;
+
+		fn added_none(&self) -> bool {
+			self.added.is_empty()
+		}
+
+		fn iter_added_mut<'a>(&'a mut self) -> impl DoubleEndedIterator<Item = &'a mut QueuedSkill>
+		where
+			QueuedSkill: 'a,
+		{
+			self.added.iter_mut()
+		}
+	}
+
+	fn beam(range: f32) -> Skill {
+		Skill {
+			behavior: RunSkillBehavior::OnActive(SkillBehaviorConfig::from_shape(
+				SkillShape::Beam(Beam {
+					range: Units::from(range),
+					blocked_by: Blockers::All,
+				}),
+			)),
+			..default()
+		}
+	}
+
+	fn setup<const N: usize>(items: [(&Handle<Item>, Item); N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut item_assets = Assets::default();
+
+		for (id, asset) in items {
+			_ = item_assets.insert(id, asset);
+		}
+
+		app.insert_resource(item_assets);
+		app.add_systems(Update, SocketsInternal::apply_essences::<_Queue>);
+
+		app
+	}
+
+	fn essence(item_type: ItemType, modifiers: Vec<SkillModifier>) -> Item {
+		Item {
+			socket_modifiers: HashMap::from([(item_type, modifiers)]),
+			..default()
+		}
+	}
+
+	#[test]
+	fn apply_essence_modifiers_to_added_skills() {
+		let item = new_handle();
+		let essence_handle = new_handle();
+		let mut app = setup([
+			(
+				&item,
+				Item {
+					item_type: ItemType::VoidBeam,
+					..default()
+				},
+			),
+			(
+				&essence_handle,
+				essence(ItemType::VoidBeam, vec![SkillModifier::Range(2.)]),
+			),
+		]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				SocketsInternal::from([(LoadoutKey::from(SlotKey(1)), essence_handle)]),
+				Slots::from([(SlotKey(1), Some(item))]),
+				_Queue {
+					added: vec![QueuedSkill::new(beam(5.), SlotKey(1))],
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Queue {
+				added: vec![QueuedSkill::new(beam(10.), SlotKey(1))],
+			}),
+			app.world().entity(entity).get::<_Queue>(),
+		);
+	}
+
+	#[test]
+	fn do_not_apply_essence_modifiers_of_other_slots() {
+		let item = new_handle();
+		let essence_handle = new_handle();
+		let mut app = setup([
+			(
+				&item,
+				Item {
+					item_type: ItemType::VoidBeam,
+					..default()
+				},
+			),
+			(
+				&essence_handle,
+				essence(ItemType::VoidBeam, vec![SkillModifier::Range(2.)]),
+			),
+		]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				SocketsInternal::from([(LoadoutKey::from(SlotKey(2)), essence_handle)]),
+				Slots::from([(SlotKey(1), Some(item))]),
+				_Queue {
+					added: vec![QueuedSkill::new(beam(5.), SlotKey(1))],
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Queue {
+				added: vec![QueuedSkill::new(beam(5.), SlotKey(1))],
+			}),
+			app.world().entity(entity).get::<_Queue>(),
+		);
+	}
+
+	#[test]
+	fn do_not_apply_essence_modifiers_for_other_item_types() {
+		let item = new_handle();
+		let essence_handle = new_handle();
+		let mut app = setup([
+			(
+				&item,
+				Item {
+					item_type: ItemType::Pistol,
+					..default()
+				},
+			),
+			(
+				&essence_handle,
+				essence(ItemType::VoidBeam, vec![SkillModifier::Range(2.)]),
+			),
+		]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				SocketsInternal::from([(LoadoutKey::from(SlotKey(1)), essence_handle)]),
+				Slots::from([(SlotKey(1), Some(item))]),
+				_Queue {
+					added: vec![QueuedSkill::new(beam(5.), SlotKey(1))],
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Queue {
+				added: vec![QueuedSkill::new(beam(5.), SlotKey(1))],
+			}),
+			app.world().entity(entity).get::<_Queue>(),
+		);
+	}
+
+	#[test]
+	fn queue_not_marked_changed_when_none_added() {
+		let mut app = setup([]);
+		let entity = app
+			.world_mut()
+			.spawn((
+				SocketsInternal::from([(LoadoutKey::from(SlotKey(1)), new_handle())]),
+				Slots::default(),
+				_Queue::default(),
+			))
+			.id();
+
+		app.add_systems(PostUpdate, IsChanged::<_Queue>::detect);
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::<_Queue>::FALSE),
+			app.world().entity(entity).get::<IsChanged<_Queue>>(),
+		);
+	}
+}
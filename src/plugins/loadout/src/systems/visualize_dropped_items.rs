@@ -0,0 +1,195 @@
+use crate::{
+	components::{dropped_item_model::DroppedItemModel, model_render::ModelRender},
+	item::Item,
+	system_parameters::loadout::write::insert_default_loadout::DefaultLoadout,
+};
+use bevy::{ecs::component::Mutable, prelude::*};
+use common::{prelude::*, traits::load_asset::LoadAsset};
+use macros::asset_path;
+
+impl DroppedItemModel {
+	pub(crate) fn visualize<TAssetServer>(
+		mut commands: ZyheedaCommands,
+		mut server: ResMut<TAssetServer>,
+		items: Res<Assets<Item>>,
+		dropped_items: Query<(Entity, &DroppedItem, Option<&Self>)>,
+	) where
+		TAssetServer: Resource<Mutability = Mutable> + LoadAsset,
+	{
+		for (entity, DroppedItem(name), model) in &dropped_items {
+			match model {
+				None => {
+					let handle = server.load_asset(DefaultLoadout::asset_path(name));
+					commands.try_apply_on(&entity, |mut e| {
+						e.try_insert(Self::Loading(handle));
+					});
+				}
+				Some(Self::Loading(handle)) => {
+					let Some(item) = items.get(handle) else {
+						continue;
+					};
+					let visual = visual(item);
+					commands.try_apply_on(&entity, |mut e| {
+						e.try_insert(Self::Loaded).with_child(visual);
+					});
+				}
+				Some(Self::Loaded) => {}
+			}
+		}
+	}
+}
+
+/// Items without a model of their own are shown as a small sphere.
+const PLACEHOLDER_SCALE: f32 = 0.3;
+
+fn visual(item: &Item) -> (Model, Transform) {
+	match &item.model {
+		ModelRender::Hand(path) | ModelRender::Forearm(path) => {
+			(Model::scene(path), Transform::default())
+		}
+		ModelRender::None => (
+			Model::scene(asset_path!("generic/models/sphere.glb")),
+			Transform::from_scale(Vec3::splat(PLACEHOLDER_SCALE)),
+		),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use common::traits::load_asset::mock_asset_loader::MockAssetServer;
+	use testing::{SingleThreadedApp, new_handle};
+
+	fn setup<const N: usize>(server: MockAssetServer, items: [(&Handle<Item>, Item); N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let mut assets = Assets::default();
+
+		for (id, asset) in items {
+			_ = assets.insert(id, asset);
+		}
+
+		app.insert_resource(server);
+		app.insert_resource(assets);
+		app.add_systems(Update, DroppedItemModel::visualize::<MockAssetServer>);
+
+		app
+	}
+
+	fn children_models(app: &App, entity: Entity) -> Vec<(&Model, &Transform)> {
+		let Some(children) = app.world().entity(entity).get::<Children>() else {
+			return vec![];
+		};
+
+		children
+			.iter()
+			.filter_map(|child| {
+				let child = app.world().entity(child);
+				Some((child.get::<Model>()?, child.get::<Transform>()?))
+			})
+			.collect()
+	}
+
+	#[test]
+	fn load_item() {
+		let handle = new_handle::<Item>();
+		let mut app = setup(
+			MockAssetServer::default()
+				.path("items/my_item/meta.item")
+				.returns(handle.clone()),
+			[],
+		);
+		let entity = app
+			.world_mut()
+			.spawn(DroppedItem(ItemName::from("my_item")))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&DroppedItemModel::Loading(handle)),
+			app.world().entity(entity).get::<DroppedItemModel>(),
+		);
+	}
+
+	#[test]
+	fn spawn_item_model_when_loaded() {
+		let handle = new_handle::<Item>();
+		let mut app = setup(
+			MockAssetServer::default()
+				.path("items/my_item/meta.item")
+				.returns(handle.clone()),
+			[(
+				&handle,
+				Item {
+					model: ModelRender::Hand(String::from("my/model.glb")),
+					..default()
+				},
+			)],
+		);
+		let entity = app
+			.world_mut()
+			.spawn(DroppedItem(ItemName::from("my_item")))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			(
+				Some(&DroppedItemModel::Loaded),
+				vec![(&Model::scene("my/model.glb"), &Transform::default())],
+			),
+			(
+				app.world().entity(entity).get::<DroppedItemModel>(),
+				children_models(&app, entity),
+			),
+		);
+	}
+
+	#[test]
+	fn spawn_placeholder_when_item_has_no_model() {
+		let handle = new_handle::<Item>();
+		let mut app = setup(
+			MockAssetServer::default()
+				.path("items/my_item/meta.item")
+				.returns(handle.clone()),
+			[(&handle, Item::default())],
+		);
+		let entity = app
+			.world_mut()
+			.spawn(DroppedItem(ItemName::from("my_item")))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			vec![(
+				&Model::scene(asset_path!("generic/models/sphere.glb")),
+				&Transform::from_scale(Vec3::splat(PLACEHOLDER_SCALE)),
+			)],
+			children_models(&app, entity),
+		);
+	}
+
+	#[test]
+	fn do_not_spawn_model_twice() {
+		let handle = new_handle::<Item>();
+		let mut app = setup(
+			MockAssetServer::default()
+				.path("items/my_item/meta.item")
+				.returns(handle.clone()),
+			[(&handle, Item::default())],
+		);
+		let entity = app
+			.world_mut()
+			.spawn(DroppedItem(ItemName::from("my_item")))
+			.id();
+
+		app.update();
+		app.update();
+		app.update();
+
+		assert_eq!(1, children_models(&app, entity).len());
+	}
+}
@@ -9,7 +9,7 @@ pub(crate) mod skill_state;
 pub(crate) mod spawn_skill;
 pub(crate) mod visualize_item;
 
-use crate::skills::{RunSkillBehavior, Skill};
+use crate::skills::{RunSkillBehavior, Skill, SkillCost};
 use common::prelude::*;
 
 pub(crate) trait Enqueue<TItem> {
@@ -47,7 +47,7 @@ pub(crate) trait ReleaseSkill {
 }
 
 pub(crate) trait GetActiveSkill<TSkillState> {
-	type TActive<'a>: GetSkillBehavior + UpdatedStates<TSkillState>
+	type TActive<'a>: GetSkillBehavior + GetSkillCost + UpdatedStates<TSkillState>
 	where
 		Self: 'a;
 
@@ -67,6 +67,10 @@ pub(crate) trait GetSkillBehavior {
 	fn behavior(&self) -> (SlotKey, RunSkillBehavior);
 }
 
+pub(crate) trait GetSkillCost {
+	fn cost(&self) -> SkillCost;
+}
+
 pub trait Schedule<TBehavior> {
 	fn schedule(&mut self, slot_key: SlotKey, behavior: TBehavior);
 }
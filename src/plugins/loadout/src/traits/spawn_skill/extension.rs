@@ -17,6 +17,8 @@ where
 			mount: config.mount(slot),
 			contact_effects: config.contact_effects(),
 			projection_effects: config.projection_effects(),
+			impact_sound: config.impact_sound(),
+			follow_ups: config.follow_ups(),
 			caster,
 		});
 
@@ -29,6 +31,9 @@ pub(crate) trait SkillConfigData {
 	fn shape(&self) -> &'_ SkillShape;
 	fn contact_effects(&self) -> &'_ [SkillEffect];
 	fn projection_effects(&self) -> &'_ [SkillEffect];
+	fn cast_sound(&self) -> Option<&'_ SoundCue>;
+	fn impact_sound(&self) -> Option<&'_ SoundCue>;
+	fn follow_ups(&self) -> &'_ [FollowUpSkill];
 	fn on_skill_stop(&self, skill: PersistentEntity) -> OnSkillStop;
 }
 
@@ -45,6 +50,8 @@ mod tests {
 		shape: SkillShape,
 		contact: Vec<SkillEffect>,
 		projection: Vec<SkillEffect>,
+		impact_sound: Option<SoundCue>,
+		follow_ups: Vec<FollowUpSkill>,
 		mount: fn(SlotKey) -> SkillMount,
 		on_skill_stop: fn(PersistentEntity) -> OnSkillStop,
 	}
@@ -58,6 +65,8 @@ mod tests {
 			}),
 			contact: vec![],
 			projection: vec![],
+			impact_sound: None,
+			follow_ups: vec![],
 			mount: |_| SkillMount::Center,
 			on_skill_stop: |_| OnSkillStop::Ignore,
 		};
@@ -86,6 +95,18 @@ mod tests {
 			&self.projection
 		}
 
+		fn cast_sound(&self) -> Option<&'_ SoundCue> {
+			None
+		}
+
+		fn impact_sound(&self) -> Option<&'_ SoundCue> {
+			self.impact_sound.as_ref()
+		}
+
+		fn follow_ups(&self) -> &'_ [FollowUpSkill] {
+			&self.follow_ups
+		}
+
 		fn on_skill_stop(&self, skill: PersistentEntity) -> OnSkillStop {
 			(self.on_skill_stop)(skill)
 		}
@@ -122,6 +143,8 @@ mod tests {
 							mount: mount(SLOT),
 							contact_effects: &[],
 							projection_effects: &[],
+							impact_sound: None,
+							follow_ups: &[],
 						}
 					})
 					.return_const(PersistentEntity::default());
@@ -149,7 +172,7 @@ mod tests {
 	#[test]
 	fn add_contact_effect() {
 		let config = _Config {
-			contact: vec![SkillEffect::Force(Force)],
+			contact: vec![SkillEffect::Force(Force::default())],
 			..default()
 		};
 		let mut spawn = Mock_Spawn::new_mock(assert_added_effects);
@@ -159,7 +182,7 @@ mod tests {
 		fn assert_added_effects(mock: &mut Mock_Spawn) {
 			mock.expect_spawn_skill()
 				.once()
-				.withf(|args| args.contact_effects == [SkillEffect::Force(Force)])
+				.withf(|args| args.contact_effects == [SkillEffect::Force(Force::default())])
 				.return_const(PersistentEntity::default());
 		}
 	}
@@ -167,7 +190,7 @@ mod tests {
 	#[test]
 	fn add_projection_effect() {
 		let config = _Config {
-			projection: vec![SkillEffect::Force(Force)],
+			projection: vec![SkillEffect::Force(Force::default())],
 			..default()
 		};
 		let mut spawn = Mock_Spawn::new_mock(assert_added_effects);
@@ -177,8 +200,53 @@ mod tests {
 		fn assert_added_effects(mock: &mut Mock_Spawn) {
 			mock.expect_spawn_skill()
 				.once()
-				.withf(|args| args.projection_effects == [SkillEffect::Force(Force)])
+				.withf(|args| args.projection_effects == [SkillEffect::Force(Force::default())])
 				.return_const(PersistentEntity::default());
 		}
 	}
+
+	#[test]
+	fn add_impact_sound() {
+		let config = _Config {
+			impact_sound: Some(SoundCue::from("sounds/impact.ogg")),
+			..default()
+		};
+		let mut spawn = Mock_Spawn::new_mock(assert_impact_sound);
+
+		spawn.spawn_skill_internal(config, *CASTER, SLOT);
+
+		fn assert_impact_sound(mock: &mut Mock_Spawn) {
+			mock.expect_spawn_skill()
+				.once()
+				.withf(|args| args.impact_sound == Some(&SoundCue::from("sounds/impact.ogg")))
+				.return_const(PersistentEntity::default());
+		}
+	}
+
+	#[test]
+	fn add_follow_ups() {
+		let config = _Config {
+			follow_ups: vec![FOLLOW_UP.clone()],
+			..default()
+		};
+		let mut spawn = Mock_Spawn::new_mock(assert_follow_ups);
+
+		spawn.spawn_skill_internal(config, *CASTER, SLOT);
+
+		fn assert_follow_ups(mock: &mut Mock_Spawn) {
+			mock.expect_spawn_skill()
+				.once()
+				.withf(|args| args.follow_ups == [FOLLOW_UP.clone()])
+				.return_const(PersistentEntity::default());
+		}
+	}
+
+	static FOLLOW_UP: LazyLock<FollowUpSkill> = LazyLock::new(|| FollowUpSkill {
+		trigger: FollowUpTrigger::OnContact,
+		shape: SkillShape::Shield(Shield),
+		contact_effects: vec![],
+		projection_effects: vec![],
+		impact_sound: None,
+		follow_ups: vec![],
+	});
 }
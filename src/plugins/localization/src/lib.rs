@@ -6,11 +6,14 @@ mod tools;
 mod traits;
 
 use crate::{
-	resources::ftl_server::{FtlServerParam, FtlServerParamMut},
+	resources::{
+		available_locales::AvailableLocales,
+		ftl_server::{FtlServerParam, FtlServerParamMut},
+	},
 	systems::remove_empty_folder_handle::RemoveEmptyFolderHandle,
 };
 use assets::ftl::{Ftl, loader::FtlLoader};
-use bevy::prelude::*;
+use bevy::{asset::io::file::FileAssetReader, prelude::*};
 use common::{prelude::*, states::game_state::LoadingEssentialAssets, tools::path::Path};
 use resources::ftl_server::FtlServer;
 use std::marker::PhantomData;
@@ -24,7 +27,7 @@ use systems::{
 use unic_langid::langid;
 
 type StaticFtlServerParam = FtlServerParam<'static, 'static>;
-type StaticFtlServerParamMut = FtlServerParamMut<'static>;
+type StaticFtlServerParamMut = FtlServerParamMut<'static, 'static>;
 
 pub struct LocalizationPlugin<TLoading>(PhantomData<TLoading>);
 
@@ -45,9 +48,21 @@ where
 		TLoading::register_load_tracking::<FtlServer, LoadingEssentialAssets, AssetsProgress>()
 			.in_app(app, FtlServer::all_fallback_files_loaded);
 
+		let locale_directory = FileAssetReader::get_base_path()
+			.join(AssetPlugin::default().file_path)
+			.join("locale");
+
 		app.init_asset::<Ftl>()
+			.init_resource::<AvailableLocales>()
+			.add_message::<LocalizationChanged>()
 			.register_asset_loader(FtlLoader)
-			.add_systems(Startup, FtlServer::init_with(langid!("en-US")))
+			.add_systems(
+				Startup,
+				(
+					FtlServer::init_with(langid!("en-US")),
+					AvailableLocales::discover(locale_directory).pipe(OnError::log),
+				),
+			)
 			.add_systems(
 				Update,
 				(
@@ -1 +1,2 @@
+pub mod available_locales;
 pub mod ftl_server;
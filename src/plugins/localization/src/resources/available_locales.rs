@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+use common::{prelude::*, tools::file_io::FileError};
+use std::{fs, path::PathBuf};
+use unic_langid::LanguageIdentifier;
+
+/// Locales found in the locale asset directory, either as `<locale>.ftl` files
+/// or as `<locale>/` folders.
+#[derive(Resource, Debug, PartialEq, Default)]
+pub struct AvailableLocales(pub(crate) Vec<LanguageIdentifier>);
+
+impl AvailableLocales {
+	pub(crate) fn discover(directory: PathBuf) -> impl Fn(ResMut<Self>) -> Result<(), FileError> {
+		move |mut locales| {
+			let entries = fs::read_dir(&directory).map_err(FileError::IO)?;
+			let paths = entries.filter_map(|entry| Some(entry.ok()?.path()));
+
+			*locales = Self::from_paths(paths);
+			Ok(())
+		}
+	}
+
+	fn from_paths(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+		let mut locales = paths
+			.into_iter()
+			.filter_map(|path| {
+				let name = match path.extension() {
+					Some(extension) if extension == "ftl" => path.file_stem()?,
+					Some(_) => return None,
+					None => path.file_name()?,
+				};
+				name.to_str()?.parse::<LanguageIdentifier>().ok()
+			})
+			.collect::<Vec<_>>();
+
+		locales.sort_by_key(|locale| locale.to_string());
+		locales.dedup();
+
+		Self(locales)
+	}
+
+	/// Resolves the requested locale to a shipped one:
+	/// 1. the exact locale (e.g. `de-AT`)
+	/// 2. the bare language of the requested locale (e.g. `de`)
+	///
+	/// Returns `None` when neither is available, in which case the fallback locale
+	/// needs to be used.
+	pub(crate) fn resolve(&self, requested: &LanguageIdentifier) -> Option<LanguageIdentifier> {
+		if self.0.contains(requested) {
+			return Some(requested.clone());
+		}
+
+		let language = LanguageIdentifier::from_parts(requested.language, None, None, &[]);
+		if self.0.contains(&language) {
+			return Some(language);
+		}
+
+		None
+	}
+}
+
+impl ListLocales for AvailableLocales {
+	fn locales(&self) -> &[LanguageIdentifier] {
+		&self.0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+	use unic_langid::langid;
+
+	#[test]
+	fn parse_files_and_folders() {
+		let locales = AvailableLocales::from_paths([
+			PathBuf::from("locale/en-us.ftl"),
+			PathBuf::from("locale/de"),
+		]);
+
+		assert_eq!(
+			AvailableLocales(vec![langid!("de"), langid!("en-US")]),
+			locales
+		);
+	}
+
+	#[test]
+	fn ignore_other_files_and_invalid_names() {
+		let locales = AvailableLocales::from_paths([
+			PathBuf::from("locale/en-us.ftl"),
+			PathBuf::from("locale/en-us.txt"),
+			PathBuf::from("locale/not a locale.ftl"),
+		]);
+
+		assert_eq!(AvailableLocales(vec![langid!("en-US")]), locales);
+	}
+
+	#[test]
+	fn remove_duplicates() {
+		let locales = AvailableLocales::from_paths([
+			PathBuf::from("locale/en-us.ftl"),
+			PathBuf::from("locale/en-us"),
+		]);
+
+		assert_eq!(AvailableLocales(vec![langid!("en-US")]), locales);
+	}
+
+	#[test_case(langid!("de-AT"), Some(langid!("de-AT")); "exact")]
+	#[test_case(langid!("de-CH"), Some(langid!("de")); "language only")]
+	#[test_case(langid!("fr-FR"), None; "not available")]
+	fn resolve(requested: LanguageIdentifier, expected: Option<LanguageIdentifier>) {
+		let locales = AvailableLocales(vec![langid!("de"), langid!("de-AT"), langid!("en-US")]);
+
+		assert_eq!(expected, locales.resolve(&requested));
+	}
+}
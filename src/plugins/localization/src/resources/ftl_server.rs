@@ -1,5 +1,6 @@
 use crate::{
 	assets::ftl::Ftl,
+	resources::available_locales::AvailableLocales,
 	tools::list_string,
 	traits::{
 		current_locale::CurrentLocaleMut,
@@ -15,7 +16,7 @@ use common::{
 	error_logger::{GlobalErrorLogger, Log},
 	prelude::*,
 };
-use fluent::{FluentError, FluentResource, concurrent::FluentBundle};
+use fluent::{FluentArgs, FluentError, FluentResource, FluentValue, concurrent::FluentBundle};
 use std::fmt::Display;
 use unic_langid::LanguageIdentifier;
 
@@ -63,9 +64,19 @@ where
 	TLogger: SystemParam + ThreadSafe,
 {
 	server: Res<'w, FtlServer>,
+	locales: Res<'w, AvailableLocales>,
 	logger: StaticSystemParam<'w, 's, TLogger>,
 }
 
+impl<'w, 's, TLogger> ListLocales for FtlServerParam<'w, 's, TLogger>
+where
+	TLogger: SystemParam + ThreadSafe,
+{
+	fn locales(&self) -> &[LanguageIdentifier] {
+		self.locales.locales()
+	}
+}
+
 impl<'w, 's, TLogger> Localize for FtlServerParam<'w, 's, TLogger>
 where
 	TLogger: for<'w2, 's2> SystemParam<Item<'w2, 's2>: Log> + ThreadSafe,
@@ -76,6 +87,7 @@ where
 			None => (&self.server.fallback, vec![&self.server.fallback]),
 		};
 		let str = &**token;
+		let args = self.fluent_args(token);
 		let localize = |locale: &&Locale| {
 			if locale.ln != current.ln {
 				self.logger.log(FtlError::FallbackAttempt {
@@ -102,7 +114,7 @@ where
 			};
 
 			let mut fluent_errors = vec![];
-			let localized = bundle.format_pattern(pattern, None, &mut fluent_errors);
+			let localized = bundle.format_pattern(pattern, args.as_ref(), &mut fluent_errors);
 
 			if !fluent_errors.is_empty() {
 				self.logger.log(FtlError::FluentErrors {
@@ -121,23 +133,76 @@ where
 	}
 }
 
+impl<'w, 's, TLogger> FtlServerParam<'w, 's, TLogger>
+where
+	TLogger: for<'w2, 's2> SystemParam<Item<'w2, 's2>: Log> + ThreadSafe,
+{
+	fn fluent_args<'a>(&self, token: &'a Token) -> Option<FluentArgs<'a>> {
+		if token.args().is_empty() {
+			return None;
+		}
+
+		let args = token.args().iter().map(|(name, arg)| {
+			let value = match arg {
+				TokenArg::Number(number) => FluentValue::from(*number),
+				TokenArg::String(string) => FluentValue::from(&**string),
+				TokenArg::Token(token) => {
+					FluentValue::from(self.localize(token).or_token().to_string())
+				}
+			};
+			(&**name, value)
+		});
+
+		Some(FluentArgs::from_iter(args))
+	}
+}
+
 #[derive(SystemParam)]
-pub struct FtlServerParamMut<'w> {
+pub struct FtlServerParamMut<'w, 's, TLogger = GlobalErrorLogger>
+where
+	TLogger: SystemParam + ThreadSafe,
+{
 	server: ResMut<'w, FtlServer>,
+	locales: Res<'w, AvailableLocales>,
+	changed: MessageWriter<'w, LocalizationChanged>,
+	logger: StaticSystemParam<'w, 's, TLogger>,
 }
 
-impl CurrentLocaleMut for FtlServerParamMut<'_> {
+impl<TLogger> CurrentLocaleMut for FtlServerParamMut<'_, '_, TLogger>
+where
+	TLogger: SystemParam + ThreadSafe,
+{
 	fn current_locale_mut(&mut self) -> &mut Locale {
 		self.server.get_current()
 	}
 }
 
-impl<'w> SetLocalization for FtlServerParamMut<'w> {
-	fn set_localization(&mut self, language: LanguageIdentifier) {
+impl<TLogger> SetLocalization for FtlServerParamMut<'_, '_, TLogger>
+where
+	TLogger: for<'w, 's> SystemParam<Item<'w, 's>: Log> + ThreadSafe,
+{
+	/// Switches to the requested locale or, if it is not shipped, to its bare language
+	/// (e.g. `de-AT` -> `de`). If neither is available, the fallback locale is used.
+	fn set_localization(&mut self, requested: LanguageIdentifier) {
+		let language = match self.locales.resolve(&requested) {
+			Some(language) => language,
+			None => self.server.fallback.ln.clone(),
+		};
+
+		if language != requested {
+			self.logger.log(FtlError::LocaleNotAvailable {
+				requested,
+				resolved: language.clone(),
+			});
+		}
+
 		if language == self.server.fallback.ln {
-			self.server.current = None;
+			if self.server.current.take().is_none() {
+				return;
+			}
 			self.server.update_file = false;
 			self.server.update_folder = false;
+			self.changed.write(LocalizationChanged);
 			return;
 		}
 
@@ -156,13 +221,19 @@ impl<'w> SetLocalization for FtlServerParamMut<'w> {
 	}
 }
 
-impl UpdateCurrentLocaleFromFile for FtlServerParamMut<'_> {
+impl<TLogger> UpdateCurrentLocaleFromFile for FtlServerParamMut<'_, '_, TLogger>
+where
+	TLogger: SystemParam + ThreadSafe,
+{
 	fn update_current_locale_from_file(&mut self) -> &mut bool {
 		&mut self.server.update_file
 	}
 }
 
-impl UpdateCurrentLocaleFromFolder for FtlServerParamMut<'_> {
+impl<TLogger> UpdateCurrentLocaleFromFolder for FtlServerParamMut<'_, '_, TLogger>
+where
+	TLogger: SystemParam + ThreadSafe,
+{
 	fn update_current_locale_from_folder(&mut self) -> &mut bool {
 		&mut self.server.update_folder
 	}
@@ -197,6 +268,10 @@ pub enum FtlError {
 		token: LnToken,
 		fallback: LanguageIdentifier,
 	},
+	LocaleNotAvailable {
+		requested: LanguageIdentifier,
+		resolved: LanguageIdentifier,
+	},
 }
 
 impl Display for FtlError {
@@ -211,6 +286,12 @@ impl Display for FtlError {
 			FtlError::FallbackAttempt { token, fallback } => {
 				write!(f, "fallback attempted for {token} -> {fallback}")
 			}
+			FtlError::LocaleNotAvailable {
+				requested,
+				resolved,
+			} => {
+				write!(f, "locale {requested} not available, using {resolved}")
+			}
 		}
 	}
 }
@@ -219,6 +300,7 @@ impl ErrorData for FtlError {
 	fn level(&self) -> Level {
 		match self {
 			FtlError::FallbackAttempt { .. } => const { Level::Warning },
+			FtlError::LocaleNotAvailable { .. } => const { Level::Warning },
 			_ => const { Level::Error },
 		}
 	}
@@ -251,6 +333,7 @@ mod tests {
 	use fluent::resolver::{ResolverError, errors::ReferenceKind};
 	use macros::NestedMocks;
 	use mockall::{automock, predicate::eq};
+	use test_case::test_case;
 	use testing::{NestedMocks, SingleThreadedApp, new_handle};
 	use unic_langid::langid;
 
@@ -284,10 +367,23 @@ mod tests {
 
 		app.insert_resource(server);
 		app.insert_resource(logger);
+		app.insert_resource(AvailableLocales(vec![
+			langid!("en"),
+			langid!("fr"),
+			langid!("jp"),
+		]));
+		app.add_message::<LocalizationChanged>();
 
 		app
 	}
 
+	fn localization_changed_count(app: &mut App) -> Result<usize, RunSystemError> {
+		app.world_mut()
+			.run_system_once(|mut changed: MessageReader<LocalizationChanged>| {
+				changed.read().count()
+			})
+	}
+
 	#[test]
 	fn current_locale_mut_only_fallback() -> Result<(), RunSystemError> {
 		let mut app = setup(
@@ -496,6 +592,170 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn set_localization_to_language_of_unavailable_region() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			FtlServer {
+				fallback: Locale {
+					ln: langid!("en"),
+					file: None,
+					folder: None,
+					bundle: None,
+				},
+				current: None,
+				update_file: false,
+				update_folder: false,
+			},
+			_Logger::new().with_mock(|mock| {
+				mock.expect_log()
+					.times(1)
+					.with(eq(FtlError::LocaleNotAvailable {
+						requested: langid!("fr-CA"),
+						resolved: langid!("fr"),
+					}))
+					.return_const(());
+			}),
+		);
+
+		let current =
+			app.world_mut()
+				.run_system_once(|mut f: FtlServerParamMut<_LoggerParam>| {
+					f.set_localization(langid!("fr-CA"));
+					f.current_locale_mut().ln.clone()
+				})?;
+
+		assert_eq!(langid!("fr"), current);
+		Ok(())
+	}
+
+	#[test]
+	fn set_localization_to_fallback_when_unavailable() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			FtlServer {
+				fallback: Locale {
+					ln: langid!("en"),
+					file: None,
+					folder: None,
+					bundle: None,
+				},
+				current: Some(Locale {
+					ln: langid!("jp"),
+					file: None,
+					folder: None,
+					bundle: None,
+				}),
+				update_file: false,
+				update_folder: false,
+			},
+			_Logger::new().with_mock(|mock| {
+				mock.expect_log()
+					.times(1)
+					.with(eq(FtlError::LocaleNotAvailable {
+						requested: langid!("de-AT"),
+						resolved: langid!("en"),
+					}))
+					.return_const(());
+			}),
+		);
+
+		let current =
+			app.world_mut()
+				.run_system_once(|mut f: FtlServerParamMut<_LoggerParam>| {
+					f.set_localization(langid!("de-AT"));
+					f.current_locale_mut().ln.clone()
+				})?;
+
+		assert_eq!(langid!("en"), current);
+		Ok(())
+	}
+
+	#[test]
+	fn notify_change_when_switching_to_fallback() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			FtlServer {
+				fallback: Locale {
+					ln: langid!("en"),
+					file: None,
+					folder: None,
+					bundle: None,
+				},
+				current: Some(Locale {
+					ln: langid!("jp"),
+					file: None,
+					folder: None,
+					bundle: None,
+				}),
+				update_file: false,
+				update_folder: false,
+			},
+			default(),
+		);
+
+		app.world_mut()
+			.run_system_once(|mut f: FtlServerParamMut| {
+				f.set_localization(langid!("en"));
+			})?;
+
+		assert_eq!(1, localization_changed_count(&mut app)?);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_notify_change_when_already_using_fallback() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			FtlServer {
+				fallback: Locale {
+					ln: langid!("en"),
+					file: None,
+					folder: None,
+					bundle: None,
+				},
+				current: None,
+				update_file: false,
+				update_folder: false,
+			},
+			default(),
+		);
+
+		app.world_mut()
+			.run_system_once(|mut f: FtlServerParamMut| {
+				f.set_localization(langid!("en"));
+			})?;
+
+		assert_eq!(0, localization_changed_count(&mut app)?);
+		Ok(())
+	}
+
+	#[test]
+	fn keep_loading_fallback_when_already_using_fallback() -> Result<(), RunSystemError> {
+		let mut app = setup(FtlServer::from(langid!("en")), default());
+
+		let update = app
+			.world_mut()
+			.run_system_once(|mut f: FtlServerParamMut| {
+				f.set_localization(langid!("en"));
+				(
+					*f.update_current_locale_from_file(),
+					*f.update_current_locale_from_folder(),
+				)
+			})?;
+
+		assert_eq!((true, true), update);
+		Ok(())
+	}
+
+	#[test]
+	fn list_locales() -> Result<(), RunSystemError> {
+		let mut app = setup(FtlServer::from(langid!("en")), default());
+
+		let locales = app
+			.world_mut()
+			.run_system_once(|f: FtlServerParam<_LoggerParam>| f.locales().to_vec())?;
+
+		assert_eq!(vec![langid!("en"), langid!("fr"), langid!("jp")], locales);
+		Ok(())
+	}
+
 	#[test]
 	fn fallback_loaded_if_bundle_present() -> Result<(), RunSystemError> {
 		let mut app = setup(
@@ -847,6 +1107,52 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn localize_with_args() -> Result<(), RunSystemError> {
+		let mut bundle = FluentBundle::new_concurrent(vec![langid!("en")]);
+		bundle.set_use_isolating(false);
+		let res = match FluentResource::try_new(String::from(
+			"a = Deals { $damage } { $type } damage over { $seconds }s\nb = void",
+		)) {
+			Ok(res) => res,
+			Err((res, ..)) => res,
+		};
+		_ = bundle.add_resource(res);
+		let mut app = setup(
+			FtlServer {
+				fallback: Locale {
+					ln: langid!("en"),
+					file: None,
+					folder: None,
+					bundle: Some(bundle),
+				},
+				current: None,
+				update_file: false,
+				update_folder: false,
+			},
+			_Logger::new().with_mock(|mock| {
+				mock.expect_log::<FtlError>().never();
+			}),
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(|f: FtlServerParam<_LoggerParam>| {
+				f.localize(
+					&Token::from("a")
+						.with_arg("damage", 42.)
+						.with_arg("type", Token::from("b"))
+						.with_arg("seconds", "3"),
+				)
+			})?;
+
+		assert_eq!(
+			LocalizationResult::Ok(Localized::from("Deals 42 void damage over 3s")),
+			result
+		);
+		Ok(())
+	}
+
 	#[test]
 	fn fallback_attempt_on_bundle_error() -> Result<(), RunSystemError> {
 		let mut fallback = FluentBundle::new_concurrent(vec![langid!("en")]);
@@ -1074,4 +1380,51 @@ mod tests {
 		assert_eq!(LocalizationResult::Ok(Localized::from("{$a}")), result);
 		Ok(())
 	}
+
+	const EN_US: &str = include_str!("../../../../../assets/locale/en-us.ftl");
+	const DE: &str = include_str!("../../../../../assets/locale/de.ftl");
+
+	#[test_case(langid!("en-US"), EN_US, 1, "Jumps once"; "en one")]
+	#[test_case(langid!("en-US"), EN_US, 3, "Jumps 3 times"; "en other")]
+	#[test_case(langid!("de"), DE, 1, "Springt einmal"; "de one")]
+	#[test_case(langid!("de"), DE, 3, "Springt 3 mal"; "de other")]
+	fn localize_plural_forms(
+		ln: LanguageIdentifier,
+		ftl: &str,
+		jumps: u32,
+		expected: &str,
+	) -> Result<(), RunSystemError> {
+		let mut bundle = FluentBundle::new_concurrent(vec![ln.clone()]);
+		bundle.set_use_isolating(false);
+		let res = match FluentResource::try_new(String::from(ftl)) {
+			Ok(res) => res,
+			Err((res, ..)) => res,
+		};
+		_ = bundle.add_resource(res);
+		let mut app = setup(
+			FtlServer {
+				fallback: Locale {
+					ln,
+					file: None,
+					folder: None,
+					bundle: Some(bundle),
+				},
+				current: None,
+				update_file: false,
+				update_folder: false,
+			},
+			_Logger::new().with_mock(|mock| {
+				mock.expect_log::<FtlError>().never();
+			}),
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(move |f: FtlServerParam<_LoggerParam>| {
+				f.localize(&Token::from("skill-stat-jumps").with_arg("jumps", jumps))
+			})?;
+
+		assert_eq!(LocalizationResult::Ok(Localized::from(expected)), result);
+		Ok(())
+	}
 }
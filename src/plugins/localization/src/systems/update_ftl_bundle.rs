@@ -23,15 +23,24 @@ pub(crate) trait UpdateFtlBundle:
 	fn update_ftl_bundle(
 		mut server: StaticSystemParam<Self>,
 		mut messages: MessageReader<AssetEvent<Ftl>>,
+		mut changed: MessageWriter<LocalizationChanged>,
 		files: Res<Assets<Ftl>>,
 		mut folders: ResMut<Assets<LoadedFolder>>,
 	) -> Result<(), Vec<SetBundleError>> {
 		let locale = server.current_locale_mut();
-
-		messages
+		let updates = messages
 			.read()
 			.filter_map(added_id)
 			.filter_map(update_bundle(locale, &files, &mut folders))
+			.collect::<Vec<_>>();
+
+		if !updates.is_empty() {
+			changed.write(LocalizationChanged);
+		}
+
+		updates
+			.into_iter()
+			.filter_map(Result::err)
 			.collect::<Vec<_>>()
 			.or_ok(|| ())
 	}
@@ -48,28 +57,31 @@ fn update_bundle(
 	locale: &mut Locale,
 	assets: &Assets<Ftl>,
 	folders: &mut Assets<LoadedFolder>,
-) -> impl FnMut(&AssetId<Ftl>) -> Option<SetBundleError> {
+) -> impl FnMut(&AssetId<Ftl>) -> Option<Result<(), SetBundleError>> {
 	move |id| {
 		let id = removed_handle_id(id, locale, folders)?;
 		let Ftl(file) = match get_ftl_file(assets, id, &locale.ln) {
 			Ok(ftl_file) => ftl_file,
-			Err(error) => return Some(error),
+			Err(error) => return Some(Err(error)),
 		};
 		let (res, parse_errors) = new_resource(file);
-		let bundle = locale
-			.bundle
-			.get_or_insert_with(|| FluentBundle::new_concurrent(vec![locale.ln.clone()]));
+		let bundle = locale.bundle.get_or_insert_with(|| {
+			let mut bundle = FluentBundle::new_concurrent(vec![locale.ln.clone()]);
+			// isolation marks around placeables are rendered as unknown glyphs by bevy's text
+			bundle.set_use_isolating(false);
+			bundle
+		});
 		let fluent_errors = bundle.add_resource(res).err().unwrap_or_default();
 
 		if !parse_errors.is_empty() || !fluent_errors.is_empty() {
-			return Some(SetBundleError::fluent_errors(
+			return Some(Err(SetBundleError::fluent_errors(
 				&locale.ln,
 				parse_errors,
 				fluent_errors,
-			));
+			)));
 		}
 
-		None
+		Some(Ok(()))
 	}
 }
 
@@ -214,6 +226,7 @@ mod tests {
 		}
 
 		app.add_message::<AssetEvent<Ftl>>();
+		app.add_message::<LocalizationChanged>();
 		for (event, ftl) in added {
 			let id = match event {
 				AssetEvent::Added { id } => id,
@@ -292,6 +305,56 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn notify_localization_changed() {
+		let file = new_handle();
+		let mut app = setup(
+			[(
+				AssetEvent::Added { id: file.id() },
+				Some(Ftl(String::from("hello-world = Hello, World!"))),
+			)],
+			[],
+		);
+		app.insert_resource(_FtlServer(Locale {
+			ln: langid!("en"),
+			file: Some(file),
+			folder: None,
+			bundle: None,
+		}));
+
+		app.update();
+
+		let messages = app.world().resource::<Messages<LocalizationChanged>>();
+		assert_eq!(
+			vec![&LocalizationChanged],
+			messages.iter_current_update_messages().collect::<Vec<_>>()
+		);
+	}
+
+	#[test]
+	fn do_not_notify_localization_changed_when_no_bundle_updated() {
+		let mut app = setup(
+			[(
+				AssetEvent::Added {
+					id: new_handle::<Ftl>().id(),
+				},
+				Some(Ftl(String::from("hello-world = Hello, World!"))),
+			)],
+			[],
+		);
+		app.insert_resource(_FtlServer(Locale {
+			ln: langid!("en"),
+			file: Some(new_handle()),
+			folder: None,
+			bundle: None,
+		}));
+
+		app.update();
+
+		let messages = app.world().resource::<Messages<LocalizationChanged>>();
+		assert_eq!(0, messages.iter_current_update_messages().count());
+	}
+
 	#[test]
 	fn set_multiple_bundles() {
 		let files = [new_handle(), new_handle()];
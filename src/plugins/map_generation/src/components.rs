@@ -1,4 +1,7 @@
 pub(crate) mod grid;
+pub(crate) mod grid_obstacle;
+pub(crate) mod level_exit;
+pub(crate) mod level_traveler;
 pub(crate) mod map;
 pub(crate) mod map_agents;
 pub(crate) mod mesh_collider;
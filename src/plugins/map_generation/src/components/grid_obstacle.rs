@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+const PARALLEL_EPSILON: f32 = 1e-6;
+
+/// Blocks all grid cells covered by the xz-footprint of a collider shape.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[component(immutable)]
+pub(crate) struct GridObstacle(pub(crate) ShapeParameters);
+
+impl GridObstacle {
+	/// Whether the segment from `a` to `b` crosses the obstacle footprint, when the obstacle is
+	/// placed at `transform`. Height is ignored.
+	pub(crate) fn crosses(&self, transform: &GlobalTransform, a: Vec3, b: Vec3) -> bool {
+		let inverse = transform.affine().inverse();
+		let a = inverse.transform_point3(a).xz();
+		let b = inverse.transform_point3(b).xz();
+
+		match self.0 {
+			ShapeParameters::Sphere { radius }
+			| ShapeParameters::Capsule { radius, .. }
+			| ShapeParameters::Cylinder { radius, .. } => closest_to_origin(a, b).length() <= *radius,
+			ShapeParameters::Cuboid { half_x, half_z, .. } => {
+				crosses_rectangle(a, b, Vec2::new(*half_x, *half_z))
+			}
+		}
+	}
+}
+
+fn closest_to_origin(a: Vec2, b: Vec2) -> Vec2 {
+	let ab = b - a;
+	let length_squared = ab.length_squared();
+
+	if length_squared == 0. {
+		return a;
+	}
+
+	let t = (-a).dot(ab) / length_squared;
+	a + ab * t.clamp(0., 1.)
+}
+
+/// Slab test against the origin centered rectangle
+fn crosses_rectangle(a: Vec2, b: Vec2, half: Vec2) -> bool {
+	let ab = b - a;
+	let mut t_min = 0_f32;
+	let mut t_max = 1_f32;
+
+	for axis in 0..2 {
+		if ab[axis].abs() < PARALLEL_EPSILON {
+			if a[axis].abs() > half[axis] {
+				return false;
+			}
+			continue;
+		}
+
+		let t_0 = (-half[axis] - a[axis]) / ab[axis];
+		let t_1 = (half[axis] - a[axis]) / ab[axis];
+
+		t_min = t_min.max(t_0.min(t_1));
+		t_max = t_max.min(t_0.max(t_1));
+
+		if t_min > t_max {
+			return false;
+		}
+	}
+
+	true
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::f32::consts::PI;
+	use test_case::test_case;
+
+	const SPHERE: GridObstacle = GridObstacle(ShapeParameters::Sphere {
+		radius: Units::from_u8(1),
+	});
+
+	const CUBOID: GridObstacle = GridObstacle(ShapeParameters::Cuboid {
+		half_x: Units::from_u8(2),
+		half_y: Units::from_u8(1),
+		half_z: Units::from_u8(1),
+	});
+
+	#[test_case(Vec3::new(0.5, 0., 0.), Vec3::new(0.5, 0., 0.), true; "point inside")]
+	#[test_case(Vec3::new(2., 0., 0.), Vec3::new(2., 0., 0.), false; "point outside")]
+	#[test_case(Vec3::new(-2., 0., 0.5), Vec3::new(2., 0., 0.5), true; "segment through")]
+	#[test_case(Vec3::new(-2., 0., 1.5), Vec3::new(2., 0., 1.5), false; "segment passing")]
+	#[test_case(Vec3::new(0.5, 10., 0.), Vec3::new(0.5, -10., 0.), true; "ignore height")]
+	fn sphere(a: Vec3, b: Vec3, crosses: bool) {
+		let transform = GlobalTransform::default();
+
+		assert_eq!(crosses, SPHERE.crosses(&transform, a, b));
+	}
+
+	#[test_case(Vec3::new(1.5, 0., 0.5), Vec3::new(1.5, 0., 0.5), true; "point inside")]
+	#[test_case(Vec3::new(2.5, 0., 0.), Vec3::new(2.5, 0., 0.), false; "point outside")]
+	#[test_case(Vec3::new(0., 0., -2.), Vec3::new(0., 0., 2.), true; "segment through")]
+	#[test_case(Vec3::new(-3., 0., -2.), Vec3::new(-3., 0., 2.), false; "segment passing")]
+	#[test_case(Vec3::new(-3., 0., 0.5), Vec3::new(-0.5, 0., 3.), false; "segment passing corner")]
+	fn cuboid(a: Vec3, b: Vec3, crosses: bool) {
+		let transform = GlobalTransform::default();
+
+		assert_eq!(crosses, CUBOID.crosses(&transform, a, b));
+	}
+
+	#[test]
+	fn cuboid_translated_and_rotated() {
+		let transform = GlobalTransform::from(
+			Transform::from_xyz(10., 0., 10.).with_rotation(Quat::from_rotation_y(PI / 2.)),
+		);
+
+		assert_eq!(
+			[true, false],
+			[
+				CUBOID.crosses(
+					&transform,
+					Vec3::new(10.5, 0., 11.5),
+					Vec3::new(10.5, 0., 11.5)
+				),
+				CUBOID.crosses(
+					&transform,
+					Vec3::new(11.5, 0., 10.5),
+					Vec3::new(11.5, 0., 10.5)
+				),
+			]
+		);
+	}
+}
@@ -0,0 +1,53 @@
+use crate::components::map::{level::LevelId, objects::MapObject};
+use bevy::prelude::*;
+use zyheeda_core::strings::normalized_name::NormalizedName;
+
+/// Travel point into another level.
+///
+/// Identified by glTF mesh names of the form `<prefix><level>`, where negative levels are
+/// written as `Neg<level>`, like `LevelExit2` or `LevelExit_Neg_1`.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[require(MapObject)]
+pub(crate) struct LevelExit {
+	pub(crate) target: LevelId,
+}
+
+impl LevelExit {
+	/// Horizontal distance in which a traveler is considered to be at the exit
+	pub(crate) const RADIUS: f32 = 1.;
+
+	pub(crate) fn from_name(prefix: &str, name: &str) -> Option<Self> {
+		let prefix = NormalizedName::from(prefix).to_string();
+		let name = NormalizedName::from(name).to_string();
+		let level = name.strip_prefix(&prefix)?;
+		let level = match level.strip_prefix("neg") {
+			Some(level) => level.parse::<i8>().ok()?.checked_neg()?,
+			None => level.parse::<i8>().ok()?,
+		};
+
+		Some(Self {
+			target: LevelId(level),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	#[test_case("LevelExit2", Some(2); "positive")]
+	#[test_case("LevelExitNeg1", Some(-1); "negative")]
+	#[test_case("Level_Exit_Neg_3.001", Some(-3); "normalized")]
+	#[test_case("LevelExit", None; "no level")]
+	#[test_case("LevelExitUp", None; "invalid level")]
+	#[test_case("Door2", None; "other prefix")]
+	fn from_name(name: &str, level: Option<i8>) {
+		assert_eq!(
+			level.map(|level| LevelExit {
+				target: LevelId(level)
+			}),
+			LevelExit::from_name("LevelExit", name),
+		);
+	}
+}
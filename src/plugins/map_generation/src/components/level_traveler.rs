@@ -0,0 +1,28 @@
+use crate::components::map::level::LevelId;
+use bevy::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+
+/// Marks the agent that travels between levels.
+///
+/// Travelers are kept when leaving a level and suppress player spawners of the levels they enter.
+#[derive(Component, SavableComponent, Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
+#[savable_component(id = "level traveler")]
+pub(crate) struct LevelTraveler;
+
+/// The [`LevelExit`](crate::components::level_exit::LevelExit) a traveler currently stands at.
+///
+/// Travel is only triggered when reaching an exit, so arriving at an exit does not
+/// immediately travel back.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct AtLevelExit(pub(crate) Entity);
+
+/// Requests travel to the contained level.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct Departing(pub(crate) LevelId);
+
+/// Traveler waiting to be placed in the level it travels to.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct Arriving {
+	pub(crate) from: LevelId,
+}
@@ -4,7 +4,7 @@ use common::prelude::*;
 use serde::{Deserialize, Serialize};
 
 #[derive(Component, Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
-#[require(Map, Name = Self, Model = Self)]
+#[require(Map, Name = Self, Model = Self, LevelId = Self)]
 pub(crate) struct Level<const L: i8>;
 
 impl<const L: i8> SavableComponent for Level<L> {
@@ -33,3 +33,39 @@ impl<const L: i8> From<Level<L>> for Model {
 		}
 	}
 }
+
+/// Runtime identifier of the [`Level`] a [`Map`] represents.
+#[derive(Component, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub(crate) struct LevelId(pub(crate) i8);
+
+impl<const L: i8> From<Level<L>> for LevelId {
+	fn from(_: Level<L>) -> Self {
+		Self(L)
+	}
+}
+
+impl From<LevelId> for StashKey {
+	fn from(LevelId(level): LevelId) -> Self {
+		Self::from(format!("level {level}"))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	#[test]
+	fn insert_level_id() {
+		let mut app = App::new().single_threaded(Update);
+
+		let entity = app.world_mut().spawn(Level::<-3>);
+
+		assert_eq!(Some(&LevelId(-3)), entity.get::<LevelId>());
+	}
+
+	#[test]
+	fn level_id_as_stash_key() {
+		assert_eq!(StashKey::from("level -3"), StashKey::from(LevelId(-3)));
+	}
+}
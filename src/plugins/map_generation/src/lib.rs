@@ -7,10 +7,12 @@ mod systems;
 
 use crate::{
 	components::{
+		level_exit::LevelExit,
+		level_traveler::{Arriving, LevelTraveler},
 		map::{
 			Map,
 			agents::AgentsLoaded,
-			level::Level,
+			level::{Level, LevelId},
 			objects::{MapObject, PersistentMapObject},
 		},
 		map_agents::{GridAgent, GridAgentOf},
@@ -21,8 +23,8 @@ use crate::{
 	},
 	mesh_grid_graph::MeshGridGraph,
 	observers::identify_by_prefix::IdentifyByPrefix,
-	resources::agents::prefab::PrefabRegister,
-	system_params::set_agent_prefab::SetAgentPrefab,
+	resources::{agents::prefab::PrefabRegister, levels::Levels},
+	system_params::{grid_blockers::GridBlockersMut, set_agent_prefab::SetAgentPrefab},
 };
 use bevy::prelude::*;
 use common::{
@@ -48,16 +50,30 @@ where
 			AgentType::Enemy(EnemyType::VoidSphere),
 		),
 	];
-	const INTERACTIVE_SPAWNERS: &[(GetNormalizedName, InteractiveType)] = &[(
-		|| NormalizedName::from("SlideDoorSpawn"),
-		InteractiveType::Door,
-	)];
+	const INTERACTIVE_SPAWNERS: &[(GetNormalizedName, InteractiveType)] = &[
+		(
+			|| NormalizedName::from("SlideDoorSpawn"),
+			InteractiveType::Door,
+		),
+		(
+			|| NormalizedName::from("ContainerSpawn"),
+			InteractiveType::Container,
+		),
+	];
+	const LEVEL_EXIT_PREFIX: &str = "LevelExit";
 	const MESH_COLLIDER_PREFIX: &str = "Collider";
 	const NAV_MESH_PREFIX: &str = "NavMesh";
 
 	pub fn from_plugins(_: &TLoading, _: &TSavegame, _: &TPhysics) -> Self {
 		Self(PhantomData)
 	}
+
+	fn register_level<const L: i8>(app: &mut App) {
+		TSavegame::register_savable_component::<Level<L>>(app);
+		app.world_mut()
+			.get_resource_or_init::<Levels>()
+			.register::<L>();
+	}
 }
 
 impl<TLoading, TSavegame, TPhysics> Plugin for MapGenerationPlugin<(TLoading, TSavegame, TPhysics)>
@@ -78,9 +94,12 @@ where
 		TSavegame::register_savable_component::<Map>(app);
 		TSavegame::register_savable_component::<PersistentMapObject>(app);
 		TSavegame::register_savable_component::<GridAgent>(app);
-		TSavegame::register_savable_component::<Level<0>>(app);
+		TSavegame::register_savable_component::<LevelTraveler>(app);
+
+		Self::register_level::<0>(app);
 
 		TSavegame::on_before_save(app, Map::apply_map_objects_persistence);
+		TSavegame::on_before_save(app, LevelId::write_metadata::<TSavegame::TSlotMetadataMut>);
 
 		#[cfg(debug_assertions)]
 		crate::mesh_grid_graph::debug::draw(app);
@@ -92,6 +111,7 @@ where
 			.add_observer(Map::apply_despawned_map_objects_persistence)
 			.add_observer(NavMesh::identify_by_prefix(Self::NAV_MESH_PREFIX))
 			.add_observer(MeshCollider::identify_by_prefix(Self::MESH_COLLIDER_PREFIX))
+			.add_observer(LevelExit::identify(Self::LEVEL_EXIT_PREFIX))
 			.add_observer(Spawner::<AgentType>::identify(Self::AGENT_SPAWNERS))
 			.add_observer(Spawner::<InteractiveType>::identify(
 				Self::INTERACTIVE_SPAWNERS,
@@ -101,11 +121,21 @@ where
 				Update,
 				(
 					NavMesh::spawn_grid::<MeshGridGraph>.pipe(OnError::log),
+					Grid::apply_obstacles,
 					MapObject::link_with_map.pipe(OnError::log),
 					PersistentMapObject::link_with_map.pipe(OnError::log),
+					LevelTraveler::deactivate_player_spawners,
 					Spawner::<AgentType>::execute,
 					Spawner::<InteractiveType>::execute,
-					GridAgent::link_to_grid::<MeshGridGraph>.run_if(in_state(GameState::Play)),
+					LevelTraveler::mark_players,
+					Arriving::place,
+					(
+						GridAgent::link_to_grid::<MeshGridGraph>,
+						LevelExit::detect_travelers,
+						LevelTraveler::travel::<TSavegame::TStashMut>.pipe(OnError::log),
+					)
+						.chain()
+						.run_if(in_state(GameState::Play)),
 				)
 					.chain(),
 			);
@@ -119,6 +149,7 @@ pub struct MapSystems;
 
 impl<TDependencies> HandlesMapGeneration for MapGenerationPlugin<TDependencies> {
 	type TMapPrefabs = SetAgentPrefab<'static>;
+	type TGridBlockersMut = GridBlockersMut<'static, 'static>;
 
 	type TGraph = MeshGridGraph;
 
@@ -25,6 +25,7 @@ pub struct MeshGridGraph {
 	vertices: Vec<VecNotNan<3>>,
 	neighbors: Vec<Vec<NodeId>>,
 	clearance: Vec<Clearance>,
+	blocked: HashSet<NodeId>,
 	max_los_fn: fn(LoSParams, &Self) -> Option<NodeId>,
 }
 
@@ -37,6 +38,35 @@ impl MeshGridGraph {
 		NodeId(self.vertices.len() - 1)
 	}
 
+	pub(crate) fn blocked(&self) -> &HashSet<NodeId> {
+		&self.blocked
+	}
+
+	/// Replace all runtime blocked nodes. Blocked nodes are treated as obstacles regardless of
+	/// their clearance.
+	pub(crate) fn with_blocked(mut self, blocked: HashSet<NodeId>) -> Self {
+		self.blocked = blocked;
+		self
+	}
+
+	/// Nodes that lie within an obstacle or that have an edge crossing it.
+	///
+	/// Edges are considered so that thin obstacles, like doors, cannot slip between nodes.
+	pub(crate) fn nodes_crossed_by<'a>(
+		&'a self,
+		crosses: impl Fn(Vec3, Vec3) -> bool + 'a,
+	) -> impl Iterator<Item = NodeId> + 'a {
+		(0..self.vertices.len()).map(NodeId).filter(move |node| {
+			let GroundPosition(pos) = self.ground_position(node);
+
+			crosses(pos, pos)
+				|| self.successors(node).any(|neighbor| {
+					let GroundPosition(neighbor) = self.ground_position(&neighbor);
+					crosses(pos, neighbor)
+				})
+		})
+	}
+
 	fn insert_unique_neighbors(
 		&mut self,
 		node: NodeId,
@@ -75,6 +105,7 @@ impl Default for MeshGridGraph {
 			vertices: vec![],
 			neighbors: vec![],
 			clearance: vec![],
+			blocked: HashSet::default(),
 			max_los_fn: |los_param, graph| line::IterLine::new(los_param, graph).last(),
 		}
 	}
@@ -86,6 +117,7 @@ impl PartialEq for MeshGridGraph {
 		self.vertices == other.vertices
 			&& self.neighbors == other.neighbors
 			&& self.clearance == other.clearance
+			&& self.blocked == other.blocked
 			&& std::ptr::fn_addr_eq(self.max_los_fn, other.max_los_fn)
 	}
 }
@@ -188,6 +220,10 @@ impl GraphNode for MeshGridGraph {
 				continue;
 			}
 
+			if self.blocked.contains(&NodeId(i)) {
+				continue;
+			}
+
 			let distance = (translation - Vec3::from(*pos)).length_squared();
 			if distance < closest_distance {
 				closest_distance = distance;
@@ -223,7 +259,8 @@ impl GraphObstacle for MeshGridGraph {
 	type TONode = NodeId;
 
 	fn is_obstacle(&self, node: &Self::TONode, required_clearance: Units) -> bool {
-		self.clearance[**node].is_zero_or_smaller_than(required_clearance)
+		self.blocked.contains(node)
+			|| self.clearance[**node].is_zero_or_smaller_than(required_clearance)
 	}
 }
 
@@ -325,6 +362,10 @@ mod test {
 				return false;
 			}
 
+			if self.blocked != other.blocked {
+				return false;
+			}
+
 			if self.neighbors.len() != other.neighbors.len() {
 				return false;
 			}
@@ -1056,6 +1097,115 @@ mod test {
 
 			assert_eq!(Some(NodeId(2)), node);
 		}
+
+		#[test]
+		fn get_closest_translation_not_blocked() {
+			let graph = MeshGridGraph {
+				vertices: vec![
+					vec_not_nan!(10., 2., 3.),
+					vec_not_nan!(1., 2., 3.),
+					vec_not_nan!(1., 2., 2.),
+				],
+				neighbors: neighbors![[], [], []],
+				clearance: vec![Clearance::INFINITY; 3],
+				blocked: HashSet::from([NodeId(1)]),
+				..default()
+			};
+
+			let node = graph.node(Vec3::new(2., 3., 4.));
+
+			assert_eq!(Some(NodeId(2)), node);
+		}
+	}
+
+	mod obstacle {
+		use super::*;
+
+		#[test]
+		fn obstacle_when_clearance_too_small() {
+			let graph = MeshGridGraph {
+				vertices: vec![vec_not_nan!(1., 2., 3.)],
+				neighbors: neighbors![[]],
+				clearance: vec![Clearance::from(Units::from(0.5))],
+				..default()
+			};
+
+			assert!(graph.is_obstacle(&NodeId(0), Units::from(1.)));
+		}
+
+		#[test]
+		fn no_obstacle_when_clearance_sufficient() {
+			let graph = MeshGridGraph {
+				vertices: vec![vec_not_nan!(1., 2., 3.)],
+				neighbors: neighbors![[]],
+				clearance: vec![Clearance::from(Units::from(2.))],
+				..default()
+			};
+
+			assert!(!graph.is_obstacle(&NodeId(0), Units::from(1.)));
+		}
+
+		#[test]
+		fn obstacle_when_blocked() {
+			let graph = MeshGridGraph {
+				vertices: vec![vec_not_nan!(1., 2., 3.)],
+				neighbors: neighbors![[]],
+				clearance: vec![Clearance::INFINITY],
+				blocked: HashSet::from([NodeId(0)]),
+				..default()
+			};
+
+			assert!(graph.is_obstacle(&NodeId(0), Units::from(1.)));
+		}
+	}
+
+	mod blocking {
+		use super::*;
+
+		/// ```
+		///  a — b — c
+		/// ```
+		fn setup_line() -> MeshGridGraph {
+			MeshGridGraph {
+				vertices: vec![
+					vec_not_nan!(0., 0., 0.),
+					vec_not_nan!(1., 0., 0.),
+					vec_not_nan!(2., 0., 0.),
+				],
+				neighbors: neighbors![[1], [0, 2], [1]],
+				clearance: vec![Clearance::INFINITY; 3],
+				..default()
+			}
+		}
+
+		#[test]
+		fn get_nodes_within_obstacle() {
+			let graph = setup_line();
+
+			let nodes = graph.nodes_crossed_by(|a, b| a == b && a == Vec3::new(2., 0., 0.));
+
+			assert_eq!(vec![NodeId(2)], nodes.collect::<Vec<_>>());
+		}
+
+		#[test]
+		fn get_nodes_of_edges_crossing_obstacle() {
+			let graph = setup_line();
+			let crosses_between_a_and_b =
+				|a: Vec3, b: Vec3| a.x.min(b.x) < 0.5 && a.x.max(b.x) > 0.5;
+
+			let nodes = graph.nodes_crossed_by(crosses_between_a_and_b);
+
+			assert_eq!(vec![NodeId(0), NodeId(1)], nodes.collect::<Vec<_>>());
+		}
+
+		#[test]
+		fn set_blocked() {
+			let graph = setup_line();
+
+			let graph = graph.with_blocked(HashSet::from([NodeId(1)]));
+
+			assert_eq!(&HashSet::from([NodeId(1)]), graph.blocked());
+		}
 	}
 
 	mod successors {
@@ -1088,6 +1238,7 @@ mod test {
 			],
 			neighbors: vec![vec![]; 3],
 			clearance: vec![Clearance::INFINITY; 3],
+			blocked: HashSet::default(),
 			max_los_fn,
 		}
 	}
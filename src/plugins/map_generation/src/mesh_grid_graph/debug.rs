@@ -3,7 +3,7 @@ use crate::{
 	mesh_grid_graph::{Clearance, NodeId},
 };
 use bevy::{
-	color::palettes::css::{BLUE, GREEN, RED},
+	color::palettes::css::{BLUE, GREEN, ORANGE, RED},
 	prelude::*,
 };
 use std::ops::Deref;
@@ -22,6 +22,7 @@ fn draw_vertices(grids: Query<&Grid>, mut gizmos: Gizmos) {
 				0.1,
 				match graph.clearance[node] {
 					Clearance::NONE => RED,
+					_ if graph.blocked.contains(&NodeId(node)) => ORANGE,
 					_ => GREEN,
 				},
 			);
@@ -1,4 +1,5 @@
 pub(crate) mod apply_despawned_map_objects_persistence;
 pub(crate) mod identify_by_prefix;
+pub(crate) mod identify_level_exits;
 pub(crate) mod identify_spawners;
 pub(crate) mod inactivate_disabled_spawners;
@@ -0,0 +1,70 @@
+use crate::components::level_exit::LevelExit;
+use bevy::{ecs::system::IntoObserverSystem, gltf::GltfMeshName, prelude::*};
+use common::prelude::*;
+
+impl LevelExit {
+	pub(crate) fn identify(prefix: &'static str) -> impl IntoObserverSystem<Add, GltfMeshName, ()> {
+		#[rustfmt::skip]
+		let observer = move |
+			on_add: On<Add, GltfMeshName>,
+			mut commands: ZyheedaCommands,
+			names: Query<&GltfMeshName>
+		| {
+			let Ok(GltfMeshName(name)) = names.get(on_add.entity) else {
+				return;
+			};
+			let Some(exit) = Self::from_name(prefix, name) else {
+				return;
+			};
+
+			commands.try_apply_on(&on_add.entity, |mut e| {
+				e.try_insert((exit, Visibility::Hidden));
+			});
+		};
+
+		IntoObserverSystem::into_system(observer)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::map::level::LevelId;
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_observer(LevelExit::identify("LevelExit"));
+
+		app
+	}
+
+	#[test]
+	fn insert_level_exit() {
+		let mut app = setup();
+
+		let entity = app
+			.world_mut()
+			.spawn(GltfMeshName("LevelExitNeg2".to_owned()));
+
+		assert_eq!(
+			(
+				Some(&LevelExit {
+					target: LevelId(-2)
+				}),
+				Some(&Visibility::Hidden)
+			),
+			(entity.get::<LevelExit>(), entity.get::<Visibility>())
+		);
+	}
+
+	#[test]
+	fn ignore_other_names() {
+		let mut app = setup();
+
+		let entity = app.world_mut().spawn(GltfMeshName("Collider".to_owned()));
+
+		assert_eq!(None, entity.get::<LevelExit>());
+	}
+}
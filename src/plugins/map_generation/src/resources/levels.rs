@@ -0,0 +1,33 @@
+use crate::components::map::level::{Level, LevelId};
+use bevy::prelude::*;
+use common::prelude::*;
+use std::collections::HashMap;
+
+/// Levels that can be traveled to via a [`LevelExit`](crate::components::level_exit::LevelExit).
+#[derive(Resource, Debug, Default)]
+pub(crate) struct Levels(HashMap<LevelId, SpawnLevel>);
+
+pub(crate) type SpawnLevel = fn(&mut ZyheedaCommands);
+
+impl Levels {
+	pub(crate) fn register<const L: i8>(&mut self) {
+		self.0.insert(LevelId(L), |commands| {
+			commands.spawn(Level::<L>);
+		});
+	}
+}
+
+impl Get<LevelId> for Levels {
+	type TValue = SpawnLevel;
+
+	fn get(&self, level: &LevelId) -> Option<SpawnLevel> {
+		self.0.get(level).copied()
+	}
+}
+
+#[cfg(test)]
+impl<const N: usize> From<[(LevelId, SpawnLevel); N]> for Levels {
+	fn from(levels: [(LevelId, SpawnLevel); N]) -> Self {
+		Self(HashMap::from(levels))
+	}
+}
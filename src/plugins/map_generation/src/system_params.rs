@@ -1 +1,2 @@
+pub(crate) mod grid_blockers;
 pub(crate) mod set_agent_prefab;
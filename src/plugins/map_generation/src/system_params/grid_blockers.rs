@@ -0,0 +1,131 @@
+use crate::components::grid_obstacle::GridObstacle;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use common::prelude::*;
+
+#[derive(SystemParam)]
+pub struct GridBlockersMut<'w, 's> {
+	obstacles: Query<'w, 's, &'static GridObstacle>,
+	commands: ZyheedaCommands<'w, 's>,
+}
+
+impl TryGetContextMut<GridBlocker> for GridBlockersMut<'static, 'static> {
+	type TContext<'ctx> = GridBlockerContextMut<'ctx>;
+
+	fn try_get_context_mut<'ctx>(
+		param: &'ctx mut GridBlockersMut,
+		GridBlocker { entity }: GridBlocker,
+	) -> Option<Self::TContext<'ctx>> {
+		Some(GridBlockerContextMut {
+			current: param.obstacles.get(entity).ok().copied(),
+			entity: param.commands.get_mut(&entity)?,
+		})
+	}
+}
+
+pub struct GridBlockerContextMut<'ctx> {
+	current: Option<GridObstacle>,
+	entity: ZyheedaEntityCommands<'ctx>,
+}
+
+impl BlockGrid for GridBlockerContextMut<'_> {
+	fn block_grid(&mut self, shape: ShapeParameters) {
+		let obstacle = GridObstacle(shape);
+
+		if self.current == Some(obstacle) {
+			return;
+		}
+
+		self.entity.try_insert(obstacle);
+		self.current = Some(obstacle);
+	}
+
+	fn unblock_grid(&mut self) {
+		if self.current.is_none() {
+			return;
+		}
+
+		self.entity.try_remove::<GridObstacle>();
+		self.current = None;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::{IsChanged, SingleThreadedApp};
+
+	const SHAPE: ShapeParameters = ShapeParameters::Sphere {
+		radius: Units::from_u8(2),
+	};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, IsChanged::<GridObstacle>::detect);
+
+		app
+	}
+
+	#[test]
+	fn block() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app.world_mut().spawn_empty().id();
+
+		app.world_mut()
+			.run_system_once(move |mut p: GridBlockersMut| {
+				let key = GridBlocker { entity };
+				let Some(mut ctx) = GridBlockersMut::try_get_context_mut(&mut p, key) else {
+					return;
+				};
+				ctx.block_grid(SHAPE);
+			})?;
+
+		assert_eq!(
+			Some(&GridObstacle(SHAPE)),
+			app.world().entity(entity).get::<GridObstacle>(),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn unblock() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(GridObstacle(SHAPE)).id();
+
+		app.world_mut()
+			.run_system_once(move |mut p: GridBlockersMut| {
+				let key = GridBlocker { entity };
+				let Some(mut ctx) = GridBlockersMut::try_get_context_mut(&mut p, key) else {
+					return;
+				};
+				ctx.unblock_grid();
+			})?;
+
+		assert_eq!(None, app.world().entity(entity).get::<GridObstacle>());
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_reinsert_same_obstacle() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(GridObstacle(SHAPE)).id();
+
+		app.update();
+		app.world_mut()
+			.run_system_once(move |mut p: GridBlockersMut| {
+				let key = GridBlocker { entity };
+				let Some(mut ctx) = GridBlockersMut::try_get_context_mut(&mut p, key) else {
+					return;
+				};
+				ctx.block_grid(SHAPE);
+			})?;
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<GridObstacle>>(),
+		);
+		Ok(())
+	}
+}
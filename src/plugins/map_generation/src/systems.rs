@@ -1,7 +1,13 @@
+pub(crate) mod apply_grid_obstacles;
 pub(crate) mod apply_map_objects_persistence;
+pub(crate) mod arrive_level;
+pub(crate) mod detect_level_exit;
+pub(crate) mod level_traveler;
 pub(crate) mod link_agent_to_grid;
 pub(crate) mod link_map_object_with_map;
 pub(crate) mod link_persistent_map_object_with_map;
 pub(crate) mod map_loaded;
 pub(crate) mod spawn_grid;
 pub(crate) mod spawner;
+pub(crate) mod travel_level;
+pub(crate) mod write_level_metadata;
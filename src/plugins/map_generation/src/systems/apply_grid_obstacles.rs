@@ -0,0 +1,194 @@
+use crate::{
+	components::{grid::Grid, grid_obstacle::GridObstacle},
+	mesh_grid_graph::MeshGridGraph,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use std::collections::HashSet;
+
+impl Grid {
+	#[allow(clippy::type_complexity)]
+	pub(crate) fn apply_obstacles(
+		mut commands: ZyheedaCommands,
+		grids: Query<(Entity, Ref<Self>)>,
+		obstacles: Query<(&GridObstacle, &GlobalTransform)>,
+		changed_obstacles: Query<
+			(),
+			(
+				With<GridObstacle>,
+				Or<(Changed<GridObstacle>, Changed<GlobalTransform>)>,
+			),
+		>,
+		mut removed_obstacles: RemovedComponents<GridObstacle>,
+	) {
+		let obstacles_changed =
+			!changed_obstacles.is_empty() || removed_obstacles.read().count() > 0;
+
+		for (entity, grid) in &grids {
+			if !obstacles_changed && !grid.is_added() {
+				continue;
+			}
+
+			let blocked = obstacles
+				.iter()
+				.flat_map(|(obstacle, transform)| {
+					grid.nodes_crossed_by(move |a, b| obstacle.crosses(transform, a, b))
+				})
+				.collect::<HashSet<_>>();
+
+			if &blocked == grid.blocked() {
+				continue;
+			}
+
+			let graph = MeshGridGraph::from(grid.as_ref()).with_blocked(blocked);
+
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert(Grid::from(graph));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::systems::spawn_grid::TryFromTriangles;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	/// ```
+	///  0 — 1 — 2
+	///  | \ | \ |
+	///  3 — 4 — 5
+	/// ```
+	fn grid() -> Grid {
+		let v = [
+			vec_not_nan!(0., 0., 0.),
+			vec_not_nan!(1., 0., 0.),
+			vec_not_nan!(2., 0., 0.),
+			vec_not_nan!(0., 0., 1.),
+			vec_not_nan!(1., 0., 1.),
+			vec_not_nan!(2., 0., 1.),
+		];
+		let triangles = [
+			[v[0], v[1], v[4]],
+			[v[0], v[4], v[3]],
+			[v[1], v[2], v[5]],
+			[v[1], v[5], v[4]],
+		];
+
+		Grid::from(MeshGridGraph::try_from_triangles(triangles.into_iter()).unwrap())
+	}
+
+	/// Only crosses edges between the first and second column of [`grid`]
+	fn obstacle() -> (GridObstacle, GlobalTransform) {
+		(
+			GridObstacle(ShapeParameters::Cuboid {
+				half_x: Units::from(0.1),
+				half_y: Units::from(1.),
+				half_z: Units::from(2.),
+			}),
+			GlobalTransform::from_xyz(0.5, 0., 0.5),
+		)
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(Grid::apply_obstacles, IsChanged::<Grid>::detect).chain(),
+		);
+
+		app
+	}
+
+	fn blocked_positions(app: &App, entity: Entity) -> Option<Vec<Vec3>> {
+		let grid = app.world().entity(entity).get::<Grid>()?;
+		let mut positions = grid
+			.blocked()
+			.iter()
+			.map(|node| *grid.ground_position(node))
+			.collect::<Vec<_>>();
+
+		positions.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.z.total_cmp(&b.z)));
+
+		Some(positions)
+	}
+
+	const BLOCKED: [Vec3; 4] = [
+		Vec3::new(0., 0., 0.),
+		Vec3::new(0., 0., 1.),
+		Vec3::new(1., 0., 0.),
+		Vec3::new(1., 0., 1.),
+	];
+
+	#[test]
+	fn block_nodes_of_new_grid() {
+		let mut app = setup();
+		app.world_mut().spawn(obstacle());
+		let entity = app.world_mut().spawn(grid()).id();
+
+		app.update();
+
+		assert_eq!(Some(Vec::from(BLOCKED)), blocked_positions(&app, entity));
+	}
+
+	#[test]
+	fn block_nodes_when_obstacle_added() {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(grid()).id();
+
+		app.update();
+		app.world_mut().spawn(obstacle());
+		app.update();
+
+		assert_eq!(Some(Vec::from(BLOCKED)), blocked_positions(&app, entity));
+	}
+
+	#[test]
+	fn unblock_nodes_when_obstacle_removed() {
+		let mut app = setup();
+		let obstacle = app.world_mut().spawn(obstacle()).id();
+		let entity = app.world_mut().spawn(grid()).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(obstacle)
+			.remove::<GridObstacle>();
+		app.update();
+
+		assert_eq!(Some(vec![]), blocked_positions(&app, entity));
+	}
+
+	#[test]
+	fn unblock_nodes_when_obstacle_despawned() {
+		let mut app = setup();
+		let obstacle = app.world_mut().spawn(obstacle()).id();
+		let entity = app.world_mut().spawn(grid()).id();
+
+		app.update();
+		app.world_mut().entity_mut(obstacle).despawn();
+		app.update();
+
+		assert_eq!(Some(vec![]), blocked_positions(&app, entity));
+	}
+
+	#[test]
+	fn do_not_reinsert_grid_when_blocked_nodes_unchanged() {
+		let mut app = setup();
+		let obstacle = app.world_mut().spawn(obstacle()).id();
+		let entity = app.world_mut().spawn(grid()).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(obstacle)
+			.insert(GlobalTransform::from_xyz(0.5, 0., 0.6));
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<Grid>>(),
+		);
+	}
+}
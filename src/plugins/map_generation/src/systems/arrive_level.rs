@@ -0,0 +1,225 @@
+use crate::components::{
+	level_exit::LevelExit,
+	level_traveler::{Arriving, AtLevelExit},
+	map::{
+		level::LevelId,
+		objects::{MapObjectOf, PersistentMapObject},
+	},
+	spawner::Spawner,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl Arriving {
+	/// Place arriving travelers at the exit leading back to the level they came from.
+	///
+	/// Falls back to the player spawner, when the entered level has no such exit.
+	pub(crate) fn place(
+		mut commands: ZyheedaCommands,
+		mut travelers: Query<(Entity, &Self, &mut Transform)>,
+		maps: Query<(&LevelId, &PersistentEntity)>,
+		exits: Query<(Entity, &LevelExit, &GlobalTransform, &MapObjectOf)>,
+		spawners: Query<(&Spawner<AgentType>, &GlobalTransform, &MapObjectOf)>,
+	) {
+		for (entity, Self { from }, mut transform) in &mut travelers {
+			let entered_map = |map: &Entity| match maps.get(*map) {
+				Ok((level, persistent)) if level != from => Some(*persistent),
+				_ => None,
+			};
+			let back_exit = exits
+				.iter()
+				.find_map(|(exit, LevelExit { target }, t, map)| {
+					if target != from {
+						return None;
+					}
+					Some((Some(exit), t.translation(), map.0, entered_map(&map.0)?))
+				});
+			let player_spawner = || {
+				spawners.iter().find_map(|(Spawner(agent), t, map)| {
+					if agent != &AgentType::Player {
+						return None;
+					}
+					Some((None, t.translation(), map.0, entered_map(&map.0)?))
+				})
+			};
+			let Some((exit, translation, map, map_persistent)) = back_exit.or_else(player_spawner)
+			else {
+				continue;
+			};
+
+			transform.translation = translation;
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_remove::<(Self, AtLevelExit)>();
+				e.try_insert((
+					MapObjectOf(map),
+					PersistentMapObject {
+						map: map_persistent,
+					},
+				));
+				if let Some(exit) = exit {
+					e.try_insert(AtLevelExit(exit));
+				}
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, Arriving::place);
+
+		app
+	}
+
+	fn spawn_map(app: &mut App, level: i8) -> (Entity, PersistentEntity) {
+		let persistent = PersistentEntity::default();
+		let map = app.world_mut().spawn((LevelId(level), persistent)).id();
+
+		(map, persistent)
+	}
+
+	#[test]
+	fn place_at_back_exit() {
+		let mut app = setup();
+		let (map, map_persistent) = spawn_map(&mut app, 2);
+		let exit = app
+			.world_mut()
+			.spawn((
+				LevelExit { target: LevelId(1) },
+				GlobalTransform::from_xyz(1., 2., 3.),
+				MapObjectOf(map),
+			))
+			.id();
+		app.world_mut().spawn((
+			Spawner(AgentType::Player),
+			GlobalTransform::from_xyz(4., 5., 6.),
+			MapObjectOf(map),
+		));
+		let traveler = app
+			.world_mut()
+			.spawn((Arriving { from: LevelId(1) }, Transform::default()))
+			.id();
+
+		app.update();
+
+		let traveler = app.world().entity(traveler);
+		assert_eq!(
+			(
+				Some(&Transform::from_xyz(1., 2., 3.)),
+				Some(&AtLevelExit(exit)),
+				Some(&MapObjectOf(map)),
+				Some(&PersistentMapObject {
+					map: map_persistent
+				}),
+				None,
+			),
+			(
+				traveler.get::<Transform>(),
+				traveler.get::<AtLevelExit>(),
+				traveler.get::<MapObjectOf>(),
+				traveler.get::<PersistentMapObject>(),
+				traveler.get::<Arriving>(),
+			)
+		);
+	}
+
+	#[test]
+	fn place_at_player_spawner_when_no_back_exit() {
+		let mut app = setup();
+		let (map, map_persistent) = spawn_map(&mut app, 2);
+		app.world_mut().spawn((
+			LevelExit { target: LevelId(3) },
+			GlobalTransform::from_xyz(1., 2., 3.),
+			MapObjectOf(map),
+		));
+		app.world_mut().spawn((
+			Spawner(AgentType::Player),
+			GlobalTransform::from_xyz(4., 5., 6.),
+			MapObjectOf(map),
+		));
+		let traveler = app
+			.world_mut()
+			.spawn((
+				Arriving { from: LevelId(1) },
+				AtLevelExit(Entity::PLACEHOLDER),
+				Transform::default(),
+			))
+			.id();
+
+		app.update();
+
+		let traveler = app.world().entity(traveler);
+		assert_eq!(
+			(
+				Some(&Transform::from_xyz(4., 5., 6.)),
+				None,
+				Some(&MapObjectOf(map)),
+				Some(&PersistentMapObject {
+					map: map_persistent
+				}),
+				None,
+			),
+			(
+				traveler.get::<Transform>(),
+				traveler.get::<AtLevelExit>(),
+				traveler.get::<MapObjectOf>(),
+				traveler.get::<PersistentMapObject>(),
+				traveler.get::<Arriving>(),
+			)
+		);
+	}
+
+	#[test]
+	fn ignore_level_that_was_left() {
+		let mut app = setup();
+		let (map, _) = spawn_map(&mut app, 1);
+		app.world_mut().spawn((
+			Spawner(AgentType::Player),
+			GlobalTransform::from_xyz(4., 5., 6.),
+			MapObjectOf(map),
+		));
+		let traveler = app
+			.world_mut()
+			.spawn((Arriving { from: LevelId(1) }, Transform::default()))
+			.id();
+
+		app.update();
+
+		let traveler = app.world().entity(traveler);
+		assert_eq!(
+			(
+				Some(&Transform::default()),
+				Some(&Arriving { from: LevelId(1) })
+			),
+			(traveler.get::<Transform>(), traveler.get::<Arriving>())
+		);
+	}
+
+	#[test]
+	fn wait_while_level_has_no_placement() {
+		let mut app = setup();
+		let (map, _) = spawn_map(&mut app, 2);
+		app.world_mut().spawn((
+			Spawner(AgentType::Enemy(EnemyType::VoidSphere)),
+			GlobalTransform::from_xyz(4., 5., 6.),
+			MapObjectOf(map),
+		));
+		let traveler = app
+			.world_mut()
+			.spawn((Arriving { from: LevelId(1) }, Transform::default()))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Arriving { from: LevelId(1) }),
+			app.world().entity(traveler).get::<Arriving>()
+		);
+	}
+}
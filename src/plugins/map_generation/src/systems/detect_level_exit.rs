@@ -0,0 +1,178 @@
+use crate::components::{
+	level_exit::LevelExit,
+	level_traveler::{Arriving, AtLevelExit, Departing, LevelTraveler},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl LevelExit {
+	#[allow(clippy::type_complexity)]
+	pub(crate) fn detect_travelers(
+		mut commands: ZyheedaCommands,
+		exits: Query<(Entity, &Self, &GlobalTransform)>,
+		travelers: Query<
+			(Entity, &GlobalTransform, Option<&AtLevelExit>),
+			(With<LevelTraveler>, Without<Arriving>),
+		>,
+	) {
+		for (entity, transform, at_exit) in &travelers {
+			let position = transform.translation().xz();
+			let exit = exits
+				.iter()
+				.find(|(.., exit)| exit.translation().xz().distance(position) <= Self::RADIUS);
+
+			match (exit, at_exit) {
+				(None, None) => {}
+				(None, Some(_)) => {
+					commands.try_apply_on(&entity, |mut e| {
+						e.try_remove::<AtLevelExit>();
+					});
+				}
+				(Some((exit, ..)), Some(AtLevelExit(current))) if &exit == current => {}
+				(Some((exit, Self { target }, _)), _) => {
+					commands.try_apply_on(&entity, |mut e| {
+						e.try_insert((AtLevelExit(exit), Departing(*target)));
+					});
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::map::level::LevelId;
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(Update, LevelExit::detect_travelers);
+
+		app
+	}
+
+	fn exit(target: i8, translation: Vec3) -> (LevelExit, GlobalTransform) {
+		(
+			LevelExit {
+				target: LevelId(target),
+			},
+			GlobalTransform::from_translation(translation),
+		)
+	}
+
+	#[test]
+	fn depart_when_reaching_exit() {
+		let mut app = setup();
+		let exit = app.world_mut().spawn(exit(3, Vec3::new(1., 0., 1.))).id();
+		let traveler = app
+			.world_mut()
+			.spawn((
+				LevelTraveler,
+				GlobalTransform::from_translation(Vec3::new(1.5, 5., 1.)),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			(Some(&AtLevelExit(exit)), Some(&Departing(LevelId(3)))),
+			(
+				app.world().entity(traveler).get::<AtLevelExit>(),
+				app.world().entity(traveler).get::<Departing>(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_depart_when_out_of_range() {
+		let mut app = setup();
+		app.world_mut().spawn(exit(3, Vec3::new(1., 0., 1.)));
+		let traveler = app
+			.world_mut()
+			.spawn((
+				LevelTraveler,
+				GlobalTransform::from_translation(Vec3::new(2.5, 0., 1.)),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			(None, None),
+			(
+				app.world().entity(traveler).get::<AtLevelExit>(),
+				app.world().entity(traveler).get::<Departing>(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_depart_when_already_at_exit() {
+		let mut app = setup();
+		let exit = app.world_mut().spawn(exit(3, Vec3::new(1., 0., 1.))).id();
+		let traveler = app
+			.world_mut()
+			.spawn((
+				LevelTraveler,
+				AtLevelExit(exit),
+				GlobalTransform::from_translation(Vec3::new(1., 0., 1.)),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(traveler).get::<Departing>());
+	}
+
+	#[test]
+	fn do_not_depart_when_arriving() {
+		let mut app = setup();
+		app.world_mut().spawn(exit(3, Vec3::new(1., 0., 1.)));
+		let traveler = app
+			.world_mut()
+			.spawn((
+				LevelTraveler,
+				Arriving { from: LevelId(3) },
+				GlobalTransform::from_translation(Vec3::new(1., 0., 1.)),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(traveler).get::<Departing>());
+	}
+
+	#[test]
+	fn remove_at_exit_when_leaving_exit() {
+		let mut app = setup();
+		let exit = app.world_mut().spawn(exit(3, Vec3::new(1., 0., 1.))).id();
+		let traveler = app
+			.world_mut()
+			.spawn((
+				LevelTraveler,
+				AtLevelExit(exit),
+				GlobalTransform::from_translation(Vec3::new(5., 0., 1.)),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(traveler).get::<AtLevelExit>());
+	}
+
+	#[test]
+	fn ignore_non_travelers() {
+		let mut app = setup();
+		app.world_mut().spawn(exit(3, Vec3::new(1., 0., 1.)));
+		let agent = app
+			.world_mut()
+			.spawn(GlobalTransform::from_translation(Vec3::new(1., 0., 1.)))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(agent).get::<Departing>());
+	}
+}
@@ -0,0 +1,196 @@
+use crate::components::{
+	level_traveler::LevelTraveler,
+	map::objects::MapObjectOf,
+	spawned_from::SpawnedFrom,
+	spawner::Spawner,
+	spawner_active::SpawnerActive,
+};
+use bevy::{gltf::GltfMeshName, prelude::*};
+use common::prelude::*;
+
+impl LevelTraveler {
+	/// Mark agents spawned by a player spawner as travelers.
+	pub(crate) fn mark_players(
+		mut commands: ZyheedaCommands,
+		spawned: Query<(Entity, &SpawnedFrom, &MapObjectOf), Added<SpawnedFrom>>,
+		spawners: Query<(&Spawner<AgentType>, &GltfMeshName, &MapObjectOf)>,
+	) {
+		for (entity, SpawnedFrom(source), MapObjectOf(map)) in &spawned {
+			let is_player = spawners.iter().any(|(Spawner(agent), name, spawner_map)| {
+				agent == &AgentType::Player && name.0 == source.0 && &spawner_map.0 == map
+			});
+
+			if !is_player {
+				continue;
+			}
+
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert(Self);
+			});
+		}
+	}
+
+	/// Prevent spawning another player, while a traveler exists.
+	pub(crate) fn deactivate_player_spawners(
+		mut commands: ZyheedaCommands,
+		travelers: Query<(), With<Self>>,
+		spawners: Query<(Entity, &Spawner<AgentType>), With<SpawnerActive>>,
+	) {
+		if travelers.is_empty() {
+			return;
+		}
+
+		for (entity, Spawner(agent)) in &spawners {
+			if agent != &AgentType::Player {
+				continue;
+			}
+
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_remove::<SpawnerActive>();
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::map::MapObjectSource;
+	use testing::SingleThreadedApp;
+
+	mod mark_players {
+		use super::*;
+
+		fn setup() -> App {
+			let mut app = App::new().single_threaded(Update);
+
+			app.add_systems(Update, LevelTraveler::mark_players);
+
+			app
+		}
+
+		fn spawn_spawner(app: &mut App, agent: AgentType, name: &str, map: Entity) {
+			app.world_mut().spawn((
+				Spawner(agent),
+				GltfMeshName(name.to_owned()),
+				MapObjectOf(map),
+			));
+		}
+
+		#[test]
+		fn mark_player() {
+			let mut app = setup();
+			let map = app.world_mut().spawn_empty().id();
+			spawn_spawner(&mut app, AgentType::Player, "PlayerSpawn", map);
+			let agent = app
+				.world_mut()
+				.spawn((
+					SpawnedFrom(MapObjectSource("PlayerSpawn".to_owned())),
+					MapObjectOf(map),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&LevelTraveler),
+				app.world().entity(agent).get::<LevelTraveler>()
+			);
+		}
+
+		#[test]
+		fn do_not_mark_enemy() {
+			let mut app = setup();
+			let map = app.world_mut().spawn_empty().id();
+			spawn_spawner(
+				&mut app,
+				AgentType::Enemy(EnemyType::VoidSphere),
+				"VoidSphereSpawn",
+				map,
+			);
+			let agent = app
+				.world_mut()
+				.spawn((
+					SpawnedFrom(MapObjectSource("VoidSphereSpawn".to_owned())),
+					MapObjectOf(map),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(None, app.world().entity(agent).get::<LevelTraveler>());
+		}
+
+		#[test]
+		fn do_not_mark_agent_of_spawner_with_same_name_on_other_map() {
+			let mut app = setup();
+			let map = app.world_mut().spawn_empty().id();
+			let other_map = app.world_mut().spawn_empty().id();
+			spawn_spawner(&mut app, AgentType::Player, "PlayerSpawn", other_map);
+			let agent = app
+				.world_mut()
+				.spawn((
+					SpawnedFrom(MapObjectSource("PlayerSpawn".to_owned())),
+					MapObjectOf(map),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(None, app.world().entity(agent).get::<LevelTraveler>());
+		}
+	}
+
+	mod deactivate_player_spawners {
+		use super::*;
+
+		fn setup() -> App {
+			let mut app = App::new().single_threaded(Update);
+
+			app.add_systems(Update, LevelTraveler::deactivate_player_spawners);
+
+			app
+		}
+
+		#[test]
+		fn deactivate_player_spawner_when_traveler_exists() {
+			let mut app = setup();
+			app.world_mut().spawn(LevelTraveler);
+			let spawner = app.world_mut().spawn(Spawner(AgentType::Player)).id();
+
+			app.update();
+
+			assert_eq!(None, app.world().entity(spawner).get::<SpawnerActive>());
+		}
+
+		#[test]
+		fn keep_player_spawner_active_when_no_traveler_exists() {
+			let mut app = setup();
+			let spawner = app.world_mut().spawn(Spawner(AgentType::Player)).id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&SpawnerActive),
+				app.world().entity(spawner).get::<SpawnerActive>()
+			);
+		}
+
+		#[test]
+		fn keep_enemy_spawner_active() {
+			let mut app = setup();
+			app.world_mut().spawn(LevelTraveler);
+			let spawner = app
+				.world_mut()
+				.spawn(Spawner(AgentType::Enemy(EnemyType::VoidSphere)))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&SpawnerActive),
+				app.world().entity(spawner).get::<SpawnerActive>()
+			);
+		}
+	}
+}
@@ -0,0 +1,385 @@
+use crate::{
+	components::{
+		level_traveler::{Arriving, Departing, LevelTraveler},
+		map::{
+			Map,
+			level::LevelId,
+			objects::{MapObjectOf, MapObjects, PersistentMapObject},
+		},
+		map_agents::GridAgentOf,
+		spawned_from::SpawnedFrom,
+	},
+	resources::levels::Levels,
+};
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::{prelude::*, states::game_state::GameState};
+use std::fmt::Display;
+
+impl LevelTraveler {
+	/// Stash the level a [`Departing`] traveler leaves and bring up the target level.
+	///
+	/// Persistent objects of the left level are stashed alongside its map, so returning restores
+	/// them as they were left. The traveler is detached from the map and placed via [`Arriving`].
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn travel<TStash>(
+		mut commands: ZyheedaCommands,
+		mut stash: StaticSystemParam<TStash>,
+		mut next_state: ResMut<NextState<GameState>>,
+		levels: Res<Levels>,
+		travelers: Query<(Entity, &Departing, &MapObjectOf), With<Self>>,
+		mut maps: Query<(&LevelId, &PersistentEntity, &mut Map, &MapObjects)>,
+		persistent_objects: Query<Option<&SpawnedFrom>, With<PersistentEntity>>,
+	) -> Result<(), Vec<TravelError>>
+	where
+		TStash: for<'w, 's> SystemParam<Item<'w, 's>: StashEntities>,
+	{
+		let mut errors = vec![];
+
+		for (entity, Departing(target), MapObjectOf(map_entity)) in &travelers {
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_remove::<Departing>();
+			});
+
+			let Some(spawn_level) = levels.get(target) else {
+				errors.push(TravelError::UnknownLevel(*target));
+				continue;
+			};
+			let Ok((level, map_persistent, mut map, objects)) = maps.get_mut(*map_entity) else {
+				errors.push(TravelError::NotOnALevel(entity));
+				continue;
+			};
+
+			let mut stashed = vec![*map_entity];
+			for object in objects.iter() {
+				let Ok(spawned_from) = persistent_objects.get(object) else {
+					continue;
+				};
+				if let Some(SpawnedFrom(source)) = spawned_from {
+					map.disabled_object_sources.insert(source.clone());
+				}
+				if object == entity {
+					continue;
+				}
+				commands.try_apply_on(&object, |mut e| {
+					e.try_insert(PersistentMapObject {
+						map: *map_persistent,
+					});
+				});
+				stashed.push(object);
+			}
+
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_remove::<(MapObjectOf, PersistentMapObject, GridAgentOf, SpawnedFrom)>();
+				e.try_insert(Arriving { from: *level });
+			});
+
+			stash.stash_entities(StashKey::from(*level), stashed);
+			match stash.is_stashed(&StashKey::from(*target)) {
+				true => stash.unstash_entities(StashKey::from(*target)),
+				false => spawn_level(&mut commands),
+			}
+			next_state.set(GameState::LoadDependencies);
+		}
+
+		if !errors.is_empty() {
+			return Err(errors);
+		}
+
+		Ok(())
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum TravelError {
+	UnknownLevel(LevelId),
+	NotOnALevel(Entity),
+}
+
+impl Display for TravelError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			TravelError::UnknownLevel(LevelId(level)) => {
+				write!(f, "Level {level} is not registered")
+			}
+			TravelError::NotOnALevel(entity) => {
+				write!(f, "{entity}: Cannot travel, because it is not on a level")
+			}
+		}
+	}
+}
+
+impl ErrorData for TravelError {
+	fn level(&self) -> Level {
+		Level::Error
+	}
+
+	fn label() -> impl Display {
+		"Level travel failed"
+	}
+
+	fn into_details(self) -> impl Display {
+		self
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::map::{MapObjectSource, level::Level};
+	use bevy::{
+		ecs::system::{RunSystemError, RunSystemOnce},
+		state::app::StatesPlugin,
+	};
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use std::collections::HashSet;
+	use testing::{NestedMocks, SingleThreadedApp};
+
+	#[derive(Resource, NestedMocks)]
+	struct _Stash {
+		mock: Mock_Stash,
+	}
+
+	#[automock]
+	impl StashEntities for _Stash {
+		fn stash_entities(&mut self, key: StashKey, entities: Vec<Entity>) {
+			self.mock.stash_entities(key, entities);
+		}
+
+		fn unstash_entities(&mut self, key: StashKey) {
+			self.mock.unstash_entities(key);
+		}
+
+		fn is_stashed(&self, key: &StashKey) -> bool {
+			self.mock.is_stashed(key)
+		}
+	}
+
+	#[derive(Component, Debug, PartialEq)]
+	struct _Spawned;
+
+	fn setup(stash: _Stash) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_plugins((StatesPlugin, CommonPlugin::with_asset_loading(false)));
+		app.init_state::<GameState>();
+		app.insert_resource(stash);
+		app.insert_resource(Levels::from([(
+			LevelId(2),
+			(|commands| {
+				commands.spawn(_Spawned);
+			}) as fn(&mut ZyheedaCommands),
+		)]));
+
+		app
+	}
+
+	fn spawn_level(app: &mut App) -> (Entity, PersistentEntity) {
+		let persistent = PersistentEntity::default();
+		let map = app.world_mut().spawn((Level::<1>, persistent)).id();
+
+		(map, persistent)
+	}
+
+	fn spawn_traveler(app: &mut App, map: Entity, target: i8) -> Entity {
+		app.world_mut()
+			.spawn((
+				LevelTraveler,
+				PersistentEntity::default(),
+				MapObjectOf(map),
+				SpawnedFrom(MapObjectSource("PlayerSpawn".to_owned())),
+				Departing(LevelId(target)),
+			))
+			.id()
+	}
+
+	fn stash_nothing() -> _Stash {
+		_Stash::new().with_mock(|mock| {
+			mock.expect_stash_entities().return_const(());
+			mock.expect_unstash_entities().return_const(());
+			mock.expect_is_stashed().return_const(false);
+		})
+	}
+
+	#[test]
+	fn stash_level_with_persistent_objects() -> Result<(), RunSystemError> {
+		let mut app = setup(_Stash::new());
+		let (map, _) = spawn_level(&mut app);
+		let object = app
+			.world_mut()
+			.spawn((MapObjectOf(map), PersistentEntity::default()))
+			.id();
+		app.world_mut().spawn(MapObjectOf(map));
+		spawn_traveler(&mut app, map, 2);
+		app.insert_resource(_Stash::new().with_mock(|mock| {
+			mock.expect_stash_entities()
+				.times(1)
+				.withf(move |key, entities| {
+					key == &StashKey::from(LevelId(1))
+						&& HashSet::<Entity>::from_iter(entities.iter().copied())
+							== HashSet::from([map, object])
+				})
+				.return_const(());
+			mock.expect_unstash_entities().return_const(());
+			mock.expect_is_stashed().return_const(false);
+		}));
+
+		_ = app
+			.world_mut()
+			.run_system_once(LevelTraveler::travel::<ResMut<_Stash>>)?;
+		Ok(())
+	}
+
+	#[test]
+	fn mark_stashed_objects_persistent() -> Result<(), RunSystemError> {
+		let mut app = setup(stash_nothing());
+		let (map, map_persistent) = spawn_level(&mut app);
+		let object = app
+			.world_mut()
+			.spawn((
+				MapObjectOf(map),
+				PersistentEntity::default(),
+				SpawnedFrom(MapObjectSource("EnemySpawn".to_owned())),
+			))
+			.id();
+		spawn_traveler(&mut app, map, 2);
+
+		_ = app
+			.world_mut()
+			.run_system_once(LevelTraveler::travel::<ResMut<_Stash>>)?;
+
+		assert_eq!(
+			(
+				Some(&PersistentMapObject {
+					map: map_persistent
+				}),
+				Some(&Map {
+					disabled_object_sources: HashSet::from([
+						MapObjectSource("EnemySpawn".to_owned()),
+						MapObjectSource("PlayerSpawn".to_owned()),
+					])
+				})
+			),
+			(
+				app.world().entity(object).get::<PersistentMapObject>(),
+				app.world().entity(map).get::<Map>(),
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn detach_traveler_from_level() -> Result<(), RunSystemError> {
+		let mut app = setup(stash_nothing());
+		let (map, map_persistent) = spawn_level(&mut app);
+		let traveler = spawn_traveler(&mut app, map, 2);
+		app.world_mut()
+			.entity_mut(traveler)
+			.insert(PersistentMapObject {
+				map: map_persistent,
+			});
+
+		_ = app
+			.world_mut()
+			.run_system_once(LevelTraveler::travel::<ResMut<_Stash>>)?;
+
+		let traveler = app.world().entity(traveler);
+		assert_eq!(
+			(None, None, None, None, Some(&Arriving { from: LevelId(1) })),
+			(
+				traveler.get::<MapObjectOf>(),
+				traveler.get::<PersistentMapObject>(),
+				traveler.get::<SpawnedFrom>(),
+				traveler.get::<Departing>(),
+				traveler.get::<Arriving>(),
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn spawn_target_level_when_not_stashed() -> Result<(), RunSystemError> {
+		let mut app = setup(_Stash::new().with_mock(|mock| {
+			mock.expect_stash_entities().return_const(());
+			mock.expect_is_stashed()
+				.with(eq(StashKey::from(LevelId(2))))
+				.return_const(false);
+			mock.expect_unstash_entities().never();
+		}));
+		let (map, _) = spawn_level(&mut app);
+		spawn_traveler(&mut app, map, 2);
+
+		_ = app
+			.world_mut()
+			.run_system_once(LevelTraveler::travel::<ResMut<_Stash>>)?;
+
+		let mut spawned = app.world_mut().query::<&_Spawned>();
+		assert_eq!(1, spawned.iter(app.world()).count());
+		Ok(())
+	}
+
+	#[test]
+	fn unstash_target_level_when_stashed() -> Result<(), RunSystemError> {
+		let mut app = setup(_Stash::new().with_mock(|mock| {
+			mock.expect_stash_entities().return_const(());
+			mock.expect_is_stashed()
+				.with(eq(StashKey::from(LevelId(2))))
+				.return_const(true);
+			mock.expect_unstash_entities()
+				.times(1)
+				.with(eq(StashKey::from(LevelId(2))))
+				.return_const(());
+		}));
+		let (map, _) = spawn_level(&mut app);
+		spawn_traveler(&mut app, map, 2);
+
+		_ = app
+			.world_mut()
+			.run_system_once(LevelTraveler::travel::<ResMut<_Stash>>)?;
+
+		let mut spawned = app.world_mut().query::<&_Spawned>();
+		assert_eq!(0, spawned.iter(app.world()).count());
+		Ok(())
+	}
+
+	#[test]
+	fn load_target_level() -> Result<(), RunSystemError> {
+		let mut app = setup(stash_nothing());
+		let (map, _) = spawn_level(&mut app);
+		spawn_traveler(&mut app, map, 2);
+
+		_ = app
+			.world_mut()
+			.run_system_once(LevelTraveler::travel::<ResMut<_Stash>>)?;
+
+		assert!(matches!(
+			app.world().resource::<NextState<GameState>>(),
+			NextState::Pending(GameState::LoadDependencies)
+		));
+		Ok(())
+	}
+
+	#[test]
+	fn unknown_level_error() -> Result<(), RunSystemError> {
+		let mut app = setup(_Stash::new().with_mock(|mock| {
+			mock.expect_stash_entities().never();
+			mock.expect_unstash_entities().never();
+			mock.expect_is_stashed().never();
+		}));
+		let (map, _) = spawn_level(&mut app);
+		let traveler = spawn_traveler(&mut app, map, 5);
+
+		let result = app
+			.world_mut()
+			.run_system_once(LevelTraveler::travel::<ResMut<_Stash>>)?;
+
+		assert_eq!(
+			(Err(vec![TravelError::UnknownLevel(LevelId(5))]), None),
+			(result, app.world().entity(traveler).get::<Departing>())
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,79 @@
+use crate::components::map::level::LevelId;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::traits::handles_saving::WriteSlotMetadata;
+
+impl LevelId {
+	pub(crate) fn write_metadata<TMetadata>(
+		levels: Query<&Self>,
+		mut metadata: StaticSystemParam<TMetadata>,
+	) where
+		TMetadata: for<'w, 's> SystemParam<Item<'w, 's>: WriteSlotMetadata>,
+	{
+		let Ok(LevelId(level)) = levels.single() else {
+			return;
+		};
+
+		metadata.write_level(*level);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::attributes::health::Health;
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use testing::{NestedMocks, SingleThreadedApp};
+
+	#[derive(Resource, NestedMocks)]
+	struct _Metadata {
+		mock: Mock_Metadata,
+	}
+
+	#[automock]
+	impl WriteSlotMetadata for _Metadata {
+		fn write_level(&mut self, level: i8) {
+			self.mock.write_level(level);
+		}
+
+		fn write_player_health(&mut self, health: Health) {
+			self.mock.write_player_health(health);
+		}
+	}
+
+	fn setup(metadata: _Metadata) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(metadata);
+
+		app
+	}
+
+	#[test]
+	fn write_level() -> Result<(), RunSystemError> {
+		let mut app = setup(_Metadata::new().with_mock(|mock| {
+			mock.expect_write_level()
+				.times(1)
+				.with(eq(-2))
+				.return_const(());
+		}));
+		app.world_mut().spawn(LevelId(-2));
+
+		app.world_mut()
+			.run_system_once(LevelId::write_metadata::<ResMut<_Metadata>>)
+	}
+
+	#[test]
+	fn do_not_write_level_when_no_level_spawned() -> Result<(), RunSystemError> {
+		let mut app = setup(_Metadata::new().with_mock(|mock| {
+			mock.expect_write_level().never();
+		}));
+
+		app.world_mut()
+			.run_system_once(LevelId::write_metadata::<ResMut<_Metadata>>)
+	}
+}
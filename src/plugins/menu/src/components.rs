@@ -14,6 +14,8 @@ pub(crate) mod loading_screen;
 pub(crate) mod menu_background;
 pub(crate) mod pause_menu;
 pub(crate) mod prevent_menu_change;
+pub(crate) mod progression_screen;
+pub(crate) mod quickbar_cooldown;
 pub(crate) mod quickbar_panel;
 pub(crate) mod settings_screen;
 pub(crate) mod start_menu;
@@ -1,17 +1,121 @@
-use crate::{
-	components::menu_background::MenuBackground,
-	traits::{LoadUi, insert_ui_content::InsertUiContent},
+pub(crate) mod save_slot_button;
+
+use super::{
+	menu_background::{MenuBackground, WithOverride},
+	ui_disabled::UIDisabled,
 };
+use crate::traits::{LoadUi, colors::PanelColors, insert_ui_content::InsertUiContent};
 use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
 use common::prelude::*;
+use save_slot_button::SaveSlotButton;
+use std::time::Duration;
+
+#[derive(Component, Debug, PartialEq, Default)]
+#[require(MenuBackground = MenuBackground::default().with(FlexDirection::Column))]
+pub(crate) struct PauseMenu {
+	pub(crate) slots: Vec<(SaveSlot, Option<SlotMetadata>)>,
+}
+
+impl PauseMenu {
+	fn font(size: f32) -> TextFont {
+		TextFont {
+			font_size: FontSize::Px(size),
+			..default()
+		}
+	}
+
+	fn add_slot_row(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		slot: SaveSlot,
+		metadata: &Option<SlotMetadata>,
+	) {
+		let SaveSlot::Manual(index) = slot else {
+			return;
+		};
+		let color = TextColor::from(PanelColors::DEFAULT.filled.text);
+		let label = Token::from("save-slot").with_arg("slot", u32::from(index));
+		let details = match metadata {
+			None => vec![Token::from("save-slot-empty")],
+			Some(SlotMetadata {
+				level, play_time, ..
+			}) => level
+				.map(|level| Token::from("save-slot-level").with_arg("level", f64::from(level)))
+				.into_iter()
+				.chain([Token::from("save-slot-play-time").with_arg("time", clock(*play_time))])
+				.collect(),
+		};
+
+		parent
+			.spawn(Node {
+				width: Val::Px(600.),
+				flex_direction: FlexDirection::Row,
+				align_items: AlignItems::Center,
+				justify_content: JustifyContent::SpaceBetween,
+				padding: UiRect::all(Val::Px(2.)),
+				margin: UiRect::all(Val::Px(2.)),
+				..default()
+			})
+			.insert(BackgroundColor(PanelColors::DEFAULT.empty.background))
+			.with_children(|parent| {
+				parent.spawn((
+					Text::from(localize.localize(&label).or_token()),
+					Self::font(20.),
+					color,
+				));
+				for token in details {
+					parent.spawn((
+						Text::from(localize.localize(&token).or_token()),
+						Self::font(15.),
+						color,
+					));
+				}
+				Self::add_button(
+					parent,
+					localize,
+					SlotRequest::Save(slot),
+					Token::from("save-slot-save"),
+					false,
+				);
+				Self::add_button(
+					parent,
+					localize,
+					SlotRequest::Load(slot),
+					Token::from("save-slot-load"),
+					metadata.is_none(),
+				);
+			});
+	}
+
+	fn add_button(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		request: SlotRequest,
+		label: Token,
+		disabled: bool,
+	) {
+		let mut button = parent.spawn(SaveSlotButton(request));
+		if disabled {
+			button.insert(UIDisabled);
+		}
+		button.with_child((
+			Text::from(localize.localize(&label).or_token()),
+			Self::font(15.),
+			TextColor::from(PanelColors::DEFAULT.filled.text),
+		));
+	}
+}
+
+/// Formats as `h:mm:ss`
+fn clock(duration: Duration) -> String {
+	let secs = duration.as_secs();
 
-#[derive(Component, Debug, PartialEq)]
-#[require(MenuBackground)]
-pub(crate) struct PauseMenu;
+	format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
 
 impl LoadUi<AssetServer> for PauseMenu {
 	fn load_ui(_: &mut AssetServer) -> Self {
-		Self
+		Self::default()
 	}
 }
 
@@ -25,10 +129,23 @@ impl InsertUiContent for PauseMenu {
 	{
 		parent.spawn((
 			Text::from(localization.localize_token("paused").or_token()),
-			TextFont {
-				font_size: FontSize::Px(40.0),
-				..default()
-			},
+			Self::font(40.),
 		));
+		for (slot, metadata) in &self.slots {
+			Self::add_slot_row(parent, localization, *slot, metadata);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	#[test_case(Duration::ZERO, "0:00:00"; "zero")]
+	#[test_case(Duration::from_secs(59), "0:00:59"; "seconds")]
+	#[test_case(Duration::from_secs(61 * 60 + 5), "1:01:05"; "hours")]
+	fn format_clock(duration: Duration, expected: &str) {
+		assert_eq!(expected, clock(duration));
 	}
 }
@@ -0,0 +1,33 @@
+use crate::{
+	tools::PanelState,
+	traits::colors::{HasPanelColors, PanelColors},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[require(Button, Node = Self::node())]
+pub(crate) struct SaveSlotButton(pub(crate) SlotRequest);
+
+impl SaveSlotButton {
+	fn node() -> Node {
+		Node {
+			width: Val::Px(100.0),
+			height: Val::Px(30.0),
+			margin: UiRect::all(Val::Px(2.0)),
+			justify_content: JustifyContent::Center,
+			align_items: AlignItems::Center,
+			..default()
+		}
+	}
+}
+
+impl View<PanelState> for SaveSlotButton {
+	fn view(&self) -> PanelState {
+		PanelState::Empty
+	}
+}
+
+impl HasPanelColors for SaveSlotButton {
+	const PANEL_COLORS: PanelColors = PanelColors::DEFAULT;
+}
@@ -0,0 +1,206 @@
+pub(crate) mod upgrade_button;
+
+use super::{
+	menu_background::{MenuBackground, WithOverride},
+	ui_disabled::UIDisabled,
+};
+use crate::traits::{LoadUi, colors::PanelColors, insert_ui_content::InsertUiContent};
+use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
+use common::{prelude::*, states::menu_state::MenuState};
+use std::fmt::Debug;
+use upgrade_button::UpgradeButton;
+
+#[derive(Component, Debug, PartialEq)]
+#[require(
+	MenuBackground = MenuBackground::default().with(FlexDirection::Column),
+	Name = "Progression",
+)]
+pub(crate) struct ProgressionScreen<TId>
+where
+	TId: Debug + PartialEq + Clone + ThreadSafe,
+{
+	pub(crate) progress: LevelProgress,
+	pub(crate) skill_upgrades: Vec<SkillUpgrades<TId>>,
+}
+
+impl<TId> Default for ProgressionScreen<TId>
+where
+	TId: Debug + PartialEq + Clone + ThreadSafe,
+{
+	fn default() -> Self {
+		Self {
+			progress: LevelProgress::default(),
+			skill_upgrades: vec![],
+		}
+	}
+}
+
+impl<TId> ProgressionScreen<TId>
+where
+	TId: Debug + PartialEq + Clone + ThreadSafe,
+{
+	fn font(size: f32) -> TextFont {
+		TextFont {
+			font_size: FontSize::Px(size),
+			..default()
+		}
+	}
+
+	fn add_text(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		token: Token,
+		size: f32,
+	) {
+		parent.spawn((
+			Text::from(localize.localize(&token).or_token()),
+			Self::font(size),
+		));
+	}
+
+	fn add_progress(&self, parent: &mut RelatedSpawnerCommands<ChildOf>, localize: &impl Localize) {
+		let LevelProgress {
+			level,
+			experience,
+			next_level,
+			skill_points,
+		} = self.progress;
+
+		Self::add_text(
+			parent,
+			localize,
+			Token::from("progression-level").with_arg("level", level),
+			20.,
+		);
+		Self::add_text(
+			parent,
+			localize,
+			Token::from("progression-experience")
+				.with_arg("experience", experience)
+				.with_arg("next", next_level),
+			15.,
+		);
+		Self::add_text(
+			parent,
+			localize,
+			Token::from("progression-skill-points").with_arg("points", skill_points),
+			15.,
+		);
+	}
+
+	fn add_skill_section(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		SkillUpgrades {
+			skill,
+			token,
+			upgrades,
+		}: &SkillUpgrades<TId>,
+	) {
+		parent
+			.spawn((
+				Node {
+					width: Val::Px(400.),
+					flex_direction: FlexDirection::Column,
+					padding: UiRect::all(Val::Px(2.)),
+					margin: UiRect::all(Val::Px(2.)),
+					..default()
+				},
+				BackgroundColor(PanelColors::DEFAULT.empty.background),
+			))
+			.with_children(|parent| {
+				Self::add_text(parent, localize, token.clone(), 20.);
+				for upgrade in upgrades {
+					Self::add_upgrade_row(parent, localize, skill, upgrade);
+				}
+			});
+	}
+
+	fn add_upgrade_row(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		skill: &TId,
+		Upgrade {
+			id,
+			token,
+			cost,
+			state,
+		}: &Upgrade,
+	) {
+		let color = TextColor::from(PanelColors::DEFAULT.filled.text);
+		let label = Token::from("progression-upgrade")
+			.with_arg("upgrade", token.clone())
+			.with_arg("cost", *cost);
+		let button_label = match state {
+			UpgradeState::Acquired => Token::from("progression-upgrade-acquired"),
+			UpgradeState::Available | UpgradeState::Locked => {
+				Token::from("progression-upgrade-acquire")
+			}
+		};
+
+		parent
+			.spawn(Node {
+				flex_direction: FlexDirection::Row,
+				align_items: AlignItems::Center,
+				justify_content: JustifyContent::SpaceBetween,
+				..default()
+			})
+			.with_children(|parent| {
+				parent.spawn((
+					Text::from(localize.localize(&label).or_token()),
+					Self::font(15.),
+					color,
+				));
+				let mut button = parent.spawn(UpgradeButton {
+					skill: skill.clone(),
+					upgrade: id.clone(),
+					state: *state,
+				});
+				if state == &UpgradeState::Locked {
+					button.insert(UIDisabled);
+				}
+				button.with_child((
+					Text::from(localize.localize(&button_label).or_token()),
+					Self::font(15.),
+					color,
+				));
+			});
+	}
+}
+
+impl<TId, TAssetServer> LoadUi<TAssetServer> for ProgressionScreen<TId>
+where
+	TId: Debug + PartialEq + Clone + ThreadSafe,
+	TAssetServer: LoadAsset,
+{
+	fn load_ui(_: &mut TAssetServer) -> Self {
+		Self::default()
+	}
+}
+
+impl<TId> InsertUiContent for ProgressionScreen<TId>
+where
+	TId: Debug + PartialEq + Clone + ThreadSafe,
+{
+	fn insert_ui_content<TLocalization>(
+		&self,
+		localize: &TLocalization,
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+	) where
+		TLocalization: Localize,
+	{
+		parent
+			.spawn(Node {
+				flex_direction: FlexDirection::Column,
+				align_items: AlignItems::Center,
+				..default()
+			})
+			.with_children(|parent| {
+				Self::add_text(parent, localize, Token::from(MenuState::Progression), 40.);
+				self.add_progress(parent, localize);
+				for skill_upgrades in &self.skill_upgrades {
+					Self::add_skill_section(parent, localize, skill_upgrades);
+				}
+			});
+	}
+}
@@ -0,0 +1,52 @@
+use crate::{
+	tools::PanelState,
+	traits::colors::{HasPanelColors, PanelColors},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Clone)]
+#[require(Button, Node = Self::node())]
+pub(crate) struct UpgradeButton<TId>
+where
+	TId: ThreadSafe,
+{
+	pub(crate) skill: TId,
+	pub(crate) upgrade: UpgradeId,
+	pub(crate) state: UpgradeState,
+}
+
+impl<TId> UpgradeButton<TId>
+where
+	TId: ThreadSafe,
+{
+	fn node() -> Node {
+		Node {
+			width: Val::Px(100.0),
+			height: Val::Px(20.0),
+			margin: UiRect::all(Val::Px(2.0)),
+			justify_content: JustifyContent::Center,
+			align_items: AlignItems::Center,
+			..default()
+		}
+	}
+}
+
+impl<TId> View<PanelState> for UpgradeButton<TId>
+where
+	TId: ThreadSafe,
+{
+	fn view(&self) -> PanelState {
+		match self.state {
+			UpgradeState::Acquired => PanelState::Filled,
+			UpgradeState::Available | UpgradeState::Locked => PanelState::Empty,
+		}
+	}
+}
+
+impl<TId> HasPanelColors for UpgradeButton<TId>
+where
+	TId: ThreadSafe,
+{
+	const PANEL_COLORS: PanelColors = PanelColors::DEFAULT;
+}
@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Overlay of a quickbar panel, which shrinks while the panel's skill is cooling down.
+#[derive(Component, Debug, PartialEq)]
+#[require(Node = Self::node(), BackgroundColor = Self::COLOR)]
+pub struct QuickbarCooldown {
+	pub key: HandSlot,
+}
+
+impl QuickbarCooldown {
+	const COLOR: BackgroundColor = BackgroundColor(Color::srgba(0., 0., 0., 0.6));
+
+	fn node() -> Node {
+		Node {
+			position_type: PositionType::Absolute,
+			left: Val::Px(0.),
+			bottom: Val::Px(0.),
+			width: Val::Percent(100.),
+			height: Val::Percent(0.),
+			..default()
+		}
+	}
+}
@@ -1,4 +1,6 @@
+pub(crate) mod general;
 pub(crate) mod key_bind;
+pub(crate) mod volume;
 
 use super::menu_background::MenuBackground;
 use crate::{
@@ -11,8 +13,10 @@ use crate::{
 };
 use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
 use common::{prelude::*, states::menu_state::MenuState};
+use general::{Setting, SettingControl, SettingLabel};
 use key_bind::{KeyBind, action::Action, input::Input};
 use std::collections::HashMap;
+use volume::{VolumeControl, VolumeLabel};
 
 #[derive(Component, Debug, PartialEq, Default)]
 #[require(MenuBackground)]
@@ -81,6 +85,144 @@ impl SettingsScreen {
 			});
 	}
 
+	fn add_volume_section(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		title: impl Into<Token>,
+	) {
+		parent
+			.spawn((
+				Node {
+					width: Val::Px(400.),
+					justify_content: JustifyContent::Center,
+					flex_direction: FlexDirection::Column,
+					padding: UiRect::all(Val::Px(2.)),
+					margin: UiRect::all(Val::Px(2.)),
+					..default()
+				},
+				BackgroundColor(PanelColors::DEFAULT.empty.background),
+			))
+			.with_children(|parent| {
+				Self::add_section_title(parent, localize, title);
+				for category in AudioCategory::iterator() {
+					Self::add_volume_row(parent, localize, category);
+				}
+			});
+	}
+
+	fn add_volume_row(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		category: AudioCategory,
+	) {
+		let font = || TextFont {
+			font_size: FontSize::Px(15.0),
+			..default()
+		};
+		let color = TextColor::from(PanelColors::DEFAULT.filled.text);
+
+		parent
+			.spawn(Node {
+				flex_direction: FlexDirection::Row,
+				align_items: AlignItems::Center,
+				..default()
+			})
+			.with_children(|parent| {
+				parent
+					.spawn(Node {
+						width: Val::Percent(50.),
+						margin: UiRect::all(Val::Px(2.0)),
+						padding: UiRect::all(Val::Px(2.0)),
+						..default()
+					})
+					.with_child((
+						Text::from(localize.localize_token(category).or_token()),
+						font(),
+						color,
+					));
+				parent.spawn(VolumeControl::decrease(category)).with_child((
+					Text::from("-"),
+					font(),
+					color,
+				));
+				parent.spawn((VolumeLabel(category), font(), color));
+				parent.spawn(VolumeControl::increase(category)).with_child((
+					Text::from("+"),
+					font(),
+					color,
+				));
+			});
+	}
+
+	fn add_general_section(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		title: impl Into<Token>,
+	) {
+		parent
+			.spawn((
+				Node {
+					width: Val::Px(400.),
+					justify_content: JustifyContent::Center,
+					flex_direction: FlexDirection::Column,
+					padding: UiRect::all(Val::Px(2.)),
+					margin: UiRect::all(Val::Px(2.)),
+					..default()
+				},
+				BackgroundColor(PanelColors::DEFAULT.empty.background),
+			))
+			.with_children(|parent| {
+				Self::add_section_title(parent, localize, title);
+				for setting in Setting::iterator() {
+					Self::add_setting_row(parent, localize, setting);
+				}
+			});
+	}
+
+	fn add_setting_row(
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+		localize: &impl Localize,
+		setting: Setting,
+	) {
+		let font = || TextFont {
+			font_size: FontSize::Px(15.0),
+			..default()
+		};
+		let color = TextColor::from(PanelColors::DEFAULT.filled.text);
+
+		parent
+			.spawn(Node {
+				flex_direction: FlexDirection::Row,
+				align_items: AlignItems::Center,
+				..default()
+			})
+			.with_children(|parent| {
+				parent
+					.spawn(Node {
+						width: Val::Percent(50.),
+						margin: UiRect::all(Val::Px(2.0)),
+						padding: UiRect::all(Val::Px(2.0)),
+						..default()
+					})
+					.with_child((
+						Text::from(localize.localize_token(setting).or_token()),
+						font(),
+						color,
+					));
+				parent.spawn(SettingControl::decrease(setting)).with_child((
+					Text::from("<"),
+					font(),
+					color,
+				));
+				parent.spawn((SettingLabel(setting), font(), color));
+				parent.spawn(SettingControl::increase(setting)).with_child((
+					Text::from(">"),
+					font(),
+					color,
+				));
+			});
+	}
+
 	fn add_key_bindings<T>(
 		&self,
 		parent: &mut RelatedSpawnerCommands<ChildOf>,
@@ -143,6 +285,8 @@ impl InsertUiContent for SettingsScreen {
 				..default()
 			})
 			.with_children(|parent| {
+				Self::add_title(parent, localize, "settings");
+				Self::add_general_section(parent, localize, "settings-display");
 				Self::add_title(parent, localize, "key-bindings");
 				self.add_section(parent, HandSlot::iterator(), localize, "key-bindings-slots");
 				self.add_section(
@@ -177,6 +321,8 @@ impl InsertUiContent for SettingsScreen {
 					localize,
 					"key-bindings-savegame",
 				);
+				Self::add_title(parent, localize, "audio");
+				Self::add_volume_section(parent, localize, "audio-volumes");
 			});
 	}
 }
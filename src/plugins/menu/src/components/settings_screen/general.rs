@@ -0,0 +1,360 @@
+use crate::{
+	tools::PanelState,
+	traits::colors::{HasPanelColors, PanelColors},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use unic_langid::LanguageIdentifier;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub(crate) enum Setting {
+	FpsCap,
+	Vsync,
+	WindowMode,
+	ResolutionScale,
+	PostProcessing,
+	Outlines,
+	CameraSensitivity,
+	Language,
+	Targeting(HandSlot),
+}
+
+impl Setting {
+	const FPS_STEP: i32 = 5;
+	const FPS_RANGE: (i32, i32) = (10, 60);
+	const SCALE_STEP: f32 = 0.1;
+	const RESOLUTION_SCALE_RANGE: (f32, f32) = (0.5, 2.);
+	const SENSITIVITY_RANGE: (f32, f32) = (0.1, 5.);
+	const DISPLAY_MODES: [DisplayMode; 3] = [
+		DisplayMode::Windowed,
+		DisplayMode::BorderlessFullscreen,
+		DisplayMode::Fullscreen,
+	];
+	const TARGETING_MODES: [TargetingMode; 4] = [
+		TargetingMode::Cursor,
+		TargetingMode::AutoTarget,
+		TargetingMode::LockOn,
+		TargetingMode::OnSelf,
+	];
+
+	/// Moves the setting by the given number of steps. Toggles, window modes, languages and
+	/// targeting modes cycle through their values, numeric settings are clamped to a sane range.
+	pub(crate) fn adjust(
+		self,
+		settings: &mut GeneralSettings,
+		steps: i32,
+		locales: &[LanguageIdentifier],
+	) {
+		match self {
+			Self::FpsCap => {
+				let (min, max) = Self::FPS_RANGE;
+				let fps = settings.fps_cap as i32 + steps * Self::FPS_STEP;
+				settings.fps_cap = fps.clamp(min, max) as u32;
+			}
+			Self::Vsync => settings.vsync = toggle(settings.vsync, steps),
+			Self::WindowMode => {
+				let current = Self::DISPLAY_MODES
+					.iter()
+					.position(|mode| mode == &settings.window_mode)
+					.unwrap_or_default() as i32;
+				let count = Self::DISPLAY_MODES.len() as i32;
+				let next = (current + steps).rem_euclid(count) as usize;
+				settings.window_mode = Self::DISPLAY_MODES[next];
+			}
+			Self::ResolutionScale => {
+				settings.resolution_scale = scale(
+					settings.resolution_scale,
+					steps,
+					Self::RESOLUTION_SCALE_RANGE,
+				);
+			}
+			Self::PostProcessing => {
+				settings.post_processing = toggle(settings.post_processing, steps);
+			}
+			Self::Outlines => settings.outlines = toggle(settings.outlines, steps),
+			Self::CameraSensitivity => {
+				settings.camera_sensitivity =
+					scale(settings.camera_sensitivity, steps, Self::SENSITIVITY_RANGE);
+			}
+			Self::Language => {
+				if locales.is_empty() {
+					return;
+				}
+				let current = locales
+					.iter()
+					.position(|locale| locale == &settings.language)
+					.unwrap_or_default() as i32;
+				let count = locales.len() as i32;
+				let next = (current + steps).rem_euclid(count) as usize;
+				settings.language = locales[next].clone();
+			}
+			Self::Targeting(slot) => {
+				let current = Self::TARGETING_MODES
+					.iter()
+					.position(|mode| mode == &settings.targeting(slot))
+					.unwrap_or_default() as i32;
+				let count = Self::TARGETING_MODES.len() as i32;
+				let next = (current + steps).rem_euclid(count) as usize;
+				settings.targeting.insert(slot, Self::TARGETING_MODES[next]);
+			}
+		}
+	}
+
+	pub(crate) fn label<TLocalize>(self, settings: &GeneralSettings, localize: &TLocalize) -> String
+	where
+		TLocalize: Localize,
+	{
+		let on_off = |value: bool| match value {
+			true => localize.localize_token("settings-on").or_token(),
+			false => localize.localize_token("settings-off").or_token(),
+		};
+
+		match self {
+			Self::FpsCap => settings.fps_cap.to_string(),
+			Self::Vsync => on_off(settings.vsync).to_string(),
+			Self::WindowMode => localize
+				.localize_token(settings.window_mode)
+				.or_token()
+				.to_string(),
+			Self::ResolutionScale => format!("{:.1}x", settings.resolution_scale),
+			Self::PostProcessing => on_off(settings.post_processing).to_string(),
+			Self::Outlines => on_off(settings.outlines).to_string(),
+			Self::CameraSensitivity => format!("{:.1}", settings.camera_sensitivity),
+			Self::Language => {
+				let language = &settings.language;
+				let token = format!("language-{}", language.to_string().to_lowercase());
+				localize
+					.localize_token(token)
+					.or_string(|| language.to_string())
+					.to_string()
+			}
+			Self::Targeting(slot) => localize
+				.localize_token(settings.targeting(slot))
+				.or_token()
+				.to_string(),
+		}
+	}
+}
+
+fn toggle(value: bool, steps: i32) -> bool {
+	match steps % 2 {
+		0 => value,
+		_ => !value,
+	}
+}
+
+fn scale(value: f32, steps: i32, (min, max): (f32, f32)) -> f32 {
+	let value = value + steps as f32 * Setting::SCALE_STEP;
+	((value * 10.).round() / 10.).clamp(min, max)
+}
+
+impl From<Setting> for Token {
+	fn from(setting: Setting) -> Self {
+		match setting {
+			Setting::FpsCap => Self::from("settings-fps-cap"),
+			Setting::Vsync => Self::from("settings-vsync"),
+			Setting::WindowMode => Self::from("settings-window-mode"),
+			Setting::ResolutionScale => Self::from("settings-resolution-scale"),
+			Setting::PostProcessing => Self::from("settings-post-processing"),
+			Setting::Outlines => Self::from("settings-outlines"),
+			Setting::CameraSensitivity => Self::from("settings-camera-sensitivity"),
+			Setting::Language => Self::from("settings-language"),
+			Setting::Targeting(HandSlot::Left) => Self::from("settings-targeting-left"),
+			Setting::Targeting(HandSlot::Right) => Self::from("settings-targeting-right"),
+		}
+	}
+}
+
+impl IterFinite for Setting {
+	fn iterator() -> FiniteIter<Self> {
+		FiniteIter(Some(Self::FpsCap))
+	}
+
+	fn next(FiniteIter(current): &FiniteIter<Self>) -> Option<Self> {
+		match current.as_ref()? {
+			Self::FpsCap => Some(Self::Vsync),
+			Self::Vsync => Some(Self::WindowMode),
+			Self::WindowMode => Some(Self::ResolutionScale),
+			Self::ResolutionScale => Some(Self::PostProcessing),
+			Self::PostProcessing => Some(Self::Outlines),
+			Self::Outlines => Some(Self::CameraSensitivity),
+			Self::CameraSensitivity => Some(Self::Language),
+			Self::Language => Some(Self::Targeting(HandSlot::Left)),
+			Self::Targeting(HandSlot::Left) => Some(Self::Targeting(HandSlot::Right)),
+			Self::Targeting(HandSlot::Right) => None,
+		}
+	}
+}
+
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[require(Button, Node = Self::node())]
+pub(crate) struct SettingControl {
+	pub(crate) setting: Setting,
+	pub(crate) steps: i32,
+}
+
+impl SettingControl {
+	pub(crate) fn decrease(setting: Setting) -> Self {
+		Self { setting, steps: -1 }
+	}
+
+	pub(crate) fn increase(setting: Setting) -> Self {
+		Self { setting, steps: 1 }
+	}
+
+	fn node() -> Node {
+		Node {
+			width: Val::Px(20.0),
+			height: Val::Px(20.0),
+			margin: UiRect::all(Val::Px(2.0)),
+			justify_content: JustifyContent::Center,
+			align_items: AlignItems::Center,
+			..default()
+		}
+	}
+}
+
+impl View<PanelState> for SettingControl {
+	fn view(&self) -> PanelState {
+		PanelState::Filled
+	}
+}
+
+impl HasPanelColors for SettingControl {
+	const PANEL_COLORS: PanelColors = PanelColors::DEFAULT;
+}
+
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[require(Text, Node = Self::node())]
+pub(crate) struct SettingLabel(pub(crate) Setting);
+
+impl SettingLabel {
+	fn node() -> Node {
+		Node {
+			width: Val::Px(100.0),
+			justify_content: JustifyContent::Center,
+			..default()
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::HashMap;
+	use test_case::test_case;
+	use unic_langid::langid;
+
+	#[test]
+	fn iterate() {
+		assert_eq!(
+			vec![
+				Setting::FpsCap,
+				Setting::Vsync,
+				Setting::WindowMode,
+				Setting::ResolutionScale,
+				Setting::PostProcessing,
+				Setting::Outlines,
+				Setting::CameraSensitivity,
+				Setting::Language,
+				Setting::Targeting(HandSlot::Left),
+				Setting::Targeting(HandSlot::Right),
+			],
+			Setting::iterator().take(100).collect::<Vec<_>>()
+		);
+	}
+
+	#[test_case(30, 1, 35; "increase")]
+	#[test_case(30, -1, 25; "decrease")]
+	#[test_case(60, 1, 60; "clamp max")]
+	#[test_case(10, -1, 10; "clamp min")]
+	fn adjust_fps_cap(fps_cap: u32, steps: i32, expected: u32) {
+		let mut settings = GeneralSettings {
+			fps_cap,
+			..default()
+		};
+
+		Setting::FpsCap.adjust(&mut settings, steps, &[]);
+
+		assert_eq!(expected, settings.fps_cap);
+	}
+
+	#[test_case(true, 1, false; "toggle on")]
+	#[test_case(false, -1, true; "toggle off")]
+	#[test_case(true, 2, true; "toggle twice")]
+	fn adjust_vsync(vsync: bool, steps: i32, expected: bool) {
+		let mut settings = GeneralSettings { vsync, ..default() };
+
+		Setting::Vsync.adjust(&mut settings, steps, &[]);
+
+		assert_eq!(expected, settings.vsync);
+	}
+
+	#[test_case(DisplayMode::Windowed, 1, DisplayMode::BorderlessFullscreen; "next")]
+	#[test_case(DisplayMode::Fullscreen, 1, DisplayMode::Windowed; "wrap forward")]
+	#[test_case(DisplayMode::Windowed, -1, DisplayMode::Fullscreen; "wrap backward")]
+	fn adjust_window_mode(window_mode: DisplayMode, steps: i32, expected: DisplayMode) {
+		let mut settings = GeneralSettings {
+			window_mode,
+			..default()
+		};
+
+		Setting::WindowMode.adjust(&mut settings, steps, &[]);
+
+		assert_eq!(expected, settings.window_mode);
+	}
+
+	#[test_case(1., 1, 1.1; "increase")]
+	#[test_case(1., -1, 0.9; "decrease")]
+	#[test_case(2., 1, 2.; "clamp max")]
+	#[test_case(0.5, -1, 0.5; "clamp min")]
+	fn adjust_resolution_scale(resolution_scale: f32, steps: i32, expected: f32) {
+		let mut settings = GeneralSettings {
+			resolution_scale,
+			..default()
+		};
+
+		Setting::ResolutionScale.adjust(&mut settings, steps, &[]);
+
+		assert_eq!(expected, settings.resolution_scale);
+	}
+
+	#[test_case(langid!("en-US"), 1, langid!("fr"); "next")]
+	#[test_case(langid!("fr"), 1, langid!("de"); "wrap forward")]
+	#[test_case(langid!("de"), -1, langid!("fr"); "wrap backward")]
+	#[test_case(langid!("it"), 1, langid!("en-US"); "step from first when unknown")]
+	fn adjust_language(language: LanguageIdentifier, steps: i32, expected: LanguageIdentifier) {
+		let mut settings = GeneralSettings {
+			language,
+			..default()
+		};
+
+		Setting::Language.adjust(
+			&mut settings,
+			steps,
+			&[langid!("de"), langid!("en-US"), langid!("fr")],
+		);
+
+		assert_eq!(expected, settings.language);
+	}
+
+	#[test_case(TargetingMode::Cursor, 1, TargetingMode::AutoTarget; "next")]
+	#[test_case(TargetingMode::OnSelf, 1, TargetingMode::Cursor; "wrap forward")]
+	#[test_case(TargetingMode::Cursor, -1, TargetingMode::OnSelf; "wrap backward")]
+	fn adjust_targeting(mode: TargetingMode, steps: i32, expected: TargetingMode) {
+		let mut settings = GeneralSettings {
+			targeting: HashMap::from([(HandSlot::Right, mode)]),
+			..default()
+		};
+
+		Setting::Targeting(HandSlot::Right).adjust(&mut settings, steps, &[]);
+
+		assert_eq!(
+			(expected, TargetingMode::Cursor),
+			(
+				settings.targeting(HandSlot::Right),
+				settings.targeting(HandSlot::Left)
+			),
+		);
+	}
+}
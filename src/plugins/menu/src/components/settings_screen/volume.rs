@@ -0,0 +1,66 @@
+use crate::{
+	tools::PanelState,
+	traits::colors::{HasPanelColors, PanelColors},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[require(Button, Node = Self::node())]
+pub(crate) struct VolumeControl {
+	pub(crate) category: AudioCategory,
+	pub(crate) step: f32,
+}
+
+impl VolumeControl {
+	const STEP: f32 = 0.1;
+
+	pub(crate) fn decrease(category: AudioCategory) -> Self {
+		Self {
+			category,
+			step: -Self::STEP,
+		}
+	}
+
+	pub(crate) fn increase(category: AudioCategory) -> Self {
+		Self {
+			category,
+			step: Self::STEP,
+		}
+	}
+
+	fn node() -> Node {
+		Node {
+			width: Val::Px(20.0),
+			height: Val::Px(20.0),
+			margin: UiRect::all(Val::Px(2.0)),
+			justify_content: JustifyContent::Center,
+			align_items: AlignItems::Center,
+			..default()
+		}
+	}
+}
+
+impl View<PanelState> for VolumeControl {
+	fn view(&self) -> PanelState {
+		PanelState::Filled
+	}
+}
+
+impl HasPanelColors for VolumeControl {
+	const PANEL_COLORS: PanelColors = PanelColors::DEFAULT;
+}
+
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+#[require(Text, Node = Self::node())]
+pub(crate) struct VolumeLabel(pub(crate) AudioCategory);
+
+impl VolumeLabel {
+	fn node() -> Node {
+		Node {
+			width: Val::Px(50.0),
+			justify_content: JustifyContent::Center,
+			..default()
+		}
+	}
+}
@@ -1,3 +1,4 @@
+pub(crate) mod labeled_stats;
 pub(crate) mod token;
 
 use super::GlobalZIndexTop;
@@ -0,0 +1,61 @@
+use super::{Tooltip, TooltipUiConfig};
+use crate::traits::{colors::PanelColors, insert_ui_content::InsertUiContent};
+use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
+use common::prelude::*;
+
+/// Tooltip content of a label followed by a line per stat.
+///
+/// Tokens are localized when the tooltip is spawned, so stat arguments and the
+/// current language are always up to date.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) struct LabeledStats {
+	pub(crate) label: Token,
+	pub(crate) stats: Vec<Token>,
+}
+
+impl TooltipUiConfig for LabeledStats {
+	fn node() -> Node {
+		Node {
+			top: Val::Px(-25.0),
+			padding: UiRect::all(Val::Px(5.0)),
+			flex_direction: FlexDirection::Column,
+			..default()
+		}
+	}
+
+	fn background_color() -> BackgroundColor {
+		BackgroundColor(PanelColors::DEFAULT.filled.text)
+	}
+}
+
+impl InsertUiContent for Tooltip<LabeledStats> {
+	fn insert_ui_content<TLocalization>(
+		&self,
+		localize: &TLocalization,
+		parent: &mut RelatedSpawnerCommands<ChildOf>,
+	) where
+		TLocalization: Localize,
+	{
+		let LabeledStats { label, stats } = &self.0;
+
+		parent.spawn((
+			Text::from(localize.localize(label).or_token()),
+			TextFont {
+				font_size: FontSize::Px(20.0),
+				..default()
+			},
+			TextColor(PanelColors::DEFAULT.filled.background),
+		));
+
+		for stat in stats {
+			parent.spawn((
+				Text::from(localize.localize(stat).or_token()),
+				TextFont {
+					font_size: FontSize::Px(15.0),
+					..default()
+				},
+				TextColor(PanelColors::DEFAULT.filled.background),
+			));
+		}
+	}
+}
@@ -1,4 +1,9 @@
-use super::{Quickbar, input_label::InputLabel, quickbar_panel::QuickbarPanel};
+use super::{
+	Quickbar,
+	input_label::InputLabel,
+	quickbar_cooldown::QuickbarCooldown,
+	quickbar_panel::QuickbarPanel,
+};
 use crate::traits::{LoadUi, colors::PanelColors, insert_ui_content::InsertUiContent};
 use bevy::{ecs::relationship::RelatedSpawnerCommands, prelude::*};
 use common::prelude::*;
@@ -75,36 +80,39 @@ fn add_slot(quickbar: &mut RelatedSpawnerCommands<ChildOf>, key: HandSlot) {
 				align_items: AlignItems::Start,
 				..default()
 			},
-			children![(
-				Node {
-					position_type: PositionType::Absolute,
-					left: Val::Px(slot_desc_offset),
-					top: Val::Px(slot_desc_offset),
-					width: Val::Px(slot_desc_size),
-					height: Val::Px(slot_desc_size),
-					border: UiRect::all(Val::Px(slot_desc_border)),
-					..default()
-				},
-				BorderColor::from(PanelColors::DEFAULT.filled.text),
-				BackgroundColor::from(PanelColors::DEFAULT.filled.background),
-				children![(
+			children![
+				QuickbarCooldown { key },
+				(
 					Node {
-						width: Val::Px(slot_desc_size - 2. * slot_desc_border),
-						height: Val::Px(slot_desc_size - 2. * slot_desc_border),
+						position_type: PositionType::Absolute,
+						left: Val::Px(slot_desc_offset),
+						top: Val::Px(slot_desc_offset),
+						width: Val::Px(slot_desc_size),
+						height: Val::Px(slot_desc_size),
+						border: UiRect::all(Val::Px(slot_desc_border)),
 						..default()
 					},
-					TextLayout {
-						justify: Justify::Center,
-						..default()
-					},
-					TextFont {
-						font_size: slot_desc_text_size,
-						..default()
-					},
-					TextColor(PanelColors::DEFAULT.filled.text),
-					InputLabel { key },
-				)],
-			)],
+					BorderColor::from(PanelColors::DEFAULT.filled.text),
+					BackgroundColor::from(PanelColors::DEFAULT.filled.background),
+					children![(
+						Node {
+							width: Val::Px(slot_desc_size - 2. * slot_desc_border),
+							height: Val::Px(slot_desc_size - 2. * slot_desc_border),
+							..default()
+						},
+						TextLayout {
+							justify: Justify::Center,
+							..default()
+						},
+						TextFont {
+							font_size: slot_desc_text_size,
+							..default()
+						},
+						TextColor(PanelColors::DEFAULT.filled.text),
+						InputLabel { key },
+					)],
+				),
+			],
 		)],
 	));
 }
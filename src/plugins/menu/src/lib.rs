@@ -27,7 +27,7 @@ use crate::{
 		dispatch_text_color::DispatchTextColor,
 		key_select_dropdown_command::KeySelectDropdownCommand,
 		label::UILabel,
-		pause_menu::PauseMenu,
+		pause_menu::{PauseMenu, save_slot_button::SaveSlotButton},
 		ui_disabled::UIDisabled,
 	},
 	systems::{
@@ -59,14 +59,18 @@ use components::{
 	loading_screen::LoadingScreen,
 	menu_background::MenuBackground,
 	prevent_menu_change::PreventMenuChange,
+	progression_screen::{ProgressionScreen, upgrade_button::UpgradeButton},
+	quickbar_cooldown::QuickbarCooldown,
 	quickbar_panel::QuickbarPanel,
 	settings_screen::{
 		SettingsScreen,
+		general::{SettingControl, SettingLabel},
 		key_bind::{KeyBind, action::Action, input::Input, rebinding::Rebinding},
+		volume::{VolumeControl, VolumeLabel},
 	},
 	start_menu::StartMenu,
 	start_menu_button::StartMenuButton,
-	tooltip::{Tooltip, TooltipUIControl},
+	tooltip::{Tooltip, TooltipUIControl, labeled_stats::LabeledStats},
 	ui_overlay::UIOverlay,
 };
 use events::DropdownMessage;
@@ -88,7 +92,7 @@ use traits::{LoadUi, add_dropdown::AddDropdown, add_tooltip::AddTooltip, add_ui:
 
 pub struct MenuPlugin<TDependencies>(PhantomData<TDependencies>);
 
-impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout>
+impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout, TAudio, TSettings>
 	MenuPlugin<(
 		TLoading,
 		TSavegame,
@@ -97,6 +101,8 @@ impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout>
 		TGraphics,
 		TPlayers,
 		TLoadout,
+		TAudio,
+		TSettings,
 	)>
 where
 	TLoading: ThreadSafe + HandlesLoadTracking,
@@ -106,7 +112,10 @@ where
 	TGraphics: ThreadSafe + HandlesCameras,
 	TPlayers: ThreadSafe + HandlesPlayer,
 	TLoadout: ThreadSafe + HandlesLoadout,
+	TAudio: ThreadSafe + HandlesAudio,
+	TSettings: ThreadSafe + HandlesSettings,
 {
+	#[allow(clippy::too_many_arguments)]
 	pub fn from_plugins(
 		_: &TLoading,
 		_: &TSavegame,
@@ -115,12 +124,14 @@ where
 		_: &TGraphics,
 		_: &TPlayers,
 		_: &TLoadout,
+		_: &TAudio,
+		_: &TSettings,
 	) -> Self {
 		Self(PhantomData)
 	}
 }
 
-impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout>
+impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout, TAudio, TSettings>
 	MenuPlugin<(
 		TLoading,
 		TSavegame,
@@ -129,6 +140,8 @@ impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout>
 		TGraphics,
 		TPlayers,
 		TLoadout,
+		TAudio,
+		TSettings,
 	)>
 where
 	TLoading: ThreadSafe + HandlesLoadTracking,
@@ -138,6 +151,8 @@ where
 	TGraphics: ThreadSafe + HandlesCameras,
 	TPlayers: ThreadSafe + HandlesPlayer,
 	TLoadout: ThreadSafe + HandlesLoadout,
+	TAudio: ThreadSafe + HandlesAudio,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	fn resources(&self, app: &mut App) {
 		app.init_resource::<Shared<Path, Handle<Image>>>()
@@ -147,7 +162,8 @@ where
 	}
 
 	fn messages(&self, app: &mut App) {
-		app.add_message::<DropdownMessage>();
+		app.add_message::<DropdownMessage>()
+			.add_message::<LocalizationChanged>();
 	}
 
 	fn state_control(&self, app: &mut App) {
@@ -185,13 +201,13 @@ where
 
 	fn start_menu(&self, app: &mut App) {
 		let start_menu = GameState::StartMenu;
-		let quick_load = GameState::Save(SaveState::AttemptLoad);
-		let enable_or_disable_quick_load_button = TSavegame::can_quick_load()
-			.pipe(|In(can_quick_load)| match can_quick_load {
+		let continue_game = GameState::Save(SaveState::AttemptLoad);
+		let enable_or_disable_continue_button = TSavegame::can_continue()
+			.pipe(|In(can_continue)| match can_continue {
 				true => Activity::Enable,
 				false => Activity::Disable,
 			})
-			.pipe(StartMenuButton::set_activity(quick_load));
+			.pipe(StartMenuButton::set_activity(continue_game));
 
 		app.add_prefab_observer::<StartMenuButton, ()>()
 			.add_ui::<StartMenu, TLocalization::TLocalizationServer, TGraphics::TCameraMut>(
@@ -200,7 +216,7 @@ where
 			.add_systems(
 				Update,
 				(
-					enable_or_disable_quick_load_button,
+					enable_or_disable_continue_button,
 					panel_colors::<StartMenuButton>,
 					StartMenuButton::trigger_on_release,
 				)
@@ -210,9 +226,19 @@ where
 	}
 
 	fn pause_menu(&self, app: &mut App) {
-		app.add_ui::<PauseMenu, TLocalization::TLocalizationServer, TGraphics::TCameraMut>(
-			GameState::IngameMenu(MenuState::Paused),
-		);
+		let paused = GameState::IngameMenu(MenuState::Paused);
+
+		app.add_ui::<PauseMenu, TLocalization::TLocalizationServer, TGraphics::TCameraMut>(paused)
+			.add_systems(
+				Update,
+				(
+					PauseMenu::update_slots::<TSavegame::TSaveSlots>,
+					panel_colors::<SaveSlotButton>,
+					SaveSlotButton::request_slot::<TSavegame::TSaveSlotsMut>,
+				)
+					.chain()
+					.run_if(in_state(paused)),
+			);
 	}
 
 	fn ui_overlay(&self, app: &mut App) {
@@ -230,6 +256,7 @@ where
 						TLoadout::TLoadout,
 					>,
 					panel_colors::<QuickbarPanel>,
+					QuickbarCooldown::sweep::<TPlayers::TPlayer, TLoadout::TLoadout>,
 				)
 					.run_if(in_state(play)),
 			);
@@ -292,6 +319,27 @@ where
 		);
 	}
 
+	fn progression_screen(&self, app: &mut App) {
+		let progression = GameState::IngameMenu(MenuState::Progression);
+
+		app.add_ui::<ProgressionScreen<TLoadout::TSkillID>, TLocalization::TLocalizationServer, TGraphics::TCameraMut>(
+			progression,
+		);
+		app.add_systems(
+			Update,
+			(
+				ProgressionScreen::update_from::<TPlayers::TPlayer, TLoadout::TLoadout>,
+				panel_colors::<UpgradeButton<TLoadout::TSkillID>>,
+				UpgradeButton::<TLoadout::TSkillID>::acquire::<
+					TPlayers::TPlayer,
+					TLoadout::TLoadoutMut,
+				>,
+			)
+				.chain()
+				.run_if(in_state(progression)),
+		);
+	}
+
 	fn inventory_screen(&self, app: &mut App) {
 		let inventory = GameState::IngameMenu(MenuState::Inventory);
 
@@ -332,6 +380,18 @@ where
 					KeyBindInput::rebind_on_click,
 					KeyRebindInput::render_ui::<TLocalization::TLocalizationServer>,
 					KeyRebindInput::rebind_apply::<TInput::TInputMut>,
+					panel_colors::<VolumeControl>,
+					VolumeControl::adjust::<TAudio::TAudioSettingsMut>,
+					VolumeLabel::update_label::<TAudio::TAudioSettings>,
+					panel_colors::<SettingControl>,
+					SettingControl::adjust::<
+						TSettings::TSettingsMut,
+						TLocalization::TLocalizationServer,
+					>,
+					SettingLabel::update_label::<
+						TSettings::TSettings,
+						TLocalization::TLocalizationServer,
+					>,
 				)
 					.run_if(in_state(settings)),
 			);
@@ -344,6 +404,7 @@ where
 			.add_observer(UILabel::localize::<TLocalization::TLocalizationServer>)
 			.add_tooltip::<TLocalization::TLocalizationServer, Token>()
 			.add_tooltip::<TLocalization::TLocalizationServer, Localized>()
+			.add_tooltip::<TLocalization::TLocalizationServer, LabeledStats>()
 			.add_systems(
 				Update,
 				(
@@ -356,6 +417,7 @@ where
 						Icon::load_image,
 						Icon::insert_image,
 						UILabel::icon_tooltip,
+						UILabel::relocalize,
 						UILabel::text,
 					)
 						.chain(),
@@ -366,7 +428,8 @@ where
 	}
 }
 
-impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout> Plugin
+impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout, TAudio, TSettings>
+	Plugin
 	for MenuPlugin<(
 		TLoading,
 		TSavegame,
@@ -375,6 +438,8 @@ impl<TLoading, TSavegame, TInput, TLocalization, TGraphics, TPlayers, TLoadout>
 		TGraphics,
 		TPlayers,
 		TLoadout,
+		TAudio,
+		TSettings,
 	)>
 where
 	TLoading: ThreadSafe + HandlesLoadTracking,
@@ -384,6 +449,8 @@ where
 	TGraphics: ThreadSafe + HandlesCameras,
 	TPlayers: ThreadSafe + HandlesPlayer,
 	TLoadout: ThreadSafe + HandlesLoadout,
+	TAudio: ThreadSafe + HandlesAudio,
+	TSettings: ThreadSafe + HandlesSettings,
 {
 	fn build(&self, app: &mut App) {
 		self.resources(app);
@@ -395,6 +462,7 @@ where
 		self.pause_menu(app);
 		self.ui_overlay(app);
 		self.combo_overview(app);
+		self.progression_screen(app);
 		self.inventory_screen(app);
 		self.settings_screen(app);
 		self.general_systems(app);
@@ -5,10 +5,13 @@ pub(crate) mod despawn;
 pub(crate) mod disable_ui;
 pub(crate) mod dispatch_text_colors;
 pub(crate) mod dropdown;
+pub(crate) mod general_settings;
 pub(crate) mod icon;
 pub(crate) mod image_color;
 pub(crate) mod inventory_panel;
 pub(crate) mod menus_unchangeable_when_present;
+pub(crate) mod pause_menu;
+pub(crate) mod progression;
 pub(crate) mod quickbar_panel;
 pub(crate) mod rebind;
 pub(crate) mod render_ui;
@@ -22,3 +25,4 @@ pub(crate) mod trigger_on_release;
 pub(crate) mod ui_label;
 pub(crate) mod update_children;
 pub(crate) mod update_panels;
+pub(crate) mod volume;
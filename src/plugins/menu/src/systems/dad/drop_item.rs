@@ -14,7 +14,7 @@ pub fn drop_item<TPlayer, TLoadout>(
 	mut param: StaticSystemParam<TLoadout>,
 ) where
 	TPlayer: for<'w, 's> SystemParam<Item<'w, 's>: View<PlayerEntity>>,
-	TLoadout: for<'c> TryGetContextMut<Items, TContext<'c>: SwapItems>,
+	TLoadout: for<'c> TryGetContextMut<Items, TContext<'c>: SwapItems + SocketItems>,
 {
 	if !mouse.just_released(MouseButton::Left) {
 		return;
@@ -36,7 +36,9 @@ pub fn drop_item<TPlayer, TLoadout>(
 	};
 
 	for (.., keyed_panel) in panels.iter().filter(is_hovered) {
-		ctx.swap_items(dad.0, keyed_panel.0);
+		if !ctx.socket_item(dad.0, keyed_panel.0) {
+			ctx.swap_items(dad.0, keyed_panel.0);
+		}
 		commands.try_apply_on(&entity, |mut e| {
 			e.try_remove::<Dad>();
 		});
@@ -60,6 +62,8 @@ mod tests {
 	#[derive(Component, Debug, PartialEq, Default)]
 	struct _Container {
 		swaps: Vec<(LoadoutKey, LoadoutKey)>,
+		sockets: Vec<(LoadoutKey, LoadoutKey)>,
+		can_socket: bool,
 	}
 
 	impl SwapItems for _Container {
@@ -72,6 +76,21 @@ mod tests {
 		}
 	}
 
+	impl SocketItems for _Container {
+		fn socket_item<TEssence, TItem>(&mut self, essence: TEssence, item: TItem) -> bool
+		where
+			TEssence: Into<LoadoutKey>,
+			TItem: Into<LoadoutKey>,
+		{
+			if !self.can_socket {
+				return false;
+			}
+
+			self.sockets.push((essence.into(), item.into()));
+			true
+		}
+	}
+
 	const MOUSE_LEFT: MouseButton = MouseButton::Left;
 
 	fn setup() -> App {
@@ -99,7 +118,38 @@ mod tests {
 
 		assert_eq!(
 			Some(&_Container {
-				swaps: vec![(LoadoutKey::from(SlotKey(42)), LoadoutKey::from(SlotKey(11)))]
+				swaps: vec![(LoadoutKey::from(SlotKey(42)), LoadoutKey::from(SlotKey(11)))],
+				..default()
+			}),
+			app.world().entity(entity).get::<_Container>(),
+		);
+	}
+
+	#[test]
+	fn call_socket_instead_of_swap() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				_Player,
+				_Container {
+					can_socket: true,
+					..default()
+				},
+				Dad::from(SlotKey(42)),
+			))
+			.id();
+		app.world_mut()
+			.spawn((Interaction::Hovered, KeyedPanel::from(SlotKey(11))));
+
+		set_input!(app, just_released(MOUSE_LEFT));
+		app.update();
+
+		assert_eq!(
+			Some(&_Container {
+				sockets: vec![(LoadoutKey::from(SlotKey(42)), LoadoutKey::from(SlotKey(11)))],
+				can_socket: true,
+				..default()
 			}),
 			app.world().entity(entity).get::<_Container>(),
 		);
@@ -119,7 +169,7 @@ mod tests {
 		app.update();
 
 		assert_eq!(
-			Some(&_Container { swaps: vec![] }),
+			Some(&_Container::default()),
 			app.world().entity(entity).get::<_Container>(),
 		);
 	}
@@ -138,7 +188,7 @@ mod tests {
 		app.update();
 
 		assert_eq!(
-			Some(&_Container { swaps: vec![] }),
+			Some(&_Container::default()),
 			app.world().entity(entity).get::<_Container>(),
 		);
 	}
@@ -157,7 +207,7 @@ mod tests {
 		app.update();
 
 		assert_eq!(
-			Some(&_Container { swaps: vec![] }),
+			Some(&_Container::default()),
 			app.world().entity(entity).get::<_Container>(),
 		);
 	}
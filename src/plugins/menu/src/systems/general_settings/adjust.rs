@@ -0,0 +1,186 @@
+use crate::{
+	components::{button_interaction::ButtonInteraction, settings_screen::general::SettingControl},
+	traits::is_released::IsReleased,
+};
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl SettingControl {
+	pub(crate) fn adjust<TSettingsMut, TLocales>(
+		mut param: StaticSystemParam<TSettingsMut>,
+		locales: StaticSystemParam<TLocales>,
+		controls: Query<(&Self, &ButtonInteraction)>,
+	) where
+		TSettingsMut: for<'c> TryGetContextMut<GameSettings, TContext<'c>: SetSettings>,
+		TLocales: for<'w, 's> SystemParam<Item<'w, 's>: ListLocales> + ThreadSafe,
+	{
+		let released = controls
+			.iter()
+			.filter(|(_, interaction)| interaction.is_released())
+			.collect::<Vec<_>>();
+
+		if released.is_empty() {
+			return;
+		}
+
+		let Some(mut ctx) = TSettingsMut::try_get_context_mut(&mut param, GameSettings) else {
+			return;
+		};
+
+		for (Self { setting, steps }, _) in released {
+			setting.adjust(ctx.settings_mut(), *steps, locales.locales());
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::settings_screen::general::Setting;
+	use bevy::ecs::system::SystemParamItem;
+	use testing::SingleThreadedApp;
+	use unic_langid::{LanguageIdentifier, langid};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Settings(GeneralSettings);
+
+	impl GetSettings for _Settings {
+		fn settings(&self) -> &GeneralSettings {
+			&self.0
+		}
+	}
+
+	impl SetSettings for _Settings {
+		fn settings_mut(&mut self) -> &mut GeneralSettings {
+			&mut self.0
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _Param<'w> {
+		settings: ResMut<'w, _Settings>,
+	}
+
+	impl TryGetContextMut<GameSettings> for _Param<'static> {
+		type TContext<'ctx> = Mut<'ctx, _Settings>;
+
+		fn try_get_context_mut<'ctx>(
+			param: &'ctx mut SystemParamItem<Self>,
+			_: GameSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(param.settings.reborrow())
+		}
+	}
+
+	#[derive(Resource)]
+	struct _Locales(Vec<LanguageIdentifier>);
+
+	impl ListLocales for _Locales {
+		fn locales(&self) -> &[LanguageIdentifier] {
+			&self.0
+		}
+	}
+
+	fn setup(settings: GeneralSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(_Settings(settings));
+		app.insert_resource(_Locales(vec![langid!("de"), langid!("en-US")]));
+		app.add_systems(Update, SettingControl::adjust::<_Param, Res<_Locales>>);
+
+		app
+	}
+
+	#[test]
+	fn adjust_setting() {
+		let mut app = setup(GeneralSettings {
+			fps_cap: 30,
+			..default()
+		});
+		app.world_mut().spawn((
+			SettingControl::increase(Setting::FpsCap),
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Settings(GeneralSettings {
+				fps_cap: 35,
+				..default()
+			}),
+			app.world().resource::<_Settings>(),
+		);
+	}
+
+	#[test]
+	fn do_nothing_if_not_released() {
+		let mut app = setup(GeneralSettings {
+			fps_cap: 30,
+			..default()
+		});
+		app.world_mut().spawn((
+			SettingControl::increase(Setting::FpsCap),
+			ButtonInteraction::Pressed,
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Settings(GeneralSettings {
+				fps_cap: 30,
+				..default()
+			}),
+			app.world().resource::<_Settings>(),
+		);
+	}
+
+	#[test]
+	fn adjust_multiple_settings() {
+		let mut app = setup(GeneralSettings::default());
+		app.world_mut().spawn((
+			SettingControl::decrease(Setting::Vsync),
+			ButtonInteraction::Released { hovered: true },
+		));
+		app.world_mut().spawn((
+			SettingControl::increase(Setting::Outlines),
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Settings(GeneralSettings {
+				vsync: false,
+				outlines: false,
+				..default()
+			}),
+			app.world().resource::<_Settings>(),
+		);
+	}
+
+	#[test]
+	fn adjust_language_from_listed_locales() {
+		let mut app = setup(GeneralSettings {
+			language: langid!("en-US"),
+			..default()
+		});
+		app.world_mut().spawn((
+			SettingControl::increase(Setting::Language),
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Settings(GeneralSettings {
+				language: langid!("de"),
+				..default()
+			}),
+			app.world().resource::<_Settings>(),
+		);
+	}
+}
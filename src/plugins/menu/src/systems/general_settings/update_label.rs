@@ -0,0 +1,175 @@
+use crate::components::settings_screen::general::SettingLabel;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl SettingLabel {
+	pub(crate) fn update_label<TSettings, TLocalization>(
+		param: StaticSystemParam<TSettings>,
+		localize: StaticSystemParam<TLocalization>,
+		mut localization_changed: MessageReader<LocalizationChanged>,
+		mut labels: Query<(Ref<Self>, &mut Text)>,
+	) where
+		TSettings: for<'c> TryGetContext<GameSettings, TContext<'c>: GetSettings>,
+		TLocalization: for<'w, 's> SystemParam<Item<'w, 's>: Localize> + ThreadSafe,
+	{
+		let localization_changed = localization_changed.read().count() > 0;
+		let Some(ctx) = TSettings::try_get_context(&param, GameSettings) else {
+			return;
+		};
+		let changed = ctx.context_changed() || localization_changed;
+
+		for (label, mut text) in &mut labels {
+			if !changed && !label.is_added() {
+				continue;
+			}
+
+			let SettingLabel(setting) = *label;
+			*text = Text::from(setting.label(ctx.settings(), &*localize));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::components::settings_screen::general::Setting;
+	use bevy::ecs::system::SystemParamItem;
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource)]
+	struct _Settings(GeneralSettings);
+
+	impl GetSettings for _Settings {
+		fn settings(&self) -> &GeneralSettings {
+			&self.0
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _Param<'w> {
+		settings: Res<'w, _Settings>,
+	}
+
+	impl TryGetContext<GameSettings> for _Param<'static> {
+		type TContext<'ctx> = &'ctx Res<'ctx, _Settings>;
+
+		fn try_get_context<'ctx>(
+			param: &'ctx SystemParamItem<Self>,
+			_: GameSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(&param.settings)
+		}
+	}
+
+	#[derive(Resource)]
+	struct _Localize;
+
+	impl Localize for _Localize {
+		fn localize(&self, token: &Token) -> LocalizationResult {
+			LocalizationResult::Ok(Localized::from(format!("localized: {}", &**token)))
+		}
+	}
+
+	fn setup(settings: GeneralSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(_Settings(settings));
+		app.insert_resource(_Localize);
+		app.add_message::<LocalizationChanged>();
+		app.add_systems(Update, SettingLabel::update_label::<_Param, Res<_Localize>>);
+
+		app
+	}
+
+	#[test]
+	fn set_numeric_label() {
+		let mut app = setup(GeneralSettings {
+			fps_cap: 42,
+			..default()
+		});
+		let entity = app.world_mut().spawn(SettingLabel(Setting::FpsCap)).id();
+
+		app.update();
+
+		assert_eq!(
+			"42",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+
+	#[test]
+	fn set_localized_label() {
+		let mut app = setup(GeneralSettings {
+			window_mode: DisplayMode::Fullscreen,
+			..default()
+		});
+		let entity = app
+			.world_mut()
+			.spawn(SettingLabel(Setting::WindowMode))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			"localized: settings-window-mode-fullscreen",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+
+	#[test]
+	fn update_when_settings_changed() {
+		let mut app = setup(GeneralSettings {
+			vsync: true,
+			..default()
+		});
+		let entity = app.world_mut().spawn(SettingLabel(Setting::Vsync)).id();
+
+		app.update();
+		app.world_mut().resource_mut::<_Settings>().0.vsync = false;
+		app.update();
+
+		assert_eq!(
+			"localized: settings-off",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+
+	#[test]
+	fn update_when_localization_changed() {
+		let mut app = setup(GeneralSettings::default());
+		let entity = app.world_mut().spawn(SettingLabel(Setting::Vsync)).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(Text::from("overridden"));
+		app.world_mut().write_message(LocalizationChanged);
+		app.update();
+
+		assert_eq!(
+			"localized: settings-on",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+
+	#[test]
+	fn do_not_override_text_when_nothing_changed() {
+		let mut app = setup(GeneralSettings::default());
+		let entity = app.world_mut().spawn(SettingLabel(Setting::Outlines)).id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(Text::from("overridden"));
+		app.update();
+
+		assert_eq!(
+			"overridden",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+}
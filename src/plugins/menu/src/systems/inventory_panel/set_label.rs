@@ -1,5 +1,10 @@
 use crate::{
-	components::{KeyedPanel, inventory_panel::InventoryPanel, label::UILabel},
+	components::{
+		KeyedPanel,
+		inventory_panel::InventoryPanel,
+		label::UILabel,
+		tooltip::{Tooltip, labeled_stats::LabeledStats},
+	},
 	tools::PanelState,
 };
 use bevy::{
@@ -11,7 +16,12 @@ use common::prelude::*;
 impl InventoryPanel {
 	pub(crate) fn set_label<TPlayer, TLoadout>(
 		mut commands: ZyheedaCommands,
-		mut panels: Query<(Entity, &mut Self, &KeyedPanel)>,
+		mut panels: Query<(
+			Entity,
+			&mut Self,
+			&KeyedPanel,
+			Option<&Tooltip<LabeledStats>>,
+		)>,
 		player: StaticSystemParam<TPlayer>,
 		param: StaticSystemParam<TLoadout>,
 	) where
@@ -28,17 +38,31 @@ impl InventoryPanel {
 			return;
 		};
 
-		for (panel_entity, mut panel, KeyedPanel(key)) in &mut panels {
+		for (panel_entity, mut panel, KeyedPanel(key), current_tooltip) in &mut panels {
 			let panel_state = match ctx.get_item(*key) {
 				None => {
 					commands.try_apply_on(&panel_entity, |mut e| {
 						e.try_insert(UILabel::empty());
+
+						if current_tooltip.is_some() {
+							e.try_remove::<Tooltip<LabeledStats>>();
+						}
 					});
 					PanelState::Empty
 				}
 				Some(item) => {
+					let token = item.view_of::<ItemToken>();
+					let tooltip = Tooltip::new(LabeledStats {
+						label: token.clone(),
+						stats: item.view_of::<SkillStats>().to_vec(),
+					});
+
 					commands.try_apply_on(&panel_entity, |mut e| {
-						e.try_insert(UILabel(item.view().clone()));
+						e.try_insert(UILabel(token.clone()));
+
+						if current_tooltip != Some(&tooltip) {
+							e.try_insert(tooltip);
+						}
 					});
 					PanelState::Filled
 				}
@@ -60,7 +84,7 @@ mod tests {
 	use testing::SingleThreadedApp;
 
 	#[derive(Clone)]
-	struct _Item(Token);
+	struct _Item(Token, Vec<Token>);
 
 	impl View<ItemToken> for _Item {
 		fn view(&self) -> &Token {
@@ -68,6 +92,12 @@ mod tests {
 		}
 	}
 
+	impl View<SkillStats> for _Item {
+		fn view(&self) -> &[Token] {
+			&self.1
+		}
+	}
+
 	#[derive(Component, Default)]
 	struct _Container(HashMap<LoadoutKey, _Item>);
 
@@ -116,7 +146,7 @@ mod tests {
 		let mut app = setup();
 		app.world_mut().spawn((
 			_Player,
-			_Container::from([(HandSlot::Left, _Item(TOKEN.clone()))]),
+			_Container::from([(HandSlot::Left, _Item(TOKEN.clone(), vec![]))]),
 		));
 		let panel = app
 			.world_mut()
@@ -134,12 +164,65 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn set_stats_tooltip() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			_Player,
+			_Container::from([(
+				HandSlot::Left,
+				_Item(TOKEN.clone(), vec![Token::from("my stat")]),
+			)]),
+		));
+		let panel = app
+			.world_mut()
+			.spawn((
+				InventoryPanel(PanelState::Empty),
+				KeyedPanel::from(HandSlot::Left),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Tooltip::new(LabeledStats {
+				label: Token::from("my token"),
+				stats: vec![Token::from("my stat")],
+			})),
+			app.world().entity(panel).get::<Tooltip<LabeledStats>>(),
+		);
+	}
+
+	#[test]
+	fn remove_stats_tooltip_when_empty() {
+		let mut app = setup();
+		app.world_mut().spawn((_Player, _Container::default()));
+		let panel = app
+			.world_mut()
+			.spawn((
+				InventoryPanel(PanelState::Filled),
+				KeyedPanel::from(HandSlot::Left),
+				Tooltip::new(LabeledStats {
+					label: Token::from("my token"),
+					stats: vec![],
+				}),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			None,
+			app.world().entity(panel).get::<Tooltip<LabeledStats>>(),
+		);
+	}
+
 	#[test]
 	fn set_panel_to_filled() {
 		let mut app = setup();
 		app.world_mut().spawn((
 			_Player,
-			_Container::from([(HandSlot::Left, _Item(TOKEN.clone()))]),
+			_Container::from([(HandSlot::Left, _Item(TOKEN.clone(), vec![]))]),
 		));
 		let panel = app
 			.world_mut()
@@ -180,8 +263,10 @@ mod tests {
 	#[test]
 	fn do_nothing_if_agent_missing() {
 		let mut app = setup();
-		app.world_mut()
-			.spawn(_Container::from([(HandSlot::Left, _Item(TOKEN.clone()))]));
+		app.world_mut().spawn(_Container::from([(
+			HandSlot::Left,
+			_Item(TOKEN.clone(), vec![]),
+		)]));
 		let panel = app
 			.world_mut()
 			.spawn((
@@ -0,0 +1,2 @@
+pub(crate) mod request_slot;
+pub(crate) mod update_slots;
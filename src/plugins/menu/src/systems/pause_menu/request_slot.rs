@@ -0,0 +1,97 @@
+use crate::{
+	components::{
+		button_interaction::ButtonInteraction,
+		pause_menu::save_slot_button::SaveSlotButton,
+		ui_disabled::UIDisabled,
+	},
+	traits::is_released::IsReleased,
+};
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl SaveSlotButton {
+	pub(crate) fn request_slot<TSaveSlotsMut>(
+		buttons: Query<(&Self, &ButtonInteraction), Without<UIDisabled>>,
+		mut slots: StaticSystemParam<TSaveSlotsMut>,
+	) where
+		TSaveSlotsMut: for<'w, 's> SystemParam<Item<'w, 's>: RequestSlot>,
+	{
+		for (Self(request), interaction) in &buttons {
+			if !interaction.is_released() {
+				continue;
+			}
+
+			slots.request_slot(*request);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Slots(Vec<SlotRequest>);
+
+	impl RequestSlot for _Slots {
+		fn request_slot(&mut self, request: SlotRequest) {
+			self.0.push(request);
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<_Slots>();
+		app.add_systems(Update, SaveSlotButton::request_slot::<ResMut<_Slots>>);
+
+		app
+	}
+
+	#[test]
+	fn request_slot() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			SaveSlotButton(SlotRequest::Save(SaveSlot::Manual(2))),
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Slots(vec![SlotRequest::Save(SaveSlot::Manual(2))]),
+			app.world().resource::<_Slots>(),
+		);
+	}
+
+	#[test]
+	fn do_nothing_if_not_released() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			SaveSlotButton(SlotRequest::Load(SaveSlot::Manual(2))),
+			ButtonInteraction::Pressed,
+		));
+
+		app.update();
+
+		assert_eq!(&_Slots(vec![]), app.world().resource::<_Slots>());
+	}
+
+	#[test]
+	fn do_nothing_if_disabled() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			SaveSlotButton(SlotRequest::Load(SaveSlot::Manual(2))),
+			UIDisabled,
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(&_Slots(vec![]), app.world().resource::<_Slots>());
+	}
+}
@@ -0,0 +1,103 @@
+use crate::components::pause_menu::PauseMenu;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl PauseMenu {
+	/// Reads the slots only when the menu is spawned, because reading them touches the save files.
+	pub(crate) fn update_slots<TSaveSlots>(
+		mut menus: Query<&mut Self, Added<Self>>,
+		slots: StaticSystemParam<TSaveSlots>,
+	) where
+		TSaveSlots: for<'w, 's> SystemParam<Item<'w, 's>: ReadManualSlots>,
+	{
+		if menus.is_empty() {
+			return;
+		}
+
+		let slots = slots.manual_slots();
+
+		for mut menu in &mut menus {
+			menu.slots = slots.clone();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use macros::NestedMocks;
+	use mockall::automock;
+	use testing::{NestedMocks, SingleThreadedApp};
+
+	#[derive(Resource, NestedMocks)]
+	struct _Slots {
+		mock: Mock_Slots,
+	}
+
+	#[automock]
+	impl ReadManualSlots for _Slots {
+		fn manual_slots(&self) -> Vec<(SaveSlot, Option<SlotMetadata>)> {
+			self.mock.manual_slots()
+		}
+	}
+
+	fn setup(slots: _Slots) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(slots);
+		app.add_systems(Update, PauseMenu::update_slots::<Res<_Slots>>);
+
+		app
+	}
+
+	fn slots() -> Vec<(SaveSlot, Option<SlotMetadata>)> {
+		vec![
+			(SaveSlot::Manual(1), None),
+			(
+				SaveSlot::Manual(2),
+				Some(SlotMetadata {
+					level: Some(3),
+					..default()
+				}),
+			),
+		]
+	}
+
+	#[test]
+	fn set_slots() {
+		let mut app = setup(_Slots::new().with_mock(|mock| {
+			mock.expect_manual_slots().return_const(slots());
+		}));
+		let menu = app.world_mut().spawn(PauseMenu::default()).id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&PauseMenu { slots: slots() }),
+			app.world().entity(menu).get::<PauseMenu>(),
+		);
+	}
+
+	#[test]
+	fn read_slots_only_once() {
+		let mut app = setup(_Slots::new().with_mock(|mock| {
+			mock.expect_manual_slots().times(1).return_const(slots());
+		}));
+		app.world_mut().spawn(PauseMenu::default());
+
+		app.update();
+		app.update();
+	}
+
+	#[test]
+	fn do_not_read_slots_without_menu() {
+		let mut app = setup(_Slots::new().with_mock(|mock| {
+			mock.expect_manual_slots().never();
+		}));
+
+		app.update();
+	}
+}
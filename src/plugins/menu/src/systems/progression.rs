@@ -0,0 +1,2 @@
+pub(crate) mod acquire_upgrade;
+pub(crate) mod update_screen;
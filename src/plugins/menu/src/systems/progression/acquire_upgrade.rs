@@ -0,0 +1,148 @@
+use crate::{
+	components::{
+		button_interaction::ButtonInteraction,
+		progression_screen::upgrade_button::UpgradeButton,
+		ui_disabled::UIDisabled,
+	},
+	traits::is_released::IsReleased,
+};
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl<TId> UpgradeButton<TId>
+where
+	TId: Clone + ThreadSafe,
+{
+	pub(crate) fn acquire<TPlayer, TLoadoutMut>(
+		commands: ZyheedaCommands,
+		buttons: Query<(&Self, &ButtonInteraction), Without<UIDisabled>>,
+		player: StaticSystemParam<TPlayer>,
+		mut param: StaticSystemParam<TLoadoutMut>,
+	) where
+		TPlayer: for<'w, 's> SystemParam<Item<'w, 's>: View<PlayerEntity>>,
+		TLoadoutMut: for<'c> TryGetContextMut<Progression, TContext<'c>: AcquireUpgrade<TId>>,
+	{
+		let released = buttons
+			.iter()
+			.filter(|(_, interaction)| interaction.is_released())
+			.collect::<Vec<_>>();
+
+		if released.is_empty() {
+			return;
+		}
+
+		let Some(player) = player.view() else {
+			return;
+		};
+		let Some(entity) = commands.get(&player) else {
+			return;
+		};
+		let Some(mut ctx) = TLoadoutMut::try_get_context_mut(&mut param, Progression { entity })
+		else {
+			return;
+		};
+
+		for (Self { skill, upgrade, .. }, _) in released {
+			ctx.acquire_upgrade(skill.clone(), upgrade);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testing::{_Player, _PlayerParam};
+	use testing::SingleThreadedApp;
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Id;
+
+	#[derive(Component, Debug, PartialEq, Default)]
+	struct _Progression(Vec<(_Id, UpgradeId)>);
+
+	impl AcquireUpgrade<_Id> for _Progression {
+		fn acquire_upgrade(&mut self, skill: _Id, upgrade: &UpgradeId) {
+			self.0.push((skill, upgrade.clone()));
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_plugins(CommonPlugin::with_asset_loading(false));
+		app.add_systems(
+			Update,
+			UpgradeButton::<_Id>::acquire::<_PlayerParam, Query<&mut _Progression>>,
+		);
+
+		app
+	}
+
+	fn button(upgrade: &str) -> UpgradeButton<_Id> {
+		UpgradeButton {
+			skill: _Id,
+			upgrade: UpgradeId::from(upgrade),
+			state: UpgradeState::Available,
+		}
+	}
+
+	#[test]
+	fn acquire_upgrade() {
+		let mut app = setup();
+		let player = app
+			.world_mut()
+			.spawn((_Player, _Progression::default()))
+			.id();
+		app.world_mut()
+			.spawn((button("a"), ButtonInteraction::Released { hovered: true }));
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Progression(vec![(_Id, UpgradeId::from("a"))])),
+			app.world().entity(player).get::<_Progression>(),
+		);
+	}
+
+	#[test]
+	fn do_nothing_if_not_released() {
+		let mut app = setup();
+		let player = app
+			.world_mut()
+			.spawn((_Player, _Progression::default()))
+			.id();
+		app.world_mut()
+			.spawn((button("a"), ButtonInteraction::Pressed));
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Progression(vec![])),
+			app.world().entity(player).get::<_Progression>(),
+		);
+	}
+
+	#[test]
+	fn do_nothing_if_disabled() {
+		let mut app = setup();
+		let player = app
+			.world_mut()
+			.spawn((_Player, _Progression::default()))
+			.id();
+		app.world_mut().spawn((
+			button("a"),
+			UIDisabled,
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Progression(vec![])),
+			app.world().entity(player).get::<_Progression>(),
+		);
+	}
+}
@@ -0,0 +1,218 @@
+use crate::components::progression_screen::ProgressionScreen;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+use std::fmt::Debug;
+
+impl<TId> ProgressionScreen<TId>
+where
+	TId: Debug + PartialEq + Clone + ThreadSafe,
+{
+	pub(crate) fn update_from<TPlayer, TLoadout>(
+		commands: ZyheedaCommands,
+		mut screens: Query<&mut Self>,
+		player: StaticSystemParam<TPlayer>,
+		param: StaticSystemParam<TLoadout>,
+	) where
+		TPlayer: for<'w, 's> SystemParam<Item<'w, 's>: View<PlayerEntity>>,
+		TLoadout: for<'c> TryGetContext<Progression, TContext<'c>: ReadProgression<TId>>,
+	{
+		let Some(player) = player.view() else {
+			return;
+		};
+		let Some(entity) = commands.get(&player) else {
+			return;
+		};
+		let Some(ctx) = TLoadout::try_get_context(&param, Progression { entity }) else {
+			return;
+		};
+
+		for mut screen in &mut screens {
+			if !ctx.context_changed() && !screen.is_added() {
+				continue;
+			}
+
+			screen.progress = ctx.level_progress();
+			screen.skill_upgrades = ctx.skill_upgrades();
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::testing::{_Player, _PlayerParam};
+	use testing::{IsChanged, SingleThreadedApp};
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Id;
+
+	#[derive(Component)]
+	struct _Progression {
+		progress: LevelProgress,
+		skill_upgrades: Vec<SkillUpgrades<_Id>>,
+	}
+
+	impl ReadProgression<_Id> for _Progression {
+		fn level_progress(&self) -> LevelProgress {
+			self.progress
+		}
+
+		fn skill_upgrades(&self) -> Vec<SkillUpgrades<_Id>> {
+			self.skill_upgrades.clone()
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_plugins(CommonPlugin::with_asset_loading(false));
+		app.add_systems(
+			Update,
+			(
+				ProgressionScreen::<_Id>::update_from::<_PlayerParam, Query<Ref<_Progression>>>,
+				IsChanged::<ProgressionScreen<_Id>>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn skill_upgrades() -> Vec<SkillUpgrades<_Id>> {
+		vec![SkillUpgrades {
+			skill: _Id,
+			token: Token::from("skill"),
+			upgrades: vec![Upgrade {
+				id: UpgradeId::from("upgrade"),
+				token: Token::from("upgrade"),
+				cost: 2,
+				state: UpgradeState::Available,
+			}],
+		}]
+	}
+
+	#[test]
+	fn update_screen() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			_Player,
+			_Progression {
+				progress: LevelProgress {
+					level: 3,
+					experience: 11,
+					next_level: 300,
+					skill_points: 2,
+				},
+				skill_upgrades: skill_upgrades(),
+			},
+		));
+		let screen = app
+			.world_mut()
+			.spawn(ProgressionScreen::<_Id>::default())
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&ProgressionScreen {
+				progress: LevelProgress {
+					level: 3,
+					experience: 11,
+					next_level: 300,
+					skill_points: 2,
+				},
+				skill_upgrades: skill_upgrades(),
+			}),
+			app.world().entity(screen).get::<ProgressionScreen<_Id>>(),
+		);
+	}
+
+	#[test]
+	fn do_not_update_screen_when_progression_unchanged() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			_Player,
+			_Progression {
+				progress: LevelProgress::default(),
+				skill_upgrades: vec![],
+			},
+		));
+		let screen = app
+			.world_mut()
+			.spawn(ProgressionScreen::<_Id>::default())
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world()
+				.entity(screen)
+				.get::<IsChanged<ProgressionScreen<_Id>>>(),
+		);
+	}
+
+	#[test]
+	fn update_screen_again_when_progression_changed() {
+		let mut app = setup();
+		let player = app
+			.world_mut()
+			.spawn((
+				_Player,
+				_Progression {
+					progress: LevelProgress::default(),
+					skill_upgrades: vec![],
+				},
+			))
+			.id();
+		let screen = app
+			.world_mut()
+			.spawn(ProgressionScreen::<_Id>::default())
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(player)
+			.get_mut::<_Progression>()
+			.unwrap()
+			.progress
+			.level = 2;
+		app.update();
+
+		assert_eq!(
+			Some(2),
+			app.world()
+				.entity(screen)
+				.get::<ProgressionScreen<_Id>>()
+				.map(|screen| screen.progress.level),
+		);
+	}
+
+	#[test]
+	fn do_nothing_if_player_missing() {
+		let mut app = setup();
+		app.world_mut().spawn(_Progression {
+			progress: LevelProgress {
+				level: 3,
+				..default()
+			},
+			skill_upgrades: vec![],
+		});
+		let screen = app
+			.world_mut()
+			.spawn(ProgressionScreen::<_Id>::default())
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&ProgressionScreen::default()),
+			app.world().entity(screen).get::<ProgressionScreen<_Id>>(),
+		);
+	}
+}
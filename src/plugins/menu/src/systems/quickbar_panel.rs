@@ -1,2 +1,3 @@
 pub(crate) mod set_color;
 pub(crate) mod set_icon;
+pub(crate) mod sweep_cooldown;
@@ -156,6 +156,18 @@ mod tests {
 		}
 	}
 
+	impl View<SkillStats> for _Skill {
+		fn view(&self) -> &'_ [Token] {
+			&[]
+		}
+	}
+
+	impl View<SkillCooldown> for _Skill {
+		fn view(&self) -> f32 {
+			0.
+		}
+	}
+
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 
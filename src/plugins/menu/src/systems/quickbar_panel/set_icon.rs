@@ -1,4 +1,9 @@
-use crate::components::{icon::Icon, label::UILabel, quickbar_panel::QuickbarPanel};
+use crate::components::{
+	icon::Icon,
+	label::UILabel,
+	quickbar_panel::QuickbarPanel,
+	tooltip::{Tooltip, labeled_stats::LabeledStats},
+};
 use bevy::{
 	ecs::system::{StaticSystemParam, SystemParam},
 	prelude::*,
@@ -25,12 +30,18 @@ impl QuickbarPanel {
 			return;
 		};
 
-		for (panel_entity, Self { key, .. }, current_icon, current_label) in &panels {
+		for (panel_entity, Self { key, .. }, current_icon, current_label, current_tooltip) in
+			&panels
+		{
 			let Some(skill) = ctx.get_skill(*key) else {
 				continue;
 			};
 			let token = skill.view_of::<SkillToken>();
 			let image = skill.view_of::<SkillIcon>();
+			let tooltip = Tooltip::new(LabeledStats {
+				label: token.clone(),
+				stats: skill.view_of::<SkillStats>().to_vec(),
+			});
 
 			commands.try_apply_on(&panel_entity, |mut e| {
 				if !loaded(current_icon, image) {
@@ -40,6 +51,10 @@ impl QuickbarPanel {
 				if !labeled(current_label, token) {
 					e.try_insert(UILabel(token.clone()));
 				}
+
+				if current_tooltip != Some(&tooltip) {
+					e.try_insert(tooltip);
+				}
 			});
 		}
 	}
@@ -50,6 +65,7 @@ type PanelComponents<'a> = (
 	&'a QuickbarPanel,
 	Option<&'a Icon>,
 	Option<&'a UILabel<Token>>,
+	Option<&'a Tooltip<LabeledStats>>,
 );
 
 fn loaded(icon: Option<&Icon>, image: &Handle<Image>) -> bool {
@@ -92,6 +108,7 @@ mod tests {
 	struct _Skill {
 		icon: Handle<Image>,
 		token: Token,
+		stats: Vec<Token>,
 	}
 
 	impl View<SkillIcon> for _Skill {
@@ -106,12 +123,24 @@ mod tests {
 		}
 	}
 
+	impl View<SkillStats> for _Skill {
+		fn view(&self) -> &'_ [Token] {
+			&self.stats
+		}
+	}
+
 	impl View<SkillExecution> for _Skill {
 		fn view(&self) -> SkillExecution {
 			SkillExecution::None
 		}
 	}
 
+	impl View<SkillCooldown> for _Skill {
+		fn view(&self) -> f32 {
+			0.
+		}
+	}
+
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 
@@ -121,6 +150,7 @@ mod tests {
 			(
 				QuickbarPanel::set_icon::<_PlayerParam, Query<Ref<_Skills>>>,
 				IsChanged::<UILabel<Token>>::detect,
+				IsChanged::<Tooltip<LabeledStats>>::detect,
 			)
 				.chain(),
 		);
@@ -134,6 +164,7 @@ mod tests {
 		let item = _Skill {
 			token: Token::from("my item"),
 			icon: image.clone(),
+			stats: vec![],
 		};
 		let mut app = setup();
 		app.world_mut().spawn((
@@ -165,6 +196,7 @@ mod tests {
 		let item = _Skill {
 			token: Token::from("my item"),
 			icon: image.clone(),
+			stats: vec![],
 		};
 		let mut app = setup();
 		app.world_mut().spawn((
@@ -199,6 +231,7 @@ mod tests {
 		let item = _Skill {
 			token: Token::from("my item"),
 			icon: image.clone(),
+			stats: vec![],
 		};
 		let mut app = setup();
 		app.world_mut().spawn((
@@ -230,6 +263,7 @@ mod tests {
 		let item = _Skill {
 			token: Token::from("my item"),
 			icon: image.clone(),
+			stats: vec![],
 		};
 		let mut app = setup();
 		app.world_mut().spawn((
@@ -256,12 +290,81 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn insert_stats_tooltip() {
+		let item = _Skill {
+			token: Token::from("my item"),
+			icon: new_handle(),
+			stats: vec![Token::from("my stat").with_arg("value", 42.)],
+		};
+		let mut app = setup();
+		app.world_mut().spawn((
+			_Player,
+			_Skills(HashMap::from([(
+				LoadoutKey::from(HandSlot::Right),
+				item.clone(),
+			)])),
+		));
+		let panel = app
+			.world_mut()
+			.spawn(QuickbarPanel {
+				key: HandSlot::Right,
+				state: PanelState::Empty,
+			})
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Tooltip::new(LabeledStats {
+				label: Token::from("my item"),
+				stats: vec![Token::from("my stat").with_arg("value", 42.)],
+			})),
+			app.world().entity(panel).get::<Tooltip<LabeledStats>>(),
+		);
+	}
+
+	#[test]
+	fn do_not_insert_stats_tooltip_when_already_present() {
+		let item = _Skill {
+			token: Token::from("my item"),
+			icon: new_handle(),
+			stats: vec![Token::from("my stat")],
+		};
+		let mut app = setup();
+		app.world_mut().spawn((
+			_Player,
+			_Skills(HashMap::from([(
+				LoadoutKey::from(HandSlot::Right),
+				item.clone(),
+			)])),
+		));
+		let panel = app
+			.world_mut()
+			.spawn(QuickbarPanel {
+				key: HandSlot::Right,
+				state: PanelState::Empty,
+			})
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world()
+				.entity(panel)
+				.get::<IsChanged<Tooltip<LabeledStats>>>(),
+		);
+	}
+
 	#[test]
 	fn insert_icon_when_not_already_loaded_but_label_is_already_present() {
 		let image = new_handle();
 		let item = _Skill {
 			token: Token::from("my item"),
 			icon: image.clone(),
+			stats: vec![],
 		};
 		let mut app = setup();
 		app.world_mut().spawn((
@@ -295,6 +398,7 @@ mod tests {
 		let item = _Skill {
 			token: Token::from("my item"),
 			icon: image.clone(),
+			stats: vec![],
 		};
 		let mut app = setup();
 		app.world_mut().spawn(_Skills(HashMap::from([(
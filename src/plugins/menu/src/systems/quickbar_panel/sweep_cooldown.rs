@@ -0,0 +1,196 @@
+use crate::components::quickbar_cooldown::QuickbarCooldown;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl QuickbarCooldown {
+	pub(crate) fn sweep<TPlayer, TLoadout>(
+		commands: ZyheedaCommands,
+		param: StaticSystemParam<TLoadout>,
+		mut overlays: Query<(&Self, &mut Node)>,
+		player: StaticSystemParam<TPlayer>,
+	) where
+		TPlayer: for<'w, 's> SystemParam<Item<'w, 's>: View<PlayerEntity>>,
+		TLoadout: for<'c> TryGetContext<Skills, TContext<'c>: ReadSkills>,
+	{
+		let Some(player) = player.view() else {
+			return;
+		};
+		let Some(entity) = commands.get(&player) else {
+			return;
+		};
+		let Some(ctx) = TLoadout::try_get_context(&param, Skills { entity }) else {
+			return;
+		};
+
+		for (Self { key }, mut node) in &mut overlays {
+			let remaining = ctx
+				.get_skill(*key)
+				.map(|skill| skill.view_of::<SkillCooldown>())
+				.unwrap_or_default();
+			let height = Val::Percent(remaining.clamp(0., 1.) * 100.);
+
+			if node.height == height {
+				continue;
+			}
+
+			node.height = height;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::testing::{_Player, _PlayerParam};
+	use std::{collections::HashMap, sync::LazyLock};
+	use testing::{IsChanged, SingleThreadedApp};
+
+	#[derive(Component)]
+	struct _Skills(HashMap<LoadoutKey, _Skill>);
+
+	impl ReadSkills for _Skills {
+		type TSkill<'a>
+			= _Skill
+		where
+			Self: 'a;
+
+		fn get_skill<TKey>(&self, key: TKey) -> Option<Self::TSkill<'_>>
+		where
+			TKey: Into<LoadoutKey>,
+		{
+			self.0.get(&key.into()).cloned()
+		}
+	}
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Skill(f32);
+
+	static IMAGE: LazyLock<Handle<Image>> = LazyLock::new(Handle::default);
+	static TOKEN: LazyLock<Token> = LazyLock::new(|| Token::from("my skill"));
+
+	impl View<SkillIcon> for _Skill {
+		fn view(&self) -> &'_ Handle<Image> {
+			&IMAGE
+		}
+	}
+
+	impl View<SkillToken> for _Skill {
+		fn view(&self) -> &'_ Token {
+			&TOKEN
+		}
+	}
+
+	impl View<SkillExecution> for _Skill {
+		fn view(&self) -> SkillExecution {
+			SkillExecution::None
+		}
+	}
+
+	impl View<SkillStats> for _Skill {
+		fn view(&self) -> &'_ [Token] {
+			&[]
+		}
+	}
+
+	impl View<SkillCooldown> for _Skill {
+		fn view(&self) -> f32 {
+			self.0
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_plugins(CommonPlugin::with_asset_loading(false));
+		app.add_systems(
+			Update,
+			(
+				QuickbarCooldown::sweep::<_PlayerParam, Query<Ref<_Skills>>>,
+				IsChanged::<Node>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	#[test]
+	fn set_height_to_remaining_cooldown() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			_Player,
+			_Skills(HashMap::from([(
+				LoadoutKey::from(HandSlot::Left),
+				_Skill(0.25),
+			)])),
+		));
+		let overlay = app
+			.world_mut()
+			.spawn(QuickbarCooldown {
+				key: HandSlot::Left,
+			})
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Val::Percent(25.),
+			app.world().entity(overlay).get::<Node>().unwrap().height
+		);
+	}
+
+	#[test]
+	fn collapse_when_no_skill() {
+		let mut app = setup();
+		app.world_mut().spawn((_Player, _Skills(HashMap::from([]))));
+		let overlay = app
+			.world_mut()
+			.spawn((
+				QuickbarCooldown {
+					key: HandSlot::Left,
+				},
+				Node {
+					height: Val::Percent(50.),
+					..default()
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Val::Percent(0.),
+			app.world().entity(overlay).get::<Node>().unwrap().height
+		);
+	}
+
+	#[test]
+	fn do_not_mutate_when_unchanged() {
+		let mut app = setup();
+		app.world_mut().spawn((
+			_Player,
+			_Skills(HashMap::from([(
+				LoadoutKey::from(HandSlot::Left),
+				_Skill(0.25),
+			)])),
+		));
+		let overlay = app
+			.world_mut()
+			.spawn(QuickbarCooldown {
+				key: HandSlot::Left,
+			})
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(overlay).get::<IsChanged<Node>>()
+		);
+	}
+}
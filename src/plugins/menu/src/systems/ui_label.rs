@@ -1,2 +1,3 @@
 pub(crate) mod icon_tooltip;
+pub(crate) mod relocalize;
 pub(crate) mod text;
@@ -1,6 +1,6 @@
 use crate::{
 	Tooltip,
-	components::{icon::Icon, label::UILabel},
+	components::{icon::Icon, label::UILabel, tooltip::labeled_stats::LabeledStats},
 };
 use bevy::prelude::*;
 use common::prelude::*;
@@ -8,7 +8,7 @@ use common::prelude::*;
 impl UILabel {
 	pub(crate) fn icon_tooltip(
 		mut commands: ZyheedaCommands,
-		icons: Query<(Entity, &Icon, &UILabel), Changed<Icon>>,
+		icons: Query<(Entity, &Icon, &UILabel), ChangedWithoutStatsTooltip>,
 	) {
 		for (entity, icon, UILabel(label)) in &icons {
 			if !matches!(icon, Icon::Loaded(_)) {
@@ -22,6 +22,8 @@ impl UILabel {
 	}
 }
 
+type ChangedWithoutStatsTooltip = (Changed<Icon>, Without<Tooltip<LabeledStats>>);
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -84,6 +86,27 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn do_not_insert_tooltip_when_stats_tooltip_present() {
+		let handle = new_handle();
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				UILabel(Localized::from("my text")),
+				Icon::Loaded(handle.clone()),
+				Tooltip::new(LabeledStats {
+					label: Token::from("my label"),
+					stats: vec![],
+				}),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(entity).get::<Tooltip<Localized>>());
+	}
+
 	#[test]
 	fn do_not_insert_tooltip_twice() {
 		let handle = new_handle();
@@ -0,0 +1,82 @@
+use crate::components::label::UILabel;
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl UILabel {
+	/// Re-inserts all token labels when the localization changed, so they are picked up
+	/// again by the [`UILabel::localize`] observer.
+	pub(crate) fn relocalize(
+		mut commands: ZyheedaCommands,
+		mut changed: MessageReader<LocalizationChanged>,
+		labels: Query<(Entity, &UILabel<Token>)>,
+	) {
+		if changed.read().count() == 0 {
+			return;
+		}
+
+		for (entity, label) in &labels {
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert(label.clone());
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource, Default)]
+	struct _Inserted(Vec<Token>);
+
+	fn track_inserted(
+		on_insert: On<Insert, UILabel<Token>>,
+		labels: Query<&UILabel<Token>>,
+		mut inserted: ResMut<_Inserted>,
+	) {
+		let Ok(UILabel(token)) = labels.get(on_insert.entity) else {
+			return;
+		};
+
+		inserted.0.push(token.clone());
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_message::<LocalizationChanged>();
+		app.init_resource::<_Inserted>();
+		app.add_observer(track_inserted);
+		app.add_systems(Update, UILabel::relocalize);
+
+		app
+	}
+
+	#[test]
+	fn reinsert_token_labels_when_localization_changed() {
+		let mut app = setup();
+		app.world_mut().spawn(UILabel(Token::from("my token")));
+
+		app.world_mut().write_message(LocalizationChanged);
+		app.update();
+
+		assert_eq!(
+			vec![Token::from("my token"), Token::from("my token")],
+			app.world().resource::<_Inserted>().0,
+		);
+	}
+
+	#[test]
+	fn do_nothing_when_localization_not_changed() {
+		let mut app = setup();
+		app.world_mut().spawn(UILabel(Token::from("my token")));
+
+		app.update();
+
+		assert_eq!(
+			vec![Token::from("my token")],
+			app.world().resource::<_Inserted>().0,
+		);
+	}
+}
@@ -0,0 +1,2 @@
+pub(crate) mod adjust;
+pub(crate) mod update_label;
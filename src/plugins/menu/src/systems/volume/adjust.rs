@@ -0,0 +1,139 @@
+use crate::{
+	components::{button_interaction::ButtonInteraction, settings_screen::volume::VolumeControl},
+	traits::is_released::IsReleased,
+};
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl VolumeControl {
+	pub(crate) fn adjust<TAudioSettingsMut>(
+		mut param: StaticSystemParam<TAudioSettingsMut>,
+		controls: Query<(&Self, &ButtonInteraction)>,
+	) where
+		TAudioSettingsMut: for<'c> TryGetContextMut<AudioSettings, TContext<'c>: SetVolume>,
+	{
+		let released = controls
+			.iter()
+			.filter(|(_, interaction)| interaction.is_released())
+			.collect::<Vec<_>>();
+
+		if released.is_empty() {
+			return;
+		}
+
+		let Some(mut ctx) = TAudioSettingsMut::try_get_context_mut(&mut param, AudioSettings)
+		else {
+			return;
+		};
+
+		for (Self { category, step }, _) in released {
+			let volume = ctx.volume(*category);
+			ctx.set_volume(*category, volume + step);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{SystemParam, SystemParamItem};
+	use std::collections::HashMap;
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Volumes(HashMap<AudioCategory, f32>);
+
+	impl GetVolume for _Volumes {
+		fn volume(&self, category: AudioCategory) -> f32 {
+			self.0.get(&category).copied().unwrap_or_default()
+		}
+	}
+
+	impl SetVolume for _Volumes {
+		fn set_volume(&mut self, category: AudioCategory, volume: f32) {
+			self.0.insert(category, volume);
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _Param<'w> {
+		volumes: ResMut<'w, _Volumes>,
+	}
+
+	impl TryGetContextMut<AudioSettings> for _Param<'static> {
+		type TContext<'ctx> = Mut<'ctx, _Volumes>;
+
+		fn try_get_context_mut<'ctx>(
+			param: &'ctx mut SystemParamItem<Self>,
+			_: AudioSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(param.volumes.reborrow())
+		}
+	}
+
+	fn setup(volumes: _Volumes) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(volumes);
+		app.add_systems(Update, VolumeControl::adjust::<_Param>);
+
+		app
+	}
+
+	#[test]
+	fn increase_volume() {
+		let mut app = setup(_Volumes(HashMap::from([(AudioCategory::Music, 0.5)])));
+		app.world_mut().spawn((
+			VolumeControl {
+				category: AudioCategory::Music,
+				step: 0.25,
+			},
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Volumes(HashMap::from([(AudioCategory::Music, 0.75)])),
+			app.world().resource::<_Volumes>(),
+		);
+	}
+
+	#[test]
+	fn decrease_volume() {
+		let mut app = setup(_Volumes(HashMap::from([(AudioCategory::Ui, 0.5)])));
+		app.world_mut().spawn((
+			VolumeControl {
+				category: AudioCategory::Ui,
+				step: -0.25,
+			},
+			ButtonInteraction::Released { hovered: true },
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Volumes(HashMap::from([(AudioCategory::Ui, 0.25)])),
+			app.world().resource::<_Volumes>(),
+		);
+	}
+
+	#[test]
+	fn do_nothing_if_not_released() {
+		let mut app = setup(_Volumes(HashMap::from([(AudioCategory::Sfx, 0.5)])));
+		app.world_mut().spawn((
+			VolumeControl {
+				category: AudioCategory::Sfx,
+				step: 0.25,
+			},
+			ButtonInteraction::Pressed,
+		));
+
+		app.update();
+
+		assert_eq!(
+			&_Volumes(HashMap::from([(AudioCategory::Sfx, 0.5)])),
+			app.world().resource::<_Volumes>(),
+		);
+	}
+}
@@ -0,0 +1,127 @@
+use crate::components::settings_screen::volume::VolumeLabel;
+use bevy::{ecs::system::StaticSystemParam, prelude::*};
+use common::prelude::*;
+
+impl VolumeLabel {
+	pub(crate) fn update_label<TAudioSettings>(
+		param: StaticSystemParam<TAudioSettings>,
+		mut labels: Query<(Ref<Self>, &mut Text)>,
+	) where
+		TAudioSettings: for<'c> TryGetContext<AudioSettings, TContext<'c>: GetVolume>,
+	{
+		let Some(ctx) = TAudioSettings::try_get_context(&param, AudioSettings) else {
+			return;
+		};
+		let changed = ctx.context_changed();
+
+		for (label, mut text) in &mut labels {
+			if !changed && !label.is_added() {
+				continue;
+			}
+
+			let VolumeLabel(category) = *label;
+			let percent = (ctx.volume(category) * 100.).round();
+			*text = Text::from(format!("{percent}%"));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use bevy::ecs::system::{SystemParam, SystemParamItem};
+	use std::collections::HashMap;
+	use testing::SingleThreadedApp;
+
+	#[derive(Resource, Default)]
+	struct _Volumes(HashMap<AudioCategory, f32>);
+
+	impl GetVolume for _Volumes {
+		fn volume(&self, category: AudioCategory) -> f32 {
+			self.0.get(&category).copied().unwrap_or_default()
+		}
+	}
+
+	#[derive(SystemParam)]
+	struct _Param<'w> {
+		volumes: Res<'w, _Volumes>,
+	}
+
+	impl TryGetContext<AudioSettings> for _Param<'static> {
+		type TContext<'ctx> = &'ctx Res<'ctx, _Volumes>;
+
+		fn try_get_context<'ctx>(
+			param: &'ctx SystemParamItem<Self>,
+			_: AudioSettings,
+		) -> Option<Self::TContext<'ctx>> {
+			Some(&param.volumes)
+		}
+	}
+
+	fn setup(volumes: _Volumes) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(volumes);
+		app.add_systems(Update, VolumeLabel::update_label::<_Param>);
+
+		app
+	}
+
+	#[test]
+	fn set_volume_percentage() {
+		let mut app = setup(_Volumes(HashMap::from([(AudioCategory::Master, 0.42)])));
+		let entity = app
+			.world_mut()
+			.spawn(VolumeLabel(AudioCategory::Master))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			"42%",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+
+	#[test]
+	fn update_when_volumes_changed() {
+		let mut app = setup(_Volumes(HashMap::from([(AudioCategory::Master, 0.42)])));
+		let entity = app
+			.world_mut()
+			.spawn(VolumeLabel(AudioCategory::Master))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.resource_mut::<_Volumes>()
+			.0
+			.insert(AudioCategory::Master, 0.7);
+		app.update();
+
+		assert_eq!(
+			"70%",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+
+	#[test]
+	fn do_not_override_text_when_nothing_changed() {
+		let mut app = setup(_Volumes(HashMap::from([(AudioCategory::Master, 0.42)])));
+		let entity = app
+			.world_mut()
+			.spawn(VolumeLabel(AudioCategory::Master))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(Text::from("overridden"));
+		app.update();
+
+		assert_eq!(
+			"overridden",
+			app.world().entity(entity).get::<Text>().unwrap().as_str(),
+		);
+	}
+}
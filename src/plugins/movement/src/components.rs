@@ -1,3 +1,6 @@
+pub(crate) mod chase;
 pub(crate) mod config;
+pub(crate) mod equipment_speed_factor;
 pub(crate) mod facing;
+pub(crate) mod forced_movement;
 pub(crate) mod movement;
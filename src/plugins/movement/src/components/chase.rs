@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+/// Paths of the [`Movement`](crate::components::movement::Movement) are computed with
+/// [`HandlesPathFinding::TComputeChasePath`](common::traits::handles_path_finding::HandlesPathFinding::TComputeChasePath).
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Chase;
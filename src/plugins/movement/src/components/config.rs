@@ -11,6 +11,13 @@ pub(crate) struct Config {
 	pub(crate) required_clearance: RequiredClearance,
 }
 
+impl Config {
+	/// The configured speed for `speed_index`, scaled by `factor`
+	pub(crate) fn speed(&self, speed_index: SpeedIndex, factor: f32) -> Speed {
+		Speed(UnitsPerSecond::from(*self[speed_index] * factor))
+	}
+}
+
 impl Index<SpeedIndex> for Config {
 	type Output = UnitsPerSecond;
 
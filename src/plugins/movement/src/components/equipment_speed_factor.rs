@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Movement speed factor granted by equipped items, applied on top of the configured speed.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub struct EquipmentSpeedFactor(pub(crate) f32);
+
+impl Default for EquipmentSpeedFactor {
+	fn default() -> Self {
+		Self(1.)
+	}
+}
+
+impl From<EquipmentStats> for EquipmentSpeedFactor {
+	fn from(EquipmentStats { movement_speed, .. }: EquipmentStats) -> Self {
+		Self(movement_speed)
+	}
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+/// Overrides [`Movement`](crate::components::movement::Movement) until `remaining` ran out.
+///
+/// The speed is used as is, unaffected by speed toggles, status effects or equipment.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub(crate) struct ForcedMovement {
+	pub(crate) target: Vec3,
+	pub(crate) speed: UnitsPerSecond,
+	pub(crate) remaining: Duration,
+}
@@ -6,7 +6,14 @@ mod systems;
 mod debug;
 
 use crate::{
-	components::{config::SpeedIndex, facing::SetFace, movement::Movement},
+	components::{
+		chase::Chase,
+		config::SpeedIndex,
+		equipment_speed_factor::EquipmentSpeedFactor,
+		facing::SetFace,
+		forced_movement::ForcedMovement,
+		movement::Movement,
+	},
 	system_param::{
 		face_param::FaceParamMut,
 		movement_config_param::MovementConfigParamMut,
@@ -78,9 +85,21 @@ where
 		app.init_resource::<JustRemovedMovements>().add_systems(
 			Update,
 			(
-				Movement::compute_path::<TPathing::TComputePath, TPathing::TComputerRef>,
-				Movement::apply::<TPhysics::TCharacterMotion>,
-				TPhysics::TCharacterMotion::update_speed,
+				(
+					Movement::compute_path::<
+						TPathing::TComputePath,
+						TPathing::TComputerRef,
+						Without<Chase>,
+					>,
+					Movement::compute_path::<
+						TPathing::TComputeChasePath,
+						TPathing::TComputerRef,
+						With<Chase>,
+					>,
+				),
+				Update::delta.pipe(ForcedMovement::tick),
+				Movement::apply::<TPhysics::TCharacterMotion, TPhysics::TStatusEffects>,
+				TPhysics::TCharacterMotion::update_speed::<TPhysics::TStatusEffects>,
 				TPhysics::TCharacterMotion::animate_forward::<TAnimations::TAnimationsMut>,
 				TPhysics::TCharacterMotion::set_facing,
 				SetFace::get_faces.pipe(execute_face::<TPhysics::TRaycastMut, TPhysics::TAgent>),
@@ -101,6 +120,10 @@ impl<TDependencies> HandlesOrientation for MovementPlugin<TDependencies> {
 	type TFaceSystemParam = FaceParamMut<'static, 'static>;
 }
 
+impl<TDependencies> HandlesEquipmentStats for MovementPlugin<TDependencies> {
+	type TEquipmentStats = EquipmentSpeedFactor;
+}
+
 #[derive(SystemSet, Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub struct MovementSystems;
 
@@ -1,5 +1,7 @@
+mod chase_movement;
 pub(crate) mod context_changed;
 mod current_movement;
+mod force_movement;
 mod start_movement;
 mod stop_movement;
 mod toggle_speed;
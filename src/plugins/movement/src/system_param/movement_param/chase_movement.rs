@@ -0,0 +1,54 @@
+use crate::{
+	components::{chase::Chase, movement::Movement},
+	system_param::movement_param::MovementContextMut,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl<TMotion> ChaseMovement for MovementContextMut<'_, TMotion>
+where
+	TMotion: Component,
+{
+	fn chase(&mut self, target: Vec3) {
+		self.entity.try_insert((Movement::from(target), Chase));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::{components::config::Config, system_param::movement_param::MovementParamMut};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::SingleThreadedApp;
+
+	#[derive(Component)]
+	struct _Motion;
+
+	fn setup() -> App {
+		App::new().single_threaded(Update)
+	}
+
+	#[test]
+	fn insert_movement_and_chase() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(Config::default()).id();
+
+		app.world_mut()
+			.run_system_once(move |mut p: MovementParamMut<_Motion>| {
+				let mut ctx =
+					MovementParamMut::try_get_context_mut(&mut p, ConfiguredMovement { entity })
+						.unwrap();
+				ctx.chase(Vec3::new(1., 2., 3.));
+			})?;
+
+		assert_eq!(
+			(Some(&Movement::Target(Vec3::new(1., 2., 3.))), Some(&Chase)),
+			(
+				app.world().entity(entity).get::<Movement>(),
+				app.world().entity(entity).get::<Chase>(),
+			)
+		);
+		Ok(())
+	}
+}
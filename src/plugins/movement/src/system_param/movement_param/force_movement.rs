@@ -0,0 +1,64 @@
+use crate::{
+	components::forced_movement::ForcedMovement,
+	system_param::movement_param::MovementContextMut,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+impl<TMotion> ForceMovement for MovementContextMut<'_, TMotion>
+where
+	TMotion: Component,
+{
+	fn force_movement(&mut self, target: Vec3, speed: UnitsPerSecond, duration: Duration) {
+		self.entity.try_insert(ForcedMovement {
+			target,
+			speed,
+			remaining: duration,
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::{components::config::Config, system_param::movement_param::MovementParamMut};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::SingleThreadedApp;
+
+	#[derive(Component)]
+	struct _Motion;
+
+	fn setup() -> App {
+		App::new().single_threaded(Update)
+	}
+
+	#[test]
+	fn insert_forced_movement() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app.world_mut().spawn(Config::default()).id();
+
+		app.world_mut()
+			.run_system_once(move |mut p: MovementParamMut<_Motion>| {
+				let mut ctx =
+					MovementParamMut::try_get_context_mut(&mut p, ConfiguredMovement { entity })
+						.unwrap();
+				ctx.force_movement(
+					Vec3::new(1., 2., 3.),
+					UnitsPerSecond::from(20.),
+					Duration::from_millis(200),
+				);
+			})?;
+
+		assert_eq!(
+			Some(&ForcedMovement {
+				target: Vec3::new(1., 2., 3.),
+				speed: UnitsPerSecond::from(20.),
+				remaining: Duration::from_millis(200),
+			}),
+			app.world().entity(entity).get::<ForcedMovement>(),
+		);
+		Ok(())
+	}
+}
@@ -1,4 +1,7 @@
-use crate::{components::movement::Movement, system_param::movement_param::MovementContextMut};
+use crate::{
+	components::{chase::Chase, movement::Movement},
+	system_param::movement_param::MovementContextMut,
+};
 use bevy::ecs::component::Component;
 use common::prelude::*;
 
@@ -19,6 +22,7 @@ where
 		}
 
 		self.entity.try_insert(movement);
+		self.entity.try_remove::<Chase>();
 	}
 }
 
@@ -77,6 +81,23 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn remove_chase() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app.world_mut().spawn((Config::default(), Chase)).id();
+
+		app.world_mut()
+			.run_system_once(move |mut p: MovementParamMut<_Motion>| {
+				let mut ctx =
+					MovementParamMut::try_get_context_mut(&mut p, ConfiguredMovement { entity })
+						.unwrap();
+				ctx.start(Vec3::new(1., 2., 3.));
+			})?;
+
+		assert_eq!(None, app.world().entity(entity).get::<Chase>());
+		Ok(())
+	}
+
 	#[test]
 	fn do_not_insert_movement_direction_if_already_present() -> Result<(), RunSystemError> {
 		let mut app = setup();
@@ -1,4 +1,7 @@
-use crate::{components::movement::Movement, system_param::movement_param::MovementContextMut};
+use crate::{
+	components::{chase::Chase, movement::Movement},
+	system_param::movement_param::MovementContextMut,
+};
 use bevy::ecs::component::Component;
 use common::prelude::*;
 
@@ -8,6 +11,7 @@ where
 {
 	fn stop(&mut self) {
 		self.entity.try_insert(Movement::None);
+		self.entity.try_remove::<Chase>();
 	}
 }
 
@@ -48,4 +52,21 @@ mod tests {
 		);
 		Ok(())
 	}
+
+	#[test]
+	fn remove_chase() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app.world_mut().spawn((Config::default(), Chase)).id();
+
+		app.world_mut()
+			.run_system_once(move |mut p: MovementParamMut<_Motion>| {
+				let mut ctx =
+					MovementParamMut::try_get_context_mut(&mut p, ConfiguredMovement { entity })
+						.unwrap();
+				ctx.stop();
+			})?;
+
+		assert_eq!(None, app.world().entity(entity).get::<Chase>());
+		Ok(())
+	}
 }
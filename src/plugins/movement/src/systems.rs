@@ -2,5 +2,6 @@ pub(crate) mod animate_forward;
 pub(crate) mod apply_motion;
 pub(crate) mod compute_path;
 pub(crate) mod face;
+pub(crate) mod forced_movement;
 pub(crate) mod set_movement_facing;
 pub(crate) mod update_speed;
@@ -1,19 +1,51 @@
 use crate::components::{
 	config::{Config, SpeedIndex},
+	equipment_speed_factor::EquipmentSpeedFactor,
+	forced_movement::ForcedMovement,
 	movement::Movement,
 };
 use bevy::prelude::*;
 use common::prelude::*;
 
 impl Movement {
-	pub(crate) fn apply<TMotion>(
+	#[allow(clippy::type_complexity)]
+	pub(crate) fn apply<TMotion, TStatusEffects>(
 		mut commands: ZyheedaCommands,
-		movements: Query<(Entity, &mut Self, &Config, &SpeedIndex, Option<&TMotion>)>,
+		movements: Query<(
+			Entity,
+			&mut Self,
+			&Config,
+			&SpeedIndex,
+			Option<&TMotion>,
+			Option<&TStatusEffects>,
+			Option<&EquipmentSpeedFactor>,
+			Option<&ForcedMovement>,
+		)>,
 	) where
 		TMotion: Component + From<CharacterMotion> + View<CharacterMotion>,
+		TStatusEffects: Component + View<MovementSpeedFactor>,
 	{
-		for (entity, mut movement, config, speed_index, current_motion) in movements {
-			let Some(motion) = movement.get_motion(config, speed_index, current_motion) else {
+		for (
+			entity,
+			mut movement,
+			config,
+			speed_index,
+			current_motion,
+			status_effects,
+			equipment,
+			forced,
+		) in movements
+		{
+			let motion = match forced {
+				Some(forced) => forced.get_motion(current_motion),
+				None => {
+					let factor = status_effects.map_or(1., |effects| effects.view())
+						* equipment.map_or(1., |EquipmentSpeedFactor(factor)| *factor);
+					let speed = config.speed(*speed_index, factor);
+					movement.get_motion(speed, current_motion)
+				}
+			};
+			let Some(motion) = motion else {
 				continue;
 			};
 
@@ -25,8 +57,7 @@ impl Movement {
 
 	fn get_motion<TMotion>(
 		&mut self,
-		config: &Config,
-		speed_index: &SpeedIndex,
+		speed: Speed,
 		current_motion: Option<&TMotion>,
 	) -> Option<CharacterMotion>
 	where
@@ -41,7 +72,7 @@ impl Movement {
 			},
 			Movement::Direction(direction) => {
 				let motion = CharacterMotion::Direction {
-					speed: Speed(config[*speed_index]),
+					speed,
 					direction: *direction,
 				};
 
@@ -52,7 +83,7 @@ impl Movement {
 			}
 			Movement::Target(target) => {
 				let motion = CharacterMotion::ToTarget {
-					speed: Speed(config[*speed_index]),
+					speed,
 					target: *target,
 				};
 
@@ -63,7 +94,7 @@ impl Movement {
 			}
 			Movement::Path(path) if path.is_new() || is_inactive(current_motion) => {
 				Some(CharacterMotion::ToTarget {
-					speed: Speed(config[*speed_index]),
+					speed,
 					target: path.pop_front()?,
 				})
 			}
@@ -72,6 +103,23 @@ impl Movement {
 	}
 }
 
+impl ForcedMovement {
+	fn get_motion<TMotion>(&self, current_motion: Option<&TMotion>) -> Option<CharacterMotion>
+	where
+		TMotion: View<CharacterMotion>,
+	{
+		let motion = CharacterMotion::ToTarget {
+			speed: Speed(self.speed),
+			target: self.target,
+		};
+
+		match current_motion.map(|m| m.view()) {
+			Some(current_motion) if current_motion == motion => None,
+			_ => Some(motion),
+		}
+	}
+}
+
 fn is_inactive(motion: Option<CharacterMotion>) -> bool {
 	matches!(motion, Some(CharacterMotion::Done) | None)
 }
@@ -100,12 +148,25 @@ mod tests {
 		}
 	}
 
+	#[derive(Component)]
+	struct _StatusEffects(f32);
+
+	impl View<MovementSpeedFactor> for _StatusEffects {
+		fn view(&self) -> f32 {
+			self.0
+		}
+	}
+
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 
 		app.add_systems(
 			Update,
-			(Movement::apply::<_Motion>, IsChanged::<_Motion>::detect).chain(),
+			(
+				Movement::apply::<_Motion, _StatusEffects>,
+				IsChanged::<_Motion>::detect,
+			)
+				.chain(),
 		);
 
 		app
@@ -492,4 +553,183 @@ mod tests {
 			}
 		}
 	}
+
+	mod forced {
+		use super::*;
+		use std::time::Duration;
+
+		fn forced_movement() -> ForcedMovement {
+			ForcedMovement {
+				target: Vec3::new(4., 0., 2.),
+				speed: UnitsPerSecond::from(20.),
+				remaining: Duration::from_millis(200),
+			}
+		}
+
+		#[test]
+		fn apply_forced_target_and_speed() {
+			let mut app = setup();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Movement::Direction(Dir3::Z),
+					SpeedIndex(SpeedToggle::Right),
+					Config {
+						speed: MovementSpeed::Variable([SLOW, FAST]),
+						..default()
+					},
+					_StatusEffects(0.5),
+					forced_movement(),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&_Motion::from(CharacterMotion::ToTarget {
+					speed: Speed(UnitsPerSecond::from(20.)),
+					target: Vec3::new(4., 0., 2.),
+				})),
+				app.world().entity(entity).get::<_Motion>(),
+			);
+		}
+
+		#[test]
+		fn do_not_reapply_forced_movement() {
+			let mut app = setup();
+			let entity = app
+				.world_mut()
+				.spawn((Movement::None, Config::default(), forced_movement()))
+				.id();
+
+			app.update();
+			app.update();
+
+			assert_eq!(
+				Some(&IsChanged::FALSE),
+				app.world().entity(entity).get::<IsChanged<_Motion>>(),
+			);
+		}
+
+		#[test]
+		fn resume_movement_when_forced_movement_removed() {
+			let mut app = setup();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Movement::Direction(Dir3::Z),
+					SpeedIndex(SpeedToggle::Right),
+					Config {
+						speed: MovementSpeed::Variable([SLOW, FAST]),
+						..default()
+					},
+					forced_movement(),
+				))
+				.id();
+
+			app.update();
+			app.world_mut()
+				.entity_mut(entity)
+				.remove::<ForcedMovement>();
+			app.update();
+
+			assert_eq!(
+				Some(&_Motion::from(CharacterMotion::Direction {
+					speed: Speed(FAST),
+					direction: Dir3::Z
+				})),
+				app.world().entity(entity).get::<_Motion>(),
+			);
+		}
+	}
+
+	mod slowed {
+		use super::*;
+
+		#[test]
+		fn apply_direction_with_scaled_speed() {
+			let mut app = setup();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Movement::Direction(Dir3::Z),
+					SpeedIndex(SpeedToggle::Right),
+					Config {
+						speed: MovementSpeed::Variable([SLOW, FAST]),
+						..default()
+					},
+					_StatusEffects(0.5),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&_Motion::from(CharacterMotion::Direction {
+					speed: Speed(UnitsPerSecond::from(5.5)),
+					direction: Dir3::Z
+				})),
+				app.world().entity(entity).get::<_Motion>(),
+			);
+		}
+
+		#[test]
+		fn apply_direction_with_equipment_and_status_scaled_speed() {
+			let mut app = setup();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Movement::Direction(Dir3::Z),
+					SpeedIndex(SpeedToggle::Right),
+					Config {
+						speed: MovementSpeed::Variable([SLOW, FAST]),
+						..default()
+					},
+					_StatusEffects(0.5),
+					EquipmentSpeedFactor(2.),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&_Motion::from(CharacterMotion::Direction {
+					speed: Speed(FAST),
+					direction: Dir3::Z
+				})),
+				app.world().entity(entity).get::<_Motion>(),
+			);
+		}
+
+		#[test]
+		fn update_target_motion_when_speed_factor_changes() {
+			let mut app = setup();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Movement::Target(Vec3::new(1., 2., 3.)),
+					SpeedIndex(SpeedToggle::Right),
+					Config {
+						speed: MovementSpeed::Variable([SLOW, FAST]),
+						..default()
+					},
+					_StatusEffects(1.),
+				))
+				.id();
+
+			app.update();
+			app.world_mut()
+				.entity_mut(entity)
+				.insert(_StatusEffects(0.5));
+			app.update();
+
+			assert_eq!(
+				Some(&_Motion::from(CharacterMotion::ToTarget {
+					speed: Speed(UnitsPerSecond::from(5.5)),
+					target: Vec3::new(1., 2., 3.),
+				})),
+				app.world().entity(entity).get::<_Motion>(),
+			);
+		}
+	}
 }
@@ -2,7 +2,7 @@ use crate::components::{
 	config::Config,
 	movement::{Movement, MovementPath},
 };
-use bevy::prelude::*;
+use bevy::{ecs::query::QueryFilter, prelude::*};
 use common::prelude::*;
 use std::collections::VecDeque;
 
@@ -14,28 +14,64 @@ type MoveComponents<TGetComputer> = (
 );
 
 impl Movement {
-	pub(crate) fn compute_path<TComputer, TGetComputer>(
-		movements: Query<MoveComponents<TGetComputer>>,
-		computers: Query<&TComputer>,
+	/// Computes paths for the movements matching `TFilter`, so different agents can use
+	/// different path computations.
+	pub(crate) fn compute_path<TComputer, TGetComputer, TFilter>(
+		movements: Query<MoveComponents<TGetComputer>, TFilter>,
+		computers: Query<Ref<TComputer>>,
 	) where
 		TComputer: Component + ComputePath,
 		TGetComputer: Component + View<Entity>,
+		TFilter: QueryFilter,
 	{
-		for (config, transform, mut path, get_computer) in movements {
-			let Movement::Target(target) = path.as_ref() else {
-				continue;
-			};
+		for (config, transform, mut movement, get_computer) in movements {
 			let Ok(computer) = computers.get(get_computer.view()) else {
 				continue;
 			};
+			let target = match movement.as_ref() {
+				Movement::Target(target) => *target,
+				Movement::Path(path) if computer.is_changed() => {
+					match obstructed_path_target(computer.as_ref(), transform, path, config) {
+						Some(target) => target,
+						None => continue,
+					}
+				}
+				_ => continue,
+			};
 
-			*path = Movement::Path(MovementPath::from(compute_path(
-				computer, transform, *target, config,
+			*movement = Movement::Path(MovementPath::from(compute_path(
+				computer.as_ref(),
+				transform,
+				target,
+				config,
 			)));
 		}
 	}
 }
 
+/// The final waypoint of a path that can no longer be walked, so it can be recomputed.
+fn obstructed_path_target<TComputer>(
+	computer: &TComputer,
+	transform: &GlobalTransform,
+	path: &MovementPath,
+	Config {
+		required_clearance, ..
+	}: &Config,
+) -> Option<Vec3>
+where
+	TComputer: ComputePath,
+{
+	let target = *path.back()?;
+	let waypoints = path.iter().copied().collect::<Vec<_>>();
+	let start = transform.translation();
+
+	if !computer.path_obstructed(start, &waypoints, required_clearance.horizontal) {
+		return None;
+	}
+
+	Some(target)
+}
+
 fn compute_path<TComputer>(
 	computer: &TComputer,
 	transform: &GlobalTransform,
@@ -75,6 +111,9 @@ mod tests {
 	#[derive(Component)]
 	struct _GetComputer(Entity);
 
+	#[derive(Component)]
+	struct _Excluded;
+
 	impl View<Entity> for _GetComputer {
 		fn view(&self) -> Entity {
 			self.0
@@ -96,6 +135,16 @@ mod tests {
 		fn compute_path(&self, start: Vec3, end: Vec3, agent_radius: Units) -> Option<Iter> {
 			self.mock.compute_path(start, end, agent_radius)
 		}
+
+		fn path_obstructed(
+			&self,
+			start: Vec3,
+			waypoints: &[Vec3],
+			required_clearance: Units,
+		) -> bool {
+			self.mock
+				.path_obstructed(start, waypoints, required_clearance)
+		}
 	}
 
 	#[derive(Clone)]
@@ -121,7 +170,10 @@ mod tests {
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 
-		app.add_systems(Update, Movement::compute_path::<_ComputePath, _GetComputer>);
+		app.add_systems(
+			Update,
+			Movement::compute_path::<_ComputePath, _GetComputer, Without<_Excluded>>,
+		);
 
 		app
 	}
@@ -129,6 +181,34 @@ mod tests {
 	mod path {
 		use super::*;
 
+		#[test]
+		fn ignore_filtered_movements() {
+			let mut app = setup();
+			let computer = app
+				.world_mut()
+				.spawn(_ComputePath::new().with_mock(|mock| {
+					mock.expect_compute_path().never();
+				}))
+				.id();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Config::default(),
+					Movement::Target(Vec3::default()),
+					GlobalTransform::default(),
+					_GetComputer(computer),
+					_Excluded,
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&Movement::Target(Vec3::default())),
+				app.world().entity(entity).get::<Movement>(),
+			);
+		}
+
 		#[test]
 		fn set_path() {
 			let mut app = setup();
@@ -323,6 +403,146 @@ mod tests {
 			app.update();
 		}
 	}
+
+	mod obstructed_path {
+		use super::*;
+
+		#[test]
+		fn recompute_path_to_last_waypoint() {
+			let mut app = setup();
+			let computer = app
+				.world_mut()
+				.spawn(_ComputePath::new().with_mock(|mock| {
+					mock.expect_path_obstructed().return_const(true);
+					mock.expect_compute_path()
+						.times(1)
+						.with(
+							eq(Vec3::new(1., 2., 3.)),
+							eq(Vec3::new(7., 8., 9.)),
+							eq(Units::from(42.)),
+						)
+						.return_const(Some(iter![
+							GroundPosition(Vec3::splat(10.)),
+							GroundPosition(Vec3::splat(11.)),
+						]));
+				}))
+				.id();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Config {
+						required_clearance: RequiredClearance {
+							vertical: Units::from_u8(0),
+							horizontal: Units::from_u8(42),
+						},
+						..default()
+					},
+					Movement::Path(MovementPath::from([
+						Vec3::new(4., 5., 6.),
+						Vec3::new(7., 8., 9.),
+					])),
+					GlobalTransform::from_xyz(1., 2., 3.),
+					_GetComputer(computer),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&Movement::Path(MovementPath::from([
+					Vec3::splat(10.),
+					Vec3::splat(11.),
+				]))),
+				app.world().entity(entity).get::<Movement>()
+			);
+		}
+
+		#[test]
+		fn check_obstruction_with_remaining_waypoints() {
+			let mut app = setup();
+			let computer = app
+				.world_mut()
+				.spawn(_ComputePath::new().with_mock(|mock| {
+					mock.expect_path_obstructed()
+						.times(1)
+						.withf(|start, waypoints, required_clearance| {
+							start == &Vec3::new(1., 2., 3.)
+								&& waypoints == [Vec3::new(4., 5., 6.), Vec3::new(7., 8., 9.)]
+								&& required_clearance == &Units::from(42.)
+						})
+						.return_const(false);
+					mock.expect_compute_path().never().return_const(None);
+				}))
+				.id();
+			app.world_mut().spawn((
+				Config {
+					required_clearance: RequiredClearance {
+						vertical: Units::from_u8(0),
+						horizontal: Units::from_u8(42),
+					},
+					..default()
+				},
+				Movement::Path(MovementPath::from([
+					Vec3::new(4., 5., 6.),
+					Vec3::new(7., 8., 9.),
+				])),
+				GlobalTransform::from_xyz(1., 2., 3.),
+				_GetComputer(computer),
+			));
+
+			app.update();
+		}
+
+		#[test]
+		fn keep_path_when_not_obstructed() {
+			let mut app = setup();
+			let computer = app
+				.world_mut()
+				.spawn(_ComputePath::new().with_mock(|mock| {
+					mock.expect_path_obstructed().return_const(false);
+					mock.expect_compute_path().never().return_const(None);
+				}))
+				.id();
+			let entity = app
+				.world_mut()
+				.spawn((
+					Config::default(),
+					Movement::Path(MovementPath::from([Vec3::splat(1.)])),
+					GlobalTransform::default(),
+					_GetComputer(computer),
+				))
+				.id();
+
+			app.update();
+
+			assert_eq!(
+				Some(&Movement::Path(MovementPath::from([Vec3::splat(1.)]))),
+				app.world().entity(entity).get::<Movement>()
+			);
+		}
+
+		#[test]
+		fn check_obstruction_only_when_computer_changed() {
+			let mut app = setup();
+			let computer = app
+				.world_mut()
+				.spawn(_ComputePath::new().with_mock(|mock| {
+					mock.expect_path_obstructed().times(1).return_const(false);
+					mock.expect_compute_path().never().return_const(None);
+				}))
+				.id();
+			app.world_mut().spawn((
+				Config::default(),
+				Movement::Path(MovementPath::from([Vec3::splat(1.)])),
+				GlobalTransform::default(),
+				_GetComputer(computer),
+			));
+
+			app.update();
+			app.update();
+		}
+	}
+
 	mod direction {
 		use super::*;
 
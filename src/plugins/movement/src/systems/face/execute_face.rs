@@ -59,6 +59,7 @@ where
 	let cursor = match target {
 		SkillTarget::Entity(entity) => return get_translation(commands.get(entity), transforms),
 		SkillTarget::Cursor(cursor) => cursor,
+		SkillTarget::Caster => return None,
 	};
 
 	let hover = hover.raycast(MouseHover {
@@ -249,6 +250,26 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn keep_facing_when_targeting_caster() {
+		let mut app = setup();
+		let agent = app
+			.world_mut()
+			.spawn((
+				Transform::from_xyz(4., 5., 6.).looking_to(Dir3::X, Dir3::Y),
+				_Face(Face::SkillTarget),
+				_Target(Some(SkillTarget::Caster)),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(4., 5., 6.).looking_to(Dir3::X, Dir3::Y)),
+			app.world().entity(agent).get::<Transform>()
+		);
+	}
+
 	#[test]
 	fn face_hovering_entity() {
 		let mut app = setup();
@@ -0,0 +1,75 @@
+use crate::components::forced_movement::ForcedMovement;
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+impl ForcedMovement {
+	pub(crate) fn tick(
+		In(delta): In<Duration>,
+		mut commands: ZyheedaCommands,
+		mut movements: Query<(Entity, &mut Self)>,
+	) {
+		for (entity, mut movement) in &mut movements {
+			if movement.remaining > delta {
+				movement.remaining -= delta;
+				continue;
+			}
+
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_remove::<Self>();
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		App::new().single_threaded(Update)
+	}
+
+	fn forced_movement(remaining: Duration) -> ForcedMovement {
+		ForcedMovement {
+			target: Vec3::ZERO,
+			speed: UnitsPerSecond::from(1.),
+			remaining,
+		}
+	}
+
+	#[test]
+	fn reduce_remaining() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn(forced_movement(Duration::from_millis(300)))
+			.id();
+
+		app.world_mut()
+			.run_system_once_with(ForcedMovement::tick, Duration::from_millis(100))?;
+
+		assert_eq!(
+			Some(&forced_movement(Duration::from_millis(200))),
+			app.world().entity(entity).get::<ForcedMovement>(),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn remove_when_elapsed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn(forced_movement(Duration::from_millis(300)))
+			.id();
+
+		app.world_mut()
+			.run_system_once_with(ForcedMovement::tick, Duration::from_millis(300))?;
+
+		assert_eq!(None, app.world().entity(entity).get::<ForcedMovement>());
+		Ok(())
+	}
+}
@@ -1,4 +1,8 @@
-use crate::components::config::{Config, SpeedIndex};
+use crate::components::{
+	config::{Config, SpeedIndex},
+	equipment_speed_factor::EquipmentSpeedFactor,
+	forced_movement::ForcedMovement,
+};
 use bevy::prelude::*;
 use common::prelude::*;
 
@@ -7,23 +11,49 @@ impl<T> UpdateSpeed for T where T: Component + From<CharacterMotion> + View<Char
 pub(crate) trait UpdateSpeed:
 	Component + From<CharacterMotion> + View<CharacterMotion> + Sized
 {
-	fn update_speed(
+	#[allow(clippy::type_complexity)]
+	fn update_speed<TStatusEffects>(
 		mut commands: ZyheedaCommands,
-		motions: Query<(Entity, &Self, &Config, &SpeedIndex), Changed<SpeedIndex>>,
-	) {
-		for (entity, motion, config, speed_index) in motions {
-			let new_motion = match motion.view() {
+		motions: Query<
+			(
+				Entity,
+				&Self,
+				&Config,
+				Ref<SpeedIndex>,
+				Option<&TStatusEffects>,
+				Option<&EquipmentSpeedFactor>,
+			),
+			(
+				Or<(
+					Changed<SpeedIndex>,
+					Changed<TStatusEffects>,
+					Changed<EquipmentSpeedFactor>,
+				)>,
+				Without<ForcedMovement>,
+			),
+		>,
+	) where
+		TStatusEffects: Component + View<MovementSpeedFactor>,
+	{
+		for (entity, motion, config, speed_index, status_effects, equipment) in motions {
+			let factor = status_effects.map_or(1., |effects| effects.view())
+				* equipment.map_or(1., |EquipmentSpeedFactor(factor)| *factor);
+			let speed = config.speed(*speed_index, factor);
+			let current_motion = motion.view();
+			let new_motion = match current_motion {
 				CharacterMotion::Done => continue,
-				CharacterMotion::Direction { direction, .. } => CharacterMotion::Direction {
-					speed: Speed(config[*speed_index]),
-					direction,
-				},
-				CharacterMotion::ToTarget { target, .. } => CharacterMotion::ToTarget {
-					speed: Speed(config[*speed_index]),
-					target,
-				},
+				CharacterMotion::Direction { direction, .. } => {
+					CharacterMotion::Direction { speed, direction }
+				}
+				CharacterMotion::ToTarget { target, .. } => {
+					CharacterMotion::ToTarget { speed, target }
+				}
 			};
 
+			if !speed_index.is_changed() && new_motion == current_motion {
+				continue;
+			}
+
 			commands.try_apply_on(&entity, |mut e| {
 				e.try_insert(Self::from(new_motion));
 			});
@@ -52,6 +82,15 @@ mod tests {
 		}
 	}
 
+	#[derive(Component)]
+	struct _StatusEffects(f32);
+
+	impl View<MovementSpeedFactor> for _StatusEffects {
+		fn view(&self) -> f32 {
+			self.0
+		}
+	}
+
 	const SLOW: UnitsPerSecond = UnitsPerSecond::from_u8(1);
 	const FAST: UnitsPerSecond = UnitsPerSecond::from_u8(2);
 
@@ -74,7 +113,11 @@ mod tests {
 
 		app.add_systems(
 			Update,
-			(_Motion::update_speed, IsChanged::<_Motion>::detect).chain(),
+			(
+				_Motion::update_speed::<_StatusEffects>,
+				IsChanged::<_Motion>::detect,
+			)
+				.chain(),
 		);
 
 		app
@@ -105,6 +148,38 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn do_not_update_speed_of_forced_movement() {
+		let mut app = setup();
+		let motion = CharacterMotion::ToTarget {
+			target: Vec3::Z,
+			speed: Speed(UnitsPerSecond::from(20.)),
+		};
+		let entity = app
+			.world_mut()
+			.spawn((
+				_Motion(motion),
+				Config {
+					speed: MovementSpeed::Variable([SLOW, FAST]),
+					..default()
+				},
+				SpeedIndex(SpeedToggle::Right),
+				ForcedMovement {
+					target: Vec3::Z,
+					speed: UnitsPerSecond::from(20.),
+					remaining: std::time::Duration::from_millis(200),
+				},
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&_Motion(motion)),
+			app.world().entity(entity).get::<_Motion>(),
+		)
+	}
+
 	#[test]
 	fn keep_done_unchanged() {
 		let mut app = setup();
@@ -186,4 +261,91 @@ mod tests {
 			app.world().entity(entity).get::<IsChanged<_Motion>>(),
 		)
 	}
+
+	#[test_case(CharacterMotion::Direction { direction: Dir3::Z, speed: Speed(SLOW) }; "direction")]
+	#[test_case(CharacterMotion::ToTarget { target: Vec3::Z, speed: Speed(SLOW) }; "target")]
+	fn scale_speed_when_status_effects_change(motion: CharacterMotion) {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				_Motion(motion),
+				Config {
+					speed: MovementSpeed::Variable([SLOW, FAST]),
+					..default()
+				},
+				SpeedIndex(SpeedToggle::Right),
+				_StatusEffects(1.),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(_StatusEffects(0.5));
+		app.update();
+
+		assert_eq!(
+			Some(&_Motion(with_speed(motion, Speed(SLOW)))),
+			app.world().entity(entity).get::<_Motion>(),
+		)
+	}
+
+	#[test_case(CharacterMotion::Direction { direction: Dir3::Z, speed: Speed(SLOW) }; "direction")]
+	#[test_case(CharacterMotion::ToTarget { target: Vec3::Z, speed: Speed(SLOW) }; "target")]
+	fn do_not_reinsert_when_status_effects_change_without_speed_change(motion: CharacterMotion) {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				_Motion(motion),
+				Config {
+					speed: MovementSpeed::Variable([SLOW, FAST]),
+					..default()
+				},
+				SpeedIndex(SpeedToggle::Right),
+				_StatusEffects(1.),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(_StatusEffects(1.));
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<_Motion>>(),
+		)
+	}
+
+	#[test_case(CharacterMotion::Direction { direction: Dir3::Z, speed: Speed(FAST) }; "direction")]
+	#[test_case(CharacterMotion::ToTarget { target: Vec3::Z, speed: Speed(FAST) }; "target")]
+	fn scale_speed_when_equipment_changes(motion: CharacterMotion) {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				_Motion(motion),
+				Config {
+					speed: MovementSpeed::Variable([SLOW, FAST]),
+					..default()
+				},
+				SpeedIndex(SpeedToggle::Left),
+				EquipmentSpeedFactor(1.),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(EquipmentSpeedFactor(2.));
+		app.update();
+
+		assert_eq!(
+			Some(&_Motion(with_speed(motion, Speed(FAST)))),
+			app.world().entity(entity).get::<_Motion>(),
+		)
+	}
 }
@@ -0,0 +1,159 @@
+use bevy::prelude::*;
+use common::prelude::*;
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use path_finding::{
+	components::navigation::Navigation,
+	methods::{flow_field::FlowFieldCache, theta_star::ThetaStar},
+};
+use std::hint::black_box;
+
+const SIZE: i32 = 64;
+const TARGET: Vec3 = Vec3::new(60., 0., 60.);
+const AGENT_COUNTS: [usize; 3] = [10, 50, 200];
+
+/// 8-connected grid, split by walls with alternating gaps at the top and bottom
+#[derive(Default)]
+struct Grid;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Cell(i32, i32);
+
+impl Grid {
+	fn is_wall(Cell(x, z): &Cell) -> bool {
+		match x {
+			16 | 48 => *z < SIZE - 8,
+			32 => *z >= 8,
+			_ => false,
+		}
+	}
+
+	fn contains(Cell(x, z): &Cell) -> bool {
+		(0..SIZE).contains(x) && (0..SIZE).contains(z)
+	}
+}
+
+impl Graph for Grid {
+	type TNode = Cell;
+}
+
+impl GraphNode for Grid {
+	type TNNode = Cell;
+
+	fn node(&self, translation: Vec3) -> Option<Cell> {
+		let cell = Cell(translation.x.round() as i32, translation.z.round() as i32);
+
+		if !Self::contains(&cell) {
+			return None;
+		}
+
+		Some(cell)
+	}
+}
+
+impl GraphSuccessors for Grid {
+	type TSNode = Cell;
+
+	fn successors(&self, Cell(x, z): &Cell) -> impl Iterator<Item = Cell> {
+		let (x, z) = (*x, *z);
+
+		(-1..=1)
+			.flat_map(|d_x| (-1..=1).map(move |d_z| (d_x, d_z)))
+			.filter(|offset| offset != &(0, 0))
+			.map(move |(d_x, d_z)| Cell(x + d_x, z + d_z))
+			.filter(Self::contains)
+	}
+}
+
+impl GraphLineOfSight for Grid {
+	type TLNode = Cell;
+
+	fn line_of_sight(&self, a: &Cell, b: &Cell, _: Units) -> bool {
+		let a = Vec2::new(a.0 as f32, a.1 as f32);
+		let b = Vec2::new(b.0 as f32, b.1 as f32);
+		let steps = (a.distance(b) * 4.).ceil() as usize;
+
+		(0..=steps).all(|step| {
+			let point = a.lerp(b, step as f32 / steps.max(1) as f32);
+			!Self::is_wall(&Cell(point.x.round() as i32, point.y.round() as i32))
+		})
+	}
+}
+
+impl GraphObstacle for Grid {
+	type TONode = Cell;
+
+	fn is_obstacle(&self, node: &Cell, _: Units) -> bool {
+		Self::is_wall(node)
+	}
+}
+
+impl GraphGroundPosition for Grid {
+	type TTNode = Cell;
+
+	fn ground_position(&self, Cell(x, z): &Cell) -> GroundPosition {
+		GroundPosition(Vec3::new(*x as f32, 0., *z as f32))
+	}
+}
+
+impl GraphNaivePath for Grid {
+	type TNNode = Cell;
+
+	fn naive_path(&self, _: Vec3, _: &Cell, _: Units) -> NaivePath {
+		NaivePath::CannotCompute
+	}
+}
+
+/// Deterministic agent positions spread over the left half of the grid
+fn agents(count: usize) -> Vec<Vec3> {
+	let mut seed = 0x2545_f491_u32;
+	let mut next = move || {
+		seed ^= seed << 13;
+		seed ^= seed >> 17;
+		seed ^= seed << 5;
+		seed
+	};
+
+	(0..count)
+		.map(|_| {
+			let x = (next() % 15) as f32;
+			let z = (next() % SIZE as u32) as f32;
+			Vec3::new(x, 0., z)
+		})
+		.collect()
+}
+
+fn chase<TNavigation>(navigation: &TNavigation, agents: &[Vec3])
+where
+	TNavigation: ComputePath,
+{
+	for agent in agents {
+		let path = navigation.compute_path(*agent, TARGET, Units::from(0.4));
+		black_box(path.map(Iterator::count));
+	}
+}
+
+fn chasing_agents(c: &mut Criterion) {
+	let mut group = c.benchmark_group("chasing agents");
+
+	for count in AGENT_COUNTS {
+		let agents = agents(count);
+		let theta_star = Navigation::<ThetaStar, Grid>::default();
+		let flow_field = Navigation::<FlowFieldCache<Cell>, Grid>::default();
+
+		group.bench_with_input(
+			BenchmarkId::new("theta star", count),
+			&agents,
+			|b, agents| b.iter(|| chase(&theta_star, agents)),
+		);
+		group.bench_with_input(
+			BenchmarkId::new("flow field", count),
+			&agents,
+			|b, agents| b.iter(|| chase(&flow_field, agents)),
+		);
+	}
+
+	group.finish();
+}
+
+criterion_group!(benches, chasing_agents);
+criterion_main!(benches);
@@ -1 +1 @@
-pub(crate) mod navigation;
+pub mod navigation;
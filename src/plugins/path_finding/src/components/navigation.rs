@@ -13,7 +13,9 @@ where
 	for<'a> TGraph: From<&'a TMap> + ThreadSafe,
 	TMethod: Default + ThreadSafe,
 {
-	const INSERT: InsertDerivedComponent = InsertDerivedComponent::IfNew;
+	/// Maps are replaced when runtime obstacles change, which needs to discard the old graph and
+	/// any method caches computed on it.
+	const INSERT: InsertDerivedComponent = InsertDerivedComponent::Always;
 
 	type TParam = ();
 
@@ -69,6 +71,29 @@ where
 			next_buffered: None,
 		})
 	}
+
+	fn path_obstructed(&self, start: Vec3, waypoints: &[Vec3], required_clearance: Units) -> bool {
+		let mut nodes = std::iter::once(start)
+			.chain(waypoints.iter().copied())
+			.map(|point| self.graph.node(point));
+		let Some(mut current) = nodes.next() else {
+			return false;
+		};
+
+		for next in nodes {
+			let (Some(a), Some(b)) = (current, next) else {
+				return true;
+			};
+
+			if a != b && !self.graph.line_of_sight(&a, &b, required_clearance) {
+				return true;
+			}
+
+			current = next;
+		}
+
+		false
+	}
 }
 
 pub enum Iter<'a, TGraph>
@@ -706,4 +731,97 @@ mod tests {
 			path.map(|p| p.collect())
 		);
 	}
+
+	mod path_obstructed {
+		use super::*;
+
+		#[test]
+		fn not_obstructed_when_all_waypoints_in_line_of_sight() {
+			let grid = Navigation {
+				method: Mock_Method2::new_mock(|_| {}),
+				graph: Mock_Graph::new_mock(|mock| {
+					mock.expect_node()
+						.returning(|Vec3 { x, z, .. }| Some(_Node(x as u8, z as u8)));
+					mock.expect_line_of_sight().return_const(true);
+				}),
+			};
+
+			let obstructed = grid.path_obstructed(
+				Vec3::new(1., 0., 1.),
+				&[Vec3::new(2., 0., 2.), Vec3::new(3., 0., 3.)],
+				Units::from(0.1),
+			);
+
+			assert!(!obstructed);
+		}
+
+		#[test]
+		fn call_line_of_sight_for_consecutive_waypoints() {
+			let grid = Navigation {
+				method: Mock_Method2::new_mock(|_| {}),
+				graph: Mock_Graph::new_mock(|mock| {
+					mock.expect_node()
+						.returning(|Vec3 { x, z, .. }| Some(_Node(x as u8, z as u8)));
+					mock.expect_line_of_sight()
+						.times(1)
+						.with(eq(_Node(1, 1)), eq(_Node(2, 2)), eq(Units::from(0.1)))
+						.return_const(true);
+					mock.expect_line_of_sight()
+						.times(1)
+						.with(eq(_Node(2, 2)), eq(_Node(3, 3)), eq(Units::from(0.1)))
+						.return_const(true);
+				}),
+			};
+
+			_ = grid.path_obstructed(
+				Vec3::new(1., 0., 1.),
+				&[Vec3::new(2., 0., 2.), Vec3::new(3., 0., 3.)],
+				Units::from(0.1),
+			);
+		}
+
+		#[test]
+		fn obstructed_when_line_of_sight_interrupted() {
+			let grid = Navigation {
+				method: Mock_Method2::new_mock(|_| {}),
+				graph: Mock_Graph::new_mock(|mock| {
+					mock.expect_node()
+						.returning(|Vec3 { x, z, .. }| Some(_Node(x as u8, z as u8)));
+					mock.expect_line_of_sight()
+						.with(eq(_Node(1, 1)), eq(_Node(2, 2)), eq(Units::from(0.1)))
+						.return_const(true);
+					mock.expect_line_of_sight()
+						.with(eq(_Node(2, 2)), eq(_Node(3, 3)), eq(Units::from(0.1)))
+						.return_const(false);
+				}),
+			};
+
+			let obstructed = grid.path_obstructed(
+				Vec3::new(1., 0., 1.),
+				&[Vec3::new(2., 0., 2.), Vec3::new(3., 0., 3.)],
+				Units::from(0.1),
+			);
+
+			assert!(obstructed);
+		}
+
+		#[test]
+		fn obstructed_when_waypoint_not_on_graph() {
+			let grid = Navigation {
+				method: Mock_Method2::new_mock(|_| {}),
+				graph: Mock_Graph::new_mock(|mock| {
+					mock.expect_node().return_const(None);
+					mock.expect_line_of_sight().return_const(true);
+				}),
+			};
+
+			let obstructed = grid.path_obstructed(
+				Vec3::new(1., 0., 1.),
+				&[Vec3::new(2., 0., 2.)],
+				Units::from(0.1),
+			);
+
+			assert!(obstructed);
+		}
+	}
 }
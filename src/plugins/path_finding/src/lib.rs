@@ -1,16 +1,20 @@
-mod methods;
 mod tools;
 mod traits;
 
 pub mod components;
+pub mod methods;
 
 use bevy::prelude::*;
 use common::prelude::*;
 use components::navigation::Navigation;
-use methods::theta_star::ThetaStar;
+use methods::{flow_field::FlowField, theta_star::ThetaStar};
 use std::marker::PhantomData;
+use traits::navigation_method::NavigationMethod;
 
-pub struct PathFindingPlugin<TMap>(PhantomData<TMap>);
+/// Computes paths with `TMethod`, except for chasing agents, which use `TChaseMethod`.
+pub struct PathFindingPlugin<TMap, TMethod = ThetaStar, TChaseMethod = FlowField>(
+	PhantomData<(TMap, TMethod, TChaseMethod)>,
+);
 
 impl<TMaps> PathFindingPlugin<TMaps>
 where
@@ -21,26 +25,59 @@ where
 	}
 }
 
-impl<TMaps> Plugin for PathFindingPlugin<TMaps>
+impl<TMaps, TMethod, TChaseMethod> PathFindingPlugin<TMaps, TMethod, TChaseMethod>
 where
 	TMaps: HandlesMapGeneration + ThreadSafe,
+{
+	/// Replace the default [`ThetaStar`] path computation.
+	pub fn with_method<TNewMethod>(self) -> PathFindingPlugin<TMaps, TNewMethod, TChaseMethod>
+	where
+		TNewMethod: NavigationMethod<TMaps::TGraph> + ThreadSafe,
+	{
+		PathFindingPlugin(PhantomData)
+	}
+
+	/// Replace the default [`FlowField`] path computation of chasing agents.
+	pub fn with_chase_method<TNewMethod>(self) -> PathFindingPlugin<TMaps, TMethod, TNewMethod>
+	where
+		TNewMethod: NavigationMethod<TMaps::TGraph> + ThreadSafe,
+	{
+		PathFindingPlugin(PhantomData)
+	}
+}
+
+impl<TMaps, TMethod, TChaseMethod> Plugin for PathFindingPlugin<TMaps, TMethod, TChaseMethod>
+where
+	TMaps: HandlesMapGeneration + ThreadSafe,
+	TMethod: NavigationMethod<TMaps::TGraph> + ThreadSafe,
+	TChaseMethod: NavigationMethod<TMaps::TGraph> + ThreadSafe,
 {
 	fn build(&self, app: &mut App) {
-		app.register_derived_component::<TMaps::TMap, Navigation<ThetaStar, TMaps::TGraph>>();
+		app.register_derived_component::<TMaps::TMap, Navigation<TMethod::TMethod, TMaps::TGraph>>(
+		)
+		.register_derived_component::<TMaps::TMap, Navigation<TChaseMethod::TMethod, TMaps::TGraph>>(
+		);
 	}
 }
 
-impl<TMaps> HandlesPathFinding for PathFindingPlugin<TMaps>
+impl<TMaps, TMethod, TChaseMethod> HandlesPathFinding
+	for PathFindingPlugin<TMaps, TMethod, TChaseMethod>
 where
 	TMaps: HandlesMapGeneration + ThreadSafe,
+	TMethod: NavigationMethod<TMaps::TGraph> + ThreadSafe,
+	TChaseMethod: NavigationMethod<TMaps::TGraph> + ThreadSafe,
 {
-	type TComputePath = Navigation<ThetaStar, TMaps::TGraph>;
+	type TComputePath = Navigation<TMethod::TMethod, TMaps::TGraph>;
+	type TComputeChasePath = Navigation<TChaseMethod::TMethod, TMaps::TGraph>;
 	type TComputerRef = TMaps::TMapRef;
 }
 
-impl<TMaps> SystemSetDefinition for PathFindingPlugin<TMaps>
+impl<TMaps, TMethod, TChaseMethod> SystemSetDefinition
+	for PathFindingPlugin<TMaps, TMethod, TChaseMethod>
 where
 	TMaps: SystemSetDefinition + ThreadSafe,
+	TMethod: ThreadSafe,
+	TChaseMethod: ThreadSafe,
 {
 	type TSystemSet = TMaps::TSystemSet;
 
@@ -1 +1,2 @@
-pub(crate) mod theta_star;
+pub mod flow_field;
+pub mod theta_star;
@@ -0,0 +1,432 @@
+use crate::{
+	tools::{g_scores::GScores, open_list::OpenList},
+	traits::{compute_path_lazy::ComputePathLazy, navigation_method::NavigationMethod},
+};
+use bevy::math::Vec3Swizzles;
+use common::prelude::*;
+use std::{
+	collections::{HashSet, VecDeque},
+	hash::Hash,
+	sync::{Mutex, MutexGuard},
+};
+
+/// Navigation method sharing one integration field per target between all agents.
+///
+/// Suited for many agents moving towards the same target, like enemies chasing a player.
+#[derive(Debug, PartialEq, Default, Clone, Copy)]
+pub struct FlowField;
+
+impl<TGraph> NavigationMethod<TGraph> for FlowField
+where
+	TGraph: Graph,
+	TGraph::TNode: ThreadSafe,
+{
+	type TMethod = FlowFieldCache<TGraph::TNode>;
+}
+
+/// Integration fields of the most recently requested targets.
+///
+/// A field is keyed by its target node and the required clearance, so it is only
+/// recomputed once a target moves into another node.
+pub struct FlowFieldCache<TNode>
+where
+	TNode: Eq + Hash,
+{
+	fields: Mutex<VecDeque<IntegrationField<TNode>>>,
+}
+
+impl<TNode> FlowFieldCache<TNode>
+where
+	TNode: Eq + Hash + Copy,
+{
+	const MAX_FIELDS: usize = 4;
+
+	fn fields(&self) -> MutexGuard<'_, VecDeque<IntegrationField<TNode>>> {
+		match self.fields.lock() {
+			Ok(fields) => fields,
+			Err(poisoned) => poisoned.into_inner(),
+		}
+	}
+
+	fn path<TGraph>(
+		graph: &TGraph,
+		field: &IntegrationField<TNode>,
+		start: TNode,
+		required_clearance: Units,
+	) -> Vec<TNode>
+	where
+		TGraph: GraphSuccessors<TSNode = TNode>
+			+ GraphLineOfSight<TLNode = TNode>
+			+ GraphObstacle<TONode = TNode>,
+	{
+		let mut current = start;
+		let mut current_cost = field.costs.get(&current);
+		let mut visited = HashSet::from([current]);
+		let mut nodes = vec![current];
+
+		if current_cost.is_infinite() {
+			return vec![];
+		}
+
+		while current != field.target {
+			let next = graph
+				.successors(&current)
+				.filter(|node| !graph.is_obstacle(node, required_clearance))
+				.map(|node| (node, field.costs.get(&node)))
+				.filter(|(node, cost)| *cost < current_cost && !visited.contains(node))
+				.min_by(|(_, a), (_, b)| a.total_cmp(b));
+
+			let Some((next, next_cost)) = next else {
+				return vec![];
+			};
+
+			visited.insert(next);
+			nodes.push(next);
+			current = next;
+			current_cost = next_cost;
+		}
+
+		Self::skip_visible(graph, nodes, required_clearance)
+	}
+
+	/// Only keeps nodes, where line of sight to the last kept node is interrupted.
+	fn skip_visible<TGraph>(
+		graph: &TGraph,
+		nodes: Vec<TNode>,
+		required_clearance: Units,
+	) -> Vec<TNode>
+	where
+		TGraph: GraphLineOfSight<TLNode = TNode>,
+	{
+		let [first, .., last] = nodes[..] else {
+			return nodes;
+		};
+		let mut path = vec![first];
+		let mut anchor = first;
+
+		for window in nodes.windows(2) {
+			let [previous, node] = window else {
+				continue;
+			};
+
+			if graph.line_of_sight(&anchor, node, required_clearance) {
+				continue;
+			}
+
+			path.push(*previous);
+			anchor = *previous;
+		}
+
+		path.push(last);
+		path
+	}
+}
+
+impl<TNode> Default for FlowFieldCache<TNode>
+where
+	TNode: Eq + Hash,
+{
+	fn default() -> Self {
+		Self {
+			fields: Mutex::new(VecDeque::default()),
+		}
+	}
+}
+
+impl<TGraph> ComputePathLazy<TGraph> for FlowFieldCache<TGraph::TSNode>
+where
+	TGraph::TSNode: Eq + Hash + Copy,
+	TGraph: GraphSuccessors
+		+ GraphLineOfSight<TLNode = TGraph::TSNode>
+		+ GraphObstacle<TONode = TGraph::TSNode>
+		+ GraphGroundPosition<TTNode = TGraph::TSNode>,
+{
+	fn compute_path(
+		&self,
+		graph: &TGraph,
+		start: TGraph::TSNode,
+		end: TGraph::TSNode,
+		required_clearance: Units,
+	) -> impl Iterator<Item = TGraph::TSNode> {
+		let mut fields = self.fields();
+		let cached = fields
+			.iter()
+			.position(|field| field.matches(end, required_clearance));
+		let field = match cached.and_then(|i| fields.remove(i)) {
+			Some(field) => field,
+			None => IntegrationField::compute(graph, end, required_clearance),
+		};
+
+		let path = Self::path(graph, &field, start, required_clearance);
+
+		fields.push_back(field);
+		while fields.len() > Self::MAX_FIELDS {
+			fields.pop_front();
+		}
+
+		path.into_iter()
+	}
+}
+
+struct IntegrationField<TNode>
+where
+	TNode: Eq + Hash,
+{
+	target: TNode,
+	required_clearance: Units,
+	costs: GScores<TNode>,
+}
+
+impl<TNode> IntegrationField<TNode>
+where
+	TNode: Eq + Hash + Copy,
+{
+	fn matches(&self, target: TNode, required_clearance: Units) -> bool {
+		self.target == target && self.required_clearance == required_clearance
+	}
+
+	fn compute<TGraph>(graph: &TGraph, target: TNode, required_clearance: Units) -> Self
+	where
+		TGraph: GraphSuccessors<TSNode = TNode>
+			+ GraphObstacle<TONode = TNode>
+			+ GraphGroundPosition<TTNode = TNode>,
+	{
+		let mut open = OpenList::new(target, target, |_, _| 0.);
+		let mut costs = GScores::new(target);
+		let mut closed = HashSet::new();
+
+		while let Some(current) = open.pop_lowest_f() {
+			if !closed.insert(current) {
+				continue;
+			}
+
+			let current_cost = costs.get(&current);
+			let current_position = graph.ground_position(&current);
+
+			for neighbor in graph.successors(&current) {
+				if closed.contains(&neighbor) || graph.is_obstacle(&neighbor, required_clearance) {
+					continue;
+				}
+
+				let neighbor_position = graph.ground_position(&neighbor);
+				let cost = current_cost + current_position.xz().distance(neighbor_position.xz());
+
+				if cost >= costs.get(&neighbor) {
+					continue;
+				}
+
+				costs.insert(neighbor, cost);
+				open.push(neighbor, cost);
+			}
+		}
+
+		Self {
+			target,
+			required_clearance,
+			costs,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::prelude::*;
+	use std::cell::Cell;
+
+	#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+	struct _Node(i8, i8);
+
+	/// 5 x 5 grid, optionally with walls and nodes blocked for large clearances
+	struct _Graph {
+		walls: Vec<_Node>,
+		narrow: Vec<_Node>,
+		successor_calls: Cell<usize>,
+	}
+
+	impl _Graph {
+		fn new(walls: impl Into<Vec<_Node>>) -> Self {
+			Self {
+				walls: walls.into(),
+				narrow: vec![],
+				successor_calls: Cell::new(0),
+			}
+		}
+
+		fn with_narrow(mut self, narrow: impl Into<Vec<_Node>>) -> Self {
+			self.narrow = narrow.into();
+			self
+		}
+	}
+
+	impl GraphSuccessors for _Graph {
+		type TSNode = _Node;
+
+		fn successors(&self, _Node(x, z): &_Node) -> impl Iterator<Item = _Node> {
+			self.successor_calls.set(self.successor_calls.get() + 1);
+			let (x, z) = (*x, *z);
+
+			[(-1, 0), (1, 0), (0, -1), (0, 1)]
+				.into_iter()
+				.map(move |(d_x, d_z)| _Node(x + d_x, z + d_z))
+				.filter(|_Node(x, z)| (0..5).contains(x) && (0..5).contains(z))
+		}
+	}
+
+	impl GraphObstacle for _Graph {
+		type TONode = _Node;
+
+		fn is_obstacle(&self, node: &_Node, required_clearance: Units) -> bool {
+			self.walls.contains(node)
+				|| (required_clearance > Units::from(0.5) && self.narrow.contains(node))
+		}
+	}
+
+	impl GraphLineOfSight for _Graph {
+		type TLNode = _Node;
+
+		fn line_of_sight(&self, a: &_Node, b: &_Node, _: Units) -> bool {
+			a.0 == b.0 || a.1 == b.1
+		}
+	}
+
+	impl GraphGroundPosition for _Graph {
+		type TTNode = _Node;
+
+		fn ground_position(&self, _Node(x, z): &_Node) -> GroundPosition {
+			GroundPosition(Vec3::new(*x as f32, 0., *z as f32))
+		}
+	}
+
+	/// Leaves a single winding corridor: up, right, down, right
+	const WALLS: [_Node; 8] = [
+		_Node(1, 0),
+		_Node(1, 1),
+		_Node(1, 2),
+		_Node(1, 3),
+		_Node(3, 1),
+		_Node(3, 2),
+		_Node(3, 3),
+		_Node(3, 4),
+	];
+
+	fn compute(
+		cache: &FlowFieldCache<_Node>,
+		graph: &_Graph,
+		start: _Node,
+		end: _Node,
+		required_clearance: Units,
+	) -> Vec<_Node> {
+		cache
+			.compute_path(graph, start, end, required_clearance)
+			.collect()
+	}
+
+	#[test]
+	fn straight_path() {
+		let cache = FlowFieldCache::default();
+		let graph = _Graph::new([]);
+
+		let path = compute(&cache, &graph, _Node(0, 0), _Node(4, 0), Units::from(0.1));
+
+		assert_eq!(vec![_Node(0, 0), _Node(4, 0)], path);
+	}
+
+	#[test]
+	fn path_around_walls() {
+		let cache = FlowFieldCache::default();
+		let graph = _Graph::new(WALLS);
+
+		let path = compute(&cache, &graph, _Node(0, 0), _Node(4, 0), Units::from(0.1));
+
+		assert_eq!(
+			vec![
+				_Node(0, 0),
+				_Node(0, 4),
+				_Node(2, 4),
+				_Node(2, 0),
+				_Node(4, 0)
+			],
+			path
+		);
+	}
+
+	#[test]
+	fn no_path_when_target_unreachable() {
+		let cache = FlowFieldCache::default();
+		let graph = _Graph::new([
+			_Node(2, 0),
+			_Node(2, 1),
+			_Node(2, 2),
+			_Node(2, 3),
+			_Node(2, 4),
+		]);
+
+		let path = compute(&cache, &graph, _Node(0, 0), _Node(4, 0), Units::from(0.1));
+
+		assert_eq!(vec![] as Vec<_Node>, path);
+	}
+
+	#[test]
+	fn respect_required_clearance() {
+		let cache = FlowFieldCache::default();
+		let graph = _Graph::new(WALLS).with_narrow([_Node(1, 4)]);
+
+		let narrow = compute(&cache, &graph, _Node(0, 0), _Node(4, 0), Units::from(0.1));
+		let wide = compute(&cache, &graph, _Node(0, 0), _Node(4, 0), Units::from(1.));
+
+		assert_eq!(
+			(
+				vec![
+					_Node(0, 0),
+					_Node(0, 4),
+					_Node(2, 4),
+					_Node(2, 0),
+					_Node(4, 0)
+				],
+				vec![] as Vec<_Node>
+			),
+			(narrow, wide)
+		);
+	}
+
+	#[test]
+	fn reuse_field_for_same_target() {
+		let cache = FlowFieldCache::default();
+		let graph = _Graph::new([]);
+
+		_ = compute(&cache, &graph, _Node(0, 0), _Node(4, 4), Units::from(0.1));
+		let calls_after_first = graph.successor_calls.get();
+		_ = compute(&cache, &graph, _Node(1, 0), _Node(4, 4), Units::from(0.1));
+		let calls_of_second = graph.successor_calls.get() - calls_after_first;
+
+		assert!(
+			calls_of_second < calls_after_first,
+			"expected sampling ({calls_of_second} calls) to be cheaper than computing the field \
+			 ({calls_after_first} calls)"
+		);
+	}
+
+	#[test]
+	fn recompute_field_when_target_changed() {
+		let cache = FlowFieldCache::default();
+		let graph = _Graph::new([]);
+
+		_ = compute(&cache, &graph, _Node(0, 0), _Node(4, 4), Units::from(0.1));
+		let path = compute(&cache, &graph, _Node(0, 0), _Node(0, 4), Units::from(0.1));
+
+		assert_eq!(vec![_Node(0, 0), _Node(0, 4)], path);
+	}
+
+	#[test]
+	fn keep_limited_amount_of_fields() {
+		let cache = FlowFieldCache::default();
+		let graph = _Graph::new([]);
+
+		for x in 0..5 {
+			_ = compute(&cache, &graph, _Node(0, 0), _Node(x, 4), Units::from(0.1));
+		}
+
+		assert_eq!(FlowFieldCache::<_Node>::MAX_FIELDS, cache.fields().len());
+	}
+}
@@ -1,6 +1,6 @@
 use crate::{
 	tools::{closed_list::ClosedList, g_scores::GScores, open_list::OpenList},
-	traits::compute_path_lazy::ComputePathLazy,
+	traits::{compute_path_lazy::ComputePathLazy, navigation_method::NavigationMethod},
 };
 use common::prelude::*;
 use std::hash::Hash;
@@ -76,6 +76,13 @@ impl Default for ThetaStar {
 	}
 }
 
+impl<TGraph> NavigationMethod<TGraph> for ThetaStar
+where
+	TGraph: Graph,
+{
+	type TMethod = Self;
+}
+
 impl<TGraph> ComputePathLazy<TGraph> for ThetaStar
 where
 	TGraph::TSNode: Eq + Hash + Copy,
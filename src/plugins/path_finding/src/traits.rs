@@ -1 +1,2 @@
 pub(crate) mod compute_path_lazy;
+pub(crate) mod navigation_method;
@@ -0,0 +1,10 @@
+use crate::traits::compute_path_lazy::ComputePathLazy;
+use common::prelude::*;
+
+/// Selects the path computation used by [`crate::components::navigation::Navigation`].
+pub trait NavigationMethod<TGraph>
+where
+	TGraph: Graph,
+{
+	type TMethod: ComputePathLazy<TGraph> + Default + ThreadSafe;
+}
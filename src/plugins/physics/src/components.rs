@@ -1,16 +1,21 @@
 pub(crate) mod affected;
 pub(crate) mod anchor;
 pub(crate) mod async_collider;
+pub(crate) mod ballistic;
 pub(crate) mod blockable;
 pub(crate) mod blocker_types;
 pub(crate) mod body;
+pub(crate) mod chain_jumps;
 pub(crate) mod character_gravity;
 pub(crate) mod character_motion;
 pub(crate) mod collider;
 pub(crate) mod collision_domains;
 pub(crate) mod default_attributes;
 pub(crate) mod effects;
+pub(crate) mod equipment_attributes;
+pub(crate) mod follow_ups;
 pub(crate) mod ground_target;
+pub(crate) mod homing;
 pub(crate) mod immobilized;
 pub(crate) mod lifetime;
 pub(crate) mod motion_controller;
@@ -19,10 +24,13 @@ pub(crate) mod offset;
 pub(crate) mod ongoing_effects;
 pub(crate) mod persistent_root;
 pub(crate) mod prevent_tunneling;
+pub(crate) mod remaining_impacts;
 pub(crate) mod set_velocity_forward;
 pub(crate) mod skill;
 pub(crate) mod skill_transform;
+pub(crate) mod sweep;
 pub(crate) mod target;
+pub(crate) mod timed_statuses;
 pub(crate) mod velocity;
 pub(crate) mod when_traveled;
 
@@ -1,3 +1,7 @@
+pub(crate) mod damage_resistances;
+pub(crate) mod energy_pool;
 pub(crate) mod force_affected;
 pub(crate) mod gravity_affected;
+pub(crate) mod incoming_damage;
 pub(crate) mod life;
+pub(crate) mod status_effects;
@@ -0,0 +1,19 @@
+use crate::systems::insert_affected::AffectedComponent;
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[savable_component(id = "damage resistances")]
+pub struct DamageResistances(pub(crate) Resistances);
+
+impl From<Resistances> for DamageResistances {
+	fn from(resistances: Resistances) -> Self {
+		Self(resistances)
+	}
+}
+
+impl AffectedComponent for DamageResistances {
+	type TAttribute = Resistances;
+}
@@ -0,0 +1,69 @@
+use crate::systems::insert_affected::AffectedComponent;
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+#[savable_component(id = "energy pool")]
+pub struct EnergyPool(pub(crate) Energy);
+
+impl From<Energy> for EnergyPool {
+	fn from(energy: Energy) -> Self {
+		Self(energy)
+	}
+}
+
+impl AffectedComponent for EnergyPool {
+	type TAttribute = Energy;
+}
+
+impl View<Energy> for EnergyPool {
+	fn view(&self) -> Energy {
+		self.0
+	}
+}
+
+impl SpendEnergy for EnergyPool {
+	fn spend_energy(&mut self, amount: f32) {
+		let Energy { current, .. } = &mut self.0;
+		*current = (*current - amount).max(0.);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn spend_energy() {
+		let mut pool = EnergyPool(Energy::new(10., 0.));
+
+		pool.spend_energy(3.);
+
+		assert_eq!(
+			EnergyPool(Energy {
+				current: 7.,
+				max: 10.,
+				regeneration: 0.
+			}),
+			pool
+		);
+	}
+
+	#[test]
+	fn do_not_spend_below_zero() {
+		let mut pool = EnergyPool(Energy::new(10., 0.));
+
+		pool.spend_energy(11.);
+
+		assert_eq!(
+			EnergyPool(Energy {
+				current: 0.,
+				max: 10.,
+				regeneration: 0.
+			}),
+			pool
+		);
+	}
+}
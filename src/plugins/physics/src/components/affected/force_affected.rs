@@ -1,19 +1,170 @@
-use crate::systems::insert_affected::AffectedComponent;
+use crate::systems::{apply_push::PushAbleByForce, insert_affected::AffectedComponent};
 use bevy::prelude::*;
 use common::prelude::*;
 use macros::SavableComponent;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::vec::Drain;
 
-#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
-#[savable_component(id = "force affected")]
-pub struct ForceAffected(pub(crate) EffectTarget<Force>);
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "force affected", migrations = [wrap_affected])]
+pub enum ForceAffected {
+	AffectedBy {
+		#[serde(default, skip_serializing_if = "Vec::is_empty")]
+		pushes: Vec<ForcePush>,
+	},
+	Immune,
+}
+
+impl ForceAffected {
+	pub(crate) fn affected<T>(pushes: T) -> Self
+	where
+		T: IntoIterator<Item = ForcePush>,
+	{
+		Self::AffectedBy {
+			pushes: pushes.into_iter().collect(),
+		}
+	}
+
+	pub(crate) fn push(&mut self, push: ForcePush) {
+		match self {
+			ForceAffected::AffectedBy { pushes } => pushes.push(push),
+			ForceAffected::Immune => {}
+		}
+	}
+}
+
+/// Version 0 serialized the plain [`EffectTarget`].
+fn wrap_affected(affected: &mut Value) {
+	if affected != "Affected" {
+		return;
+	}
+
+	*affected = Value::Object(
+		[("AffectedBy".to_owned(), Value::Object(default()))]
+			.into_iter()
+			.collect(),
+	);
+}
+
+impl PushAbleByForce for ForceAffected {
+	type TDrain<'a> = DrainPushes<'a>;
+
+	fn is_pushed(&self) -> bool {
+		match self {
+			ForceAffected::AffectedBy { pushes } => !pushes.is_empty(),
+			ForceAffected::Immune => false,
+		}
+	}
+
+	fn drain_pushes(&mut self) -> Self::TDrain<'_> {
+		match self {
+			ForceAffected::AffectedBy { pushes } => DrainPushes::Iterator(pushes.drain(..)),
+			ForceAffected::Immune => DrainPushes::None,
+		}
+	}
+}
+
+pub(crate) enum DrainPushes<'a> {
+	Iterator(Drain<'a, ForcePush>),
+	None,
+}
+
+impl<'a> Iterator for DrainPushes<'a> {
+	type Item = ForcePush;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			DrainPushes::Iterator(it) => it.next(),
+			DrainPushes::None => None,
+		}
+	}
+}
 
 impl From<EffectTarget<Force>> for ForceAffected {
 	fn from(target: EffectTarget<Force>) -> Self {
-		Self(target)
+		match target {
+			EffectTarget::Affected => Self::affected([]),
+			EffectTarget::Immune => Self::Immune,
+		}
 	}
 }
 
 impl AffectedComponent for ForceAffected {
 	type TAttribute = EffectTarget<Force>;
 }
+
+#[derive(Debug, PartialEq, Default, Clone, Serialize, Deserialize)]
+pub struct ForcePush {
+	pub(crate) strength: UnitsPerSecond,
+	/// The root of the force, whose colliders define the volume to push out of.
+	pub(crate) from: PersistentEntity,
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use serde_json::json;
+	use test_case::test_case;
+
+	#[test_case(ForceAffected::affected([ForcePush::default()]), true; "true when affected")]
+	#[test_case(ForceAffected::affected([]), false; "false when affected and empty")]
+	#[test_case(ForceAffected::Immune, false; "false when immune")]
+	fn is_pushed(affected: ForceAffected, expected: bool) {
+		assert_eq!(expected, affected.is_pushed());
+	}
+
+	#[test]
+	fn drain_affected() {
+		let from = PersistentEntity::default();
+		let mut affected = ForceAffected::affected([
+			ForcePush {
+				strength: UnitsPerSecond::from(42.),
+				from,
+			},
+			ForcePush {
+				strength: UnitsPerSecond::from(11.),
+				from,
+			},
+		]);
+
+		let drained = affected.drain_pushes().collect::<Vec<_>>();
+		assert_eq!(
+			(
+				ForceAffected::affected([]),
+				vec![
+					ForcePush {
+						strength: UnitsPerSecond::from(42.),
+						from,
+					},
+					ForcePush {
+						strength: UnitsPerSecond::from(11.),
+						from,
+					}
+				]
+			),
+			(affected, drained)
+		)
+	}
+
+	#[test]
+	fn push_to_immune() {
+		let mut immune = ForceAffected::Immune;
+
+		immune.push(ForcePush::default());
+
+		assert_eq!(ForceAffected::Immune, immune);
+	}
+
+	#[test_case(json!("Affected"), ForceAffected::affected([]); "affected")]
+	#[test_case(json!("Immune"), ForceAffected::Immune; "immune")]
+	fn migrate_effect_target(mut value: Value, expected: ForceAffected) {
+		wrap_affected(&mut value);
+
+		assert_eq!(
+			expected,
+			serde_json::from_value::<ForceAffected>(value).unwrap(),
+		);
+	}
+}
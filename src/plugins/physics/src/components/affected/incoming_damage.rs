@@ -0,0 +1,22 @@
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+
+/// Damage collected from effects, resolved against life and resistances once per fixed update.
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[savable_component(id = "incoming damage")]
+pub struct IncomingDamage(pub(crate) Vec<Damage>);
+
+impl IncomingDamage {
+	pub(crate) fn push(&mut self, damage: Damage) {
+		self.0.push(damage);
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct Damage {
+	pub(crate) amount: f32,
+	pub(crate) damage_type: DamageType,
+	pub(crate) source: Option<PersistentEntity>,
+}
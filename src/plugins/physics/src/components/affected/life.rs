@@ -1,35 +1,99 @@
-use crate::systems::insert_affected::AffectedComponent;
+use crate::{
+	components::timed_statuses::TimedStatuses,
+	systems::insert_affected::AffectedComponent,
+};
 use bevy::prelude::*;
 use common::prelude::*;
 use macros::SavableComponent;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
 
-#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
-#[savable_component(id = "life")]
-pub struct Life(pub(crate) Health);
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "life", migrations = [wrap_health])]
+pub struct Life {
+	pub(crate) health: Health,
+	/// Remaining absorb amounts, consumed by damage before health.
+	#[serde(default)]
+	pub(crate) shields: TimedStatuses<f32>,
+	/// Max health currently granted by equipment, tracked to revert it on unequip.
+	#[serde(default)]
+	pub(crate) equipment_health: f32,
+}
 
 impl Life {
-	pub(crate) fn change_by(&mut self, health: f32) {
-		let Life(Health { current, max }) = self;
+	/// Change current health, with damage consumed by shields first.
+	///
+	/// Returns the change actually applied to current health.
+	pub(crate) fn change_by(&mut self, health: f32) -> f32 {
+		let Life {
+			health: Health { current, max },
+			shields,
+			..
+		} = self;
+		let mut health = health;
+
+		if health < 0. {
+			for shield in shields.values_mut() {
+				let absorbed = shield.min(-health);
+				*shield -= absorbed;
+				health += absorbed;
+			}
+			shields.retain(|shield| *shield > 0.);
+		}
 
+		let before = *current;
 		*current += health;
 		*current = current.min(*max);
+		*current - before
 	}
 
 	pub(crate) fn current_hp(&self) -> f32 {
-		self.0.current
+		self.health.current
 	}
+
+	/// Replace the max health granted by equipment, without healing.
+	///
+	/// Current health is capped when the max health drops.
+	pub(crate) fn set_equipment_health(&mut self, amount: f32) {
+		let Health { current, max } = &mut self.health;
+
+		*max += amount - self.equipment_health;
+		*current = current.min(*max);
+		self.equipment_health = amount;
+	}
+
+	pub(crate) fn add_shield(&mut self, amount: f32, duration: Duration, stacking: StatusStacking) {
+		self.shields.apply(amount, duration, stacking);
+	}
+}
+
+/// Version 0 serialized the plain [`Health`].
+fn wrap_health(life: &mut Value) {
+	let health = life.take();
+	*life = Value::Object([("health".to_owned(), health)].into_iter().collect());
 }
 
 impl From<Health> for Life {
 	fn from(health: Health) -> Self {
-		Self(health)
+		Self {
+			health,
+			shields: TimedStatuses::default(),
+			equipment_health: 0.,
+		}
 	}
 }
 
 impl View<Health> for Life {
 	fn view(&self) -> Health {
-		self.0
+		self.health
+	}
+}
+
+impl RaiseMaxHealth for Life {
+	fn raise_max_health(&mut self, amount: f32) {
+		self.health.max += amount;
+		self.health.current += amount;
 	}
 }
 
@@ -39,11 +103,14 @@ impl AffectedComponent for Life {
 
 #[cfg(test)]
 mod tests {
+	#![allow(clippy::unwrap_used)]
 	use super::*;
+	use crate::components::timed_statuses::TimedStatus;
+	use serde_json::json;
 
 	#[test]
 	fn update_life() {
-		let mut life = Life(Health {
+		let mut life = Life::from(Health {
 			current: 42.,
 			max: 100.,
 		});
@@ -51,7 +118,7 @@ mod tests {
 		life.change_by(11.);
 
 		assert_eq!(
-			Life(Health {
+			Life::from(Health {
 				current: 53.,
 				max: 100.,
 			}),
@@ -61,7 +128,7 @@ mod tests {
 
 	#[test]
 	fn do_not_surpass_max() {
-		let mut life = Life(Health {
+		let mut life = Life::from(Health {
 			current: 87.,
 			max: 100.,
 		});
@@ -69,11 +136,193 @@ mod tests {
 		life.change_by(101.);
 
 		assert_eq!(
-			Life(Health {
+			Life::from(Health {
 				current: 100.,
 				max: 100.,
 			}),
 			life
 		);
 	}
+
+	#[test]
+	fn raise_max_health() {
+		let mut life = Life::from(Health {
+			current: 42.,
+			max: 100.,
+		});
+
+		life.raise_max_health(10.);
+
+		assert_eq!(
+			Life::from(Health {
+				current: 52.,
+				max: 110.,
+			}),
+			life
+		);
+	}
+
+	#[test]
+	fn set_equipment_health_without_healing() {
+		let mut life = Life::from(Health {
+			current: 42.,
+			max: 100.,
+		});
+
+		life.set_equipment_health(20.);
+
+		assert_eq!(
+			Life {
+				health: Health {
+					current: 42.,
+					max: 120.,
+				},
+				equipment_health: 20.,
+				..Life::from(Health::new(0.))
+			},
+			life
+		);
+	}
+
+	#[test]
+	fn replace_equipment_health() {
+		let mut life = Life::from(Health {
+			current: 42.,
+			max: 100.,
+		});
+
+		life.set_equipment_health(20.);
+		life.set_equipment_health(5.);
+
+		assert_eq!(
+			Life {
+				health: Health {
+					current: 42.,
+					max: 105.,
+				},
+				equipment_health: 5.,
+				..Life::from(Health::new(0.))
+			},
+			life
+		);
+	}
+
+	#[test]
+	fn cap_current_health_when_removing_equipment_health() {
+		let mut life = Life::from(Health::new(100.));
+
+		life.set_equipment_health(20.);
+		life.change_by(20.);
+		life.set_equipment_health(0.);
+
+		assert_eq!(Life::from(Health::new(100.)), life);
+	}
+
+	#[test]
+	fn absorb_damage_with_shields() {
+		let mut life = Life::from(Health::new(100.));
+		life.add_shield(
+			10.,
+			Duration::from_secs(1),
+			StatusStacking::Stack { max: 2 },
+		);
+		life.add_shield(
+			20.,
+			Duration::from_secs(2),
+			StatusStacking::Stack { max: 2 },
+		);
+
+		life.change_by(-15.);
+
+		assert_eq!(
+			Life {
+				health: Health::new(100.),
+				shields: TimedStatuses::from([TimedStatus {
+					value: 15.,
+					remaining: Duration::from_secs(2),
+				}]),
+				equipment_health: 0.,
+			},
+			life
+		);
+	}
+
+	#[test]
+	fn reduce_health_by_damage_exceeding_shields() {
+		let mut life = Life::from(Health::new(100.));
+		life.add_shield(10., Duration::from_secs(1), StatusStacking::Refresh);
+
+		life.change_by(-15.);
+
+		assert_eq!(
+			Life::from(Health {
+				current: 95.,
+				max: 100.,
+			}),
+			life
+		);
+	}
+
+	#[test]
+	fn return_health_change_after_shields() {
+		let mut life = Life::from(Health::new(100.));
+		life.add_shield(10., Duration::from_secs(1), StatusStacking::Refresh);
+
+		let change = life.change_by(-15.);
+
+		assert_eq!(-5., change);
+	}
+
+	#[test]
+	fn return_health_change_capped_by_max() {
+		let mut life = Life::from(Health {
+			current: 95.,
+			max: 100.,
+		});
+
+		let change = life.change_by(15.);
+
+		assert_eq!(5., change);
+	}
+
+	#[test]
+	fn do_not_consume_shields_when_healing() {
+		let mut life = Life::from(Health {
+			current: 50.,
+			max: 100.,
+		});
+		life.add_shield(10., Duration::from_secs(1), StatusStacking::Refresh);
+
+		life.change_by(15.);
+
+		assert_eq!(
+			Life {
+				health: Health {
+					current: 65.,
+					max: 100.,
+				},
+				shields: TimedStatuses::from([TimedStatus {
+					value: 10.,
+					remaining: Duration::from_secs(1),
+				}]),
+				equipment_health: 0.,
+			},
+			life
+		);
+	}
+
+	#[test]
+	fn migrate_plain_health() {
+		let mut value = json!({"current": 42., "max": 100.});
+
+		wrap_health(&mut value);
+
+		assert_eq!(
+			Life::from(Health {
+				current: 42.,
+				max: 100.,
+			}),
+			serde_json::from_value::<Life>(value).unwrap(),
+		);
+	}
 }
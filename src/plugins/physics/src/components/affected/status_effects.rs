@@ -0,0 +1,99 @@
+use crate::components::timed_statuses::TimedStatuses;
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+#[savable_component(id = "status effects")]
+pub struct StatusEffects {
+	pub(crate) damage_over_time: TimedStatuses<DamagePerSecond>,
+	/// Fraction of removed speed
+	pub(crate) slows: TimedStatuses<f32>,
+	pub(crate) stuns: TimedStatuses<()>,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct DamagePerSecond {
+	pub(crate) amount: f32,
+	pub(crate) damage_type: DamageType,
+}
+
+impl StatusEffects {
+	pub(crate) fn is_empty(&self) -> bool {
+		self.damage_over_time.is_empty() && self.slows.is_empty() && self.stuns.is_empty()
+	}
+}
+
+impl View<Stunned> for StatusEffects {
+	fn view(&self) -> bool {
+		!self.stuns.is_empty()
+	}
+}
+
+impl View<MovementSpeedFactor> for StatusEffects {
+	fn view(&self) -> f32 {
+		self.slows
+			.iter()
+			.map(|slow| 1. - slow.value.clamp(0., 1.))
+			.product()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::timed_statuses::TimedStatus;
+	use std::time::Duration;
+
+	fn timed<T>(value: T) -> TimedStatus<T> {
+		TimedStatus {
+			value,
+			remaining: Duration::from_secs(1),
+		}
+	}
+
+	#[test]
+	fn not_stunned() {
+		let effects = StatusEffects::default();
+
+		assert!(!View::<Stunned>::view(&effects));
+	}
+
+	#[test]
+	fn stunned() {
+		let effects = StatusEffects {
+			stuns: TimedStatuses::from([timed(())]),
+			..default()
+		};
+
+		assert!(View::<Stunned>::view(&effects));
+	}
+
+	#[test]
+	fn full_speed_when_not_slowed() {
+		let effects = StatusEffects::default();
+
+		assert_eq!(1., View::<MovementSpeedFactor>::view(&effects));
+	}
+
+	#[test]
+	fn multiply_slows() {
+		let effects = StatusEffects {
+			slows: TimedStatuses::from([timed(0.5), timed(0.5)]),
+			..default()
+		};
+
+		assert_eq!(0.25, View::<MovementSpeedFactor>::view(&effects));
+	}
+
+	#[test]
+	fn clamp_slows() {
+		let effects = StatusEffects {
+			slows: TimedStatuses::from([timed(1.5), timed(-0.5)]),
+			..default()
+		};
+
+		assert_eq!(0., View::<MovementSpeedFactor>::view(&effects));
+	}
+}
@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Pulls a projectile down, so it flies in an arc.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct Ballistic;
+
+impl Ballistic {
+	pub(crate) const GRAVITY: f32 = 9.81;
+}
+
+/// Launches a [`Ballistic`] projectile, so that it lands on the ground point targeted by its
+/// caster, flying `speed` horizontally and landing within `range`.
+#[derive(Component, Debug, PartialEq)]
+#[require(Transform)]
+pub(crate) struct LaunchBallistic {
+	pub(crate) caster: SkillCaster,
+	pub(crate) range: Units,
+	pub(crate) speed: UnitsPerSecond,
+}
@@ -0,0 +1,21 @@
+use bevy::{ecs::entity::EntityHashSet, prelude::*};
+use common::prelude::*;
+
+/// Redirects a chain skill from each hit target towards the closest target within `range`,
+/// until no jumps remain.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct ChainJumps {
+	pub(crate) range: Units,
+	pub(crate) remaining: u8,
+	pub(crate) hit: EntityHashSet,
+}
+
+impl From<&Chain> for ChainJumps {
+	fn from(chain: &Chain) -> Self {
+		Self {
+			range: chain.range,
+			remaining: chain.jumps,
+			hit: EntityHashSet::default(),
+		}
+	}
+}
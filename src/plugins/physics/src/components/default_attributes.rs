@@ -16,6 +16,18 @@ impl View<AttributeOnSpawn<Health>> for DefaultAttributes {
 	}
 }
 
+impl View<AttributeOnSpawn<Resistances>> for DefaultAttributes {
+	fn view(&self) -> Resistances {
+		self.0.resistances
+	}
+}
+
+impl View<AttributeOnSpawn<Energy>> for DefaultAttributes {
+	fn view(&self) -> Energy {
+		self.0.energy
+	}
+}
+
 impl View<AttributeOnSpawn<EffectTarget<Gravity>>> for DefaultAttributes {
 	fn view(&self) -> EffectTarget<Gravity> {
 		self.0.gravity_interaction
@@ -1,6 +1,10 @@
+pub(crate) mod absorb_shield;
+pub(crate) mod damage_over_time;
 pub(crate) mod force;
 pub(crate) mod gravity;
 pub(crate) mod health_damage;
+pub(crate) mod slow;
+pub(crate) mod stun;
 
 use bevy::prelude::*;
 use common::prelude::*;
@@ -0,0 +1,55 @@
+use crate::{
+	components::affected::life::Life,
+	traits::{act_on::ActOn, update_blockers::UpdateBlockers},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "absorb shield effect")]
+pub struct AbsorbShieldEffect(pub(crate) AbsorbShield);
+
+impl UpdateBlockers for AbsorbShieldEffect {}
+
+impl ActOn<Life> for AbsorbShieldEffect {
+	fn on_begin_interaction(&mut self, _: PersistentEntity, life: &mut Life) {
+		let Self(AbsorbShield {
+			amount,
+			duration,
+			stacking,
+		}) = *self;
+
+		life.add_shield(amount, Duration::from(duration), stacking);
+	}
+
+	fn on_repeated_interaction(&mut self, _: PersistentEntity, _: &mut Life, _: Duration) {}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn add_shield() {
+		let mut effect = AbsorbShieldEffect(AbsorbShield {
+			amount: 20.,
+			duration: DurationInSeconds::from(Duration::from_secs(2)),
+			stacking: StatusStacking::Refresh,
+		});
+		let mut life = Life::from(Health::new(100.));
+
+		effect.on_begin_interaction(PersistentEntity::default(), &mut life);
+		effect.on_repeated_interaction(
+			PersistentEntity::default(),
+			&mut life,
+			Duration::from_secs(1),
+		);
+
+		let mut expected = Life::from(Health::new(100.));
+		expected.add_shield(20., Duration::from_secs(2), StatusStacking::Refresh);
+		assert_eq!(expected, life);
+	}
+}
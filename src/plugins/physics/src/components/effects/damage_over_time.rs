@@ -0,0 +1,75 @@
+use crate::{
+	components::affected::status_effects::{DamagePerSecond, StatusEffects},
+	traits::{act_on::ActOn, update_blockers::UpdateBlockers},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "damage over time effect")]
+pub struct DamageOverTimeEffect(pub(crate) DamageOverTime);
+
+impl UpdateBlockers for DamageOverTimeEffect {}
+
+impl ActOn<StatusEffects> for DamageOverTimeEffect {
+	fn on_begin_interaction(&mut self, _: PersistentEntity, target: &mut StatusEffects) {
+		let Self(DamageOverTime {
+			damage_per_second,
+			damage_type,
+			duration,
+			stacking,
+		}) = *self;
+		let damage = DamagePerSecond {
+			amount: damage_per_second,
+			damage_type,
+		};
+
+		target
+			.damage_over_time
+			.apply(damage, Duration::from(duration), stacking);
+	}
+
+	fn on_repeated_interaction(&mut self, _: PersistentEntity, _: &mut StatusEffects, _: Duration) {
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::timed_statuses::{TimedStatus, TimedStatuses};
+
+	#[test]
+	fn apply_damage_over_time() {
+		let mut effect = DamageOverTimeEffect(DamageOverTime {
+			damage_per_second: 5.,
+			damage_type: DamageType::Plasma,
+			duration: DurationInSeconds::from(Duration::from_secs(3)),
+			stacking: StatusStacking::Refresh,
+		});
+		let mut effects = StatusEffects::default();
+
+		effect.on_begin_interaction(PersistentEntity::default(), &mut effects);
+		effect.on_repeated_interaction(
+			PersistentEntity::default(),
+			&mut effects,
+			Duration::from_secs(1),
+		);
+
+		assert_eq!(
+			StatusEffects {
+				damage_over_time: TimedStatuses::from([TimedStatus {
+					value: DamagePerSecond {
+						amount: 5.,
+						damage_type: DamageType::Plasma,
+					},
+					remaining: Duration::from_secs(3),
+				}]),
+				..default()
+			},
+			effects
+		);
+	}
+}
@@ -1,7 +1,10 @@
 use crate::{
 	ActOn,
 	PhysicsPlugin,
-	components::{affected::force_affected::ForceAffected, blocker_types::BlockerTypes},
+	components::{
+		affected::force_affected::{ForceAffected, ForcePush},
+		blocker_types::BlockerTypes,
+	},
 	traits::update_blockers::UpdateBlockers,
 };
 use bevy::prelude::*;
@@ -32,7 +35,53 @@ impl UpdateBlockers for ForceEffect {
 impl ActOn<ForceAffected> for ForceEffect {
 	fn on_begin_interaction(&mut self, _: PersistentEntity, _: &mut ForceAffected) {}
 
-	fn on_repeated_interaction(&mut self, _: PersistentEntity, _: &mut ForceAffected, _: Duration) {
-		// FIXME: Target should be moved outside the force effect collider
+	fn on_repeated_interaction(
+		&mut self,
+		self_entity: PersistentEntity,
+		target: &mut ForceAffected,
+		_: Duration,
+	) {
+		let Self(Force { strength }) = *self;
+
+		target.push(ForcePush {
+			strength,
+			from: self_entity,
+		});
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn add_force_push() {
+		let mut force = ForceEffect(Force {
+			strength: UnitsPerSecond::from(42.),
+		});
+		let mut force_pushes = ForceAffected::affected([]);
+		let from = PersistentEntity::default();
+
+		force.on_repeated_interaction(from, &mut force_pushes, Duration::ZERO);
+
+		assert_eq!(
+			ForceAffected::affected([ForcePush {
+				strength: UnitsPerSecond::from(42.),
+				from,
+			}]),
+			force_pushes
+		);
+	}
+
+	#[test]
+	fn no_force_push_on_begin() {
+		let mut force = ForceEffect(Force {
+			strength: UnitsPerSecond::from(42.),
+		});
+		let mut force_pushes = ForceAffected::affected([]);
+
+		force.on_begin_interaction(PersistentEntity::default(), &mut force_pushes);
+
+		assert_eq!(ForceAffected::affected([]), force_pushes);
 	}
 }
@@ -1,6 +1,9 @@
 use crate::{
 	PhysicsPlugin,
-	components::affected::life::Life,
+	components::affected::{
+		incoming_damage::{Damage, IncomingDamage},
+		life::Life,
+	},
 	traits::{act_on::ActOn, update_blockers::UpdateBlockers},
 };
 use bevy::prelude::*;
@@ -24,21 +27,34 @@ impl<TSaveGame> HandlesPhysicalEffect<HealthDamage> for PhysicsPlugin<TSaveGame>
 
 impl UpdateBlockers for HealthDamageEffect {}
 
-impl ActOn<Life> for HealthDamageEffect {
-	fn on_begin_interaction(&mut self, _: PersistentEntity, life: &mut Life) {
-		let Self(HealthDamage(damage, EffectApplies::Once)) = *self else {
+impl ActOn<IncomingDamage> for HealthDamageEffect {
+	fn on_begin_interaction(&mut self, self_entity: PersistentEntity, target: &mut IncomingDamage) {
+		let Self(HealthDamage(amount, EffectApplies::Once, damage_type)) = *self else {
 			return;
 		};
 
-		life.change_by(-damage);
+		target.push(Damage {
+			amount,
+			damage_type,
+			source: Some(self_entity),
+		});
 	}
 
-	fn on_repeated_interaction(&mut self, _: PersistentEntity, life: &mut Life, delta: Duration) {
-		let Self(HealthDamage(damage, EffectApplies::OncePerSecond)) = *self else {
+	fn on_repeated_interaction(
+		&mut self,
+		self_entity: PersistentEntity,
+		target: &mut IncomingDamage,
+		delta: Duration,
+	) {
+		let Self(HealthDamage(amount, EffectApplies::OncePerSecond, damage_type)) = *self else {
 			return;
 		};
 
-		life.change_by(-damage * delta.as_secs_f32());
+		target.push(Damage {
+			amount: amount * delta.as_secs_f32(),
+			damage_type,
+			source: Some(self_entity),
+		});
 	}
 }
 
@@ -48,35 +64,40 @@ mod tests {
 
 	#[test]
 	fn deal_damage_once() {
-		let mut damage = HealthDamageEffect(HealthDamage::once(42.));
-		let mut life = Life(Health::new(100.));
+		let mut damage = HealthDamageEffect(HealthDamage::once(42.).with_type(DamageType::Void));
+		let mut incoming = IncomingDamage::default();
+		let source = PersistentEntity::default();
 
-		damage.on_begin_interaction(PersistentEntity::default(), &mut life);
-		damage.on_repeated_interaction(
-			PersistentEntity::default(),
-			&mut life,
-			Duration::from_secs(1),
-		);
+		damage.on_begin_interaction(source, &mut incoming);
+		damage.on_repeated_interaction(source, &mut incoming, Duration::from_secs(1));
 
-		let mut expected = Life(Health::new(100.));
-		expected.change_by(-42.);
-		assert_eq!(expected, life);
+		assert_eq!(
+			IncomingDamage(vec![Damage {
+				amount: 42.,
+				damage_type: DamageType::Void,
+				source: Some(source),
+			}]),
+			incoming
+		);
 	}
 
 	#[test]
 	fn deal_damage_over_time_scaled_by_delta() {
-		let mut damage = HealthDamageEffect(HealthDamage::per_second(42.));
-		let mut life = Life(Health::new(100.));
+		let mut damage =
+			HealthDamageEffect(HealthDamage::per_second(42.).with_type(DamageType::Plasma));
+		let mut incoming = IncomingDamage::default();
+		let source = PersistentEntity::default();
 
-		damage.on_begin_interaction(PersistentEntity::default(), &mut life);
-		damage.on_repeated_interaction(
-			PersistentEntity::default(),
-			&mut life,
-			Duration::from_millis(100),
-		);
+		damage.on_begin_interaction(source, &mut incoming);
+		damage.on_repeated_interaction(source, &mut incoming, Duration::from_millis(100));
 
-		let mut expected = Life(Health::new(100.));
-		expected.change_by(-42. * 0.1);
-		assert_eq!(expected, life);
+		assert_eq!(
+			IncomingDamage(vec![Damage {
+				amount: 42. * 0.1,
+				damage_type: DamageType::Plasma,
+				source: Some(source),
+			}]),
+			incoming
+		);
 	}
 }
@@ -0,0 +1,66 @@
+use crate::{
+	components::affected::status_effects::StatusEffects,
+	traits::{act_on::ActOn, update_blockers::UpdateBlockers},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "slow effect")]
+pub struct SlowEffect(pub(crate) Slow);
+
+impl UpdateBlockers for SlowEffect {}
+
+impl ActOn<StatusEffects> for SlowEffect {
+	fn on_begin_interaction(&mut self, _: PersistentEntity, target: &mut StatusEffects) {
+		let Self(Slow {
+			speed_reduction,
+			duration,
+			stacking,
+		}) = *self;
+
+		target
+			.slows
+			.apply(speed_reduction, Duration::from(duration), stacking);
+	}
+
+	fn on_repeated_interaction(&mut self, _: PersistentEntity, _: &mut StatusEffects, _: Duration) {
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::timed_statuses::{TimedStatus, TimedStatuses};
+
+	#[test]
+	fn apply_slow() {
+		let mut effect = SlowEffect(Slow {
+			speed_reduction: 0.3,
+			duration: DurationInSeconds::from(Duration::from_secs(2)),
+			stacking: StatusStacking::Refresh,
+		});
+		let mut effects = StatusEffects::default();
+
+		effect.on_begin_interaction(PersistentEntity::default(), &mut effects);
+		effect.on_repeated_interaction(
+			PersistentEntity::default(),
+			&mut effects,
+			Duration::from_secs(1),
+		);
+
+		assert_eq!(
+			StatusEffects {
+				slows: TimedStatuses::from([TimedStatus {
+					value: 0.3,
+					remaining: Duration::from_secs(2),
+				}]),
+				..default()
+			},
+			effects
+		);
+	}
+}
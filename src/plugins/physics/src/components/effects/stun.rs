@@ -0,0 +1,59 @@
+use crate::{
+	components::affected::status_effects::StatusEffects,
+	traits::{act_on::ActOn, update_blockers::UpdateBlockers},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "stun effect")]
+pub struct StunEffect(pub(crate) Stun);
+
+impl UpdateBlockers for StunEffect {}
+
+impl ActOn<StatusEffects> for StunEffect {
+	fn on_begin_interaction(&mut self, _: PersistentEntity, target: &mut StatusEffects) {
+		let Self(Stun { duration, stacking }) = *self;
+
+		target.stuns.apply((), Duration::from(duration), stacking);
+	}
+
+	fn on_repeated_interaction(&mut self, _: PersistentEntity, _: &mut StatusEffects, _: Duration) {
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::timed_statuses::{TimedStatus, TimedStatuses};
+
+	#[test]
+	fn apply_stun() {
+		let mut effect = StunEffect(Stun {
+			duration: DurationInSeconds::from(Duration::from_secs(2)),
+			stacking: StatusStacking::Refresh,
+		});
+		let mut effects = StatusEffects::default();
+
+		effect.on_begin_interaction(PersistentEntity::default(), &mut effects);
+		effect.on_repeated_interaction(
+			PersistentEntity::default(),
+			&mut effects,
+			Duration::from_secs(1),
+		);
+
+		assert_eq!(
+			StatusEffects {
+				stuns: TimedStatuses::from([TimedStatus {
+					value: (),
+					remaining: Duration::from_secs(2),
+				}]),
+				..default()
+			},
+			effects
+		);
+	}
+}
@@ -0,0 +1,14 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Stacked stats of the items equipped by an agent.
+///
+/// Derived from the loadout, so it is not saved.
+#[derive(Component, Debug, PartialEq, Default, Clone, Copy)]
+pub struct EquipmentAttributes(pub(crate) EquipmentStats);
+
+impl From<EquipmentStats> for EquipmentAttributes {
+	fn from(stats: EquipmentStats) -> Self {
+		Self(stats)
+	}
+}
@@ -0,0 +1,316 @@
+use crate::components::skill::{CreatedFrom, Skill};
+use bevy::prelude::*;
+use common::prelude::*;
+use macros::SavableComponent;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Skills spawned by the skill holding this component, see [`FollowUpSkill`].
+#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[savable_component(id = "follow ups")]
+pub struct FollowUps {
+	pub(crate) skills: Vec<FollowUpSkill>,
+	/// Time since the parent was spawned, used for [`FollowUpTrigger::Interval`]
+	#[serde(default)]
+	pub(crate) elapsed: Duration,
+}
+
+impl FollowUps {
+	pub(crate) fn triggered_on(
+		&self,
+		trigger: FollowUpTrigger,
+	) -> impl Iterator<Item = &'_ FollowUpSkill> {
+		self.skills
+			.iter()
+			.filter(move |follow_up| follow_up.trigger == trigger)
+	}
+}
+
+impl<T> From<T> for FollowUps
+where
+	T: IntoIterator<Item = FollowUpSkill>,
+{
+	fn from(skills: T) -> Self {
+		Self {
+			skills: skills.into_iter().collect(),
+			elapsed: Duration::ZERO,
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) enum FollowUpPlacement {
+	/// Spawn as a child of the parent, so it moves and despawns with the parent.
+	///
+	/// Moving skills are not attached and spawn at the parent's current `transform` instead.
+	AttachedTo {
+		parent: PersistentEntity,
+		transform: Transform,
+	},
+	/// Spawn at the given transform, used when the parent is about to despawn.
+	At(Transform),
+}
+
+pub(crate) trait SpawnFollowUp {
+	fn spawn_follow_up(
+		&mut self,
+		caster: SkillCaster,
+		follow_up: &FollowUpSkill,
+		placement: FollowUpPlacement,
+	);
+}
+
+impl SpawnFollowUp for ZyheedaCommands<'_, '_> {
+	fn spawn_follow_up(
+		&mut self,
+		caster: SkillCaster,
+		follow_up: &FollowUpSkill,
+		placement: FollowUpPlacement,
+	) {
+		let mut entity = self.spawn(Skill {
+			created_from: CreatedFrom::Spawn,
+			shape: follow_up.shape.clone(),
+			contact_effects: follow_up.contact_effects.clone(),
+			projection_effects: follow_up.projection_effects.clone(),
+			caster,
+			mount: SkillMount::Parent,
+			impact_sound: follow_up.impact_sound.clone(),
+		});
+
+		match placement {
+			// Moving skills would fight their parent's transform, so they are never attached
+			FollowUpPlacement::AttachedTo { parent, .. } if !is_moving(&follow_up.shape) => {
+				entity.insert(ChildOfPersistent(parent));
+			}
+			FollowUpPlacement::AttachedTo { transform, .. } | FollowUpPlacement::At(transform) => {
+				entity.insert(transform);
+			}
+		}
+
+		if !follow_up.follow_ups.is_empty() {
+			entity.insert(FollowUps::from(follow_up.follow_ups.clone()));
+		}
+	}
+}
+
+fn is_moving(shape: &SkillShape) -> bool {
+	matches!(shape, SkillShape::Projectile(..) | SkillShape::Chain(..))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use test_case::test_case;
+	use testing::{SingleThreadedApp, assert_count};
+
+	fn follow_up(shape: SkillShape, follow_ups: Vec<FollowUpSkill>) -> FollowUpSkill {
+		FollowUpSkill {
+			trigger: FollowUpTrigger::OnContact,
+			shape,
+			contact_effects: vec![SkillEffect::Force(Force::default())],
+			projection_effects: vec![SkillEffect::Gravity(Gravity::default())],
+			impact_sound: Some(SoundCue::from("sounds/impact.ogg")),
+			follow_ups,
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+
+		app
+	}
+
+	#[test]
+	fn filter_by_trigger() {
+		let interval = FollowUpTrigger::Interval(Duration::from_secs(1));
+		let follow_ups = FollowUps::from([
+			FollowUpSkill {
+				trigger: FollowUpTrigger::OnContact,
+				..follow_up(SkillShape::Shield(Shield), vec![])
+			},
+			FollowUpSkill {
+				trigger: FollowUpTrigger::OnExpiry,
+				..follow_up(SkillShape::Shield(Shield), vec![])
+			},
+			FollowUpSkill {
+				trigger: interval,
+				..follow_up(SkillShape::Shield(Shield), vec![])
+			},
+		]);
+
+		assert_eq!(
+			vec![&follow_ups.skills[2]],
+			follow_ups.triggered_on(interval).collect::<Vec<_>>(),
+		);
+	}
+
+	#[test]
+	fn spawn_skill() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let caster = SkillCaster(PersistentEntity::default());
+
+		app.world_mut()
+			.run_system_once(move |mut commands: ZyheedaCommands| {
+				commands.spawn_follow_up(
+					caster,
+					&follow_up(SkillShape::Shield(Shield), vec![]),
+					FollowUpPlacement::At(Transform::default()),
+				);
+			})?;
+
+		let mut skills = app.world_mut().query::<&Skill>();
+		let [skill] = assert_count!(1, skills.iter(app.world()));
+		assert_eq!(
+			&Skill {
+				created_from: CreatedFrom::Spawn,
+				shape: SkillShape::Shield(Shield),
+				contact_effects: vec![SkillEffect::Force(Force::default())],
+				projection_effects: vec![SkillEffect::Gravity(Gravity::default())],
+				caster,
+				mount: SkillMount::Parent,
+				impact_sound: Some(SoundCue::from("sounds/impact.ogg")),
+			},
+			skill
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn spawn_at_transform() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut()
+			.run_system_once(|mut commands: ZyheedaCommands| {
+				commands.spawn_follow_up(
+					SkillCaster(PersistentEntity::default()),
+					&follow_up(SkillShape::Shield(Shield), vec![]),
+					FollowUpPlacement::At(Transform::from_xyz(1., 2., 3.)),
+				);
+			})?;
+
+		let mut skills = app
+			.world_mut()
+			.query_filtered::<(&Transform, Option<&ChildOfPersistent>), With<Skill>>();
+		let [skill] = assert_count!(1, skills.iter(app.world()));
+		assert_eq!((&Transform::from_xyz(1., 2., 3.), None), skill);
+		Ok(())
+	}
+
+	#[test_case(SkillShape::Shield(Shield), true; "shield")]
+	#[test_case(
+		SkillShape::Projectile(Projectile {
+			destroyed_by: Blockers::All,
+			motion: ProjectileMotion::Straight,
+			impact: ProjectileImpact::Destroy,
+		}),
+		false;
+		"projectile"
+	)]
+	fn attach_to_parent_unless_moving(
+		shape: SkillShape,
+		attached: bool,
+	) -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let parent = PersistentEntity::default();
+
+		app.world_mut()
+			.run_system_once(move |mut commands: ZyheedaCommands| {
+				commands.spawn_follow_up(
+					SkillCaster(PersistentEntity::default()),
+					&follow_up(shape.clone(), vec![]),
+					FollowUpPlacement::AttachedTo {
+						parent,
+						transform: Transform::from_xyz(1., 2., 3.),
+					},
+				);
+			})?;
+
+		let mut skills = app
+			.world_mut()
+			.query_filtered::<Option<&ChildOfPersistent>, With<Skill>>();
+		let [child_of] = assert_count!(1, skills.iter(app.world()));
+		assert_eq!(attached.then_some(&ChildOfPersistent(parent)), child_of);
+		Ok(())
+	}
+
+	#[test_case(SkillShape::Shield(Shield), Transform::default(); "shield")]
+	#[test_case(
+		SkillShape::Projectile(Projectile {
+			destroyed_by: Blockers::All,
+			motion: ProjectileMotion::Straight,
+			impact: ProjectileImpact::Destroy,
+		}),
+		Transform::from_xyz(1., 2., 3.);
+		"projectile"
+	)]
+	fn place_at_parent_transform_when_moving(
+		shape: SkillShape,
+		expected: Transform,
+	) -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut()
+			.run_system_once(move |mut commands: ZyheedaCommands| {
+				commands.spawn_follow_up(
+					SkillCaster(PersistentEntity::default()),
+					&follow_up(shape.clone(), vec![]),
+					FollowUpPlacement::AttachedTo {
+						parent: PersistentEntity::default(),
+						transform: Transform::from_xyz(1., 2., 3.),
+					},
+				);
+			})?;
+
+		let mut skills = app.world_mut().query_filtered::<&Transform, With<Skill>>();
+		let [transform] = assert_count!(1, skills.iter(app.world()));
+		assert_eq!(&expected, transform);
+		Ok(())
+	}
+
+	#[test]
+	fn insert_nested_follow_ups() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let nested = follow_up(SkillShape::Shield(Shield), vec![]);
+		let follow_up = follow_up(SkillShape::Shield(Shield), vec![nested.clone()]);
+
+		app.world_mut()
+			.run_system_once(move |mut commands: ZyheedaCommands| {
+				commands.spawn_follow_up(
+					SkillCaster(PersistentEntity::default()),
+					&follow_up,
+					FollowUpPlacement::At(Transform::default()),
+				);
+			})?;
+
+		let mut skills = app
+			.world_mut()
+			.query_filtered::<Option<&FollowUps>, With<Skill>>();
+		let [follow_ups] = assert_count!(1, skills.iter(app.world()));
+		assert_eq!(Some(&FollowUps::from([nested])), follow_ups);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_insert_empty_follow_ups() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut()
+			.run_system_once(|mut commands: ZyheedaCommands| {
+				commands.spawn_follow_up(
+					SkillCaster(PersistentEntity::default()),
+					&follow_up(SkillShape::Shield(Shield), vec![]),
+					FollowUpPlacement::At(Transform::default()),
+				);
+			})?;
+
+		let mut skills = app
+			.world_mut()
+			.query_filtered::<Option<&FollowUps>, With<Skill>>();
+		let [follow_ups] = assert_count!(1, skills.iter(app.world()));
+		assert_eq!(None, follow_ups);
+		Ok(())
+	}
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Turns the velocity of a projectile towards the entity targeted by its caster,
+/// by up to `turn_rate` radians per second.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct Homing {
+	pub(crate) caster: SkillCaster,
+	pub(crate) turn_rate: f32,
+}
+
+impl Homing {
+	pub(crate) fn new(caster: SkillCaster, turn_rate_degrees: f32) -> Self {
+		Self {
+			caster,
+			turn_rate: turn_rate_degrees.to_radians(),
+		}
+	}
+}
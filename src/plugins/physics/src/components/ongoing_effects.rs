@@ -1,6 +1,20 @@
 use crate::components::{
-	affected::{force_affected::ForceAffected, gravity_affected::GravityAffected, life::Life},
-	effects::{force::ForceEffect, gravity::GravityEffect, health_damage::HealthDamageEffect},
+	affected::{
+		force_affected::ForceAffected,
+		gravity_affected::GravityAffected,
+		incoming_damage::IncomingDamage,
+		life::Life,
+		status_effects::StatusEffects,
+	},
+	effects::{
+		absorb_shield::AbsorbShieldEffect,
+		damage_over_time::DamageOverTimeEffect,
+		force::ForceEffect,
+		gravity::GravityEffect,
+		health_damage::HealthDamageEffect,
+		slow::SlowEffect,
+		stun::StunEffect,
+	},
 };
 use bevy::prelude::{Component, default};
 use common::prelude::*;
@@ -18,7 +32,7 @@ where
 	_p: PhantomData<(TActor, TTarget)>,
 }
 
-impl SavableComponent for OngoingEffects<HealthDamageEffect, Life> {
+impl SavableComponent for OngoingEffects<HealthDamageEffect, IncomingDamage> {
 	type TDto = Self;
 
 	const ID: UniqueComponentId = UniqueComponentId::from_str("ongoing health damage effects");
@@ -36,6 +50,30 @@ impl SavableComponent for OngoingEffects<ForceEffect, ForceAffected> {
 	const ID: UniqueComponentId = UniqueComponentId::from_str("ongoing force effects");
 }
 
+impl SavableComponent for OngoingEffects<DamageOverTimeEffect, StatusEffects> {
+	type TDto = Self;
+
+	const ID: UniqueComponentId = UniqueComponentId::from_str("ongoing damage over time effects");
+}
+
+impl SavableComponent for OngoingEffects<SlowEffect, StatusEffects> {
+	type TDto = Self;
+
+	const ID: UniqueComponentId = UniqueComponentId::from_str("ongoing slow effects");
+}
+
+impl SavableComponent for OngoingEffects<StunEffect, StatusEffects> {
+	type TDto = Self;
+
+	const ID: UniqueComponentId = UniqueComponentId::from_str("ongoing stun effects");
+}
+
+impl SavableComponent for OngoingEffects<AbsorbShieldEffect, Life> {
+	type TDto = Self;
+
+	const ID: UniqueComponentId = UniqueComponentId::from_str("ongoing absorb shield effects");
+}
+
 impl<TActor, TTarget> Clone for OngoingEffects<TActor, TTarget>
 where
 	TActor: Component,
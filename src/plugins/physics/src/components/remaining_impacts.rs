@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Hits with its blockers a fragile projectile survives, before being destroyed.
+#[derive(Component, Debug, PartialEq, Clone, Copy)]
+pub(crate) enum RemainingImpacts {
+	Pierce(u8),
+	Ricochet(u8),
+}
+
+impl RemainingImpacts {
+	pub(crate) fn from_impact(impact: ProjectileImpact) -> Option<Self> {
+		match impact {
+			ProjectileImpact::Destroy => None,
+			ProjectileImpact::Pierce { count } => Some(Self::Pierce(count)),
+			ProjectileImpact::Ricochet { count } => Some(Self::Ricochet(count)),
+		}
+	}
+}
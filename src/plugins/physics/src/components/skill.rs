@@ -20,6 +20,7 @@ pub struct Skill {
 	pub(crate) projection_effects: Vec<SkillEffect>,
 	pub(crate) caster: SkillCaster,
 	pub(crate) mount: SkillMount,
+	pub(crate) impact_sound: Option<SoundCue>,
 }
 
 #[derive(Component, Debug, PartialEq)]
@@ -47,6 +48,14 @@ const BEAM_MODEL: fn() -> Mesh = || {
 const BEAM_CONTACT_RADIUS: f32 = 0.003;
 const BEAM_PROJECTION_RADIUS: f32 = 0.2;
 
+const ARC_MODEL: fn() -> Mesh = || Mesh::from(Cuboid::from_length(1.));
+const ARC_CONTACT_HALF_WIDTH: f32 = 0.05;
+const ARC_PROJECTION_HALF_WIDTH: f32 = 0.3;
+const ARC_HALF_HEIGHT: f32 = 0.1;
+
+const DASH_CONTACT_RADIUS: f32 = 0.6;
+const DASH_PROJECTION_RADIUS: f32 = 1.;
+
 const PROJECTILE_CONTACT_RADIUS: f32 = 0.05;
 const PROJECTILE_PROJECTION_RADIUS: f32 = 0.5;
 const PROJECTILE_RANGE: Units = Units::from_u8(20);
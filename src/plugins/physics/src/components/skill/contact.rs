@@ -4,8 +4,12 @@ use crate::{
 		collider::ColliderShape,
 		effects::Effects,
 		skill::{
+			ARC_CONTACT_HALF_WIDTH,
+			ARC_HALF_HEIGHT,
+			ARC_MODEL,
 			BEAM_CONTACT_RADIUS,
 			BEAM_MODEL,
+			DASH_CONTACT_RADIUS,
 			HALF_FORWARD,
 			PROJECTILE_CONTACT_RADIUS,
 			SHIELD_CONTACT_COLLIDER,
@@ -20,7 +24,7 @@ use crate::{
 use bevy::prelude::*;
 use bevy_rapier3d::prelude::ColliderScale;
 use common::prelude::*;
-use std::f32::consts::PI;
+use std::{collections::HashSet, f32::consts::PI};
 
 impl GetContactPrefab for Skill {
 	fn get_contact_prefab(&self) -> (PhysicalObject, SubModel, ContactCollider, Effects) {
@@ -41,7 +45,7 @@ impl GetContactPrefab for Skill {
 					transform: Transform::default(),
 				},
 			),
-			SkillShape::Projectile(Projectile { destroyed_by }) => (
+			SkillShape::Projectile(Projectile { destroyed_by, .. }) => (
 				PhysicalObject::Fragile {
 					destroyed_by: destroyed_by.clone().into(),
 				},
@@ -97,6 +101,69 @@ impl GetContactPrefab for Skill {
 					transform: Transform::default(),
 				},
 			),
+			SkillShape::MeleeArc(MeleeArc {
+				range,
+				destroyed_by,
+				..
+			}) => (
+				PhysicalObject::Fragile {
+					destroyed_by: destroyed_by.clone().into(),
+				},
+				SubModel {
+					model: Model::Mesh(InsertAsset::shared::<MeleeArc>(ARC_MODEL)),
+					transform: Transform::from_xyz(0., 0., -**range / 2.).with_scale(Vec3 {
+						x: ARC_CONTACT_HALF_WIDTH * 2.,
+						y: ARC_HALF_HEIGHT * 2.,
+						z: **range,
+					}),
+				},
+				ContactCollider {
+					shape: ColliderShape::Cuboid {
+						half_x: Units::from(ARC_CONTACT_HALF_WIDTH),
+						half_y: Units::from(ARC_HALF_HEIGHT),
+						half_z: Units::from(**range / 2.),
+					},
+					transform: Transform::from_xyz(0., 0., -**range / 2.),
+				},
+			),
+			SkillShape::Chain(Chain { destroyed_by, .. }) => {
+				let mut destroyed_by: HashSet<Blocker> = destroyed_by.clone().into();
+				// Characters end a chain through its jumps instead
+				destroyed_by.remove(&Blocker::Character);
+
+				(
+					PhysicalObject::Fragile { destroyed_by },
+					SubModel {
+						model: Model::scene(SPHERE_MODEL),
+						transform: Transform::from_scale(Vec3::splat(
+							PROJECTILE_CONTACT_RADIUS * 2.,
+						)),
+					},
+					ContactCollider {
+						shape: ColliderShape::Sphere {
+							radius: Units::from(PROJECTILE_CONTACT_RADIUS),
+							hollow: false,
+						},
+						transform: Transform::default(),
+					},
+				)
+			}
+			SkillShape::Dash(Dash { .. }) => (
+				PhysicalObject::Fragile {
+					destroyed_by: Blocker::none(),
+				},
+				SubModel {
+					model: Model::scene(SPHERE_MODEL),
+					transform: Transform::from_scale(Vec3::splat(DASH_CONTACT_RADIUS * 2.)),
+				},
+				ContactCollider {
+					shape: ColliderShape::Sphere {
+						radius: Units::from(DASH_CONTACT_RADIUS),
+						hollow: false,
+					},
+					transform: Transform::default(),
+				},
+			),
 		};
 
 		(obj, model, collider, Effects(self.contact_effects.clone()))
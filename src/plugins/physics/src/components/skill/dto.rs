@@ -9,6 +9,8 @@ pub struct SkillDto {
 	pub(crate) projection_effects: Vec<SkillEffect>,
 	pub(crate) caster: SkillCaster,
 	pub(crate) mount: SkillMount,
+	#[serde(default)]
+	pub(crate) impact_sound: Option<SoundCue>,
 }
 
 impl From<Skill> for SkillDto {
@@ -19,6 +21,7 @@ impl From<Skill> for SkillDto {
 			projection_effects: skill.projection_effects,
 			caster: skill.caster,
 			mount: skill.mount,
+			impact_sound: skill.impact_sound,
 		}
 	}
 }
@@ -37,6 +40,7 @@ impl TryLoadFrom<SkillDto> for Skill {
 			projection_effects: dto.projection_effects,
 			caster: dto.caster,
 			mount: dto.mount,
+			impact_sound: dto.impact_sound,
 		})
 	}
 }
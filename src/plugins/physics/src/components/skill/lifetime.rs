@@ -6,6 +6,8 @@ impl GetLifetime for Skill {
 	fn get_lifetime(&self) -> Option<Duration> {
 		match self.shape {
 			SkillShape::SphereAoE(SphereAoE { lifetime, .. }) => lifetime,
+			SkillShape::MeleeArc(MeleeArc { duration, .. }) => Some(duration),
+			SkillShape::Dash(Dash { duration, .. }) => Some(duration),
 			_ => None,
 		}
 	}
@@ -1,8 +1,12 @@
 use crate::{
 	components::{
 		anchor::Anchor,
+		ballistic::{Ballistic, LaunchBallistic},
+		chain_jumps::ChainJumps,
 		ground_target::GroundTarget,
+		homing::Homing,
 		prevent_tunneling::PreventTunneling,
+		remaining_impacts::RemainingImpacts,
 		set_velocity_forward::SetVelocityForward,
 		skill::{
 			CreatedFrom,
@@ -11,6 +15,7 @@ use crate::{
 			PROJECTILE_SPEED,
 			Skill,
 		},
+		sweep::Sweep,
 		when_traveled::WhenTraveled,
 	},
 	observers::skill_prefab::ApplyMotionPrefab,
@@ -22,14 +27,37 @@ impl ApplyMotionPrefab for Skill {
 	fn apply_motion_prefab(&self, entity: &mut ZyheedaEntityCommands) -> RigidBody {
 		match &self.shape {
 			SkillShape::SphereAoE(SphereAoE { max_range, .. }) => {
-				entity.try_insert(GroundTarget {
-					caster: self.caster,
-					max_cast_range: *max_range,
-				});
+				if self.mount != SkillMount::Parent {
+					entity.try_insert(GroundTarget {
+						caster: self.caster,
+						max_cast_range: *max_range,
+					});
+				}
 
 				RigidBody::Fixed
 			}
-			SkillShape::Projectile(..) => {
+			SkillShape::Projectile(..) | SkillShape::Chain(..) => {
+				let (motion, impact) = match &self.shape {
+					SkillShape::Projectile(projectile) => (projectile.motion, projectile.impact),
+					_ => (ProjectileMotion::Straight, ProjectileImpact::Destroy),
+				};
+
+				if let SkillShape::Chain(chain) = &self.shape {
+					entity.try_insert(ChainJumps::from(chain));
+				}
+				if let Some(impacts) = RemainingImpacts::from_impact(impact) {
+					entity.try_insert(impacts);
+				}
+				match motion {
+					ProjectileMotion::Straight => {}
+					ProjectileMotion::Homing { turn_rate } => {
+						entity.try_insert(Homing::new(self.caster, turn_rate));
+					}
+					ProjectileMotion::Ballistic => {
+						entity.try_insert(Ballistic);
+					}
+				}
+
 				entity.try_insert((
 					GravityScale(0.),
 					Ccd::enabled(),
@@ -40,37 +68,64 @@ impl ApplyMotionPrefab for Skill {
 				));
 
 				if self.created_from == CreatedFrom::Spawn {
-					entity.try_insert((
-						Anchor::attach_to(self.caster.0)
-							.on(self.mount)
-							.looking_at_skill_target()
-							.once(),
-						SetVelocityForward(PROJECTILE_SPEED),
-					));
+					if let Some(anchor) = self.anchor() {
+						entity.try_insert(anchor.looking_at_skill_target().once());
+					}
+					match motion {
+						ProjectileMotion::Ballistic => {
+							entity.try_insert(LaunchBallistic {
+								caster: self.caster,
+								range: PROJECTILE_RANGE,
+								speed: PROJECTILE_SPEED,
+							});
+						}
+						_ => {
+							entity.try_insert(SetVelocityForward(PROJECTILE_SPEED));
+						}
+					}
 				}
 
 				RigidBody::Dynamic
 			}
 			SkillShape::Beam(..) => {
-				entity.try_insert(
-					Anchor::attach_to(self.caster.0)
-						.on(self.mount)
-						.looking_at_skill_target()
-						.always(),
-				);
+				if let Some(anchor) = self.anchor() {
+					entity.try_insert(anchor.looking_at_skill_target().always());
+				}
 
 				RigidBody::Fixed
 			}
 			SkillShape::Shield(..) => {
-				entity.try_insert(
-					Anchor::attach_to(self.caster.0)
-						.on(self.mount)
-						.with_attached_rotation()
-						.always(),
-				);
+				if let Some(anchor) = self.anchor() {
+					entity.try_insert(anchor.with_attached_rotation().always());
+				}
+
+				RigidBody::Fixed
+			}
+			SkillShape::MeleeArc(arc) => {
+				if let Some(anchor) = self.anchor() {
+					entity.try_insert(anchor.with_attached_rotation().always());
+				}
+				entity.try_insert(Sweep::from(arc));
+
+				RigidBody::Fixed
+			}
+			SkillShape::Dash(..) => {
+				if let Some(anchor) = self.anchor() {
+					entity.try_insert(anchor.with_attached_rotation().always());
+				}
 
 				RigidBody::Fixed
 			}
 		}
 	}
 }
+
+impl Skill {
+	/// Skills mounted on their parent are placed by the parent, so they get no [`Anchor`].
+	fn anchor(&self) -> Option<Anchor> {
+		match self.mount {
+			SkillMount::Parent => None,
+			mount => Some(Anchor::attach_to(self.caster.0).on(mount)),
+		}
+	}
+}
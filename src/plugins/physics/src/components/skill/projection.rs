@@ -4,8 +4,12 @@ use crate::{
 		collider::ColliderShape,
 		effects::Effects,
 		skill::{
+			ARC_HALF_HEIGHT,
+			ARC_MODEL,
+			ARC_PROJECTION_HALF_WIDTH,
 			BEAM_MODEL,
 			BEAM_PROJECTION_RADIUS,
+			DASH_PROJECTION_RADIUS,
 			HALF_FORWARD,
 			PROJECTILE_PROJECTION_RADIUS,
 			SHIELD_PROJECTION_COLLIDER,
@@ -38,7 +42,7 @@ impl GetProjectionPrefab for Skill {
 					transform: Transform::default(),
 				},
 			),
-			SkillShape::Projectile(..) => (
+			SkillShape::Projectile(..) | SkillShape::Chain(..) => (
 				SubModel {
 					model: Model::scene(SPHERE_MODEL),
 					transform: Transform::from_scale(Vec3::splat(
@@ -86,6 +90,37 @@ impl GetProjectionPrefab for Skill {
 					transform: Transform::default(),
 				},
 			),
+			SkillShape::MeleeArc(MeleeArc { range, .. }) => (
+				SubModel {
+					model: Model::Mesh(InsertAsset::shared::<MeleeArc>(ARC_MODEL)),
+					transform: Transform::from_xyz(0., 0., -**range / 2.).with_scale(Vec3 {
+						x: ARC_PROJECTION_HALF_WIDTH * 2.,
+						y: ARC_HALF_HEIGHT * 2.,
+						z: **range,
+					}),
+				},
+				ProjectionCollider {
+					shape: ColliderShape::Cuboid {
+						half_x: Units::from(ARC_PROJECTION_HALF_WIDTH),
+						half_y: Units::from(ARC_HALF_HEIGHT),
+						half_z: Units::from(**range / 2.),
+					},
+					transform: Transform::from_xyz(0., 0., -**range / 2.),
+				},
+			),
+			SkillShape::Dash(Dash { .. }) => (
+				SubModel {
+					model: Model::scene(SPHERE_MODEL),
+					transform: Transform::from_scale(Vec3::splat(DASH_PROJECTION_RADIUS * 2.)),
+				},
+				ProjectionCollider {
+					shape: ColliderShape::Sphere {
+						radius: Units::from(DASH_PROJECTION_RADIUS),
+						hollow: false,
+					},
+					transform: Transform::default(),
+				},
+			),
 		};
 
 		(model, collider, Effects(self.projection_effects.clone()))
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+/// Rotates the [`SkillTransforms`](crate::components::skill_transform::SkillTransforms) of a skill
+/// around its origin, from `angle / 2` to `-angle / 2` over `duration`.
+#[derive(Component, Debug, PartialEq)]
+pub(crate) struct Sweep {
+	pub(crate) range: Units,
+	pub(crate) angle: f32,
+	pub(crate) duration: Duration,
+	pub(crate) elapsed: Duration,
+}
+
+impl Sweep {
+	pub(crate) fn transform(&self) -> Transform {
+		let progress = match self.duration.is_zero() {
+			true => 1.,
+			false => (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.),
+		};
+		let rotation = Quat::from_rotation_y(self.angle / 2. - self.angle * progress);
+
+		Transform::from_translation(rotation * Vec3::new(0., 0., -*self.range / 2.))
+			.with_rotation(rotation)
+	}
+}
+
+impl From<&MeleeArc> for Sweep {
+	fn from(arc: &MeleeArc) -> Self {
+		Self {
+			range: arc.range,
+			angle: arc.angle.to_radians(),
+			duration: arc.duration,
+			elapsed: Duration::ZERO,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::f32::consts::PI;
+	use test_case::test_case;
+	use testing::assert_eq_approx;
+
+	#[test_case(Duration::ZERO, Quat::from_rotation_y(PI / 4.); "start")]
+	#[test_case(Duration::from_millis(500), Quat::IDENTITY; "half")]
+	#[test_case(Duration::from_secs(1), Quat::from_rotation_y(-PI / 4.); "end")]
+	#[test_case(Duration::from_secs(2), Quat::from_rotation_y(-PI / 4.); "past end")]
+	fn transform(elapsed: Duration, rotation: Quat) {
+		let sweep = Sweep {
+			range: Units::from(2.),
+			angle: PI / 2.,
+			duration: Duration::from_secs(1),
+			elapsed,
+		};
+
+		assert_eq_approx!(
+			Transform::from_translation(rotation * Vec3::new(0., 0., -1.)).with_rotation(rotation),
+			sweep.transform(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn from_arc() {
+		let arc = MeleeArc {
+			range: Units::from(3.),
+			angle: 90.,
+			duration: Duration::from_secs(2),
+			destroyed_by: Blockers::All,
+		};
+
+		assert_eq!(
+			Sweep {
+				range: Units::from(3.),
+				angle: PI / 2.,
+				duration: Duration::from_secs(2),
+				elapsed: Duration::ZERO,
+			},
+			Sweep::from(&arc)
+		);
+	}
+}
@@ -0,0 +1,123 @@
+use common::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Active instances of one status kind, each expiring on its own.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub(crate) struct TimedStatuses<T>(Vec<TimedStatus<T>>);
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct TimedStatus<T> {
+	pub(crate) value: T,
+	pub(crate) remaining: Duration,
+}
+
+impl<T> TimedStatuses<T> {
+	pub(crate) fn is_empty(&self) -> bool {
+		self.0.is_empty()
+	}
+
+	pub(crate) fn iter(&self) -> impl Iterator<Item = &TimedStatus<T>> {
+		self.0.iter()
+	}
+
+	pub(crate) fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+		self.0.iter_mut().map(|status| &mut status.value)
+	}
+
+	pub(crate) fn retain(&mut self, predicate: impl Fn(&T) -> bool) {
+		self.0.retain(|status| predicate(&status.value));
+	}
+
+	pub(crate) fn apply(&mut self, value: T, duration: Duration, stacking: StatusStacking) {
+		let status = TimedStatus {
+			value,
+			remaining: duration,
+		};
+
+		match stacking {
+			StatusStacking::Refresh => {
+				self.0.clear();
+				self.0.push(status);
+			}
+			StatusStacking::Stack { max } if self.0.len() < usize::from(max.max(1)) => {
+				self.0.push(status);
+			}
+			StatusStacking::Stack { .. } => {
+				let closest_to_expiring = self.0.iter_mut().min_by_key(|status| status.remaining);
+				if let Some(closest_to_expiring) = closest_to_expiring {
+					*closest_to_expiring = status;
+				}
+			}
+			StatusStacking::Ignore if self.0.is_empty() => {
+				self.0.push(status);
+			}
+			StatusStacking::Ignore => {}
+		}
+	}
+
+	/// Reduces the remaining time of all instances by `delta` and drops expired ones.
+	pub(crate) fn advance(&mut self, delta: Duration) {
+		for status in &mut self.0 {
+			status.remaining = status.remaining.saturating_sub(delta);
+		}
+
+		self.0.retain(|status| status.remaining > Duration::ZERO);
+	}
+}
+
+impl<T> Default for TimedStatuses<T> {
+	fn default() -> Self {
+		Self(vec![])
+	}
+}
+
+#[cfg(test)]
+impl<T, const N: usize> From<[TimedStatus<T>; N]> for TimedStatuses<T> {
+	fn from(statuses: [TimedStatus<T>; N]) -> Self {
+		Self(Vec::from(statuses))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use test_case::test_case;
+
+	fn status(value: u8, seconds: u64) -> TimedStatus<u8> {
+		TimedStatus {
+			value,
+			remaining: Duration::from_secs(seconds),
+		}
+	}
+
+	#[test_case(StatusStacking::Refresh, [status(3, 10)]; "refresh")]
+	#[test_case(StatusStacking::Stack { max: 3 }, [status(1, 2), status(2, 5), status(3, 10)]; "stack")]
+	#[test_case(StatusStacking::Stack { max: 2 }, [status(3, 10), status(2, 5)]; "stack full")]
+	#[test_case(StatusStacking::Ignore, [status(1, 2), status(2, 5)]; "ignore")]
+	fn apply<const N: usize>(stacking: StatusStacking, expected: [TimedStatus<u8>; N]) {
+		let mut statuses = TimedStatuses::from([status(1, 2), status(2, 5)]);
+
+		statuses.apply(3, Duration::from_secs(10), stacking);
+
+		assert_eq!(TimedStatuses::from(expected), statuses);
+	}
+
+	#[test]
+	fn apply_ignored_when_empty() {
+		let mut statuses = TimedStatuses::default();
+
+		statuses.apply(3, Duration::from_secs(10), StatusStacking::Ignore);
+
+		assert_eq!(TimedStatuses::from([status(3, 10)]), statuses);
+	}
+
+	#[test]
+	fn advance_and_drop_expired() {
+		let mut statuses = TimedStatuses::from([status(1, 2), status(2, 5)]);
+
+		statuses.advance(Duration::from_secs(3));
+
+		assert_eq!(TimedStatuses::from([status(2, 2)]), statuses);
+	}
+}
@@ -16,22 +16,43 @@ mod debug;
 use crate::{
 	app::add_physics::AddPhysics,
 	components::{
-		affected::{force_affected::ForceAffected, gravity_affected::GravityAffected, life::Life},
+		affected::{
+			damage_resistances::DamageResistances,
+			energy_pool::EnergyPool,
+			force_affected::ForceAffected,
+			gravity_affected::GravityAffected,
+			incoming_damage::IncomingDamage,
+			life::Life,
+			status_effects::StatusEffects,
+		},
 		anchor::{Anchor, AnchorDirty},
 		async_collider::AsyncCollider,
+		ballistic::{Ballistic, LaunchBallistic},
 		blockable::Blockable,
 		body::Body,
+		chain_jumps::ChainJumps,
 		character_gravity::CharacterGravity,
 		character_motion::ApplyMotion,
 		collider::{ColliderRoot, ColliderShape},
 		collision_domains::{Interactive, Physical},
 		default_attributes::DefaultAttributes,
-		effects::{Effects, force::ForceEffect},
+		effects::{
+			Effects,
+			absorb_shield::AbsorbShieldEffect,
+			damage_over_time::DamageOverTimeEffect,
+			force::ForceEffect,
+			slow::SlowEffect,
+			stun::StunEffect,
+		},
+		equipment_attributes::EquipmentAttributes,
+		follow_ups::FollowUps,
 		ground_target::GroundTarget,
+		homing::Homing,
 		lifetime::{LifetimeTiedTo, TiedLifetimes},
 		motion_controller::{MotionController, MotionControllerOf},
 		set_velocity_forward::SetVelocityForward,
 		skill::{Skill, SkillContactRoot, SkillProjectionRoot},
+		sweep::Sweep,
 		target::SkillTargetInternal,
 		velocity::LinearVelocity,
 		when_traveled::DestroyAfterDistanceTraveled,
@@ -48,6 +69,7 @@ use crate::{
 	},
 	systems::{
 		apply_pull::ApplyPull,
+		apply_push::ApplyPush,
 		insert_affected::InsertAffected,
 		interactions::push_ongoing_collisions::PushOngoingCollisions,
 		interpolate_position::OverstepFraction,
@@ -90,9 +112,13 @@ where
 
 		TSaveGame::register_savable_component::<ApplyMotion>(app);
 		TSaveGame::register_savable_component::<Skill>(app);
+		TSaveGame::register_savable_component::<FollowUps>(app);
 		TSaveGame::register_savable_component::<SkillTargetInternal>(app);
 		TSaveGame::register_savable_component::<LinearVelocity>(app);
 		TSaveGame::register_savable_component::<CharacterGravity>(app);
+		TSaveGame::register_savable_component::<Life>(app);
+		TSaveGame::register_savable_component::<DamageResistances>(app);
+		TSaveGame::register_savable_component::<EnergyPool>(app);
 
 		app.configure_sets(
 			Update,
@@ -136,6 +162,9 @@ where
 				FixedPreUpdate,
 				(
 					FixedPreUpdate::delta.pipe(MotionController::set_translation),
+					FixedPreUpdate::delta
+						.pipe(ForceAffected::apply_push)
+						.pipe(OnError::log),
 					FixedPreUpdate::delta.pipe(MotionController::apply_gravity),
 				)
 					.chain(),
@@ -158,6 +187,7 @@ where
 			)
 			// Skills
 			.add_observer(Skill::prefab)
+			.add_observer(FollowUps::spawn_on_expired)
 			// Colliders/Bodies
 			.add_prefab_observer::<ColliderShape, ()>()
 			.add_prefab_observer::<Body, ()>()
@@ -168,17 +198,50 @@ where
 					.in_set(PhysicsSystems::Prep),
 			)
 			.add_message::<RayEvent>()
+			.add_message::<PlaySound>()
 			.init_resource::<RootCollisions<Physical>>()
 			.init_resource::<RootCollisions<Interactive>>()
 			// All effects
 			.add_observer(Effects::insert)
 			// Deal health damage
-			.add_physics::<HealthDamageEffect, Life, TSaveGame>()
+			.add_physics::<HealthDamageEffect, IncomingDamage, TSaveGame>()
 			.add_observer(HealthDamageEffect::update_blockers_observer)
+			.add_message::<DamageDealt>()
+			.add_message::<HealthRestored>()
+			.register_required_components::<Life, IncomingDamage>()
 			.add_systems(
 				FixedPreUpdate,
-				(Life::insert_from::<DefaultAttributes>, Life::despawn_dead).chain(),
+				(
+					Life::insert_from::<DefaultAttributes>,
+					DamageResistances::insert_from::<DefaultAttributes>,
+					Life::apply_equipment_health,
+					DamageResistances::apply_equipment_resistances,
+					FixedPreUpdate::delta.pipe(StatusEffects::tick),
+					FixedPreUpdate::delta.pipe(Life::expire_shields),
+					Life::take_damage,
+					Life::despawn_dead,
+				)
+					.chain(),
+			)
+			// Energy
+			.add_systems(
+				FixedPreUpdate,
+				(
+					EnergyPool::insert_from::<DefaultAttributes>,
+					FixedPreUpdate::delta.pipe(EnergyPool::regenerate),
+				)
+					.chain(),
 			)
+			// Apply status effects
+			.register_required_components::<Life, StatusEffects>()
+			.add_physics::<DamageOverTimeEffect, StatusEffects, TSaveGame>()
+			.add_physics::<SlowEffect, StatusEffects, TSaveGame>()
+			.add_physics::<StunEffect, StatusEffects, TSaveGame>()
+			.add_physics::<AbsorbShieldEffect, Life, TSaveGame>()
+			.add_observer(DamageOverTimeEffect::update_blockers_observer)
+			.add_observer(SlowEffect::update_blockers_observer)
+			.add_observer(StunEffect::update_blockers_observer)
+			.add_observer(AbsorbShieldEffect::update_blockers_observer)
 			// Apply gravity effect
 			.add_physics::<GravityEffect, GravityAffected, TSaveGame>()
 			.add_observer(GravityEffect::update_blockers_observer)
@@ -213,6 +276,11 @@ where
 						GroundTarget::set_position::<RayCasterMut>,
 						DestroyAfterDistanceTraveled::system,
 						SetVelocityForward::system,
+						LaunchBallistic::system::<RayCasterMut>,
+						Update::delta.pipe(Homing::steer),
+						Update::delta.pipe(Ballistic::fall),
+						Update::delta.pipe(Sweep::rotate),
+						Update::delta.pipe(FollowUps::spawn_on_interval),
 					)
 						.chain(),
 				),
@@ -228,6 +296,7 @@ where
 							.pipe(UpdateRootCollisions::<Physical>::prevent_tunneling)
 							.pipe(OnError::log),
 						UpdateRootCollisions::<Physical>::push_ongoing_collisions,
+						Skill::play_impact_sound,
 					)
 						.chain(),
 					// Collect interactive collisions
@@ -242,7 +311,13 @@ where
 			)
 			.add_systems(
 				FixedPostUpdate,
-				apply_fragile_blocks.after(PhysicsSystems::Resolve),
+				(
+					ChainJumps::jump,
+					FollowUps::spawn_on_contact,
+					apply_fragile_blocks.pipe(OnError::log),
+				)
+					.chain()
+					.after(PhysicsSystems::Resolve),
 			);
 	}
 }
@@ -278,6 +353,22 @@ impl<TDependencies> SystemSetDefinition for PhysicsPlugin<TDependencies> {
 		PluginSystemSet::from_set(PhysicsSystems::Interpolate);
 }
 
+impl<TDependencies> HandlesStatusEffects for PhysicsPlugin<TDependencies> {
+	type TStatusEffects = StatusEffects;
+}
+
+impl<TDependencies> HandlesEnergy for PhysicsPlugin<TDependencies> {
+	type TEnergy = EnergyPool;
+}
+
+impl<TDependencies> HandlesMaxHealth for PhysicsPlugin<TDependencies> {
+	type TMaxHealth = Life;
+}
+
+impl<TDependencies> HandlesEquipmentStats for PhysicsPlugin<TDependencies> {
+	type TEquipmentStats = EquipmentAttributes;
+}
+
 impl<TDependencies> HandlesMotion for PhysicsPlugin<TDependencies> {
 	type TCharacterMotion = ApplyMotion;
 }
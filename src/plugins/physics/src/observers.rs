@@ -2,6 +2,7 @@ pub(crate) mod insert_effects;
 pub(crate) mod lifetime;
 pub(crate) mod process_dirty_anchor;
 pub(crate) mod skill_prefab;
+pub(crate) mod spawn_follow_ups_on_expired;
 pub(crate) mod spawn_motion_controller;
 pub(crate) mod update_blockers;
 pub(crate) mod velocity;
@@ -1,8 +1,12 @@
 use crate::components::effects::{
 	Effects,
+	absorb_shield::AbsorbShieldEffect,
+	damage_over_time::DamageOverTimeEffect,
 	force::ForceEffect,
 	gravity::GravityEffect,
 	health_damage::HealthDamageEffect,
+	slow::SlowEffect,
+	stun::StunEffect,
 };
 use bevy::prelude::*;
 use common::prelude::*;
@@ -26,6 +30,12 @@ impl Effects {
 				SkillEffect::Force(effect) => entity.try_insert(ForceEffect(*effect)),
 				SkillEffect::Gravity(effect) => entity.try_insert(GravityEffect(*effect)),
 				SkillEffect::HealthDamage(effect) => entity.try_insert(HealthDamageEffect(*effect)),
+				SkillEffect::DamageOverTime(effect) => {
+					entity.try_insert(DamageOverTimeEffect(*effect))
+				}
+				SkillEffect::Slow(effect) => entity.try_insert(SlowEffect(*effect)),
+				SkillEffect::Stun(effect) => entity.try_insert(StunEffect(*effect)),
+				SkillEffect::AbsorbShield(effect) => entity.try_insert(AbsorbShieldEffect(*effect)),
 			};
 		}
 
@@ -36,6 +46,7 @@ impl Effects {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use std::time::Duration;
 	use testing::SingleThreadedApp;
 
 	fn setup() -> App {
@@ -51,8 +62,8 @@ mod tests {
 		let mut app = setup();
 
 		let entity = app.world_mut().spawn(Effects(vec![
-			SkillEffect::Force(Force),
-			SkillEffect::HealthDamage(HealthDamage(42., EffectApplies::Once)),
+			SkillEffect::Force(Force::default()),
+			SkillEffect::HealthDamage(HealthDamage::once(42.)),
 			SkillEffect::Gravity(Gravity {
 				strength: UnitsPerSecond::from(11.),
 			}),
@@ -60,8 +71,8 @@ mod tests {
 
 		assert_eq!(
 			(
-				Some(&ForceEffect(Force)),
-				Some(&HealthDamageEffect(HealthDamage(42., EffectApplies::Once))),
+				Some(&ForceEffect(Force::default())),
+				Some(&HealthDamageEffect(HealthDamage::once(42.))),
 				Some(&GravityEffect(Gravity {
 					strength: UnitsPerSecond::from(11.),
 				})),
@@ -74,6 +85,54 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn insert_status_effects() {
+		let mut app = setup();
+		let duration = DurationInSeconds::from(Duration::from_secs(2));
+		let damage_over_time = DamageOverTime {
+			damage_per_second: 3.,
+			damage_type: DamageType::Plasma,
+			duration,
+			stacking: StatusStacking::Refresh,
+		};
+		let slow = Slow {
+			speed_reduction: 0.5,
+			duration,
+			stacking: StatusStacking::Stack { max: 2 },
+		};
+		let stun = Stun {
+			duration,
+			stacking: StatusStacking::Ignore,
+		};
+		let absorb_shield = AbsorbShield {
+			amount: 20.,
+			duration,
+			stacking: StatusStacking::Refresh,
+		};
+
+		let entity = app.world_mut().spawn(Effects(vec![
+			SkillEffect::DamageOverTime(damage_over_time),
+			SkillEffect::Slow(slow),
+			SkillEffect::Stun(stun),
+			SkillEffect::AbsorbShield(absorb_shield),
+		]));
+
+		assert_eq!(
+			(
+				Some(&DamageOverTimeEffect(damage_over_time)),
+				Some(&SlowEffect(slow)),
+				Some(&StunEffect(stun)),
+				Some(&AbsorbShieldEffect(absorb_shield)),
+			),
+			(
+				entity.get::<DamageOverTimeEffect>(),
+				entity.get::<SlowEffect>(),
+				entity.get::<StunEffect>(),
+				entity.get::<AbsorbShieldEffect>(),
+			)
+		);
+	}
+
 	#[test]
 	fn remove_effects_component() {
 		let mut app = setup();
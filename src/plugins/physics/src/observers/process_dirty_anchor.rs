@@ -69,6 +69,7 @@ impl AnchorDirty {
 				Ok(mount) => mount,
 				Err(error) => return Err(AnchorError::MountError(error)),
 			},
+			SkillMount::Parent => return Err(AnchorError::ParentMount(on_add.entity)),
 		};
 
 		let Ok((attached_to_transform, _)) = transforms.get(attached_to) else {
@@ -160,6 +161,13 @@ where
 
 			anchor_transform.look_at(offset.compute_translation(target), Vec3::Y);
 		}
+		SkillTarget::Caster => {
+			let Ok((caster, _)) = transforms.get(attached_to) else {
+				return Err(AnchorError::EntityWithoutTransform(attached_to));
+			};
+
+			return match_rotation(anchor_transform, caster);
+		}
 	}
 
 	Ok(())
@@ -172,6 +180,8 @@ pub(crate) enum AnchorError<TMountError> {
 	EntityWithoutTransform(Entity),
 	EntityWithoutTarget(Entity),
 	TranslationNaN(Entity),
+	/// Skills mounted on their parent are placed by the parent and cannot be anchored
+	ParentMount(Entity),
 }
 
 impl<TMountError> Display for AnchorError<TMountError>
@@ -185,6 +195,7 @@ where
 			AnchorError::EntityWithoutTransform(e) => write!(f, "{e}: has no transform"),
 			AnchorError::EntityWithoutTarget(e) => write!(f, "{e}: has no target"),
 			AnchorError::TranslationNaN(e) => write!(f, "{e}: translation is NaN"),
+			AnchorError::ParentMount(e) => write!(f, "{e}: cannot anchor on parent mount"),
 		}
 	}
 }
@@ -212,7 +223,7 @@ mod tests {
 	use macros::NestedMocks;
 	use mockall::{automock, predicate::eq};
 	use std::{collections::HashMap, sync::LazyLock};
-	use testing::{NestedMocks, SingleThreadedApp};
+	use testing::{NestedMocks, SingleThreadedApp, assert_eq_approx};
 
 	#[derive(Resource)]
 	struct _Lookup {
@@ -434,6 +445,39 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn match_caster_rotation_when_targeting_caster() {
+		let mut app = setup();
+		let bone = SkillMountBone::Slot(SlotKey(22));
+		let agent = app
+			.world_mut()
+			.spawn((
+				*AGENT,
+				GlobalTransform::from(Transform::default().looking_to(Dir3::X, Dir3::Y)),
+				SkillTargetInternal(Some(SkillTarget::Caster)),
+			))
+			.id();
+		let mount_entity = app
+			.world_mut()
+			.spawn(GlobalTransform::from_xyz(4., 11., 9.))
+			.id();
+		app.insert_resource(_Lookup {
+			mount_points: HashMap::from([((bone, agent), mount_entity)]),
+		});
+
+		let anchor = app.world_mut().spawn(
+			Anchor::attach_to(*AGENT)
+				.on(SkillMount::Bone(bone))
+				.looking_at_skill_target(),
+		);
+
+		assert_eq_approx!(
+			Some(Dir3::X),
+			anchor.get::<Transform>().map(Transform::forward),
+			0.0001,
+		);
+	}
+
 	mod mounted_on_bone {
 		use super::*;
 		use test_case::test_case;
@@ -955,6 +999,22 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn return_parent_mount_error() {
+		let mut app = setup();
+		app.world_mut().spawn((*AGENT, GlobalTransform::default()));
+
+		let anchor = app
+			.world_mut()
+			.spawn(Anchor::attach_to(*AGENT).on(SkillMount::Parent))
+			.id();
+
+		assert_eq!(
+			&_Result(Err(AnchorError::ParentMount(anchor))),
+			app.world().resource::<_Result>(),
+		);
+	}
+
 	#[test]
 	fn return_root_no_transform_error() {
 		let mut app = setup();
@@ -443,13 +443,13 @@ mod tests {
 					_Skill::default_object(),
 					_Skill::default_model(),
 					_Skill::default_contact_collider(),
-					Effects(vec![SkillEffect::Force(Force)]),
+					Effects(vec![SkillEffect::Force(Force::default())]),
 				),
 				..default()
 			});
 
 			assert_eq!(
-				Some(&Effects(vec![SkillEffect::Force(Force)])),
+				Some(&Effects(vec![SkillEffect::Force(Force::default())])),
 				skill.get::<Effects>(),
 			);
 		}
@@ -590,7 +590,7 @@ mod tests {
 					projection: (
 						_Skill::default_model(),
 						_Skill::default_projection_collider(),
-						Effects(vec![SkillEffect::Force(Force)]),
+						Effects(vec![SkillEffect::Force(Force::default())]),
 					),
 					..default()
 				})
@@ -598,7 +598,7 @@ mod tests {
 
 			let [.., projection] = assert_children_count!(3, app, skill);
 			assert_eq!(
-				Some(&Effects(vec![SkillEffect::Force(Force)])),
+				Some(&Effects(vec![SkillEffect::Force(Force::default())])),
 				projection.get::<Effects>(),
 			);
 		}
@@ -0,0 +1,116 @@
+use crate::components::{
+	follow_ups::{FollowUpPlacement, FollowUps, SpawnFollowUp},
+	skill::Skill,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl FollowUps {
+	pub(crate) fn spawn_on_expired(
+		on_expired: On<Expired>,
+		mut commands: ZyheedaCommands,
+		skills: Query<(&Self, &Skill, &GlobalTransform)>,
+	) {
+		let Ok((follow_ups, skill, transform)) = skills.get(on_expired.entity) else {
+			return;
+		};
+
+		let placement = FollowUpPlacement::At(transform.compute_transform());
+		for follow_up in follow_ups.triggered_on(FollowUpTrigger::OnExpiry) {
+			commands.spawn_follow_up(skill.caster, follow_up, placement);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::skill::CreatedFrom;
+	use std::time::Duration;
+	use testing::{SingleThreadedApp, assert_count};
+
+	fn parent() -> Skill {
+		Skill {
+			created_from: CreatedFrom::Spawn,
+			shape: SkillShape::Shield(Shield),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			caster: SkillCaster(PersistentEntity::default()),
+			mount: SkillMount::Center,
+			impact_sound: None,
+		}
+	}
+
+	fn follow_up(trigger: FollowUpTrigger) -> FollowUpSkill {
+		FollowUpSkill {
+			trigger,
+			shape: SkillShape::Shield(Shield),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			impact_sound: None,
+			follow_ups: vec![],
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+		app.add_observer(FollowUps::spawn_on_expired);
+
+		app
+	}
+
+	fn follow_up_skills(app: &mut App) -> Vec<(Skill, Transform)> {
+		let mut skills = app.world_mut().query::<(&Skill, &Transform)>();
+		skills
+			.iter(app.world())
+			.filter(|(skill, _)| skill.mount == SkillMount::Parent)
+			.map(|(skill, transform)| (skill.clone(), *transform))
+			.collect()
+	}
+
+	#[test]
+	fn spawn_at_parent_transform() {
+		let mut app = setup();
+		let parent = parent();
+		let entity = app
+			.world_mut()
+			.spawn((
+				parent.clone(),
+				FollowUps::from([follow_up(FollowUpTrigger::OnExpiry)]),
+				GlobalTransform::from_xyz(1., 2., 3.),
+			))
+			.id();
+
+		app.world_mut().trigger(Expired { entity });
+		app.world_mut().flush();
+
+		let [(skill, transform)] = assert_count!(1, follow_up_skills(&mut app).into_iter());
+		assert_eq!(
+			(parent.caster, Transform::from_xyz(1., 2., 3.)),
+			(skill.caster, transform)
+		);
+	}
+
+	#[test]
+	fn spawn_only_expiry_follow_ups() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				parent(),
+				FollowUps::from([
+					follow_up(FollowUpTrigger::OnContact),
+					follow_up(FollowUpTrigger::Interval(Duration::from_secs(1))),
+				]),
+				GlobalTransform::default(),
+			))
+			.id();
+
+		app.world_mut().trigger(Expired { entity });
+		app.world_mut().flush();
+
+		assert_count!(0, follow_up_skills(&mut app).into_iter());
+	}
+}
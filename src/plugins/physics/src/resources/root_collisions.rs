@@ -34,6 +34,18 @@ impl<T> RootCollisions<T> {
 		self.ongoing.get(entity).unwrap_or(&*EMPTY)
 	}
 
+	pub(crate) fn just_started(&self, entity: &Entity) -> HashSet<Entity> {
+		let ongoing = self.ongoing.get(entity).unwrap_or(&*EMPTY);
+		let old = self.old.get(entity).unwrap_or(&*EMPTY);
+
+		HashSet::from_iter(
+			ongoing
+				.iter()
+				.filter(|ongoing| !old.contains(ongoing))
+				.copied(),
+		)
+	}
+
 	pub(crate) fn just_stopped(&self, entity: &Entity) -> HashSet<Entity> {
 		let ongoing = self.ongoing.get(entity).unwrap_or(&*EMPTY);
 		let old = self.old.get(entity).unwrap_or(&*EMPTY);
@@ -215,4 +227,21 @@ mod tests {
 			interactions.just_stopped(&fake_entity!(1)),
 		);
 	}
+
+	#[test]
+	fn iterate_just_started() {
+		let mut interactions = RootCollisions::<()>::default();
+
+		interactions.update(fake_entity!(1), [fake_entity!(2), fake_entity!(3)]);
+		interactions.rotate();
+		interactions.update(
+			fake_entity!(1),
+			[fake_entity!(2), fake_entity!(3), fake_entity!(4)],
+		);
+
+		assert_eq!(
+			HashSet::from([fake_entity!(4)]),
+			interactions.just_started(&fake_entity!(1)),
+		);
+	}
 }
@@ -40,6 +40,11 @@ mod tests {
 				let mut ctx = ConfigParamMut::try_get_context_mut(&mut p, key).unwrap();
 				ctx.configure_default_attributes(PhysicalDefaultAttributes {
 					health: Health::new(11.),
+					resistances: Resistances {
+						void: 0.5,
+						..default()
+					},
+					energy: Energy::new(20., 2.),
 					force_interaction: EffectTarget::Affected,
 					gravity_interaction: EffectTarget::Immune,
 				});
@@ -48,6 +53,11 @@ mod tests {
 		assert_eq!(
 			Some(&DefaultAttributes(PhysicalDefaultAttributes {
 				health: Health::new(11.),
+				resistances: Resistances {
+					void: 0.5,
+					..default()
+				},
+				energy: Energy::new(20., 2.),
 				force_interaction: EffectTarget::Affected,
 				gravity_interaction: EffectTarget::Immune,
 			})),
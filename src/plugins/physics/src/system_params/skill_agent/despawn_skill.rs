@@ -41,6 +41,7 @@ mod tests {
 			projection_effects: vec![],
 			caster: SkillCaster(PersistentEntity::default()),
 			mount: SkillMount::Center,
+			impact_sound: None,
 		}
 	}
 
@@ -1,5 +1,8 @@
 use crate::{
-	components::skill::{CreatedFrom, Skill},
+	components::{
+		follow_ups::FollowUps,
+		skill::{CreatedFrom, Skill},
+	},
 	system_params::skill_agent::SkillAgentMut,
 };
 use common::prelude::*;
@@ -8,7 +11,7 @@ impl SpawnSkill for SkillAgentMut<'_, '_> {
 	fn spawn_skill(&mut self, args: SpawnArgs) -> PersistentEntity {
 		let persistent_entity = PersistentEntity::default();
 
-		self.commands.spawn((
+		let mut entity = self.commands.spawn((
 			Skill {
 				shape: args.shape.clone(),
 				created_from: CreatedFrom::Spawn,
@@ -16,10 +19,15 @@ impl SpawnSkill for SkillAgentMut<'_, '_> {
 				projection_effects: args.projection_effects.to_vec(),
 				caster: args.caster,
 				mount: args.mount,
+				impact_sound: args.impact_sound.cloned(),
 			},
 			persistent_entity,
 		));
 
+		if !args.follow_ups.is_empty() {
+			entity.insert(FollowUps::from(args.follow_ups.to_vec()));
+		}
+
 		persistent_entity
 	}
 }
@@ -50,6 +58,8 @@ mod tests {
 		projection_effects: &[],
 		caster: SkillCaster(PersistentEntity::default()),
 		mount: SkillMount::Center,
+		impact_sound: None,
+		follow_ups: &[],
 	});
 
 	mod spawn {
@@ -92,6 +102,7 @@ mod tests {
 					projection_effects: ARGS.projection_effects.to_vec(),
 					caster: ARGS.caster,
 					mount: ARGS.mount,
+					impact_sound: ARGS.impact_sound.cloned(),
 				},
 				skill
 			);
@@ -99,6 +110,62 @@ mod tests {
 		}
 	}
 
+	mod follow_ups {
+		use super::*;
+		use std::time::Duration;
+
+		static FOLLOW_UPS: LazyLock<Vec<FollowUpSkill>> = LazyLock::new(|| {
+			vec![FollowUpSkill {
+				trigger: FollowUpTrigger::OnExpiry,
+				shape: SkillShape::Shield(Shield),
+				contact_effects: vec![],
+				projection_effects: vec![],
+				impact_sound: None,
+				follow_ups: vec![],
+			}]
+		});
+
+		#[test]
+		fn insert_follow_ups() -> Result<(), RunSystemError> {
+			let mut app = setup();
+
+			app.world_mut()
+				.run_system_once(move |mut p: SkillAgentMut| {
+					p.spawn_skill(SpawnArgs {
+						follow_ups: &FOLLOW_UPS,
+						..*ARGS
+					});
+				})?;
+
+			let mut skills = app.world_mut().query_filtered::<&FollowUps, With<Skill>>();
+			let [follow_ups] = assert_count!(1, skills.iter(app.world()));
+			assert_eq!(
+				&FollowUps {
+					skills: FOLLOW_UPS.clone(),
+					elapsed: Duration::ZERO,
+				},
+				follow_ups
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn do_not_insert_empty_follow_ups() -> Result<(), RunSystemError> {
+			let mut app = setup();
+
+			app.world_mut()
+				.run_system_once(move |mut p: SkillAgentMut| {
+					p.spawn_skill(*ARGS);
+				})?;
+
+			let mut skills = app
+				.world_mut()
+				.query_filtered::<(), (With<Skill>, With<FollowUps>)>();
+			assert_count!(0, skills.iter(app.world()));
+			Ok(())
+		}
+	}
+
 	mod returned_skill {
 		use super::*;
 
@@ -1,8 +1,13 @@
+pub(crate) mod apply_equipment_resistances;
 pub(crate) mod apply_gravity;
 pub(crate) mod apply_pull;
+pub(crate) mod apply_push;
 pub(crate) mod async_collider;
+pub(crate) mod ballistic;
 pub(crate) mod blockable;
+pub(crate) mod chain_jump;
 pub(crate) mod ground_target;
+pub(crate) mod homing;
 pub(crate) mod insert_affected;
 pub(crate) mod interactions;
 pub(crate) mod interpolate_position;
@@ -10,10 +15,15 @@ pub(crate) mod life;
 pub(crate) mod link_to_collider_root;
 pub(crate) mod mark_anchor_dirty;
 pub(crate) mod ongoing_interactions;
+pub(crate) mod play_impact_sound;
 pub(crate) mod prevent_tunneling;
+pub(crate) mod regenerate_energy;
 pub(crate) mod set_motion_done;
 pub(crate) mod set_motion_translation;
 pub(crate) mod set_velocity_forward;
+pub(crate) mod spawn_follow_ups;
+pub(crate) mod sweep;
+pub(crate) mod tick_status_effects;
 pub(crate) mod update_target_pitch;
 pub(crate) mod when_traveled;
 pub(crate) mod world_camera;
@@ -0,0 +1,151 @@
+use crate::components::{
+	affected::damage_resistances::DamageResistances,
+	default_attributes::DefaultAttributes,
+	equipment_attributes::EquipmentAttributes,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl DamageResistances {
+	#[allow(clippy::type_complexity)]
+	pub(crate) fn apply_equipment_resistances(
+		agents: Query<
+			(&mut Self, &DefaultAttributes, &EquipmentAttributes),
+			Or<(
+				Changed<EquipmentAttributes>,
+				Changed<DefaultAttributes>,
+				Added<Self>,
+			)>,
+		>,
+	) {
+		for (mut resistances, default_attributes, EquipmentAttributes(stats)) in agents {
+			let base = View::<AttributeOnSpawn<Resistances>>::view(default_attributes);
+			resistances.set_if_neq(Self(base + stats.resistances));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(
+				DamageResistances::apply_equipment_resistances,
+				IsChanged::<DamageResistances>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn default_attributes(resistances: Resistances) -> DefaultAttributes {
+		DefaultAttributes(PhysicalDefaultAttributes {
+			resistances,
+			..default()
+		})
+	}
+
+	fn equipment(resistances: Resistances) -> EquipmentAttributes {
+		EquipmentAttributes(EquipmentStats {
+			resistances,
+			..default()
+		})
+	}
+
+	#[test]
+	fn add_equipment_to_default_resistances() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				DamageResistances(Resistances::default()),
+				default_attributes(Resistances {
+					kinetic: 0.25,
+					..default()
+				}),
+				equipment(Resistances {
+					kinetic: 0.25,
+					void: 0.5,
+					..default()
+				}),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&DamageResistances(Resistances {
+				kinetic: 0.5,
+				void: 0.5,
+				..default()
+			})),
+			app.world().entity(entity).get::<DamageResistances>(),
+		);
+	}
+
+	#[test]
+	fn revert_to_default_resistances_when_equipment_changes() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				DamageResistances(Resistances::default()),
+				default_attributes(Resistances {
+					kinetic: 0.25,
+					..default()
+				}),
+				equipment(Resistances {
+					void: 0.5,
+					..default()
+				}),
+			))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(entity)
+			.insert(equipment(Resistances::default()));
+		app.update();
+
+		assert_eq!(
+			Some(&DamageResistances(Resistances {
+				kinetic: 0.25,
+				..default()
+			})),
+			app.world().entity(entity).get::<DamageResistances>(),
+		);
+	}
+
+	#[test]
+	fn act_only_once() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				DamageResistances(Resistances::default()),
+				default_attributes(Resistances::default()),
+				equipment(Resistances {
+					void: 0.5,
+					..default()
+				}),
+			))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world()
+				.entity(entity)
+				.get::<IsChanged<DamageResistances>>(),
+		);
+	}
+}
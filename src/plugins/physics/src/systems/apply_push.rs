@@ -0,0 +1,487 @@
+use crate::{
+	components::{
+		affected::force_affected::ForcePush,
+		collider::Colliders,
+		effects::force::ForceEffect,
+		motion_controller::{MotionController, OldTranslation},
+		persistent_root::PersistentRoot,
+	},
+	traits::project_point::{GetPointProjector, ProjectPoint},
+};
+use bevy::{
+	ecs::{
+		component::Mutable,
+		system::{StaticSystemParam, SystemParam},
+	},
+	prelude::*,
+};
+use bevy_rapier3d::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+impl<T> ApplyPush for T where T: PushAbleByForce + Component<Mutability = Mutable> {}
+
+pub(crate) trait ApplyPush:
+	PushAbleByForce + Component<Mutability = Mutable> + Sized
+{
+	fn apply_push(
+		delta: In<Duration>,
+		get_projector: StaticSystemParam<ReadRapierContext>,
+		controllers: Query<Controller, Without<MotionController>>,
+		controlled: Query<(&mut Self, &MotionController)>,
+		forces: Query<(&PersistentRoot, &Colliders), With<ForceEffect>>,
+	) -> Result<(), NoPointProjector> {
+		system_internal(delta, get_projector, controllers, controlled, forces)
+	}
+}
+
+type Controller = (
+	&'static mut KinematicCharacterController,
+	&'static Transform,
+	&'static mut OldTranslation,
+);
+
+fn system_internal<TAffected, TGetProjector, TError>(
+	In(delta): In<Duration>,
+	get_projector: StaticSystemParam<TGetProjector>,
+	mut controllers: Query<Controller, Without<MotionController>>,
+	controlled: Query<(&mut TAffected, &MotionController)>,
+	forces: Query<(&PersistentRoot, &Colliders), With<ForceEffect>>,
+) -> Result<(), NoPointProjector<TError>>
+where
+	TAffected: PushAbleByForce + Component<Mutability = Mutable>,
+	TGetProjector: for<'w, 's> SystemParam<Item<'w, 's>: GetPointProjector<TError = TError>>,
+{
+	let projector = match get_projector.get_point_projector() {
+		Ok(projector) => projector,
+		Err(error) => return Err(NoPointProjector(error)),
+	};
+	let delta_secs = delta.as_secs_f32();
+
+	for (mut affected, ctrl) in controlled {
+		if !affected.is_pushed() {
+			continue;
+		}
+
+		let Ok((mut ctrl, current, mut old)) = controllers.get_mut(ctrl.id()) else {
+			affected.drain_pushes().for_each(drop);
+			continue;
+		};
+
+		let position = current.translation;
+		let get_force_colliders = |push: &ForcePush| {
+			forces
+				.iter()
+				.filter(|(PersistentRoot(root), _)| root == &push.from)
+				.flat_map(|(_, colliders)| colliders.iter())
+				.collect::<Vec<_>>()
+		};
+		let push_sum = affected
+			.drain_pushes()
+			.filter_map(|push| {
+				let projection = projector.project_point(position, &get_force_colliders(&push))?;
+				Some(get_push_vector(delta_secs, position, push, projection))
+			})
+			.sum::<Vec3>();
+
+		ctrl.translation = match ctrl.translation {
+			Some(translation) => Some(translation + push_sum),
+			None => {
+				*old = OldTranslation(current.translation);
+				Some(push_sum)
+			}
+		};
+	}
+
+	Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct NoPointProjector<TError = BevyError>(pub(crate) TError);
+
+impl ErrorData for NoPointProjector {
+	fn level(&self) -> Level {
+		Level::Error
+	}
+
+	fn label() -> impl std::fmt::Display {
+		"No point projector"
+	}
+
+	fn into_details(self) -> impl std::fmt::Display {
+		format!("{:?}", self.0)
+	}
+}
+
+pub(crate) trait PushAbleByForce {
+	type TDrain<'a>: Iterator<Item = ForcePush>
+	where
+		Self: 'a;
+
+	fn is_pushed(&self) -> bool;
+	fn drain_pushes(&mut self) -> Self::TDrain<'_>;
+}
+
+fn get_push_vector(
+	delta_secs: f32,
+	position: Vec3,
+	push: ForcePush,
+	projection: PointProjection,
+) -> Vec3 {
+	let direction = match projection.is_inside {
+		true => projection.point - position,
+		false => position - projection.point,
+	};
+
+	direction.with_y(0.).normalize_or_zero() * *push.strength * delta_secs
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use crate::{
+		components::motion_controller::MotionControllerOf,
+		tests::{NoTestProjector, TestPointProjector, TestProjector},
+	};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use std::{collections::HashSet, vec::Drain};
+	use testing::{SingleThreadedApp, assert_eq_approx};
+
+	#[derive(Component, Debug, PartialEq)]
+	struct _ForceTarget(Vec<ForcePush>);
+
+	impl<T> From<T> for _ForceTarget
+	where
+		T: IntoIterator<Item = ForcePush>,
+	{
+		fn from(pushes: T) -> Self {
+			Self(pushes.into_iter().collect())
+		}
+	}
+
+	impl PushAbleByForce for _ForceTarget {
+		type TDrain<'a> = Drain<'a, ForcePush>;
+
+		fn is_pushed(&self) -> bool {
+			!self.0.is_empty()
+		}
+
+		fn drain_pushes(&mut self) -> Self::TDrain<'_> {
+			self.0.drain(..)
+		}
+	}
+
+	fn system(
+		delta: In<Duration>,
+		get_projector: StaticSystemParam<Res<TestPointProjector>>,
+		controllers: Query<Controller, Without<MotionController>>,
+		controlled: Query<(&mut _ForceTarget, &MotionController)>,
+		forces: Query<(&PersistentRoot, &Colliders), With<ForceEffect>>,
+	) -> Result<(), NoPointProjector<NoTestProjector>> {
+		system_internal(delta, get_projector, controllers, controlled, forces)
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<TestPointProjector>();
+
+		app
+	}
+
+	fn spawn_force(app: &mut App, from: PersistentEntity) -> HashSet<Entity> {
+		let force = app
+			.world_mut()
+			.spawn((
+				PersistentRoot(from),
+				ForceEffect(Force::default()),
+				related!(Colliders[(), ()]),
+			))
+			.id();
+
+		app.world()
+			.entity(force)
+			.get::<Colliders>()
+			.map(|colliders| colliders.iter().collect())
+			.unwrap_or_default()
+	}
+
+	fn spawn_agent(app: &mut App, pushes: impl IntoIterator<Item = ForcePush>) -> Entity {
+		let agent = app.world_mut().spawn(_ForceTarget::from(pushes)).id();
+
+		app.world_mut()
+			.spawn((
+				MotionControllerOf(agent),
+				Transform::from_xyz(1., 2., 3.),
+				KinematicCharacterController::default(),
+			))
+			.id()
+	}
+
+	fn translation(app: &App, ctrl: Entity) -> Option<Vec3> {
+		app.world()
+			.entity(ctrl)
+			.get::<KinematicCharacterController>()
+			.and_then(|ctrl| ctrl.translation)
+	}
+
+	#[test]
+	fn push_towards_surface_when_inside() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let from = PersistentEntity::default();
+		let colliders = spawn_force(&mut app, from);
+		let ctrl = spawn_agent(
+			&mut app,
+			[ForcePush {
+				strength: UnitsPerSecond::from(2.),
+				from,
+			}],
+		);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders,
+			projection: PointProjection {
+				is_inside: true,
+				point: Vec3::new(4., 2., 3.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_millis(100))?;
+
+		assert_eq_approx!(
+			Some(Vec3::new(0.2, 0., 0.)),
+			translation(&app, ctrl),
+			0.0001
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn push_away_from_surface_when_outside() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let from = PersistentEntity::default();
+		let colliders = spawn_force(&mut app, from);
+		let ctrl = spawn_agent(
+			&mut app,
+			[ForcePush {
+				strength: UnitsPerSecond::from(2.),
+				from,
+			}],
+		);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders,
+			projection: PointProjection {
+				is_inside: false,
+				point: Vec3::new(1., 2., 2.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_millis(100))?;
+
+		assert_eq_approx!(
+			Some(Vec3::new(0., 0., 0.2)),
+			translation(&app, ctrl),
+			0.0001
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn push_horizontally() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let from = PersistentEntity::default();
+		let colliders = spawn_force(&mut app, from);
+		let ctrl = spawn_agent(
+			&mut app,
+			[ForcePush {
+				strength: UnitsPerSecond::from(2.),
+				from,
+			}],
+		);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders,
+			projection: PointProjection {
+				is_inside: true,
+				point: Vec3::new(4., 10., 3.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_secs(1))?;
+
+		assert_eq_approx!(Some(Vec3::new(2., 0., 0.)), translation(&app, ctrl), 0.0001);
+		Ok(())
+	}
+
+	#[test]
+	fn add_to_present_translation() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let from = PersistentEntity::default();
+		let colliders = spawn_force(&mut app, from);
+		let ctrl = spawn_agent(
+			&mut app,
+			[ForcePush {
+				strength: UnitsPerSecond::from(2.),
+				from,
+			}],
+		);
+		app.world_mut()
+			.entity_mut(ctrl)
+			.insert(KinematicCharacterController {
+				translation: Some(Vec3::new(0., -1., 1.)),
+				..default()
+			});
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders,
+			projection: PointProjection {
+				is_inside: true,
+				point: Vec3::new(4., 2., 3.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_secs(1))?;
+
+		assert_eq_approx!(
+			Some(Vec3::new(2., -1., 1.)),
+			translation(&app, ctrl),
+			0.0001
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn set_old_translation() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let from = PersistentEntity::default();
+		let colliders = spawn_force(&mut app, from);
+		let ctrl = spawn_agent(
+			&mut app,
+			[ForcePush {
+				strength: UnitsPerSecond::from(2.),
+				from,
+			}],
+		);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders,
+			projection: PointProjection {
+				is_inside: true,
+				point: Vec3::new(4., 2., 3.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_secs(1))?;
+
+		assert_eq!(
+			Some(&OldTranslation(Vec3::new(1., 2., 3.))),
+			app.world().entity(ctrl).get::<OldTranslation>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn ignore_colliders_of_other_forces() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let from = PersistentEntity::default();
+		let other = PersistentEntity::default();
+		spawn_force(&mut app, from);
+		let other_colliders = spawn_force(&mut app, other);
+		let ctrl = spawn_agent(
+			&mut app,
+			[ForcePush {
+				strength: UnitsPerSecond::from(2.),
+				from,
+			}],
+		);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders: other_colliders,
+			projection: PointProjection {
+				is_inside: true,
+				point: Vec3::new(4., 2., 3.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_secs(1))?;
+
+		assert_eq!(Some(Vec3::ZERO), translation(&app, ctrl));
+		Ok(())
+	}
+
+	#[test]
+	fn drain_pushes() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let from = PersistentEntity::default();
+		let colliders = spawn_force(&mut app, from);
+		let ctrl = spawn_agent(
+			&mut app,
+			[ForcePush {
+				strength: UnitsPerSecond::from(2.),
+				from,
+			}],
+		);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders,
+			projection: PointProjection {
+				is_inside: true,
+				point: Vec3::new(4., 2., 3.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_secs(1))?;
+
+		let agent = app
+			.world()
+			.entity(ctrl)
+			.get::<MotionControllerOf>()
+			.unwrap();
+		assert_eq!(
+			Some(&_ForceTarget(vec![])),
+			app.world().entity(agent.0).get::<_ForceTarget>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn do_nothing_when_not_pushed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let ctrl = spawn_agent(&mut app, []);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders: HashSet::default(),
+			projection: PointProjection {
+				is_inside: true,
+				point: Vec3::new(4., 2., 3.),
+			},
+		})));
+
+		_ = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_secs(1))?;
+
+		assert_eq!(None, translation(&app, ctrl));
+		Ok(())
+	}
+
+	#[test]
+	fn return_projector_error() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		let result = app
+			.world_mut()
+			.run_system_once_with(system, Duration::from_secs(1))?;
+
+		assert_eq!(Err(NoPointProjector(NoTestProjector)), result);
+		Ok(())
+	}
+}
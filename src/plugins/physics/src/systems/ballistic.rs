@@ -0,0 +1,350 @@
+use crate::components::{
+	ballistic::{Ballistic, LaunchBallistic},
+	target::SkillTargetInternal,
+	velocity::LinearVelocity,
+	when_traveled::WhenTraveled,
+};
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+use std::time::Duration;
+
+impl LaunchBallistic {
+	/// Keeps very close targets from requiring an extreme vertical launch velocity
+	const MIN_FLIGHT_SECS: f32 = 0.2;
+
+	pub(crate) fn system<TRayCaster>(
+		mut commands: ZyheedaCommands,
+		launches: Query<(Entity, &Self, &Transform)>,
+		targets: Query<&SkillTargetInternal>,
+		transforms: Query<&Transform>,
+		mut ray_caster: StaticSystemParam<TRayCaster>,
+	) where
+		TRayCaster: for<'w, 's> SystemParam<Item<'w, 's>: Raycast<MouseHover>>,
+	{
+		for (entity, launch, transform) in &launches {
+			let origin = transform.translation;
+			let landing = launch
+				.landing(&commands, &targets, &transforms, &mut ray_caster)
+				.unwrap_or_else(|| origin + transform.forward().with_y(0.) * *launch.range);
+			let (velocity, distance) = launch.arc(landing - origin);
+
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert((
+					LinearVelocity(velocity),
+					WhenTraveled::distance(Units::from(distance)).destroy(),
+				));
+				e.try_remove::<Self>();
+			});
+		}
+	}
+
+	fn landing(
+		&self,
+		commands: &ZyheedaCommands,
+		targets: &Query<&SkillTargetInternal>,
+		transforms: &Query<&Transform>,
+		ray_caster: &mut impl Raycast<MouseHover>,
+	) -> Option<Vec3> {
+		let caster = commands.get(&self.caster.0)?;
+		let Ok(SkillTargetInternal(Some(target))) = targets.get(caster) else {
+			return None;
+		};
+		let target = match target {
+			SkillTarget::Cursor(_) => {
+				return match ray_caster.raycast(MouseHover::TERRAIN_WITHOUT_EXCLUDES)? {
+					MouseHoversOver::Point(point) | MouseHoversOver::Object { point, .. } => {
+						Some(point)
+					}
+				};
+			}
+			SkillTarget::Entity(target) => commands.get(target)?,
+			SkillTarget::Caster => caster,
+		};
+
+		transforms.get(target).ok().map(|t| t.translation)
+	}
+
+	/// Velocity to land at `offset` and an upper bound of the distance flown until then.
+	fn arc(&self, offset: Vec3) -> (Vec3, f32) {
+		let horizontal = offset.with_y(0.).clamp_length_max(*self.range);
+		let distance = horizontal.length();
+		let flight_secs = (distance / *self.speed).max(Self::MIN_FLIGHT_SECS);
+		let vertical = offset.y / flight_secs + 0.5 * Ballistic::GRAVITY * flight_secs;
+		let rise = vertical.max(0.).powi(2) / (2. * Ballistic::GRAVITY);
+
+		(
+			horizontal / flight_secs + Vec3::Y * vertical,
+			distance + 2. * rise - offset.y,
+		)
+	}
+}
+
+impl Ballistic {
+	pub(crate) fn fall(
+		In(delta): In<Duration>,
+		mut commands: ZyheedaCommands,
+		projectiles: Query<(Entity, &LinearVelocity), With<Self>>,
+	) {
+		let fall = Vec3::Y * Self::GRAVITY * delta.as_secs_f32();
+
+		for (entity, LinearVelocity(velocity)) in &projectiles {
+			let velocity = *velocity - fall;
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert(LinearVelocity(velocity));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::when_traveled::DestroyAfterDistanceTraveled;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use macros::NestedMocks;
+	use mockall::{automock, predicate::eq};
+	use test_case::test_case;
+	use testing::{NestedMocks, SingleThreadedApp, assert_eq_approx, fake_entity};
+
+	#[derive(Resource, NestedMocks)]
+	struct _RayCaster {
+		mock: Mock_RayCaster,
+	}
+
+	impl Default for _RayCaster {
+		fn default() -> Self {
+			Self::new().with_mock(|mock| {
+				mock.expect_raycast().return_const(None);
+			})
+		}
+	}
+
+	#[automock]
+	impl Raycast<MouseHover> for _RayCaster {
+		fn raycast(&mut self, args: MouseHover) -> Option<MouseHoversOver> {
+			self.mock.raycast(args)
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+		app.init_resource::<_RayCaster>();
+		app.add_systems(Update, LaunchBallistic::system::<ResMut<_RayCaster>>);
+
+		app
+	}
+
+	fn spawn_caster(app: &mut App, target: SkillTarget) -> SkillCaster {
+		let caster = SkillCaster::default();
+		app.world_mut().spawn((
+			Transform::from_xyz(0., 0., 2.),
+			*caster,
+			SkillTargetInternal(Some(target)),
+		));
+
+		caster
+	}
+
+	fn launch(caster: SkillCaster) -> LaunchBallistic {
+		LaunchBallistic {
+			caster,
+			range: Units::from(10.),
+			speed: UnitsPerSecond::from(3.),
+		}
+	}
+
+	#[test_case(MouseHoversOver::Point(Vec3::new(3., 0., 0.)); "point")]
+	#[test_case(
+		MouseHoversOver::Object {
+			entity: fake_entity!(42),
+			point: Vec3::new(3., 0., 0.),
+		};
+		"object"
+	)]
+	fn launch_towards_cursor_point(hover: MouseHoversOver) {
+		let mut app = setup();
+		let caster = spawn_caster(&mut app, SkillTarget::Cursor(Cursor::TerrainHover));
+		app.insert_resource(_RayCaster::new().with_mock(|mock| {
+			mock.expect_raycast()
+				.once()
+				.with(eq(MouseHover::TERRAIN_WITHOUT_EXCLUDES))
+				.return_const(hover);
+		}));
+		let entity = app
+			.world_mut()
+			.spawn((launch(caster), Transform::default()))
+			.id();
+
+		app.update();
+
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(3., 0.5 * Ballistic::GRAVITY, 0.))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn launch_towards_target_entity() {
+		let mut app = setup();
+		let target = PersistentEntity::default();
+		app.world_mut()
+			.spawn((target, Transform::from_xyz(0., 3., -6.)));
+		let caster = spawn_caster(&mut app, SkillTarget::Entity(target));
+		let entity = app
+			.world_mut()
+			.spawn((launch(caster), Transform::default()))
+			.id();
+
+		app.update();
+
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(
+				0.,
+				1.5 + Ballistic::GRAVITY,
+				-3.
+			))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn launch_towards_caster() {
+		let mut app = setup();
+		let caster = spawn_caster(&mut app, SkillTarget::Caster);
+		let entity = app
+			.world_mut()
+			.spawn((launch(caster), Transform::from_xyz(0., 0., -1.)))
+			.id();
+
+		app.update();
+
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(0., 0.5 * Ballistic::GRAVITY, 3.))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn limit_landing_to_range() {
+		let mut app = setup();
+		let caster = spawn_caster(&mut app, SkillTarget::Cursor(Cursor::TerrainHover));
+		app.insert_resource(_RayCaster::new().with_mock(|mock| {
+			mock.expect_raycast()
+				.return_const(MouseHoversOver::Point(Vec3::new(30., 0., 0.)));
+		}));
+		let entity = app
+			.world_mut()
+			.spawn((launch(caster), Transform::default()))
+			.id();
+
+		app.update();
+
+		let flight_secs = 10. / 3.;
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(
+				3.,
+				0.5 * Ballistic::GRAVITY * flight_secs,
+				0.
+			))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn launch_forward_to_range_when_no_target_found() {
+		let mut app = setup();
+		let caster = spawn_caster(&mut app, SkillTarget::Cursor(Cursor::TerrainHover));
+		let entity = app
+			.world_mut()
+			.spawn((
+				launch(caster),
+				Transform::default().looking_to(Dir3::X, Dir3::Y),
+			))
+			.id();
+
+		app.update();
+
+		let flight_secs = 10. / 3.;
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(
+				3.,
+				0.5 * Ballistic::GRAVITY * flight_secs,
+				0.
+			))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn limit_travel_distance_to_arc() {
+		let mut app = setup();
+		let caster = spawn_caster(&mut app, SkillTarget::Cursor(Cursor::TerrainHover));
+		app.insert_resource(_RayCaster::new().with_mock(|mock| {
+			mock.expect_raycast()
+				.return_const(MouseHoversOver::Point(Vec3::new(3., 0., 0.)));
+		}));
+		let entity = app
+			.world_mut()
+			.spawn((launch(caster), Transform::default()))
+			.id();
+
+		app.update();
+
+		let rise = Ballistic::GRAVITY / 8.;
+		assert_eq_approx!(
+			Some(3. + 2. * rise),
+			app.world()
+				.entity(entity)
+				.get::<DestroyAfterDistanceTraveled>()
+				.map(|traveled| *traveled.remaining_distance),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn remove_launch() {
+		let mut app = setup();
+		let caster = spawn_caster(&mut app, SkillTarget::Caster);
+		let entity = app
+			.world_mut()
+			.spawn((launch(caster), Transform::default()))
+			.id();
+
+		app.update();
+
+		assert_eq!(None, app.world().entity(entity).get::<LaunchBallistic>());
+	}
+
+	#[test]
+	fn fall() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((Ballistic, LinearVelocity(Vec3::new(1., 2., 3.))))
+			.id();
+
+		app.world_mut()
+			.run_system_once_with(Ballistic::fall, Duration::from_millis(100))?;
+
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(
+				1.,
+				2. - Ballistic::GRAVITY * 0.1,
+				3.
+			))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+		Ok(())
+	}
+}
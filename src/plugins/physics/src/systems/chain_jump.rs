@@ -0,0 +1,266 @@
+use crate::{
+	components::{
+		affected::life::Life,
+		chain_jumps::ChainJumps,
+		collision_domains::Physical,
+		skill::Skill,
+		velocity::LinearVelocity,
+	},
+	resources::root_collisions::RootCollisions,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl ChainJumps {
+	pub(crate) fn jump(
+		mut commands: ZyheedaCommands,
+		collisions: Res<RootCollisions<Physical>>,
+		chains: Query<(
+			Entity,
+			&mut Self,
+			&Skill,
+			&Transform,
+			Option<&LinearVelocity>,
+		)>,
+		targets: Query<(Entity, &GlobalTransform), With<Life>>,
+	) {
+		for (entity, mut chain, skill, transform, velocity) in chains {
+			let caster = commands.get(&skill.caster.0);
+			let is_new_target =
+				|target: &Entity| Some(*target) != caster && !chain.hit.contains(target);
+			let hits = collisions
+				.ongoing(&entity)
+				.iter()
+				.filter(|hit| targets.contains(**hit))
+				.filter(|hit| is_new_target(hit))
+				.copied()
+				.collect::<Vec<_>>();
+
+			if hits.is_empty() {
+				continue;
+			}
+
+			chain.hit.extend(hits);
+
+			let position = transform.translation;
+			let next = targets
+				.iter()
+				.filter(|(target, _)| Some(*target) != caster && !chain.hit.contains(target))
+				.map(|(_, target)| target.translation())
+				.filter(|target| target.distance(position) <= *chain.range)
+				.min_by(|a, b| a.distance(position).total_cmp(&b.distance(position)));
+			let direction = next.and_then(|next| Dir3::new(next - position).ok());
+
+			let (Some(remaining), Some(direction)) = (chain.remaining.checked_sub(1), direction)
+			else {
+				commands.try_apply_on(&entity, |e| e.try_despawn());
+				continue;
+			};
+			let speed = velocity.map_or(0., |LinearVelocity(velocity)| velocity.length());
+
+			chain.remaining = remaining;
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert((
+					Transform::from_translation(position).looking_to(direction, Dir3::Y),
+					LinearVelocity(*direction * speed),
+				));
+			});
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::skill::CreatedFrom;
+	use bevy::ecs::entity::EntityHashSet;
+	use testing::{SingleThreadedApp, assert_eq_approx};
+
+	fn chain(remaining: u8) -> ChainJumps {
+		ChainJumps {
+			range: Units::from(5.),
+			remaining,
+			hit: EntityHashSet::default(),
+		}
+	}
+
+	fn skill(caster: PersistentEntity) -> Skill {
+		Skill {
+			created_from: CreatedFrom::Spawn,
+			shape: SkillShape::Chain(Chain {
+				range: Units::from(5.),
+				jumps: 3,
+				destroyed_by: Blockers::All,
+			}),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			caster: SkillCaster(caster),
+			mount: SkillMount::Center,
+			impact_sound: None,
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+		app.init_resource::<RootCollisions<Physical>>();
+		app.add_systems(Update, ChainJumps::jump);
+
+		app
+	}
+
+	fn spawn_target(app: &mut App, position: Vec3) -> Entity {
+		app.world_mut()
+			.spawn((
+				Life::from(Health::new(10.)),
+				GlobalTransform::from_translation(position),
+			))
+			.id()
+	}
+
+	fn spawn_chain(app: &mut App, chain: ChainJumps, caster: PersistentEntity) -> Entity {
+		app.world_mut()
+			.spawn((
+				chain,
+				skill(caster),
+				Transform::from_xyz(1., 0., 0.),
+				LinearVelocity(Vec3::new(0., 0., -2.)),
+			))
+			.id()
+	}
+
+	fn collide(app: &mut App, entity: Entity, targets: &[Entity]) {
+		let mut collisions = app.world_mut().resource_mut::<RootCollisions<Physical>>();
+		collisions.rotate();
+		collisions.update(entity, targets.iter().copied());
+	}
+
+	#[test]
+	fn redirect_towards_closest_target() {
+		let mut app = setup();
+		let hit = spawn_target(&mut app, Vec3::new(1., 0., 0.));
+		spawn_target(&mut app, Vec3::new(1., 0., 4.));
+		spawn_target(&mut app, Vec3::new(3., 0., 0.));
+		let entity = spawn_chain(&mut app, chain(3), PersistentEntity::default());
+		collide(&mut app, entity, &[hit]);
+
+		app.update();
+
+		let entity = app.world().entity(entity);
+		assert_eq_approx!(
+			Some(&Transform::from_xyz(1., 0., 0.).looking_to(Dir3::X, Dir3::Y)),
+			entity.get::<Transform>(),
+			0.0001
+		);
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(2., 0., 0.))),
+			entity.get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn track_hits_and_remaining_jumps() {
+		let mut app = setup();
+		let hit = spawn_target(&mut app, Vec3::new(1., 0., 0.));
+		spawn_target(&mut app, Vec3::new(3., 0., 0.));
+		let entity = spawn_chain(&mut app, chain(3), PersistentEntity::default());
+		collide(&mut app, entity, &[hit]);
+
+		app.update();
+
+		assert_eq!(
+			Some(&ChainJumps {
+				range: Units::from(5.),
+				remaining: 2,
+				hit: EntityHashSet::from_iter([hit]),
+			}),
+			app.world().entity(entity).get::<ChainJumps>()
+		);
+	}
+
+	#[test]
+	fn despawn_when_no_jumps_remaining() {
+		let mut app = setup();
+		let hit = spawn_target(&mut app, Vec3::new(1., 0., 0.));
+		spawn_target(&mut app, Vec3::new(3., 0., 0.));
+		let entity = spawn_chain(&mut app, chain(0), PersistentEntity::default());
+		collide(&mut app, entity, &[hit]);
+
+		app.update();
+
+		assert!(app.world().get_entity(entity).is_err());
+	}
+
+	#[test]
+	fn despawn_when_no_target_in_range() {
+		let mut app = setup();
+		let hit = spawn_target(&mut app, Vec3::new(1., 0., 0.));
+		spawn_target(&mut app, Vec3::new(7., 0., 0.));
+		let entity = spawn_chain(&mut app, chain(3), PersistentEntity::default());
+		collide(&mut app, entity, &[hit]);
+
+		app.update();
+
+		assert!(app.world().get_entity(entity).is_err());
+	}
+
+	#[test]
+	fn do_not_jump_to_caster() {
+		let mut app = setup();
+		let caster = PersistentEntity::default();
+		app.world_mut().spawn((
+			caster,
+			Life::from(Health::new(10.)),
+			GlobalTransform::from_xyz(2., 0., 0.),
+		));
+		let hit = spawn_target(&mut app, Vec3::new(1., 0., 0.));
+		let entity = spawn_chain(&mut app, chain(3), caster);
+		collide(&mut app, entity, &[hit]);
+
+		app.update();
+
+		assert!(app.world().get_entity(entity).is_err());
+	}
+
+	#[test]
+	fn ignore_hits_on_caster() {
+		let mut app = setup();
+		let caster = PersistentEntity::default();
+		let caster_entity = app
+			.world_mut()
+			.spawn((
+				caster,
+				Life::from(Health::new(10.)),
+				GlobalTransform::from_xyz(1., 0., 0.),
+			))
+			.id();
+		spawn_target(&mut app, Vec3::new(3., 0., 0.));
+		let entity = spawn_chain(&mut app, chain(3), caster);
+		collide(&mut app, entity, &[caster_entity]);
+
+		app.update();
+
+		assert_eq!(
+			Some(&chain(3)),
+			app.world().entity(entity).get::<ChainJumps>()
+		);
+	}
+
+	#[test]
+	fn ignore_hits_on_non_targets() {
+		let mut app = setup();
+		let wall = app.world_mut().spawn(GlobalTransform::default()).id();
+		spawn_target(&mut app, Vec3::new(3., 0., 0.));
+		let entity = spawn_chain(&mut app, chain(3), PersistentEntity::default());
+		collide(&mut app, entity, &[wall]);
+
+		app.update();
+
+		assert_eq!(
+			Some(&chain(3)),
+			app.world().entity(entity).get::<ChainJumps>()
+		);
+	}
+}
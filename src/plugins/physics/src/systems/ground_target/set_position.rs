@@ -55,9 +55,15 @@ impl GroundTarget {
 				.get(&persistent_entity)
 				.and_then(|e| transforms.get(e).map(|t| t.translation).ok())
 				.map(Transform::from_translation),
+			SkillTarget::Caster => commands
+				.get(&self.caster.0)
+				.and_then(|e| transforms.get(e).map(|t| t.translation).ok())
+				.map(Transform::from_translation),
 		}
 	}
 
+	/// Applies to every [`SkillTarget`], so auto targeted or locked on enemies beyond
+	/// `max_cast_range` are not reached either.
 	fn correct_for_max_range(&self, contact: &mut Transform, caster: &Transform) {
 		let direction = contact.translation - caster.translation;
 		let max_range = *self.max_cast_range;
@@ -218,6 +224,28 @@ mod tests {
 		)
 	}
 
+	#[test]
+	fn set_to_caster_transform() {
+		let mut app = setup();
+		let caster = SkillCaster::default();
+		app.world_mut().spawn((
+			Transform::from_xyz(3., 7., -1.),
+			*caster,
+			SkillTargetInternal(Some(SkillTarget::Caster)),
+		));
+		let entity = app
+			.world_mut()
+			.spawn(GroundTarget::with_caster(caster))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(3., 7., -1.)),
+			app.world().entity(entity).get::<Transform>(),
+		)
+	}
+
 	#[test]
 	fn set_to_entity_transform_with_scale_zero() {
 		let mut app = setup();
@@ -246,7 +274,33 @@ mod tests {
 	}
 
 	#[test]
-	fn limit_by_max_range() {
+	fn limit_cursor_target_by_max_range() {
+		let mut app = setup();
+		let caster = SkillCaster::default();
+		app.world_mut().spawn((
+			Transform::default(),
+			*caster,
+			SkillTargetInternal::from(Cursor::TerrainHover),
+		));
+		app.insert_resource(_RayCaster::new().with_mock(|mock| {
+			mock.expect_raycast()
+				.return_const(MouseHoversOver::Point(Vec3::new(6., 0., 8.)));
+		}));
+		let entity = app
+			.world_mut()
+			.spawn(GroundTarget::with_caster(caster).with_max_range(Units::from(5.)))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Transform::from_xyz(3., 0., 4.)),
+			app.world().entity(entity).get::<Transform>(),
+		)
+	}
+
+	#[test]
+	fn limit_entity_target_by_max_range() {
 		let mut app = setup();
 		let caster = SkillCaster::default();
 		let target = PersistentEntity::default();
@@ -0,0 +1,187 @@
+use crate::components::{
+	homing::Homing,
+	offset::{CenterOffset, ComputeOffsetTranslation},
+	target::SkillTargetInternal,
+	velocity::LinearVelocity,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+impl Homing {
+	pub(crate) fn steer(
+		In(delta): In<Duration>,
+		mut commands: ZyheedaCommands,
+		homings: Query<(Entity, &Self, &Transform, &LinearVelocity)>,
+		targets: Query<&SkillTargetInternal>,
+		transforms: Query<(&GlobalTransform, Option<&CenterOffset>)>,
+	) {
+		for (entity, homing, transform, LinearVelocity(velocity)) in &homings {
+			let Some(target) = homing.target(&commands, &targets, &transforms) else {
+				continue;
+			};
+			let Ok(direction) = Dir3::new(*velocity) else {
+				continue;
+			};
+			let Ok(desired) = Dir3::new(target - transform.translation) else {
+				continue;
+			};
+			let (axis, angle) = Quat::from_rotation_arc(*direction, *desired).to_axis_angle();
+			let turn = angle.min(homing.turn_rate * delta.as_secs_f32());
+
+			if turn <= 0. {
+				continue;
+			}
+
+			let velocity = Quat::from_axis_angle(axis, turn) * *velocity;
+			commands.try_apply_on(&entity, |mut e| {
+				e.try_insert(LinearVelocity(velocity));
+			});
+		}
+	}
+
+	fn target(
+		&self,
+		commands: &ZyheedaCommands,
+		targets: &Query<&SkillTargetInternal>,
+		transforms: &Query<(&GlobalTransform, Option<&CenterOffset>)>,
+	) -> Option<Vec3> {
+		let caster = commands.get(&self.caster.0)?;
+		let Ok(SkillTargetInternal(Some(SkillTarget::Entity(target)))) = targets.get(caster) else {
+			return None;
+		};
+		let (transform, offset) = transforms.get(commands.get(target)?).ok()?;
+
+		Some(offset.compute_translation(transform))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use std::f32::consts::FRAC_PI_2;
+	use testing::{SingleThreadedApp, assert_eq_approx};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+
+		app
+	}
+
+	fn spawn_caster(app: &mut App, target: Option<SkillTarget>) -> PersistentEntity {
+		let caster = PersistentEntity::default();
+		app.world_mut().spawn((caster, SkillTargetInternal(target)));
+
+		caster
+	}
+
+	fn spawn_target(app: &mut App, position: Vec3) -> PersistentEntity {
+		let target = PersistentEntity::default();
+		app.world_mut()
+			.spawn((target, GlobalTransform::from_translation(position)));
+
+		target
+	}
+
+	fn spawn_projectile(app: &mut App, caster: PersistentEntity, turn_rate: f32) -> Entity {
+		app.world_mut()
+			.spawn((
+				Homing {
+					caster: SkillCaster(caster),
+					turn_rate,
+				},
+				Transform::default(),
+				LinearVelocity(Vec3::new(0., 0., -2.)),
+			))
+			.id()
+	}
+
+	#[test]
+	fn turn_towards_target_by_turn_rate() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let target = spawn_target(&mut app, Vec3::new(10., 0., 0.));
+		let caster = spawn_caster(&mut app, Some(SkillTarget::Entity(target)));
+		let entity = spawn_projectile(&mut app, caster, FRAC_PI_2);
+
+		app.world_mut()
+			.run_system_once_with(Homing::steer, Duration::from_millis(500))?;
+
+		let direction = Vec3::new(1., 0., -1.).normalize();
+		assert_eq_approx!(
+			Some(&LinearVelocity(direction * 2.)),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_turn_past_target() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let target = spawn_target(&mut app, Vec3::new(10., 0., 0.));
+		let caster = spawn_caster(&mut app, Some(SkillTarget::Entity(target)));
+		let entity = spawn_projectile(&mut app, caster, FRAC_PI_2);
+
+		app.world_mut()
+			.run_system_once_with(Homing::steer, Duration::from_secs(2))?;
+
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(2., 0., 0.))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn turn_towards_target_center() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let target = PersistentEntity::default();
+		app.world_mut().spawn((
+			target,
+			GlobalTransform::from_xyz(0., -2., 0.),
+			CenterOffset(2.),
+		));
+		let caster = spawn_caster(&mut app, Some(SkillTarget::Entity(target)));
+		let entity = app
+			.world_mut()
+			.spawn((
+				Homing {
+					caster: SkillCaster(caster),
+					turn_rate: FRAC_PI_2,
+				},
+				Transform::from_xyz(0., 0., 10.),
+				LinearVelocity(Vec3::new(2., 0., 0.)),
+			))
+			.id();
+
+		app.world_mut()
+			.run_system_once_with(Homing::steer, Duration::from_secs(1))?;
+
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(0., 0., -2.))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+			0.0001
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn fly_straight_when_caster_targets_cursor() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let caster = spawn_caster(&mut app, Some(SkillTarget::Cursor(Cursor::TerrainHover)));
+		let entity = spawn_projectile(&mut app, caster, FRAC_PI_2);
+
+		app.world_mut()
+			.run_system_once_with(Homing::steer, Duration::from_millis(500))?;
+
+		assert_eq!(
+			Some(&LinearVelocity(Vec3::new(0., 0., -2.))),
+			app.world().entity(entity).get::<LinearVelocity>(),
+		);
+		Ok(())
+	}
+}
@@ -1,25 +1,114 @@
 use crate::{
-	components::{blockable::Blockable, blocker_types::BlockerTypes, collision_domains::Physical},
+	components::{
+		blockable::Blockable,
+		blocker_types::BlockerTypes,
+		collider::Colliders,
+		collision_domains::Physical,
+		remaining_impacts::RemainingImpacts,
+		velocity::LinearVelocity,
+	},
 	resources::root_collisions::RootCollisions,
+	systems::apply_push::NoPointProjector,
+	traits::project_point::{GetPointProjector, ProjectPoint},
 };
-use bevy::prelude::*;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use bevy_rapier3d::prelude::*;
 use common::prelude::*;
 
 pub(crate) fn apply_fragile_blocks(
+	commands: ZyheedaCommands,
+	get_projector: StaticSystemParam<ReadRapierContext>,
+	collisions: Res<RootCollisions<Physical>>,
+	fragiles: Query<(Entity, &Blockable)>,
+	impacts: Query<Impacts>,
+	blockers: Query<&BlockerTypes>,
+	colliders: Query<&Colliders>,
+) -> Result<(), NoPointProjector> {
+	system_internal(
+		commands,
+		get_projector,
+		collisions,
+		fragiles,
+		impacts,
+		blockers,
+		colliders,
+	)
+}
+
+type Impacts = (
+	&'static mut RemainingImpacts,
+	&'static Transform,
+	Option<&'static LinearVelocity>,
+);
+
+fn system_internal<TGetProjector, TError>(
 	mut commands: ZyheedaCommands,
-	ongoing_interactions: Res<RootCollisions<Physical>>,
+	get_projector: StaticSystemParam<TGetProjector>,
+	collisions: Res<RootCollisions<Physical>>,
 	fragiles: Query<(Entity, &Blockable)>,
+	mut impacts: Query<Impacts>,
 	blockers: Query<&BlockerTypes>,
-) {
-	for (blocker, blocked) in &ongoing_interactions {
+	colliders: Query<&Colliders>,
+) -> Result<(), NoPointProjector<TError>>
+where
+	TGetProjector: for<'w, 's> SystemParam<Item<'w, 's>: GetPointProjector<TError = TError>>,
+{
+	for (blocker, blocked) in &collisions {
 		for blocked in blocked {
 			let Some(fragile) = is_fragile(blocked, blocker, &fragiles, &blockers) else {
 				continue;
 			};
+			let Ok((mut remaining, transform, velocity)) = impacts.get_mut(fragile) else {
+				commands.try_apply_on(&fragile, |e| e.try_despawn());
+				continue;
+			};
+
+			// Hits only count once while passing through or bouncing off a blocker
+			if !collisions.just_started(blocker).contains(&fragile) {
+				continue;
+			}
+
+			match *remaining {
+				RemainingImpacts::Pierce(0) | RemainingImpacts::Ricochet(0) => {
+					commands.try_apply_on(&fragile, |e| e.try_despawn());
+				}
+				RemainingImpacts::Pierce(count) => {
+					*remaining = RemainingImpacts::Pierce(count - 1);
+				}
+				RemainingImpacts::Ricochet(count) => {
+					*remaining = RemainingImpacts::Ricochet(count - 1);
+
+					let Some(LinearVelocity(velocity)) = velocity else {
+						continue;
+					};
+					let Ok(colliders) = colliders.get(*blocker) else {
+						continue;
+					};
+					let projector = match get_projector.get_point_projector() {
+						Ok(projector) => projector,
+						Err(error) => return Err(NoPointProjector(error)),
+					};
+					let colliders = colliders.iter().collect::<Vec<_>>();
+					let position = transform.translation;
+					let Some(projection) = projector.project_point(position, &colliders) else {
+						continue;
+					};
+					let Some(velocity) = reflect(*velocity, position, projection) else {
+						continue;
+					};
 
-			commands.try_apply_on(&fragile, |e| e.try_despawn());
+					commands.try_apply_on(&fragile, |mut e| {
+						e.try_insert(LinearVelocity(velocity));
+					});
+				}
+			}
 		}
 	}
+
+	Ok(())
 }
 
 fn is_fragile(
@@ -38,21 +127,90 @@ fn is_fragile(
 	blocker.intersection(destroyed_by).next().map(|_| entity)
 }
 
+/// Mirrors the velocity on the blocker surface, unless already moving away from it.
+fn reflect(velocity: Vec3, position: Vec3, projection: PointProjection) -> Option<Vec3> {
+	let outwards = match projection.is_inside {
+		true => projection.point - position,
+		false => position - projection.point,
+	};
+	let normal = Dir3::new(outwards).ok()?;
+
+	if velocity.dot(*normal) >= 0. {
+		return None;
+	}
+
+	Some(velocity.reflect(*normal))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::tests::{NoTestProjector, TestPointProjector, TestProjector};
 	use std::collections::HashSet;
-	use testing::SingleThreadedApp;
+	use testing::{SingleThreadedApp, assert_eq_approx};
+
+	#[derive(Resource, Debug, PartialEq)]
+	struct _Result(Result<(), NoPointProjector<NoTestProjector>>);
+
+	fn store_result(
+		In(result): In<Result<(), NoPointProjector<NoTestProjector>>>,
+		mut commands: Commands,
+	) {
+		commands.insert_resource(_Result(result));
+	}
 
 	fn setup() -> App {
 		let mut app = App::new().single_threaded(Update);
 
 		app.init_resource::<RootCollisions<Physical>>();
-		app.add_systems(Update, apply_fragile_blocks);
+		app.init_resource::<TestPointProjector>();
+		app.add_systems(
+			Update,
+			system_internal::<Res<TestPointProjector>, _>.pipe(store_result),
+		);
 
 		app
 	}
 
+	fn spawn_fragile(app: &mut App, impacts: Option<RemainingImpacts>) -> Entity {
+		let mut fragile = app.world_mut().spawn((
+			Blockable(PhysicalObject::Fragile {
+				destroyed_by: [Blocker::Physical].into(),
+			}),
+			Transform::default(),
+			LinearVelocity(Vec3::new(1., 0., -1.)),
+		));
+
+		if let Some(impacts) = impacts {
+			fragile.insert(impacts);
+		}
+
+		fragile.id()
+	}
+
+	fn spawn_blocker(app: &mut App) -> Entity {
+		app.world_mut()
+			.spawn((
+				BlockerTypes::from([Blocker::Physical]),
+				related!(Colliders[()]),
+			))
+			.id()
+	}
+
+	fn colliders(app: &App, blocker: Entity) -> HashSet<Entity> {
+		app.world()
+			.entity(blocker)
+			.get::<Colliders>()
+			.map(|colliders| colliders.iter().collect())
+			.unwrap_or_default()
+	}
+
+	fn collide(app: &mut App, blocker: Entity, fragile: Entity) {
+		let mut collisions = app.world_mut().resource_mut::<RootCollisions<Physical>>();
+		collisions.rotate();
+		collisions.update(blocker, [fragile]);
+	}
+
 	#[test]
 	fn destroy_on_collision() {
 		let mut app = setup();
@@ -122,4 +280,147 @@ mod tests {
 
 		assert!(app.world().get_entity(fragile).is_ok());
 	}
+
+	#[test]
+	fn pierce() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Pierce(2)));
+		let blocker = spawn_blocker(&mut app);
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		assert_eq!(
+			Some(&RemainingImpacts::Pierce(1)),
+			app.world().entity(fragile).get::<RemainingImpacts>(),
+		);
+	}
+
+	#[test]
+	fn destroy_when_no_pierces_remaining() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Pierce(0)));
+		let blocker = spawn_blocker(&mut app);
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		assert!(app.world().get_entity(fragile).is_err());
+	}
+
+	#[test]
+	fn count_ongoing_collision_once() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Pierce(2)));
+		let blocker = spawn_blocker(&mut app);
+		collide(&mut app, blocker, fragile);
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		assert_eq!(
+			Some(&RemainingImpacts::Pierce(2)),
+			app.world().entity(fragile).get::<RemainingImpacts>(),
+		);
+	}
+
+	#[test]
+	fn ricochet_from_outside() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Ricochet(2)));
+		let blocker = spawn_blocker(&mut app);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders: colliders(&app, blocker),
+			projection: PointProjection {
+				point: Vec3::new(0., 0., -1.),
+				is_inside: false,
+			},
+		})));
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		let fragile = app.world().entity(fragile);
+		assert_eq!(
+			Some(&RemainingImpacts::Ricochet(1)),
+			fragile.get::<RemainingImpacts>(),
+		);
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(1., 0., 1.))),
+			fragile.get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn ricochet_from_inside() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Ricochet(2)));
+		let blocker = spawn_blocker(&mut app);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders: colliders(&app, blocker),
+			projection: PointProjection {
+				point: Vec3::new(0., 0., 1.),
+				is_inside: true,
+			},
+		})));
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		assert_eq_approx!(
+			Some(&LinearVelocity(Vec3::new(1., 0., 1.))),
+			app.world().entity(fragile).get::<LinearVelocity>(),
+			0.0001
+		);
+	}
+
+	#[test]
+	fn do_not_ricochet_when_moving_away() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Ricochet(2)));
+		let blocker = spawn_blocker(&mut app);
+		app.insert_resource(TestPointProjector(Some(TestProjector {
+			colliders: colliders(&app, blocker),
+			projection: PointProjection {
+				point: Vec3::new(0., 0., 1.),
+				is_inside: false,
+			},
+		})));
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		assert_eq!(
+			Some(&LinearVelocity(Vec3::new(1., 0., -1.))),
+			app.world().entity(fragile).get::<LinearVelocity>(),
+		);
+	}
+
+	#[test]
+	fn destroy_when_no_ricochets_remaining() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Ricochet(0)));
+		let blocker = spawn_blocker(&mut app);
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		assert!(app.world().get_entity(fragile).is_err());
+	}
+
+	#[test]
+	fn return_error_when_no_projector_for_ricochet() {
+		let mut app = setup();
+		let fragile = spawn_fragile(&mut app, Some(RemainingImpacts::Ricochet(2)));
+		let blocker = spawn_blocker(&mut app);
+		collide(&mut app, blocker, fragile);
+
+		app.update();
+
+		assert_eq!(
+			&_Result(Err(NoPointProjector(NoTestProjector))),
+			app.world().resource::<_Result>(),
+		);
+	}
 }
@@ -1 +1,4 @@
+pub(crate) mod apply_equipment_health;
 pub(crate) mod despawn_dead;
+pub(crate) mod expire_shields;
+pub(crate) mod take_damage;
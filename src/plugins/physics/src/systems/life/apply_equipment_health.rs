@@ -0,0 +1,133 @@
+use crate::components::{affected::life::Life, equipment_attributes::EquipmentAttributes};
+use bevy::prelude::*;
+
+impl Life {
+	#[allow(clippy::type_complexity)]
+	pub(crate) fn apply_equipment_health(
+		agents: Query<
+			(&mut Self, &EquipmentAttributes),
+			Or<(Changed<EquipmentAttributes>, Added<Self>)>,
+		>,
+	) {
+		for (mut life, EquipmentAttributes(stats)) in agents {
+			if life.equipment_health == stats.max_health {
+				continue;
+			}
+
+			life.set_equipment_health(stats.max_health);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use common::prelude::*;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(Life::apply_equipment_health, IsChanged::<Life>::detect).chain(),
+		);
+
+		app
+	}
+
+	fn equipment(max_health: f32) -> EquipmentAttributes {
+		EquipmentAttributes(EquipmentStats {
+			max_health,
+			..default()
+		})
+	}
+
+	#[test]
+	fn raise_max_health() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((Life::from(Health::new(100.)), equipment(20.)))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Health {
+				current: 100.,
+				max: 120.,
+			}),
+			app.world()
+				.entity(entity)
+				.get::<Life>()
+				.map(|life| &life.health),
+		);
+	}
+
+	#[test]
+	fn update_max_health_when_equipment_changes() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((Life::from(Health::new(100.)), equipment(20.)))
+			.id();
+
+		app.update();
+		app.world_mut().entity_mut(entity).insert(equipment(5.));
+		app.update();
+
+		assert_eq!(
+			Some(&Health {
+				current: 100.,
+				max: 105.,
+			}),
+			app.world()
+				.entity(entity)
+				.get::<Life>()
+				.map(|life| &life.health),
+		);
+	}
+
+	#[test]
+	fn do_not_apply_twice_when_loaded_with_equipment_health() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Life {
+					equipment_health: 20.,
+					..Life::from(Health::new(120.))
+				},
+				equipment(20.),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&Health::new(120.)),
+			app.world()
+				.entity(entity)
+				.get::<Life>()
+				.map(|life| &life.health),
+		);
+	}
+
+	#[test]
+	fn act_only_once() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((Life::from(Health::new(100.)), equipment(20.)))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<Life>>(),
+		);
+	}
+}
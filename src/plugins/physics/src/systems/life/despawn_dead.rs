@@ -5,7 +5,10 @@ use common::prelude::*;
 impl Life {
 	pub(crate) fn despawn_dead(mut commands: ZyheedaCommands, agents: Query<(Entity, &Self)>) {
 		for entity in agents.iter().filter_map(dead) {
-			commands.try_apply_on(&entity, |e| e.try_despawn());
+			commands.try_apply_on(&entity, |mut e| {
+				e.trigger(|entity| Died { entity });
+				e.try_despawn();
+			});
 		}
 	}
 }
@@ -22,9 +25,19 @@ fn dead((entity, life): (Entity, &Life)) -> Option<Entity> {
 mod tests {
 	use super::*;
 
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Died(Vec<(Entity, bool)>);
+
 	fn setup() -> App {
 		let mut app = App::new();
+		app.init_resource::<_Died>();
 		app.add_systems(Update, Life::despawn_dead);
+		app.add_observer(
+			|on_died: On<Died>, mut died: ResMut<_Died>, lives: Query<(), With<Life>>| {
+				died.0
+					.push((on_died.entity, lives.contains(on_died.entity)));
+			},
+		);
 
 		app
 	}
@@ -34,7 +47,7 @@ mod tests {
 		let mut app = setup();
 		let health = app
 			.world_mut()
-			.spawn(Life(Health {
+			.spawn(Life::from(Health {
 				current: 0.,
 				max: 100.,
 			}))
@@ -50,7 +63,7 @@ mod tests {
 		let mut app = setup();
 		let health = app
 			.world_mut()
-			.spawn(Life(Health {
+			.spawn(Life::from(Health {
 				current: 1.,
 				max: 100.,
 			}))
@@ -66,7 +79,7 @@ mod tests {
 		let mut app = setup();
 		let health = app
 			.world_mut()
-			.spawn(Life(Health {
+			.spawn(Life::from(Health {
 				current: -1.,
 				max: 100.,
 			}))
@@ -76,4 +89,36 @@ mod tests {
 
 		assert!(app.world().get_entity(health).is_err());
 	}
+
+	#[test]
+	fn trigger_died_before_despawn() {
+		let mut app = setup();
+		let health = app
+			.world_mut()
+			.spawn(Life::from(Health {
+				current: 0.,
+				max: 100.,
+			}))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			&_Died(vec![(health, true)]),
+			app.world().resource::<_Died>()
+		);
+	}
+
+	#[test]
+	fn do_not_trigger_died_when_health_above_zero() {
+		let mut app = setup();
+		app.world_mut().spawn(Life::from(Health {
+			current: 1.,
+			max: 100.,
+		}));
+
+		app.update();
+
+		assert_eq!(&_Died(vec![]), app.world().resource::<_Died>());
+	}
 }
@@ -0,0 +1,74 @@
+use crate::components::affected::life::Life;
+use bevy::prelude::*;
+use std::time::Duration;
+
+impl Life {
+	pub(crate) fn expire_shields(In(delta): In<Duration>, lives: Query<&mut Self>) {
+		for mut life in lives {
+			if life.shields.is_empty() {
+				continue;
+			}
+
+			life.shields.advance(delta);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use common::prelude::*;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	fn setup(delta: Duration) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(
+				(move || delta).pipe(Life::expire_shields),
+				IsChanged::<Life>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	#[test]
+	fn expire_shields() {
+		let mut app = setup(Duration::from_secs(1));
+		let mut life = Life::from(Health::new(100.));
+		life.add_shield(
+			10.,
+			Duration::from_secs(1),
+			StatusStacking::Stack { max: 2 },
+		);
+		life.add_shield(
+			20.,
+			Duration::from_secs(3),
+			StatusStacking::Stack { max: 2 },
+		);
+		let entity = app.world_mut().spawn(life).id();
+
+		app.update();
+
+		let mut expected = Life::from(Health::new(100.));
+		expected.add_shield(20., Duration::from_secs(2), StatusStacking::Refresh);
+		assert_eq!(Some(&expected), app.world().entity(entity).get::<Life>());
+	}
+
+	#[test]
+	fn do_not_mutate_without_shields() {
+		let mut app = setup(Duration::from_secs(1));
+		let entity = app.world_mut().spawn(Life::from(Health::new(100.))).id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<Life>>(),
+		);
+	}
+}
@@ -0,0 +1,283 @@
+use crate::components::affected::{
+	damage_resistances::DamageResistances,
+	incoming_damage::IncomingDamage,
+	life::Life,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl Life {
+	pub(crate) fn take_damage(
+		mut damage_dealt: MessageWriter<DamageDealt>,
+		mut health_restored: MessageWriter<HealthRestored>,
+		agents: Query<(
+			Entity,
+			&mut Self,
+			&mut IncomingDamage,
+			Option<&DamageResistances>,
+		)>,
+	) {
+		for (entity, mut life, mut incoming, resistances) in agents {
+			if incoming.0.is_empty() {
+				continue;
+			}
+
+			for damage in incoming.0.drain(..) {
+				let amount = match resistances {
+					Some(DamageResistances(resistances)) => {
+						resistances.apply(damage.amount, damage.damage_type)
+					}
+					None => damage.amount,
+				};
+
+				let change = life.change_by(-amount);
+
+				if amount < 0. {
+					health_restored.write(HealthRestored {
+						target: entity,
+						source: damage.source,
+						amount: change,
+					});
+					continue;
+				}
+
+				damage_dealt.write(DamageDealt {
+					target: entity,
+					source: damage.source,
+					damage_type: damage.damage_type,
+					amount: -change,
+					lethal: life.current_hp() <= 0.,
+				});
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::affected::incoming_damage::Damage;
+	use std::time::Duration;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Written(Vec<DamageDealt>);
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Restored(Vec<HealthRestored>);
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_message::<DamageDealt>();
+		app.add_message::<HealthRestored>();
+		app.init_resource::<_Written>();
+		app.init_resource::<_Restored>();
+		app.add_systems(
+			Update,
+			(
+				Life::take_damage,
+				IsChanged::<Life>::detect,
+				|mut messages: MessageReader<DamageDealt>, mut written: ResMut<_Written>| {
+					written.0.extend(messages.read().copied());
+				},
+				|mut messages: MessageReader<HealthRestored>, mut restored: ResMut<_Restored>| {
+					restored.0.extend(messages.read().copied());
+				},
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn damage(amount: f32, damage_type: DamageType) -> Damage {
+		Damage {
+			amount,
+			damage_type,
+			source: None,
+		}
+	}
+
+	#[test]
+	fn apply_damage_reduced_by_resistances() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Life::from(Health::new(100.)),
+				IncomingDamage(vec![
+					damage(10., DamageType::Kinetic),
+					damage(10., DamageType::Void),
+				]),
+				DamageResistances(Resistances {
+					void: 0.75,
+					..default()
+				}),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			(
+				Some(&Life::from(Health {
+					current: 87.5,
+					max: 100.
+				})),
+				Some(&IncomingDamage(vec![])),
+			),
+			(
+				app.world().entity(entity).get::<Life>(),
+				app.world().entity(entity).get::<IncomingDamage>(),
+			)
+		);
+	}
+
+	#[test]
+	fn write_damage_dealt() {
+		let mut app = setup();
+		let source = PersistentEntity::default();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Life::from(Health::new(10.)),
+				IncomingDamage(vec![
+					Damage {
+						amount: 4.,
+						damage_type: DamageType::Plasma,
+						source: Some(source),
+					},
+					damage(8., DamageType::Kinetic),
+				]),
+				DamageResistances(Resistances {
+					plasma: 0.5,
+					..default()
+				}),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			&_Written(vec![
+				DamageDealt {
+					target: entity,
+					source: Some(source),
+					damage_type: DamageType::Plasma,
+					amount: 2.,
+					lethal: false,
+				},
+				DamageDealt {
+					target: entity,
+					source: None,
+					damage_type: DamageType::Kinetic,
+					amount: 8.,
+					lethal: true,
+				},
+			]),
+			app.world().resource::<_Written>()
+		);
+	}
+
+	#[test]
+	fn write_damage_dealt_without_shield_absorbed_part() {
+		let mut app = setup();
+		let mut life = Life::from(Health::new(10.));
+		life.add_shield(5., Duration::from_secs(1), StatusStacking::Refresh);
+		let entity = app
+			.world_mut()
+			.spawn((
+				life,
+				IncomingDamage(vec![
+					damage(3., DamageType::Kinetic),
+					damage(4., DamageType::Kinetic),
+					damage(9., DamageType::Kinetic),
+				]),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			&_Written(vec![
+				DamageDealt {
+					target: entity,
+					source: None,
+					damage_type: DamageType::Kinetic,
+					amount: 0.,
+					lethal: false,
+				},
+				DamageDealt {
+					target: entity,
+					source: None,
+					damage_type: DamageType::Kinetic,
+					amount: 2.,
+					lethal: false,
+				},
+				DamageDealt {
+					target: entity,
+					source: None,
+					damage_type: DamageType::Kinetic,
+					amount: 9.,
+					lethal: true,
+				},
+			]),
+			app.world().resource::<_Written>()
+		);
+	}
+
+	#[test]
+	fn write_health_restored_for_negative_damage() {
+		let mut app = setup();
+		let source = PersistentEntity::default();
+		let entity = app
+			.world_mut()
+			.spawn((
+				Life::from(Health {
+					current: 5.,
+					max: 10.,
+				}),
+				IncomingDamage(vec![Damage {
+					amount: -8.,
+					damage_type: DamageType::Kinetic,
+					source: Some(source),
+				}]),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			(
+				&_Restored(vec![HealthRestored {
+					target: entity,
+					source: Some(source),
+					amount: 5.,
+				}]),
+				&_Written(vec![]),
+			),
+			(
+				app.world().resource::<_Restored>(),
+				app.world().resource::<_Written>(),
+			)
+		);
+	}
+
+	#[test]
+	fn do_not_mutate_life_without_incoming_damage() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((Life::from(Health::new(100.)), IncomingDamage::default()))
+			.id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<Life>>(),
+		);
+	}
+}
@@ -0,0 +1,137 @@
+use crate::{
+	components::{
+		collision_domains::Physical,
+		skill::{Skill, SkillContactRoot},
+	},
+	resources::root_collisions::RootCollisions,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl Skill {
+	pub(crate) fn play_impact_sound(
+		mut sounds: MessageWriter<PlaySound>,
+		collisions: Res<RootCollisions<Physical>>,
+		skills: Query<(Entity, &Self, &GlobalTransform), With<SkillContactRoot>>,
+	) {
+		for (entity, skill, transform) in &skills {
+			let Some(cue) = &skill.impact_sound else {
+				continue;
+			};
+			if collisions.just_started(&entity).is_empty() {
+				continue;
+			}
+
+			sounds.write(PlaySound::sfx(
+				cue.clone(),
+				SoundEmitter::Position(transform.translation()),
+			));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::skill::CreatedFrom;
+	use std::collections::{HashMap, HashSet};
+	use testing::{SingleThreadedApp, fake_entity};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Sounds(Vec<PlaySound>);
+
+	impl _Sounds {
+		fn collect(mut reader: MessageReader<PlaySound>, mut sounds: ResMut<Self>) {
+			sounds.0.extend(reader.read().cloned());
+		}
+	}
+
+	fn skill(impact_sound: Option<SoundCue>) -> Skill {
+		Skill {
+			created_from: CreatedFrom::Spawn,
+			shape: SkillShape::Shield(Shield),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			caster: SkillCaster(PersistentEntity::default()),
+			mount: SkillMount::Center,
+			impact_sound,
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_message::<PlaySound>();
+		app.init_resource::<RootCollisions<Physical>>();
+		app.init_resource::<_Sounds>();
+		app.add_systems(Update, (Skill::play_impact_sound, _Sounds::collect).chain());
+
+		app
+	}
+
+	fn collide(app: &mut App, entity: Entity, targets: &[Entity]) {
+		let mut collisions = app.world_mut().resource_mut::<RootCollisions<Physical>>();
+		collisions.rotate();
+		collisions.update(entity, targets.iter().copied());
+	}
+
+	#[test]
+	fn play_impact_sound_at_skill_position() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				skill(Some(SoundCue::from("sounds/impact.ogg"))),
+				SkillContactRoot,
+				GlobalTransform::from_xyz(1., 2., 3.),
+			))
+			.id();
+		collide(&mut app, entity, &[fake_entity!(42)]);
+
+		app.update();
+
+		assert_eq!(
+			&_Sounds(vec![PlaySound::sfx(
+				SoundCue::from("sounds/impact.ogg"),
+				SoundEmitter::Position(Vec3::new(1., 2., 3.)),
+			)]),
+			app.world().resource::<_Sounds>(),
+		);
+	}
+
+	#[test]
+	fn play_nothing_when_collision_ongoing() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				skill(Some(SoundCue::from("sounds/impact.ogg"))),
+				SkillContactRoot,
+				GlobalTransform::default(),
+			))
+			.id();
+		collide(&mut app, entity, &[fake_entity!(42)]);
+		collide(&mut app, entity, &[fake_entity!(42)]);
+
+		app.update();
+
+		assert_eq!(&_Sounds(vec![]), app.world().resource::<_Sounds>());
+	}
+
+	#[test]
+	fn play_nothing_without_impact_sound() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((skill(None), SkillContactRoot, GlobalTransform::default()))
+			.id();
+		app.insert_resource(RootCollisions::<Physical>::from(HashMap::from([(
+			entity,
+			HashSet::from([fake_entity!(42)]),
+		)])));
+
+		app.update();
+
+		assert_eq!(&_Sounds(vec![]), app.world().resource::<_Sounds>());
+	}
+}
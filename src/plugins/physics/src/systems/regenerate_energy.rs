@@ -0,0 +1,105 @@
+use crate::components::affected::energy_pool::EnergyPool;
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+impl EnergyPool {
+	pub(crate) fn regenerate(In(delta): In<Duration>, pools: Query<&mut Self>) {
+		for mut pool in pools {
+			let Energy {
+				current,
+				max,
+				regeneration,
+			} = pool.0;
+
+			if current >= max {
+				continue;
+			}
+
+			pool.0.current = (current + regeneration * delta.as_secs_f32()).min(max);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	fn setup(delta: Duration) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(
+				(move || delta).pipe(EnergyPool::regenerate),
+				IsChanged::<EnergyPool>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	#[test]
+	fn regenerate() {
+		let mut app = setup(Duration::from_millis(500));
+		let entity = app
+			.world_mut()
+			.spawn(EnergyPool(Energy {
+				current: 1.,
+				max: 10.,
+				regeneration: 4.,
+			}))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnergyPool(Energy {
+				current: 3.,
+				max: 10.,
+				regeneration: 4.,
+			})),
+			app.world().entity(entity).get::<EnergyPool>()
+		);
+	}
+
+	#[test]
+	fn do_not_regenerate_beyond_max() {
+		let mut app = setup(Duration::from_secs(1));
+		let entity = app
+			.world_mut()
+			.spawn(EnergyPool(Energy {
+				current: 9.,
+				max: 10.,
+				regeneration: 4.,
+			}))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&EnergyPool(Energy {
+				current: 10.,
+				max: 10.,
+				regeneration: 4.,
+			})),
+			app.world().entity(entity).get::<EnergyPool>()
+		);
+	}
+
+	#[test]
+	fn do_not_mutate_when_full() {
+		let mut app = setup(Duration::from_secs(1));
+		let entity = app.world_mut().spawn(EnergyPool(Energy::new(10., 4.))).id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<EnergyPool>>()
+		);
+	}
+}
@@ -0,0 +1,2 @@
+pub(crate) mod on_contact;
+pub(crate) mod on_interval;
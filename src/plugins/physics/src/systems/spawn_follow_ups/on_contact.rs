@@ -0,0 +1,156 @@
+use crate::{
+	components::{
+		collision_domains::Physical,
+		follow_ups::{FollowUpPlacement, FollowUps, SpawnFollowUp},
+		skill::Skill,
+	},
+	resources::root_collisions::RootCollisions,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+
+impl FollowUps {
+	pub(crate) fn spawn_on_contact(
+		mut commands: ZyheedaCommands,
+		collisions: Res<RootCollisions<Physical>>,
+		skills: Query<(Entity, &Self, &Skill, &GlobalTransform)>,
+	) {
+		for (entity, follow_ups, skill, transform) in &skills {
+			if collisions.just_started(&entity).is_empty() {
+				continue;
+			}
+
+			let placement = FollowUpPlacement::At(transform.compute_transform());
+			for follow_up in follow_ups.triggered_on(FollowUpTrigger::OnContact) {
+				commands.spawn_follow_up(skill.caster, follow_up, placement);
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::skill::CreatedFrom;
+	use std::time::Duration;
+	use testing::{SingleThreadedApp, assert_count, fake_entity};
+
+	fn parent() -> Skill {
+		Skill {
+			created_from: CreatedFrom::Spawn,
+			shape: SkillShape::Projectile(Projectile {
+				destroyed_by: Blockers::All,
+				motion: ProjectileMotion::Straight,
+				impact: ProjectileImpact::Destroy,
+			}),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			caster: SkillCaster(PersistentEntity::default()),
+			mount: SkillMount::Center,
+			impact_sound: None,
+		}
+	}
+
+	fn follow_up(trigger: FollowUpTrigger) -> FollowUpSkill {
+		FollowUpSkill {
+			trigger,
+			shape: SkillShape::SphereAoE(SphereAoE {
+				max_range: Units::from(10.),
+				radius: Units::from(2.),
+				lifetime: Some(Duration::from_secs(1)),
+			}),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			impact_sound: None,
+			follow_ups: vec![],
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+		app.init_resource::<RootCollisions<Physical>>();
+		app.add_systems(Update, FollowUps::spawn_on_contact);
+
+		app
+	}
+
+	fn collide(app: &mut App, entity: Entity, targets: &[Entity]) {
+		let mut collisions = app.world_mut().resource_mut::<RootCollisions<Physical>>();
+		collisions.rotate();
+		collisions.update(entity, targets.iter().copied());
+	}
+
+	fn follow_up_skills(app: &mut App) -> Vec<(Skill, Transform)> {
+		let mut skills = app.world_mut().query::<(&Skill, &Transform)>();
+		skills
+			.iter(app.world())
+			.filter(|(skill, _)| skill.mount == SkillMount::Parent)
+			.map(|(skill, transform)| (skill.clone(), *transform))
+			.collect()
+	}
+
+	#[test]
+	fn spawn_at_parent_transform_on_contact() {
+		let mut app = setup();
+		let parent = parent();
+		let entity = app
+			.world_mut()
+			.spawn((
+				parent.clone(),
+				FollowUps::from([follow_up(FollowUpTrigger::OnContact)]),
+				GlobalTransform::from_xyz(1., 2., 3.),
+			))
+			.id();
+		collide(&mut app, entity, &[fake_entity!(42)]);
+
+		app.update();
+
+		let [(skill, transform)] = assert_count!(1, follow_up_skills(&mut app).into_iter());
+		assert_eq!(
+			(parent.caster, Transform::from_xyz(1., 2., 3.)),
+			(skill.caster, transform)
+		);
+	}
+
+	#[test]
+	fn spawn_only_contact_follow_ups() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				parent(),
+				FollowUps::from([
+					follow_up(FollowUpTrigger::OnExpiry),
+					follow_up(FollowUpTrigger::Interval(Duration::from_secs(1))),
+				]),
+				GlobalTransform::default(),
+			))
+			.id();
+		collide(&mut app, entity, &[fake_entity!(42)]);
+
+		app.update();
+
+		assert_count!(0, follow_up_skills(&mut app).into_iter());
+	}
+
+	#[test]
+	fn do_not_spawn_when_collision_ongoing() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				parent(),
+				FollowUps::from([follow_up(FollowUpTrigger::OnContact)]),
+				GlobalTransform::default(),
+			))
+			.id();
+		collide(&mut app, entity, &[fake_entity!(42)]);
+		collide(&mut app, entity, &[fake_entity!(42)]);
+
+		app.update();
+
+		assert_count!(0, follow_up_skills(&mut app).into_iter());
+	}
+}
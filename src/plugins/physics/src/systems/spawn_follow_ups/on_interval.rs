@@ -0,0 +1,258 @@
+use crate::components::{
+	follow_ups::{FollowUpPlacement, FollowUps, SpawnFollowUp},
+	skill::Skill,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use std::time::Duration;
+
+impl FollowUps {
+	pub(crate) fn spawn_on_interval(
+		In(delta): In<Duration>,
+		mut commands: ZyheedaCommands,
+		skills: Query<(&mut Self, &Skill, &PersistentEntity, &GlobalTransform)>,
+	) {
+		for (mut follow_ups, skill, persistent_entity, transform) in skills {
+			let before = follow_ups.elapsed;
+			let after = before + delta;
+			follow_ups.elapsed = after;
+
+			let placement = FollowUpPlacement::AttachedTo {
+				parent: *persistent_entity,
+				transform: transform.compute_transform(),
+			};
+			for follow_up in &follow_ups.skills {
+				let FollowUpTrigger::Interval(interval) = follow_up.trigger else {
+					continue;
+				};
+
+				for _ in 0..triggered(before, after, interval) {
+					commands.spawn_follow_up(skill.caster, follow_up, placement);
+				}
+			}
+		}
+	}
+}
+
+fn triggered(before: Duration, after: Duration, interval: Duration) -> u128 {
+	if interval.is_zero() {
+		return 0;
+	}
+
+	after.as_nanos() / interval.as_nanos() - before.as_nanos() / interval.as_nanos()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::skill::CreatedFrom;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::{SingleThreadedApp, assert_count};
+
+	fn parent() -> Skill {
+		Skill {
+			created_from: CreatedFrom::Spawn,
+			shape: SkillShape::SphereAoE(SphereAoE {
+				max_range: Units::from(10.),
+				radius: Units::from(2.),
+				lifetime: Some(Duration::from_secs(10)),
+			}),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			caster: SkillCaster(PersistentEntity::default()),
+			mount: SkillMount::Center,
+			impact_sound: None,
+		}
+	}
+
+	fn follow_up(trigger: FollowUpTrigger) -> FollowUpSkill {
+		FollowUpSkill {
+			trigger,
+			shape: SkillShape::Beam(Beam {
+				range: Units::from(5.),
+				blocked_by: Blockers::All,
+			}),
+			contact_effects: vec![],
+			projection_effects: vec![],
+			impact_sound: None,
+			follow_ups: vec![],
+		}
+	}
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.register_persistent_entities();
+
+		app
+	}
+
+	fn follow_up_skills(app: &mut App) -> Vec<(Skill, Option<ChildOfPersistent>)> {
+		let mut skills = app
+			.world_mut()
+			.query::<(&Skill, Option<&ChildOfPersistent>)>();
+		skills
+			.iter(app.world())
+			.filter(|(skill, _)| skill.mount == SkillMount::Parent)
+			.map(|(skill, child_of)| (skill.clone(), child_of.cloned()))
+			.collect()
+	}
+
+	#[test]
+	fn spawn_attached_to_parent_when_interval_passed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let parent = parent();
+		let persistent_entity = PersistentEntity::default();
+		app.world_mut().spawn((
+			parent.clone(),
+			persistent_entity,
+			FollowUps {
+				skills: vec![follow_up(FollowUpTrigger::Interval(Duration::from_secs(1)))],
+				elapsed: Duration::from_millis(900),
+			},
+		));
+
+		app.world_mut()
+			.run_system_once_with(FollowUps::spawn_on_interval, Duration::from_millis(200))?;
+
+		let [(skill, child_of)] = assert_count!(1, follow_up_skills(&mut app).into_iter());
+		assert_eq!(
+			(parent.caster, Some(ChildOfPersistent(persistent_entity))),
+			(skill.caster, child_of)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn spawn_moving_follow_up_at_parent_position() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		app.world_mut().spawn((
+			parent(),
+			GlobalTransform::from_xyz(1., 2., 3.),
+			FollowUps {
+				skills: vec![FollowUpSkill {
+					shape: SkillShape::Projectile(Projectile {
+						destroyed_by: Blockers::All,
+						motion: ProjectileMotion::Straight,
+						impact: ProjectileImpact::Destroy,
+					}),
+					..follow_up(FollowUpTrigger::Interval(Duration::from_secs(1)))
+				}],
+				elapsed: Duration::from_millis(900),
+			},
+		));
+
+		app.world_mut()
+			.run_system_once_with(FollowUps::spawn_on_interval, Duration::from_millis(200))?;
+
+		let mut skills = app.world_mut().query::<(&Skill, &Transform)>();
+		let [transform] = assert_count!(
+			1,
+			skills
+				.iter(app.world())
+				.filter(|(skill, _)| skill.mount == SkillMount::Parent)
+				.map(|(_, transform)| transform)
+		);
+		assert_eq!(&Transform::from_xyz(1., 2., 3.), transform);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_spawn_before_interval_passed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		app.world_mut().spawn((
+			parent(),
+			FollowUps {
+				skills: vec![follow_up(FollowUpTrigger::Interval(Duration::from_secs(1)))],
+				elapsed: Duration::from_millis(100),
+			},
+		));
+
+		app.world_mut()
+			.run_system_once_with(FollowUps::spawn_on_interval, Duration::from_millis(200))?;
+
+		assert_count!(0, follow_up_skills(&mut app).into_iter());
+		Ok(())
+	}
+
+	#[test]
+	fn spawn_once_per_passed_interval() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		app.world_mut().spawn((
+			parent(),
+			FollowUps {
+				skills: vec![follow_up(FollowUpTrigger::Interval(Duration::from_secs(1)))],
+				elapsed: Duration::from_millis(900),
+			},
+		));
+
+		app.world_mut()
+			.run_system_once_with(FollowUps::spawn_on_interval, Duration::from_millis(2200))?;
+
+		assert_count!(3, follow_up_skills(&mut app).into_iter());
+		Ok(())
+	}
+
+	#[test]
+	fn advance_elapsed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				parent(),
+				FollowUps {
+					skills: vec![],
+					elapsed: Duration::from_millis(900),
+				},
+			))
+			.id();
+
+		app.world_mut()
+			.run_system_once_with(FollowUps::spawn_on_interval, Duration::from_millis(200))?;
+
+		assert_eq!(
+			Some(Duration::from_millis(1100)),
+			app.world()
+				.entity(entity)
+				.get::<FollowUps>()
+				.map(|follow_ups| follow_ups.elapsed)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn ignore_non_interval_follow_ups() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		app.world_mut().spawn((
+			parent(),
+			FollowUps {
+				skills: vec![
+					follow_up(FollowUpTrigger::OnContact),
+					follow_up(FollowUpTrigger::OnExpiry),
+				],
+				elapsed: Duration::ZERO,
+			},
+		));
+
+		app.world_mut()
+			.run_system_once_with(FollowUps::spawn_on_interval, Duration::from_secs(1))?;
+
+		assert_count!(0, follow_up_skills(&mut app).into_iter());
+		Ok(())
+	}
+
+	#[test]
+	fn ignore_zero_interval() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		app.world_mut().spawn((
+			parent(),
+			FollowUps::from([follow_up(FollowUpTrigger::Interval(Duration::ZERO))]),
+		));
+
+		app.world_mut()
+			.run_system_once_with(FollowUps::spawn_on_interval, Duration::from_secs(1))?;
+
+		assert_count!(0, follow_up_skills(&mut app).into_iter());
+		Ok(())
+	}
+}
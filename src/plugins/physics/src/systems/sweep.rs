@@ -0,0 +1,124 @@
+use crate::components::{skill_transform::SkillTransforms, sweep::Sweep};
+use bevy::prelude::*;
+use std::time::Duration;
+
+impl Sweep {
+	pub(crate) fn rotate(
+		In(delta): In<Duration>,
+		sweeps: Query<(&mut Self, &SkillTransforms)>,
+		mut transforms: Query<&mut Transform>,
+	) {
+		for (mut sweep, skill_transforms) in sweeps {
+			sweep.elapsed = (sweep.elapsed + delta).min(sweep.duration);
+			let sweep_transform = sweep.transform();
+
+			for entity in skill_transforms.iter() {
+				let Ok(mut transform) = transforms.get_mut(entity) else {
+					continue;
+				};
+
+				transform.translation = sweep_transform.translation;
+				transform.rotation = sweep_transform.rotation;
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::skill_transform::SkillTransformOf;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::prelude::*;
+	use std::f32::consts::PI;
+	use testing::{SingleThreadedApp, assert_eq_approx};
+
+	fn setup() -> App {
+		App::new().single_threaded(Update)
+	}
+
+	#[test]
+	fn advance_elapsed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn(Sweep {
+				range: Units::from(2.),
+				angle: PI / 2.,
+				duration: Duration::from_secs(1),
+				elapsed: Duration::from_millis(100),
+			})
+			.id();
+		app.world_mut().spawn(SkillTransformOf(entity));
+
+		app.world_mut()
+			.run_system_once_with(Sweep::rotate, Duration::from_millis(200))?;
+
+		assert_eq!(
+			Some(Duration::from_millis(300)),
+			app.world()
+				.entity(entity)
+				.get::<Sweep>()
+				.map(|sweep| sweep.elapsed)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn do_not_advance_elapsed_past_duration() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn(Sweep {
+				range: Units::from(2.),
+				angle: PI / 2.,
+				duration: Duration::from_secs(1),
+				elapsed: Duration::from_millis(900),
+			})
+			.id();
+		app.world_mut().spawn(SkillTransformOf(entity));
+
+		app.world_mut()
+			.run_system_once_with(Sweep::rotate, Duration::from_millis(200))?;
+
+		assert_eq!(
+			Some(Duration::from_secs(1)),
+			app.world()
+				.entity(entity)
+				.get::<Sweep>()
+				.map(|sweep| sweep.elapsed)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn rotate_skill_transforms_and_keep_scale() -> Result<(), RunSystemError> {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn(Sweep {
+				range: Units::from(2.),
+				angle: PI / 2.,
+				duration: Duration::from_secs(1),
+				elapsed: Duration::ZERO,
+			})
+			.id();
+		let child = app
+			.world_mut()
+			.spawn((
+				SkillTransformOf(entity),
+				Transform::from_scale(Vec3::new(1., 2., 3.)),
+			))
+			.id();
+
+		app.world_mut()
+			.run_system_once_with(Sweep::rotate, Duration::from_millis(500))?;
+
+		assert_eq_approx!(
+			Some(&Transform::from_xyz(0., 0., -1.).with_scale(Vec3::new(1., 2., 3.))),
+			app.world().entity(child).get::<Transform>(),
+			0.0001
+		);
+		Ok(())
+	}
+}
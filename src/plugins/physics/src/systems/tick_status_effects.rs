@@ -0,0 +1,150 @@
+use crate::components::affected::{
+	incoming_damage::{Damage, IncomingDamage},
+	status_effects::StatusEffects,
+};
+use bevy::prelude::*;
+use std::time::Duration;
+
+impl StatusEffects {
+	pub(crate) fn tick(
+		In(delta): In<Duration>,
+		agents: Query<(&mut Self, Option<&mut IncomingDamage>)>,
+	) {
+		for (mut effects, incoming) in agents {
+			if effects.is_empty() {
+				continue;
+			}
+
+			if let Some(mut incoming) = incoming {
+				for dot in effects.damage_over_time.iter() {
+					incoming.push(Damage {
+						amount: dot.value.amount * dot.remaining.min(delta).as_secs_f32(),
+						damage_type: dot.value.damage_type,
+						source: None,
+					});
+				}
+			}
+
+			effects.damage_over_time.advance(delta);
+			effects.slows.advance(delta);
+			effects.stuns.advance(delta);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::components::{
+		affected::status_effects::DamagePerSecond,
+		timed_statuses::{TimedStatus, TimedStatuses},
+	};
+	use common::prelude::*;
+	use testing::{IsChanged, SingleThreadedApp};
+
+	fn setup(delta: Duration) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_systems(
+			Update,
+			(
+				(move || delta).pipe(StatusEffects::tick),
+				IsChanged::<StatusEffects>::detect,
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	fn dps(amount: f32, damage_type: DamageType) -> DamagePerSecond {
+		DamagePerSecond {
+			amount,
+			damage_type,
+		}
+	}
+
+	fn timed<T>(value: T, millis: u64) -> TimedStatus<T> {
+		TimedStatus {
+			value,
+			remaining: Duration::from_millis(millis),
+		}
+	}
+
+	#[test]
+	fn deal_damage_over_time() {
+		let mut app = setup(Duration::from_millis(500));
+		let entity = app
+			.world_mut()
+			.spawn((
+				StatusEffects {
+					damage_over_time: TimedStatuses::from([
+						timed(dps(4., DamageType::Plasma), 1000),
+						timed(dps(10., DamageType::Void), 100),
+					]),
+					..default()
+				},
+				IncomingDamage::default(),
+			))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&IncomingDamage(vec![
+				Damage {
+					amount: 4. * 0.5,
+					damage_type: DamageType::Plasma,
+					source: None,
+				},
+				Damage {
+					amount: 10. * 0.1,
+					damage_type: DamageType::Void,
+					source: None,
+				},
+			])),
+			app.world().entity(entity).get::<IncomingDamage>()
+		);
+	}
+
+	#[test]
+	fn expire_statuses() {
+		let mut app = setup(Duration::from_millis(500));
+		let entity = app
+			.world_mut()
+			.spawn(StatusEffects {
+				damage_over_time: TimedStatuses::from([
+					timed(dps(4., DamageType::Plasma), 1000),
+					timed(dps(10., DamageType::Void), 100),
+				]),
+				slows: TimedStatuses::from([timed(0.5, 600), timed(0.5, 500)]),
+				stuns: TimedStatuses::from([timed((), 200)]),
+			})
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			Some(&StatusEffects {
+				damage_over_time: TimedStatuses::from([timed(dps(4., DamageType::Plasma), 500)]),
+				slows: TimedStatuses::from([timed(0.5, 100)]),
+				stuns: TimedStatuses::default(),
+			}),
+			app.world().entity(entity).get::<StatusEffects>(),
+		);
+	}
+
+	#[test]
+	fn do_not_mutate_when_empty() {
+		let mut app = setup(Duration::from_millis(500));
+		let entity = app.world_mut().spawn(StatusEffects::default()).id();
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			Some(&IsChanged::FALSE),
+			app.world().entity(entity).get::<IsChanged<StatusEffects>>(),
+		);
+	}
+}
@@ -80,7 +80,7 @@ impl SkillTargetInternal {
 					}
 				}
 			}
-			SkillTarget::Cursor(Cursor::Direction) => None,
+			SkillTarget::Cursor(Cursor::Direction) | SkillTarget::Caster => None,
 		}
 	}
 }
@@ -565,6 +565,34 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn do_not_set_pitch_for_caster_target() {
+		let mut app = setup();
+		let entity = app
+			.world_mut()
+			.spawn((
+				SkillTargetInternal(Some(SkillTarget::Caster)),
+				GlobalTransform::default(),
+				_Animations {
+					forward_pitch: Some(DirForwardPitch::Up(ForwardPitch::MAX)),
+				},
+			))
+			.id();
+		app.insert_resource(_RayCast::new().with_mock(|mock| {
+			mock.expect_raycast().never();
+		}));
+
+		app.update();
+
+		assert_eq_approx!(
+			Some(&_Animations {
+				forward_pitch: None
+			}),
+			app.world().entity(entity).get::<_Animations>(),
+			1e-5,
+		);
+	}
+
 	#[test]
 	fn raycast_excludes_self() {
 		let mut app = setup();
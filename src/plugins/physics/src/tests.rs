@@ -1,5 +1,7 @@
+use crate::traits::project_point::{GetPointProjector, ProjectPoint};
 use bevy::{mesh::MeshPlugin, prelude::*, scene::ScenePlugin};
 use bevy_rapier3d::prelude::*;
+use std::collections::HashSet;
 
 pub(crate) struct TestCollisionsPlugin;
 
@@ -15,3 +17,38 @@ impl Plugin for TestCollisionsPlugin {
 		));
 	}
 }
+
+/// Point projector double, erroring with [`NoTestProjector`] while unset.
+#[derive(Resource, Default)]
+pub(crate) struct TestPointProjector(pub(crate) Option<TestProjector>);
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct NoTestProjector;
+
+/// Projects onto `projection`, but only for exactly the given `colliders`.
+pub(crate) struct TestProjector {
+	pub(crate) colliders: HashSet<Entity>,
+	pub(crate) projection: PointProjection,
+}
+
+impl GetPointProjector for Res<'_, TestPointProjector> {
+	type TError = NoTestProjector;
+	type TPointProjector<'a>
+		= &'a TestProjector
+	where
+		Self: 'a;
+
+	fn get_point_projector(&self) -> Result<Self::TPointProjector<'_>, Self::TError> {
+		self.0.as_ref().ok_or(NoTestProjector)
+	}
+}
+
+impl ProjectPoint for &'_ TestProjector {
+	fn project_point(&self, _: Vec3, colliders: &[Entity]) -> Option<PointProjection> {
+		if self.colliders != colliders.iter().copied().collect() {
+			return None;
+		}
+
+		Some(self.projection)
+	}
+}
@@ -1,5 +1,6 @@
 pub(crate) mod act_on;
 pub(crate) mod get_mount_point;
+pub(crate) mod project_point;
 pub(crate) mod rapier_context;
 pub(crate) mod ray_cast;
 pub(crate) mod send_collision_interaction;
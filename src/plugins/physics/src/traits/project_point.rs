@@ -0,0 +1,42 @@
+use bevy::{ecs::error::BevyError, prelude::*};
+use bevy_rapier3d::{
+	pipeline::QueryFilter,
+	plugin::{RapierContext, ReadRapierContext},
+	prelude::PointProjection,
+};
+
+pub trait GetPointProjector {
+	type TError;
+	type TPointProjector<'a>: ProjectPoint
+	where
+		Self: 'a;
+
+	fn get_point_projector(&self) -> Result<Self::TPointProjector<'_>, Self::TError>;
+}
+
+pub trait ProjectPoint {
+	/// Project the point onto the closest surface of the given colliders.
+	fn project_point(&self, point: Vec3, colliders: &[Entity]) -> Option<PointProjection>;
+}
+
+impl GetPointProjector for ReadRapierContext<'_, '_> {
+	type TError = BevyError;
+	type TPointProjector<'a>
+		= RapierContext<'a>
+	where
+		Self: 'a;
+
+	fn get_point_projector(&self) -> Result<Self::TPointProjector<'_>, Self::TError> {
+		self.single()
+	}
+}
+
+impl ProjectPoint for RapierContext<'_> {
+	fn project_point(&self, point: Vec3, colliders: &[Entity]) -> Option<PointProjection> {
+		let predicate = |entity| colliders.contains(&entity);
+		let filter = QueryFilter::new().predicate(&predicate);
+		let (_, projection) = RapierContext::project_point(self, point, f32::MAX, false, filter)?;
+
+		Some(projection)
+	}
+}
@@ -3,19 +3,26 @@ pub(crate) mod handler;
 use crate::{
 	context::handler::ComponentHandler,
 	errors::{EntitySerializationErrors, SerdeJsonError},
-	file_io::FileIO,
+	save_slots::SaveSlots,
 	traits::{buffer_entity_component::BufferEntityComponent, write_buffer::WriteBuffer},
 };
 use bevy::prelude::*;
+use common::prelude::*;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
 
 pub(crate) type SaveBuffer = HashMap<Entity, HashMap<String, Value>>;
 pub(crate) type EntityLoadBuffer<TComponent> = HashMap<String, TComponent>;
 pub(crate) type LoadBuffer<TComponent> = Vec<EntityLoadBuffer<TComponent>>;
+pub(crate) type Stashed = HashMap<StashKey, LoadBuffer<Value>>;
 
 #[derive(Debug, PartialEq, Default)]
-pub struct SaveContext<TFileIO = FileIO, TComponentHandler = ComponentHandler, TComponent = Value> {
+pub struct SaveContext<
+	TFileIO = SaveSlots,
+	TComponentHandler = ComponentHandler,
+	TComponent = Value,
+> {
 	pub(crate) handlers: Handlers<TComponentHandler>,
 	pub(crate) buffers: Buffers<TComponent>,
 	pub(crate) io: TFileIO,
@@ -31,6 +38,27 @@ pub(crate) struct Handlers<TComponentHandler> {
 pub(crate) struct Buffers<TComponent> {
 	pub(crate) save: SaveBuffer,
 	pub(crate) load: LoadBuffer<TComponent>,
+	pub(crate) metadata: SlotMetadata,
+	pub(crate) stash: Stashed,
+}
+
+/// Version of the [`SaveFile`] layout.
+///
+/// Version `0` are files without header, which only contain the serialized entities.
+pub(crate) const SAVE_FILE_VERSION: u32 = 1;
+
+/// The layout of a save file
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct SaveFile<TEntities> {
+	pub(crate) version: u32,
+	pub(crate) header: SlotMetadata,
+	/// [`SavableComponent`] versions by component id, missing components are at version `0`
+	#[serde(default)]
+	pub(crate) component_versions: HashMap<String, usize>,
+	pub(crate) entities: TEntities,
+	/// Entities that were stashed via [`StashEntities`] when saving
+	#[serde(default)]
+	pub(crate) stash: HashMap<StashKey, TEntities>,
 }
 
 #[cfg(test)]
@@ -51,6 +79,19 @@ impl<TFileIO, TComponentHandler> SaveContext<TFileIO, TComponentHandler> {
 		self
 	}
 
+	pub(crate) fn with_metadata(mut self, metadata: SlotMetadata) -> Self {
+		self.buffers.metadata = metadata;
+		self
+	}
+
+	pub(crate) fn with_stash<T>(mut self, stash: T) -> Self
+	where
+		T: Into<Stashed>,
+	{
+		self.buffers.stash = stash.into();
+		self
+	}
+
 	pub(crate) fn with_low_priority_handlers<T>(mut self, handlers: T) -> Self
 	where
 		T: Into<Vec<TComponentHandler>>,
@@ -102,7 +143,7 @@ where
 }
 
 impl<TComponentHandler> Handlers<TComponentHandler> {
-	fn all(&self) -> impl Iterator<Item = &TComponentHandler> {
+	pub(crate) fn all(&self) -> impl Iterator<Item = &TComponentHandler> {
 		self.high_priority.iter().chain(self.low_priority.iter())
 	}
 }
@@ -120,6 +161,7 @@ impl<TComponentHandler> Default for Handlers<TComponentHandler> {
 mod test_write_buffer {
 	#![allow(clippy::unwrap_used)]
 	use super::*;
+	use common::tools::file_io::FileIO;
 	use macros::simple_mock;
 	use serde_json::from_str;
 	use std::path::PathBuf;
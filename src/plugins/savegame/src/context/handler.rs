@@ -4,6 +4,7 @@ use crate::{
 	traits::{
 		buffer_entity_component::BufferEntityComponent,
 		insert_entity_component::InsertEntityComponent,
+		migrate_component::MigrateComponent,
 	},
 };
 use bevy::prelude::*;
@@ -15,6 +16,7 @@ use std::collections::{HashMap, hash_map::Entry};
 pub(crate) struct ComponentHandler<TLoadAsset = AssetServer> {
 	buffer_fn: fn(&mut SaveBuffer, EntityRef) -> Result<(), Error>,
 	insert_fn: fn(&mut EntityCommands, Value, &mut TLoadAsset) -> Result<(), SerdeJsonError>,
+	migrations: &'static [Migration],
 	component_id: UniqueComponentId,
 }
 
@@ -29,6 +31,7 @@ where
 		Self {
 			buffer_fn: Self::buffer::<T>,
 			insert_fn: Self::insert::<T>,
+			migrations: T::MIGRATIONS,
 			component_id: T::ID,
 		}
 	}
@@ -108,12 +111,28 @@ where
 	}
 }
 
+impl<TLoadAsset> MigrateComponent for ComponentHandler<TLoadAsset> {
+	fn component_id(&self) -> &UniqueComponentId {
+		&self.component_id
+	}
+
+	fn version(&self) -> usize {
+		self.migrations.len()
+	}
+
+	fn migrate(&self, component: &mut Value, version: usize) {
+		for migrate in self.migrations.iter().skip(version) {
+			migrate(component);
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	#![allow(clippy::unwrap_used)]
 	use super::*;
-	use crate::traits::write_file::WriteFile;
 	use bevy::asset::AssetPath;
+	use common::traits::write_file::WriteFile;
 	use macros::SavableComponent;
 	use serde::{Deserialize, Serialize};
 	use serde_json::{from_str, to_string};
@@ -401,4 +420,78 @@ mod tests {
 			assert_eq!(&_A::ID, handler.id());
 		}
 	}
+	mod migrate {
+		use super::*;
+		use serde_json::json;
+
+		#[derive(Component, SavableComponent, Serialize, Deserialize, Clone, PartialEq, Debug)]
+		#[savable_component(id = "d", migrations = [rename_value, double_count])]
+		struct _D {
+			count: i32,
+		}
+
+		fn rename_value(component: &mut Value) {
+			let Some(component) = component.as_object_mut() else {
+				return;
+			};
+			let Some(value) = component.remove("value") else {
+				return;
+			};
+			component.insert("count".to_owned(), value);
+		}
+
+		fn double_count(component: &mut Value) {
+			let Some(count) = component.get_mut("count") else {
+				return;
+			};
+			let Some(value) = count.as_i64() else {
+				return;
+			};
+			*count = json!(value * 2);
+		}
+
+		#[test]
+		fn version() {
+			let handler = ComponentHandler::<_LoadAsset>::new::<_D>();
+
+			assert_eq!(2, handler.version());
+		}
+
+		#[test]
+		fn version_without_migrations() {
+			let handler = ComponentHandler::<_LoadAsset>::new::<_B>();
+
+			assert_eq!(0, handler.version());
+		}
+
+		#[test]
+		fn migrate_from_version_0() {
+			let handler = ComponentHandler::<_LoadAsset>::new::<_D>();
+			let mut component = json!({"value": 21});
+
+			handler.migrate(&mut component, 0);
+
+			assert_eq!(json!({"count": 42}), component);
+		}
+
+		#[test]
+		fn migrate_from_version_1() {
+			let handler = ComponentHandler::<_LoadAsset>::new::<_D>();
+			let mut component = json!({"count": 21});
+
+			handler.migrate(&mut component, 1);
+
+			assert_eq!(json!({"count": 42}), component);
+		}
+
+		#[test]
+		fn do_not_migrate_current_version() {
+			let handler = ComponentHandler::<_LoadAsset>::new::<_D>();
+			let mut component = json!({"count": 21});
+
+			handler.migrate(&mut component, 2);
+
+			assert_eq!(json!({"count": 21}), component);
+		}
+	}
 }
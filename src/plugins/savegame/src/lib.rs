@@ -1,14 +1,31 @@
 mod context;
 mod errors;
-mod file_io;
 mod resources;
+mod save_slots;
+mod system_params;
 mod systems;
 mod traits;
 
 use crate::{
-	resources::{inspector::Inspector, unique_ids::UniqueIds},
+	resources::{
+		autosave::Autosave,
+		inspector::Inspector,
+		pending_slots::PendingSlots,
+		play_time::PlayTime,
+		slot_metadata_buffer::SlotMetadataBuffer,
+		stash::Stash,
+		unique_ids::UniqueIds,
+	},
+	save_slots::SaveSlots,
+	system_params::{
+		save_slots_mut::SaveSlotsMut,
+		save_slots_param::SaveSlotsParam,
+		slot_metadata_mut::SlotMetadataMut,
+		stash_mut::StashMut,
+	},
 	systems::{
 		despawn_persistent_entities::DespawnAll,
+		slot_metadata::unix_timestamp,
 		trigger_state::TriggerState,
 		write_buffer::WriteBufferSystem,
 	},
@@ -22,18 +39,22 @@ use common::{
 		transition_to_previous,
 		transition_to_state,
 	},
+	tools::file_io::FileIO,
 };
 use context::SaveContext;
-use file_io::FileIO;
 use resources::register::Register;
 use std::{
 	any::{TypeId, type_name},
 	marker::PhantomData,
 	path::PathBuf,
 	sync::{Arc, Mutex},
+	time::Duration,
 };
 
 const ON_ENTER_SAVE: OnEnter<GameState> = OnEnter(GameState::Save(SaveState::Save));
+const MANUAL_SLOTS: u8 = 5;
+const AUTO_SLOTS: u8 = 3;
+const AUTOSAVE_INTERVAL: Duration = Duration::from_secs(300);
 
 pub struct SavegamePlugin<TDependencies> {
 	game_directory: PathBuf,
@@ -65,25 +86,31 @@ where
 	TInput: ThreadSafe + SystemSetDefinition + HandlesInput,
 {
 	fn build(&self, app: &mut App) {
-		let quick_save_file = self
-			.game_directory
-			.clone()
-			.join("Saves")
-			.join("Quick Save")
-			.with_extension("json");
-		let quick_save = Arc::new(Mutex::new(SaveContext::from(FileIO::with_file(
-			quick_save_file,
+		let slots = [SaveSlot::Quick]
+			.into_iter()
+			.chain((1..=MANUAL_SLOTS).map(SaveSlot::Manual))
+			.chain((1..=AUTO_SLOTS).map(SaveSlot::Auto));
+		let saves_directory = self.game_directory.join("Saves");
+		let context = Arc::new(Mutex::new(SaveContext::from(SaveSlots::in_directory(
+			&saves_directory,
+			slots,
 		))));
-		let trigger_quick_save = TInput::TInput::trigger(
+		let trigger_quick_save = TInput::TInput::trigger::<_, SaveSlotsMut>(
 			ActionKey::Save(SaveKey::QuickSave),
-			GameState::Save(SaveState::Save),
+			SlotRequest::Save(SaveSlot::Quick),
 		);
-		let trigger_quick_load_attempt = TInput::TInput::trigger(
+		let trigger_quick_load_attempt = TInput::TInput::trigger::<_, SaveSlotsMut>(
 			ActionKey::Save(SaveKey::QuickLoad),
-			GameState::Save(SaveState::AttemptLoad),
+			SlotRequest::Load(SaveSlot::Quick),
 		);
 		let transition_to_load = transition_to_state(GameState::Save(SaveState::Load));
 		let transition_to_previous = transition_to_previous::<GameState>;
+		let can_load = || {
+			IntoSystem::into_system(
+				Inspector::<FileIO>::pending_load_slot_exists
+					.pipe(OnError::log_and_return(|| false)),
+			)
+		};
 
 		Self::register_savable_component::<Name>(app);
 		Self::register_savable_component::<Transform>(app);
@@ -97,18 +124,35 @@ where
 		);
 
 		app.init_resource::<Register>()
+			.init_resource::<PendingSlots>()
+			.init_resource::<PlayTime>()
+			.init_resource::<SlotMetadataBuffer>()
+			.init_resource::<Stash>()
+			.insert_resource(Autosave::every(AUTOSAVE_INTERVAL))
 			.insert_resource(Inspector {
-				quick_save: quick_save.clone(),
+				context: context.clone(),
 			})
 			.add_systems(
 				Startup,
-				Register::update_context(quick_save.clone()).pipe(OnError::log),
+				Register::update_context(context.clone()).pipe(OnError::log),
+			)
+			.add_systems(OnEnter(GameState::NewGame), (PlayTime::reset, Stash::clear))
+			.add_systems(
+				PostUpdate,
+				(
+					SaveContext::process_stash_system(context.clone()).pipe(OnError::log),
+					SaveContext::read_buffer_system(context.clone()).pipe(OnError::log),
+				)
+					.chain()
+					.run_if(Stash::has_pending),
 			)
 			.add_systems(
 				Update,
 				(
 					trigger_quick_save,
 					trigger_quick_load_attempt.run_if(Self::can_quick_load()),
+					PlayTime::track::<Virtual>,
+					Autosave::trigger::<Virtual, FileIO>(context.clone()).pipe(OnError::log),
 				)
 					.run_if(in_state(GameState::Play))
 					.after_plugin(TInput::SYSTEMS),
@@ -116,8 +160,12 @@ where
 			.add_systems(
 				ON_ENTER_SAVE,
 				(
-					SaveContext::write_buffer_system(quick_save.clone()).pipe(OnError::log),
-					SaveContext::write_file_system(quick_save.clone()).pipe(OnError::log),
+					SaveContext::activate_save_slot_system(context.clone()).pipe(OnError::log),
+					SaveContext::write_buffer_system(context.clone()).pipe(OnError::log),
+					SaveContext::write_metadata_system(context.clone(), unix_timestamp)
+						.pipe(OnError::log),
+					SaveContext::write_stash_system(context.clone()).pipe(OnError::log),
+					SaveContext::write_file_system(context.clone()).pipe(OnError::log),
 				)
 					.in_set(SaveSystems::ExecuteSave)
 					.chain(),
@@ -125,16 +173,19 @@ where
 			.add_systems(
 				OnEnter(GameState::Save(SaveState::AttemptLoad)),
 				(
-					transition_to_load.run_if(Self::can_quick_load()),
-					transition_to_previous.run_if(not(Self::can_quick_load())),
+					transition_to_load.run_if(can_load()),
+					(transition_to_previous, PendingSlots::clear_load).run_if(not(can_load())),
 				),
 			)
 			.add_systems(
 				OnEnter(GameState::Save(SaveState::Load)),
 				(
+					SaveContext::activate_load_slot_system(context.clone()).pipe(OnError::log),
 					PersistentEntity::despawn_all,
-					SaveContext::read_file_system(quick_save.clone()).pipe(OnError::log),
-					SaveContext::read_buffer_system(quick_save).pipe(OnError::log),
+					SaveContext::read_file_system(context.clone()).pipe(OnError::log),
+					SaveContext::read_metadata_system(context.clone()).pipe(OnError::log),
+					SaveContext::read_stash_system(context.clone()).pipe(OnError::log),
+					SaveContext::read_buffer_system(context).pipe(OnError::log),
 				)
 					.chain(),
 			);
@@ -142,9 +193,21 @@ where
 }
 
 impl<TDependencies> HandlesSaving for SavegamePlugin<TDependencies> {
+	type TSaveSlotsMut = SaveSlotsMut<'static>;
+	type TSaveSlots = SaveSlotsParam<'static>;
+	type TSlotMetadataMut = SlotMetadataMut<'static>;
+	type TStashMut = StashMut<'static>;
+
 	fn can_quick_load() -> impl SystemCondition<()> {
 		IntoSystem::into_system(
-			Inspector::<FileIO>::quick_save_file_exists.pipe(OnError::log_and_return(|| false)),
+			Inspector::<FileIO>::slot_exists(SaveSlot::Quick)
+				.pipe(OnError::log_and_return(|| false)),
+		)
+	}
+
+	fn can_continue() -> impl SystemCondition<()> {
+		IntoSystem::into_system(
+			Inspector::<FileIO>::any_slot_exists.pipe(OnError::log_and_return(|| false)),
 		)
 	}
 
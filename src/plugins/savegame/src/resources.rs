@@ -1,3 +1,8 @@
+pub(crate) mod autosave;
 pub(crate) mod inspector;
+pub(crate) mod pending_slots;
+pub(crate) mod play_time;
 pub(crate) mod register;
+pub(crate) mod slot_metadata_buffer;
+pub(crate) mod stash;
 pub(crate) mod unique_ids;
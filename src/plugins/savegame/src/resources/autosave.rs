@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Debug, PartialEq)]
+pub(crate) struct Autosave {
+	pub(crate) timer: Timer,
+}
+
+impl Autosave {
+	pub(crate) fn every(interval: Duration) -> Self {
+		Self {
+			timer: Timer::new(interval, TimerMode::Repeating),
+		}
+	}
+}
@@ -1,8 +1,9 @@
-use crate::{context::SaveContext, file_io::FileIO};
+use crate::{context::SaveContext, save_slots::SaveSlots};
 use bevy::prelude::*;
+use common::tools::file_io::FileIO;
 use std::sync::{Arc, Mutex};
 
 #[derive(Resource, Debug)]
-pub(crate) struct Inspector<TIO = FileIO> {
-	pub(crate) quick_save: Arc<Mutex<SaveContext<TIO>>>,
+pub(crate) struct Inspector<TFileIO = FileIO> {
+	pub(crate) context: Arc<Mutex<SaveContext<SaveSlots<TFileIO>>>>,
 }
@@ -0,0 +1,11 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Slots requested for the next save or load.
+///
+/// Saving falls back to [`SaveSlot::Quick`], loading to the newest existing slot.
+#[derive(Resource, Debug, PartialEq, Default)]
+pub(crate) struct PendingSlots {
+	pub(crate) save: Option<SaveSlot>,
+	pub(crate) load: Option<SaveSlot>,
+}
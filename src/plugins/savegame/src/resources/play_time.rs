@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Debug, PartialEq, Default, Clone, Copy)]
+pub(crate) struct PlayTime(pub(crate) Duration);
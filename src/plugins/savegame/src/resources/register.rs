@@ -1,7 +1,6 @@
 use crate::{
 	context::{Handlers, SaveContext, handler::ComponentHandler},
 	errors::LockPoisonedError,
-	file_io::FileIO,
 };
 use bevy::prelude::*;
 use common::prelude::*;
@@ -21,8 +20,8 @@ impl<TLoadAsset> Register<TLoadAsset>
 where
 	TLoadAsset: ThreadSafe + Clone + LoadAsset,
 {
-	pub(crate) fn update_context(
-		context: Arc<Mutex<SaveContext<FileIO, ComponentHandler<TLoadAsset>>>>,
+	pub(crate) fn update_context<TFileIO>(
+		context: Arc<Mutex<SaveContext<TFileIO, ComponentHandler<TLoadAsset>>>>,
 	) -> impl Fn(Res<Self>) -> Result<(), LockPoisonedError> {
 		move |register| {
 			let Ok(mut context) = context.lock() else {
@@ -168,8 +167,8 @@ mod test_registration {
 mod test_update_context {
 	#![allow(clippy::expect_used)]
 	use super::*;
-	use crate::file_io::FileIO;
 	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::tools::file_io::FileIO;
 	use macros::SavableComponent;
 	use serde::{Deserialize, Serialize};
 	use std::{ops::Deref, path::PathBuf};
@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Collects [`SlotMetadata`] contributed by other plugins before saving.
+#[derive(Resource, Debug, PartialEq, Default)]
+pub(crate) struct SlotMetadataBuffer(pub(crate) SlotMetadata);
@@ -0,0 +1,95 @@
+use crate::context::Stashed;
+use bevy::prelude::*;
+use common::prelude::*;
+
+/// Serialized entities that were moved out of the world.
+///
+/// Requests are processed in order by [`SaveContext::process_stash_system`](crate::context::SaveContext::process_stash_system).
+#[derive(Resource, Debug, PartialEq, Default)]
+pub(crate) struct Stash {
+	pub(crate) entities: Stashed,
+	pub(crate) pending: Vec<StashRequest>,
+}
+
+impl Stash {
+	pub(crate) fn contains(&self, key: &StashKey) -> bool {
+		self.pending.iter().fold(
+			self.entities.contains_key(key),
+			|stashed, request| match request {
+				StashRequest::Stash(k, _) if k == key => true,
+				StashRequest::Unstash(k) if k == key => false,
+				_ => stashed,
+			},
+		)
+	}
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum StashRequest {
+	Stash(StashKey, Vec<Entity>),
+	Unstash(StashKey),
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::collections::HashMap;
+	use testing::fake_entity;
+
+	#[test]
+	fn contains_stashed_key() {
+		let stash = Stash {
+			entities: HashMap::from([(StashKey::from("a"), vec![])]),
+			pending: vec![],
+		};
+
+		assert!(stash.contains(&StashKey::from("a")));
+	}
+
+	#[test]
+	fn does_not_contain_other_key() {
+		let stash = Stash {
+			entities: HashMap::from([(StashKey::from("a"), vec![])]),
+			pending: vec![],
+		};
+
+		assert!(!stash.contains(&StashKey::from("b")));
+	}
+
+	#[test]
+	fn contains_pending_stash() {
+		let stash = Stash {
+			entities: HashMap::from([]),
+			pending: vec![StashRequest::Stash(
+				StashKey::from("a"),
+				vec![fake_entity!(1)],
+			)],
+		};
+
+		assert!(stash.contains(&StashKey::from("a")));
+	}
+
+	#[test]
+	fn does_not_contain_pending_unstash() {
+		let stash = Stash {
+			entities: HashMap::from([(StashKey::from("a"), vec![])]),
+			pending: vec![StashRequest::Unstash(StashKey::from("a"))],
+		};
+
+		assert!(!stash.contains(&StashKey::from("a")));
+	}
+
+	#[test]
+	fn use_last_pending_request() {
+		let stash = Stash {
+			entities: HashMap::from([]),
+			pending: vec![
+				StashRequest::Stash(StashKey::from("a"), vec![fake_entity!(1)]),
+				StashRequest::Unstash(StashKey::from("a")),
+				StashRequest::Stash(StashKey::from("a"), vec![fake_entity!(2)]),
+			],
+		};
+
+		assert!(stash.contains(&StashKey::from("a")));
+	}
+}
@@ -0,0 +1,502 @@
+use crate::context::SaveFile;
+use common::{
+	prelude::*,
+	tools::file_io::FileIO,
+	traits::{file_exists::FileExists, read_file::ReadFile, write_file::WriteFile},
+};
+use serde::de::IgnoredAny;
+use std::{fmt::Display, path::Path};
+
+/// Routes file operations to the file of the currently active [`SaveSlot`].
+#[derive(Debug, PartialEq)]
+pub(crate) struct SaveSlots<TFileIO = FileIO> {
+	active: SaveSlot,
+	slots: Vec<(SaveSlot, TFileIO)>,
+}
+
+impl SaveSlots {
+	pub(crate) fn in_directory<TSlots>(directory: &Path, slots: TSlots) -> Self
+	where
+		TSlots: IntoIterator<Item = SaveSlot>,
+	{
+		Self::from_iter(slots.into_iter().map(|slot| {
+			let file = directory.join(file_name(slot)).with_extension("json");
+			(slot, FileIO::with_file(file))
+		}))
+	}
+}
+
+fn file_name(slot: SaveSlot) -> String {
+	match slot {
+		SaveSlot::Quick => "Quick Save".to_owned(),
+		SaveSlot::Manual(index) => format!("Save {index}"),
+		SaveSlot::Auto(index) => format!("Autosave {index}"),
+	}
+}
+
+impl<TFileIO> SaveSlots<TFileIO> {
+	/// Activate the slot used by following file operations.
+	///
+	/// Operations on slots that have not been configured will fail with [`SlotError::Unknown`].
+	pub(crate) fn activate(&mut self, slot: SaveSlot) {
+		self.active = slot;
+	}
+
+	#[cfg(test)]
+	pub(crate) fn active(&self) -> SaveSlot {
+		self.active
+	}
+
+	fn file(&self, slot: SaveSlot) -> Option<&TFileIO> {
+		self.slots
+			.iter()
+			.find_map(|(s, file)| (s == &slot).then_some(file))
+	}
+
+	fn active_file<TError>(&self) -> Result<&TFileIO, SlotError<TError>> {
+		self.file(self.active)
+			.ok_or(SlotError::Unknown(UnknownSlot(self.active)))
+	}
+}
+
+impl<TFileIO> SaveSlots<TFileIO>
+where
+	TFileIO: FileExists,
+{
+	pub(crate) fn slot_exists(&self, slot: SaveSlot) -> bool {
+		self.file(slot).is_some_and(FileExists::file_exists)
+	}
+
+	pub(crate) fn any_slot_exists(&self) -> bool {
+		self.slots.iter().any(|(_, file)| file.file_exists())
+	}
+}
+
+impl<TFileIO> SaveSlots<TFileIO>
+where
+	TFileIO: FileExists + ReadFile,
+{
+	/// The existing slot with the most recent [`SlotMetadata::timestamp`].
+	///
+	/// Slots with unreadable headers are treated as the oldest, so this is only `None`
+	/// when no slot exists.
+	pub(crate) fn newest(&self) -> Option<SaveSlot> {
+		self.slots
+			.iter()
+			.filter(|(_, file)| file.file_exists())
+			.max_by_key(|(_, file)| timestamp(file).unwrap_or_default())
+			.map(|(slot, _)| *slot)
+	}
+
+	/// Manual slots in configured order, with the header of those that can be read.
+	pub(crate) fn manual_slots(&self) -> Vec<(SaveSlot, Option<SlotMetadata>)> {
+		self.slots
+			.iter()
+			.filter(|(slot, _)| matches!(slot, SaveSlot::Manual(_)))
+			.map(|(slot, file)| (*slot, header(file)))
+			.collect()
+	}
+
+	/// The first unused autosave slot or, if all are used, the one with the oldest
+	/// [`SlotMetadata::timestamp`].
+	///
+	/// Slots with unreadable headers are treated as the oldest.
+	pub(crate) fn next_autosave(&self) -> Option<SaveSlot> {
+		let autosaves = self
+			.slots
+			.iter()
+			.filter(|(slot, _)| matches!(slot, SaveSlot::Auto(_)));
+
+		if let Some((slot, _)) = autosaves.clone().find(|(_, file)| !file.file_exists()) {
+			return Some(*slot);
+		}
+
+		autosaves
+			.min_by_key(|(_, file)| timestamp(file).unwrap_or_default())
+			.map(|(slot, _)| *slot)
+	}
+}
+
+fn header<TFileIO>(file: &TFileIO) -> Option<SlotMetadata>
+where
+	TFileIO: FileExists + ReadFile,
+{
+	if !file.file_exists() {
+		return None;
+	}
+
+	let content = file.read().ok()?;
+	let SaveFile { header, .. } = serde_json::from_str::<SaveFile<IgnoredAny>>(&content).ok()?;
+
+	Some(header)
+}
+
+fn timestamp<TFileIO>(file: &TFileIO) -> Option<u64>
+where
+	TFileIO: FileExists + ReadFile,
+{
+	header(file).map(|header| header.timestamp)
+}
+
+impl<TFileIO> FromIterator<(SaveSlot, TFileIO)> for SaveSlots<TFileIO> {
+	fn from_iter<T: IntoIterator<Item = (SaveSlot, TFileIO)>>(slots: T) -> Self {
+		Self {
+			active: SaveSlot::Quick,
+			slots: slots.into_iter().collect(),
+		}
+	}
+}
+
+impl<TFileIO> WriteFile for SaveSlots<TFileIO>
+where
+	TFileIO: WriteFile,
+{
+	type TWriteError = SlotError<TFileIO::TWriteError>;
+
+	fn write(&self, string: &str) -> Result<(), Self::TWriteError> {
+		self.active_file()?.write(string).map_err(SlotError::File)
+	}
+}
+
+impl<TFileIO> ReadFile for SaveSlots<TFileIO>
+where
+	TFileIO: ReadFile,
+{
+	type TReadError = SlotError<TFileIO::TReadError>;
+
+	fn read(&self) -> Result<String, Self::TReadError> {
+		self.active_file()?.read().map_err(SlotError::File)
+	}
+}
+
+impl<TFileIO> FileExists for SaveSlots<TFileIO>
+where
+	TFileIO: FileExists,
+{
+	fn file_exists(&self) -> bool {
+		self.slot_exists(self.active)
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum SlotError<TError> {
+	Unknown(UnknownSlot),
+	File(TError),
+}
+
+impl<TError> Display for SlotError<TError>
+where
+	TError: Display,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			SlotError::Unknown(error) => write!(f, "{error}"),
+			SlotError::File(error) => write!(f, "{error}"),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct UnknownSlot(pub(crate) SaveSlot);
+
+impl Display for UnknownSlot {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:?}: not a configured save slot", self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use macros::simple_mock;
+	use std::path::PathBuf;
+	use testing::Mock;
+
+	simple_mock! {
+		_File {}
+		impl ReadFile for _File {
+			type TReadError = _Error;
+			fn read(&self) -> Result<String, _Error>;
+		}
+		impl WriteFile for _File {
+			type TWriteError = _Error;
+			fn write(&self, string: &str) -> Result<(), _Error>;
+		}
+		impl FileExists for _File {
+			fn file_exists(&self) -> bool;
+		}
+	}
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Error;
+
+	fn saved_at(timestamp: u64) -> Mock_File {
+		Mock_File::new_mock(move |mock| {
+			mock.expect_file_exists().return_const(true);
+			mock.expect_read().returning(move || {
+				Ok(format!(
+					r#"{{"version":1,"header":{{"timestamp":{timestamp},"play_time":{{"secs":0,"nanos":0}},"level":null,"player_health":null}},"entities":[]}}"#
+				))
+			});
+		})
+	}
+
+	fn missing() -> Mock_File {
+		Mock_File::new_mock(|mock| {
+			mock.expect_file_exists().return_const(false);
+			mock.expect_read().never();
+		})
+	}
+
+	#[test]
+	fn files_in_directory() {
+		let slots = SaveSlots::in_directory(
+			Path::new("/my/saves"),
+			[SaveSlot::Quick, SaveSlot::Manual(1), SaveSlot::Auto(2)],
+		);
+
+		assert_eq!(
+			SaveSlots {
+				active: SaveSlot::Quick,
+				slots: vec![
+					(
+						SaveSlot::Quick,
+						FileIO::with_file(PathBuf::from("/my/saves/Quick Save.json"))
+					),
+					(
+						SaveSlot::Manual(1),
+						FileIO::with_file(PathBuf::from("/my/saves/Save 1.json"))
+					),
+					(
+						SaveSlot::Auto(2),
+						FileIO::with_file(PathBuf::from("/my/saves/Autosave 2.json"))
+					),
+				],
+			},
+			slots,
+		);
+	}
+
+	#[test]
+	fn write_to_active_slot() {
+		let mut slots = SaveSlots::from_iter([
+			(
+				SaveSlot::Quick,
+				Mock_File::new_mock(|mock| {
+					mock.expect_write().never();
+				}),
+			),
+			(
+				SaveSlot::Manual(1),
+				Mock_File::new_mock(|mock| {
+					mock.expect_write()
+						.times(1)
+						.withf(|string| string == "content")
+						.return_const(Ok(()));
+				}),
+			),
+		]);
+
+		slots.activate(SaveSlot::Manual(1));
+
+		assert_eq!(Ok(()), slots.write("content"));
+	}
+
+	#[test]
+	fn read_from_active_slot() {
+		let mut slots = SaveSlots::from_iter([
+			(
+				SaveSlot::Quick,
+				Mock_File::new_mock(|mock| {
+					mock.expect_read().never();
+				}),
+			),
+			(
+				SaveSlot::Auto(1),
+				Mock_File::new_mock(|mock| {
+					mock.expect_read()
+						.times(1)
+						.return_const(Ok("content".to_owned()));
+				}),
+			),
+		]);
+
+		slots.activate(SaveSlot::Auto(1));
+
+		assert_eq!(Ok("content".to_owned()), slots.read());
+	}
+
+	#[test]
+	fn read_and_write_unknown_slot() {
+		let mut slots = SaveSlots::from_iter([(SaveSlot::Quick, missing())]);
+
+		slots.activate(SaveSlot::Manual(11));
+
+		assert_eq!(
+			(
+				Err(SlotError::Unknown(UnknownSlot(SaveSlot::Manual(11)))),
+				Err(SlotError::Unknown(UnknownSlot(SaveSlot::Manual(11)))),
+			),
+			(slots.read(), slots.write("")),
+		);
+	}
+
+	#[test]
+	fn return_file_errors() {
+		let slots = SaveSlots::from_iter([(
+			SaveSlot::Quick,
+			Mock_File::new_mock(|mock| {
+				mock.expect_read().return_const(Err(_Error));
+				mock.expect_write().return_const(Err(_Error));
+			}),
+		)]);
+
+		assert_eq!(
+			(Err(SlotError::File(_Error)), Err(SlotError::File(_Error))),
+			(slots.read(), slots.write("")),
+		);
+	}
+
+	#[test]
+	fn slot_exists() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, missing()),
+			(SaveSlot::Manual(1), saved_at(0)),
+		]);
+
+		assert_eq!(
+			[false, true, false],
+			[
+				slots.slot_exists(SaveSlot::Quick),
+				slots.slot_exists(SaveSlot::Manual(1)),
+				slots.slot_exists(SaveSlot::Manual(2)),
+			]
+		);
+	}
+
+	#[test]
+	fn any_slot_exists() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, missing()),
+			(SaveSlot::Manual(1), saved_at(0)),
+		]);
+
+		assert!(slots.any_slot_exists());
+	}
+
+	#[test]
+	fn no_slot_exists() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, missing()),
+			(SaveSlot::Manual(1), missing()),
+		]);
+
+		assert!(!slots.any_slot_exists());
+	}
+
+	#[test]
+	fn newest() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, saved_at(10)),
+			(SaveSlot::Manual(1), saved_at(30)),
+			(SaveSlot::Manual(2), missing()),
+			(SaveSlot::Auto(1), saved_at(20)),
+		]);
+
+		assert_eq!(Some(SaveSlot::Manual(1)), slots.newest());
+	}
+
+	#[test]
+	fn newest_ignores_unreadable_headers() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, saved_at(10)),
+			(
+				SaveSlot::Manual(1),
+				Mock_File::new_mock(|mock| {
+					mock.expect_file_exists().return_const(true);
+					mock.expect_read().return_const(Ok("[]".to_owned()));
+				}),
+			),
+		]);
+
+		assert_eq!(Some(SaveSlot::Quick), slots.newest());
+	}
+
+	#[test]
+	fn newest_falls_back_to_slot_with_unreadable_header() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, missing()),
+			(
+				SaveSlot::Manual(1),
+				Mock_File::new_mock(|mock| {
+					mock.expect_file_exists().return_const(true);
+					mock.expect_read().return_const(Ok("[]".to_owned()));
+				}),
+			),
+		]);
+
+		assert_eq!(Some(SaveSlot::Manual(1)), slots.newest());
+	}
+
+	#[test]
+	fn no_newest() {
+		let slots = SaveSlots::from_iter([(SaveSlot::Quick, missing())]);
+
+		assert_eq!(None, slots.newest());
+	}
+
+	#[test]
+	fn manual_slots() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, saved_at(10)),
+			(SaveSlot::Manual(1), saved_at(30)),
+			(SaveSlot::Manual(2), missing()),
+			(SaveSlot::Auto(1), saved_at(20)),
+		]);
+
+		assert_eq!(
+			vec![
+				(
+					SaveSlot::Manual(1),
+					Some(SlotMetadata {
+						timestamp: 30,
+						..SlotMetadata::default()
+					})
+				),
+				(SaveSlot::Manual(2), None),
+			],
+			slots.manual_slots()
+		);
+	}
+
+	#[test]
+	fn next_autosave_is_first_unused() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, missing()),
+			(SaveSlot::Auto(1), saved_at(10)),
+			(SaveSlot::Auto(2), missing()),
+			(SaveSlot::Auto(3), missing()),
+		]);
+
+		assert_eq!(Some(SaveSlot::Auto(2)), slots.next_autosave());
+	}
+
+	#[test]
+	fn next_autosave_is_oldest() {
+		let slots = SaveSlots::from_iter([
+			(SaveSlot::Quick, missing()),
+			(SaveSlot::Auto(1), saved_at(10)),
+			(SaveSlot::Auto(2), saved_at(5)),
+			(SaveSlot::Auto(3), saved_at(20)),
+		]);
+
+		assert_eq!(Some(SaveSlot::Auto(2)), slots.next_autosave());
+	}
+
+	#[test]
+	fn no_autosave_slots() {
+		let slots = SaveSlots::from_iter([(SaveSlot::Quick, missing())]);
+
+		assert_eq!(None, slots.next_autosave());
+	}
+}
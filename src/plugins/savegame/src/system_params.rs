@@ -0,0 +1,4 @@
+pub(crate) mod save_slots_mut;
+pub(crate) mod save_slots_param;
+pub(crate) mod slot_metadata_mut;
+pub(crate) mod stash_mut;
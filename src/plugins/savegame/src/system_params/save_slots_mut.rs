@@ -0,0 +1,101 @@
+use crate::resources::pending_slots::PendingSlots;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use common::{
+	prelude::*,
+	states::{game_state::GameState, save_state::SaveState},
+};
+
+#[derive(SystemParam)]
+pub struct SaveSlotsMut<'w> {
+	pending: ResMut<'w, PendingSlots>,
+	next_state: ResMut<'w, NextState<GameState>>,
+}
+
+impl RequestSlot for SaveSlotsMut<'_> {
+	fn request_slot(&mut self, request: SlotRequest) {
+		match request {
+			SlotRequest::Save(slot) => {
+				self.pending.save = Some(slot);
+				self.next_state.set(GameState::Save(SaveState::Save));
+			}
+			SlotRequest::Load(slot) => {
+				self.pending.load = Some(slot);
+				self.next_state.set(GameState::Save(SaveState::AttemptLoad));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::{
+		ecs::system::{RunSystemError, RunSystemOnce},
+		state::app::StatesPlugin,
+	};
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.add_plugins(StatesPlugin);
+		app.init_state::<GameState>();
+		app.init_resource::<PendingSlots>();
+
+		app
+	}
+
+	#[test]
+	fn request_save() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut().run_system_once(|mut slots: SaveSlotsMut| {
+			slots.request_slot(SlotRequest::Save(SaveSlot::Manual(2)));
+		})?;
+
+		assert_eq!(
+			(
+				&PendingSlots {
+					save: Some(SaveSlot::Manual(2)),
+					load: None,
+				},
+				true,
+			),
+			(
+				app.world().resource::<PendingSlots>(),
+				matches!(
+					app.world().resource::<NextState<GameState>>(),
+					NextState::Pending(GameState::Save(SaveState::Save))
+				),
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn request_load() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut().run_system_once(|mut slots: SaveSlotsMut| {
+			slots.request_slot(SlotRequest::Load(SaveSlot::Auto(1)));
+		})?;
+
+		assert_eq!(
+			(
+				&PendingSlots {
+					save: None,
+					load: Some(SaveSlot::Auto(1)),
+				},
+				true,
+			),
+			(
+				app.world().resource::<PendingSlots>(),
+				matches!(
+					app.world().resource::<NextState<GameState>>(),
+					NextState::Pending(GameState::Save(SaveState::AttemptLoad))
+				),
+			)
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,105 @@
+use crate::resources::inspector::Inspector;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use common::{
+	prelude::*,
+	tools::file_io::FileIO,
+	traits::{file_exists::FileExists, read_file::ReadFile},
+};
+
+#[derive(SystemParam)]
+pub struct SaveSlotsParam<'w, TFileIO = FileIO>
+where
+	TFileIO: ThreadSafe,
+{
+	inspector: Res<'w, Inspector<TFileIO>>,
+}
+
+impl<TFileIO> ReadManualSlots for SaveSlotsParam<'_, TFileIO>
+where
+	TFileIO: FileExists + ReadFile + ThreadSafe,
+{
+	fn manual_slots(&self) -> Vec<(SaveSlot, Option<SlotMetadata>)> {
+		let Ok(context) = self.inspector.context.lock() else {
+			return vec![];
+		};
+
+		context.io.manual_slots()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{context::SaveContext, save_slots::SaveSlots};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use macros::simple_mock;
+	use std::sync::{Arc, Mutex};
+	use testing::{Mock, SingleThreadedApp};
+
+	simple_mock! {
+		_IO {}
+		impl FileExists for _IO {
+			fn file_exists(&self) -> bool;
+		}
+		impl ReadFile for _IO {
+			type TReadError = ();
+			fn read(&self) -> Result<String, ()>;
+		}
+	}
+
+	fn saved_at(timestamp: u64) -> Mock_IO {
+		Mock_IO::new_mock(move |mock| {
+			mock.expect_file_exists().return_const(true);
+			mock.expect_read().returning(move || {
+				Ok(format!(
+					r#"{{"version":1,"header":{{"timestamp":{timestamp},"play_time":{{"secs":0,"nanos":0}},"level":2,"player_health":null}},"entities":[]}}"#
+				))
+			});
+		})
+	}
+
+	fn missing() -> Mock_IO {
+		Mock_IO::new_mock(|mock| {
+			mock.expect_file_exists().return_const(false);
+		})
+	}
+
+	fn setup<const N: usize>(slots: [(SaveSlot, Mock_IO); N]) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(Inspector {
+			context: Arc::new(Mutex::new(SaveContext::from(SaveSlots::from_iter(slots)))),
+		});
+
+		app
+	}
+
+	#[test]
+	fn read_manual_slots() -> Result<(), RunSystemError> {
+		let mut app = setup([
+			(SaveSlot::Quick, saved_at(1)),
+			(SaveSlot::Manual(1), missing()),
+			(SaveSlot::Manual(2), saved_at(42)),
+		]);
+
+		let slots = app
+			.world_mut()
+			.run_system_once(|slots: SaveSlotsParam<Mock_IO>| slots.manual_slots())?;
+
+		assert_eq!(
+			vec![
+				(SaveSlot::Manual(1), None),
+				(
+					SaveSlot::Manual(2),
+					Some(SlotMetadata {
+						timestamp: 42,
+						level: Some(2),
+						..default()
+					})
+				),
+			],
+			slots
+		);
+		Ok(())
+	}
+}
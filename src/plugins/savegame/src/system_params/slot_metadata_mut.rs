@@ -0,0 +1,77 @@
+use crate::resources::slot_metadata_buffer::SlotMetadataBuffer;
+use bevy::{ecs::system::SystemParam, prelude::*};
+use common::prelude::*;
+
+#[derive(SystemParam)]
+pub struct SlotMetadataMut<'w> {
+	buffer: ResMut<'w, SlotMetadataBuffer>,
+}
+
+impl WriteSlotMetadata for SlotMetadataMut<'_> {
+	fn write_level(&mut self, level: i8) {
+		self.buffer.0.level = Some(level);
+	}
+
+	fn write_player_health(&mut self, health: Health) {
+		self.buffer.0.player_health = Some(health);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::SingleThreadedApp;
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<SlotMetadataBuffer>();
+
+		app
+	}
+
+	#[test]
+	fn write_level() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut()
+			.run_system_once(|mut metadata: SlotMetadataMut| {
+				metadata.write_level(-2);
+			})?;
+
+		assert_eq!(
+			&SlotMetadataBuffer(SlotMetadata {
+				level: Some(-2),
+				..default()
+			}),
+			app.world().resource::<SlotMetadataBuffer>(),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn write_player_health() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut()
+			.run_system_once(|mut metadata: SlotMetadataMut| {
+				metadata.write_player_health(Health {
+					current: 3.,
+					max: 4.,
+				});
+			})?;
+
+		assert_eq!(
+			&SlotMetadataBuffer(SlotMetadata {
+				player_health: Some(Health {
+					current: 3.,
+					max: 4.,
+				}),
+				..default()
+			}),
+			app.world().resource::<SlotMetadataBuffer>(),
+		);
+		Ok(())
+	}
+}
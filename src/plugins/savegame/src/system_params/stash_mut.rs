@@ -0,0 +1,83 @@
+use crate::resources::stash::{Stash, StashRequest};
+use bevy::{ecs::system::SystemParam, prelude::*};
+use common::prelude::*;
+
+#[derive(SystemParam)]
+pub struct StashMut<'w> {
+	stash: ResMut<'w, Stash>,
+}
+
+impl StashEntities for StashMut<'_> {
+	fn stash_entities(&mut self, key: StashKey, entities: Vec<Entity>) {
+		self.stash.pending.push(StashRequest::Stash(key, entities));
+	}
+
+	fn unstash_entities(&mut self, key: StashKey) {
+		self.stash.pending.push(StashRequest::Unstash(key));
+	}
+
+	fn is_stashed(&self, key: &StashKey) -> bool {
+		self.stash.contains(key)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::{SingleThreadedApp, fake_entity};
+
+	fn setup() -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<Stash>();
+
+		app
+	}
+
+	#[test]
+	fn request_stash() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut().run_system_once(|mut stash: StashMut| {
+			stash.stash_entities(StashKey::from("a"), vec![fake_entity!(11)]);
+		})?;
+
+		assert_eq!(
+			vec![StashRequest::Stash(
+				StashKey::from("a"),
+				vec![fake_entity!(11)]
+			)],
+			app.world().resource::<Stash>().pending,
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn request_unstash() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		app.world_mut().run_system_once(|mut stash: StashMut| {
+			stash.unstash_entities(StashKey::from("a"));
+		})?;
+
+		assert_eq!(
+			vec![StashRequest::Unstash(StashKey::from("a"))],
+			app.world().resource::<Stash>().pending,
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn is_stashed() -> Result<(), RunSystemError> {
+		let mut app = setup();
+
+		let stashed = app.world_mut().run_system_once(|mut stash: StashMut| {
+			stash.stash_entities(StashKey::from("a"), vec![fake_entity!(11)]);
+			stash.is_stashed(&StashKey::from("a"))
+		})?;
+
+		assert!(stashed);
+		Ok(())
+	}
+}
@@ -1,7 +1,15 @@
+pub(crate) mod activate_slot;
+pub(crate) mod autosave;
+pub(crate) mod clear_pending_load;
 pub(crate) mod despawn_persistent_entities;
-pub(crate) mod quick_save_file_exists;
+pub(crate) mod play_time;
+pub(crate) mod process_stash;
 pub(crate) mod read_buffer;
 pub(crate) mod read_file;
+pub(crate) mod slot_exists;
+pub(crate) mod slot_metadata;
+pub(crate) mod stash;
+pub(crate) mod stash_buffer;
 pub(crate) mod trigger_state;
 pub(crate) mod write_buffer;
 pub(crate) mod write_file;
@@ -0,0 +1,186 @@
+use crate::{
+	context::SaveContext,
+	errors::LockPoisonedError,
+	resources::pending_slots::PendingSlots,
+	save_slots::SaveSlots,
+};
+use bevy::prelude::*;
+use common::{
+	prelude::*,
+	traits::{file_exists::FileExists, read_file::ReadFile},
+};
+use std::sync::{Arc, Mutex};
+
+impl<TFileIO> SaveContext<SaveSlots<TFileIO>> {
+	/// Activate the pending save slot or [`SaveSlot::Quick`]
+	pub(crate) fn activate_save_slot_system(
+		context: Arc<Mutex<Self>>,
+	) -> impl Fn(ResMut<PendingSlots>) -> Result<(), LockPoisonedError> {
+		move |mut pending| {
+			let Ok(mut context) = context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			let slot = pending.save.take().unwrap_or(SaveSlot::Quick);
+			context.io.activate(slot);
+			Ok(())
+		}
+	}
+
+	/// Activate the pending load slot or the newest existing slot
+	pub(crate) fn activate_load_slot_system(
+		context: Arc<Mutex<Self>>,
+	) -> impl Fn(ResMut<PendingSlots>) -> Result<(), LockPoisonedError>
+	where
+		TFileIO: FileExists + ReadFile,
+	{
+		move |mut pending| {
+			let Ok(mut context) = context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			let slot = match pending.load.take() {
+				Some(slot) => slot,
+				None => context.io.newest().unwrap_or(SaveSlot::Quick),
+			};
+			context.io.activate(slot);
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::expect_used)]
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use macros::simple_mock;
+	use testing::{Mock, SingleThreadedApp};
+
+	simple_mock! {
+		_IO {}
+		impl FileExists for _IO {
+			fn file_exists(&self) -> bool;
+		}
+		impl ReadFile for _IO {
+			type TReadError = ();
+			fn read(&self) -> Result<String, ()>;
+		}
+	}
+
+	fn saved_at(timestamp: u64) -> Mock_IO {
+		Mock_IO::new_mock(move |mock| {
+			mock.expect_file_exists().return_const(true);
+			mock.expect_read().returning(move || {
+				Ok(format!(
+					r#"{{"version":1,"header":{{"timestamp":{timestamp},"play_time":{{"secs":0,"nanos":0}},"level":null,"player_health":null}},"entities":[]}}"#
+				))
+			});
+		})
+	}
+
+	fn setup(pending: PendingSlots) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(pending);
+
+		app
+	}
+
+	fn active_slot(context: &Arc<Mutex<SaveContext<SaveSlots<Mock_IO>>>>) -> SaveSlot {
+		context.lock().expect("COULD NOT LOCK CONTEXT").io.active()
+	}
+
+	mod save {
+		use super::*;
+
+		#[test]
+		fn activate_pending_slot() -> Result<(), RunSystemError> {
+			let context = Arc::new(Mutex::new(SaveContext::from(SaveSlots::from_iter([
+				(SaveSlot::Quick, saved_at(0)),
+				(SaveSlot::Manual(3), saved_at(0)),
+			]))));
+			let mut app = setup(PendingSlots {
+				save: Some(SaveSlot::Manual(3)),
+				..default()
+			});
+
+			_ = app
+				.world_mut()
+				.run_system_once(SaveContext::activate_save_slot_system(context.clone()))?;
+
+			assert_eq!(
+				(SaveSlot::Manual(3), &PendingSlots::default()),
+				(
+					active_slot(&context),
+					app.world().resource::<PendingSlots>()
+				),
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn activate_quick_slot_when_nothing_pending() -> Result<(), RunSystemError> {
+			let mut slots = SaveSlots::from_iter([
+				(SaveSlot::Quick, saved_at(0)),
+				(SaveSlot::Manual(3), saved_at(0)),
+			]);
+			slots.activate(SaveSlot::Manual(3));
+			let context = Arc::new(Mutex::new(SaveContext::from(slots)));
+			let mut app = setup(PendingSlots::default());
+
+			_ = app
+				.world_mut()
+				.run_system_once(SaveContext::activate_save_slot_system(context.clone()))?;
+
+			assert_eq!(SaveSlot::Quick, active_slot(&context));
+			Ok(())
+		}
+	}
+
+	mod load {
+		use super::*;
+
+		#[test]
+		fn activate_pending_slot() -> Result<(), RunSystemError> {
+			let context = Arc::new(Mutex::new(SaveContext::from(SaveSlots::from_iter([
+				(SaveSlot::Quick, saved_at(100)),
+				(SaveSlot::Auto(1), saved_at(0)),
+			]))));
+			let mut app = setup(PendingSlots {
+				load: Some(SaveSlot::Auto(1)),
+				..default()
+			});
+
+			_ = app
+				.world_mut()
+				.run_system_once(SaveContext::activate_load_slot_system(context.clone()))?;
+
+			assert_eq!(
+				(SaveSlot::Auto(1), &PendingSlots::default()),
+				(
+					active_slot(&context),
+					app.world().resource::<PendingSlots>()
+				),
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn activate_newest_slot_when_nothing_pending() -> Result<(), RunSystemError> {
+			let context = Arc::new(Mutex::new(SaveContext::from(SaveSlots::from_iter([
+				(SaveSlot::Quick, saved_at(100)),
+				(SaveSlot::Manual(1), saved_at(300)),
+				(SaveSlot::Auto(1), saved_at(200)),
+			]))));
+			let mut app = setup(PendingSlots::default());
+
+			_ = app
+				.world_mut()
+				.run_system_once(SaveContext::activate_load_slot_system(context.clone()))?;
+
+			assert_eq!(SaveSlot::Manual(1), active_slot(&context));
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,156 @@
+use crate::{
+	context::SaveContext,
+	errors::LockPoisonedError,
+	resources::autosave::Autosave,
+	save_slots::SaveSlots,
+	system_params::save_slots_mut::SaveSlotsMut,
+};
+use bevy::prelude::*;
+use common::{
+	prelude::*,
+	traits::{file_exists::FileExists, read_file::ReadFile},
+};
+use std::sync::{Arc, Mutex};
+
+impl Autosave {
+	/// Request a save to the next autosave slot whenever the timer finishes.
+	pub(crate) fn trigger<TTime, TFileIO>(
+		context: Arc<Mutex<SaveContext<SaveSlots<TFileIO>>>>,
+	) -> impl Fn(Res<Time<TTime>>, ResMut<Self>, SaveSlotsMut) -> Result<(), LockPoisonedError>
+	where
+		TTime: Default + Sync + Send + 'static,
+		TFileIO: FileExists + ReadFile,
+	{
+		move |time, mut autosave, mut slots| {
+			if !autosave.timer.tick(time.delta()).just_finished() {
+				return Ok(());
+			}
+
+			let Ok(context) = context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			let Some(slot) = context.io.next_autosave() else {
+				return Ok(());
+			};
+
+			slots.request_slot(SlotRequest::Save(slot));
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::resources::pending_slots::PendingSlots;
+	use bevy::state::app::StatesPlugin;
+	use common::states::{game_state::GameState, save_state::SaveState};
+	use macros::simple_mock;
+	use std::time::Duration;
+	use testing::{MissingLastUpdate, Mock, SingleThreadedApp, TickTime};
+
+	simple_mock! {
+		_IO {}
+		impl FileExists for _IO {
+			fn file_exists(&self) -> bool;
+		}
+		impl ReadFile for _IO {
+			type TReadError = ();
+			fn read(&self) -> Result<String, ()>;
+		}
+	}
+
+	fn missing() -> Mock_IO {
+		Mock_IO::new_mock(|mock| {
+			mock.expect_file_exists().return_const(false);
+			mock.expect_read().never();
+		})
+	}
+
+	fn setup(autosave: Autosave, slots: SaveSlots<Mock_IO>) -> App {
+		let mut app = App::new().single_threaded(Update);
+		let context = Arc::new(Mutex::new(SaveContext::from(slots)));
+
+		app.add_plugins(StatesPlugin);
+		app.init_state::<GameState>();
+		app.init_resource::<Time<Real>>();
+		app.init_resource::<PendingSlots>();
+		app.insert_resource(autosave);
+		app.add_systems(
+			Update,
+			Autosave::trigger::<Real, Mock_IO>(context).pipe(|In(_)| {}),
+		);
+
+		app
+	}
+
+	#[test]
+	fn request_autosave_when_timer_finished() -> Result<(), MissingLastUpdate> {
+		let mut app = setup(
+			Autosave::every(Duration::from_secs(1)),
+			SaveSlots::from_iter([(SaveSlot::Quick, missing()), (SaveSlot::Auto(1), missing())]),
+		);
+
+		app.tick_time(Duration::from_secs(1))?;
+		app.update();
+
+		assert_eq!(
+			(
+				&PendingSlots {
+					save: Some(SaveSlot::Auto(1)),
+					load: None,
+				},
+				true,
+			),
+			(
+				app.world().resource::<PendingSlots>(),
+				matches!(
+					app.world().resource::<NextState<GameState>>(),
+					NextState::Pending(GameState::Save(SaveState::Save))
+				),
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn do_nothing_when_timer_not_finished() -> Result<(), MissingLastUpdate> {
+		let mut app = setup(
+			Autosave::every(Duration::from_secs(1)),
+			SaveSlots::from_iter([(SaveSlot::Auto(1), missing())]),
+		);
+
+		app.tick_time(Duration::from_millis(999))?;
+		app.update();
+
+		assert_eq!(
+			(&PendingSlots::default(), true),
+			(
+				app.world().resource::<PendingSlots>(),
+				matches!(
+					app.world().resource::<NextState<GameState>>(),
+					NextState::Unchanged
+				),
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn do_nothing_without_autosave_slots() -> Result<(), MissingLastUpdate> {
+		let mut app = setup(
+			Autosave::every(Duration::from_secs(1)),
+			SaveSlots::from_iter([(SaveSlot::Quick, missing())]),
+		);
+
+		app.tick_time(Duration::from_secs(1))?;
+		app.update();
+
+		assert_eq!(
+			&PendingSlots::default(),
+			app.world().resource::<PendingSlots>()
+		);
+		Ok(())
+	}
+}
@@ -0,0 +1,36 @@
+use crate::resources::pending_slots::PendingSlots;
+use bevy::prelude::*;
+
+impl PendingSlots {
+	pub(crate) fn clear_load(mut pending: ResMut<Self>) {
+		pending.load = None;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::prelude::*;
+	use testing::SingleThreadedApp;
+
+	#[test]
+	fn clear_load() -> Result<(), RunSystemError> {
+		let mut app = App::new().single_threaded(Update);
+		app.insert_resource(PendingSlots {
+			save: Some(SaveSlot::Manual(1)),
+			load: Some(SaveSlot::Manual(2)),
+		});
+
+		app.world_mut().run_system_once(PendingSlots::clear_load)?;
+
+		assert_eq!(
+			&PendingSlots {
+				save: Some(SaveSlot::Manual(1)),
+				load: None,
+			},
+			app.world().resource::<PendingSlots>(),
+		);
+		Ok(())
+	}
+}
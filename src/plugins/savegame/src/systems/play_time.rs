@@ -0,0 +1,59 @@
+use crate::resources::play_time::PlayTime;
+use bevy::prelude::*;
+
+impl PlayTime {
+	pub(crate) fn track<TTime: Default + Sync + Send + 'static>(
+		time: Res<Time<TTime>>,
+		mut play_time: ResMut<Self>,
+	) {
+		play_time.0 += time.delta();
+	}
+
+	pub(crate) fn reset(mut play_time: ResMut<Self>) {
+		*play_time = Self::default();
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+	use testing::{MissingLastUpdate, SingleThreadedApp, TickTime};
+
+	fn setup(play_time: PlayTime) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<Time<Real>>();
+		app.insert_resource(play_time);
+
+		app
+	}
+
+	#[test]
+	fn add_delta() -> Result<(), MissingLastUpdate> {
+		let mut app = setup(PlayTime(Duration::from_secs(10)));
+		app.add_systems(Update, PlayTime::track::<Real>);
+
+		app.tick_time(Duration::from_millis(500))?;
+		app.update();
+
+		assert_eq!(
+			Some(&PlayTime(Duration::from_millis(10_500))),
+			app.world().get_resource::<PlayTime>()
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn reset() {
+		let mut app = setup(PlayTime(Duration::from_secs(10)));
+		app.add_systems(Update, PlayTime::reset);
+
+		app.update();
+
+		assert_eq!(
+			Some(&PlayTime(Duration::ZERO)),
+			app.world().get_resource::<PlayTime>()
+		);
+	}
+}
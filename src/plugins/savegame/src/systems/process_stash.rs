@@ -0,0 +1,291 @@
+use crate::{
+	context::{LoadBuffer, SaveContext},
+	errors::{
+		EntitySerializationErrors,
+		LockPoisonedError,
+		SerializationErrors,
+		SerializationOrLockError,
+	},
+	resources::stash::{Stash, StashRequest},
+	traits::write_buffer::WriteBuffer,
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use serde_json::Value;
+use std::{
+	collections::HashMap,
+	mem,
+	sync::{Arc, Mutex},
+};
+
+impl<TFileIO, TComponentHandler> SaveContext<TFileIO, TComponentHandler>
+where
+	Self: WriteBuffer,
+{
+	/// Process pending [`Stash`] requests in order.
+	///
+	/// Stashed entities are serialized and despawned. Unstashed entities are moved into
+	/// the load buffer, so they are spawned by the next
+	/// [`read_buffer_system`](SaveContext::read_buffer_system) run.
+	pub(crate) fn process_stash_system(
+		context: Arc<Mutex<Self>>,
+	) -> impl Fn(&mut World) -> Result<(), SerializationOrLockError> {
+		move |world| {
+			let Ok(mut context) = context.lock() else {
+				return Err(SerializationOrLockError::LockPoisoned(LockPoisonedError));
+			};
+
+			let errors = world
+				.try_resource_scope(|world, mut stash: Mut<Stash>| {
+					let mut errors = HashMap::default();
+
+					for request in mem::take(&mut stash.pending) {
+						match request {
+							StashRequest::Stash(key, roots) => {
+								let entities = context.serialize(world, &roots, &mut errors);
+								stash.entities.insert(key, entities);
+								for root in roots {
+									_ = world.try_despawn(root);
+								}
+							}
+							StashRequest::Unstash(key) => {
+								let Some(entities) = stash.entities.remove(&key) else {
+									continue;
+								};
+								context.buffers.load.extend(entities);
+							}
+						}
+					}
+
+					errors
+				})
+				.unwrap_or_default();
+
+			match errors.is_empty() {
+				true => Ok(()),
+				false => Err(SerializationOrLockError::SerializationErrors(
+					SerializationErrors(errors),
+				)),
+			}
+		}
+	}
+
+	fn serialize(
+		&mut self,
+		world: &World,
+		roots: &[Entity],
+		errors: &mut HashMap<Entity, EntitySerializationErrors>,
+	) -> LoadBuffer<Value> {
+		let save_buffer = mem::take(&mut self.buffers.save);
+
+		for entity in roots
+			.iter()
+			.flat_map(|root| persistent_hierarchy(world, *root))
+		{
+			if let Err(error) = self.write_buffer(entity) {
+				errors.insert(entity.id(), error);
+			}
+		}
+
+		mem::replace(&mut self.buffers.save, save_buffer)
+			.into_values()
+			.collect()
+	}
+}
+
+fn persistent_hierarchy(world: &World, root: Entity) -> Vec<EntityRef<'_>> {
+	let Ok(root) = world.get_entity(root) else {
+		return vec![];
+	};
+	let children = root
+		.get::<Children>()
+		.into_iter()
+		.flat_map(|children| children.iter())
+		.flat_map(|child| persistent_hierarchy(world, child));
+
+	root.contains::<PersistentEntity>()
+		.then_some(root)
+		.into_iter()
+		.chain(children)
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	#![allow(clippy::expect_used)]
+	use super::*;
+	use crate::context::handler::ComponentHandler;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::tools::file_io::FileIO;
+	use macros::SavableComponent;
+	use serde::{Deserialize, Serialize};
+	use serde_json::json;
+	use std::path::PathBuf;
+	use testing::SingleThreadedApp;
+
+	#[derive(Component, SavableComponent, Debug, PartialEq, Clone, Serialize, Deserialize)]
+	#[savable_component(id = "a")]
+	struct _A(u32);
+
+	type _Context = SaveContext<FileIO, ComponentHandler>;
+
+	fn setup(stash: Stash) -> (App, Arc<Mutex<_Context>>) {
+		let mut app = App::new().single_threaded(Update);
+		let context = SaveContext::from(FileIO::with_file(PathBuf::new()))
+			.with_low_priority_handlers([ComponentHandler::new::<_A>()]);
+
+		app.insert_resource(stash);
+
+		(app, Arc::new(Mutex::new(context)))
+	}
+
+	#[test]
+	fn stash_entities() -> Result<(), RunSystemError> {
+		let (mut app, context) = setup(Stash::default());
+		let entity = app
+			.world_mut()
+			.spawn((PersistentEntity::default(), _A(42)))
+			.id();
+		app.world_mut()
+			.resource_mut::<Stash>()
+			.pending
+			.push(StashRequest::Stash(StashKey::from("key"), vec![entity]));
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::process_stash_system(context))?;
+
+		assert_eq!(
+			(
+				&Stash {
+					entities: HashMap::from([(
+						StashKey::from("key"),
+						vec![HashMap::from([("a".to_owned(), json!(42))])]
+					)]),
+					pending: vec![],
+				},
+				true
+			),
+			(
+				app.world().resource::<Stash>(),
+				app.world().get_entity(entity).is_err()
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn stash_persistent_children() -> Result<(), RunSystemError> {
+		let (mut app, context) = setup(Stash::default());
+		let entity = app
+			.world_mut()
+			.spawn((PersistentEntity::default(), _A(1)))
+			.id();
+		let child = app
+			.world_mut()
+			.spawn((ChildOf(entity), PersistentEntity::default(), _A(2)))
+			.id();
+		app.world_mut().spawn((ChildOf(entity), _A(3)));
+		app.world_mut()
+			.resource_mut::<Stash>()
+			.pending
+			.push(StashRequest::Stash(StashKey::from("key"), vec![entity]));
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::process_stash_system(context))?;
+
+		let mut stashed = app.world().resource::<Stash>().entities[&StashKey::from("key")]
+			.iter()
+			.map(|components| components["a"].clone())
+			.collect::<Vec<_>>();
+		stashed.sort_by_key(|value| value.as_u64());
+		assert_eq!(
+			(vec![json!(1), json!(2)], true),
+			(stashed, app.world().get_entity(child).is_err())
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn keep_save_buffer() -> Result<(), RunSystemError> {
+		let (mut app, context) = setup(Stash::default());
+		let entity = app
+			.world_mut()
+			.spawn((PersistentEntity::default(), _A(42)))
+			.id();
+		let save_buffer = HashMap::from([(
+			Entity::PLACEHOLDER,
+			HashMap::from([("a".to_owned(), json!(11))]),
+		)]);
+		context.lock().unwrap().buffers.save = save_buffer.clone();
+		app.world_mut()
+			.resource_mut::<Stash>()
+			.pending
+			.push(StashRequest::Stash(StashKey::from("key"), vec![entity]));
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::process_stash_system(context.clone()))?;
+
+		assert_eq!(save_buffer, context.lock().unwrap().buffers.save);
+		Ok(())
+	}
+
+	#[test]
+	fn unstash_entities_into_load_buffer() -> Result<(), RunSystemError> {
+		let (mut app, context) = setup(Stash {
+			entities: HashMap::from([(
+				StashKey::from("key"),
+				vec![HashMap::from([("a".to_owned(), json!(42))])],
+			)]),
+			pending: vec![StashRequest::Unstash(StashKey::from("key"))],
+		});
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::process_stash_system(context.clone()))?;
+
+		assert_eq!(
+			(
+				&Stash::default(),
+				vec![HashMap::from([("a".to_owned(), json!(42))])]
+			),
+			(
+				app.world().resource::<Stash>(),
+				context.lock().unwrap().buffers.load.clone()
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn process_requests_in_order() -> Result<(), RunSystemError> {
+		let (mut app, context) = setup(Stash::default());
+		let entity = app
+			.world_mut()
+			.spawn((PersistentEntity::default(), _A(42)))
+			.id();
+		app.world_mut().resource_mut::<Stash>().pending = vec![
+			StashRequest::Stash(StashKey::from("key"), vec![entity]),
+			StashRequest::Unstash(StashKey::from("key")),
+		];
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::process_stash_system(context.clone()))?;
+
+		assert_eq!(
+			(
+				&Stash::default(),
+				vec![HashMap::from([("a".to_owned(), json!(42))])]
+			),
+			(
+				app.world().resource::<Stash>(),
+				context.lock().unwrap().buffers.load.clone()
+			)
+		);
+		Ok(())
+	}
+}
@@ -1,7 +1,6 @@
 use crate::{
 	context::{EntityLoadBuffer, LoadBuffer, SaveContext},
 	errors::{DeserializationOrLockError, IOErrors, InsertionError, Load, LockPoisonedError},
-	file_io::FileIO,
 	traits::insert_entity_component::InsertEntityComponent,
 };
 use bevy::{ecs::component::Mutable, prelude::*};
@@ -11,7 +10,7 @@ use std::{
 	sync::{Arc, Mutex},
 };
 
-impl<T, TComponent> SaveContext<FileIO, T, TComponent> {
+impl<TFileIO, T, TComponent> SaveContext<TFileIO, T, TComponent> {
 	pub(crate) fn read_buffer_system<TLoadAsset>(
 		context: Arc<Mutex<Self>>,
 	) -> impl Fn(Commands, ResMut<TLoadAsset>) -> Result<(), DeserializationOrLockError<T::TError>>
@@ -130,11 +129,11 @@ where
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use crate::file_io::FileIO;
 	use bevy::{
 		asset::AssetPath,
 		ecs::system::{RunSystemError, RunSystemOnce},
 	};
+	use common::tools::file_io::FileIO;
 	use serde_json::{Value, json};
 	use std::{
 		collections::{HashMap, HashSet},
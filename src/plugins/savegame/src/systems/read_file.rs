@@ -1,9 +1,11 @@
 use crate::{
-	context::SaveContext,
+	context::{SAVE_FILE_VERSION, SaveContext, SaveFile},
 	errors::{ContextIOError, IOErrors, LockPoisonedError, SerdeJsonError},
-	traits::read_file::ReadFile,
+	traits::migrate_component::MigrateComponent,
 };
 use bevy::prelude::*;
+use common::traits::read_file::ReadFile;
+use serde::de::Error;
 use serde_json::Value;
 use std::{
 	collections::HashMap,
@@ -11,10 +13,12 @@ use std::{
 };
 
 type SerializedEntities = Vec<HashMap<String, Value>>;
+type SerializedSaveFile = SaveFile<SerializedEntities>;
 
-impl<TFileIO> SaveContext<TFileIO>
+impl<TFileIO, TComponentHandler> SaveContext<TFileIO, TComponentHandler>
 where
 	TFileIO: ReadFile,
+	TComponentHandler: MigrateComponent,
 {
 	pub(crate) fn read_file_system(
 		context: Arc<Mutex<Self>>,
@@ -24,23 +28,68 @@ where
 				Err(_) => return Err(ContextIOError::LockPoisoned(LockPoisonedError)),
 				Ok(context) => context,
 			};
-			let entities = match context.io.read() {
+			let content = match context.io.read() {
 				Err(e) => return Err(ContextIOError::FileError(e)),
-				Ok(entities) => entities,
+				Ok(content) => content,
 			};
 
-			context.buffers.load = match serde_json::from_str::<SerializedEntities>(&entities) {
+			let mut save_file = match parse(&content) {
 				Err(error) => {
 					return Err(ContextIOError::SerdeErrors(IOErrors::from(vec![
 						SerdeJsonError(error),
 					])));
 				}
-				Ok(components) => components,
+				Ok(save_file) => save_file,
 			};
 
+			context.migrate(&mut save_file);
+			context.buffers.load = save_file.entities;
+			context.buffers.metadata = save_file.header;
+			context.buffers.stash = save_file.stash;
+
 			Ok(())
 		}
 	}
+
+	fn migrate(&self, save_file: &mut SerializedSaveFile) {
+		for handler in self.handlers.all() {
+			let id = &**handler.component_id();
+			let version = save_file
+				.component_versions
+				.get(id)
+				.copied()
+				.unwrap_or_default();
+
+			let stashed = save_file.stash.values_mut().flatten();
+			let entities = save_file.entities.iter_mut().chain(stashed);
+
+			for component in entities.filter_map(|e| e.get_mut(id)) {
+				handler.migrate(component, version);
+			}
+		}
+	}
+}
+
+fn parse(content: &str) -> Result<SerializedSaveFile, serde_json::Error> {
+	let save_file = match serde_json::from_str::<Value>(content)? {
+		Value::Array(entities) => SaveFile {
+			version: 0,
+			header: default(),
+			component_versions: default(),
+			entities: serde_json::from_value(Value::Array(entities))?,
+			stash: default(),
+		},
+		save_file => serde_json::from_value::<SerializedSaveFile>(save_file)?,
+	};
+
+	if save_file.version > SAVE_FILE_VERSION {
+		return Err(serde_json::Error::custom(format!(
+			"save file version {} is not supported, expected {SAVE_FILE_VERSION} or lower",
+			save_file.version,
+		)));
+	}
+
+	Ok(save_file)
 }
 
 #[cfg(test)]
@@ -48,10 +97,13 @@ mod tests {
 	#![allow(clippy::unwrap_used)]
 	#![allow(clippy::expect_used)]
 	use super::*;
-	use crate::errors::IOErrors;
+	use crate::{context::handler::ComponentHandler, errors::IOErrors};
 	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
-	use macros::simple_mock;
-	use std::collections::HashMap;
+	use common::prelude::*;
+	use macros::{SavableComponent, simple_mock};
+	use serde::{Deserialize, Serialize};
+	use serde_json::json;
+	use std::{collections::HashMap, time::Duration};
 	use testing::{Mock, SingleThreadedApp};
 
 	#[derive(Debug, PartialEq, Clone)]
@@ -65,6 +117,9 @@ mod tests {
 		}
 	}
 
+	const HEADER: &str =
+		r#"{"timestamp":0,"play_time":{"secs":0,"nanos":0},"level":null,"player_health":null}"#;
+
 	fn setup() -> App {
 		App::new().single_threaded(Update)
 	}
@@ -72,11 +127,11 @@ mod tests {
 	#[test]
 	fn call_read() -> Result<(), RunSystemError> {
 		let reader = Mock_Reader::new_mock(|mock| {
-			mock.expect_read()
-				.times(1)
-				.return_const(Ok("[]".to_owned()));
+			mock.expect_read().times(1).return_const(Ok(format!(
+				r#"{{"version":1,"header":{HEADER},"entities":[]}}"#
+			)));
 		});
-		let context = Arc::new(Mutex::new(SaveContext::from(reader)));
+		let context = Arc::new(Mutex::new(SaveContext::<_, ComponentHandler>::from(reader)));
 		let mut app = setup();
 
 		_ = app
@@ -88,10 +143,11 @@ mod tests {
 	#[test]
 	fn write_load_buffer() -> Result<(), RunSystemError> {
 		let reader = Mock_Reader::new_mock(|mock| {
-			mock.expect_read()
-				.return_const(Ok(r#"[{"A":{"value":32}}]"#.to_owned()));
+			mock.expect_read().return_const(Ok(format!(
+				r#"{{"version":1,"header":{HEADER},"entities":[{{"A":{{"value":32}}}}]}}"#
+			)));
 		});
-		let context = Arc::new(Mutex::new(SaveContext::from(reader)));
+		let context = Arc::new(Mutex::new(SaveContext::<_, ComponentHandler>::from(reader)));
 		let mut app = setup();
 
 		_ = app
@@ -108,12 +164,73 @@ mod tests {
 		Ok(())
 	}
 
+	#[test]
+	fn write_metadata() -> Result<(), RunSystemError> {
+		let reader = Mock_Reader::new_mock(|mock| {
+			mock.expect_read().return_const(Ok(
+				r#"{"version":1,"header":{"timestamp":42,"play_time":{"secs":11,"nanos":0},"level":3,"player_health":null},"entities":[]}"#.to_owned(),
+			));
+		});
+		let context = Arc::new(Mutex::new(SaveContext::<_, ComponentHandler>::from(reader)));
+		let mut app = setup();
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::read_file_system(context.clone()))?;
+
+		assert_eq!(
+			SlotMetadata {
+				timestamp: 42,
+				play_time: Duration::from_secs(11),
+				level: Some(3),
+				player_health: None,
+			},
+			context
+				.lock()
+				.expect("COULD NOT LOCK CONTEXT")
+				.buffers
+				.metadata
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn write_stash() -> Result<(), RunSystemError> {
+		let reader = Mock_Reader::new_mock(|mock| {
+			mock.expect_read().return_const(Ok(format!(
+				r#"{{"version":1,"header":{HEADER},"entities":[],"stash":{{"level":[{{"A":{{"value":32}}}}]}}}}"#
+			)));
+		});
+		let context = Arc::new(Mutex::new(SaveContext::<_, ComponentHandler>::from(reader)));
+		let mut app = setup();
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::read_file_system(context.clone()))?;
+
+		assert_eq!(
+			HashMap::from([(
+				StashKey::from("level"),
+				vec![HashMap::from([(
+					"A".to_owned(),
+					serde_json::from_str(r#"{"value":32}"#).unwrap(),
+				)])]
+			)]),
+			context
+				.lock()
+				.expect("COULD NOT LOCK CONTEXT")
+				.buffers
+				.stash
+		);
+		Ok(())
+	}
+
 	#[test]
 	fn return_read_error() -> Result<(), RunSystemError> {
 		let reader = Mock_Reader::new_mock(|mock| {
 			mock.expect_read().return_const(Err(_Error));
 		});
-		let context = Arc::new(Mutex::new(SaveContext::from(reader)));
+		let context = Arc::new(Mutex::new(SaveContext::<_, ComponentHandler>::from(reader)));
 		let mut app = setup();
 
 		let result = app
@@ -127,7 +244,7 @@ mod tests {
 	#[test]
 	fn deserialize_error() -> Result<(), RunSystemError> {
 		let entities = "[my so very broken entity]".to_owned();
-		let Err(error) = serde_json::from_str::<SerializedEntities>(&entities) else {
+		let Err(error) = serde_json::from_str::<Value>(&entities) else {
 			panic!("SETUP BROKEN: EXPECTED ERROR");
 		};
 		let reader = Mock_Reader::new_mock(|mock| {
@@ -135,7 +252,7 @@ mod tests {
 				.times(1)
 				.return_const(Ok(entities.clone()));
 		});
-		let context = Arc::new(Mutex::new(SaveContext::from(reader)));
+		let context = Arc::new(Mutex::new(SaveContext::<_, ComponentHandler>::from(reader)));
 		let mut app = setup();
 
 		let result = app
@@ -150,4 +267,169 @@ mod tests {
 		);
 		Ok(())
 	}
+
+	#[test]
+	fn unsupported_version_error() -> Result<(), RunSystemError> {
+		let reader = Mock_Reader::new_mock(|mock| {
+			mock.expect_read().return_const(Ok(format!(
+				r#"{{"version":{},"header":{HEADER},"entities":[]}}"#,
+				SAVE_FILE_VERSION + 1
+			)));
+		});
+		let context = Arc::new(Mutex::new(SaveContext::<_, ComponentHandler>::from(reader)));
+		let mut app = setup();
+
+		let result = app
+			.world_mut()
+			.run_system_once(SaveContext::read_file_system(context.clone()))?;
+
+		assert_eq!(
+			Err(ContextIOError::SerdeErrors(IOErrors::from(vec![
+				SerdeJsonError(serde_json::Error::custom(format!(
+					"save file version {} is not supported, expected {SAVE_FILE_VERSION} or lower",
+					SAVE_FILE_VERSION + 1
+				)))
+			]))),
+			result
+		);
+		Ok(())
+	}
+
+	mod migrations {
+		use super::*;
+
+		#[derive(Component, SavableComponent, Serialize, Deserialize, Clone)]
+		#[savable_component(id = "counter", migrations = [rename_value_to_count])]
+		struct _Counter {
+			count: u32,
+		}
+
+		fn rename_value_to_count(component: &mut Value) {
+			let Some(component) = component.as_object_mut() else {
+				return;
+			};
+			let Some(value) = component.remove("value") else {
+				return;
+			};
+			component.insert("count".to_owned(), value);
+		}
+
+		fn load_buffer(file: &'static str) -> Result<SerializedEntities, RunSystemError> {
+			let reader = Mock_Reader::new_mock(|mock| {
+				mock.expect_read().return_const(Ok(file.to_owned()));
+			});
+			let context = Arc::new(Mutex::new(
+				SaveContext::<_, ComponentHandler>::from(reader)
+					.with_low_priority_handlers([ComponentHandler::new::<_Counter>()]),
+			));
+			let mut app = setup();
+
+			_ = app
+				.world_mut()
+				.run_system_once(SaveContext::read_file_system(context.clone()))?;
+
+			let mut context = context.lock().expect("COULD NOT LOCK CONTEXT");
+			Ok(std::mem::take(&mut context.buffers.load))
+		}
+
+		fn expected_buffer() -> SerializedEntities {
+			vec![
+				HashMap::from([
+					("counter".to_owned(), json!({"count": 3})),
+					("other".to_owned(), json!({"value": 4})),
+				]),
+				HashMap::from([("counter".to_owned(), json!({"count": 11}))]),
+			]
+		}
+
+		#[test]
+		fn load_version_0_fixture() -> Result<(), RunSystemError> {
+			let buffer = load_buffer(include_str!("../../fixtures/save_file_v0.json"))?;
+
+			assert_eq!(expected_buffer(), buffer);
+			Ok(())
+		}
+
+		#[test]
+		fn load_version_1_fixture_without_component_versions() -> Result<(), RunSystemError> {
+			let buffer = load_buffer(include_str!(
+				"../../fixtures/save_file_v1_component_v0.json"
+			))?;
+
+			assert_eq!(expected_buffer(), buffer);
+			Ok(())
+		}
+
+		#[test]
+		fn load_version_1_fixture_with_component_versions() -> Result<(), RunSystemError> {
+			let buffer = load_buffer(include_str!(
+				"../../fixtures/save_file_v1_component_v1.json"
+			))?;
+
+			assert_eq!(expected_buffer(), buffer);
+			Ok(())
+		}
+
+		#[test]
+		fn migrate_stashed_entities() -> Result<(), RunSystemError> {
+			let reader = Mock_Reader::new_mock(|mock| {
+				mock.expect_read().return_const(Ok(format!(
+					r#"{{"version":1,"header":{HEADER},"entities":[],"stash":{{"level":[{{"counter":{{"value":3}}}}]}}}}"#
+				)));
+			});
+			let context = Arc::new(Mutex::new(
+				SaveContext::<_, ComponentHandler>::from(reader)
+					.with_low_priority_handlers([ComponentHandler::new::<_Counter>()]),
+			));
+			let mut app = setup();
+
+			_ = app
+				.world_mut()
+				.run_system_once(SaveContext::read_file_system(context.clone()))?;
+
+			assert_eq!(
+				HashMap::from([(
+					StashKey::from("level"),
+					vec![HashMap::from([("counter".to_owned(), json!({"count": 3}))])]
+				)]),
+				context
+					.lock()
+					.expect("COULD NOT LOCK CONTEXT")
+					.buffers
+					.stash
+			);
+			Ok(())
+		}
+
+		#[test]
+		fn version_0_fixture_has_default_metadata() -> Result<(), RunSystemError> {
+			let reader = Mock_Reader::new_mock(|mock| {
+				mock.expect_read()
+					.return_const(Ok(
+						include_str!("../../fixtures/save_file_v0.json").to_owned()
+					));
+			});
+			let context = Arc::new(Mutex::new(
+				SaveContext::<_, ComponentHandler>::from(reader).with_metadata(SlotMetadata {
+					timestamp: 42,
+					..default()
+				}),
+			));
+			let mut app = setup();
+
+			_ = app
+				.world_mut()
+				.run_system_once(SaveContext::read_file_system(context.clone()))?;
+
+			assert_eq!(
+				SlotMetadata::default(),
+				context
+					.lock()
+					.expect("COULD NOT LOCK CONTEXT")
+					.buffers
+					.metadata
+			);
+			Ok(())
+		}
+	}
 }
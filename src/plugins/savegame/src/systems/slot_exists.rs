@@ -0,0 +1,191 @@
+use crate::{
+	errors::LockPoisonedError,
+	resources::{inspector::Inspector, pending_slots::PendingSlots},
+};
+use bevy::prelude::*;
+use common::{prelude::*, traits::file_exists::FileExists};
+
+impl<TFileIO> Inspector<TFileIO>
+where
+	TFileIO: FileExists + ThreadSafe,
+{
+	pub(crate) fn slot_exists(
+		slot: SaveSlot,
+	) -> impl Fn(Res<Self>) -> Result<bool, LockPoisonedError> {
+		move |inspector| {
+			let Ok(context) = inspector.context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			Ok(context.io.slot_exists(slot))
+		}
+	}
+
+	pub(crate) fn any_slot_exists(inspector: Res<Self>) -> Result<bool, LockPoisonedError> {
+		let Ok(context) = inspector.context.lock() else {
+			return Err(LockPoisonedError);
+		};
+
+		Ok(context.io.any_slot_exists())
+	}
+
+	/// Check the slot pending for load or, if none is pending, whether any slot exists
+	pub(crate) fn pending_load_slot_exists(
+		inspector: Res<Self>,
+		pending: Res<PendingSlots>,
+	) -> Result<bool, LockPoisonedError> {
+		let Ok(context) = inspector.context.lock() else {
+			return Err(LockPoisonedError);
+		};
+
+		match pending.load {
+			Some(slot) => Ok(context.io.slot_exists(slot)),
+			None => Ok(context.io.any_slot_exists()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{context::SaveContext, save_slots::SaveSlots};
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use macros::simple_mock;
+	use std::sync::{Arc, Mutex};
+	use testing::{Mock, SingleThreadedApp};
+
+	simple_mock! {
+		_IO {}
+		impl FileExists for _IO {
+			fn file_exists(&self) -> bool;
+		}
+	}
+
+	fn exists(exists: bool) -> Mock_IO {
+		Mock_IO::new_mock(|mock| {
+			mock.expect_file_exists().return_const(exists);
+		})
+	}
+
+	fn setup<const N: usize>(slots: [(SaveSlot, Mock_IO); N], pending: PendingSlots) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(pending);
+		app.insert_resource(Inspector {
+			context: Arc::new(Mutex::new(SaveContext::from(SaveSlots::from_iter(slots)))),
+		});
+
+		app
+	}
+
+	#[test]
+	fn slot_exists() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			[
+				(SaveSlot::Quick, exists(false)),
+				(SaveSlot::Manual(1), exists(true)),
+			],
+			PendingSlots::default(),
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(Inspector::<Mock_IO>::slot_exists(SaveSlot::Manual(1)))?;
+
+		assert_eq!(Ok(true), result);
+		Ok(())
+	}
+
+	#[test]
+	fn slot_does_not_exist() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			[
+				(SaveSlot::Quick, exists(false)),
+				(SaveSlot::Manual(1), exists(true)),
+			],
+			PendingSlots::default(),
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(Inspector::<Mock_IO>::slot_exists(SaveSlot::Quick))?;
+
+		assert_eq!(Ok(false), result);
+		Ok(())
+	}
+
+	#[test]
+	fn any_slot_exists() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			[
+				(SaveSlot::Quick, exists(false)),
+				(SaveSlot::Auto(1), exists(true)),
+			],
+			PendingSlots::default(),
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(Inspector::<Mock_IO>::any_slot_exists)?;
+
+		assert_eq!(Ok(true), result);
+		Ok(())
+	}
+
+	#[test]
+	fn no_slot_exists() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			[
+				(SaveSlot::Quick, exists(false)),
+				(SaveSlot::Auto(1), exists(false)),
+			],
+			PendingSlots::default(),
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(Inspector::<Mock_IO>::any_slot_exists)?;
+
+		assert_eq!(Ok(false), result);
+		Ok(())
+	}
+
+	#[test]
+	fn pending_load_slot_exists() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			[
+				(SaveSlot::Quick, exists(true)),
+				(SaveSlot::Manual(1), exists(false)),
+			],
+			PendingSlots {
+				load: Some(SaveSlot::Manual(1)),
+				..default()
+			},
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(Inspector::<Mock_IO>::pending_load_slot_exists)?;
+
+		assert_eq!(Ok(false), result);
+		Ok(())
+	}
+
+	#[test]
+	fn any_slot_exists_when_no_load_slot_pending() -> Result<(), RunSystemError> {
+		let mut app = setup(
+			[
+				(SaveSlot::Quick, exists(false)),
+				(SaveSlot::Manual(1), exists(true)),
+			],
+			PendingSlots::default(),
+		);
+
+		let result = app
+			.world_mut()
+			.run_system_once(Inspector::<Mock_IO>::pending_load_slot_exists)?;
+
+		assert_eq!(Ok(true), result);
+		Ok(())
+	}
+}
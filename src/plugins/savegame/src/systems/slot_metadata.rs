@@ -0,0 +1,161 @@
+use crate::{
+	context::SaveContext,
+	errors::LockPoisonedError,
+	resources::{play_time::PlayTime, slot_metadata_buffer::SlotMetadataBuffer},
+};
+use bevy::prelude::*;
+use common::prelude::*;
+use std::{
+	mem,
+	sync::{Arc, Mutex},
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+impl<TFileIO> SaveContext<TFileIO> {
+	/// Move the collected metadata into the context, stamped with `now` and the current
+	/// [`PlayTime`].
+	pub(crate) fn write_metadata_system(
+		context: Arc<Mutex<Self>>,
+		now: fn() -> u64,
+	) -> impl Fn(ResMut<SlotMetadataBuffer>, Res<PlayTime>) -> Result<(), LockPoisonedError> {
+		move |mut buffer, play_time| {
+			let Ok(mut context) = context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			let SlotMetadataBuffer(metadata) = mem::take(buffer.as_mut());
+			context.buffers.metadata = SlotMetadata {
+				timestamp: now(),
+				play_time: play_time.0,
+				..metadata
+			};
+			Ok(())
+		}
+	}
+
+	/// Restore the [`PlayTime`] from the loaded metadata.
+	pub(crate) fn read_metadata_system(
+		context: Arc<Mutex<Self>>,
+	) -> impl Fn(ResMut<PlayTime>) -> Result<(), LockPoisonedError> {
+		move |mut play_time| {
+			let Ok(mut context) = context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			let metadata = mem::take(&mut context.buffers.metadata);
+			*play_time = PlayTime(metadata.play_time);
+			Ok(())
+		}
+	}
+}
+
+/// Seconds since the unix epoch, `0` if the system clock is set before it.
+pub(crate) fn unix_timestamp() -> u64 {
+	SystemTime::now()
+		.duration_since(UNIX_EPOCH)
+		.map(|duration| duration.as_secs())
+		.unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::expect_used)]
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::tools::file_io::FileIO;
+	use std::{path::PathBuf, time::Duration};
+	use testing::SingleThreadedApp;
+
+	fn setup(buffer: SlotMetadataBuffer, play_time: PlayTime) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(buffer);
+		app.insert_resource(play_time);
+
+		app
+	}
+
+	fn metadata(context: &Arc<Mutex<SaveContext<FileIO>>>) -> SlotMetadata {
+		context
+			.lock()
+			.expect("COULD NOT LOCK CONTEXT")
+			.buffers
+			.metadata
+			.clone()
+	}
+
+	#[test]
+	fn write_metadata() -> Result<(), RunSystemError> {
+		let context = Arc::new(Mutex::new(SaveContext::from(FileIO::with_file(
+			PathBuf::new(),
+		))));
+		let mut app = setup(
+			SlotMetadataBuffer(SlotMetadata {
+				level: Some(2),
+				player_health: Some(Health::new(42.)),
+				..default()
+			}),
+			PlayTime(Duration::from_secs(100)),
+		);
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::write_metadata_system(context.clone(), || 11))?;
+
+		assert_eq!(
+			SlotMetadata {
+				timestamp: 11,
+				play_time: Duration::from_secs(100),
+				level: Some(2),
+				player_health: Some(Health::new(42.)),
+			},
+			metadata(&context),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn clear_metadata_buffer() -> Result<(), RunSystemError> {
+		let context = Arc::new(Mutex::new(SaveContext::from(FileIO::with_file(
+			PathBuf::new(),
+		))));
+		let mut app = setup(
+			SlotMetadataBuffer(SlotMetadata {
+				level: Some(2),
+				..default()
+			}),
+			PlayTime::default(),
+		);
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::write_metadata_system(context, || 11))?;
+
+		assert_eq!(
+			&SlotMetadataBuffer::default(),
+			app.world().resource::<SlotMetadataBuffer>(),
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn read_play_time() -> Result<(), RunSystemError> {
+		let context = Arc::new(Mutex::new(
+			SaveContext::from(FileIO::with_file(PathBuf::new())).with_metadata(SlotMetadata {
+				play_time: Duration::from_secs(300),
+				..default()
+			}),
+		));
+		let mut app = setup(SlotMetadataBuffer::default(), PlayTime::default());
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::read_metadata_system(context.clone()))?;
+
+		assert_eq!(
+			(&PlayTime(Duration::from_secs(300)), SlotMetadata::default()),
+			(app.world().resource::<PlayTime>(), metadata(&context)),
+		);
+		Ok(())
+	}
+}
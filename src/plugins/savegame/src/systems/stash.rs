@@ -0,0 +1,69 @@
+use crate::resources::stash::Stash;
+use bevy::prelude::*;
+
+impl Stash {
+	pub(crate) fn clear(mut stash: ResMut<Self>) {
+		*stash = Self::default();
+	}
+
+	pub(crate) fn has_pending(stash: Res<Self>) -> bool {
+		!stash.pending.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::resources::stash::StashRequest;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::prelude::*;
+	use std::collections::HashMap;
+	use testing::SingleThreadedApp;
+
+	fn setup(stash: Stash) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(stash);
+
+		app
+	}
+
+	#[test]
+	fn clear() -> Result<(), RunSystemError> {
+		let mut app = setup(Stash {
+			entities: HashMap::from([(StashKey::from("a"), vec![])]),
+			pending: vec![StashRequest::Unstash(StashKey::from("a"))],
+		});
+
+		app.world_mut().run_system_once(Stash::clear)?;
+
+		assert_eq!(&Stash::default(), app.world().resource::<Stash>());
+		Ok(())
+	}
+
+	#[test]
+	fn has_pending() -> Result<(), RunSystemError> {
+		let mut app = setup(Stash {
+			entities: HashMap::from([]),
+			pending: vec![StashRequest::Unstash(StashKey::from("a"))],
+		});
+
+		let has_pending = app.world_mut().run_system_once(Stash::has_pending)?;
+
+		assert!(has_pending);
+		Ok(())
+	}
+
+	#[test]
+	fn has_no_pending() -> Result<(), RunSystemError> {
+		let mut app = setup(Stash {
+			entities: HashMap::from([(StashKey::from("a"), vec![])]),
+			pending: vec![],
+		});
+
+		let has_pending = app.world_mut().run_system_once(Stash::has_pending)?;
+
+		assert!(!has_pending);
+		Ok(())
+	}
+}
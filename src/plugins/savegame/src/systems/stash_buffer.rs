@@ -0,0 +1,138 @@
+use crate::{context::SaveContext, errors::LockPoisonedError, resources::stash::Stash};
+use bevy::prelude::*;
+use std::{
+	mem,
+	sync::{Arc, Mutex},
+};
+
+impl<TFileIO> SaveContext<TFileIO> {
+	/// Copy the stashed entities into the context, so they are written alongside the
+	/// active entities.
+	pub(crate) fn write_stash_system(
+		context: Arc<Mutex<Self>>,
+	) -> impl Fn(Res<Stash>) -> Result<(), LockPoisonedError> {
+		move |stash| {
+			let Ok(mut context) = context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			context.buffers.stash = stash.entities.clone();
+			Ok(())
+		}
+	}
+
+	/// Replace the [`Stash`] with the stashed entities of the loaded file.
+	pub(crate) fn read_stash_system(
+		context: Arc<Mutex<Self>>,
+	) -> impl Fn(ResMut<Stash>) -> Result<(), LockPoisonedError> {
+		move |mut stash| {
+			let Ok(mut context) = context.lock() else {
+				return Err(LockPoisonedError);
+			};
+
+			*stash = Stash {
+				entities: mem::take(&mut context.buffers.stash),
+				pending: vec![],
+			};
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::expect_used)]
+	use super::*;
+	use crate::resources::stash::StashRequest;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use common::{prelude::*, tools::file_io::FileIO};
+	use serde_json::json;
+	use std::{collections::HashMap, path::PathBuf};
+	use testing::SingleThreadedApp;
+
+	fn setup(stash: Stash) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(stash);
+
+		app
+	}
+
+	#[test]
+	fn write_stash() -> Result<(), RunSystemError> {
+		let stashed = HashMap::from([(
+			StashKey::from("a"),
+			vec![HashMap::from([("b".to_owned(), json!(42))])],
+		)]);
+		let mut app = setup(Stash {
+			entities: stashed.clone(),
+			pending: vec![],
+		});
+		let context = Arc::new(Mutex::new(SaveContext::from(FileIO::with_file(
+			PathBuf::new(),
+		))));
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::write_stash_system(context.clone()))?;
+
+		assert_eq!(
+			(
+				&Stash {
+					entities: stashed.clone(),
+					pending: vec![],
+				},
+				stashed
+			),
+			(
+				app.world().resource::<Stash>(),
+				context
+					.lock()
+					.expect("COULD NOT LOCK CONTEXT")
+					.buffers
+					.stash
+					.clone()
+			)
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn read_stash() -> Result<(), RunSystemError> {
+		let stashed = HashMap::from([(
+			StashKey::from("a"),
+			vec![HashMap::from([("b".to_owned(), json!(42))])],
+		)]);
+		let mut app = setup(Stash {
+			entities: HashMap::from([(StashKey::from("c"), vec![])]),
+			pending: vec![StashRequest::Unstash(StashKey::from("c"))],
+		});
+		let context = Arc::new(Mutex::new(
+			SaveContext::from(FileIO::with_file(PathBuf::new())).with_stash(stashed.clone()),
+		));
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::read_stash_system(context.clone()))?;
+
+		assert_eq!(
+			(
+				&Stash {
+					entities: stashed,
+					pending: vec![],
+				},
+				HashMap::default()
+			),
+			(
+				app.world().resource::<Stash>(),
+				context
+					.lock()
+					.expect("COULD NOT LOCK CONTEXT")
+					.buffers
+					.stash
+					.clone()
+			)
+		);
+		Ok(())
+	}
+}
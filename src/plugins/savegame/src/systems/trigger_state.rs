@@ -1,31 +1,29 @@
-use bevy::{
-	ecs::system::{StaticSystemParam, SystemParam},
-	prelude::*,
-	state::state::FreelyMutableState,
-};
+use bevy::ecs::system::{StaticSystemParam, SystemParam};
 use common::{
 	tools::action_key::ActionKey,
-	traits::handles_input::{GetInputState, InputState},
+	traits::{
+		handles_input::{GetInputState, InputState},
+		handles_saving::{RequestSlot, SlotRequest},
+	},
 };
 
 impl<T> TriggerState for T where T: for<'w, 's> SystemParam<Item<'w, 's>: GetInputState> {}
 
 pub(crate) trait TriggerState: for<'w, 's> SystemParam<Item<'w, 's>: GetInputState> {
-	#[allow(clippy::type_complexity)]
-	fn trigger<TActionKey, TState>(
+	fn trigger<TActionKey, TSlots>(
 		action: TActionKey,
-		state: TState,
-	) -> impl Fn(StaticSystemParam<Self>, ResMut<NextState<TState>>)
+		request: SlotRequest,
+	) -> impl Fn(StaticSystemParam<Self>, StaticSystemParam<TSlots>)
 	where
 		TActionKey: Into<ActionKey> + Copy + 'static,
-		TState: FreelyMutableState + Copy,
+		TSlots: for<'w, 's> SystemParam<Item<'w, 's>: RequestSlot>,
 	{
-		move |input, mut game_state| {
+		move |input, mut slots| {
 			if input.get_input_state(action) != InputState::just_pressed() {
 				return;
 			}
 
-			game_state.set(state);
+			slots.request_slot(request);
 		}
 	}
 }
@@ -33,19 +31,12 @@ pub(crate) trait TriggerState: for<'w, 's> SystemParam<Item<'w, 's>: GetInputSta
 #[cfg(test)]
 mod tests {
 	use super::*;
-	use bevy::state::app::StatesPlugin;
+	use bevy::prelude::*;
+	use common::traits::handles_saving::SaveSlot;
 	use macros::NestedMocks;
 	use mockall::{automock, predicate::eq};
 	use testing::{NestedMocks, SingleThreadedApp};
 
-	#[derive(States, Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
-	enum _State {
-		#[default]
-		Default,
-		A,
-		B,
-	}
-
 	#[derive(Debug, PartialEq, Clone, Copy)]
 	enum _Action {
 		A,
@@ -73,48 +64,83 @@ mod tests {
 		}
 	}
 
-	fn setup(input: _Input, action: _Action, state: _State) -> App {
+	#[derive(Resource, NestedMocks)]
+	struct _Slots {
+		mock: Mock_Slots,
+	}
+
+	#[automock]
+	impl RequestSlot for _Slots {
+		fn request_slot(&mut self, request: SlotRequest) {
+			self.mock.request_slot(request);
+		}
+	}
+
+	fn setup(input: _Input, slots: _Slots, action: _Action, request: SlotRequest) -> App {
 		let mut app = App::new().single_threaded(Update);
 
-		app.add_plugins(StatesPlugin);
-		app.init_state::<_State>();
 		app.insert_resource(input);
-		app.add_systems(Update, Res::<_Input>::trigger(action, state));
+		app.insert_resource(slots);
+		app.add_systems(
+			Update,
+			Res::<_Input>::trigger::<_Action, ResMut<_Slots>>(action, request),
+		);
 
 		app
 	}
 
 	#[test]
-	fn trigger_a() {
+	fn request_save() {
 		let input = _Input::new().with_mock(|mock| {
 			mock.expect_get_input_state()
 				.with(eq(_Action::A))
 				.return_const(InputState::just_pressed());
 		});
-		let mut app = setup(input, _Action::A, _State::A);
+		let slots = _Slots::new().with_mock(|mock| {
+			mock.expect_request_slot()
+				.times(1)
+				.with(eq(SlotRequest::Save(SaveSlot::Quick)))
+				.return_const(());
+		});
+		let mut app = setup(input, slots, _Action::A, SlotRequest::Save(SaveSlot::Quick));
 
 		app.update();
-
-		assert!(matches!(
-			app.world().get_resource::<NextState<_State>>(),
-			Some(NextState::Pending(_State::A))
-		));
 	}
 
 	#[test]
-	fn trigger_b() {
+	fn request_load() {
 		let input = _Input::new().with_mock(|mock| {
 			mock.expect_get_input_state()
 				.with(eq(_Action::B))
 				.return_const(InputState::just_pressed());
 		});
-		let mut app = setup(input, _Action::B, _State::B);
+		let slots = _Slots::new().with_mock(|mock| {
+			mock.expect_request_slot()
+				.times(1)
+				.with(eq(SlotRequest::Load(SaveSlot::Manual(2))))
+				.return_const(());
+		});
+		let mut app = setup(
+			input,
+			slots,
+			_Action::B,
+			SlotRequest::Load(SaveSlot::Manual(2)),
+		);
 
 		app.update();
+	}
 
-		assert!(matches!(
-			app.world().get_resource::<NextState<_State>>(),
-			Some(NextState::Pending(_State::B))
-		));
+	#[test]
+	fn do_not_request_when_not_just_pressed() {
+		let input = _Input::new().with_mock(|mock| {
+			mock.expect_get_input_state::<_Action>()
+				.return_const(InputState::pressed());
+		});
+		let slots = _Slots::new().with_mock(|mock| {
+			mock.expect_request_slot().never();
+		});
+		let mut app = setup(input, slots, _Action::A, SlotRequest::Save(SaveSlot::Quick));
+
+		app.update();
 	}
 }
@@ -1,13 +1,21 @@
 use crate::{
-	context::SaveContext,
+	context::{SAVE_FILE_VERSION, SaveContext, SaveFile},
 	errors::{ContextIOError, IOErrors, LockPoisonedError, SerdeJsonError},
-	traits::write_file::WriteFile,
+	traits::migrate_component::MigrateComponent,
 };
 use bevy::prelude::*;
-use serde_json::to_value;
-use std::sync::{Arc, Mutex};
+use common::traits::write_file::WriteFile;
+use serde_json::{Value, to_value};
+use std::{
+	collections::HashMap,
+	mem,
+	sync::{Arc, Mutex},
+};
 
-impl<TFileIO> SaveContext<TFileIO> {
+impl<TFileIO, TComponentHandler> SaveContext<TFileIO, TComponentHandler>
+where
+	TComponentHandler: MigrateComponent,
+{
 	pub(crate) fn write_file_system(
 		context: Arc<Mutex<Self>>,
 	) -> impl Fn() -> Result<(), ContextIOError<TFileIO::TWriteError>>
@@ -29,24 +37,40 @@ impl<TFileIO> SaveContext<TFileIO> {
 		TFileIO: WriteFile,
 	{
 		let mut errors = vec![];
-		let entities = self
-			.buffers
-			.save
-			.drain()
-			.map(|(_, components)| match to_value(&components) {
-				Ok(value) => Some(value),
-				Err(error) => {
-					errors.push(SerdeJsonError(error));
-					None
-				}
-			})
-			.collect::<Vec<_>>();
+		let mut to_values = |entities: Vec<HashMap<String, Value>>| {
+			entities
+				.into_iter()
+				.filter_map(|components| match to_value(&components) {
+					Ok(value) => Some(value),
+					Err(error) => {
+						errors.push(SerdeJsonError(error));
+						None
+					}
+				})
+				.collect::<Vec<_>>()
+		};
+		let entities = to_values(self.buffers.save.drain().map(|(_, c)| c).collect());
+		let stash = mem::take(&mut self.buffers.stash)
+			.into_iter()
+			.map(|(key, entities)| (key, to_values(entities)))
+			.collect();
 
 		if !errors.is_empty() {
 			return Err(ContextIOError::SerdeErrors(IOErrors::from(errors)));
 		}
 
-		let json = match serde_json::to_string(&entities) {
+		let save_file = SaveFile {
+			version: SAVE_FILE_VERSION,
+			header: mem::take(&mut self.buffers.metadata),
+			component_versions: self
+				.handlers
+				.all()
+				.map(|handler| (String::from(handler.component_id()), handler.version()))
+				.collect(),
+			entities,
+			stash,
+		};
+		let json = match serde_json::to_string(&save_file) {
 			Ok(json) => json,
 			Err(error) => {
 				return Err(ContextIOError::SerdeErrors(IOErrors::from(vec![
@@ -64,10 +88,13 @@ mod tests {
 	#![allow(clippy::unwrap_used)]
 	#![allow(clippy::expect_used)]
 	use super::*;
+	use crate::context::handler::ComponentHandler;
 	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
-	use macros::simple_mock;
+	use common::prelude::*;
+	use macros::{SavableComponent, simple_mock};
 	use mockall::predicate::eq;
-	use std::collections::HashMap;
+	use serde::{Deserialize, Serialize};
+	use std::{collections::HashMap, time::Duration};
 	use testing::{Mock, SingleThreadedApp, fake_entity};
 
 	#[derive(Debug, PartialEq, Clone)]
@@ -81,6 +108,9 @@ mod tests {
 		}
 	}
 
+	const HEADER: &str =
+		r#"{"timestamp":0,"play_time":{"secs":0,"nanos":0},"level":null,"player_health":null}"#;
+
 	fn setup() -> App {
 		App::new().single_threaded(Update)
 	}
@@ -95,13 +125,14 @@ mod tests {
 			mock.expect_write()
 				.times(1)
 				.with(eq(format!(
-					"[{}]",
+					r#"{{"version":1,"header":{HEADER},"component_versions":{{}},"entities":[{}],"stash":{{}}}}"#,
 					serde_json::to_string(&components).unwrap()
 				)))
 				.return_const(Ok(()));
 		});
 		let context = Arc::new(Mutex::new(
-			SaveContext::from(writer).with_save_buffer([(fake_entity!(42), components)]),
+			SaveContext::<_, ComponentHandler>::from(writer)
+				.with_save_buffer([(fake_entity!(42), components)]),
 		));
 		let mut app = setup();
 
@@ -127,15 +158,20 @@ mod tests {
 			mock.expect_write()
 				.times(1)
 				.withf(|v| {
-					let a_b = r#"[{"A":{"value":32},"B":{"v":42}}]"#;
-					let b_a = r#"[{"B":{"v":42},"A":{"value":32}}]"#;
+					let a_b = format!(
+						r#"{{"version":1,"header":{HEADER},"component_versions":{{}},"entities":[{{"A":{{"value":32}},"B":{{"v":42}}}}],"stash":{{}}}}"#
+					);
+					let b_a = format!(
+						r#"{{"version":1,"header":{HEADER},"component_versions":{{}},"entities":[{{"B":{{"v":42}},"A":{{"value":32}}}}],"stash":{{}}}}"#
+					);
 
 					v == a_b || v == b_a
 				})
 				.return_const(Ok(()));
 		});
 		let context = Arc::new(Mutex::new(
-			SaveContext::from(writer).with_save_buffer([(fake_entity!(42), components)]),
+			SaveContext::<_, ComponentHandler>::from(writer)
+				.with_save_buffer([(fake_entity!(42), components)]),
 		));
 		let mut app = setup();
 
@@ -159,17 +195,23 @@ mod tests {
 			mock.expect_write()
 				.times(1)
 				.withf(|v| {
-					let a_b = r#"[{"A":{"value":32}},{"B":{"v":42}}]"#;
-					let b_a = r#"[{"B":{"v":42}},{"A":{"value":32}}]"#;
+					let a_b = format!(
+						r#"{{"version":1,"header":{HEADER},"component_versions":{{}},"entities":[{{"A":{{"value":32}}}},{{"B":{{"v":42}}}}],"stash":{{}}}}"#
+					);
+					let b_a = format!(
+						r#"{{"version":1,"header":{HEADER},"component_versions":{{}},"entities":[{{"B":{{"v":42}}}},{{"A":{{"value":32}}}}],"stash":{{}}}}"#
+					);
 
 					v == a_b || v == b_a
 				})
 				.return_const(Ok(()));
 		});
-		let context = Arc::new(Mutex::new(SaveContext::from(writer).with_save_buffer([
-			(fake_entity!(42), component_a),
-			(fake_entity!(43), component_b),
-		])));
+		let context = Arc::new(Mutex::new(
+			SaveContext::<_, ComponentHandler>::from(writer).with_save_buffer([
+				(fake_entity!(42), component_a),
+				(fake_entity!(43), component_b),
+			]),
+		));
 		let mut app = setup();
 
 		_ = app
@@ -183,13 +225,15 @@ mod tests {
 		let writer = Mock_Writer::new_mock(|mock| {
 			mock.expect_write().return_const(Ok(()));
 		});
-		let context = Arc::new(Mutex::new(SaveContext::from(writer).with_save_buffer([(
-			fake_entity!(42),
-			HashMap::from([(
-				"A".to_owned(),
-				serde_json::from_str(r#"{"value": 32}"#).unwrap(),
+		let context = Arc::new(Mutex::new(
+			SaveContext::<_, ComponentHandler>::from(writer).with_save_buffer([(
+				fake_entity!(42),
+				HashMap::from([(
+					"A".to_owned(),
+					serde_json::from_str(r#"{"value": 32}"#).unwrap(),
+				)]),
 			)]),
-		)])));
+		));
 		let mut app = setup();
 
 		_ = app
@@ -206,4 +250,125 @@ mod tests {
 		);
 		Ok(())
 	}
+
+	#[test]
+	fn write_metadata_header() -> Result<(), RunSystemError> {
+		let writer = Mock_Writer::new_mock(|mock| {
+			mock.expect_write()
+				.times(1)
+				.with(eq(
+					r#"{"version":1,"header":{"timestamp":42,"play_time":{"secs":11,"nanos":0},"level":-1,"player_health":{"current":5.0,"max":10.0}},"component_versions":{},"entities":[],"stash":{}}"#,
+				))
+				.return_const(Ok(()));
+		});
+		let context = Arc::new(Mutex::new(
+			SaveContext::<_, ComponentHandler>::from(writer).with_metadata(SlotMetadata {
+				timestamp: 42,
+				play_time: Duration::from_secs(11),
+				level: Some(-1),
+				player_health: Some(Health {
+					current: 5.,
+					max: 10.,
+				}),
+			}),
+		));
+		let mut app = setup();
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::write_file_system(context))?;
+		Ok(())
+	}
+
+	#[test]
+	fn clear_metadata() -> Result<(), RunSystemError> {
+		let writer = Mock_Writer::new_mock(|mock| {
+			mock.expect_write().return_const(Ok(()));
+		});
+		let context = Arc::new(Mutex::new(
+			SaveContext::<_, ComponentHandler>::from(writer).with_metadata(SlotMetadata {
+				timestamp: 42,
+				..default()
+			}),
+		));
+		let mut app = setup();
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::write_file_system(context.clone()))?;
+
+		assert_eq!(
+			SlotMetadata::default(),
+			context
+				.lock()
+				.expect("COULD NOT LOCK CONTEXT")
+				.buffers
+				.metadata
+		);
+		Ok(())
+	}
+
+	#[test]
+	fn write_component_versions() -> Result<(), RunSystemError> {
+		#[derive(Component, SavableComponent, Serialize, Deserialize, Clone)]
+		#[savable_component(id = "migrated", migrations = [noop, noop])]
+		struct _Migrated;
+
+		fn noop(_: &mut serde_json::Value) {}
+
+		let writer = Mock_Writer::new_mock(|mock| {
+			mock.expect_write()
+				.times(1)
+				.with(eq(format!(
+					r#"{{"version":1,"header":{HEADER},"component_versions":{{"migrated":2}},"entities":[],"stash":{{}}}}"#
+				)))
+				.return_const(Ok(()));
+		});
+		let context = Arc::new(Mutex::new(
+			SaveContext::<_, ComponentHandler>::from(writer)
+				.with_low_priority_handlers([ComponentHandler::new::<_Migrated>()]),
+		));
+		let mut app = setup();
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::write_file_system(context))?;
+		Ok(())
+	}
+
+	#[test]
+	fn write_stash() -> Result<(), RunSystemError> {
+		let writer = Mock_Writer::new_mock(|mock| {
+			mock.expect_write()
+				.times(1)
+				.with(eq(format!(
+					r#"{{"version":1,"header":{HEADER},"component_versions":{{}},"entities":[],"stash":{{"level":[{{"A":{{"value":32}}}}]}}}}"#
+				)))
+				.return_const(Ok(()));
+		});
+		let context = Arc::new(Mutex::new(
+			SaveContext::<_, ComponentHandler>::from(writer).with_stash([(
+				StashKey::from("level"),
+				vec![HashMap::from([(
+					"A".to_owned(),
+					serde_json::from_str(r#"{"value": 32}"#).unwrap(),
+				)])],
+			)]),
+		));
+		let mut app = setup();
+
+		_ = app
+			.world_mut()
+			.run_system_once(SaveContext::write_file_system(context.clone()))?;
+
+		assert!(
+			context
+				.lock()
+				.expect("COULD NOT LOCK CONTEXT")
+				.buffers
+				.stash
+				.is_empty()
+		);
+		Ok(())
+	}
 }
@@ -1,6 +1,4 @@
 pub(crate) mod buffer_entity_component;
-pub(crate) mod file_exists;
 pub(crate) mod insert_entity_component;
-pub(crate) mod read_file;
+pub(crate) mod migrate_component;
 pub(crate) mod write_buffer;
-pub(crate) mod write_file;
@@ -0,0 +1,12 @@
+use common::prelude::*;
+use serde_json::Value;
+
+pub(crate) trait MigrateComponent {
+	fn component_id(&self) -> &UniqueComponentId;
+
+	/// The current serialization version of the component
+	fn version(&self) -> usize;
+
+	/// Upgrade a serialized component from `version` to [`MigrateComponent::version`]
+	fn migrate(&self, component: &mut Value, version: usize);
+}
@@ -0,0 +1,37 @@
+use common::errors::{ErrorData, Level};
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub(crate) enum SettingsIOError<TFileError> {
+	File(TFileError),
+	Serde(serde_json::Error),
+}
+
+impl<TFileError> Display for SettingsIOError<TFileError>
+where
+	TFileError: Display,
+{
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			SettingsIOError::File(error) => write!(f, "{error}"),
+			SettingsIOError::Serde(error) => write!(f, "{error}"),
+		}
+	}
+}
+
+impl<TFileError> ErrorData for SettingsIOError<TFileError>
+where
+	TFileError: Display + 'static,
+{
+	fn level(&self) -> Level {
+		Level::Error
+	}
+
+	fn label() -> impl Display {
+		"Settings IO error"
+	}
+
+	fn into_details(self) -> impl Display {
+		self
+	}
+}
@@ -0,0 +1,67 @@
+mod errors;
+mod resources;
+mod system_params;
+mod systems;
+
+use crate::{
+	resources::current_settings::CurrentSettings,
+	system_params::settings_param::{SettingsParam, SettingsParamMut},
+};
+use bevy::prelude::*;
+use common::{prelude::*, tools::file_io::FileIO};
+use std::{marker::PhantomData, path::PathBuf};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+pub struct SettingsPlugin<TDependencies> {
+	game_directory: PathBuf,
+	_p: PhantomData<TDependencies>,
+}
+
+impl<TLocalization> SettingsPlugin<TLocalization>
+where
+	TLocalization: ThreadSafe + HandlesLocalization,
+{
+	pub fn from_plugin(_: &TLocalization) -> SettingsPluginBuilder<TLocalization> {
+		SettingsPluginBuilder(PhantomData)
+	}
+}
+
+pub struct SettingsPluginBuilder<TDependencies>(PhantomData<TDependencies>);
+
+impl<TDependencies> SettingsPluginBuilder<TDependencies> {
+	pub fn with_game_directory(self, game_directory: PathBuf) -> SettingsPlugin<TDependencies> {
+		SettingsPlugin {
+			game_directory,
+			_p: PhantomData,
+		}
+	}
+}
+
+impl<TLocalization> Plugin for SettingsPlugin<TLocalization>
+where
+	TLocalization: ThreadSafe + HandlesLocalization,
+{
+	fn build(&self, app: &mut App) {
+		let file = self.game_directory.join(SETTINGS_FILE);
+
+		app.init_resource::<CurrentSettings>()
+			.add_systems(
+				Startup,
+				CurrentSettings::read_from(FileIO::with_file(file.clone())).pipe(OnError::log),
+			)
+			.add_systems(
+				Update,
+				(
+					CurrentSettings::apply_window,
+					CurrentSettings::apply_language::<TLocalization::TLocalizationServerMut>,
+					CurrentSettings::write_to(FileIO::with_file(file)).pipe(OnError::log),
+				),
+			);
+	}
+}
+
+impl<TDependencies> HandlesSettings for SettingsPlugin<TDependencies> {
+	type TSettings = SettingsParam<'static>;
+	type TSettingsMut = SettingsParamMut<'static>;
+}
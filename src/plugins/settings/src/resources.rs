@@ -0,0 +1 @@
+pub(crate) mod current_settings;
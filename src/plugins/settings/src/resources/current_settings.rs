@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+use common::prelude::*;
+
+#[derive(Resource, Debug, PartialEq, Default, Clone)]
+pub struct CurrentSettings(pub(crate) GeneralSettings);
+
+impl GetSettings for CurrentSettings {
+	fn settings(&self) -> &GeneralSettings {
+		&self.0
+	}
+}
+
+impl SetSettings for CurrentSettings {
+	fn settings_mut(&mut self) -> &mut GeneralSettings {
+		&mut self.0
+	}
+}
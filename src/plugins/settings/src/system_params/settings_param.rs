@@ -0,0 +1,93 @@
+use crate::resources::current_settings::CurrentSettings;
+use bevy::{
+	ecs::system::{SystemParam, SystemParamItem},
+	prelude::*,
+};
+use common::prelude::*;
+
+#[derive(SystemParam)]
+pub struct SettingsParam<'w> {
+	settings: Option<Res<'w, CurrentSettings>>,
+}
+
+impl TryGetContext<GameSettings> for SettingsParam<'static> {
+	type TContext<'ctx> = &'ctx Res<'ctx, CurrentSettings>;
+
+	fn try_get_context<'ctx>(
+		param: &'ctx SystemParamItem<Self>,
+		_: GameSettings,
+	) -> Option<Self::TContext<'ctx>> {
+		param.settings.as_ref()
+	}
+}
+
+#[derive(SystemParam)]
+pub struct SettingsParamMut<'w> {
+	settings: Option<ResMut<'w, CurrentSettings>>,
+}
+
+impl TryGetContextMut<GameSettings> for SettingsParamMut<'static> {
+	type TContext<'ctx> = Mut<'ctx, CurrentSettings>;
+
+	fn try_get_context_mut<'ctx>(
+		param: &'ctx mut SystemParamItem<Self>,
+		_: GameSettings,
+	) -> Option<Self::TContext<'ctx>> {
+		let settings = param.settings.as_mut()?;
+
+		Some(settings.reborrow())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use bevy::ecs::system::{RunSystemError, RunSystemOnce};
+	use testing::SingleThreadedApp;
+
+	fn setup(settings: CurrentSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+		app.insert_resource(settings);
+
+		app
+	}
+
+	#[test]
+	fn get_settings() -> Result<(), RunSystemError> {
+		let settings = GeneralSettings {
+			fps_cap: 42,
+			..default()
+		};
+		let mut app = setup(CurrentSettings(settings.clone()));
+
+		let result = app.world_mut().run_system_once(|param: SettingsParam| {
+			let ctx = SettingsParam::try_get_context(&param, GameSettings).unwrap();
+			ctx.settings().clone()
+		})?;
+
+		assert_eq!(settings, result);
+		Ok(())
+	}
+
+	#[test]
+	fn set_settings() -> Result<(), RunSystemError> {
+		let mut app = setup(CurrentSettings::default());
+
+		app.world_mut()
+			.run_system_once(|mut param: SettingsParamMut| {
+				let mut ctx =
+					SettingsParamMut::try_get_context_mut(&mut param, GameSettings).unwrap();
+				ctx.settings_mut().vsync = false;
+			})?;
+
+		assert_eq!(
+			&CurrentSettings(GeneralSettings {
+				vsync: false,
+				..default()
+			}),
+			app.world().resource::<CurrentSettings>(),
+		);
+		Ok(())
+	}
+}
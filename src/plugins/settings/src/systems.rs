@@ -0,0 +1,4 @@
+pub(crate) mod apply_language;
+pub(crate) mod apply_window;
+pub(crate) mod read_settings;
+pub(crate) mod write_settings;
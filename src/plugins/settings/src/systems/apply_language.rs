@@ -0,0 +1,82 @@
+use crate::resources::current_settings::CurrentSettings;
+use bevy::{
+	ecs::system::{StaticSystemParam, SystemParam},
+	prelude::*,
+};
+use common::prelude::*;
+
+impl CurrentSettings {
+	pub(crate) fn apply_language<TLocalizationMut>(
+		settings: Res<Self>,
+		mut localization: StaticSystemParam<TLocalizationMut>,
+	) where
+		TLocalizationMut: for<'w, 's> SystemParam<Item<'w, 's>: SetLocalization>,
+	{
+		if !settings.is_changed() {
+			return;
+		}
+
+		let CurrentSettings(settings) = settings.as_ref();
+		localization.set_localization(settings.language.clone());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use testing::SingleThreadedApp;
+	use unic_langid::{LanguageIdentifier, langid};
+
+	#[derive(Resource, Debug, PartialEq, Default)]
+	struct _Localization(Vec<LanguageIdentifier>);
+
+	impl SetLocalization for _Localization {
+		fn set_localization(&mut self, language: LanguageIdentifier) {
+			self.0.push(language);
+		}
+	}
+
+	fn setup(settings: GeneralSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<_Localization>();
+		app.insert_resource(CurrentSettings(settings));
+		app.add_systems(
+			Update,
+			CurrentSettings::apply_language::<ResMut<_Localization>>,
+		);
+
+		app
+	}
+
+	#[test]
+	fn set_language() {
+		let mut app = setup(GeneralSettings {
+			language: langid!("de"),
+			..default()
+		});
+
+		app.update();
+
+		assert_eq!(
+			&_Localization(vec![langid!("de")]),
+			app.world().resource::<_Localization>(),
+		);
+	}
+
+	#[test]
+	fn set_language_only_when_changed() {
+		let mut app = setup(GeneralSettings {
+			language: langid!("de"),
+			..default()
+		});
+
+		app.update();
+		app.update();
+
+		assert_eq!(
+			&_Localization(vec![langid!("de")]),
+			app.world().resource::<_Localization>(),
+		);
+	}
+}
@@ -0,0 +1,170 @@
+use crate::resources::current_settings::CurrentSettings;
+use bevy::{
+	prelude::*,
+	window::{MonitorSelection, PresentMode, PrimaryWindow, VideoModeSelection, WindowMode},
+};
+use common::prelude::*;
+
+impl CurrentSettings {
+	pub(crate) fn apply_window(
+		settings: Res<Self>,
+		mut windows: Query<&mut Window, With<PrimaryWindow>>,
+	) {
+		if !settings.is_changed() {
+			return;
+		}
+
+		let CurrentSettings(settings) = settings.as_ref();
+
+		for mut window in &mut windows {
+			window.present_mode = match settings.vsync {
+				true => PresentMode::AutoVsync,
+				false => PresentMode::AutoNoVsync,
+			};
+			window.mode = match settings.window_mode {
+				DisplayMode::Windowed => WindowMode::Windowed,
+				DisplayMode::BorderlessFullscreen => {
+					WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+				}
+				DisplayMode::Fullscreen => {
+					WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current)
+				}
+			};
+
+			let scale_factor = window.resolution.base_scale_factor() * settings.resolution_scale;
+			window
+				.resolution
+				.set_scale_factor_override(Some(scale_factor));
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	#![allow(clippy::unwrap_used)]
+	use super::*;
+	use testing::SingleThreadedApp;
+
+	fn setup(settings: GeneralSettings) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.insert_resource(CurrentSettings(settings));
+		app.add_systems(Update, CurrentSettings::apply_window);
+
+		app
+	}
+
+	#[test]
+	fn apply_vsync() {
+		let mut app = setup(GeneralSettings {
+			vsync: false,
+			..default()
+		});
+		let window = app
+			.world_mut()
+			.spawn((Window::default(), PrimaryWindow))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			PresentMode::AutoNoVsync,
+			app.world()
+				.entity(window)
+				.get::<Window>()
+				.unwrap()
+				.present_mode,
+		);
+	}
+
+	#[test]
+	fn apply_window_mode() {
+		let mut app = setup(GeneralSettings {
+			window_mode: DisplayMode::BorderlessFullscreen,
+			..default()
+		});
+		let window = app
+			.world_mut()
+			.spawn((Window::default(), PrimaryWindow))
+			.id();
+
+		app.update();
+
+		assert_eq!(
+			WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+			app.world().entity(window).get::<Window>().unwrap().mode,
+		);
+	}
+
+	#[test]
+	fn apply_resolution_scale() {
+		let mut app = setup(GeneralSettings {
+			resolution_scale: 0.5,
+			..default()
+		});
+		let mut window = Window::default();
+		window.resolution.set_scale_factor(2.);
+		let window = app.world_mut().spawn((window, PrimaryWindow)).id();
+
+		app.update();
+
+		assert_eq!(
+			Some(1.),
+			app.world()
+				.entity(window)
+				.get::<Window>()
+				.unwrap()
+				.resolution
+				.scale_factor_override(),
+		);
+	}
+
+	#[test]
+	fn ignore_non_primary_windows() {
+		let mut app = setup(GeneralSettings {
+			vsync: false,
+			..default()
+		});
+		let window = app.world_mut().spawn(Window::default()).id();
+
+		app.update();
+
+		assert_eq!(
+			Window::default().present_mode,
+			app.world()
+				.entity(window)
+				.get::<Window>()
+				.unwrap()
+				.present_mode,
+		);
+	}
+
+	#[test]
+	fn apply_only_when_changed() {
+		let mut app = setup(GeneralSettings {
+			vsync: false,
+			..default()
+		});
+		let window = app
+			.world_mut()
+			.spawn((Window::default(), PrimaryWindow))
+			.id();
+
+		app.update();
+		app.world_mut()
+			.entity_mut(window)
+			.get_mut::<Window>()
+			.unwrap()
+			.present_mode = PresentMode::Fifo;
+		app.update();
+
+		assert_eq!(
+			PresentMode::Fifo,
+			app.world()
+				.entity(window)
+				.get::<Window>()
+				.unwrap()
+				.present_mode,
+		);
+	}
+}
@@ -0,0 +1,172 @@
+use crate::{errors::SettingsIOError, resources::current_settings::CurrentSettings};
+use bevy::prelude::*;
+use common::{
+	prelude::*,
+	traits::{file_exists::FileExists, read_file::ReadFile},
+};
+
+impl CurrentSettings {
+	/// Replaces the current settings with the file content, if the file exists.
+	///
+	/// Change detection is bypassed, so that freshly read settings are not written back. Systems
+	/// applying the settings still pick them up, because the resource counts as newly added.
+	pub(crate) fn read_from<TFileIO>(
+		file: TFileIO,
+	) -> impl Fn(ResMut<Self>) -> Result<(), SettingsIOError<TFileIO::TReadError>>
+	where
+		TFileIO: FileExists + ReadFile + ThreadSafe,
+	{
+		move |mut settings| {
+			if !file.file_exists() {
+				return Ok(());
+			}
+
+			let json = file.read().map_err(SettingsIOError::File)?;
+			let read = serde_json::from_str(&json).map_err(SettingsIOError::Serde)?;
+			*settings.bypass_change_detection() = CurrentSettings(read);
+
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::{Arc, Mutex};
+	use testing::{SingleThreadedApp, is_changed_resource};
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Error;
+
+	struct _File(Option<Result<String, _Error>>);
+
+	impl FileExists for _File {
+		fn file_exists(&self) -> bool {
+			self.0.is_some()
+		}
+	}
+
+	impl ReadFile for _File {
+		type TReadError = _Error;
+
+		fn read(&self) -> Result<String, Self::TReadError> {
+			self.0.clone().unwrap_or(Err(_Error))
+		}
+	}
+
+	#[derive(Resource, Debug, PartialEq)]
+	struct _Result(Result<(), Option<_Error>>);
+
+	fn store_result(In(result): In<Result<(), SettingsIOError<_Error>>>, mut commands: Commands) {
+		commands.insert_resource(_Result(result.map_err(|error| match error {
+			SettingsIOError::File(error) => Some(error),
+			SettingsIOError::Serde(_) => None,
+		})));
+	}
+
+	fn setup(file: _File) -> App {
+		setup_with_change_tracking(file, &Arc::new(Mutex::new(false)))
+	}
+
+	fn setup_with_change_tracking(file: _File, changed: &Arc<Mutex<bool>>) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<CurrentSettings>();
+		app.add_systems(
+			Update,
+			(
+				CurrentSettings::read_from(file).pipe(store_result),
+				is_changed_resource!(CurrentSettings, changed),
+			)
+				.chain(),
+		);
+
+		app
+	}
+
+	#[test]
+	fn read_settings() {
+		let settings = GeneralSettings {
+			fps_cap: 30,
+			vsync: false,
+			..default()
+		};
+		let json = serde_json::to_string(&settings).expect("SERIALIZATION FAILED");
+		let mut app = setup(_File(Some(Ok(json))));
+
+		app.update();
+
+		assert_eq!(
+			(&CurrentSettings(settings), &_Result(Ok(()))),
+			(
+				app.world().resource::<CurrentSettings>(),
+				app.world().resource::<_Result>(),
+			),
+		);
+	}
+
+	#[test]
+	fn do_not_mark_settings_as_changed() {
+		let json =
+			serde_json::to_string(&GeneralSettings::default()).expect("SERIALIZATION FAILED");
+		let changed = Arc::new(Mutex::new(false));
+		let mut app = setup_with_change_tracking(_File(Some(Ok(json))), &changed);
+
+		app.update();
+		app.update();
+
+		assert!(!*changed.lock().expect("LOCK POISONED"));
+	}
+
+	#[test]
+	fn use_defaults_for_missing_fields() {
+		let mut app = setup(_File(Some(Ok(String::from(r#"{"fps_cap": 30}"#)))));
+
+		app.update();
+
+		assert_eq!(
+			&CurrentSettings(GeneralSettings {
+				fps_cap: 30,
+				..default()
+			}),
+			app.world().resource::<CurrentSettings>(),
+		);
+	}
+
+	#[test]
+	fn keep_defaults_when_file_does_not_exist() {
+		let mut app = setup(_File(None));
+
+		app.update();
+
+		assert_eq!(
+			(&CurrentSettings::default(), &_Result(Ok(()))),
+			(
+				app.world().resource::<CurrentSettings>(),
+				app.world().resource::<_Result>(),
+			),
+		);
+	}
+
+	#[test]
+	fn return_file_error() {
+		let mut app = setup(_File(Some(Err(_Error))));
+
+		app.update();
+
+		assert_eq!(
+			&_Result(Err(Some(_Error))),
+			app.world().resource::<_Result>(),
+		);
+	}
+
+	#[test]
+	fn return_serde_error() {
+		let mut app = setup(_File(Some(Ok(String::from("{")))));
+
+		app.update();
+
+		assert_eq!(&_Result(Err(None)), app.world().resource::<_Result>());
+	}
+}
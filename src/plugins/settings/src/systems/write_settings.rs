@@ -0,0 +1,138 @@
+use crate::{errors::SettingsIOError, resources::current_settings::CurrentSettings};
+use bevy::prelude::*;
+use common::{prelude::*, traits::write_file::WriteFile};
+
+impl CurrentSettings {
+	pub(crate) fn write_to<TFileIO>(
+		file: TFileIO,
+	) -> impl Fn(Res<Self>) -> Result<(), SettingsIOError<TFileIO::TWriteError>>
+	where
+		TFileIO: WriteFile + ThreadSafe,
+	{
+		move |settings| {
+			if settings.is_added() || !settings.is_changed() {
+				return Ok(());
+			}
+
+			let CurrentSettings(settings) = settings.as_ref();
+			let json = serde_json::to_string_pretty(settings).map_err(SettingsIOError::Serde)?;
+
+			file.write(&json).map_err(SettingsIOError::File)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::sync::{Arc, Mutex};
+	use testing::SingleThreadedApp;
+
+	#[derive(Debug, PartialEq, Clone)]
+	struct _Error;
+
+	#[derive(Default, Clone)]
+	struct _File {
+		written: Arc<Mutex<Vec<String>>>,
+		result: Option<_Error>,
+	}
+
+	impl WriteFile for _File {
+		type TWriteError = _Error;
+
+		fn write(&self, string: &str) -> Result<(), Self::TWriteError> {
+			self.written
+				.lock()
+				.expect("LOCK POISONED")
+				.push(string.to_owned());
+
+			match &self.result {
+				Some(error) => Err(error.clone()),
+				None => Ok(()),
+			}
+		}
+	}
+
+	#[derive(Resource, Debug, PartialEq)]
+	struct _Result(Result<(), Option<_Error>>);
+
+	fn store_result(In(result): In<Result<(), SettingsIOError<_Error>>>, mut commands: Commands) {
+		commands.insert_resource(_Result(result.map_err(|error| match error {
+			SettingsIOError::File(error) => Some(error),
+			SettingsIOError::Serde(_) => None,
+		})));
+	}
+
+	fn setup(file: _File) -> App {
+		let mut app = App::new().single_threaded(Update);
+
+		app.init_resource::<CurrentSettings>();
+		app.add_systems(Update, CurrentSettings::write_to(file).pipe(store_result));
+
+		app
+	}
+
+	fn written(file: &_File) -> Vec<String> {
+		file.written.lock().expect("LOCK POISONED").clone()
+	}
+
+	#[test]
+	fn write_changed_settings() {
+		let file = _File::default();
+		let mut app = setup(file.clone());
+		let settings = GeneralSettings {
+			outlines: false,
+			..default()
+		};
+
+		app.update();
+		app.world_mut().resource_mut::<CurrentSettings>().0 = settings.clone();
+		app.update();
+
+		assert_eq!(
+			vec![serde_json::to_string_pretty(&settings).expect("SERIALIZATION FAILED")],
+			written(&file),
+		);
+	}
+
+	#[test]
+	fn do_not_write_when_just_added() {
+		let file = _File::default();
+		let mut app = setup(file.clone());
+
+		app.update();
+
+		assert_eq!(Vec::<String>::new(), written(&file));
+	}
+
+	#[test]
+	fn do_not_write_when_not_changed() {
+		let file = _File::default();
+		let mut app = setup(file.clone());
+
+		app.update();
+		app.world_mut().resource_mut::<CurrentSettings>().0.vsync = false;
+		app.update();
+		app.update();
+
+		assert_eq!(1, written(&file).len());
+	}
+
+	#[test]
+	fn return_file_error() {
+		let file = _File {
+			result: Some(_Error),
+			..default()
+		};
+		let mut app = setup(file);
+
+		app.update();
+		app.world_mut().resource_mut::<CurrentSettings>().0.vsync = false;
+		app.update();
+
+		assert_eq!(
+			&_Result(Err(Some(_Error))),
+			app.world().resource::<_Result>(),
+		);
+	}
+}